@@ -18,6 +18,8 @@
 
 mod bytecode;
 mod context;
+mod convert;
+mod disasm;
 mod error;
 mod eval;
 mod field;
@@ -32,8 +34,10 @@ mod object;
 mod opregion;
 mod parser;
 mod reference;
+mod resource;
 mod state;
 mod sync;
+mod table;
 mod trace;
 mod value;
 mod verify;
@@ -41,6 +45,7 @@ mod vm;
 
 pub use bytecode::*;
 pub use context::*;
+pub use disasm::*;
 pub use error::*;
 pub use eval::*;
 pub use field::*;
@@ -55,8 +60,10 @@ pub use object::*;
 pub use opregion::*;
 pub use parser::*;
 pub use reference::*;
+pub use resource::*;
 pub use sync::*;
 pub use state::*;
+pub use table::*;
 pub use trace::*;
 pub use value::*;
 pub use verify::*;
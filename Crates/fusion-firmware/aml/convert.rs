@@ -0,0 +1,346 @@
+//! AML data-object conversion rules shared by the evaluator.
+//!
+//! These follow the implicit and explicit conversion tables of ACPI 6.5 section 19.3.5. Text
+//! layouts match what ACPICA produces, since firmware is written and tested against it.
+
+use crate::aml::{
+    AML_MAX_BUFFER_BYTES,
+    AmlError,
+    AmlIntegerWidth,
+    AmlResult,
+};
+
+/// Fixed-capacity byte accumulator for strings and buffers produced during evaluation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct AmlByteBuilder {
+    bytes: [u8; AML_MAX_BUFFER_BYTES],
+    len: usize,
+}
+
+impl AmlByteBuilder {
+    pub(super) const fn new() -> Self {
+        Self {
+            bytes: [0; AML_MAX_BUFFER_BYTES],
+            len: 0,
+        }
+    }
+
+    pub(super) fn from_slice(bytes: &[u8]) -> AmlResult<Self> {
+        let mut builder = Self::new();
+        builder.extend(bytes)?;
+        Ok(builder)
+    }
+
+    pub(super) fn push(&mut self, byte: u8) -> AmlResult<()> {
+        let slot = self
+            .bytes
+            .get_mut(self.len)
+            .ok_or_else(AmlError::overflow)?;
+        *slot = byte;
+        self.len += 1;
+        Ok(())
+    }
+
+    pub(super) fn extend(&mut self, bytes: &[u8]) -> AmlResult<()> {
+        let end = self.len + bytes.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or_else(AmlError::overflow)?
+            .copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+
+    pub(super) fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    pub(super) fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.bytes[..self.len]
+    }
+
+    /// Appends `value` in decimal without leading zeros.
+    pub(super) fn push_decimal(&mut self, value: u64) -> AmlResult<()> {
+        let mut digits = [0_u8; 20];
+        let mut remaining = value;
+        let mut count = 0_usize;
+        loop {
+            digits[count] = b'0' + (remaining % 10) as u8;
+            count += 1;
+            remaining /= 10;
+            if remaining == 0 {
+                break;
+            }
+        }
+        while count > 0 {
+            count -= 1;
+            self.push(digits[count])?;
+        }
+        Ok(())
+    }
+
+    /// Appends the low `digits` nibbles of `value` as upper-case hex.
+    pub(super) fn push_hex(&mut self, value: u64, digits: usize) -> AmlResult<()> {
+        let mut index = digits;
+        while index > 0 {
+            index -= 1;
+            self.push(hex_digit(((value >> (index * 4)) & 0x0f) as u8))?;
+        }
+        Ok(())
+    }
+}
+
+/// Operand bytes, borrowed from the definition block or copied out of runtime state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum AmlOperandBytes<'a> {
+    Borrowed(&'a [u8]),
+    Runtime(AmlByteBuilder),
+}
+
+impl AmlOperandBytes<'_> {
+    pub(super) fn as_slice(&self) -> &[u8] {
+        match self {
+            Self::Borrowed(bytes) => bytes,
+            Self::Runtime(builder) => builder.as_slice(),
+        }
+    }
+}
+
+/// Layout used when rendering a buffer or integer as text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum AmlStringStyle {
+    /// Implicit operand conversion: integers as full-width hex, buffer bytes as `"01 02"`.
+    Implicit,
+    /// `ToHexString`: integers as `0x`-prefixed hex without leading zeros, buffer bytes as
+    /// `"0x01,0x02"`.
+    Hex,
+    /// `ToDecimalString`: integers in decimal, buffer bytes as `"1,2"`.
+    Decimal,
+}
+
+/// Renders one integer in the given style.
+pub(super) fn integer_text(
+    value: u64,
+    width: AmlIntegerWidth,
+    style: AmlStringStyle,
+) -> AmlResult<AmlByteBuilder> {
+    let mut out = AmlByteBuilder::new();
+    match style {
+        AmlStringStyle::Decimal => out.push_decimal(value)?,
+        AmlStringStyle::Implicit => out.push_hex(value, integer_byte_len(width) * 2)?,
+        AmlStringStyle::Hex => {
+            out.extend(b"0x")?;
+            let significant = (u64::BITS - value.leading_zeros()).div_ceil(4).max(1);
+            out.push_hex(value, significant as usize)?;
+        }
+    }
+    Ok(out)
+}
+
+/// Renders one buffer in the given style.
+pub(super) fn buffer_text(bytes: &[u8], style: AmlStringStyle) -> AmlResult<AmlByteBuilder> {
+    let mut out = AmlByteBuilder::new();
+    for (index, byte) in bytes.iter().enumerate() {
+        match style {
+            AmlStringStyle::Implicit => {
+                if index != 0 {
+                    out.push(b' ')?;
+                }
+                out.push_hex(u64::from(*byte), 2)?;
+            }
+            AmlStringStyle::Hex => {
+                if index != 0 {
+                    out.push(b',')?;
+                }
+                out.extend(b"0x")?;
+                out.push_hex(u64::from(*byte), 2)?;
+            }
+            AmlStringStyle::Decimal => {
+                if index != 0 {
+                    out.push(b',')?;
+                }
+                out.push_decimal(u64::from(*byte))?;
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Byte length of one integer in the namespace's integer width.
+pub(super) const fn integer_byte_len(width: AmlIntegerWidth) -> usize {
+    match width {
+        AmlIntegerWidth::Bits32 => 4,
+        AmlIntegerWidth::Bits64 => 8,
+    }
+}
+
+/// Little-endian integer from the leading bytes of one buffer.
+pub(super) fn buffer_integer(bytes: &[u8], width: AmlIntegerWidth) -> u64 {
+    bytes
+        .iter()
+        .take(integer_byte_len(width))
+        .rev()
+        .fold(0_u64, |value, byte| (value << 8) | u64::from(*byte))
+}
+
+/// Parses string text the way `ToInteger` does: `0x`-prefixed hex, otherwise decimal.
+///
+/// Leading whitespace is skipped and parsing stops at the first character that is not a digit
+/// of the chosen radix.
+///
+/// # Errors
+///
+/// Returns `overflow` when the value does not fit the namespace integer width.
+pub(super) fn parse_explicit_integer(text: &[u8], width: AmlIntegerWidth) -> AmlResult<u64> {
+    let text = trim_leading_whitespace(text);
+    match text {
+        [b'0', b'x' | b'X', digits @ ..] => parse_radix(digits, 16, width),
+        _ => parse_radix(text, 10, width),
+    }
+}
+
+/// Parses string text the way implicit operand conversion does: always hex.
+///
+/// # Errors
+///
+/// Returns `overflow` when the value does not fit the namespace integer width.
+pub(super) fn parse_implicit_integer(text: &[u8], width: AmlIntegerWidth) -> AmlResult<u64> {
+    parse_radix(trim_leading_whitespace(text), 16, width)
+}
+
+fn trim_leading_whitespace(text: &[u8]) -> &[u8] {
+    let start = text
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(text.len());
+    &text[start..]
+}
+
+fn parse_radix(text: &[u8], radix: u32, width: AmlIntegerWidth) -> AmlResult<u64> {
+    let limit = match width {
+        AmlIntegerWidth::Bits32 => u64::from(u32::MAX),
+        AmlIntegerWidth::Bits64 => u64::MAX,
+    };
+    let mut value = 0_u64;
+    for byte in text {
+        let Some(digit) = char::from(*byte).to_digit(radix) else {
+            break;
+        };
+        value = value
+            .checked_mul(u64::from(radix))
+            .and_then(|value| value.checked_add(u64::from(digit)))
+            .filter(|value| *value <= limit)
+            .ok_or_else(AmlError::overflow)?;
+    }
+    Ok(value)
+}
+
+const fn hex_digit(nibble: u8) -> u8 {
+    let nibble = nibble & 0x0f;
+    if nibble < 10 {
+        b'0' + nibble
+    } else {
+        b'A' + nibble - 10
+    }
+}
+
+/// Evaluates one `Match` comparison (`MTR`, `MEQ`, `MLE`, `MLT`, `MGE`, `MGT`).
+///
+/// # Errors
+///
+/// Returns `invalid_bytecode` for match opcodes above `MGT`.
+pub(super) const fn match_predicate(op: u8, element: u64, operand: u64) -> AmlResult<bool> {
+    match op {
+        0 => Ok(true),
+        1 => Ok(element == operand),
+        2 => Ok(element <= operand),
+        3 => Ok(element < operand),
+        4 => Ok(element >= operand),
+        5 => Ok(element > operand),
+        _ => Err(AmlError::invalid_bytecode()),
+    }
+}
+
+/// One-based index of the most significant set bit, or zero.
+pub(super) const fn find_set_left_bit(value: u64) -> u64 {
+    if value == 0 {
+        0
+    } else {
+        64 - value.leading_zeros() as u64
+    }
+}
+
+/// One-based index of the least significant set bit, or zero.
+pub(super) const fn find_set_right_bit(value: u64) -> u64 {
+    if value == 0 {
+        0
+    } else {
+        value.trailing_zeros() as u64 + 1
+    }
+}
+
+/// Strips the end tag from one resource template so another can be appended (`ConcatRes`).
+///
+/// # Errors
+///
+/// Returns `invalid_bytecode` when the template is malformed or lacks an end tag.
+pub(super) fn resource_template_body(bytes: &[u8]) -> AmlResult<&[u8]> {
+    let template = crate::aml::AmlResourceTemplate::parse(bytes)?;
+    let bytes = template.as_bytes();
+    Ok(&bytes[..bytes.len() - 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_and_buffer_text_follow_acpica_layouts() {
+        let hex = integer_text(0x1234, AmlIntegerWidth::Bits32, AmlStringStyle::Hex).unwrap();
+        assert_eq!(hex.as_slice(), b"0x1234");
+        let zero = integer_text(0, AmlIntegerWidth::Bits64, AmlStringStyle::Hex).unwrap();
+        assert_eq!(zero.as_slice(), b"0x0");
+        let implicit =
+            integer_text(0x1234, AmlIntegerWidth::Bits32, AmlStringStyle::Implicit).unwrap();
+        assert_eq!(implicit.as_slice(), b"00001234");
+        let decimal = integer_text(1234, AmlIntegerWidth::Bits64, AmlStringStyle::Decimal).unwrap();
+        assert_eq!(decimal.as_slice(), b"1234");
+
+        let bytes = [0x01, 0xab, 0x10];
+        assert_eq!(
+            buffer_text(&bytes, AmlStringStyle::Implicit)
+                .unwrap()
+                .as_slice(),
+            b"01 AB 10"
+        );
+        assert_eq!(
+            buffer_text(&bytes, AmlStringStyle::Hex).unwrap().as_slice(),
+            b"0x01,0xAB,0x10"
+        );
+        assert_eq!(
+            buffer_text(&bytes, AmlStringStyle::Decimal)
+                .unwrap()
+                .as_slice(),
+            b"1,171,16"
+        );
+    }
+
+    #[test]
+    fn integer_parsing_honours_radix_and_width() {
+        let width = AmlIntegerWidth::Bits64;
+        assert_eq!(parse_explicit_integer(b"  0x1F", width).unwrap(), 0x1f);
+        assert_eq!(parse_explicit_integer(b"42abc", width).unwrap(), 42);
+        assert_eq!(parse_implicit_integer(b"1F", width).unwrap(), 0x1f);
+        assert_eq!(
+            parse_explicit_integer(b"0x100000000", AmlIntegerWidth::Bits32)
+                .unwrap_err()
+                .kind,
+            crate::aml::AmlErrorKind::Overflow
+        );
+        assert_eq!(
+            buffer_integer(&[0x78, 0x56, 0x34, 0x12, 0xff], AmlIntegerWidth::Bits32),
+            0x1234_5678
+        );
+        assert_eq!((find_set_left_bit(0x90), find_set_right_bit(0x90)), (8, 5));
+    }
+}
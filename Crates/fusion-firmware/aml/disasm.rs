@@ -0,0 +1,1387 @@
+//! AML disassembly back to ASL-like text, plus a namespace tree dump.
+//!
+//! The disassembler walks the same encodings the loader and evaluator consume and renders each
+//! opcode in ASL function-call form (`Add (Local0, One, Local1)`). It never fails on malformed
+//! bytecode: the offending term list ends with a comment naming the error and its offset, which is
+//! exactly what bring-up needs when an `AmlError` points into code nobody has read yet.
+
+use core::fmt::{
+    self,
+    Write,
+};
+
+use crate::aml::loader::{
+    AmlFieldElement,
+    decode_external,
+    decode_field_access,
+    decode_field_element,
+    decode_field_list_header,
+    decode_field_update,
+    decode_method_header,
+    decode_named_object,
+    decode_opregion_header,
+    decode_pkg_extent,
+};
+use crate::aml::{
+    AmlAddressSpaceId,
+    AmlBytecodeSpan,
+    AmlCodeLocation,
+    AmlDefinitionBlock,
+    AmlEncodedNameString,
+    AmlError,
+    AmlFieldAccessKind,
+    AmlFieldUpdateKind,
+    AmlLoadedNamespace,
+    AmlMethodSerialization,
+    AmlNameAnchor,
+    AmlNameSeg,
+    AmlNamespaceLoadRecord,
+    AmlNamespaceNodePayload,
+    AmlResolvedNamePath,
+    AML_MAX_PATH_TEXT_BYTES,
+};
+
+/// Renders one definition block (or a span of it) as ASL-like text.
+///
+/// With a namespace attached, name references that resolve to methods are rendered as calls with
+/// their arguments; without one every name is rendered as a plain reference.
+#[derive(Debug, Clone, Copy)]
+pub struct AmlDisassembler<'records, 'blocks> {
+    block: AmlDefinitionBlock<'blocks>,
+    namespace: Option<AmlLoadedNamespace<'records, 'blocks>>,
+    marker: Option<u32>,
+}
+
+impl<'records, 'blocks> AmlDisassembler<'records, 'blocks> {
+    #[must_use]
+    pub const fn new(block: AmlDefinitionBlock<'blocks>) -> Self {
+        Self {
+            block,
+            namespace: None,
+            marker: None,
+        }
+    }
+
+    /// Resolves method arity through `namespace` so invocations render with their arguments.
+    #[must_use]
+    pub const fn with_namespace(self, namespace: AmlLoadedNamespace<'records, 'blocks>) -> Self {
+        Self {
+            namespace: Some(namespace),
+            ..self
+        }
+    }
+
+    /// Flags the innermost statement containing block offset `offset` with a trailing comment.
+    #[must_use]
+    pub const fn with_marker(self, offset: u32) -> Self {
+        Self {
+            marker: Some(offset),
+            ..self
+        }
+    }
+
+    /// Writes the whole block, wrapped in its `DefinitionBlock` header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only when `out` does.
+    pub fn write_block(&self, out: &mut dyn Write) -> fmt::Result {
+        let header = self.block.header;
+        out.write_str("DefinitionBlock (\"\", ")?;
+        write_quoted(out, &header.signature)?;
+        write!(out, ", {}, ", header.revision)?;
+        write_quoted(out, &header.oem_id)?;
+        out.write_str(", ")?;
+        write_quoted(out, &header.oem_table_id)?;
+        writeln!(out, ", 0x{:08X})", header.oem_revision)?;
+        out.write_str("{\n")?;
+        self.writer(out)
+            .term_list(0, self.block.bytes.len(), 1, AmlResolvedNamePath::root())?;
+        out.write_str("}\n")
+    }
+
+    /// Writes the term list covering `span`, resolving names relative to `scope`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only when `out` does.
+    pub fn write_span(
+        &self,
+        span: AmlBytecodeSpan,
+        scope: AmlResolvedNamePath,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        self.write_span_at(span, scope, 0, out)
+    }
+
+    /// Writes the method enclosing `location`, marking the statement it points at.
+    ///
+    /// Falls back to the whole block when the location is outside every method body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only when `out` does.
+    pub fn write_location(
+        namespace: AmlLoadedNamespace<'records, 'blocks>,
+        location: AmlCodeLocation,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        let Some(block) = namespace.blocks.block(location.block_index) else {
+            return writeln!(out, "/* no definition block {} */", location.block_index);
+        };
+        let disassembler = Self::new(block)
+            .with_namespace(namespace)
+            .with_marker(location.span.offset);
+        let method = namespace
+            .records
+            .iter()
+            .find_map(|record| match record.payload {
+                AmlNamespaceNodePayload::Method(method)
+                    if method.body.block_index == location.block_index
+                        && method.body.span.contains(location.span.offset) =>
+                {
+                    Some((record.descriptor.path, method))
+                }
+                _ => None,
+            });
+        let Some((path, method)) = method else {
+            return disassembler.write_block(out);
+        };
+
+        out.write_str("Method (")?;
+        write_path(out, path)?;
+        write!(out, ", {}, ", method.arg_count)?;
+        write_serialization(out, method.serialization, method.sync_level)?;
+        out.write_str(")\n{\n")?;
+        disassembler.write_span_at(method.body.span, path, 1, out)?;
+        out.write_str("}\n")
+    }
+
+    fn write_span_at(
+        &self,
+        span: AmlBytecodeSpan,
+        scope: AmlResolvedNamePath,
+        depth: usize,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        let start = span.offset as usize;
+        let end = span.end_offset() as usize;
+        if start > end || end > self.block.bytes.len() {
+            return writeln!(out, "/* span 0x{start:04X}..0x{end:04X} outside block */");
+        }
+        self.writer(out).term_list(start, end, depth, scope)
+    }
+
+    fn writer<'d, 'o>(
+        &'d self,
+        out: &'o mut dyn Write,
+    ) -> AmlTextWriter<'d, 'o, 'records, 'blocks> {
+        AmlTextWriter {
+            disassembler: self,
+            out,
+            marked: false,
+        }
+    }
+}
+
+impl fmt::Display for AmlDisassembler<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_block(f)
+    }
+}
+
+/// Renders a loaded namespace as an indented tree of object kinds and payload details.
+#[derive(Debug, Clone, Copy)]
+pub struct AmlNamespaceDump<'records, 'blocks>(pub AmlLoadedNamespace<'records, 'blocks>);
+
+impl AmlNamespaceDump<'_, '_> {
+    fn write_node(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        record: &AmlNamespaceLoadRecord,
+        depth: usize,
+    ) -> fmt::Result {
+        write_indent(f, depth)?;
+        match record.descriptor.path.last_segment() {
+            Some(segment) => write_segment(f, segment)?,
+            None => f.write_str("\\")?,
+        }
+        write!(f, " {:?}", record.descriptor.kind)?;
+        match record.payload {
+            AmlNamespaceNodePayload::None => {}
+            AmlNamespaceNodePayload::NameInteger(value) => write!(f, " = 0x{value:X}")?,
+            AmlNamespaceNodePayload::Method(method) => {
+                write!(f, " ({} args, ", method.arg_count)?;
+                write_serialization(f, method.serialization, method.sync_level)?;
+                f.write_str(")")?;
+            }
+            AmlNamespaceNodePayload::OpRegion(region) => {
+                f.write_str(" (")?;
+                write_address_space(f, region.space)?;
+                for value in [region.offset, region.length] {
+                    match value {
+                        Some(value) => write!(f, ", 0x{value:X}")?,
+                        None => f.write_str(", ?")?,
+                    }
+                }
+                f.write_str(")")?;
+            }
+            AmlNamespaceNodePayload::Field(field) => write!(
+                f,
+                " (bit 0x{:X}, width {})",
+                field.bit_offset, field.bit_width
+            )?,
+            AmlNamespaceNodePayload::Mutex(mutex) => {
+                write!(f, " (sync level {})", mutex.sync_level)?;
+            }
+        }
+        f.write_str("\n")?;
+
+        let id = record.descriptor.id;
+        for child in self
+            .0
+            .records
+            .iter()
+            .filter(|child| child.descriptor.parent == Some(id))
+        {
+            self.write_node(f, child, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AmlNamespaceDump<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for root in self
+            .0
+            .records
+            .iter()
+            .filter(|record| record.descriptor.parent.is_none())
+        {
+            self.write_node(f, root, 0)?;
+        }
+        Ok(())
+    }
+}
+
+/// Operand shapes of the fixed-layout opcodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AmlOperand {
+    Term,
+    SuperName,
+    Target,
+    NameString,
+    Byte,
+    Word,
+    DWord,
+    MatchOp,
+}
+
+/// Why one term stopped rendering.
+enum AmlTextFault {
+    Format,
+    Bytecode(AmlError),
+}
+
+impl From<fmt::Error> for AmlTextFault {
+    fn from(_: fmt::Error) -> Self {
+        Self::Format
+    }
+}
+
+impl From<AmlError> for AmlTextFault {
+    fn from(error: AmlError) -> Self {
+        Self::Bytecode(error)
+    }
+}
+
+type AmlTextResult<T> = Result<T, AmlTextFault>;
+
+struct AmlTextWriter<'d, 'o, 'records, 'blocks> {
+    disassembler: &'d AmlDisassembler<'records, 'blocks>,
+    out: &'o mut dyn Write,
+    marked: bool,
+}
+
+impl<'blocks> AmlTextWriter<'_, '_, '_, 'blocks> {
+    const fn bytes(&self) -> &'blocks [u8] {
+        self.disassembler.block.bytes
+    }
+
+    /// Returns the bytes of the object starting at `at`, bounded by the enclosing `end`.
+    fn object(&self, at: usize, end: usize) -> AmlTextResult<&'blocks [u8]> {
+        Ok(self.bytes().get(at..end).ok_or_else(AmlError::truncated)?)
+    }
+
+    fn byte(&self, at: usize) -> AmlTextResult<u8> {
+        Ok(*self.bytes().get(at).ok_or_else(AmlError::truncated)?)
+    }
+
+    fn le(&self, at: usize, len: usize) -> AmlTextResult<u64> {
+        let raw = self
+            .bytes()
+            .get(at..at + len)
+            .ok_or_else(AmlError::truncated)?;
+        Ok(raw
+            .iter()
+            .rev()
+            .fold(0_u64, |value, byte| (value << 8) | u64::from(*byte)))
+    }
+
+    /// Writes every statement in `start..end`, one per line at `depth`.
+    fn term_list(
+        &mut self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+    ) -> fmt::Result {
+        let mut at = start;
+        while at < end {
+            write_indent(self.out, depth)?;
+            match self.term(at, end, depth, scope, true) {
+                Ok(consumed) => {
+                    self.mark(at, consumed)?;
+                    self.out.write_str("\n")?;
+                    at += consumed;
+                }
+                Err(AmlTextFault::Bytecode(error)) => {
+                    return writeln!(self.out, "/* {} at 0x{at:04X} */", error.detail);
+                }
+                Err(AmlTextFault::Format) => return Err(fmt::Error),
+            }
+        }
+        Ok(())
+    }
+
+    fn mark(&mut self, at: usize, consumed: usize) -> fmt::Result {
+        let Some(marker) = self.disassembler.marker else {
+            return Ok(());
+        };
+        let marker = marker as usize;
+        if self.marked || marker < at || marker >= at + consumed {
+            return Ok(());
+        }
+        self.marked = true;
+        write!(self.out, " // <-- 0x{marker:04X}")
+    }
+
+    /// Writes one term starting at `at` and returns its encoded length.
+    ///
+    /// `invoke` is false where the grammar wants a `SuperName` or package element, in which case a
+    /// method name is a reference rather than a call.
+    fn term(
+        &mut self,
+        at: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+        invoke: bool,
+    ) -> AmlTextResult<usize> {
+        if at >= end {
+            return Err(AmlError::truncated().into());
+        }
+        let opcode = self.byte(at)?;
+        match opcode {
+            0x00 => self.keyword("Zero"),
+            0x01 => self.keyword("One"),
+            0xff => self.keyword("Ones"),
+            0x0a => self.integer(at + 1, 1).map(|len| 1 + len),
+            0x0b => self.integer(at + 1, 2).map(|len| 1 + len),
+            0x0c => self.integer(at + 1, 4).map(|len| 1 + len),
+            0x0e => self.integer(at + 1, 8).map(|len| 1 + len),
+            0x0d => self.string(at + 1, end).map(|len| 1 + len),
+            0x60..=0x67 => {
+                write!(self.out, "Local{}", opcode - 0x60)?;
+                Ok(1)
+            }
+            0x68..=0x6e => {
+                write!(self.out, "Arg{}", opcode - 0x68)?;
+                Ok(1)
+            }
+            0x06 => self.fixed(
+                "Alias",
+                at,
+                1,
+                end,
+                depth,
+                scope,
+                &[AmlOperand::NameString, AmlOperand::NameString],
+            ),
+            0x08 => self.fixed(
+                "Name",
+                at,
+                1,
+                end,
+                depth,
+                scope,
+                &[AmlOperand::NameString, AmlOperand::Term],
+            ),
+            0x10 => self.scope_op(at, end, depth, scope),
+            0x11 => self.buffer(at, end, depth, scope),
+            0x12 | 0x13 => self.package(at, end, depth, scope),
+            0x14 => self.method(at, end, depth, scope),
+            0x15 => self.external(at, end),
+            0x5b => self.ext_term(at, end, depth, scope),
+            0x92 if matches!(self.bytes().get(at + 1), Some(0x93..=0x95)) => {
+                let keyword = match self.byte(at + 1)? {
+                    0x93 => "LNotEqual",
+                    0x94 => "LLessEqual",
+                    _ => "LGreaterEqual",
+                };
+                self.fixed(
+                    keyword,
+                    at,
+                    2,
+                    end,
+                    depth,
+                    scope,
+                    &[AmlOperand::Term, AmlOperand::Term],
+                )
+            }
+            0xa0 | 0xa2 => {
+                let keyword = if opcode == 0xa0 { "If" } else { "While" };
+                let (body, object_end) = self.pkg(at, 1, end)?;
+                write!(self.out, "{keyword} (")?;
+                let predicate = self.term(body, object_end, depth, scope, true)?;
+                self.out.write_str(")")?;
+                self.block(body + predicate, object_end, depth, scope)?;
+                Ok(object_end - at)
+            }
+            0xa1 => {
+                let (body, object_end) = self.pkg(at, 1, end)?;
+                self.out.write_str("Else")?;
+                self.block(body, object_end, depth, scope)?;
+                Ok(object_end - at)
+            }
+            b'\\' | b'^' | b'_' | b'A'..=b'Z' | 0x2e | 0x2f => {
+                self.name_term(at, end, depth, scope, invoke)
+            }
+            other => {
+                let (keyword, operands) =
+                    simple_op(other).ok_or_else(AmlError::invalid_bytecode)?;
+                self.fixed(keyword, at, 1, end, depth, scope, operands)
+            }
+        }
+    }
+
+    fn ext_term(
+        &mut self,
+        at: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+    ) -> AmlTextResult<usize> {
+        let opcode = self.byte(at + 1)?;
+        match opcode {
+            0x80 => {
+                let header = decode_opregion_header(self.object(at, end)?)?;
+                self.out.write_str("OperationRegion (")?;
+                write_encoded_name(self.out, header.name)?;
+                self.out.write_str(", ")?;
+                write_address_space(self.out, header.space)?;
+                self.out.write_str(", ")?;
+                let consumed = self.operands(
+                    at + header.operands,
+                    end,
+                    depth,
+                    scope,
+                    &[AmlOperand::Term, AmlOperand::Term],
+                )?;
+                self.out.write_str(")")?;
+                Ok(header.operands + consumed)
+            }
+            0x81 | 0x86 | 0x87 => self.field_op(opcode, at, end, depth, scope),
+            0x82..=0x85 => self.scoped_object(opcode, at, end, depth, scope),
+            other => {
+                let (keyword, operands) =
+                    simple_ext_op(other).ok_or_else(AmlError::invalid_bytecode)?;
+                self.fixed(keyword, at, 2, end, depth, scope, operands)
+            }
+        }
+    }
+
+    /// Writes a fixed-layout opcode of `opcode_len` bytes followed by `operands`.
+    #[allow(clippy::too_many_arguments)]
+    fn fixed(
+        &mut self,
+        keyword: &str,
+        at: usize,
+        opcode_len: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+        operands: &[AmlOperand],
+    ) -> AmlTextResult<usize> {
+        self.out.write_str(keyword)?;
+        if operands.is_empty() {
+            return Ok(opcode_len);
+        }
+        self.out.write_str(" (")?;
+        let consumed = self.operands(at + opcode_len, end, depth, scope, operands)?;
+        self.out.write_str(")")?;
+        Ok(opcode_len + consumed)
+    }
+
+    /// Writes a comma-separated operand list; null targets are left empty, trailing ones dropped.
+    fn operands(
+        &mut self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+        operands: &[AmlOperand],
+    ) -> AmlTextResult<usize> {
+        let mut at = start;
+        let mut separators = 0_usize;
+        for (index, operand) in operands.iter().enumerate() {
+            if index != 0 {
+                separators += 1;
+            }
+            if *operand == AmlOperand::Target && self.byte(at)? == 0x00 {
+                at += 1;
+                continue;
+            }
+            for _ in 0..separators {
+                self.out.write_str(", ")?;
+            }
+            separators = 0;
+            at += match operand {
+                AmlOperand::Term => self.term(at, end, depth, scope, true)?,
+                AmlOperand::SuperName | AmlOperand::Target => {
+                    self.term(at, end, depth, scope, false)?
+                }
+                AmlOperand::NameString => self.name(at)?,
+                AmlOperand::Byte => self.integer(at, 1)?,
+                AmlOperand::Word => self.integer(at, 2)?,
+                AmlOperand::DWord => self.integer(at, 4)?,
+                AmlOperand::MatchOp => {
+                    self.out.write_str(match self.byte(at)? {
+                        0 => "MTR",
+                        1 => "MEQ",
+                        2 => "MLE",
+                        3 => "MLT",
+                        4 => "MGE",
+                        5 => "MGT",
+                        _ => return Err(AmlError::invalid_bytecode().into()),
+                    })?;
+                    1
+                }
+            };
+        }
+        Ok(at - start)
+    }
+
+    fn keyword(&mut self, keyword: &str) -> AmlTextResult<usize> {
+        self.out.write_str(keyword)?;
+        Ok(1)
+    }
+
+    fn integer(&mut self, at: usize, len: usize) -> AmlTextResult<usize> {
+        let value = self.le(at, len)?;
+        write!(self.out, "0x{value:0width$X}", width = len * 2)?;
+        Ok(len)
+    }
+
+    fn string(&mut self, at: usize, end: usize) -> AmlTextResult<usize> {
+        let bytes = self.bytes().get(at..end).ok_or_else(AmlError::truncated)?;
+        let len = bytes
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or_else(AmlError::truncated)?;
+        self.out.write_str("\"")?;
+        for byte in &bytes[..len] {
+            match byte {
+                b'"' => self.out.write_str("\\\"")?,
+                b'\\' => self.out.write_str("\\\\")?,
+                0x20..=0x7e => self.out.write_char(char::from(*byte))?,
+                other => write!(self.out, "\\x{other:02X}")?,
+            }
+        }
+        self.out.write_str("\"")?;
+        Ok(len + 1)
+    }
+
+    fn parse_name(&self, at: usize) -> AmlTextResult<AmlEncodedNameString<'blocks>> {
+        let bytes = self.bytes().get(at..).ok_or_else(AmlError::truncated)?;
+        Ok(AmlEncodedNameString::parse(bytes)?)
+    }
+
+    fn name(&mut self, at: usize) -> AmlTextResult<usize> {
+        let name = self.parse_name(at)?;
+        write_encoded_name(self.out, name)?;
+        Ok(usize::from(name.consumed_bytes))
+    }
+
+    /// Writes a name reference, expanding it into a call when it resolves to a method.
+    fn name_term(
+        &mut self,
+        at: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+        invoke: bool,
+    ) -> AmlTextResult<usize> {
+        let encoded = self.parse_name(at)?;
+        let arg_count = if invoke {
+            self.disassembler.namespace.and_then(|namespace| {
+                let path = namespace.resolve_lookup_path(scope, encoded).ok()?;
+                match namespace.record_by_path(path)?.payload {
+                    AmlNamespaceNodePayload::Method(method) => Some(method.arg_count),
+                    _ => None,
+                }
+            })
+        } else {
+            None
+        };
+        let consumed = self.name(at)?;
+        let Some(arg_count) = arg_count else {
+            return Ok(consumed);
+        };
+        self.out.write_str(" (")?;
+        let args = [AmlOperand::Term; 7];
+        let args_consumed = self.operands(
+            at + consumed,
+            end,
+            depth,
+            scope,
+            &args[..usize::from(arg_count.min(7))],
+        )?;
+        self.out.write_str(")")?;
+        Ok(consumed + args_consumed)
+    }
+
+    /// Parses the package length after the `opcode_len`-byte opcode at `at`, returning where its
+    /// contents start and end.
+    fn pkg(&self, at: usize, opcode_len: usize, end: usize) -> AmlTextResult<(usize, usize)> {
+        let (contents, object_end) = decode_pkg_extent(self.object(at, end)?, opcode_len)?;
+        Ok((at + contents, at + object_end))
+    }
+
+    /// Writes `{ term list }` on the lines below the current statement.
+    fn block(
+        &mut self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+    ) -> AmlTextResult<()> {
+        self.open(depth)?;
+        self.term_list(start, end, depth + 1, scope)?;
+        write_indent(self.out, depth)?;
+        self.out.write_str("}")?;
+        Ok(())
+    }
+
+    fn open(&mut self, depth: usize) -> fmt::Result {
+        self.out.write_str("\n")?;
+        write_indent(self.out, depth)?;
+        self.out.write_str("{\n")
+    }
+
+    fn scope_op(
+        &mut self,
+        at: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+    ) -> AmlTextResult<usize> {
+        let object = decode_named_object(self.object(at, end)?, 1)?;
+        let path = scope.resolve(object.name).unwrap_or(scope);
+        self.out.write_str("Scope (")?;
+        write_encoded_name(self.out, object.name)?;
+        self.out.write_str(")")?;
+        self.block(at + object.after_name, at + object.end, depth, path)?;
+        Ok(object.end)
+    }
+
+    fn method(
+        &mut self,
+        at: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+    ) -> AmlTextResult<usize> {
+        let header = decode_method_header(self.object(at, end)?)?;
+        let path = scope.resolve(header.name).unwrap_or(scope);
+        self.out.write_str("Method (")?;
+        write_encoded_name(self.out, header.name)?;
+        write!(self.out, ", {}, ", header.arg_count)?;
+        write_serialization(self.out, header.serialization, header.sync_level)?;
+        self.out.write_str(")")?;
+        self.block(at + header.body, at + header.end, depth, path)?;
+        Ok(header.end)
+    }
+
+    fn external(&mut self, at: usize, end: usize) -> AmlTextResult<usize> {
+        let external = decode_external(self.object(at, end)?)?;
+        self.out.write_str("External (")?;
+        write_encoded_name(self.out, external.name)?;
+        self.out.write_str(", ")?;
+        match object_type_name(external.object_type) {
+            Some(name) => self.out.write_str(name)?,
+            None => write!(self.out, "0x{:02X}", external.object_type)?,
+        }
+        self.out.write_str(")")?;
+        if external.object_type == 8 {
+            write!(self.out, " // {} Arguments", external.arg_count)?;
+        }
+        Ok(external.end)
+    }
+
+    fn buffer(
+        &mut self,
+        at: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+    ) -> AmlTextResult<usize> {
+        let (start, object_end) = self.pkg(at, 1, end)?;
+        self.out.write_str("Buffer (")?;
+        let size_len = self.term(start, object_end, depth, scope, true)?;
+        self.out.write_str(")")?;
+        let data = self
+            .bytes()
+            .get(start + size_len..object_end)
+            .ok_or_else(AmlError::truncated)?;
+        if data.is_empty() {
+            self.out.write_str(" {}")?;
+            return Ok(object_end - at);
+        }
+
+        let out = &mut *self.out;
+        out.write_str("\n")?;
+        write_indent(out, depth)?;
+        out.write_str("{\n")?;
+        let line_count = data.len().div_ceil(8);
+        for (line, chunk) in data.chunks(8).enumerate() {
+            write_indent(out, depth + 1)?;
+            for (index, byte) in chunk.iter().enumerate() {
+                if index != 0 {
+                    out.write_str(" ")?;
+                }
+                write!(out, "0x{byte:02X}")?;
+                if line + 1 != line_count || index + 1 != chunk.len() {
+                    out.write_str(",")?;
+                }
+            }
+            out.write_str("\n")?;
+        }
+        write_indent(out, depth)?;
+        out.write_str("}")?;
+        Ok(object_end - at)
+    }
+
+    fn package(
+        &mut self,
+        at: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+    ) -> AmlTextResult<usize> {
+        let (start, object_end) = self.pkg(at, 1, end)?;
+        let elements = if self.byte(at)? == 0x12 {
+            self.out.write_str("Package (")?;
+            self.integer(start, 1)?
+        } else {
+            self.out.write_str("VarPackage (")?;
+            self.term(start, object_end, depth, scope, true)?
+        };
+        self.out.write_str(")")?;
+        self.open(depth)?;
+        let mut cursor = start + elements;
+        while cursor < object_end {
+            write_indent(self.out, depth + 1)?;
+            cursor += self.term(cursor, object_end, depth + 1, scope, false)?;
+            if cursor < object_end {
+                self.out.write_str(",")?;
+            }
+            self.out.write_str("\n")?;
+        }
+        write_indent(self.out, depth)?;
+        self.out.write_str("}")?;
+        Ok(object_end - at)
+    }
+
+    /// `Device`, `Processor`, `PowerResource` and `ThermalZone`: named scopes with fixed data.
+    fn scoped_object(
+        &mut self,
+        opcode: u8,
+        at: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+    ) -> AmlTextResult<usize> {
+        let (keyword, operands): (&str, &[AmlOperand]) = match opcode {
+            0x82 => ("Device", &[]),
+            0x83 => (
+                "Processor",
+                &[AmlOperand::Byte, AmlOperand::DWord, AmlOperand::Byte],
+            ),
+            0x84 => ("PowerResource", &[AmlOperand::Byte, AmlOperand::Word]),
+            _ => ("ThermalZone", &[]),
+        };
+        let object = decode_named_object(self.object(at, end)?, 2)?;
+        let object_end = at + object.end;
+        let path = scope.resolve(object.name).unwrap_or(scope);
+        write!(self.out, "{keyword} (")?;
+        write_encoded_name(self.out, object.name)?;
+        let mut cursor = at + object.after_name;
+        if !operands.is_empty() {
+            self.out.write_str(", ")?;
+            cursor += self.operands(cursor, object_end, depth, scope, operands)?;
+        }
+        self.out.write_str(")")?;
+        self.block(cursor, object_end, depth, path)?;
+        Ok(object_end - at)
+    }
+
+    /// `Field`, `IndexField` and `BankField` with their field-unit lists.
+    fn field_op(
+        &mut self,
+        opcode: u8,
+        at: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+    ) -> AmlTextResult<usize> {
+        let keyword = match opcode {
+            0x81 => "Field",
+            0x86 => "IndexField",
+            _ => "BankField",
+        };
+        let header = decode_field_list_header(self.object(at, end)?)?;
+        let object_end = at + header.end;
+        write!(self.out, "{keyword} (")?;
+        write_encoded_name(self.out, header.first)?;
+        if let Some(second) = header.second {
+            self.out.write_str(", ")?;
+            write_encoded_name(self.out, second)?;
+        }
+        let mut cursor = at + header.after_names;
+        if opcode == 0x87 {
+            self.out.write_str(", ")?;
+            cursor += self.term(cursor, object_end, depth, scope, true)?;
+        }
+        let flags = self.byte(cursor)?;
+        cursor += 1;
+        self.out.write_str(", ")?;
+        write_access_kind(self.out, decode_field_access(flags))?;
+        self.out.write_str(if flags & 0x10 != 0 {
+            ", Lock, "
+        } else {
+            ", NoLock, "
+        })?;
+        self.out.write_str(match decode_field_update(flags) {
+            AmlFieldUpdateKind::Preserve => "Preserve",
+            AmlFieldUpdateKind::WriteAsOnes => "WriteAsOnes",
+            AmlFieldUpdateKind::WriteAsZeros => "WriteAsZeros",
+        })?;
+        self.out.write_str(")")?;
+        self.open(depth)?;
+        while cursor < object_end {
+            write_indent(self.out, depth + 1)?;
+            match self.field_element(cursor, object_end, depth + 1, scope) {
+                Ok(consumed) => cursor += consumed,
+                Err(AmlTextFault::Bytecode(error)) => {
+                    writeln!(self.out, "/* {} at 0x{cursor:04X} */", error.detail)?;
+                    break;
+                }
+                Err(AmlTextFault::Format) => return Err(AmlTextFault::Format),
+            }
+            if cursor < object_end {
+                self.out.write_str(",")?;
+            }
+            self.out.write_str("\n")?;
+        }
+        write_indent(self.out, depth)?;
+        self.out.write_str("}")?;
+        Ok(object_end - at)
+    }
+
+    fn field_element(
+        &mut self,
+        at: usize,
+        end: usize,
+        depth: usize,
+        scope: AmlResolvedNamePath,
+    ) -> AmlTextResult<usize> {
+        let (element, consumed) = decode_field_element(self.object(at, end)?)?;
+        match element {
+            AmlFieldElement::Reserved { bits } => write!(self.out, ", {bits}")?,
+            AmlFieldElement::AccessAs { access, attrib } => {
+                self.out.write_str("AccessAs (")?;
+                write_access_kind(self.out, access)?;
+                write!(self.out, ", 0x{attrib:02X})")?;
+            }
+            AmlFieldElement::Connection(name) => {
+                self.out.write_str("Connection (")?;
+                write_encoded_name(self.out, name)?;
+                self.out.write_str(")")?;
+            }
+            AmlFieldElement::ConnectionBuffer => {
+                self.out.write_str("Connection (")?;
+                self.term(at + 1, at + consumed, depth, scope, false)?;
+                self.out.write_str(")")?;
+            }
+            AmlFieldElement::ExtendedAccessAs {
+                access,
+                attrib,
+                length,
+            } => {
+                self.out.write_str("AccessAs (")?;
+                write_access_kind(self.out, access)?;
+                write!(self.out, ", 0x{attrib:02X}, 0x{length:02X})")?;
+            }
+            AmlFieldElement::Named { name, bits } => {
+                write_segment(self.out, name)?;
+                write!(self.out, ", {bits}")?;
+            }
+        }
+        Ok(consumed)
+    }
+}
+
+const fn simple_op(opcode: u8) -> Option<(&'static str, &'static [AmlOperand])> {
+    const T: AmlOperand = AmlOperand::Term;
+    const S: AmlOperand = AmlOperand::SuperName;
+    const D: AmlOperand = AmlOperand::Target;
+    const N: AmlOperand = AmlOperand::NameString;
+    const M: AmlOperand = AmlOperand::MatchOp;
+    Some(match opcode {
+        0x70 => ("Store", &[T, S]),
+        0x71 => ("RefOf", &[S]),
+        0x72 => ("Add", &[T, T, D]),
+        0x73 => ("Concatenate", &[T, T, D]),
+        0x74 => ("Subtract", &[T, T, D]),
+        0x75 => ("Increment", &[S]),
+        0x76 => ("Decrement", &[S]),
+        0x77 => ("Multiply", &[T, T, D]),
+        0x78 => ("Divide", &[T, T, D, D]),
+        0x79 => ("ShiftLeft", &[T, T, D]),
+        0x7a => ("ShiftRight", &[T, T, D]),
+        0x7b => ("And", &[T, T, D]),
+        0x7c => ("NAnd", &[T, T, D]),
+        0x7d => ("Or", &[T, T, D]),
+        0x7e => ("NOr", &[T, T, D]),
+        0x7f => ("XOr", &[T, T, D]),
+        0x80 => ("Not", &[T, D]),
+        0x81 => ("FindSetLeftBit", &[T, D]),
+        0x82 => ("FindSetRightBit", &[T, D]),
+        0x83 => ("DerefOf", &[T]),
+        0x84 => ("ConcatenateResTemplate", &[T, T, D]),
+        0x85 => ("Mod", &[T, T, D]),
+        0x86 => ("Notify", &[S, T]),
+        0x87 => ("SizeOf", &[S]),
+        0x88 => ("Index", &[T, T, D]),
+        0x89 => ("Match", &[T, M, T, M, T, T]),
+        0x8a => ("CreateDWordField", &[T, T, N]),
+        0x8b => ("CreateWordField", &[T, T, N]),
+        0x8c => ("CreateByteField", &[T, T, N]),
+        0x8d => ("CreateBitField", &[T, T, N]),
+        0x8e => ("ObjectType", &[S]),
+        0x8f => ("CreateQWordField", &[T, T, N]),
+        0x90 => ("LAnd", &[T, T]),
+        0x91 => ("LOr", &[T, T]),
+        0x92 => ("LNot", &[T]),
+        0x93 => ("LEqual", &[T, T]),
+        0x94 => ("LGreater", &[T, T]),
+        0x95 => ("LLess", &[T, T]),
+        0x96 => ("ToBuffer", &[T, D]),
+        0x97 => ("ToDecimalString", &[T, D]),
+        0x98 => ("ToHexString", &[T, D]),
+        0x99 => ("ToInteger", &[T, D]),
+        0x9c => ("ToString", &[T, T, D]),
+        0x9d => ("CopyObject", &[T, S]),
+        0x9e => ("Mid", &[T, T, T, D]),
+        0x9f => ("Continue", &[]),
+        0xa3 => ("Noop", &[]),
+        0xa4 => ("Return", &[T]),
+        0xa5 => ("Break", &[]),
+        0xcc => ("BreakPoint", &[]),
+        _ => return None,
+    })
+}
+
+const fn simple_ext_op(opcode: u8) -> Option<(&'static str, &'static [AmlOperand])> {
+    const T: AmlOperand = AmlOperand::Term;
+    const S: AmlOperand = AmlOperand::SuperName;
+    const D: AmlOperand = AmlOperand::Target;
+    const N: AmlOperand = AmlOperand::NameString;
+    Some(match opcode {
+        0x01 => ("Mutex", &[N, AmlOperand::Byte]),
+        0x02 => ("Event", &[N]),
+        0x12 => ("CondRefOf", &[S, D]),
+        0x13 => ("CreateField", &[T, T, T, N]),
+        0x1f => ("LoadTable", &[T, T, T, T, T, T]),
+        0x20 => ("Load", &[N, D]),
+        0x21 => ("Stall", &[T]),
+        0x22 => ("Sleep", &[T]),
+        0x23 => ("Acquire", &[S, AmlOperand::Word]),
+        0x24 => ("Signal", &[S]),
+        0x25 => ("Wait", &[S, T]),
+        0x26 => ("Reset", &[S]),
+        0x27 => ("Release", &[S]),
+        0x28 => ("FromBCD", &[T, D]),
+        0x29 => ("ToBCD", &[T, D]),
+        0x2a => ("Unload", &[S]),
+        0x30 => ("Revision", &[]),
+        0x31 => ("Debug", &[]),
+        0x32 => ("Fatal", &[AmlOperand::Byte, AmlOperand::DWord, T]),
+        0x33 => ("Timer", &[]),
+        0x88 => ("DataTableRegion", &[N, T, T, T]),
+        _ => return None,
+    })
+}
+
+const fn object_type_name(object_type: u8) -> Option<&'static str> {
+    Some(match object_type {
+        0 => "UnknownObj",
+        1 => "IntObj",
+        2 => "StrObj",
+        3 => "BuffObj",
+        4 => "PkgObj",
+        5 => "FieldUnitObj",
+        6 => "DeviceObj",
+        7 => "EventObj",
+        8 => "MethodObj",
+        9 => "MutexObj",
+        10 => "OpRegionObj",
+        11 => "PowerResObj",
+        12 => "ProcessorObj",
+        13 => "ThermalZoneObj",
+        14 => "BuffFieldObj",
+        15 => "DDBHandleObj",
+        _ => return None,
+    })
+}
+
+fn write_indent(out: &mut dyn Write, depth: usize) -> fmt::Result {
+    for _ in 0..depth {
+        out.write_str("    ")?;
+    }
+    Ok(())
+}
+
+/// Segment bytes with trailing `_` padding trimmed, the way ASL spells names.
+const fn trimmed_segment(segment: AmlNameSeg) -> ([u8; 4], usize) {
+    let bytes = segment.bytes();
+    let mut len = 4;
+    while len > 1 && bytes[len - 1] == b'_' {
+        len -= 1;
+    }
+    (bytes, len)
+}
+
+fn write_segment(out: &mut dyn Write, segment: AmlNameSeg) -> fmt::Result {
+    let (bytes, len) = trimmed_segment(segment);
+    out.write_str(ascii(&bytes[..len]))
+}
+
+fn write_path(out: &mut dyn Write, path: AmlResolvedNamePath) -> fmt::Result {
+    let mut text = [0_u8; AML_MAX_PATH_TEXT_BYTES];
+    let len = path.write_text(&mut text).map_err(|_| fmt::Error)?;
+    out.write_str(ascii(&text[..len]))
+}
+
+fn write_encoded_name(out: &mut dyn Write, name: AmlEncodedNameString<'_>) -> fmt::Result {
+    let mut spelled = [0_u8; AML_MAX_PATH_TEXT_BYTES + 8];
+    let len = spell_encoded_name(name, &mut spelled);
+    out.write_str(ascii(&spelled[..len]))
+}
+
+/// Spells an encoded name string with its `\` or `^` prefixes; returns the length written.
+fn spell_encoded_name(name: AmlEncodedNameString<'_>, out: &mut [u8]) -> usize {
+    let mut cursor = 0_usize;
+    let mut push = |byte: u8| {
+        if let Some(slot) = out.get_mut(cursor) {
+            *slot = byte;
+            cursor += 1;
+        }
+    };
+    match name.anchor {
+        AmlNameAnchor::Root => push(b'\\'),
+        AmlNameAnchor::ParentPrefix => (0..name.parent_prefixes).for_each(|_| push(b'^')),
+        AmlNameAnchor::Local => {}
+    }
+    for index in 0..name.segment_count {
+        let Some(segment) = name.segment(index) else {
+            break;
+        };
+        if index != 0 {
+            push(b'.');
+        }
+        let (bytes, len) = trimmed_segment(segment);
+        bytes[..len].iter().for_each(|byte| push(*byte));
+    }
+    cursor
+}
+
+/// Writes a header identity field as a quoted string, dropping NUL padding.
+fn write_quoted(out: &mut dyn Write, bytes: &[u8]) -> fmt::Result {
+    out.write_str("\"")?;
+    for byte in bytes.iter().filter(|byte| **byte != 0) {
+        if byte.is_ascii_graphic() || *byte == b' ' {
+            out.write_char(char::from(*byte))?;
+        } else {
+            write!(out, "\\x{byte:02X}")?;
+        }
+    }
+    out.write_str("\"")
+}
+
+fn write_serialization(
+    out: &mut dyn Write,
+    serialization: AmlMethodSerialization,
+    sync_level: u8,
+) -> fmt::Result {
+    match serialization {
+        AmlMethodSerialization::Serialized => out.write_str("Serialized")?,
+        AmlMethodSerialization::NotSerialized => out.write_str("NotSerialized")?,
+    }
+    if sync_level != 0 {
+        write!(out, ", {sync_level}")?;
+    }
+    Ok(())
+}
+
+fn write_access_kind(out: &mut dyn Write, access: AmlFieldAccessKind) -> fmt::Result {
+    out.write_str(match access {
+        AmlFieldAccessKind::Any => "AnyAcc",
+        AmlFieldAccessKind::Byte => "ByteAcc",
+        AmlFieldAccessKind::Word => "WordAcc",
+        AmlFieldAccessKind::DWord => "DWordAcc",
+        AmlFieldAccessKind::QWord => "QWordAcc",
+        AmlFieldAccessKind::Buffer => "BufferAcc",
+    })
+}
+
+fn write_address_space(out: &mut dyn Write, space: AmlAddressSpaceId) -> fmt::Result {
+    out.write_str(match space {
+        AmlAddressSpaceId::SystemMemory => "SystemMemory",
+        AmlAddressSpaceId::SystemIo => "SystemIO",
+        AmlAddressSpaceId::PciConfig => "PCI_Config",
+        AmlAddressSpaceId::EmbeddedControl => "EmbeddedControl",
+        AmlAddressSpaceId::SmBus => "SMBus",
+        AmlAddressSpaceId::Cmos => "SystemCMOS",
+        AmlAddressSpaceId::PciBarTarget => "PciBarTarget",
+        AmlAddressSpaceId::Ipmi => "IPMI",
+        AmlAddressSpaceId::Gpio => "GeneralPurposeIo",
+        AmlAddressSpaceId::GenericSerialBus => "GenericSerialBus",
+        AmlAddressSpaceId::PlatformCommChannel => "PCC",
+        AmlAddressSpaceId::FunctionalFixedHardware => "FFixedHW",
+        AmlAddressSpaceId::Oem(value) => return write!(out, "0x{value:02X}"),
+    })
+}
+
+/// Name bytes are validated ASCII by the time they get here.
+fn ascii(bytes: &[u8]) -> &str {
+    core::str::from_utf8(bytes).unwrap_or("????")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aml::{
+        AmlDefinitionBlockSet,
+        AmlNamespaceLoadPlan,
+    };
+    use crate::pal::hal::acpi::Dsdt;
+    use core::mem::MaybeUninit;
+    use std::boxed::Box;
+    use std::string::String;
+    use std::vec::Vec;
+
+    fn pkg(opcode: &[u8], payload: &[u8]) -> Vec<u8> {
+        let length = u8::try_from(payload.len() + 1)
+            .ok()
+            .filter(|length| *length <= 0x3f)
+            .expect("payload should fit a one-byte package length");
+        let mut bytes = opcode.to_vec();
+        bytes.push(length);
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    fn definition_block(payload: &[u8]) -> AmlDefinitionBlock<'static> {
+        let mut table = Vec::from([0_u8; 36]);
+        table[0..4].copy_from_slice(b"DSDT");
+        let length = u32::try_from(36 + payload.len()).expect("table should fit");
+        table[4..8].copy_from_slice(&length.to_le_bytes());
+        table[8] = 2;
+        table[10..16].copy_from_slice(b"FUSION");
+        table[16..24].copy_from_slice(b"AMLDASM ");
+        table.extend_from_slice(payload);
+        let checksum =
+            (!table.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        table[9] = checksum;
+        let leaked = Box::leak(table.into_boxed_slice());
+        AmlDefinitionBlock::from_dsdt(Dsdt::parse(leaked).unwrap()).unwrap()
+    }
+
+    /// Scope (\_SB) with a name, an EC region and field, and two methods calling each other.
+    fn sample_block() -> AmlDefinitionBlock<'static> {
+        let mut body = vec![0x08, b'F', b'O', b'O', b'0', 0x01];
+        body.extend([
+            0x5B, 0x80, b'E', b'C', b'O', b'R', 0x03, 0x0A, 0x10, 0x0A, 0x20,
+        ]);
+        body.extend(pkg(
+            &[0x5B, 0x81],
+            &[
+                b'E', b'C', b'O', b'R', 0x01, b'S', b'T', b'0', b'0', 0x08, 0x00, 0x08, b'S', b'T',
+                b'0', b'1', 0x08,
+            ],
+        ));
+        // If (LEqual (Arg0, One)) { Return (Add (Arg0, Arg1)) } Else { Return (ADD2 (Arg1, Zero)) }
+        let mut add2 = vec![b'A', b'D', b'D', b'2', 0x02];
+        add2.extend(pkg(
+            &[0xA0],
+            &[0x93, 0x68, 0x01, 0xA4, 0x72, 0x68, 0x69, 0x00],
+        ));
+        add2.extend(pkg(&[0xA1], &[0xA4, b'A', b'D', b'D', b'2', 0x69, 0x00]));
+        body.extend(pkg(&[0x14], &add2));
+        // Store (ADD2 (One, 0x05), Local0) / Divide (Local0, 0x02, , Local1)
+        // Return (Package (0x02) { FOO0, "a\"b" })
+        let mut sta = vec![b'_', b'S', b'T', b'A', 0x08];
+        sta.extend([0x70, b'A', b'D', b'D', b'2', 0x01, 0x0A, 0x05, 0x60]);
+        sta.extend([0x78, 0x60, 0x0A, 0x02, 0x00, 0x61]);
+        sta.push(0xA4);
+        sta.extend(pkg(
+            &[0x12],
+            &[0x02, b'F', b'O', b'O', b'0', 0x0D, b'a', b'"', b'b', 0x00],
+        ));
+        body.extend(pkg(&[0x14], &sta));
+        let mut scope = vec![b'\\', b'_', b'S', b'B', b'_'];
+        scope.extend(body);
+        let mut payload = vec![0x10];
+        let len = u16::try_from(scope.len() + 2).expect("scope should fit");
+        let [low, high] = len.to_le_bytes();
+        payload.extend([0x40 | (low & 0x0f), (low >> 4) | (high << 4)]);
+        payload.extend(scope);
+        definition_block(&payload)
+    }
+
+    fn load(block: AmlDefinitionBlock<'static>) -> AmlLoadedNamespace<'static, 'static> {
+        let storage = Box::leak(Box::new(
+            [MaybeUninit::<AmlNamespaceLoadRecord>::uninit(); 16],
+        ));
+        AmlNamespaceLoadPlan::from_definition_blocks(AmlDefinitionBlockSet::new(block, &[]))
+            .load_into(storage)
+            .unwrap()
+    }
+
+    #[test]
+    fn disassembler_renders_definition_block_as_asl() {
+        let block = sample_block();
+        let namespace = load(block);
+        let text = std::format!("{}", AmlDisassembler::new(block).with_namespace(namespace));
+        assert_eq!(
+            text,
+            r#"DefinitionBlock ("", "DSDT", 2, "FUSION", "AMLDASM ", 0x00000000)
+{
+    Scope (\_SB)
+    {
+        Name (FOO0, One)
+        OperationRegion (ECOR, EmbeddedControl, 0x10, 0x20)
+        Field (ECOR, ByteAcc, NoLock, Preserve)
+        {
+            ST00, 8,
+            , 8,
+            ST01, 8
+        }
+        Method (ADD2, 2, NotSerialized)
+        {
+            If (LEqual (Arg0, One))
+            {
+                Return (Add (Arg0, Arg1))
+            }
+            Else
+            {
+                Return (ADD2 (Arg1, Zero))
+            }
+        }
+        Method (_STA, 0, Serialized)
+        {
+            Store (ADD2 (One, 0x05), Local0)
+            Divide (Local0, 0x02, , Local1)
+            Return (Package (0x02)
+            {
+                FOO0,
+                "a\"b"
+            })
+        }
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn disassembler_marks_code_locations_and_reports_bad_bytecode() {
+        let block = sample_block();
+        let namespace = load(block);
+        let mut path = AmlResolvedNamePath::parse_text("\\_SB_._STA").unwrap();
+        let Some(AmlNamespaceNodePayload::Method(method)) =
+            namespace.record_by_path(path).map(|record| record.payload)
+        else {
+            panic!("_STA should load as a method");
+        };
+        let divide = method.body.span.offset + 9;
+        let mut text = String::new();
+        AmlDisassembler::write_location(
+            namespace,
+            AmlCodeLocation {
+                block_index: 0,
+                span: AmlBytecodeSpan {
+                    offset: divide,
+                    length: 1,
+                },
+            },
+            &mut text,
+        )
+        .unwrap();
+        assert_eq!(
+            text.lines().take(4).collect::<Vec<_>>(),
+            [
+                "Method (\\_SB._STA, 0, Serialized)",
+                "{",
+                "    Store (ADD2 (One, 0x05), Local0)",
+                &std::format!("    Divide (Local0, 0x02, , Local1) // <-- 0x{divide:04X}"),
+            ]
+        );
+
+        // Return (One) followed by an opcode AML does not define.
+        let broken = definition_block(&[0xA4, 0x01, 0x02, 0xA3]);
+        path = AmlResolvedNamePath::root();
+        text.clear();
+        AmlDisassembler::new(broken)
+            .write_span(
+                AmlBytecodeSpan {
+                    offset: 0,
+                    length: 4,
+                },
+                path,
+                &mut text,
+            )
+            .unwrap();
+        assert_eq!(text, "Return (One)\n/* invalid aml bytecode at 0x0002 */\n");
+    }
+
+    #[test]
+    fn namespace_dump_prints_tree_with_payload_details() {
+        let namespace = load(sample_block());
+        let text = std::format!("{}", AmlNamespaceDump(namespace));
+        assert_eq!(
+            text,
+            "\\ Scope
+    _SB Scope
+        FOO0 Name = 0x1
+        ECOR OpRegion (EmbeddedControl, 0x10, 0x20)
+        ST00 Field (bit 0x0, width 8)
+        ST01 Field (bit 0x10, width 8)
+        ADD2 Method (2 args, NotSerialized)
+        _STA Method (0 args, Serialized)
+"
+        );
+    }
+}
@@ -85,6 +85,19 @@ impl AmlError {
         Self::new(AmlErrorKind::HostFailure, "aml host interaction failed")
     }
 
+    #[must_use]
+    pub const fn fatal() -> Self {
+        Self::new(AmlErrorKind::HostFailure, "aml fatal opcode executed")
+    }
+
+    #[must_use]
+    pub const fn sync_level() -> Self {
+        Self::new(
+            AmlErrorKind::InvalidState,
+            "aml sync level ordering violated",
+        )
+    }
+
     #[must_use]
     pub const fn overflow() -> Self {
         Self::new(AmlErrorKind::Overflow, "aml integer or buffer overflow")
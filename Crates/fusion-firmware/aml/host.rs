@@ -2,8 +2,14 @@
 
 use crate::aml::{
     AmlAccessWidth,
+    AmlDdbHandle,
+    AmlError,
     AmlNamespaceNodeId,
     AmlResult,
+    AmlTableLoad,
+    AmlTracer,
+    AmlWaitObject,
+    AmlWaitOutcome,
 };
 
 /// OSPM personality surface visible to AML.
@@ -16,11 +22,88 @@ pub trait AmlOspmInterface {
 pub trait AmlSleepHost {
     fn stall_us(&self, microseconds: u32) -> AmlResult<()>;
     fn sleep_ms(&self, milliseconds: u32) -> AmlResult<()>;
+
+    /// Monotonic 100-nanosecond tick count backing the `Timer` opcode.
+    ///
+    /// # Errors
+    ///
+    /// The default returns `unsupported` for hosts without a monotonic clock.
+    fn timer_100ns(&self) -> AmlResult<u64> {
+        Err(AmlError::unsupported())
+    }
+
+    /// Suspends the calling AML fiber until `object` may be available or `timeout_ms` elapses.
+    ///
+    /// `AML_WAIT_FOREVER` means no timeout. Hosts that cannot suspend keep the default, which
+    /// unwinds the evaluation as blocked so the caller can retry it later.
+    ///
+    /// # Errors
+    ///
+    /// Any error the host returns aborts the evaluation that was waiting.
+    fn wait(&self, object: AmlWaitObject, timeout_ms: u16) -> AmlResult<AmlWaitOutcome> {
+        let _ = (object, timeout_ms);
+        Ok(AmlWaitOutcome::Deferred)
+    }
+
+    /// Wakes fibers suspended on `object` after AML released or signaled it.
+    ///
+    /// # Errors
+    ///
+    /// Any error the host returns aborts the evaluation that released or signaled `object`.
+    fn wake(&self, object: AmlWaitObject) -> AmlResult<()> {
+        let _ = object;
+        Ok(())
+    }
 }
 
 /// Host-side notification sink.
 pub trait AmlNotifySink {
     fn notify(&self, source: AmlNamespaceNodeId, value: u8) -> AmlResult<()>;
+
+    /// Reports one AML `Fatal` event. Evaluation aborts after the host returns.
+    ///
+    /// # Errors
+    ///
+    /// Any error the host returns replaces the fatal error the evaluation unwinds with.
+    fn fatal(&self, fatal_type: u8, code: u32, argument: u64) -> AmlResult<()> {
+        let _ = (fatal_type, code, argument);
+        Ok(())
+    }
+}
+
+/// Host-side dynamic table loading surface behind `Load`, `LoadTable` and `Unload`.
+///
+/// Hosts typically resolve the table bytes, stage them in an `AmlTableRegistry`, and rebuild the
+/// namespace once the current evaluation returns.
+pub trait AmlTableHost {
+    /// Loads one definition block. `None` means `LoadTable` named no table in the firmware.
+    ///
+    /// # Errors
+    ///
+    /// The default returns `unsupported`. Any error the host returns aborts the `Load` or
+    /// `LoadTable` evaluation.
+    fn load_table(&self, request: AmlTableLoad<'_>) -> AmlResult<Option<AmlDdbHandle>> {
+        let _ = request;
+        Err(AmlError::unsupported())
+    }
+
+    /// Unloads the definition block `handle` refers to.
+    ///
+    /// # Errors
+    ///
+    /// The default returns `unsupported`. Any error the host returns aborts the `Unload`
+    /// evaluation.
+    fn unload_table(&self, handle: AmlDdbHandle) -> AmlResult<()> {
+        let _ = handle;
+        Err(AmlError::unsupported())
+    }
+}
+
+/// Host-side trace hookup consulted by the VM when `AmlVmConfig::enable_tracing` is set.
+pub trait AmlTraceHost {
+    fn tracer(&self) -> Option<&AmlTracer<'_>> {
+        None
+    }
 }
 
 /// Optional direct system-memory access surface.
@@ -49,7 +132,10 @@ pub trait AmlEmbeddedControllerHost {
 }
 
 /// Complete AML host envelope expected by the VM.
-pub trait AmlHost: AmlNotifySink + AmlOspmInterface + AmlSleepHost {}
+pub trait AmlHost:
+    AmlNotifySink + AmlOspmInterface + AmlSleepHost + AmlTableHost + AmlTraceHost
+{
+}
 
 /// Host envelope required for opregion and field execution.
 pub trait AmlRegionAccessHost:
@@ -7,6 +7,7 @@ use crate::aml::{
     AmlAddressSpaceId,
     AmlBytecodeSpan,
     AmlCodeLocation,
+    AmlDdbHandle,
     AmlDefinitionBlock,
     AmlEncodedNameString,
     AmlError,
@@ -18,6 +19,7 @@ use crate::aml::{
     AmlMethodDescriptor,
     AmlMethodKind,
     AmlMethodSerialization,
+    AmlMutexDescriptor,
     AmlNameSeg,
     AmlNamespace,
     AmlNamespaceLoadRecord,
@@ -30,6 +32,7 @@ use crate::aml::{
     AmlPkgLength,
     AmlResolvedNamePath,
     AmlResult,
+    AmlTableRegistry,
 };
 
 /// Borrowed definition-block set for one AML namespace.
@@ -37,6 +40,8 @@ use crate::aml::{
 pub struct AmlDefinitionBlockSet<'a> {
     pub dsdt: AmlDefinitionBlock<'a>,
     pub ssdts: &'a [AmlDefinitionBlock<'a>],
+    /// Blocks AML loaded at runtime, indexed after the SSDTs.
+    pub dynamic: AmlTableRegistry<'a>,
 }
 
 /// Namespace loading phase ordering.
@@ -57,9 +62,19 @@ pub struct AmlNamespaceLoadPlan<'a> {
 impl<'a> AmlDefinitionBlockSet<'a> {
     #[must_use]
     pub const fn new(dsdt: AmlDefinitionBlock<'a>, ssdts: &'a [AmlDefinitionBlock<'a>]) -> Self {
-        Self { dsdt, ssdts }
+        Self {
+            dsdt,
+            ssdts,
+            dynamic: AmlTableRegistry::new(&[]),
+        }
     }
 
+    #[must_use]
+    pub const fn with_dynamic_tables(self, dynamic: AmlTableRegistry<'a>) -> Self {
+        Self { dynamic, ..self }
+    }
+
+    /// Blocks present at bring-up: the DSDT plus every SSDT.
     #[must_use]
     pub const fn total_block_count(self) -> usize {
         1 + self.ssdts.len()
@@ -69,9 +84,22 @@ impl<'a> AmlDefinitionBlockSet<'a> {
     pub fn block(self, index: u16) -> Option<AmlDefinitionBlock<'a>> {
         match index {
             0 => Some(self.dsdt),
-            other => self.ssdts.get(usize::from(other - 1)).copied(),
+            other if usize::from(other) <= self.ssdts.len() => {
+                self.ssdts.get(usize::from(other - 1)).copied()
+            }
+            other => {
+                let slot = usize::from(other) - 1 - self.ssdts.len();
+                let handle = AmlDdbHandle(u16::try_from(slot).ok()?);
+                self.dynamic.table(handle).map(|table| table.block)
+            }
         }
     }
+
+    /// Block index code locations use for one dynamically loaded table.
+    #[must_use]
+    pub fn dynamic_block_index(self, handle: AmlDdbHandle) -> Option<u16> {
+        u16::try_from(1 + self.ssdts.len() + usize::from(handle.0)).ok()
+    }
 }
 
 impl<'a> AmlNamespaceLoadPlan<'a> {
@@ -163,6 +191,20 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
             )?;
         }
 
+        let blocks = self.plan.blocks;
+        for slot in blocks.dynamic.tables() {
+            let Some(table) = slot.get().filter(|table| table.loaded) else {
+                continue;
+            };
+            let block_index = blocks
+                .dynamic_block_index(table.handle)
+                .ok_or_else(AmlError::overflow)?;
+            let scope_id = self
+                .ensure_scope_path(Some(table.root))?
+                .ok_or_else(AmlError::invalid_namespace)?;
+            self.walk_term_list(table.block.bytes, 0, block_index, table.root, scope_id)?;
+        }
+
         let records = unsafe {
             slice::from_raw_parts(
                 self.storage.as_ptr().cast::<AmlNamespaceLoadRecord>(),
@@ -301,10 +343,8 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
         current_scope_path: AmlResolvedNamePath,
         current_scope_id: AmlNamespaceNodeId,
     ) -> AmlResult<usize> {
-        let pkg = AmlPkgLength::parse(&bytes[1..])?;
-        let object_end = 1 + pkg.value as usize;
-        let object_bytes = bytes.get(..object_end).ok_or_else(AmlError::truncated)?;
-        let predicate_offset = 1 + usize::from(pkg.encoded_bytes);
+        let (predicate_offset, object_end) = decode_pkg_extent(bytes, 1)?;
+        let object_bytes = &bytes[..object_end];
         let (predicate, predicate_consumed) = self
             .evaluate_load_time_term_arg(&object_bytes[predicate_offset..], current_scope_path)?;
         let body_start = predicate_offset + predicate_consumed;
@@ -320,15 +360,12 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
 
         let mut consumed = object_end;
         if bytes.get(object_end) == Some(&0xa1) {
-            let else_pkg = AmlPkgLength::parse(&bytes[object_end + 1..])?;
-            let else_end = object_end + 1 + else_pkg.value as usize;
-            let else_bytes = bytes
-                .get(object_end..else_end)
-                .ok_or_else(AmlError::truncated)?;
-            let else_body_start = 1 + usize::from(else_pkg.encoded_bytes);
+            let else_bytes = &bytes[object_end..];
+            let (else_body_start, else_length) = decode_pkg_extent(else_bytes, 1)?;
+            let else_end = object_end + else_length;
             if predicate == 0 {
                 self.walk_term_list(
-                    &else_bytes[else_body_start..],
+                    &else_bytes[else_body_start..else_length],
                     absolute_offset + object_end as u32 + else_body_start as u32,
                     block_index,
                     current_scope_path,
@@ -346,8 +383,8 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
         bytes: &[u8],
         current_scope_path: AmlResolvedNamePath,
     ) -> AmlResult<usize> {
-        let name = AmlEncodedNameString::parse(&bytes[1..])?;
-        let path = current_scope_path.resolve(name)?;
+        let external = decode_external(bytes)?;
+        let path = current_scope_path.resolve(external.name)?;
         let parent_id = self.ensure_scope_path(path.parent())?;
         if self.find_record(path).is_none() {
             self.insert_unique_record(
@@ -358,14 +395,7 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
                 AmlNamespaceNodePayload::None,
             )?;
         }
-        let object_type_index = 1 + usize::from(name.consumed_bytes);
-        let _object_type = *bytes
-            .get(object_type_index)
-            .ok_or_else(AmlError::truncated)?;
-        let _arg_count = *bytes
-            .get(object_type_index + 1)
-            .ok_or_else(AmlError::truncated)?;
-        Ok(object_type_index + 2)
+        Ok(external.end)
     }
 
     fn parse_create_field_like_op(
@@ -401,22 +431,18 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
         block_index: u16,
         current_scope_path: AmlResolvedNamePath,
     ) -> AmlResult<usize> {
-        let pkg = AmlPkgLength::parse(&bytes[1..])?;
-        let object_end = 1 + pkg.value as usize;
-        let object_bytes = bytes.get(..object_end).ok_or_else(AmlError::truncated)?;
-        let name_offset = 1 + usize::from(pkg.encoded_bytes);
-        let name = AmlEncodedNameString::parse(&object_bytes[name_offset..])?;
-        let path = current_scope_path.resolve(name)?;
+        let object = decode_named_object(bytes, 1)?;
+        let path = current_scope_path.resolve(object.name)?;
         let scope_id = self.ensure_scope_like_path(path)?;
-        let body_start = name_offset + usize::from(name.consumed_bytes);
+        let body_offset = u32::try_from(object.after_name).map_err(|_| AmlError::overflow())?;
         self.walk_term_list(
-            &object_bytes[body_start..],
-            absolute_offset + body_start as u32,
+            &bytes[object.after_name..object.end],
+            absolute_offset + body_offset,
             block_index,
             path,
             scope_id,
         )?;
-        Ok(object_end)
+        Ok(object.end)
     }
 
     fn parse_method_op(
@@ -426,34 +452,24 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
         block_index: u16,
         current_scope_path: AmlResolvedNamePath,
     ) -> AmlResult<usize> {
-        let pkg = AmlPkgLength::parse(&bytes[1..])?;
-        let object_end = 1 + pkg.value as usize;
-        let object_bytes = bytes.get(..object_end).ok_or_else(AmlError::truncated)?;
-        let name_offset = 1 + usize::from(pkg.encoded_bytes);
-        let name = AmlEncodedNameString::parse(&object_bytes[name_offset..])?;
-        let path = current_scope_path.resolve(name)?;
+        let header = decode_method_header(bytes)?;
+        let path = current_scope_path.resolve(header.name)?;
         let parent_id = self.ensure_scope_path(path.parent())?;
-        let flags_index = name_offset + usize::from(name.consumed_bytes);
-        let flags = *object_bytes
-            .get(flags_index)
-            .ok_or_else(AmlError::truncated)?;
-        let body_start = flags_index + 1;
         let method_id = self.next_node_id();
+        let body_offset = u32::try_from(header.body).map_err(|_| AmlError::overflow())?;
+        let body_length =
+            u32::try_from(header.end - header.body).map_err(|_| AmlError::overflow())?;
         let descriptor = AmlMethodDescriptor {
             node: method_id,
-            arg_count: flags & 0b111,
-            serialization: if flags & 0b1000 != 0 {
-                AmlMethodSerialization::Serialized
-            } else {
-                AmlMethodSerialization::NotSerialized
-            },
-            sync_level: flags >> 4,
+            arg_count: header.arg_count,
+            serialization: header.serialization,
+            sync_level: header.sync_level,
             kind: classify_method_kind(path),
             body: AmlCodeLocation {
                 block_index,
                 span: AmlBytecodeSpan {
-                    offset: absolute_offset + body_start as u32,
-                    length: (object_end - body_start) as u32,
+                    offset: absolute_offset + body_offset,
+                    length: body_length,
                 },
             },
         };
@@ -467,7 +483,7 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
             Some(descriptor.body),
             AmlNamespaceNodePayload::Method(descriptor),
         )?;
-        Ok(object_end)
+        Ok(header.end)
     }
 
     fn parse_ext_op(
@@ -536,13 +552,15 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
         let path = current_scope_path.resolve(name)?;
         let parent_id = self.ensure_scope_path(path.parent())?;
         let sync_index = 2 + usize::from(name.consumed_bytes);
-        let _sync_level = *bytes.get(sync_index).ok_or_else(AmlError::truncated)?;
+        let sync_level = *bytes.get(sync_index).ok_or_else(AmlError::truncated)?;
         self.insert_unique_record(
             path,
             parent_id,
             AmlObjectKind::Mutex,
             None,
-            AmlNamespaceNodePayload::None,
+            AmlNamespaceNodePayload::Mutex(AmlMutexDescriptor {
+                sync_level: sync_level & 0x0f,
+            }),
         )?;
         Ok(sync_index + 1)
     }
@@ -570,19 +588,17 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
         bytes: &[u8],
         current_scope_path: AmlResolvedNamePath,
     ) -> AmlResult<usize> {
-        let name = AmlEncodedNameString::parse(&bytes[2..])?;
-        let path = current_scope_path.resolve(name)?;
+        let header = decode_opregion_header(bytes)?;
+        let path = current_scope_path.resolve(header.name)?;
         let parent_id = self.ensure_scope_path(path.parent())?;
-        let space_index = 2 + usize::from(name.consumed_bytes);
-        let space = *bytes.get(space_index).ok_or_else(AmlError::truncated)?;
-        let (offset_value, offset_consumed) = parse_term_arg(&bytes[space_index + 1..])?;
-        let length_index = space_index + 1 + offset_consumed;
+        let (offset_value, offset_consumed) = parse_term_arg(&bytes[header.operands..])?;
+        let length_index = header.operands + offset_consumed;
         let (length_value, length_consumed) = parse_term_arg(&bytes[length_index..])?;
 
         let node_id = self.next_node_id();
         let payload = AmlNamespaceNodePayload::OpRegion(AmlOpRegionDescriptor {
             node: node_id,
-            space: map_address_space(space),
+            space: header.space,
             offset: offset_value,
             length: length_value,
         });
@@ -606,27 +622,17 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
         current_scope_path: AmlResolvedNamePath,
         current_scope_id: AmlNamespaceNodeId,
     ) -> AmlResult<usize> {
-        let pkg = AmlPkgLength::parse(&bytes[2..])?;
-        let object_end = 2 + pkg.value as usize;
-        let object_bytes = bytes.get(..object_end).ok_or_else(AmlError::truncated)?;
-        let region_name_offset = 2 + usize::from(pkg.encoded_bytes);
-        let region_name = AmlEncodedNameString::parse(&object_bytes[region_name_offset..])?;
-        let region_path = current_scope_path.resolve(region_name)?;
+        let header = decode_field_list_header(bytes)?;
+        let region_path = current_scope_path.resolve(header.first)?;
         let region_id = self.find_node_id(region_path);
-        let flags_index = region_name_offset + usize::from(region_name.consumed_bytes);
-        let flags = *object_bytes
-            .get(flags_index)
-            .ok_or_else(AmlError::truncated)?;
         self.parse_field_entries(
-            &object_bytes[..object_end],
-            flags_index + 1,
-            object_end,
+            &bytes[..header.end],
+            header.after_names,
             current_scope_path,
             current_scope_id,
             region_id,
-            flags,
         )?;
-        Ok(object_end)
+        Ok(header.end)
     }
 
     fn parse_index_field_op(
@@ -635,27 +641,15 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
         current_scope_path: AmlResolvedNamePath,
         current_scope_id: AmlNamespaceNodeId,
     ) -> AmlResult<usize> {
-        let pkg = AmlPkgLength::parse(&bytes[2..])?;
-        let object_end = 2 + pkg.value as usize;
-        let object_bytes = bytes.get(..object_end).ok_or_else(AmlError::truncated)?;
-        let index_name_offset = 2 + usize::from(pkg.encoded_bytes);
-        let index_name = AmlEncodedNameString::parse(&object_bytes[index_name_offset..])?;
-        let data_name_offset = index_name_offset + usize::from(index_name.consumed_bytes);
-        let data_name = AmlEncodedNameString::parse(&object_bytes[data_name_offset..])?;
-        let flags_index = data_name_offset + usize::from(data_name.consumed_bytes);
-        let flags = *object_bytes
-            .get(flags_index)
-            .ok_or_else(AmlError::truncated)?;
+        let header = decode_field_list_header(bytes)?;
         self.parse_field_entries(
-            &object_bytes[..object_end],
-            flags_index + 1,
-            object_end,
+            &bytes[..header.end],
+            header.after_names,
             current_scope_path,
             current_scope_id,
             None,
-            flags,
         )?;
-        Ok(object_end)
+        Ok(header.end)
     }
 
     fn parse_pkg_scoped_named_object(
@@ -667,13 +661,16 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
         kind: AmlObjectKind,
         fixed_prefix_bytes: usize,
     ) -> AmlResult<usize> {
-        let pkg = AmlPkgLength::parse(&bytes[2..])?;
-        let object_end = 2 + pkg.value as usize;
-        let object_bytes = bytes.get(..object_end).ok_or_else(AmlError::truncated)?;
-        let name_offset = 2 + usize::from(pkg.encoded_bytes);
-        let name = AmlEncodedNameString::parse(&object_bytes[name_offset..])?;
-        let path = current_scope_path.resolve(name)?;
+        let object = decode_named_object(bytes, 2)?;
+        let path = current_scope_path.resolve(object.name)?;
         let parent_id = self.ensure_scope_path(path.parent())?;
+        let body_start = object.after_name + fixed_prefix_bytes;
+        let body = bytes
+            .get(body_start..object.end)
+            .ok_or_else(AmlError::truncated)?;
+        let body_offset =
+            absolute_offset + u32::try_from(body_start).map_err(|_| AmlError::overflow())?;
+        let body_length = u32::try_from(body.len()).map_err(|_| AmlError::overflow())?;
         let node_id = self.insert_unique_record(
             path,
             parent_id,
@@ -681,25 +678,14 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
             Some(AmlCodeLocation {
                 block_index,
                 span: AmlBytecodeSpan {
-                    offset: absolute_offset
-                        + (name_offset + usize::from(name.consumed_bytes) + fixed_prefix_bytes)
-                            as u32,
-                    length: (object_end
-                        - (name_offset + usize::from(name.consumed_bytes) + fixed_prefix_bytes))
-                        as u32,
+                    offset: body_offset,
+                    length: body_length,
                 },
             }),
             AmlNamespaceNodePayload::None,
         )?;
-        let body_start = name_offset + usize::from(name.consumed_bytes) + fixed_prefix_bytes;
-        self.walk_term_list(
-            &object_bytes[body_start..],
-            absolute_offset + body_start as u32,
-            block_index,
-            path,
-            node_id,
-        )?;
-        Ok(object_end)
+        self.walk_term_list(body, body_offset, block_index, path, node_id)?;
+        Ok(object.end)
     }
 
     fn ensure_scope_path(
@@ -913,76 +899,56 @@ impl<'plan, 'storage> AmlNamespaceLoader<'plan, 'storage> {
         }
     }
 
+    /// Walks the field list of a `Field` or `IndexField` whose flags byte sits at `flags_index`.
     fn parse_field_entries(
         &mut self,
         object_bytes: &[u8],
-        start_cursor: usize,
-        object_end: usize,
+        flags_index: usize,
         current_scope_path: AmlResolvedNamePath,
         current_scope_id: AmlNamespaceNodeId,
         region_id: Option<AmlNamespaceNodeId>,
-        flags: u8,
     ) -> AmlResult<()> {
+        let flags = *object_bytes
+            .get(flags_index)
+            .ok_or_else(AmlError::truncated)?;
         let mut current_bit_offset = 0_u32;
-        let mut cursor = start_cursor;
-        while cursor < object_end {
-            let opcode = object_bytes[cursor];
-            match opcode {
-                0x00 => {
-                    let skip = AmlPkgLength::parse(&object_bytes[cursor + 1..])?;
-                    current_bit_offset = current_bit_offset.saturating_add(skip.value);
-                    cursor += 1 + usize::from(skip.encoded_bytes);
-                }
-                0x01 => {
-                    cursor += 3;
-                }
-                0x02 => {
-                    let name = AmlEncodedNameString::parse(&object_bytes[cursor + 1..])?;
-                    cursor += 1 + usize::from(name.consumed_bytes);
+        let mut cursor = flags_index + 1;
+        while cursor < object_bytes.len() {
+            let (element, consumed) = decode_field_element(&object_bytes[cursor..])?;
+            match element {
+                AmlFieldElement::Reserved { bits } => {
+                    current_bit_offset = current_bit_offset.saturating_add(bits);
                 }
-                0x03 => {
-                    cursor += 4;
-                }
-                _ => {
-                    let seg = AmlNameSeg::from_bytes([
-                        object_bytes[cursor],
-                        *object_bytes
-                            .get(cursor + 1)
-                            .ok_or_else(AmlError::truncated)?,
-                        *object_bytes
-                            .get(cursor + 2)
-                            .ok_or_else(AmlError::truncated)?,
-                        *object_bytes
-                            .get(cursor + 3)
-                            .ok_or_else(AmlError::truncated)?,
-                    ])?;
-                    let width = AmlPkgLength::parse(&object_bytes[cursor + 4..])?;
+                AmlFieldElement::Named { name, bits } => {
                     let mut field_path = current_scope_path;
-                    field_path.push(seg)?;
-                    let parent_id = Some(current_scope_id);
+                    field_path.push(name)?;
                     let node_id = self.next_node_id();
                     let descriptor = AmlFieldDescriptor {
                         node: node_id,
                         region: region_id,
                         bit_offset: current_bit_offset,
-                        bit_width: width.value,
+                        bit_width: bits,
                         access: decode_field_access(flags),
                         update: decode_field_update(flags),
                     };
                     self.insert_record_with_id(
                         AmlNamespaceNodeDescriptor {
                             id: node_id,
-                            parent: parent_id,
+                            parent: Some(current_scope_id),
                             kind: AmlObjectKind::Field,
                             path: field_path,
                         },
                         None,
                         AmlNamespaceNodePayload::Field(descriptor),
                     )?;
-                    current_bit_offset = current_bit_offset.saturating_add(width.value);
-                    cursor += 4 + usize::from(width.encoded_bytes);
+                    current_bit_offset = current_bit_offset.saturating_add(bits);
                 }
+                AmlFieldElement::AccessAs { .. }
+                | AmlFieldElement::Connection(_)
+                | AmlFieldElement::ConnectionBuffer
+                | AmlFieldElement::ExtendedAccessAs { .. } => {}
             }
+            cursor += consumed;
         }
         Ok(())
     }
@@ -1017,7 +983,7 @@ fn classify_method_kind(path: AmlResolvedNamePath) -> AmlMethodKind {
     }
 }
 
-fn map_address_space(value: u8) -> AmlAddressSpaceId {
+const fn map_address_space(value: u8) -> AmlAddressSpaceId {
     match value {
         0x00 => AmlAddressSpaceId::SystemMemory,
         0x01 => AmlAddressSpaceId::SystemIo,
@@ -1035,7 +1001,7 @@ fn map_address_space(value: u8) -> AmlAddressSpaceId {
     }
 }
 
-fn decode_field_access(flags: u8) -> AmlFieldAccessKind {
+pub(super) const fn decode_field_access(flags: u8) -> AmlFieldAccessKind {
     match flags & 0x0f {
         0x00 => AmlFieldAccessKind::Any,
         0x01 => AmlFieldAccessKind::Byte,
@@ -1047,7 +1013,7 @@ fn decode_field_access(flags: u8) -> AmlFieldAccessKind {
     }
 }
 
-fn decode_field_update(flags: u8) -> AmlFieldUpdateKind {
+pub(super) const fn decode_field_update(flags: u8) -> AmlFieldUpdateKind {
     match (flags >> 5) & 0b11 {
         0b01 => AmlFieldUpdateKind::WriteAsOnes,
         0b10 => AmlFieldUpdateKind::WriteAsZeros,
@@ -1055,6 +1021,224 @@ fn decode_field_update(flags: u8) -> AmlFieldUpdateKind {
     }
 }
 
+/// Named object whose extent is given by a package length: `Scope`, `Method`, `Device`,
+/// `Processor`, `PowerResource` and `ThermalZone`.
+///
+/// Offsets are relative to the first opcode byte, so the loader and the disassembler can both
+/// apply them to whatever slice they started the decode on.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AmlNamedObjectHeader<'a> {
+    pub(super) name: AmlEncodedNameString<'a>,
+    /// First byte after the name: fixed data, if the object has any, then its term list.
+    pub(super) after_name: usize,
+    pub(super) end: usize,
+}
+
+/// Decoded `DefMethod` header.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AmlMethodHeader<'a> {
+    pub(super) name: AmlEncodedNameString<'a>,
+    pub(super) arg_count: u8,
+    pub(super) serialization: AmlMethodSerialization,
+    pub(super) sync_level: u8,
+    pub(super) body: usize,
+    pub(super) end: usize,
+}
+
+/// Decoded `DefOpRegion` header; the offset and length term arguments start at `operands`.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AmlOpRegionHeader<'a> {
+    pub(super) name: AmlEncodedNameString<'a>,
+    pub(super) space: AmlAddressSpaceId,
+    pub(super) operands: usize,
+}
+
+/// Decoded `DefField`, `DefIndexField` or `DefBankField` header up to its register names.
+///
+/// `Field` and `IndexField` put their flags byte at `after_names`; `BankField` puts its bank value
+/// term argument there first.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AmlFieldListHeader<'a> {
+    /// Region for `Field` and `BankField`, index register for `IndexField`.
+    pub(super) first: AmlEncodedNameString<'a>,
+    /// Data register for `IndexField`, bank register for `BankField`.
+    pub(super) second: Option<AmlEncodedNameString<'a>>,
+    pub(super) after_names: usize,
+    pub(super) end: usize,
+}
+
+/// One entry of a field list.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum AmlFieldElement<'a> {
+    Reserved {
+        bits: u32,
+    },
+    AccessAs {
+        access: AmlFieldAccessKind,
+        attrib: u8,
+    },
+    Connection(AmlEncodedNameString<'a>),
+    /// `Connection` to an inline resource buffer, which starts one byte into the element.
+    ConnectionBuffer,
+    ExtendedAccessAs {
+        access: AmlFieldAccessKind,
+        attrib: u8,
+        length: u8,
+    },
+    Named {
+        name: AmlNameSeg,
+        bits: u32,
+    },
+}
+
+/// Decoded `DefExternal`.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct AmlExternalHeader<'a> {
+    pub(super) name: AmlEncodedNameString<'a>,
+    pub(super) object_type: u8,
+    pub(super) arg_count: u8,
+    pub(super) end: usize,
+}
+
+/// Decodes the package length after an `opcode_len`-byte opcode and returns where the contents
+/// start and where the object ends.
+pub(super) fn decode_pkg_extent(bytes: &[u8], opcode_len: usize) -> AmlResult<(usize, usize)> {
+    let pkg = AmlPkgLength::parse(bytes.get(opcode_len..).ok_or_else(AmlError::truncated)?)?;
+    let length = usize::try_from(pkg.value).map_err(|_| AmlError::overflow())?;
+    let end = opcode_len + length;
+    if end > bytes.len() || length < usize::from(pkg.encoded_bytes) {
+        return Err(AmlError::truncated());
+    }
+    Ok((opcode_len + usize::from(pkg.encoded_bytes), end))
+}
+
+pub(super) fn decode_named_object(
+    bytes: &[u8],
+    opcode_len: usize,
+) -> AmlResult<AmlNamedObjectHeader<'_>> {
+    let (contents, end) = decode_pkg_extent(bytes, opcode_len)?;
+    let name = AmlEncodedNameString::parse(&bytes[contents..end])?;
+    Ok(AmlNamedObjectHeader {
+        name,
+        after_name: contents + usize::from(name.consumed_bytes),
+        end,
+    })
+}
+
+pub(super) fn decode_method_header(bytes: &[u8]) -> AmlResult<AmlMethodHeader<'_>> {
+    let object = decode_named_object(bytes, 1)?;
+    let flags = *bytes[..object.end]
+        .get(object.after_name)
+        .ok_or_else(AmlError::truncated)?;
+    Ok(AmlMethodHeader {
+        name: object.name,
+        arg_count: flags & 0b111,
+        serialization: if flags & 0b1000 != 0 {
+            AmlMethodSerialization::Serialized
+        } else {
+            AmlMethodSerialization::NotSerialized
+        },
+        sync_level: flags >> 4,
+        body: object.after_name + 1,
+        end: object.end,
+    })
+}
+
+pub(super) fn decode_opregion_header(bytes: &[u8]) -> AmlResult<AmlOpRegionHeader<'_>> {
+    let name = AmlEncodedNameString::parse(bytes.get(2..).ok_or_else(AmlError::truncated)?)?;
+    let space_index = 2 + usize::from(name.consumed_bytes);
+    let space = *bytes.get(space_index).ok_or_else(AmlError::truncated)?;
+    Ok(AmlOpRegionHeader {
+        name,
+        space: map_address_space(space),
+        operands: space_index + 1,
+    })
+}
+
+pub(super) fn decode_field_list_header(bytes: &[u8]) -> AmlResult<AmlFieldListHeader<'_>> {
+    let opcode = *bytes.get(1).ok_or_else(AmlError::truncated)?;
+    let (contents, end) = decode_pkg_extent(bytes, 2)?;
+    let object = &bytes[..end];
+    let first = AmlEncodedNameString::parse(&object[contents..])?;
+    let mut after_names = contents + usize::from(first.consumed_bytes);
+    let second = if opcode == 0x81 {
+        None
+    } else {
+        let name = AmlEncodedNameString::parse(&object[after_names..])?;
+        after_names += usize::from(name.consumed_bytes);
+        Some(name)
+    };
+    Ok(AmlFieldListHeader {
+        first,
+        second,
+        after_names,
+        end,
+    })
+}
+
+/// Decodes the field-list entry at the start of `bytes`, which must end with the field object.
+pub(super) fn decode_field_element(bytes: &[u8]) -> AmlResult<(AmlFieldElement<'_>, usize)> {
+    let byte = |index: usize| bytes.get(index).copied().ok_or_else(AmlError::truncated);
+    match byte(0)? {
+        0x00 => {
+            let width = AmlPkgLength::parse(&bytes[1..])?;
+            Ok((
+                AmlFieldElement::Reserved { bits: width.value },
+                1 + usize::from(width.encoded_bytes),
+            ))
+        }
+        0x01 => Ok((
+            AmlFieldElement::AccessAs {
+                access: decode_field_access(byte(1)?),
+                attrib: byte(2)?,
+            },
+            3,
+        )),
+        0x02 if byte(1)? == 0x11 => {
+            let (_, buffer_end) = decode_pkg_extent(&bytes[1..], 1)?;
+            Ok((AmlFieldElement::ConnectionBuffer, 1 + buffer_end))
+        }
+        0x02 => {
+            let name = AmlEncodedNameString::parse(&bytes[1..])?;
+            Ok((
+                AmlFieldElement::Connection(name),
+                1 + usize::from(name.consumed_bytes),
+            ))
+        }
+        0x03 => Ok((
+            AmlFieldElement::ExtendedAccessAs {
+                access: decode_field_access(byte(1)?),
+                attrib: byte(2)?,
+                length: byte(3)?,
+            },
+            4,
+        )),
+        first => {
+            let name = AmlNameSeg::from_bytes([first, byte(1)?, byte(2)?, byte(3)?])?;
+            let width = AmlPkgLength::parse(&bytes[4..])?;
+            Ok((
+                AmlFieldElement::Named {
+                    name,
+                    bits: width.value,
+                },
+                4 + usize::from(width.encoded_bytes),
+            ))
+        }
+    }
+}
+
+pub(super) fn decode_external(bytes: &[u8]) -> AmlResult<AmlExternalHeader<'_>> {
+    let name = AmlEncodedNameString::parse(bytes.get(1..).ok_or_else(AmlError::truncated)?)?;
+    let object_type_index = 1 + usize::from(name.consumed_bytes);
+    let byte = |index: usize| bytes.get(index).copied().ok_or_else(AmlError::truncated);
+    Ok(AmlExternalHeader {
+        name,
+        object_type: byte(object_type_index)?,
+        arg_count: byte(object_type_index + 1)?,
+        end: object_type_index + 2,
+    })
+}
+
 fn parse_term_arg_value(bytes: &[u8]) -> AmlResult<(Option<u64>, usize)> {
     let opcode = *bytes.first().ok_or_else(AmlError::truncated)?;
     match opcode {
@@ -1291,4 +1475,29 @@ mod tests {
             .expect("method should exist");
         assert_eq!(method.kind, AmlMethodKind::NotificationQuery);
     }
+
+    #[test]
+    fn namespace_loader_skips_connection_resource_buffers_in_field_lists() {
+        let payload: &[u8] = &[
+            0x5b, 0x80, b'G', b'P', b'I', b'O', 0x08, 0x00, 0x0a, 0x10, // OpRegion
+            0x5b, 0x81, 0x12, b'G', b'P', b'I', b'O', 0x01, // Field
+            0x02, 0x11, 0x05, 0x0a, 0x02, 0x79, 0x00, // Connection(Buffer)
+            b'P', b'I', b'N', b'0', 0x01,
+        ];
+        let block = definition_block(payload);
+        let plan =
+            AmlNamespaceLoadPlan::from_definition_blocks(AmlDefinitionBlockSet::new(block, &[]));
+        let mut storage = [MaybeUninit::<AmlNamespaceLoadRecord>::uninit(); 8];
+        let loaded = plan.load_into(&mut storage).expect("namespace should load");
+        let field = loaded
+            .records
+            .iter()
+            .find_map(|record| match record.payload {
+                AmlNamespaceNodePayload::Field(field) => Some(field),
+                _ => None,
+            })
+            .expect("field should exist");
+        assert!(field.region.is_some());
+        assert_eq!((field.bit_offset, field.bit_width), (0, 1));
+    }
 }
@@ -159,6 +159,8 @@ mod tests {
         AmlSleepHost,
         AmlSystemIoHost,
         AmlSystemMemoryHost,
+        AmlTableHost,
+        AmlTraceHost,
         AmlPciConfigHost,
         AmlRuntimeIntegerSlot,
         AmlRuntimeMutexSlot,
@@ -312,6 +314,10 @@ mod tests {
         }
     }
 
+    impl AmlTableHost for FakeHost {}
+
+    impl AmlTraceHost for FakeHost {}
+
     impl AmlHost for FakeHost {}
 
     fn encode_pkg_length(payload_len: usize) -> Vec<u8> {
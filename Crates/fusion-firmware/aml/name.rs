@@ -6,6 +6,8 @@ use crate::aml::{
 };
 
 pub const AML_MAX_PATH_SEGMENTS: usize = 16;
+/// Longest absolute text spelling of one resolved path: `\` plus dotted 4-byte segments.
+pub const AML_MAX_PATH_TEXT_BYTES: usize = AML_MAX_PATH_SEGMENTS * 5;
 
 /// One AML 4-character name segment.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -142,6 +144,43 @@ impl AmlResolvedNamePath {
         Ok(path)
     }
 
+    /// Writes the absolute text spelling of this path (for example `\\_SB.AC`) into `out`.
+    ///
+    /// Trailing `_` padding is trimmed from each segment, matching how ASL spells names.
+    /// Returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns `overflow` when `out` is too short to hold the whole path.
+    pub fn write_text(self, out: &mut [u8]) -> AmlResult<usize> {
+        let Some(first) = out.first_mut() else {
+            return Err(AmlError::overflow());
+        };
+        *first = b'\\';
+
+        let mut cursor = 1_usize;
+        let mut index = 0_u8;
+        while index < self.segment_count {
+            let bytes = self.segments[usize::from(index)].bytes();
+            let mut len = 4_usize;
+            while len > 1 && bytes[len - 1] == b'_' {
+                len -= 1;
+            }
+            let separator = usize::from(index != 0);
+            let end = cursor + separator + len;
+            if end > out.len() {
+                return Err(AmlError::overflow());
+            }
+            if separator != 0 {
+                out[cursor] = b'.';
+            }
+            out[cursor + separator..end].copy_from_slice(&bytes[..len]);
+            cursor = end;
+            index += 1;
+        }
+        Ok(cursor)
+    }
+
     pub fn parse_text(raw: &str) -> AmlResult<Self> {
         if !raw.is_ascii() || raw.is_empty() {
             return Err(AmlError::invalid_name());
@@ -393,6 +432,20 @@ mod tests {
         assert_eq!(path.segment(2).unwrap().bytes(), *b"_PSR");
     }
 
+    #[test]
+    fn resolved_path_writes_trimmed_textual_spelling() {
+        let path = AmlResolvedNamePath::parse_text("\\_SB.AC._PSR").expect("path should parse");
+        let mut text = [0_u8; AML_MAX_PATH_TEXT_BYTES];
+        let len = path.write_text(&mut text).expect("path text should fit");
+        assert_eq!(&text[..len], b"\\_SB.AC._PSR");
+
+        let len = AmlResolvedNamePath::root()
+            .write_text(&mut text)
+            .expect("root text should fit");
+        assert_eq!(&text[..len], b"\\");
+        assert_eq!(path.write_text(&mut text[..4]), Err(AmlError::overflow()));
+    }
+
     #[test]
     fn resolved_path_rejects_invalid_textual_segments() {
         let error = AmlResolvedNamePath::parse_text("\\_SB.PCI_Config").unwrap_err();
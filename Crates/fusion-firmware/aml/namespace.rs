@@ -5,6 +5,7 @@ use crate::aml::{
     AmlEncodedNameString,
     AmlFieldDescriptor,
     AmlMethodDescriptor,
+    AmlMutexDescriptor,
     AmlNameAnchor,
     AmlObjectKind,
     AmlOpRegionDescriptor,
@@ -33,6 +34,7 @@ pub enum AmlNamespaceNodePayload {
     Method(AmlMethodDescriptor),
     OpRegion(AmlOpRegionDescriptor),
     Field(AmlFieldDescriptor),
+    Mutex(AmlMutexDescriptor),
 }
 
 /// One loaded namespace node record.
@@ -0,0 +1,2258 @@
+//! ACPI resource templates (`_CRS`, `_PRS`, `_SRS`) and PCI interrupt routing (`_PRT`).
+//!
+//! Resource templates are the buffers AML hands back to describe what a device decodes: MMIO
+//! windows, I/O ports, interrupts, DMA channels, and the GPIO/serial-bus connections of newer
+//! SoC-style platforms. They are plain byte streams of small and large descriptors (ACPI 6.5
+//! section 6.4) terminated by one end tag.
+//!
+//! Decoding here is bounds-checked and borrowing: [`AmlResourceTemplate`] validates the whole
+//! stream once, and every descriptor it yields borrows its variable-length tails (pin lists,
+//! resource-source names, vendor data) straight out of the template. Descriptors this layer does
+//! not model are still yielded as [`AmlRawResource`] so callers can skip or forward them.
+//!
+//! [`AmlResourceTemplateWriter`] goes the other way for `_SRS`, including the end-tag checksum,
+//! and can land the result in one runtime buffer object ready to pass as the method argument.
+
+use core::mem::MaybeUninit;
+
+use crate::aml::{
+    AML_MAX_BUFFER_BYTES,
+    AmlCodeLocation,
+    AmlEncodedNameString,
+    AmlError,
+    AmlExecutionPhase,
+    AmlLoadedNamespace,
+    AmlNamespaceNodeId,
+    AmlPkgLength,
+    AmlPureEvaluator,
+    AmlRegionAccessHost,
+    AmlResolvedNamePath,
+    AmlResult,
+    AmlRuntimeBufferHandle,
+    AmlRuntimeState,
+    AmlValue,
+};
+
+const SMALL_IRQ: u8 = 0x04;
+const SMALL_DMA: u8 = 0x05;
+const SMALL_IO: u8 = 0x08;
+const SMALL_FIXED_IO: u8 = 0x09;
+const SMALL_END_TAG: u8 = 0x0f;
+
+const LARGE_MEMORY32: u8 = 0x85;
+const LARGE_FIXED_MEMORY32: u8 = 0x86;
+const LARGE_DWORD_ADDRESS: u8 = 0x87;
+const LARGE_WORD_ADDRESS: u8 = 0x88;
+const LARGE_EXTENDED_IRQ: u8 = 0x89;
+const LARGE_QWORD_ADDRESS: u8 = 0x8a;
+const LARGE_GPIO: u8 = 0x8c;
+const LARGE_SERIAL_BUS: u8 = 0x8e;
+
+/// Fixed `GpioInt`/`GpioIo` header length; the pin table conventionally starts right after it.
+const GPIO_HEADER_BYTES: usize = 23;
+/// Fixed serial-bus header length up to the start of the type-specific data.
+const SERIAL_BUS_HEADER_BYTES: usize = 12;
+
+const SERIAL_BUS_I2C: u8 = 1;
+const SERIAL_BUS_SPI: u8 = 2;
+const SERIAL_BUS_UART: u8 = 3;
+
+const I2C_TYPE_DATA_BYTES: usize = 6;
+const SPI_TYPE_DATA_BYTES: usize = 9;
+const UART_TYPE_DATA_BYTES: usize = 10;
+
+/// Whether one resource is consumed by the device or produced for its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlResourceUsage {
+    Consumer,
+    Producer,
+}
+
+/// Interrupt trigger mode (`_HE`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlInterruptTrigger {
+    Level,
+    Edge,
+}
+
+/// Interrupt polarity (`_LL`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlInterruptPolarity {
+    ActiveHigh,
+    ActiveLow,
+    /// Only meaningful for GPIO interrupts.
+    ActiveBoth,
+}
+
+/// Interrupt sharing and wake capability (`_SHR`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlInterruptSharing {
+    Exclusive,
+    Shared,
+    ExclusiveAndWake,
+    SharedAndWake,
+}
+
+impl AmlInterruptSharing {
+    const fn from_bits(shared: bool, wake: bool) -> Self {
+        match (shared, wake) {
+            (false, false) => Self::Exclusive,
+            (true, false) => Self::Shared,
+            (false, true) => Self::ExclusiveAndWake,
+            (true, true) => Self::SharedAndWake,
+        }
+    }
+
+    const fn shared(self) -> bool {
+        matches!(self, Self::Shared | Self::SharedAndWake)
+    }
+
+    const fn wake(self) -> bool {
+        matches!(self, Self::ExclusiveAndWake | Self::SharedAndWake)
+    }
+}
+
+/// Interrupt signalling mode shared by IRQ, extended-IRQ, and `GpioInt` descriptors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlInterruptMode {
+    pub trigger: AmlInterruptTrigger,
+    pub polarity: AmlInterruptPolarity,
+    pub sharing: AmlInterruptSharing,
+}
+
+impl AmlInterruptMode {
+    /// Mode implied by a legacy IRQ descriptor that omits its information byte.
+    pub const ISA_DEFAULT: Self = Self {
+        trigger: AmlInterruptTrigger::Edge,
+        polarity: AmlInterruptPolarity::ActiveHigh,
+        sharing: AmlInterruptSharing::Exclusive,
+    };
+}
+
+/// Named resource source (`ResourceSource` plus `ResourceSourceIndex`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlResourceSource<'a> {
+    pub index: u8,
+    pub name: &'a str,
+}
+
+/// Legacy IRQ descriptor (`IRQ`/`IRQNoFlags`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlIrqResource {
+    /// Bit `n` set means ISA IRQ `n` is decoded.
+    pub mask: u16,
+    /// `None` for the two-byte `IRQNoFlags` form.
+    pub mode: Option<AmlInterruptMode>,
+}
+
+/// DMA transfer width supported by one legacy DMA descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlDmaTransferWidth {
+    Bits8,
+    Bits8And16,
+    Bits16,
+}
+
+/// ISA DMA channel speed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlDmaSpeed {
+    Compatibility,
+    TypeA,
+    TypeB,
+    TypeF,
+}
+
+/// Legacy DMA descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlDmaResource {
+    pub channel_mask: u8,
+    pub bus_master: bool,
+    pub transfer: AmlDmaTransferWidth,
+    pub speed: AmlDmaSpeed,
+}
+
+/// Relocatable I/O-port descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlIoResource {
+    /// Full 16-bit decode rather than ISA 10-bit decode.
+    pub decode16: bool,
+    pub minimum: u16,
+    pub maximum: u16,
+    pub alignment: u8,
+    pub length: u8,
+}
+
+/// Fixed-location I/O-port descriptor (10-bit decode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlFixedIoResource {
+    pub base: u16,
+    pub length: u8,
+}
+
+/// Relocatable 32-bit memory-range descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlMemory32Resource {
+    pub writable: bool,
+    pub minimum: u32,
+    pub maximum: u32,
+    pub alignment: u32,
+    pub length: u32,
+}
+
+/// Fixed 32-bit memory-range descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlFixedMemory32Resource {
+    pub writable: bool,
+    pub base: u32,
+    pub length: u32,
+}
+
+/// Field width of one address-space descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlAddressWidth {
+    Word,
+    DWord,
+    QWord,
+}
+
+impl AmlAddressWidth {
+    const fn bytes(self) -> usize {
+        match self {
+            Self::Word => 2,
+            Self::DWord => 4,
+            Self::QWord => 8,
+        }
+    }
+
+    const fn tag(self) -> u8 {
+        match self {
+            Self::Word => LARGE_WORD_ADDRESS,
+            Self::DWord => LARGE_DWORD_ADDRESS,
+            Self::QWord => LARGE_QWORD_ADDRESS,
+        }
+    }
+}
+
+/// Resource type carried by one address-space descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlAddressResourceKind {
+    Memory,
+    Io,
+    BusNumber,
+    /// Reserved or vendor-defined resource type (`3..=255`).
+    Other(u8),
+}
+
+impl AmlAddressResourceKind {
+    const fn from_raw(raw: u8) -> Self {
+        match raw {
+            0 => Self::Memory,
+            1 => Self::Io,
+            2 => Self::BusNumber,
+            other => Self::Other(other),
+        }
+    }
+
+    const fn raw(self) -> u8 {
+        match self {
+            Self::Memory => 0,
+            Self::Io => 1,
+            Self::BusNumber => 2,
+            Self::Other(raw) => raw,
+        }
+    }
+}
+
+/// `Word`, `DWord`, or `QWord` address-space descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlAddressResource<'a> {
+    pub width: AmlAddressWidth,
+    pub kind: AmlAddressResourceKind,
+    pub usage: AmlResourceUsage,
+    /// Bridge decodes subtractively (`_DEC`).
+    pub subtractive_decode: bool,
+    pub min_fixed: bool,
+    pub max_fixed: bool,
+    /// Type-specific flags (cacheability for memory, ISA/translation bits for I/O).
+    pub type_flags: u8,
+    pub granularity: u64,
+    pub minimum: u64,
+    pub maximum: u64,
+    pub translation_offset: u64,
+    pub length: u64,
+    pub source: Option<AmlResourceSource<'a>>,
+}
+
+/// Borrowed little-endian list of 32-bit interrupt numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlResourceInterruptList<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> AmlResourceInterruptList<'a> {
+    /// Wraps one raw interrupt table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `invalid_bytecode` when `bytes` is not a whole number of 32-bit entries.
+    pub const fn new(bytes: &'a [u8]) -> AmlResult<Self> {
+        if !bytes.len().is_multiple_of(4) {
+            return Err(AmlError::invalid_bytecode());
+        }
+        Ok(Self { bytes })
+    }
+
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.bytes.len() / 4
+    }
+
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    #[must_use]
+    pub const fn as_bytes(self) -> &'a [u8] {
+        self.bytes
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = u32> + 'a {
+        self.bytes
+            .chunks_exact(4)
+            .map(|raw| u32::from_le_bytes([raw[0], raw[1], raw[2], raw[3]]))
+    }
+}
+
+/// Extended interrupt descriptor (`Interrupt`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlExtendedIrqResource<'a> {
+    pub usage: AmlResourceUsage,
+    pub mode: AmlInterruptMode,
+    pub interrupts: AmlResourceInterruptList<'a>,
+    pub source: Option<AmlResourceSource<'a>>,
+}
+
+/// Borrowed little-endian list of 16-bit GPIO pin numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlResourcePinList<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> AmlResourcePinList<'a> {
+    /// Wraps one raw pin table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `invalid_bytecode` when `bytes` is not a whole number of 16-bit entries.
+    pub const fn new(bytes: &'a [u8]) -> AmlResult<Self> {
+        if !bytes.len().is_multiple_of(2) {
+            return Err(AmlError::invalid_bytecode());
+        }
+        Ok(Self { bytes })
+    }
+
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.bytes.len() / 2
+    }
+
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    #[must_use]
+    pub const fn as_bytes(self) -> &'a [u8] {
+        self.bytes
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = u16> + 'a {
+        self.bytes
+            .chunks_exact(2)
+            .map(|raw| u16::from_le_bytes([raw[0], raw[1]]))
+    }
+}
+
+/// Direction restriction of one `GpioIo` connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlGpioIoRestriction {
+    None,
+    InputOnly,
+    OutputOnly,
+    /// Preserve the current direction across OS control.
+    Preserve,
+}
+
+/// Connection type of one GPIO descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlGpioConnection {
+    Interrupt(AmlInterruptMode),
+    Io {
+        restriction: AmlGpioIoRestriction,
+        shared: bool,
+    },
+}
+
+/// `GpioInt` or `GpioIo` connection descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlGpioResource<'a> {
+    pub revision: u8,
+    pub usage: AmlResourceUsage,
+    pub connection: AmlGpioConnection,
+    /// `PinConfig`: 0 default, 1 pull-up, 2 pull-down, 3 no pull, or vendor values.
+    pub pin_config: u8,
+    /// Output drive strength in hundredths of a milliamp.
+    pub drive_strength: u16,
+    /// Debounce timeout in hundredths of a millisecond.
+    pub debounce_timeout: u16,
+    pub pins: AmlResourcePinList<'a>,
+    pub source: AmlResourceSource<'a>,
+    pub vendor_data: &'a [u8],
+}
+
+/// Fields every generic serial-bus connection descriptor carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlSerialBusCommon<'a> {
+    pub revision: u8,
+    pub type_revision: u8,
+    pub usage: AmlResourceUsage,
+    /// Connection is initiated by the device rather than the controller (`SlaveMode`).
+    pub device_initiated: bool,
+    pub shared: bool,
+    pub source: AmlResourceSource<'a>,
+    pub vendor_data: &'a [u8],
+}
+
+/// `I2cSerialBusV2` connection descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlI2cResource<'a> {
+    pub common: AmlSerialBusCommon<'a>,
+    pub ten_bit_addressing: bool,
+    pub speed_hz: u32,
+    pub address: u16,
+}
+
+/// `SpiSerialBusV2` connection descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlSpiResource<'a> {
+    pub common: AmlSerialBusCommon<'a>,
+    pub three_wire: bool,
+    pub chip_select_active_high: bool,
+    pub speed_hz: u32,
+    pub data_bits: u8,
+    /// Clock phase: 0 samples on the first edge, 1 on the second.
+    pub clock_phase: u8,
+    /// Clock polarity: 0 idles low, 1 idles high.
+    pub clock_polarity: u8,
+    pub chip_select: u16,
+}
+
+/// UART flow-control mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlUartFlowControl {
+    None,
+    Hardware,
+    XonXoff,
+}
+
+/// UART stop-bit configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlUartStopBits {
+    None,
+    One,
+    OnePointFive,
+    Two,
+}
+
+/// UART parity mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlUartParity {
+    None,
+    Even,
+    Odd,
+    Mark,
+    Space,
+    Other(u8),
+}
+
+impl AmlUartParity {
+    const fn from_raw(raw: u8) -> Self {
+        match raw {
+            0 => Self::None,
+            1 => Self::Even,
+            2 => Self::Odd,
+            3 => Self::Mark,
+            4 => Self::Space,
+            other => Self::Other(other),
+        }
+    }
+
+    const fn raw(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Even => 1,
+            Self::Odd => 2,
+            Self::Mark => 3,
+            Self::Space => 4,
+            Self::Other(raw) => raw,
+        }
+    }
+}
+
+/// `UartSerialBusV2` connection descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlUartResource<'a> {
+    pub common: AmlSerialBusCommon<'a>,
+    pub flow_control: AmlUartFlowControl,
+    pub stop_bits: AmlUartStopBits,
+    /// Data bits per character, `5..=9`.
+    pub data_bits: u8,
+    pub big_endian: bool,
+    pub baud_rate: u32,
+    pub rx_fifo: u16,
+    pub tx_fifo: u16,
+    pub parity: AmlUartParity,
+    /// Bitmask of enabled control lines (`RTS`, `CTS`, `DTR`, `DSR`, `RI`, `DTD`).
+    pub lines_enabled: u8,
+}
+
+/// One descriptor this layer does not model, kept verbatim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlRawResource<'a> {
+    /// Small item name (`0x00..=0x0f`) or large tag byte (`0x80..=0xff`).
+    pub tag: u8,
+    pub data: &'a [u8],
+}
+
+/// One decoded ACPI resource descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlResourceDescriptor<'a> {
+    Irq(AmlIrqResource),
+    Dma(AmlDmaResource),
+    Io(AmlIoResource),
+    FixedIo(AmlFixedIoResource),
+    Memory32(AmlMemory32Resource),
+    FixedMemory32(AmlFixedMemory32Resource),
+    Address(AmlAddressResource<'a>),
+    ExtendedIrq(AmlExtendedIrqResource<'a>),
+    Gpio(AmlGpioResource<'a>),
+    I2c(AmlI2cResource<'a>),
+    Spi(AmlSpiResource<'a>),
+    Uart(AmlUartResource<'a>),
+    Other(AmlRawResource<'a>),
+}
+
+/// Validated, borrowed ACPI resource template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlResourceTemplate<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> AmlResourceTemplate<'a> {
+    /// Validates one resource template up to and including its end tag.
+    ///
+    /// Bytes after the end tag are ignored, matching how AML buffers are often padded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `truncated` when a descriptor runs past the buffer or the end tag is missing, and
+    /// `invalid_bytecode` when one modelled descriptor is malformed.
+    pub fn parse(bytes: &'a [u8]) -> AmlResult<Self> {
+        let mut cursor = 0_usize;
+        loop {
+            let (descriptor, next) = decode_descriptor(bytes, cursor)?;
+            if descriptor.is_none() {
+                return Ok(Self {
+                    bytes: &bytes[..next],
+                });
+            }
+            cursor = next;
+        }
+    }
+
+    /// Resolves one evaluated `_CRS`/`_PRS` value into its raw template bytes.
+    ///
+    /// Static buffers are borrowed in place; runtime buffer objects are copied into `scratch`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `unsupported` for non-buffer values, `invalid_state` when a runtime buffer is
+    /// missing, `overflow` when `scratch` is too small, and any [`Self::parse`] error.
+    pub fn from_value(
+        state: Option<&AmlRuntimeState<'_>>,
+        value: &AmlValue<'a>,
+        scratch: &'a mut [u8],
+    ) -> AmlResult<Self> {
+        match *value {
+            AmlValue::Buffer(bytes) => Self::parse(bytes),
+            AmlValue::BufferHandle(handle) => {
+                let state = state.ok_or_else(AmlError::invalid_state)?;
+                let len = state
+                    .read_buffer_len(handle)
+                    .ok_or_else(AmlError::invalid_state)?;
+                let target = scratch
+                    .get_mut(..usize::from(len))
+                    .ok_or_else(AmlError::overflow)?;
+                for (index, slot) in (0_u8..).zip(target.iter_mut()) {
+                    *slot = state
+                        .read_buffer_byte(handle, index)
+                        .ok_or_else(AmlError::invalid_state)?;
+                }
+                Self::parse(target)
+            }
+            _ => Err(AmlError::unsupported()),
+        }
+    }
+
+    /// Evaluates one `_CRS`/`_PRS` object and validates the returned template.
+    ///
+    /// # Errors
+    ///
+    /// Returns any evaluation error, `invalid_state` when evaluation blocks or returns nothing,
+    /// and any [`Self::from_value`] error.
+    pub fn evaluate<'blocks>(
+        namespace: AmlLoadedNamespace<'_, 'blocks>,
+        host: &dyn AmlRegionAccessHost,
+        state: &AmlRuntimeState<'_>,
+        node: AmlNamespaceNodeId,
+        scratch: &'a mut [u8],
+    ) -> AmlResult<Self>
+    where
+        'blocks: 'a,
+    {
+        let outcome = AmlPureEvaluator::new(namespace).evaluate_object_with_host_and_state(
+            host,
+            state,
+            node,
+            AmlExecutionPhase::Runtime,
+        )?;
+        if outcome.blocked {
+            return Err(AmlError::invalid_state());
+        }
+        let value = outcome.return_value.ok_or_else(AmlError::invalid_state)?;
+        Self::from_value(Some(state), &value, scratch)
+    }
+
+    /// Returns the template bytes including the end tag.
+    #[must_use]
+    pub const fn as_bytes(self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Iterates every descriptor before the end tag.
+    #[must_use]
+    pub const fn iter(self) -> AmlResourceIter<'a> {
+        AmlResourceIter {
+            bytes: self.bytes,
+            cursor: 0,
+        }
+    }
+}
+
+impl<'a> IntoIterator for AmlResourceTemplate<'a> {
+    type Item = AmlResourceDescriptor<'a>;
+    type IntoIter = AmlResourceIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the descriptors of one validated resource template.
+#[derive(Debug, Clone)]
+pub struct AmlResourceIter<'a> {
+    bytes: &'a [u8],
+    cursor: usize,
+}
+
+impl<'a> Iterator for AmlResourceIter<'a> {
+    type Item = AmlResourceDescriptor<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The template was validated up front, so decoding cannot fail here.
+        let (descriptor, next) = decode_descriptor(self.bytes, self.cursor).ok()?;
+        self.cursor = next;
+        descriptor
+    }
+}
+
+fn decode_descriptor(
+    bytes: &[u8],
+    cursor: usize,
+) -> AmlResult<(Option<AmlResourceDescriptor<'_>>, usize)> {
+    let tag = *bytes.get(cursor).ok_or_else(AmlError::truncated)?;
+    if tag & 0x80 == 0 {
+        let item = (tag >> 3) & 0x0f;
+        let body_start = cursor + 1;
+        let next = body_start + usize::from(tag & 0x07);
+        let body = bytes
+            .get(body_start..next)
+            .ok_or_else(AmlError::truncated)?;
+        if item == SMALL_END_TAG {
+            return Ok((None, next));
+        }
+        return Ok((Some(decode_small(item, body)?), next));
+    }
+
+    let length = u16_at(bytes, cursor + 1)?;
+    let next = cursor + 3 + usize::from(length);
+    let descriptor = bytes.get(cursor..next).ok_or_else(AmlError::truncated)?;
+    Ok((Some(decode_large(tag, descriptor)?), next))
+}
+
+fn decode_small(item: u8, body: &[u8]) -> AmlResult<AmlResourceDescriptor<'_>> {
+    match item {
+        SMALL_IRQ => {
+            let mask = u16_at(body, 0)?;
+            let mode = body.get(2).map(|&info| AmlInterruptMode {
+                trigger: trigger_from_bit(info & 0x01 != 0),
+                polarity: if info & 0x08 != 0 {
+                    AmlInterruptPolarity::ActiveLow
+                } else {
+                    AmlInterruptPolarity::ActiveHigh
+                },
+                sharing: AmlInterruptSharing::from_bits(info & 0x10 != 0, info & 0x20 != 0),
+            });
+            Ok(AmlResourceDescriptor::Irq(AmlIrqResource { mask, mode }))
+        }
+        SMALL_DMA => {
+            let channel_mask = byte_at(body, 0)?;
+            let flags = byte_at(body, 1)?;
+            let transfer = match flags & 0x03 {
+                0 => AmlDmaTransferWidth::Bits8,
+                1 => AmlDmaTransferWidth::Bits8And16,
+                2 => AmlDmaTransferWidth::Bits16,
+                _ => return Err(AmlError::invalid_bytecode()),
+            };
+            let speed = match (flags >> 5) & 0x03 {
+                0 => AmlDmaSpeed::Compatibility,
+                1 => AmlDmaSpeed::TypeA,
+                2 => AmlDmaSpeed::TypeB,
+                _ => AmlDmaSpeed::TypeF,
+            };
+            Ok(AmlResourceDescriptor::Dma(AmlDmaResource {
+                channel_mask,
+                bus_master: flags & 0x04 != 0,
+                transfer,
+                speed,
+            }))
+        }
+        SMALL_IO => {
+            if body.len() != 7 {
+                return Err(AmlError::invalid_bytecode());
+            }
+            Ok(AmlResourceDescriptor::Io(AmlIoResource {
+                decode16: body[0] & 0x01 != 0,
+                minimum: u16_at(body, 1)?,
+                maximum: u16_at(body, 3)?,
+                alignment: body[5],
+                length: body[6],
+            }))
+        }
+        SMALL_FIXED_IO => {
+            if body.len() != 3 {
+                return Err(AmlError::invalid_bytecode());
+            }
+            Ok(AmlResourceDescriptor::FixedIo(AmlFixedIoResource {
+                base: u16_at(body, 0)? & 0x03ff,
+                length: body[2],
+            }))
+        }
+        _ => Ok(AmlResourceDescriptor::Other(AmlRawResource {
+            tag: item,
+            data: body,
+        })),
+    }
+}
+
+fn decode_large(tag: u8, descriptor: &[u8]) -> AmlResult<AmlResourceDescriptor<'_>> {
+    let body = &descriptor[3..];
+    match tag {
+        LARGE_MEMORY32 => {
+            if body.len() != 17 {
+                return Err(AmlError::invalid_bytecode());
+            }
+            Ok(AmlResourceDescriptor::Memory32(AmlMemory32Resource {
+                writable: body[0] & 0x01 != 0,
+                minimum: u32_at(body, 1)?,
+                maximum: u32_at(body, 5)?,
+                alignment: u32_at(body, 9)?,
+                length: u32_at(body, 13)?,
+            }))
+        }
+        LARGE_FIXED_MEMORY32 => {
+            if body.len() != 9 {
+                return Err(AmlError::invalid_bytecode());
+            }
+            Ok(AmlResourceDescriptor::FixedMemory32(
+                AmlFixedMemory32Resource {
+                    writable: body[0] & 0x01 != 0,
+                    base: u32_at(body, 1)?,
+                    length: u32_at(body, 5)?,
+                },
+            ))
+        }
+        LARGE_WORD_ADDRESS => decode_address(AmlAddressWidth::Word, body),
+        LARGE_DWORD_ADDRESS => decode_address(AmlAddressWidth::DWord, body),
+        LARGE_QWORD_ADDRESS => decode_address(AmlAddressWidth::QWord, body),
+        LARGE_EXTENDED_IRQ => {
+            let flags = byte_at(body, 0)?;
+            let count = usize::from(byte_at(body, 1)?);
+            let table_end = 2 + count * 4;
+            let table = body.get(2..table_end).ok_or_else(AmlError::truncated)?;
+            Ok(AmlResourceDescriptor::ExtendedIrq(AmlExtendedIrqResource {
+                usage: usage_from_consumer_bit(flags & 0x01 != 0),
+                mode: AmlInterruptMode {
+                    trigger: trigger_from_bit(flags & 0x02 != 0),
+                    polarity: if flags & 0x04 != 0 {
+                        AmlInterruptPolarity::ActiveLow
+                    } else {
+                        AmlInterruptPolarity::ActiveHigh
+                    },
+                    sharing: AmlInterruptSharing::from_bits(flags & 0x08 != 0, flags & 0x10 != 0),
+                },
+                interrupts: AmlResourceInterruptList::new(table)?,
+                source: optional_source(&body[table_end..])?,
+            }))
+        }
+        LARGE_GPIO => decode_gpio(descriptor),
+        LARGE_SERIAL_BUS => decode_serial_bus(descriptor),
+        _ => Ok(AmlResourceDescriptor::Other(AmlRawResource {
+            tag,
+            data: body,
+        })),
+    }
+}
+
+fn decode_address(width: AmlAddressWidth, body: &[u8]) -> AmlResult<AmlResourceDescriptor<'_>> {
+    let field = width.bytes();
+    let fixed_end = 3 + 5 * field;
+    if body.len() < fixed_end {
+        return Err(AmlError::truncated());
+    }
+    let flags = body[1];
+    let value = |index: usize| uint_at(body, 3 + index * field, field);
+
+    Ok(AmlResourceDescriptor::Address(AmlAddressResource {
+        width,
+        kind: AmlAddressResourceKind::from_raw(body[0]),
+        usage: usage_from_consumer_bit(flags & 0x01 != 0),
+        subtractive_decode: flags & 0x02 != 0,
+        min_fixed: flags & 0x04 != 0,
+        max_fixed: flags & 0x08 != 0,
+        type_flags: body[2],
+        granularity: value(0)?,
+        minimum: value(1)?,
+        maximum: value(2)?,
+        translation_offset: value(3)?,
+        length: value(4)?,
+        source: optional_source(&body[fixed_end..])?,
+    }))
+}
+
+fn decode_gpio(descriptor: &[u8]) -> AmlResult<AmlResourceDescriptor<'_>> {
+    if descriptor.len() < GPIO_HEADER_BYTES {
+        return Err(AmlError::truncated());
+    }
+    let connection_flags = u16_at(descriptor, 7)?;
+    let connection = match descriptor[4] {
+        0 => AmlGpioConnection::Interrupt(AmlInterruptMode {
+            trigger: trigger_from_bit(connection_flags & 0x01 != 0),
+            polarity: match (connection_flags >> 1) & 0x03 {
+                0 => AmlInterruptPolarity::ActiveHigh,
+                1 => AmlInterruptPolarity::ActiveLow,
+                2 => AmlInterruptPolarity::ActiveBoth,
+                _ => return Err(AmlError::invalid_bytecode()),
+            },
+            sharing: AmlInterruptSharing::from_bits(
+                connection_flags & 0x08 != 0,
+                connection_flags & 0x10 != 0,
+            ),
+        }),
+        1 => AmlGpioConnection::Io {
+            restriction: match connection_flags & 0x03 {
+                0 => AmlGpioIoRestriction::None,
+                1 => AmlGpioIoRestriction::InputOnly,
+                2 => AmlGpioIoRestriction::OutputOnly,
+                _ => AmlGpioIoRestriction::Preserve,
+            },
+            shared: connection_flags & 0x08 != 0,
+        },
+        _ => return Err(AmlError::invalid_bytecode()),
+    };
+
+    let pin_offset = usize::from(u16_at(descriptor, 14)?);
+    let source_offset = usize::from(u16_at(descriptor, 17)?);
+    let vendor_offset = usize::from(u16_at(descriptor, 19)?);
+    let vendor_length = usize::from(u16_at(descriptor, 21)?);
+    let pins = descriptor
+        .get(pin_offset..source_offset)
+        .ok_or_else(AmlError::truncated)?;
+    let name_end = if vendor_length == 0 {
+        descriptor.len()
+    } else {
+        vendor_offset
+    };
+    let name = descriptor
+        .get(source_offset..name_end)
+        .ok_or_else(AmlError::truncated)?;
+    let vendor_data = if vendor_length == 0 {
+        &[][..]
+    } else {
+        descriptor
+            .get(vendor_offset..vendor_offset + vendor_length)
+            .ok_or_else(AmlError::truncated)?
+    };
+
+    Ok(AmlResourceDescriptor::Gpio(AmlGpioResource {
+        revision: descriptor[3],
+        usage: usage_from_consumer_bit(u16_at(descriptor, 5)? & 0x01 != 0),
+        connection,
+        pin_config: descriptor[9],
+        drive_strength: u16_at(descriptor, 10)?,
+        debounce_timeout: u16_at(descriptor, 12)?,
+        pins: AmlResourcePinList::new(pins)?,
+        source: AmlResourceSource {
+            index: descriptor[16],
+            name: source_name(name)?,
+        },
+        vendor_data,
+    }))
+}
+
+fn decode_serial_bus(descriptor: &[u8]) -> AmlResult<AmlResourceDescriptor<'_>> {
+    if descriptor.len() < SERIAL_BUS_HEADER_BYTES {
+        return Err(AmlError::truncated());
+    }
+    let bus_type = descriptor[5];
+    let general_flags = descriptor[6];
+    let type_flags = u16_at(descriptor, 7)?;
+    let type_data_len = usize::from(u16_at(descriptor, 10)?);
+    let type_data_end = SERIAL_BUS_HEADER_BYTES + type_data_len;
+    let type_data = descriptor
+        .get(SERIAL_BUS_HEADER_BYTES..type_data_end)
+        .ok_or_else(AmlError::truncated)?;
+    let fixed = match bus_type {
+        SERIAL_BUS_I2C => I2C_TYPE_DATA_BYTES,
+        SERIAL_BUS_SPI => SPI_TYPE_DATA_BYTES,
+        SERIAL_BUS_UART => UART_TYPE_DATA_BYTES,
+        _ => {
+            return Ok(AmlResourceDescriptor::Other(AmlRawResource {
+                tag: LARGE_SERIAL_BUS,
+                data: &descriptor[3..],
+            }));
+        }
+    };
+    if type_data.len() < fixed {
+        return Err(AmlError::invalid_bytecode());
+    }
+    let common = AmlSerialBusCommon {
+        revision: descriptor[3],
+        type_revision: descriptor[9],
+        usage: usage_from_consumer_bit(general_flags & 0x02 != 0),
+        device_initiated: general_flags & 0x01 != 0,
+        shared: general_flags & 0x04 != 0,
+        source: AmlResourceSource {
+            index: descriptor[4],
+            name: source_name(&descriptor[type_data_end..])?,
+        },
+        vendor_data: &type_data[fixed..],
+    };
+
+    Ok(match bus_type {
+        SERIAL_BUS_I2C => AmlResourceDescriptor::I2c(AmlI2cResource {
+            common,
+            ten_bit_addressing: type_flags & 0x01 != 0,
+            speed_hz: u32_at(type_data, 0)?,
+            address: u16_at(type_data, 4)?,
+        }),
+        SERIAL_BUS_SPI => AmlResourceDescriptor::Spi(AmlSpiResource {
+            common,
+            three_wire: type_flags & 0x01 != 0,
+            chip_select_active_high: type_flags & 0x02 != 0,
+            speed_hz: u32_at(type_data, 0)?,
+            data_bits: type_data[4],
+            clock_phase: type_data[5],
+            clock_polarity: type_data[6],
+            chip_select: u16_at(type_data, 7)?,
+        }),
+        _ => AmlResourceDescriptor::Uart(AmlUartResource {
+            common,
+            flow_control: match type_flags & 0x03 {
+                0 => AmlUartFlowControl::None,
+                1 => AmlUartFlowControl::Hardware,
+                2 => AmlUartFlowControl::XonXoff,
+                _ => return Err(AmlError::invalid_bytecode()),
+            },
+            stop_bits: match (type_flags >> 2) & 0x03 {
+                0 => AmlUartStopBits::None,
+                1 => AmlUartStopBits::One,
+                2 => AmlUartStopBits::OnePointFive,
+                _ => AmlUartStopBits::Two,
+            },
+            data_bits: match (type_flags >> 4) & 0x07 {
+                0 => 5,
+                1 => 6,
+                2 => 7,
+                3 => 8,
+                4 => 9,
+                _ => return Err(AmlError::invalid_bytecode()),
+            },
+            big_endian: type_flags & 0x80 != 0,
+            baud_rate: u32_at(type_data, 0)?,
+            rx_fifo: u16_at(type_data, 4)?,
+            tx_fifo: u16_at(type_data, 6)?,
+            parity: AmlUartParity::from_raw(type_data[8]),
+            lines_enabled: type_data[9],
+        }),
+    })
+}
+
+const fn trigger_from_bit(edge: bool) -> AmlInterruptTrigger {
+    if edge {
+        AmlInterruptTrigger::Edge
+    } else {
+        AmlInterruptTrigger::Level
+    }
+}
+
+const fn usage_from_consumer_bit(consumer: bool) -> AmlResourceUsage {
+    if consumer {
+        AmlResourceUsage::Consumer
+    } else {
+        AmlResourceUsage::Producer
+    }
+}
+
+fn optional_source(tail: &[u8]) -> AmlResult<Option<AmlResourceSource<'_>>> {
+    let Some((&index, name)) = tail.split_first() else {
+        return Ok(None);
+    };
+    Ok(Some(AmlResourceSource {
+        index,
+        name: source_name(name)?,
+    }))
+}
+
+fn source_name(bytes: &[u8]) -> AmlResult<&str> {
+    let end = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(bytes.len());
+    core::str::from_utf8(&bytes[..end]).map_err(|_| AmlError::invalid_bytecode())
+}
+
+fn byte_at(bytes: &[u8], offset: usize) -> AmlResult<u8> {
+    bytes.get(offset).copied().ok_or_else(AmlError::truncated)
+}
+
+fn u16_at(bytes: &[u8], offset: usize) -> AmlResult<u16> {
+    let raw = bytes
+        .get(offset..offset + 2)
+        .ok_or_else(AmlError::truncated)?;
+    Ok(u16::from_le_bytes([raw[0], raw[1]]))
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> AmlResult<u32> {
+    let raw = bytes
+        .get(offset..offset + 4)
+        .ok_or_else(AmlError::truncated)?;
+    Ok(u32::from_le_bytes([raw[0], raw[1], raw[2], raw[3]]))
+}
+
+fn uint_at(bytes: &[u8], offset: usize, width: usize) -> AmlResult<u64> {
+    let raw = bytes
+        .get(offset..offset + width)
+        .ok_or_else(AmlError::truncated)?;
+    let mut value = [0_u8; 8];
+    value[..width].copy_from_slice(raw);
+    Ok(u64::from_le_bytes(value))
+}
+
+/// Bounded writer that encodes resource descriptors into one `_SRS` template.
+#[derive(Debug)]
+pub struct AmlResourceTemplateWriter<'out> {
+    out: &'out mut [u8],
+    len: usize,
+}
+
+impl<'out> AmlResourceTemplateWriter<'out> {
+    #[must_use]
+    pub const fn new(out: &'out mut [u8]) -> Self {
+        Self { out, len: 0 }
+    }
+
+    /// Appends one descriptor in its canonical encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns `overflow` when the output buffer is too small or a variable-length field does
+    /// not fit its length field, and `invalid_bytecode` when a field cannot be represented (for
+    /// example an `ActiveBoth` polarity on a non-GPIO interrupt).
+    pub fn push(&mut self, descriptor: &AmlResourceDescriptor<'_>) -> AmlResult<()> {
+        match *descriptor {
+            AmlResourceDescriptor::Irq(irq) => self.push_irq(irq),
+            AmlResourceDescriptor::Dma(dma) => {
+                let transfer = match dma.transfer {
+                    AmlDmaTransferWidth::Bits8 => 0,
+                    AmlDmaTransferWidth::Bits8And16 => 1,
+                    AmlDmaTransferWidth::Bits16 => 2,
+                };
+                let speed = match dma.speed {
+                    AmlDmaSpeed::Compatibility => 0,
+                    AmlDmaSpeed::TypeA => 1,
+                    AmlDmaSpeed::TypeB => 2,
+                    AmlDmaSpeed::TypeF => 3,
+                };
+                self.put(&[
+                    small_tag(SMALL_DMA, 2),
+                    dma.channel_mask,
+                    transfer | (u8::from(dma.bus_master) << 2) | (speed << 5),
+                ])
+            }
+            AmlResourceDescriptor::Io(io) => {
+                self.put(&[small_tag(SMALL_IO, 7), u8::from(io.decode16)])?;
+                self.put(&io.minimum.to_le_bytes())?;
+                self.put(&io.maximum.to_le_bytes())?;
+                self.put(&[io.alignment, io.length])
+            }
+            AmlResourceDescriptor::FixedIo(io) => {
+                self.put(&[small_tag(SMALL_FIXED_IO, 3)])?;
+                self.put(&(io.base & 0x03ff).to_le_bytes())?;
+                self.put(&[io.length])
+            }
+            AmlResourceDescriptor::Memory32(memory) => {
+                let start = self.begin_large(LARGE_MEMORY32)?;
+                self.put(&[u8::from(memory.writable)])?;
+                for value in [
+                    memory.minimum,
+                    memory.maximum,
+                    memory.alignment,
+                    memory.length,
+                ] {
+                    self.put(&value.to_le_bytes())?;
+                }
+                self.end_large(start)
+            }
+            AmlResourceDescriptor::FixedMemory32(memory) => {
+                let start = self.begin_large(LARGE_FIXED_MEMORY32)?;
+                self.put(&[u8::from(memory.writable)])?;
+                self.put(&memory.base.to_le_bytes())?;
+                self.put(&memory.length.to_le_bytes())?;
+                self.end_large(start)
+            }
+            AmlResourceDescriptor::Address(address) => self.push_address(&address),
+            AmlResourceDescriptor::ExtendedIrq(irq) => self.push_extended_irq(&irq),
+            AmlResourceDescriptor::Gpio(gpio) => self.push_gpio(&gpio),
+            AmlResourceDescriptor::I2c(i2c) => {
+                let mut data = [0_u8; I2C_TYPE_DATA_BYTES];
+                data[..4].copy_from_slice(&i2c.speed_hz.to_le_bytes());
+                data[4..].copy_from_slice(&i2c.address.to_le_bytes());
+                self.push_serial_bus(
+                    SERIAL_BUS_I2C,
+                    &i2c.common,
+                    u16::from(i2c.ten_bit_addressing),
+                    &data,
+                )
+            }
+            AmlResourceDescriptor::Spi(spi) => {
+                let mut data = [0_u8; SPI_TYPE_DATA_BYTES];
+                data[..4].copy_from_slice(&spi.speed_hz.to_le_bytes());
+                data[4] = spi.data_bits;
+                data[5] = spi.clock_phase;
+                data[6] = spi.clock_polarity;
+                data[7..].copy_from_slice(&spi.chip_select.to_le_bytes());
+                let flags =
+                    u16::from(spi.three_wire) | (u16::from(spi.chip_select_active_high) << 1);
+                self.push_serial_bus(SERIAL_BUS_SPI, &spi.common, flags, &data)
+            }
+            AmlResourceDescriptor::Uart(uart) => self.push_uart(&uart),
+            AmlResourceDescriptor::Other(raw) => {
+                if raw.tag & 0x80 == 0 {
+                    let len = u8::try_from(raw.data.len())
+                        .ok()
+                        .filter(|len| *len <= 7)
+                        .ok_or_else(AmlError::overflow)?;
+                    self.put(&[small_tag(raw.tag, len)])?;
+                    self.put(raw.data)
+                } else {
+                    let start = self.begin_large(raw.tag)?;
+                    self.put(raw.data)?;
+                    self.end_large(start)
+                }
+            }
+        }
+    }
+
+    /// Appends every descriptor of one decoded template, for example to echo `_CRS` into `_SRS`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any [`Self::push`] error.
+    pub fn push_template(&mut self, template: AmlResourceTemplate<'_>) -> AmlResult<()> {
+        for descriptor in template {
+            self.push(&descriptor)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the checksummed end tag and returns the finished template bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `overflow` when the end tag does not fit.
+    pub fn finish(mut self) -> AmlResult<&'out [u8]> {
+        self.put(&[small_tag(SMALL_END_TAG, 1), 0])?;
+        let sum = self.out[..self.len]
+            .iter()
+            .fold(0_u8, |sum, byte| sum.wrapping_add(*byte));
+        self.out[self.len - 1] = sum.wrapping_neg();
+        let len = self.len;
+        Ok(&self.out[..len])
+    }
+
+    /// Finishes the template into one fresh runtime buffer object, ready to pass to `_SRS`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `overflow` when the template exceeds one runtime buffer object, plus any
+    /// [`Self::finish`] or runtime-buffer allocation error.
+    pub fn finish_into_runtime_buffer(
+        self,
+        state: &AmlRuntimeState<'_>,
+    ) -> AmlResult<AmlRuntimeBufferHandle> {
+        let bytes = self.finish()?;
+        if bytes.len() > AML_MAX_BUFFER_BYTES {
+            return Err(AmlError::overflow());
+        }
+        let len = u8::try_from(bytes.len()).map_err(|_| AmlError::overflow())?;
+        let handle = state.create_buffer(len)?;
+        state.copy_bytes_into_buffer(handle, bytes)?;
+        Ok(handle)
+    }
+
+    fn put(&mut self, bytes: &[u8]) -> AmlResult<()> {
+        let end = self
+            .len
+            .checked_add(bytes.len())
+            .ok_or_else(AmlError::overflow)?;
+        self.out
+            .get_mut(self.len..end)
+            .ok_or_else(AmlError::overflow)?
+            .copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+
+    fn begin_large(&mut self, tag: u8) -> AmlResult<usize> {
+        let start = self.len;
+        self.put(&[tag, 0, 0])?;
+        Ok(start)
+    }
+
+    fn end_large(&mut self, start: usize) -> AmlResult<()> {
+        let length = u16::try_from(self.len - start - 3).map_err(|_| AmlError::overflow())?;
+        self.out[start + 1..start + 3].copy_from_slice(&length.to_le_bytes());
+        Ok(())
+    }
+
+    fn put_source(&mut self, source: AmlResourceSource<'_>) -> AmlResult<()> {
+        self.put(&[source.index])?;
+        self.put(source.name.as_bytes())?;
+        self.put(&[0])
+    }
+
+    fn push_irq(&mut self, irq: AmlIrqResource) -> AmlResult<()> {
+        let Some(mode) = irq.mode else {
+            self.put(&[small_tag(SMALL_IRQ, 2)])?;
+            return self.put(&irq.mask.to_le_bytes());
+        };
+        let active_low = match mode.polarity {
+            AmlInterruptPolarity::ActiveHigh => false,
+            AmlInterruptPolarity::ActiveLow => true,
+            AmlInterruptPolarity::ActiveBoth => return Err(AmlError::invalid_bytecode()),
+        };
+        let info = u8::from(mode.trigger == AmlInterruptTrigger::Edge)
+            | (u8::from(active_low) << 3)
+            | (u8::from(mode.sharing.shared()) << 4)
+            | (u8::from(mode.sharing.wake()) << 5);
+        self.put(&[small_tag(SMALL_IRQ, 3)])?;
+        self.put(&irq.mask.to_le_bytes())?;
+        self.put(&[info])
+    }
+
+    fn push_address(&mut self, address: &AmlAddressResource<'_>) -> AmlResult<()> {
+        let width = address.width.bytes();
+        let flags = u8::from(address.usage == AmlResourceUsage::Consumer)
+            | (u8::from(address.subtractive_decode) << 1)
+            | (u8::from(address.min_fixed) << 2)
+            | (u8::from(address.max_fixed) << 3);
+        let start = self.begin_large(address.width.tag())?;
+        self.put(&[address.kind.raw(), flags, address.type_flags])?;
+        for value in [
+            address.granularity,
+            address.minimum,
+            address.maximum,
+            address.translation_offset,
+            address.length,
+        ] {
+            if width < 8 && value >> (width * 8) != 0 {
+                return Err(AmlError::overflow());
+            }
+            self.put(&value.to_le_bytes()[..width])?;
+        }
+        if let Some(source) = address.source {
+            self.put_source(source)?;
+        }
+        self.end_large(start)
+    }
+
+    fn push_extended_irq(&mut self, irq: &AmlExtendedIrqResource<'_>) -> AmlResult<()> {
+        let active_low = match irq.mode.polarity {
+            AmlInterruptPolarity::ActiveHigh => false,
+            AmlInterruptPolarity::ActiveLow => true,
+            AmlInterruptPolarity::ActiveBoth => return Err(AmlError::invalid_bytecode()),
+        };
+        let flags = u8::from(irq.usage == AmlResourceUsage::Consumer)
+            | (u8::from(irq.mode.trigger == AmlInterruptTrigger::Edge) << 1)
+            | (u8::from(active_low) << 2)
+            | (u8::from(irq.mode.sharing.shared()) << 3)
+            | (u8::from(irq.mode.sharing.wake()) << 4);
+        let count = u8::try_from(irq.interrupts.len()).map_err(|_| AmlError::overflow())?;
+        let start = self.begin_large(LARGE_EXTENDED_IRQ)?;
+        self.put(&[flags, count])?;
+        self.put(irq.interrupts.as_bytes())?;
+        if let Some(source) = irq.source {
+            self.put_source(source)?;
+        }
+        self.end_large(start)
+    }
+
+    fn push_gpio(&mut self, gpio: &AmlGpioResource<'_>) -> AmlResult<()> {
+        let (connection_type, connection_flags) = match gpio.connection {
+            AmlGpioConnection::Interrupt(mode) => {
+                let polarity = match mode.polarity {
+                    AmlInterruptPolarity::ActiveHigh => 0,
+                    AmlInterruptPolarity::ActiveLow => 1,
+                    AmlInterruptPolarity::ActiveBoth => 2,
+                };
+                (
+                    0_u8,
+                    u16::from(mode.trigger == AmlInterruptTrigger::Edge)
+                        | (polarity << 1)
+                        | (u16::from(mode.sharing.shared()) << 3)
+                        | (u16::from(mode.sharing.wake()) << 4),
+                )
+            }
+            AmlGpioConnection::Io {
+                restriction,
+                shared,
+            } => {
+                let restriction = match restriction {
+                    AmlGpioIoRestriction::None => 0,
+                    AmlGpioIoRestriction::InputOnly => 1,
+                    AmlGpioIoRestriction::OutputOnly => 2,
+                    AmlGpioIoRestriction::Preserve => 3,
+                };
+                (1_u8, restriction | (u16::from(shared) << 3))
+            }
+        };
+        let offset = |value: usize| u16::try_from(value).map_err(|_| AmlError::overflow());
+        let source_offset = GPIO_HEADER_BYTES + gpio.pins.as_bytes().len();
+        let vendor_offset = source_offset + gpio.source.name.len() + 1;
+        let vendor_length = offset(gpio.vendor_data.len())?;
+
+        let start = self.begin_large(LARGE_GPIO)?;
+        self.put(&[gpio.revision, connection_type])?;
+        self.put(&u16::from(gpio.usage == AmlResourceUsage::Consumer).to_le_bytes())?;
+        self.put(&connection_flags.to_le_bytes())?;
+        self.put(&[gpio.pin_config])?;
+        self.put(&gpio.drive_strength.to_le_bytes())?;
+        self.put(&gpio.debounce_timeout.to_le_bytes())?;
+        self.put(&offset(GPIO_HEADER_BYTES)?.to_le_bytes())?;
+        self.put(&[gpio.source.index])?;
+        self.put(&offset(source_offset)?.to_le_bytes())?;
+        let vendor_offset = if vendor_length == 0 {
+            0
+        } else {
+            offset(vendor_offset)?
+        };
+        self.put(&vendor_offset.to_le_bytes())?;
+        self.put(&vendor_length.to_le_bytes())?;
+        self.put(gpio.pins.as_bytes())?;
+        self.put(gpio.source.name.as_bytes())?;
+        self.put(&[0])?;
+        self.put(gpio.vendor_data)?;
+        self.end_large(start)
+    }
+
+    fn push_uart(&mut self, uart: &AmlUartResource<'_>) -> AmlResult<()> {
+        if !(5..=9).contains(&uart.data_bits) {
+            return Err(AmlError::invalid_bytecode());
+        }
+        let flow = match uart.flow_control {
+            AmlUartFlowControl::None => 0,
+            AmlUartFlowControl::Hardware => 1,
+            AmlUartFlowControl::XonXoff => 2,
+        };
+        let stop = match uart.stop_bits {
+            AmlUartStopBits::None => 0,
+            AmlUartStopBits::One => 1,
+            AmlUartStopBits::OnePointFive => 2,
+            AmlUartStopBits::Two => 3,
+        };
+        let flags = flow
+            | (stop << 2)
+            | (u16::from(uart.data_bits - 5) << 4)
+            | (u16::from(uart.big_endian) << 7);
+        let mut data = [0_u8; UART_TYPE_DATA_BYTES];
+        data[..4].copy_from_slice(&uart.baud_rate.to_le_bytes());
+        data[4..6].copy_from_slice(&uart.rx_fifo.to_le_bytes());
+        data[6..8].copy_from_slice(&uart.tx_fifo.to_le_bytes());
+        data[8] = uart.parity.raw();
+        data[9] = uart.lines_enabled;
+        self.push_serial_bus(SERIAL_BUS_UART, &uart.common, flags, &data)
+    }
+
+    fn push_serial_bus(
+        &mut self,
+        bus_type: u8,
+        common: &AmlSerialBusCommon<'_>,
+        type_flags: u16,
+        type_data: &[u8],
+    ) -> AmlResult<()> {
+        let general_flags = u8::from(common.device_initiated)
+            | (u8::from(common.usage == AmlResourceUsage::Consumer) << 1)
+            | (u8::from(common.shared) << 2);
+        let type_data_len = u16::try_from(type_data.len() + common.vendor_data.len())
+            .map_err(|_| AmlError::overflow())?;
+
+        let start = self.begin_large(LARGE_SERIAL_BUS)?;
+        self.put(&[
+            common.revision,
+            common.source.index,
+            bus_type,
+            general_flags,
+        ])?;
+        self.put(&type_flags.to_le_bytes())?;
+        self.put(&[common.type_revision])?;
+        self.put(&type_data_len.to_le_bytes())?;
+        self.put(type_data)?;
+        self.put(common.vendor_data)?;
+        self.put(common.source.name.as_bytes())?;
+        self.put(&[0])?;
+        self.end_large(start)
+    }
+}
+
+const fn small_tag(item: u8, len: u8) -> u8 {
+    (item << 3) | (len & 0x07)
+}
+
+/// PCI interrupt pin named by one `_PRT` entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlPciInterruptPin {
+    IntA,
+    IntB,
+    IntC,
+    IntD,
+}
+
+/// Interrupt source of one `_PRT` entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlPciInterruptSource {
+    /// Hard-wired global system interrupt.
+    Gsi(u32),
+    /// Interrupt link device (`PNP0C0F`) plus the resource index within its `_CRS`.
+    Link {
+        path: AmlResolvedNamePath,
+        index: u32,
+    },
+}
+
+/// One decoded `_PRT` routing entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlPciRoutingEntry {
+    /// `_ADR`-style address; the function word is `0xFFFF` (any function).
+    pub address: u32,
+    pub pin: AmlPciInterruptPin,
+    pub source: AmlPciInterruptSource,
+}
+
+impl AmlPciRoutingEntry {
+    /// PCI device number this entry routes.
+    #[must_use]
+    pub const fn device(self) -> u16 {
+        (self.address >> 16) as u16
+    }
+}
+
+/// Evaluates one `_PRT` object and decodes its routing entries into `out`.
+///
+/// Link sources are resolved against the scope that owns the `_PRT` object, applying the
+/// usual single-segment upward search.
+///
+/// # Errors
+///
+/// Returns any evaluation error, `unsupported` when the table is not a static package,
+/// `invalid_bytecode` for malformed entries, and `overflow` when `out` is too small.
+pub fn decode_pci_routing_table<'out>(
+    namespace: AmlLoadedNamespace<'_, '_>,
+    host: &dyn AmlRegionAccessHost,
+    state: &AmlRuntimeState<'_>,
+    prt: AmlNamespaceNodeId,
+    out: &'out mut [MaybeUninit<AmlPciRoutingEntry>],
+) -> AmlResult<&'out [AmlPciRoutingEntry]> {
+    let record = namespace
+        .record(prt)
+        .ok_or_else(AmlError::undefined_object)?;
+    let scope = record
+        .descriptor
+        .path
+        .parent()
+        .ok_or_else(AmlError::invalid_namespace)?;
+    let outcome = AmlPureEvaluator::new(namespace).evaluate_object_with_host_and_state(
+        host,
+        state,
+        prt,
+        AmlExecutionPhase::Runtime,
+    )?;
+    if outcome.blocked {
+        return Err(AmlError::invalid_state());
+    }
+    let Some(AmlValue::StaticPackage(location)) = outcome.return_value else {
+        return Err(AmlError::unsupported());
+    };
+    let (entries, _) = static_package(namespace, location)?;
+
+    let mut cursor = entries;
+    let mut written = 0_usize;
+    while !cursor.elements.is_empty() && cursor.remaining != 0 {
+        let (entry, consumed) = decode_routing_entry(namespace, scope, cursor.elements)?;
+        out.get_mut(written)
+            .ok_or_else(AmlError::overflow)?
+            .write(entry);
+        written += 1;
+        cursor.elements = &cursor.elements[consumed..];
+        cursor.remaining -= 1;
+    }
+    if cursor.remaining != 0 {
+        return Err(AmlError::truncated());
+    }
+
+    // SAFETY: the first `written` slots were initialized above.
+    Ok(unsafe { core::slice::from_raw_parts(out.as_ptr().cast::<AmlPciRoutingEntry>(), written) })
+}
+
+/// Element bytes and declared element count of one static package.
+#[derive(Clone, Copy)]
+struct AmlStaticPackageElements<'a> {
+    elements: &'a [u8],
+    remaining: u8,
+}
+
+fn static_package<'blocks>(
+    namespace: AmlLoadedNamespace<'_, 'blocks>,
+    location: AmlCodeLocation,
+) -> AmlResult<(AmlStaticPackageElements<'blocks>, usize)> {
+    let bytes = namespace
+        .code_bytes(location)
+        .ok_or_else(AmlError::invalid_state)?;
+    package_elements(bytes)
+}
+
+fn package_elements(bytes: &[u8]) -> AmlResult<(AmlStaticPackageElements<'_>, usize)> {
+    if bytes.first() != Some(&0x12) {
+        return Err(AmlError::invalid_bytecode());
+    }
+    let pkg = AmlPkgLength::parse(&bytes[1..])?;
+    let end = 1 + pkg.value as usize;
+    let count_offset = 1 + usize::from(pkg.encoded_bytes);
+    let package = bytes.get(..end).ok_or_else(AmlError::truncated)?;
+    let remaining = byte_at(package, count_offset)?;
+    Ok((
+        AmlStaticPackageElements {
+            elements: &package[count_offset + 1..],
+            remaining,
+        },
+        end,
+    ))
+}
+
+fn decode_routing_entry(
+    namespace: AmlLoadedNamespace<'_, '_>,
+    scope: AmlResolvedNamePath,
+    bytes: &[u8],
+) -> AmlResult<(AmlPciRoutingEntry, usize)> {
+    let (fields, consumed) = package_elements(bytes)?;
+    if fields.remaining != 4 {
+        return Err(AmlError::invalid_bytecode());
+    }
+    let mut cursor = fields.elements;
+    let (address, used) = static_integer(cursor)?;
+    cursor = &cursor[used..];
+    let (pin, used) = static_integer(cursor)?;
+    cursor = &cursor[used..];
+
+    // The source is either a NameString naming a link device or the constant zero.
+    let link = if matches!(
+        cursor.first(),
+        Some(b'\\' | b'^' | b'_' | b'.' | b'/' | b'A'..=b'Z')
+    ) {
+        let encoded = AmlEncodedNameString::parse(cursor)?;
+        cursor = &cursor[usize::from(encoded.consumed_bytes)..];
+        Some(namespace.resolve_lookup_path(scope, encoded)?)
+    } else {
+        let (value, used) = static_integer(cursor)?;
+        cursor = &cursor[used..];
+        if value != 0 {
+            return Err(AmlError::invalid_bytecode());
+        }
+        None
+    };
+    let (index, _) = static_integer(cursor)?;
+    let index = u32::try_from(index).map_err(|_| AmlError::invalid_bytecode())?;
+
+    let entry = AmlPciRoutingEntry {
+        address: u32::try_from(address).map_err(|_| AmlError::invalid_bytecode())?,
+        pin: match pin {
+            0 => AmlPciInterruptPin::IntA,
+            1 => AmlPciInterruptPin::IntB,
+            2 => AmlPciInterruptPin::IntC,
+            3 => AmlPciInterruptPin::IntD,
+            _ => return Err(AmlError::invalid_bytecode()),
+        },
+        source: link.map_or(AmlPciInterruptSource::Gsi(index), |path| {
+            AmlPciInterruptSource::Link { path, index }
+        }),
+    };
+    Ok((entry, consumed))
+}
+
+fn static_integer(bytes: &[u8]) -> AmlResult<(u64, usize)> {
+    match byte_at(bytes, 0)? {
+        0x00 => Ok((0, 1)),
+        0x01 => Ok((1, 1)),
+        0xff => Ok((u64::MAX, 1)),
+        0x0a => Ok((u64::from(byte_at(bytes, 1)?), 2)),
+        0x0b => Ok((u64::from(u16_at(bytes, 1)?), 3)),
+        0x0c => Ok((u64::from(u32_at(bytes, 1)?), 5)),
+        0x0e => Ok((uint_at(bytes, 1, 8)?, 9)),
+        _ => Err(AmlError::unsupported()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aml::{
+        AmlAccessWidth,
+        AmlDefinitionBlock,
+        AmlDefinitionBlockSet,
+        AmlEmbeddedControllerHost,
+        AmlErrorKind,
+        AmlHost,
+        AmlNameSeg,
+        AmlNamespaceLoadPlan,
+        AmlNamespaceLoadRecord,
+        AmlNotifySink,
+        AmlOspmInterface,
+        AmlPciConfigHost,
+        AmlRuntimeBufferSlot,
+        AmlRuntimeIntegerSlot,
+        AmlSleepHost,
+        AmlSystemIoHost,
+        AmlSystemMemoryHost,
+        AmlTableHost,
+        AmlTraceHost,
+    };
+    use crate::pal::hal::acpi::Dsdt;
+    use core::cell::Cell;
+    use std::boxed::Box;
+    use std::vec::Vec;
+
+    /// Hand-assembled template covering every modelled descriptor plus one vendor-short item.
+    fn sample_template() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        // IRQ (1) {Edge, ActiveHigh, Exclusive} and IRQNoFlags (12).
+        bytes.extend_from_slice(&[0x23, 0x02, 0x00, 0x01]);
+        bytes.extend_from_slice(&[0x22, 0x00, 0x10]);
+        // DMA (TypeF, BusMaster, Transfer8_16) {2}.
+        bytes.extend_from_slice(&[0x2a, 0x04, 0x65]);
+        // IO (Decode16, 0x62, 0x62, 1, 1) and FixedIO (0x66, 1).
+        bytes.extend_from_slice(&[0x47, 0x01, 0x62, 0x00, 0x62, 0x00, 0x01, 0x01]);
+        bytes.extend_from_slice(&[0x4b, 0x66, 0x00, 0x01]);
+        // Memory32Fixed (ReadWrite, 0xFED00000, 0x400).
+        bytes.extend_from_slice(&[0x86, 0x09, 0x00, 0x01]);
+        bytes.extend_from_slice(&0xfed0_0000_u32.to_le_bytes());
+        bytes.extend_from_slice(&0x400_u32.to_le_bytes());
+        // Memory32 (ReadOnly, 0x1000, 0x2000, 0x100, 0x1000).
+        bytes.extend_from_slice(&[0x85, 0x11, 0x00, 0x00]);
+        for value in [0x1000_u32, 0x2000, 0x100, 0x1000] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        // DWordMemory (ResourceProducer, MinFixed, MaxFixed, Prefetchable).
+        bytes.extend_from_slice(&[0x87, 0x17, 0x00, 0x00, 0x0c, 0x03]);
+        for value in [0_u32, 0xc000_0000, 0xdfff_ffff, 0, 0x2000_0000] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        // WordBusNumber (ResourceProducer, MinFixed, MaxFixed, 0x00..0xFF).
+        bytes.extend_from_slice(&[0x88, 0x0d, 0x00, 0x02, 0x0c, 0x00]);
+        for value in [0_u16, 0, 0xff, 0, 0x100] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        // QWordMemory consumer with resource source "PCI0".
+        bytes.extend_from_slice(&[0x8a, 0x31, 0x00, 0x00, 0x01, 0x01]);
+        for value in [0_u64, 0x10_0000_0000, 0x1f_ffff_ffff, 0, 0x10_0000_0000] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes.extend_from_slice(b"\x00PCI0\x00");
+        // Interrupt (ResourceConsumer, Level, ActiveLow, Shared) {16, 17}.
+        bytes.extend_from_slice(&[0x89, 0x0a, 0x00, 0x0d, 0x02]);
+        bytes.extend_from_slice(&16_u32.to_le_bytes());
+        bytes.extend_from_slice(&17_u32.to_le_bytes());
+        // GpioInt (Edge, ActiveLow, ExclusiveAndWake, PullUp, 10000, "\_SB.GPO0") {21}.
+        bytes.extend_from_slice(&[0x8c, 0x20, 0x00, 0x01, 0x00, 0x01, 0x00, 0x13, 0x00, 0x01]);
+        bytes.extend_from_slice(&[0x00, 0x00, 0x10, 0x27, 0x17, 0x00, 0x00, 0x19, 0x00]);
+        bytes.extend_from_slice(&[0x00, 0x00, 0x00, 0x00, 0x15, 0x00]);
+        bytes.extend_from_slice(b"\\_SB.GPO0\x00");
+        // I2cSerialBusV2 (0x1C, ControllerInitiated, 400000, AddressingMode7Bit, "\_SB.I2C1").
+        bytes.extend_from_slice(&[0x8e, 0x19, 0x00, 0x02, 0x00, 0x01, 0x02, 0x00, 0x00, 0x01]);
+        bytes.extend_from_slice(&[0x06, 0x00, 0x80, 0x1a, 0x06, 0x00, 0x1c, 0x00]);
+        bytes.extend_from_slice(b"\\_SB.I2C1\x00");
+        // SpiSerialBusV2 (0, PolarityHigh, FourWireMode, 8, 1000000, ClockPolarityHigh,
+        // ClockPhaseSecond, "SPI1").
+        bytes.extend_from_slice(&[0x8e, 0x17, 0x00, 0x02, 0x00, 0x02, 0x02, 0x02, 0x00, 0x01]);
+        bytes.extend_from_slice(&[0x09, 0x00, 0x40, 0x42, 0x0f, 0x00, 0x08, 0x01, 0x01]);
+        bytes.extend_from_slice(&[0x00, 0x00]);
+        bytes.extend_from_slice(b"SPI1\x00");
+        // UartSerialBusV2 (115200, DataBitsEight, StopBitsOne, 0xC0, LittleEndian, ParityTypeNone,
+        // FlowControlHardware, 64, 64, "URT0").
+        bytes.extend_from_slice(&[0x8e, 0x18, 0x00, 0x02, 0x00, 0x03, 0x02, 0x35, 0x00, 0x01]);
+        bytes.extend_from_slice(&[0x0a, 0x00, 0x00, 0xc2, 0x01, 0x00, 0x40, 0x00, 0x40, 0x00]);
+        bytes.extend_from_slice(&[0x00, 0xc0]);
+        bytes.extend_from_slice(b"URT0\x00");
+        // Vendor-short item, kept verbatim.
+        bytes.extend_from_slice(&[0x71, 0xaa]);
+        bytes.push(0x79);
+        let checksum = bytes
+            .iter()
+            .fold(0_u8, |sum, byte| sum.wrapping_add(*byte))
+            .wrapping_neg();
+        bytes.push(checksum);
+        bytes
+    }
+
+    fn check_legacy_descriptors(descriptors: &[AmlResourceDescriptor<'_>]) {
+        assert_eq!(
+            descriptors[0],
+            AmlResourceDescriptor::Irq(AmlIrqResource {
+                mask: 0x0002,
+                mode: Some(AmlInterruptMode::ISA_DEFAULT),
+            })
+        );
+        assert_eq!(
+            descriptors[1],
+            AmlResourceDescriptor::Irq(AmlIrqResource {
+                mask: 0x1000,
+                mode: None,
+            })
+        );
+        assert_eq!(
+            descriptors[2],
+            AmlResourceDescriptor::Dma(AmlDmaResource {
+                channel_mask: 0x04,
+                bus_master: true,
+                transfer: AmlDmaTransferWidth::Bits8And16,
+                speed: AmlDmaSpeed::TypeF,
+            })
+        );
+        assert_eq!(
+            descriptors[3],
+            AmlResourceDescriptor::Io(AmlIoResource {
+                decode16: true,
+                minimum: 0x62,
+                maximum: 0x62,
+                alignment: 1,
+                length: 1,
+            })
+        );
+        assert_eq!(
+            descriptors[4],
+            AmlResourceDescriptor::FixedIo(AmlFixedIoResource {
+                base: 0x66,
+                length: 1,
+            })
+        );
+        assert_eq!(
+            descriptors[5],
+            AmlResourceDescriptor::FixedMemory32(AmlFixedMemory32Resource {
+                writable: true,
+                base: 0xfed0_0000,
+                length: 0x400,
+            })
+        );
+        assert_eq!(
+            descriptors[6],
+            AmlResourceDescriptor::Memory32(AmlMemory32Resource {
+                writable: false,
+                minimum: 0x1000,
+                maximum: 0x2000,
+                alignment: 0x100,
+                length: 0x1000,
+            })
+        );
+    }
+
+    fn check_address_and_interrupt_descriptors(descriptors: &[AmlResourceDescriptor<'_>]) {
+        let AmlResourceDescriptor::Address(dword) = descriptors[7] else {
+            panic!("expected DWord address descriptor");
+        };
+        assert_eq!(dword.width, AmlAddressWidth::DWord);
+        assert_eq!(dword.kind, AmlAddressResourceKind::Memory);
+        assert_eq!(dword.usage, AmlResourceUsage::Producer);
+        assert!(dword.min_fixed && dword.max_fixed && !dword.subtractive_decode);
+        assert_eq!(dword.type_flags, 0x03);
+        assert_eq!((dword.minimum, dword.length), (0xc000_0000, 0x2000_0000));
+        assert_eq!(dword.source, None);
+
+        let AmlResourceDescriptor::Address(bus) = descriptors[8] else {
+            panic!("expected Word address descriptor");
+        };
+        assert_eq!(bus.width, AmlAddressWidth::Word);
+        assert_eq!(bus.kind, AmlAddressResourceKind::BusNumber);
+        assert_eq!((bus.minimum, bus.maximum, bus.length), (0, 0xff, 0x100));
+
+        let AmlResourceDescriptor::Address(qword) = descriptors[9] else {
+            panic!("expected QWord address descriptor");
+        };
+        assert_eq!(qword.width, AmlAddressWidth::QWord);
+        assert_eq!(qword.usage, AmlResourceUsage::Consumer);
+        assert_eq!(qword.maximum, 0x1f_ffff_ffff);
+        assert_eq!(
+            qword.source,
+            Some(AmlResourceSource {
+                index: 0,
+                name: "PCI0",
+            })
+        );
+
+        let AmlResourceDescriptor::ExtendedIrq(irq) = descriptors[10] else {
+            panic!("expected extended interrupt descriptor");
+        };
+        assert_eq!(irq.usage, AmlResourceUsage::Consumer);
+        assert_eq!(
+            irq.mode,
+            AmlInterruptMode {
+                trigger: AmlInterruptTrigger::Level,
+                polarity: AmlInterruptPolarity::ActiveLow,
+                sharing: AmlInterruptSharing::Shared,
+            }
+        );
+        assert_eq!(irq.interrupts.iter().collect::<Vec<_>>(), [16, 17]);
+        assert_eq!(irq.source, None);
+    }
+
+    fn check_connection_descriptors(descriptors: &[AmlResourceDescriptor<'_>]) {
+        let AmlResourceDescriptor::Gpio(gpio) = descriptors[11] else {
+            panic!("expected GPIO descriptor");
+        };
+        assert_eq!(gpio.usage, AmlResourceUsage::Consumer);
+        assert_eq!(
+            gpio.connection,
+            AmlGpioConnection::Interrupt(AmlInterruptMode {
+                trigger: AmlInterruptTrigger::Edge,
+                polarity: AmlInterruptPolarity::ActiveLow,
+                sharing: AmlInterruptSharing::ExclusiveAndWake,
+            })
+        );
+        assert_eq!((gpio.pin_config, gpio.debounce_timeout), (1, 10000));
+        assert_eq!(gpio.pins.iter().collect::<Vec<_>>(), [21]);
+        assert_eq!(gpio.source.name, "\\_SB.GPO0");
+        assert!(gpio.vendor_data.is_empty());
+
+        let AmlResourceDescriptor::I2c(i2c) = descriptors[12] else {
+            panic!("expected I2C descriptor");
+        };
+        assert_eq!((i2c.speed_hz, i2c.address), (400_000, 0x1c));
+        assert!(!i2c.ten_bit_addressing && !i2c.common.device_initiated);
+        assert_eq!(i2c.common.usage, AmlResourceUsage::Consumer);
+        assert_eq!(i2c.common.source.name, "\\_SB.I2C1");
+
+        let AmlResourceDescriptor::Spi(spi) = descriptors[13] else {
+            panic!("expected SPI descriptor");
+        };
+        assert!(spi.chip_select_active_high && !spi.three_wire);
+        assert_eq!((spi.speed_hz, spi.data_bits), (1_000_000, 8));
+        assert_eq!(
+            (spi.clock_phase, spi.clock_polarity, spi.chip_select),
+            (1, 1, 0)
+        );
+        assert_eq!(spi.common.source.name, "SPI1");
+
+        let AmlResourceDescriptor::Uart(uart) = descriptors[14] else {
+            panic!("expected UART descriptor");
+        };
+        assert_eq!(uart.flow_control, AmlUartFlowControl::Hardware);
+        assert_eq!(uart.stop_bits, AmlUartStopBits::One);
+        assert_eq!((uart.data_bits, uart.baud_rate), (8, 115_200));
+        assert_eq!((uart.rx_fifo, uart.tx_fifo), (64, 64));
+        assert_eq!(uart.parity, AmlUartParity::None);
+        assert_eq!(uart.lines_enabled, 0xc0);
+        assert!(!uart.big_endian);
+
+        assert_eq!(
+            descriptors[15],
+            AmlResourceDescriptor::Other(AmlRawResource {
+                tag: 0x0e,
+                data: &[0xaa],
+            })
+        );
+    }
+
+    #[test]
+    fn resource_template_decodes_every_descriptor_kind() {
+        let bytes = sample_template();
+        let template = AmlResourceTemplate::parse(&bytes).unwrap();
+        let descriptors = template.iter().collect::<Vec<_>>();
+
+        assert_eq!(descriptors.len(), 16);
+        check_legacy_descriptors(&descriptors);
+        check_address_and_interrupt_descriptors(&descriptors);
+        check_connection_descriptors(&descriptors);
+    }
+
+    #[test]
+    fn resource_template_writer_round_trips_canonical_bytes() {
+        let bytes = sample_template();
+        let template = AmlResourceTemplate::parse(&bytes).unwrap();
+        let mut out = [0_u8; 512];
+        let mut writer = AmlResourceTemplateWriter::new(&mut out);
+        writer.push_template(template).unwrap();
+
+        assert_eq!(writer.finish().unwrap(), bytes.as_slice());
+    }
+
+    #[test]
+    fn resource_template_rejects_truncated_and_malformed_input() {
+        let bytes = sample_template();
+        for len in 0..bytes.len() - 1 {
+            assert_eq!(
+                AmlResourceTemplate::parse(&bytes[..len]).unwrap_err().kind,
+                AmlErrorKind::Truncated,
+                "prefix of {len} bytes"
+            );
+        }
+
+        // An I/O descriptor must carry exactly seven body bytes.
+        let short_io = [0x46, 0x01, 0x62, 0x00, 0x62, 0x00, 0x01, 0x79, 0x00];
+        assert_eq!(
+            AmlResourceTemplate::parse(&short_io).unwrap_err().kind,
+            AmlErrorKind::InvalidBytecode
+        );
+
+        // An extended interrupt claiming more entries than its length covers.
+        let overlong_irq = [
+            0x89, 0x06, 0x00, 0x01, 0x02, 0x10, 0x00, 0x00, 0x00, 0x79, 0x00,
+        ];
+        assert_eq!(
+            AmlResourceTemplate::parse(&overlong_irq).unwrap_err().kind,
+            AmlErrorKind::Truncated
+        );
+
+        let mut out = [0_u8; 4];
+        let mut writer = AmlResourceTemplateWriter::new(&mut out);
+        assert_eq!(
+            writer
+                .push(&AmlResourceDescriptor::FixedMemory32(
+                    AmlFixedMemory32Resource {
+                        writable: true,
+                        base: 0,
+                        length: 0,
+                    }
+                ))
+                .unwrap_err()
+                .kind,
+            AmlErrorKind::Overflow
+        );
+    }
+
+    #[test]
+    fn resource_template_writer_fills_runtime_buffer_for_srs() {
+        let integers = [const { Cell::new(None::<AmlRuntimeIntegerSlot>) }; 1];
+        let buffers = [const { Cell::new(None::<AmlRuntimeBufferSlot>) }; 2];
+        let state = AmlRuntimeState::new(&integers).with_buffers(&buffers);
+
+        let mut out = [0_u8; AML_MAX_BUFFER_BYTES];
+        let mut writer = AmlResourceTemplateWriter::new(&mut out);
+        writer
+            .push(&AmlResourceDescriptor::Irq(AmlIrqResource {
+                mask: 1 << 9,
+                mode: Some(AmlInterruptMode {
+                    trigger: AmlInterruptTrigger::Level,
+                    polarity: AmlInterruptPolarity::ActiveLow,
+                    sharing: AmlInterruptSharing::Shared,
+                }),
+            }))
+            .unwrap();
+        let handle = writer.finish_into_runtime_buffer(&state).unwrap();
+        assert_eq!(state.read_buffer_len(handle), Some(6));
+
+        let mut scratch = [0_u8; AML_MAX_BUFFER_BYTES];
+        let template = AmlResourceTemplate::from_value(
+            Some(&state),
+            &AmlValue::BufferHandle(handle),
+            &mut scratch,
+        )
+        .unwrap();
+        assert_eq!(template.as_bytes(), [0x23, 0x00, 0x02, 0x18, 0x79, 0x4a]);
+        assert_eq!(
+            template.iter().next(),
+            Some(AmlResourceDescriptor::Irq(AmlIrqResource {
+                mask: 1 << 9,
+                mode: Some(AmlInterruptMode {
+                    trigger: AmlInterruptTrigger::Level,
+                    polarity: AmlInterruptPolarity::ActiveLow,
+                    sharing: AmlInterruptSharing::Shared,
+                }),
+            }))
+        );
+
+        let mut small = [0_u8; 2];
+        assert_eq!(
+            AmlResourceTemplate::from_value(
+                Some(&state),
+                &AmlValue::BufferHandle(handle),
+                &mut small
+            )
+            .unwrap_err()
+            .kind,
+            AmlErrorKind::Overflow
+        );
+    }
+
+    fn pkg(opcode: &[u8], payload: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::from(opcode);
+        let one_byte_value = payload.len() + 1;
+        if one_byte_value < 0x40 {
+            bytes.push(u8::try_from(one_byte_value).unwrap());
+        } else {
+            let two_byte_value = payload.len() + 2;
+            bytes.push(0b0100_0000 | u8::try_from(two_byte_value & 0x0f).unwrap());
+            bytes.push(u8::try_from(two_byte_value >> 4).unwrap());
+        }
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    fn named(opcode: &[u8], name: &[u8], body: &[u8]) -> Vec<u8> {
+        let mut payload = Vec::from(name);
+        payload.extend_from_slice(body);
+        pkg(opcode, &payload)
+    }
+
+    fn package(elements: &[&[u8]]) -> Vec<u8> {
+        let mut payload = vec![u8::try_from(elements.len()).unwrap()];
+        for element in elements {
+            payload.extend_from_slice(element);
+        }
+        pkg(&[0x12], &payload)
+    }
+
+    fn load_namespace(payload: &[u8]) -> AmlLoadedNamespace<'static, 'static> {
+        let mut table = Vec::from([0_u8; 36]);
+        table[0..4].copy_from_slice(b"DSDT");
+        table[4..8].copy_from_slice(&u32::try_from(36 + payload.len()).unwrap().to_le_bytes());
+        table[8] = 2;
+        table[10..16].copy_from_slice(b"FUSION");
+        table[16..24].copy_from_slice(b"RESOURCE");
+        table.extend_from_slice(payload);
+        let checksum =
+            (!table.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        table[9] = checksum;
+        let leaked = Box::leak(table.into_boxed_slice());
+        let block = AmlDefinitionBlock::from_dsdt(Dsdt::parse(leaked).unwrap()).unwrap();
+        let storage =
+            Box::leak(vec![MaybeUninit::<AmlNamespaceLoadRecord>::uninit(); 32].into_boxed_slice());
+        AmlNamespaceLoadPlan::from_definition_blocks(AmlDefinitionBlockSet::new(block, &[]))
+            .load_into(storage)
+            .unwrap()
+    }
+
+    fn path(segments: &[[u8; 4]]) -> AmlResolvedNamePath {
+        let mut path = AmlResolvedNamePath::root();
+        for segment in segments {
+            path.push(AmlNameSeg::from_bytes(*segment).unwrap())
+                .unwrap();
+        }
+        path
+    }
+
+    struct NullHost;
+
+    impl AmlOspmInterface for NullHost {
+        fn osi_supported(&self, _interface: &str) -> bool {
+            false
+        }
+
+        fn os_revision(&self) -> u64 {
+            0
+        }
+    }
+
+    impl AmlSleepHost for NullHost {
+        fn stall_us(&self, _microseconds: u32) -> AmlResult<()> {
+            Ok(())
+        }
+
+        fn sleep_ms(&self, _milliseconds: u32) -> AmlResult<()> {
+            Ok(())
+        }
+    }
+
+    impl AmlNotifySink for NullHost {
+        fn notify(&self, _source: AmlNamespaceNodeId, _value: u8) -> AmlResult<()> {
+            Ok(())
+        }
+    }
+
+    impl AmlSystemMemoryHost for NullHost {
+        fn read_system_memory(&self, _address: u64, _width: AmlAccessWidth) -> AmlResult<u64> {
+            Err(AmlError::unsupported())
+        }
+
+        fn write_system_memory(
+            &self,
+            _address: u64,
+            _width: AmlAccessWidth,
+            _value: u64,
+        ) -> AmlResult<()> {
+            Err(AmlError::unsupported())
+        }
+    }
+
+    impl AmlSystemIoHost for NullHost {
+        fn read_system_io(&self, _port: u64, _width: AmlAccessWidth) -> AmlResult<u64> {
+            Err(AmlError::unsupported())
+        }
+
+        fn write_system_io(
+            &self,
+            _port: u64,
+            _width: AmlAccessWidth,
+            _value: u64,
+        ) -> AmlResult<()> {
+            Err(AmlError::unsupported())
+        }
+    }
+
+    impl AmlPciConfigHost for NullHost {
+        fn read_pci_config(&self, _address: u64, _width: AmlAccessWidth) -> AmlResult<u64> {
+            Err(AmlError::unsupported())
+        }
+
+        fn write_pci_config(
+            &self,
+            _address: u64,
+            _width: AmlAccessWidth,
+            _value: u64,
+        ) -> AmlResult<()> {
+            Err(AmlError::unsupported())
+        }
+    }
+
+    impl AmlEmbeddedControllerHost for NullHost {
+        fn read_embedded_controller(&self, _register: u8) -> AmlResult<u8> {
+            Err(AmlError::unsupported())
+        }
+
+        fn write_embedded_controller(&self, _register: u8, _value: u8) -> AmlResult<()> {
+            Err(AmlError::unsupported())
+        }
+    }
+
+    impl AmlTableHost for NullHost {}
+
+    impl AmlTraceHost for NullHost {}
+
+    impl AmlHost for NullHost {}
+
+    #[test]
+    fn pci_routing_table_decodes_link_and_gsi_sources() {
+        let prt = package(&[
+            &package(&[&[0x0c, 0xff, 0xff, 0x01, 0x00], &[0x00], b"LNKA", &[0x00]]),
+            &package(&[
+                &[0x0c, 0xff, 0xff, 0x02, 0x00],
+                &[0x01],
+                &[0x00],
+                &[0x0a, 0x11],
+            ]),
+        ]);
+        let mut crs = vec![0x0a, 0x06, 0x23, 0x00, 0x02, 0x18, 0x79, 0x00];
+        crs = pkg(&[0x11], &crs);
+        let mut pci = Vec::from(*b"\x08_PRT");
+        pci.extend_from_slice(&prt);
+        let mut link = Vec::from(*b"\x08_CRS");
+        link.extend_from_slice(&crs);
+        let mut sb = named(&[0x5b, 0x82], b"PCI0", &pci);
+        sb.extend(named(&[0x5b, 0x82], b"LNKA", &link));
+        let namespace = load_namespace(&named(&[0x10], b"\\_SB_", &sb));
+
+        let integers = [const { Cell::new(None::<AmlRuntimeIntegerSlot>) }; 1];
+        let state = AmlRuntimeState::new(&integers);
+        let prt_node = namespace
+            .record_by_path(path(&[*b"_SB_", *b"PCI0", *b"_PRT"]))
+            .unwrap()
+            .descriptor
+            .id;
+        let mut out = [const { MaybeUninit::<AmlPciRoutingEntry>::uninit() }; 4];
+        let entries =
+            decode_pci_routing_table(namespace, &NullHost, &state, prt_node, &mut out).unwrap();
+
+        assert_eq!(
+            entries,
+            [
+                AmlPciRoutingEntry {
+                    address: 0x0001_ffff,
+                    pin: AmlPciInterruptPin::IntA,
+                    source: AmlPciInterruptSource::Link {
+                        path: path(&[*b"_SB_", *b"LNKA"]),
+                        index: 0,
+                    },
+                },
+                AmlPciRoutingEntry {
+                    address: 0x0002_ffff,
+                    pin: AmlPciInterruptPin::IntB,
+                    source: AmlPciInterruptSource::Gsi(17),
+                },
+            ]
+        );
+        assert_eq!(entries[1].device(), 2);
+
+        let mut too_small = [const { MaybeUninit::<AmlPciRoutingEntry>::uninit() }; 1];
+        assert_eq!(
+            decode_pci_routing_table(namespace, &NullHost, &state, prt_node, &mut too_small)
+                .unwrap_err()
+                .kind,
+            AmlErrorKind::Overflow
+        );
+
+        let link_crs = namespace
+            .record_by_path(path(&[*b"_SB_", *b"LNKA", *b"_CRS"]))
+            .unwrap()
+            .descriptor
+            .id;
+        let mut scratch = [0_u8; AML_MAX_BUFFER_BYTES];
+        let template =
+            AmlResourceTemplate::evaluate(namespace, &NullHost, &state, link_crs, &mut scratch)
+                .unwrap();
+        assert!(matches!(
+            template.iter().next(),
+            Some(AmlResourceDescriptor::Irq(AmlIrqResource {
+                mask: 0x0200,
+                ..
+            }))
+        ));
+    }
+}
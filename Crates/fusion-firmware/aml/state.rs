@@ -17,17 +17,23 @@ pub struct AmlRuntimeIntegerSlot {
 
 pub const AML_MAX_PACKAGE_ELEMENTS: usize = 16;
 pub const AML_MAX_BUFFER_BYTES: usize = 64;
+pub const AML_MAX_STRING_BYTES: usize = 64;
 
 /// Opaque handle for one runtime AML buffer object.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct AmlRuntimeBufferHandle(pub u16);
 
+/// Opaque handle for one runtime AML string object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlRuntimeStringHandle(pub u16);
+
 /// One runtime value stored inside an aggregate slot.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AmlRuntimeAggregateValue {
     None,
     Integer(u64),
     Buffer(AmlRuntimeBufferHandle),
+    String(AmlRuntimeStringHandle),
 }
 
 /// Opaque handle for one runtime AML package object.
@@ -50,11 +56,41 @@ pub struct AmlRuntimeBufferSlot {
     pub bytes: [u8; AML_MAX_BUFFER_BYTES],
 }
 
+/// One runtime string slot for strings produced by method execution.
+///
+/// Bytes are ASCII without the terminating NUL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlRuntimeStringSlot {
+    pub handle: AmlRuntimeStringHandle,
+    pub len: u8,
+    pub bytes: [u8; AML_MAX_STRING_BYTES],
+}
+
+/// Identity of one top-level AML evaluation, used as the owner of runtime mutexes.
+///
+/// Nested method calls share their caller's owner, so a method may re-acquire a mutex its
+/// evaluation already holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlMutexOwner(pub u32);
+
 /// One runtime mutex slot in AML state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct AmlRuntimeMutexSlot {
     pub node: AmlNamespaceNodeId,
-    pub held: bool,
+    /// Evaluation holding the mutex, or `None` while it is free.
+    pub owner: Option<AmlMutexOwner>,
+    /// Acquisitions by `owner` not yet matched by a release.
+    pub depth: u16,
+    /// Sync level the owner ran at before its first acquire, restored on the last release.
+    pub restore_sync_level: u8,
+}
+
+/// One runtime event slot in AML state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlRuntimeEventSlot {
+    pub node: AmlNamespaceNodeId,
+    /// Signals not yet consumed by `Wait`.
+    pub pending: u32,
 }
 
 /// Borrowed mutable AML runtime state overlay.
@@ -65,7 +101,10 @@ pub struct AmlRuntimeState<'a> {
     integers: &'a [Cell<Option<AmlRuntimeIntegerSlot>>],
     packages: &'a [Cell<Option<AmlRuntimePackageSlot>>],
     buffers: &'a [Cell<Option<AmlRuntimeBufferSlot>>],
+    strings: &'a [Cell<Option<AmlRuntimeStringSlot>>],
     mutexes: &'a [Cell<Option<AmlRuntimeMutexSlot>>],
+    events: &'a [Cell<Option<AmlRuntimeEventSlot>>],
+    next_mutex_owner: Cell<u32>,
 }
 
 impl<'a> AmlRuntimeState<'a> {
@@ -75,7 +114,10 @@ impl<'a> AmlRuntimeState<'a> {
             integers,
             packages: &[],
             buffers: &[],
+            strings: &[],
             mutexes: &[],
+            events: &[],
+            next_mutex_owner: Cell::new(1),
         }
     }
 
@@ -89,11 +131,21 @@ impl<'a> AmlRuntimeState<'a> {
         Self { buffers, ..self }
     }
 
+    #[must_use]
+    pub const fn with_strings(self, strings: &'a [Cell<Option<AmlRuntimeStringSlot>>]) -> Self {
+        Self { strings, ..self }
+    }
+
     #[must_use]
     pub const fn with_mutexes(self, mutexes: &'a [Cell<Option<AmlRuntimeMutexSlot>>]) -> Self {
         Self { mutexes, ..self }
     }
 
+    #[must_use]
+    pub const fn with_events(self, events: &'a [Cell<Option<AmlRuntimeEventSlot>>]) -> Self {
+        Self { events, ..self }
+    }
+
     #[must_use]
     pub fn read_integer(&self, node: AmlNamespaceNodeId) -> Option<u64> {
         let mut index = 0_usize;
@@ -175,6 +227,44 @@ impl<'a> AmlRuntimeState<'a> {
         Err(AmlError::overflow())
     }
 
+    /// Creates one runtime string holding `bytes`, cut at the first NUL.
+    ///
+    /// # Errors
+    ///
+    /// Returns `overflow` when the string is longer than `AML_MAX_STRING_BYTES` or every string
+    /// slot is taken, and `invalid_state` when it is not ASCII.
+    pub fn create_string(&self, bytes: &[u8]) -> AmlResult<AmlRuntimeStringHandle> {
+        let len = bytes
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(bytes.len());
+        if len > AML_MAX_STRING_BYTES {
+            return Err(AmlError::overflow());
+        }
+        if !bytes[..len].is_ascii() {
+            return Err(AmlError::invalid_state());
+        }
+        let stored_len = u8::try_from(len).map_err(|_| AmlError::overflow())?;
+
+        let mut index = 0_usize;
+        while index < self.strings.len() {
+            if self.strings[index].get().is_none() {
+                let handle =
+                    AmlRuntimeStringHandle(u16::try_from(index).map_err(|_| AmlError::overflow())?);
+                let mut slot = AmlRuntimeStringSlot {
+                    handle,
+                    len: stored_len,
+                    bytes: [0; AML_MAX_STRING_BYTES],
+                };
+                slot.bytes[..len].copy_from_slice(&bytes[..len]);
+                self.strings[index].set(Some(slot));
+                return Ok(handle);
+            }
+            index += 1;
+        }
+        Err(AmlError::overflow())
+    }
+
     #[must_use]
     pub fn read_package_len(&self, handle: AmlRuntimePackageHandle) -> Option<u8> {
         self.package_slot(handle).map(|slot| slot.len)
@@ -290,17 +380,64 @@ impl<'a> AmlRuntimeState<'a> {
         Ok(())
     }
 
-    pub fn try_acquire_mutex(&self, node: AmlNamespaceNodeId) -> AmlResult<bool> {
+    #[must_use]
+    pub fn read_string_len(&self, handle: AmlRuntimeStringHandle) -> Option<u8> {
+        self.string_slot(handle).map(|slot| slot.len)
+    }
+
+    #[must_use]
+    pub fn read_string_byte(&self, handle: AmlRuntimeStringHandle, index: u8) -> Option<u8> {
+        let slot = self.string_slot(handle)?;
+        if index >= slot.len {
+            return None;
+        }
+        Some(slot.bytes[usize::from(index)])
+    }
+
+    /// Allocates the mutex owner for one new top-level evaluation.
+    pub fn begin_evaluation(&self) -> AmlMutexOwner {
+        let owner = self.next_mutex_owner.get();
+        self.next_mutex_owner.set(owner.wrapping_add(1).max(1));
+        AmlMutexOwner(owner)
+    }
+
+    /// Takes `node` for `owner` and returns the owner's acquisition depth.
+    ///
+    /// Re-acquiring a mutex `owner` already holds nests, keeping the sync level recorded by the
+    /// first acquire. Returns `None` while another owner holds the mutex.
+    ///
+    /// # Errors
+    ///
+    /// Returns `overflow` when the acquisition depth would wrap or every mutex slot is taken.
+    pub fn try_acquire_mutex(
+        &self,
+        node: AmlNamespaceNodeId,
+        owner: AmlMutexOwner,
+        restore_sync_level: u8,
+    ) -> AmlResult<Option<u16>> {
+        let acquired = AmlRuntimeMutexSlot {
+            node,
+            owner: Some(owner),
+            depth: 1,
+            restore_sync_level,
+        };
         let mut empty_index = None;
         let mut index = 0_usize;
         while index < self.mutexes.len() {
             match self.mutexes[index].get() {
                 Some(slot) if slot.node == node => {
-                    if slot.held {
-                        return Ok(false);
-                    }
-                    self.mutexes[index].set(Some(AmlRuntimeMutexSlot { node, held: true }));
-                    return Ok(true);
+                    return match slot.owner {
+                        None => {
+                            self.mutexes[index].set(Some(acquired));
+                            Ok(Some(1))
+                        }
+                        Some(holder) if holder == owner => {
+                            let depth = slot.depth.checked_add(1).ok_or_else(AmlError::overflow)?;
+                            self.mutexes[index].set(Some(AmlRuntimeMutexSlot { depth, ..slot }));
+                            Ok(Some(depth))
+                        }
+                        Some(_) => Ok(None),
+                    };
                 }
                 None if empty_index.is_none() => empty_index = Some(index),
                 _ => {}
@@ -311,20 +448,43 @@ impl<'a> AmlRuntimeState<'a> {
         let Some(index) = empty_index else {
             return Err(AmlError::overflow());
         };
-        self.mutexes[index].set(Some(AmlRuntimeMutexSlot { node, held: true }));
-        Ok(true)
+        self.mutexes[index].set(Some(acquired));
+        Ok(Some(1))
     }
 
-    pub fn release_mutex(&self, node: AmlNamespaceNodeId) -> AmlResult<()> {
+    /// Drops one acquisition of `node` by `owner`.
+    ///
+    /// Returns the sync level to restore once the last acquisition is released and the mutex is
+    /// free again.
+    ///
+    /// # Errors
+    ///
+    /// Returns `invalid_state` when `owner` does not hold `node`.
+    pub fn release_mutex(
+        &self,
+        node: AmlNamespaceNodeId,
+        owner: AmlMutexOwner,
+    ) -> AmlResult<Option<u8>> {
         let mut index = 0_usize;
         while index < self.mutexes.len() {
             match self.mutexes[index].get() {
                 Some(slot) if slot.node == node => {
-                    if !slot.held {
+                    if slot.owner != Some(owner) {
                         return Err(AmlError::invalid_state());
                     }
-                    self.mutexes[index].set(Some(AmlRuntimeMutexSlot { node, held: false }));
-                    return Ok(());
+                    if slot.depth > 1 {
+                        self.mutexes[index].set(Some(AmlRuntimeMutexSlot {
+                            depth: slot.depth - 1,
+                            ..slot
+                        }));
+                        return Ok(None);
+                    }
+                    self.mutexes[index].set(Some(AmlRuntimeMutexSlot {
+                        owner: None,
+                        depth: 0,
+                        ..slot
+                    }));
+                    return Ok(Some(slot.restore_sync_level));
                 }
                 _ => {}
             }
@@ -333,6 +493,69 @@ impl<'a> AmlRuntimeState<'a> {
         Err(AmlError::invalid_state())
     }
 
+    /// Records one pending signal on `node`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `overflow` when `node` has no slot yet and every event slot is taken.
+    pub fn signal_event(&self, node: AmlNamespaceNodeId) -> AmlResult<()> {
+        let mut empty_index = None;
+        let mut index = 0_usize;
+        while index < self.events.len() {
+            match self.events[index].get() {
+                Some(slot) if slot.node == node => {
+                    self.events[index].set(Some(AmlRuntimeEventSlot {
+                        node,
+                        pending: slot.pending.saturating_add(1),
+                    }));
+                    return Ok(());
+                }
+                None if empty_index.is_none() => empty_index = Some(index),
+                _ => {}
+            }
+            index += 1;
+        }
+
+        let Some(index) = empty_index else {
+            return Err(AmlError::overflow());
+        };
+        self.events[index].set(Some(AmlRuntimeEventSlot { node, pending: 1 }));
+        Ok(())
+    }
+
+    /// Consumes one pending signal on `node`, returning whether one was available.
+    #[must_use]
+    pub fn try_wait_event(&self, node: AmlNamespaceNodeId) -> bool {
+        let mut index = 0_usize;
+        while index < self.events.len() {
+            if let Some(slot) = self.events[index].get()
+                && slot.node == node
+                && slot.pending != 0
+            {
+                self.events[index].set(Some(AmlRuntimeEventSlot {
+                    node,
+                    pending: slot.pending - 1,
+                }));
+                return true;
+            }
+            index += 1;
+        }
+        false
+    }
+
+    pub fn reset_event(&self, node: AmlNamespaceNodeId) {
+        let mut index = 0_usize;
+        while index < self.events.len() {
+            if let Some(slot) = self.events[index].get()
+                && slot.node == node
+            {
+                self.events[index].set(Some(AmlRuntimeEventSlot { node, pending: 0 }));
+                return;
+            }
+            index += 1;
+        }
+    }
+
     fn package_slot(&self, handle: AmlRuntimePackageHandle) -> Option<AmlRuntimePackageSlot> {
         self.packages.get(usize::from(handle.0)).and_then(Cell::get)
     }
@@ -340,4 +563,8 @@ impl<'a> AmlRuntimeState<'a> {
     fn buffer_slot(&self, handle: AmlRuntimeBufferHandle) -> Option<AmlRuntimeBufferSlot> {
         self.buffers.get(usize::from(handle.0)).and_then(Cell::get)
     }
+
+    fn string_slot(&self, handle: AmlRuntimeStringHandle) -> Option<AmlRuntimeStringSlot> {
+        self.strings.get(usize::from(handle.0)).and_then(Cell::get)
+    }
 }
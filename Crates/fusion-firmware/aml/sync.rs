@@ -1,5 +1,13 @@
 //! AML synchronization and serialization vocabulary.
 
+use crate::aml::AmlNamespaceNodeId;
+
+/// `Acquire`/`Wait` timeout value meaning "wait forever".
+pub const AML_WAIT_FOREVER: u16 = 0xffff;
+
+/// Highest sync level a mutex or serialized method may declare.
+pub const AML_MAX_SYNC_LEVEL: u8 = 15;
+
 /// Method serialization mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AmlMethodSerialization {
@@ -12,3 +20,23 @@ pub enum AmlMethodSerialization {
 pub struct AmlMutexDescriptor {
     pub sync_level: u8,
 }
+
+/// One synchronization object an AML method can suspend on.
+///
+/// Serialized methods wait on their implicit mutex, named by the method node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlWaitObject {
+    Mutex(AmlNamespaceNodeId),
+    Event(AmlNamespaceNodeId),
+}
+
+/// Host answer to one AML wait request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AmlWaitOutcome {
+    /// The object may have changed state; the evaluator retries it.
+    Woken,
+    /// The timeout elapsed before the object became available.
+    TimedOut,
+    /// The host cannot suspend this evaluation; it unwinds as blocked instead.
+    Deferred,
+}
@@ -0,0 +1,256 @@
+//! AML dynamic table loading vocabulary (`Load`, `LoadTable`, `Unload`).
+//!
+//! AML evaluates against an immutable namespace snapshot. Tables loaded at runtime are staged in
+//! an [`AmlTableRegistry`] that the definition-block set borrows, and the owner rebuilds the
+//! namespace from the same plan between evaluations to make their objects visible.
+
+use core::cell::Cell;
+use core::mem::MaybeUninit;
+
+use crate::aml::{
+    AmlDefinitionBlock,
+    AmlDefinitionBlockSet,
+    AmlError,
+    AmlNamespaceLoadPlan,
+    AmlNamespaceLoadRecord,
+    AmlResolvedNamePath,
+    AmlResult,
+};
+
+/// Handle AML holds for one dynamically loaded definition block (`DDBHandle`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlDdbHandle(pub u16);
+
+/// One definition block loaded at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmlDynamicTable<'a> {
+    pub handle: AmlDdbHandle,
+    pub block: AmlDefinitionBlock<'a>,
+    /// Scope the block's term list is loaded into.
+    pub root: AmlResolvedNamePath,
+    /// Cleared by `Unload`. The slot is kept so code locations in older snapshots stay valid.
+    pub loaded: bool,
+}
+
+/// Where the bytes of one `Load` operand live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmlTableSource<'a> {
+    /// A `SystemMemory` operation region holding the whole table.
+    Memory { address: u64, length: u64 },
+    /// A buffer object. The host must copy it; the bytes do not outlive the call.
+    Buffer(&'a [u8]),
+}
+
+/// Object `LoadTable` stores its parameter into once the table is live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmlTableParameter {
+    pub path: AmlResolvedNamePath,
+    pub value: u64,
+}
+
+/// One AML request to load a definition block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmlTableLoad<'a> {
+    /// `Load(Object, DDBHandle)`: the table is always loaded at the namespace root.
+    Load(AmlTableSource<'a>),
+    /// `LoadTable(Signature, OEMID, OEMTableID, RootPath, ParameterPath, ParameterData)`.
+    ///
+    /// Identity fields are zero-padded the way they appear in the table header.
+    LoadTable {
+        signature: [u8; 4],
+        oem_id: [u8; 6],
+        oem_table_id: [u8; 8],
+        root: AmlResolvedNamePath,
+        parameter: Option<AmlTableParameter>,
+    },
+}
+
+/// Caller-owned registry of definition blocks AML loaded or unloaded at runtime.
+///
+/// Slots are interior-mutable so a host can stage tables while an evaluation still borrows the
+/// current snapshot. Slots are never reused, which keeps DDB handles unique.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmlTableRegistry<'a> {
+    tables: &'a [Cell<Option<AmlDynamicTable<'a>>>],
+}
+
+impl<'a> AmlTableRegistry<'a> {
+    #[must_use]
+    pub const fn new(tables: &'a [Cell<Option<AmlDynamicTable<'a>>>]) -> Self {
+        Self { tables }
+    }
+
+    #[must_use]
+    pub const fn tables(self) -> &'a [Cell<Option<AmlDynamicTable<'a>>>] {
+        self.tables
+    }
+
+    #[must_use]
+    pub fn table(self, handle: AmlDdbHandle) -> Option<AmlDynamicTable<'a>> {
+        self.tables.get(usize::from(handle.0)).and_then(Cell::get)
+    }
+
+    #[must_use]
+    pub fn loaded_count(self) -> usize {
+        self.tables
+            .iter()
+            .filter(|slot| slot.get().is_some_and(|table| table.loaded))
+            .count()
+    }
+
+    /// Stages `block` under `root` after a trial load of `blocks` plus the new table succeeds.
+    ///
+    /// `scratch` receives the trial namespace and must be large enough for the whole namespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns `namespace_conflict` when the block redefines an existing object, `overflow` when
+    /// the registry or `scratch` is full, and any loader error raised by the block itself. The
+    /// registry is left unchanged on error.
+    pub fn load(
+        self,
+        blocks: AmlDefinitionBlockSet<'a>,
+        block: AmlDefinitionBlock<'a>,
+        root: AmlResolvedNamePath,
+        scratch: &mut [MaybeUninit<AmlNamespaceLoadRecord>],
+    ) -> AmlResult<AmlDdbHandle> {
+        if !block.kind.is_definition_block() {
+            return Err(AmlError::invalid_definition_block());
+        }
+        let index = self
+            .tables
+            .iter()
+            .position(|slot| slot.get().is_none())
+            .ok_or_else(AmlError::overflow)?;
+        let handle = AmlDdbHandle(u16::try_from(index).map_err(|_| AmlError::overflow())?);
+        self.tables[index].set(Some(AmlDynamicTable {
+            handle,
+            block,
+            root,
+            loaded: true,
+        }));
+
+        let plan = AmlNamespaceLoadPlan::from_definition_blocks(blocks.with_dynamic_tables(self));
+        if let Err(error) = plan.load_into(scratch) {
+            self.tables[index].set(None);
+            return Err(error);
+        }
+        Ok(handle)
+    }
+
+    /// Marks one loaded table as unloaded; the next rebuild drops its objects.
+    ///
+    /// # Errors
+    ///
+    /// Returns `invalid_state` when `handle` does not name a loaded table.
+    pub fn unload(self, handle: AmlDdbHandle) -> AmlResult<()> {
+        let slot = self
+            .tables
+            .get(usize::from(handle.0))
+            .ok_or_else(AmlError::invalid_state)?;
+        match slot.get() {
+            Some(table) if table.loaded => {
+                slot.set(Some(AmlDynamicTable {
+                    loaded: false,
+                    ..table
+                }));
+                Ok(())
+            }
+            _ => Err(AmlError::invalid_state()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aml::AmlErrorKind;
+    use crate::pal::hal::acpi::AcpiTableView;
+    use std::boxed::Box;
+    use std::vec::Vec;
+
+    fn definition_block(signature: [u8; 4], payload: &[u8]) -> AmlDefinitionBlock<'static> {
+        let mut table = Vec::from([0_u8; 36]);
+        table[0..4].copy_from_slice(&signature);
+        table[4..8].copy_from_slice(
+            &u32::try_from(36 + payload.len())
+                .expect("table should fit")
+                .to_le_bytes(),
+        );
+        table[8] = 2;
+        table[10..16].copy_from_slice(b"FUSION");
+        table[16..24].copy_from_slice(b"AMLTABLE");
+        table.extend_from_slice(payload);
+        let checksum =
+            (!table.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        table[9] = checksum;
+        let leaked = Box::leak(table.into_boxed_slice());
+        AmlDefinitionBlock::from_acpi_table(AcpiTableView::parse(leaked).unwrap()).unwrap()
+    }
+
+    fn path(text: &str) -> AmlResolvedNamePath {
+        AmlResolvedNamePath::parse_text(text).unwrap()
+    }
+
+    #[test]
+    fn registry_stages_tables_rejects_conflicts_and_unloads() {
+        // Name(FOO0, One)
+        let dsdt = definition_block(*b"DSDT", &[0x08, b'F', b'O', b'O', b'0', 0x01]);
+        // Name(BAR0, 0x02)
+        let bar = definition_block(*b"SSDT", &[0x08, b'B', b'A', b'R', b'0', 0x0A, 0x02]);
+        // Name(FOO0, 0x03)
+        let conflict = definition_block(*b"SSDT", &[0x08, b'F', b'O', b'O', b'0', 0x0A, 0x03]);
+        let slots: [Cell<Option<AmlDynamicTable<'_>>>; 4] = Default::default();
+        let registry = AmlTableRegistry::new(&slots);
+        let blocks = AmlDefinitionBlockSet::new(dsdt, &[]).with_dynamic_tables(registry);
+        let mut scratch = [MaybeUninit::<AmlNamespaceLoadRecord>::uninit(); 16];
+
+        let first = registry
+            .load(blocks, bar, AmlResolvedNamePath::root(), &mut scratch)
+            .unwrap();
+        assert_eq!(first, AmlDdbHandle(0));
+        assert_eq!(
+            registry
+                .load(blocks, conflict, AmlResolvedNamePath::root(), &mut scratch)
+                .unwrap_err()
+                .kind,
+            AmlErrorKind::NamespaceConflict
+        );
+        assert_eq!(registry.loaded_count(), 1);
+        let second = registry
+            .load(blocks, bar, path("\\_SB_"), &mut scratch)
+            .unwrap();
+        assert_eq!(second, AmlDdbHandle(1));
+        assert_eq!(
+            blocks.block(blocks.dynamic_block_index(second).unwrap()),
+            Some(bar)
+        );
+
+        let mut storage = [MaybeUninit::<AmlNamespaceLoadRecord>::uninit(); 16];
+        let loaded = AmlNamespaceLoadPlan::from_definition_blocks(blocks)
+            .load_into(&mut storage)
+            .unwrap();
+        assert!(loaded.record_by_path(path("\\FOO0")).is_some());
+        assert!(loaded.record_by_path(path("\\BAR0")).is_some());
+        assert!(loaded.record_by_path(path("\\_SB_.BAR0")).is_some());
+
+        registry.unload(first).unwrap();
+        assert_eq!(
+            registry.unload(first).unwrap_err().kind,
+            AmlErrorKind::InvalidState
+        );
+        let mut storage = [MaybeUninit::<AmlNamespaceLoadRecord>::uninit(); 16];
+        let loaded = AmlNamespaceLoadPlan::from_definition_blocks(blocks)
+            .load_into(&mut storage)
+            .unwrap();
+        assert!(loaded.record_by_path(path("\\BAR0")).is_none());
+        assert!(loaded.record_by_path(path("\\_SB_.BAR0")).is_some());
+        assert_eq!(registry.loaded_count(), 1);
+        assert_eq!(
+            registry
+                .load(blocks, bar, AmlResolvedNamePath::root(), &mut scratch)
+                .unwrap(),
+            AmlDdbHandle(2)
+        );
+    }
+}
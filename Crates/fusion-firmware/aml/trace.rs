@@ -1,8 +1,28 @@
 //! AML trace and source-location vocabulary.
+//!
+//! The evaluator reports method entry/return, region accesses and notifications to one
+//! host-provided [`AmlTraceSink`] through an [`AmlTracer`]. The tracer owns the namespace-subtree
+//! filter: once a method inside the subtree is entered, everything it does (including callees
+//! outside the subtree) is traced until it returns, so tracing one `_BST` shows its EC reads
+//! without every other method in the namespace.
+
+use core::cell::Cell;
+use core::fmt;
+
+use fusion_sys::channel::insight::{
+    InsightTimelineSpanToken,
+    LocalInsightTimeline,
+};
 
 use crate::aml::{
+    AmlAccessWidth,
+    AmlAddressSpaceId,
     AmlCodeLocation,
+    AmlError,
+    AmlFieldAccessKind,
     AmlNamespaceNodeId,
+    AmlResolvedNamePath,
+    AmlValue,
 };
 
 /// Source location for one lowered or interpreted AML action.
@@ -21,10 +41,381 @@ pub enum AmlTraceEventKind {
     Notify,
 }
 
+/// One operation-region access as issued to the host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmlTraceRegionAccess {
+    pub space: AmlAddressSpaceId,
+    /// Absolute address, port, PCI config offset or EC register.
+    pub address: u64,
+    /// Width issued to the host; field I/O is always split into byte accesses.
+    pub width: AmlAccessWidth,
+    /// Access type the field declared (`ByteAcc`, `DWordAcc`, ... or a later `AccessAs`).
+    pub field_access: AmlFieldAccessKind,
+    /// Value read back or written.
+    pub value: u64,
+}
+
+/// Event-specific payload carried by one [`AmlTraceEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmlTraceDetail<'a> {
+    /// Arguments passed to the entered method.
+    Arguments(&'a [AmlValue<'a>]),
+    /// Value the method returned, if any.
+    Return(Option<&'a AmlValue<'a>>),
+    /// The method unwound with an error instead of returning.
+    Error(AmlError),
+    Region(AmlTraceRegionAccess),
+    /// `Notify` value delivered to the event node.
+    Notify(u8),
+}
+
 /// One trace event emitted by the AML VM or lowering path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmlTraceEvent<'a> {
+    /// Method, region or notified object the event is about.
+    pub node: Option<AmlNamespaceNodeId>,
+    /// Method body for entry; the statement being evaluated otherwise.
+    ///
+    /// Statement locations are zero-length spans at the first byte of the statement, which
+    /// `AmlDisassembler::write_location` can render in context.
+    pub location: Option<AmlSourceLocation>,
+    pub kind: AmlTraceEventKind,
+    /// Traced method nesting depth, 1 for the outermost traced method.
+    pub depth: u16,
+    pub detail: AmlTraceDetail<'a>,
+}
+
+/// Host-provided receiver for AML trace events.
+pub trait AmlTraceSink {
+    fn record(&self, event: &AmlTraceEvent<'_>);
+}
+
+/// Trace session handed to the evaluator.
+///
+/// Without a subtree every evaluated method is traced. The tracer is single-threaded like the
+/// evaluator it is attached to.
+pub struct AmlTracer<'sink> {
+    sink: &'sink dyn AmlTraceSink,
+    subtree: Option<AmlResolvedNamePath>,
+    depth: Cell<u16>,
+    statement: Cell<Option<AmlSourceLocation>>,
+}
+
+impl<'sink> AmlTracer<'sink> {
+    #[must_use]
+    pub const fn new(sink: &'sink dyn AmlTraceSink) -> Self {
+        Self {
+            sink,
+            subtree: None,
+            depth: Cell::new(0),
+            statement: Cell::new(None),
+        }
+    }
+
+    /// Restricts tracing to methods at or below `scope` and everything they call.
+    #[must_use]
+    pub const fn with_subtree(mut self, scope: AmlResolvedNamePath) -> Self {
+        self.subtree = Some(scope);
+        self
+    }
+
+    #[must_use]
+    pub const fn subtree(&self) -> Option<AmlResolvedNamePath> {
+        self.subtree
+    }
+
+    /// Returns `true` while a traced method is executing.
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.depth.get() != 0
+    }
+
+    /// Opens one method frame, returning whether it is traced.
+    pub(super) fn enter(&self, method: AmlResolvedNamePath) -> bool {
+        let traced = self.is_active()
+            || self
+                .subtree
+                .is_none_or(|scope| method.prefix(scope.segment_count()) == Some(scope));
+        if traced {
+            self.depth.set(self.depth.get().saturating_add(1));
+        }
+        traced
+    }
+
+    pub(super) fn leave(&self) {
+        self.depth.set(self.depth.get().saturating_sub(1));
+    }
+
+    pub(super) const fn statement(&self) -> Option<AmlSourceLocation> {
+        self.statement.get()
+    }
+
+    pub(super) fn set_statement(&self, location: Option<AmlSourceLocation>) {
+        self.statement.set(location);
+    }
+
+    /// Forwards one event to the sink when a traced method is executing.
+    pub(super) fn emit(
+        &self,
+        node: Option<AmlNamespaceNodeId>,
+        location: Option<AmlSourceLocation>,
+        kind: AmlTraceEventKind,
+        detail: AmlTraceDetail<'_>,
+    ) {
+        if !self.is_active() {
+            return;
+        }
+        self.sink.record(&AmlTraceEvent {
+            node,
+            location,
+            kind,
+            depth: self.depth.get(),
+            detail,
+        });
+    }
+}
+
+impl fmt::Debug for AmlTracer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AmlTracer")
+            .field("subtree", &self.subtree)
+            .field("depth", &self.depth.get())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Owned span metadata published on an insight timeline by [`AmlTimelineTraceSink`].
+///
+/// Argument and return values borrow evaluator state, so only their shape survives the trip
+/// through the channel: region accesses and notify values are kept, AML values are not.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct AmlTraceEvent {
+pub struct AmlTraceSpan {
     pub node: Option<AmlNamespaceNodeId>,
     pub location: Option<AmlSourceLocation>,
     pub kind: AmlTraceEventKind,
+    pub region: Option<AmlTraceRegionAccess>,
+    pub notify: Option<u8>,
+}
+
+/// Trace sink bridging AML events into one `fusion-sys` insight timeline.
+///
+/// Methods become nested spans; region accesses and notifications become zero-length child
+/// spans of the method that issued them. Capture is lossy: a full or unobserved timeline drops
+/// spans rather than failing the evaluation.
+pub struct AmlTimelineTraceSink<'timeline, const CAPACITY: usize, const MAX_CONSUMERS: usize = 8> {
+    timeline: &'timeline LocalInsightTimeline<AmlTraceSpan, CAPACITY, MAX_CONSUMERS>,
+    spans: [Cell<Option<InsightTimelineSpanToken>>; AML_TRACE_SPAN_DEPTH],
+    depth: Cell<usize>,
+}
+
+const AML_TRACE_SPAN_DEPTH: usize = 64;
+
+impl<'timeline, const CAPACITY: usize, const MAX_CONSUMERS: usize>
+    AmlTimelineTraceSink<'timeline, CAPACITY, MAX_CONSUMERS>
+{
+    #[must_use]
+    pub fn new(
+        timeline: &'timeline LocalInsightTimeline<AmlTraceSpan, CAPACITY, MAX_CONSUMERS>,
+    ) -> Self {
+        Self {
+            timeline,
+            spans: core::array::from_fn(|_| Cell::new(None)),
+            depth: Cell::new(0),
+        }
+    }
+
+    fn parent(&self) -> Option<InsightTimelineSpanToken> {
+        let depth = self.depth.get();
+        depth
+            .checked_sub(1)
+            .and_then(|index| self.spans.get(index))
+            .and_then(Cell::get)
+    }
+
+    fn open(&self, span: AmlTraceSpan) -> Option<InsightTimelineSpanToken> {
+        self.timeline.begin_span(self.parent(), span).ok().flatten()
+    }
+
+    fn close(&self, token: Option<InsightTimelineSpanToken>) {
+        if let Some(token) = token {
+            let _ = self.timeline.end_span(token);
+        }
+    }
+}
+
+impl<const CAPACITY: usize, const MAX_CONSUMERS: usize> AmlTraceSink
+    for AmlTimelineTraceSink<'_, CAPACITY, MAX_CONSUMERS>
+{
+    fn record(&self, event: &AmlTraceEvent<'_>) {
+        let span = AmlTraceSpan {
+            node: event.node,
+            location: event.location,
+            kind: event.kind,
+            region: match event.detail {
+                AmlTraceDetail::Region(access) => Some(access),
+                _ => None,
+            },
+            notify: match event.detail {
+                AmlTraceDetail::Notify(value) => Some(value),
+                _ => None,
+            },
+        };
+        match event.kind {
+            AmlTraceEventKind::MethodEnter => {
+                let token = self.open(span);
+                let depth = self.depth.get();
+                if let Some(slot) = self.spans.get(depth) {
+                    slot.set(token);
+                } else {
+                    // Too deep to remember; close now so the timeline stays balanced.
+                    self.close(token);
+                }
+                self.depth.set(depth + 1);
+            }
+            AmlTraceEventKind::MethodReturn => {
+                let Some(depth) = self.depth.get().checked_sub(1) else {
+                    return;
+                };
+                self.depth.set(depth);
+                self.close(self.spans.get(depth).and_then(Cell::take));
+            }
+            AmlTraceEventKind::RegionRead
+            | AmlTraceEventKind::RegionWrite
+            | AmlTraceEventKind::Notify => {
+                let token = self.open(span);
+                self.close(token);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::RefCell;
+
+    #[derive(Default)]
+    struct KindLog(RefCell<Vec<(AmlTraceEventKind, u16)>>);
+
+    impl AmlTraceSink for KindLog {
+        fn record(&self, event: &AmlTraceEvent<'_>) {
+            self.0.borrow_mut().push((event.kind, event.depth));
+        }
+    }
+
+    fn path(text: &str) -> AmlResolvedNamePath {
+        AmlResolvedNamePath::parse_text(text).unwrap()
+    }
+
+    #[test]
+    fn tracer_follows_subtree_into_callees_only() {
+        let log = KindLog::default();
+        let tracer = AmlTracer::new(&log).with_subtree(path("\\_SB.BAT0"));
+
+        assert!(!tracer.enter(path("\\_SB.AC0._PSR")));
+        tracer.emit(
+            None,
+            None,
+            AmlTraceEventKind::Notify,
+            AmlTraceDetail::Notify(0x80),
+        );
+        assert!(!tracer.is_active());
+
+        assert!(tracer.enter(path("\\_SB.BAT0._BST")));
+        tracer.emit(
+            None,
+            None,
+            AmlTraceEventKind::MethodEnter,
+            AmlTraceDetail::Arguments(&[]),
+        );
+        // Helpers outside the subtree are traced while `_BST` is running.
+        assert!(tracer.enter(path("\\_SB.PCI0.LPCB.EC0.ECRD")));
+        tracer.emit(
+            None,
+            None,
+            AmlTraceEventKind::MethodEnter,
+            AmlTraceDetail::Arguments(&[]),
+        );
+        tracer.leave();
+        tracer.leave();
+        assert!(!tracer.is_active());
+
+        assert_eq!(
+            *log.0.borrow(),
+            [
+                (AmlTraceEventKind::MethodEnter, 1),
+                (AmlTraceEventKind::MethodEnter, 2),
+            ]
+        );
+    }
+
+    #[cfg(feature = "debug-insights")]
+    #[test]
+    fn timeline_sink_nests_region_spans_under_methods() {
+        use fusion_sys::channel::insight::{
+            InsightCaptureMode,
+            InsightTimelineRecord,
+        };
+        use fusion_sys::transport::TransportAttachmentRequest;
+
+        let timeline =
+            LocalInsightTimeline::<AmlTraceSpan, 8>::new(InsightCaptureMode::Lossy).unwrap();
+        let consumer = timeline
+            .attach_consumer(TransportAttachmentRequest::same_courier())
+            .expect("consumer should attach");
+        let sink = AmlTimelineTraceSink::new(&timeline);
+        let access = AmlTraceRegionAccess {
+            space: AmlAddressSpaceId::EmbeddedControl,
+            address: 0x12,
+            width: AmlAccessWidth::Bits8,
+            field_access: AmlFieldAccessKind::Byte,
+            value: 0x5a,
+        };
+        let enter = AmlTraceEvent {
+            node: None,
+            location: None,
+            kind: AmlTraceEventKind::MethodEnter,
+            depth: 1,
+            detail: AmlTraceDetail::Arguments(&[]),
+        };
+        sink.record(&enter);
+        sink.record(&AmlTraceEvent {
+            kind: AmlTraceEventKind::RegionRead,
+            detail: AmlTraceDetail::Region(access),
+            ..enter
+        });
+        sink.record(&AmlTraceEvent {
+            kind: AmlTraceEventKind::MethodReturn,
+            detail: AmlTraceDetail::Return(None),
+            ..enter
+        });
+
+        let Some(InsightTimelineRecord::SpanOpened {
+            span: method,
+            parent: None,
+            ..
+        }) = timeline.try_receive(consumer).unwrap()
+        else {
+            panic!("method span should open first");
+        };
+        let Some(InsightTimelineRecord::SpanOpened {
+            span: region,
+            parent,
+            meta,
+            ..
+        }) = timeline.try_receive(consumer).unwrap()
+        else {
+            panic!("region span should open second");
+        };
+        assert_eq!(parent, Some(method));
+        assert_eq!(meta.region, Some(access));
+        assert!(matches!(
+            timeline.try_receive(consumer).unwrap(),
+            Some(InsightTimelineRecord::SpanClosed { span, .. }) if span == region
+        ));
+        assert!(matches!(
+            timeline.try_receive(consumer).unwrap(),
+            Some(InsightTimelineRecord::SpanClosed { span, .. }) if span == method
+        ));
+    }
 }
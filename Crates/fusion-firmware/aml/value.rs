@@ -2,10 +2,13 @@
 
 use crate::aml::{
     AmlCodeLocation,
+    AmlDdbHandle,
     AmlError,
+    AmlReference,
     AmlResult,
     AmlRuntimeBufferHandle,
     AmlRuntimePackageHandle,
+    AmlRuntimeStringHandle,
 };
 
 /// Effective AML integer width for one namespace.
@@ -32,11 +35,14 @@ pub enum AmlValue<'a> {
     Integer(u64),
     String(&'a str),
     StaticString(AmlCodeLocation),
+    StringHandle(AmlRuntimeStringHandle),
     Buffer(&'a [u8]),
     BufferHandle(AmlRuntimeBufferHandle),
     Package(&'a [AmlValue<'a>]),
     StaticPackage(AmlCodeLocation),
     PackageHandle(AmlRuntimePackageHandle),
+    Reference(AmlReference),
+    DdbHandle(AmlDdbHandle),
     DebugObject,
     None,
 }
@@ -78,12 +84,12 @@ impl<'a> AmlValue<'a> {
             Self::None => false,
             Self::DebugObject => true,
             Self::String(value) => !value.is_empty(),
-            Self::StaticString(_) => true,
+            Self::StaticString(_) | Self::StringHandle(_) => true,
             Self::Buffer(value) => !value.is_empty(),
             Self::BufferHandle(_) => true,
             Self::Package(value) => !value.is_empty(),
             Self::StaticPackage(_) => true,
-            Self::PackageHandle(_) => true,
+            Self::PackageHandle(_) | Self::Reference(_) | Self::DdbHandle(_) => true,
         }
     }
 }
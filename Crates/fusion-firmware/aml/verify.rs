@@ -10,6 +10,7 @@ use fusion_hal::drivers::acpi::public::interface::backend::{
 
 use crate::aml::{
     AmlAddressSpaceId,
+    AmlDefinitionBlockSet,
     AmlError,
     AmlLoadedNamespace,
     AmlObjectKind,
@@ -49,6 +50,10 @@ pub struct AmlBackendVerificationIssue {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AmlBackendVerificationReport<'a> {
     pub issues: &'a [AmlBackendVerificationIssue],
+    /// Definition blocks the namespace was built from, including tables loaded by AML.
+    pub definition_blocks: usize,
+    /// Tables among `definition_blocks` that AML loaded at runtime and has not unloaded.
+    pub dynamic_blocks: usize,
 }
 
 impl<'a> AmlBackendVerificationReport<'a> {
@@ -82,14 +87,19 @@ impl<'a> AmlBackendIssueWriter<'a> {
         Ok(())
     }
 
-    fn finish(self) -> AmlBackendVerificationReport<'a> {
+    fn finish(self, blocks: AmlDefinitionBlockSet<'_>) -> AmlBackendVerificationReport<'a> {
         let issues = unsafe {
             slice::from_raw_parts(
                 self.storage.as_ptr().cast::<AmlBackendVerificationIssue>(),
                 self.len,
             )
         };
-        AmlBackendVerificationReport { issues }
+        let dynamic_blocks = blocks.dynamic.loaded_count();
+        AmlBackendVerificationReport {
+            issues,
+            definition_blocks: blocks.total_block_count() + dynamic_blocks,
+            dynamic_blocks,
+        }
     }
 }
 
@@ -166,7 +176,7 @@ pub fn verify_acpi_backend<'records, 'blocks, 'storage, B: AcpiAmlBackend>(
         }
     }
 
-    Ok(writer.finish())
+    Ok(writer.finish(namespace.blocks))
 }
 
 fn verify_root(
@@ -270,6 +280,8 @@ mod tests {
         AmlSleepHost,
         AmlSystemIoHost,
         AmlSystemMemoryHost,
+        AmlTableHost,
+        AmlTraceHost,
         AmlVm,
         AmlValue,
         AmlEmbeddedControllerHost,
@@ -477,6 +489,10 @@ mod tests {
         }
     }
 
+    impl AmlTableHost for DellRegionHost {}
+
+    impl AmlTraceHost for DellRegionHost {}
+
     impl AmlHost for DellRegionHost {}
 
     fn load_definition_block(
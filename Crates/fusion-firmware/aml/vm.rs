@@ -123,7 +123,7 @@ impl AmlVm {
             });
         }
 
-        let evaluator = AmlPureEvaluator::new(namespace);
+        let evaluator = self.evaluator(namespace, host);
         let mut report = AmlVmLifecycleReport {
             invoked: 0,
             skipped: 0,
@@ -199,7 +199,7 @@ impl AmlVm {
             self.state = AmlVmState::Loaded;
         }
 
-        let evaluator = AmlPureEvaluator::new(namespace);
+        let evaluator = self.evaluator(namespace, host);
         let mut report = AmlVmLifecycleReport {
             invoked: 0,
             skipped: 0,
@@ -294,6 +294,19 @@ impl AmlVm {
         )
     }
 
+    /// Builds the evaluator for one pass, attached to the host tracer when tracing is enabled.
+    fn evaluator<'a, 'blocks>(
+        self,
+        namespace: AmlLoadedNamespace<'a, 'blocks>,
+        host: &'a dyn AmlRegionAccessHost,
+    ) -> AmlPureEvaluator<'a, 'blocks> {
+        let evaluator = AmlPureEvaluator::new(namespace);
+        match host.tracer() {
+            Some(tracer) if self.config.enable_tracing => evaluator.with_tracer(tracer),
+            _ => evaluator,
+        }
+    }
+
     fn should_run_initializer(
         &self,
         namespace: AmlLoadedNamespace<'_, '_>,
@@ -351,7 +364,7 @@ impl AmlVm {
         expected_prefix: [u8; 2],
         expected_code: u8,
     ) -> AmlResult<AmlVmHandlerDispatchReport> {
-        let evaluator = AmlPureEvaluator::new(namespace);
+        let evaluator = self.evaluator(namespace, host);
         let mut report = AmlVmHandlerDispatchReport {
             invoked: 0,
             blocked: 0,
@@ -457,6 +470,8 @@ mod tests {
         AmlSleepHost,
         AmlSystemIoHost,
         AmlSystemMemoryHost,
+        AmlTableHost,
+        AmlTraceHost,
         AmlPciConfigHost,
         AmlAccessWidth,
         AmlNotifyEvent,
@@ -647,6 +662,10 @@ mod tests {
         }
     }
 
+    impl AmlTableHost for FakeHost {}
+
+    impl AmlTraceHost for FakeHost {}
+
     impl AmlHost for FakeHost {}
 
     #[test]
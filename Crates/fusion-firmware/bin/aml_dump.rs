@@ -0,0 +1,447 @@
+//! Hosted AML bring-up tool.
+//!
+//! Loads DSDT/SSDT images from disk, prints the namespace tree, optionally disassembles every
+//! block, and can evaluate one method against a logging mock host, optionally tracing it.
+
+use std::cell::Cell;
+use std::env;
+use std::fs;
+use std::mem::MaybeUninit;
+use std::string::String;
+use std::vec::Vec;
+
+use fusion_firmware::aml::{
+    AmlAccessWidth,
+    AmlDefinitionBlock,
+    AmlDefinitionBlockSet,
+    AmlDisassembler,
+    AmlEmbeddedControllerHost,
+    AmlErrorKind,
+    AmlExecutionPhase,
+    AmlHost,
+    AmlLoadedNamespace,
+    AmlMethodInvocation,
+    AmlNamespaceDump,
+    AmlNamespaceLoadPlan,
+    AmlNamespaceLoadRecord,
+    AmlNamespaceNodeId,
+    AmlNamespaceNodePayload,
+    AmlNotifySink,
+    AmlOspmInterface,
+    AmlPciConfigHost,
+    AmlPureEvaluator,
+    AmlResolvedNamePath,
+    AmlResult,
+    AmlRuntimeBufferSlot,
+    AmlRuntimeEventSlot,
+    AmlRuntimeIntegerSlot,
+    AmlRuntimeMutexSlot,
+    AmlRuntimePackageSlot,
+    AmlRuntimeState,
+    AmlRuntimeStringSlot,
+    AmlSleepHost,
+    AmlSystemIoHost,
+    AmlSystemMemoryHost,
+    AmlTableHost,
+    AmlTraceDetail,
+    AmlTraceEvent,
+    AmlTraceEventKind,
+    AmlTraceHost,
+    AmlTraceSink,
+    AmlTracer,
+    AmlValue,
+};
+use fusion_firmware::pal::hal::acpi::AcpiTableView;
+
+const INITIAL_NAMESPACE_RECORDS: usize = 4096;
+const MAX_NAMESPACE_RECORDS: usize = 1 << 20;
+const RUNTIME_SLOTS: usize = 256;
+
+fn main() {
+    if let Err(error) = try_main() {
+        eprintln!("fusion_firmware_aml_dump: {error}");
+        std::process::exit(1);
+    }
+}
+
+fn try_main() -> Result<(), String> {
+    let options = Options::parse(env::args().skip(1))?;
+    let images = options
+        .tables
+        .iter()
+        .map(|path| fs::read(path).map_err(|error| format!("{path}: {error}")))
+        .collect::<Result<Vec<_>, _>>()?;
+    let blocks = images
+        .iter()
+        .zip(&options.tables)
+        .map(|(bytes, path)| {
+            let table =
+                AcpiTableView::parse(bytes).map_err(|error| format!("{path}: {error:?}"))?;
+            AmlDefinitionBlock::from_acpi_table(table).map_err(|error| format!("{path}: {error:?}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let (dsdt, ssdts) = blocks.split_first().ok_or_else(usage)?;
+    let plan =
+        AmlNamespaceLoadPlan::from_definition_blocks(AmlDefinitionBlockSet::new(*dsdt, ssdts));
+
+    let mut storage = vec![MaybeUninit::uninit(); namespace_capacity(plan)?];
+    let namespace = plan
+        .load_into(&mut storage)
+        .map_err(|error| format!("namespace load failed: {error:?}"))?;
+
+    print!("{}", AmlNamespaceDump(namespace));
+    if options.disassemble {
+        for block in &blocks {
+            println!();
+            print!("{}", AmlDisassembler::new(*block).with_namespace(namespace));
+        }
+    }
+    if let Some(path) = &options.evaluate {
+        println!();
+        evaluate(namespace, path, &options.args, options.trace)?;
+    }
+    Ok(())
+}
+
+/// Smallest power-of-two record count the namespace fits in.
+fn namespace_capacity(plan: AmlNamespaceLoadPlan<'_>) -> Result<usize, String> {
+    let mut capacity = INITIAL_NAMESPACE_RECORDS;
+    loop {
+        let mut scratch = vec![MaybeUninit::<AmlNamespaceLoadRecord>::uninit(); capacity];
+        match plan.load_into(&mut scratch) {
+            Ok(_) => return Ok(capacity),
+            Err(error)
+                if error.kind == AmlErrorKind::Overflow && capacity < MAX_NAMESPACE_RECORDS =>
+            {
+                capacity *= 2;
+            }
+            Err(error) => return Err(format!("namespace load failed: {error:?}")),
+        }
+    }
+}
+
+struct Options {
+    tables: Vec<String>,
+    disassemble: bool,
+    evaluate: Option<String>,
+    args: Vec<String>,
+    trace: bool,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self {
+            tables: Vec::new(),
+            disassemble: false,
+            evaluate: None,
+            args: Vec::new(),
+            trace: false,
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--disassemble" | "-d" => options.disassemble = true,
+                "--eval" | "-e" => options.evaluate = Some(args.next().ok_or_else(usage)?),
+                "--arg" | "-a" => options.args.push(args.next().ok_or_else(usage)?),
+                "--trace" | "-t" => options.trace = true,
+                "--help" | "-h" => return Err(usage()),
+                _ if arg.starts_with('-') => {
+                    return Err(format!("unknown option `{arg}`\n{}", usage()));
+                }
+                _ => options.tables.push(arg),
+            }
+        }
+        if options.tables.is_empty() {
+            return Err(usage());
+        }
+        Ok(options)
+    }
+}
+
+fn usage() -> String {
+    String::from(
+        "usage: fusion_firmware_aml_dump [--disassemble] \
+         [--eval <\\PATH> [--arg <value>]... [--trace]] <DSDT> [SSDT...]\n\
+         \n\
+         Arguments are integers (decimal or 0x-prefixed hex); anything else is passed as a string.\n\
+         --trace logs method calls and region accesses made while evaluating.",
+    )
+}
+
+fn evaluate(
+    namespace: AmlLoadedNamespace<'_, '_>,
+    path: &str,
+    args: &[String],
+    trace: bool,
+) -> Result<(), String> {
+    let resolved =
+        AmlResolvedNamePath::parse_text(path).map_err(|error| format!("{path}: {error:?}"))?;
+    let record = namespace
+        .record_by_path(resolved)
+        .ok_or_else(|| format!("{path}: not found in namespace"))?;
+    let AmlNamespaceNodePayload::Method(method) = record.payload else {
+        return Err(format!(
+            "{path}: not a method ({:?})",
+            record.descriptor.kind
+        ));
+    };
+    let values = args.iter().map(|arg| parse_arg(arg)).collect::<Vec<_>>();
+
+    let slots = RuntimeSlots::new();
+    let state = slots.state();
+    let host = MockHost;
+    let printer = TracePrinter(namespace);
+    let tracer = AmlTracer::new(&printer);
+    let evaluator = AmlPureEvaluator::new(namespace);
+    let evaluator = if trace {
+        evaluator.with_tracer(&tracer)
+    } else {
+        evaluator
+    };
+    println!("evaluating {path}");
+    let outcome = evaluator.evaluate_with_host_and_state(
+        &host,
+        &state,
+        AmlMethodInvocation {
+            method: record.descriptor.id,
+            phase: AmlExecutionPhase::Runtime,
+            args: &values,
+        },
+    );
+    match outcome {
+        Ok(outcome) if outcome.blocked => println!("blocked"),
+        Ok(outcome) => match outcome.return_value {
+            Some(value) => println!("returned {}", render_value(&state, &value)),
+            None => println!("returned nothing"),
+        },
+        Err(error) => {
+            println!("failed: {:?}: {}", error.kind, error.detail);
+            let block = namespace
+                .blocks
+                .block(method.body.block_index)
+                .ok_or_else(|| format!("{path}: body outside the loaded blocks"))?;
+            let mut text = String::new();
+            AmlDisassembler::new(block)
+                .with_namespace(namespace)
+                .write_span(method.body.span, resolved, &mut text)
+                .map_err(|error| error.to_string())?;
+            print!("{text}");
+        }
+    }
+    Ok(())
+}
+
+fn parse_arg(arg: &str) -> AmlValue<'_> {
+    let integer = arg
+        .strip_prefix("0x")
+        .or_else(|| arg.strip_prefix("0X"))
+        .map_or_else(
+            || arg.parse::<u64>().ok(),
+            |hex| u64::from_str_radix(hex, 16).ok(),
+        );
+    integer.map_or(AmlValue::String(arg), AmlValue::Integer)
+}
+
+fn render_value(state: &AmlRuntimeState<'_>, value: &AmlValue<'_>) -> String {
+    match *value {
+        AmlValue::Integer(value) => format!("0x{value:X}"),
+        AmlValue::String(text) => format!("{text:?}"),
+        AmlValue::StringHandle(handle) => {
+            let bytes = (0..state.read_string_len(handle).unwrap_or(0))
+                .filter_map(|index| state.read_string_byte(handle, index))
+                .collect::<Vec<_>>();
+            format!("{:?}", String::from_utf8_lossy(&bytes))
+        }
+        AmlValue::BufferHandle(handle) => {
+            let bytes = (0..state.read_buffer_len(handle).unwrap_or(0))
+                .filter_map(|index| state.read_buffer_byte(handle, index))
+                .collect::<Vec<_>>();
+            format!("Buffer {bytes:02X?}")
+        }
+        ref other => format!("{other:?}"),
+    }
+}
+
+fn node_path(namespace: AmlLoadedNamespace<'_, '_>, node: Option<AmlNamespaceNodeId>) -> String {
+    let Some(record) = node.and_then(|node| namespace.record(node)) else {
+        return String::from("?");
+    };
+    let mut text = [0_u8; 256];
+    record.descriptor.path.write_text(&mut text).map_or_else(
+        |_| String::from("?"),
+        |len| String::from_utf8_lossy(&text[..len]).into_owned(),
+    )
+}
+
+/// Trace sink printing one indented line per event next to the mock host log.
+struct TracePrinter<'records, 'blocks>(AmlLoadedNamespace<'records, 'blocks>);
+
+impl AmlTraceSink for TracePrinter<'_, '_> {
+    fn record(&self, event: &AmlTraceEvent<'_>) {
+        let indent = "  ".repeat(usize::from(event.depth));
+        let at = event
+            .location
+            .map(|location| format!(" @ 0x{:04X}", location.code.span.offset))
+            .unwrap_or_default();
+        let node = node_path(self.0, event.node);
+        let what = match (event.kind, event.detail) {
+            (AmlTraceEventKind::MethodEnter, AmlTraceDetail::Arguments(args)) => {
+                format!("-> {node} ({} args)", args.len())
+            }
+            (_, AmlTraceDetail::Return(Some(value))) => format!("<- {node} = {value:?}"),
+            (_, AmlTraceDetail::Return(None)) => format!("<- {node}"),
+            (_, AmlTraceDetail::Error(error)) => format!("<- {node} failed: {:?}", error.kind),
+            (kind, AmlTraceDetail::Region(access)) => format!(
+                "{} {node} {:?} 0x{:X} ({:?}, field {:?}) = 0x{:X}",
+                if kind == AmlTraceEventKind::RegionRead {
+                    "read"
+                } else {
+                    "write"
+                },
+                access.space,
+                access.address,
+                access.width,
+                access.field_access,
+                access.value,
+            ),
+            (_, AmlTraceDetail::Notify(value)) => format!("notify {node} 0x{value:02X}"),
+            (kind, _) => format!("{kind:?} {node}"),
+        };
+        eprintln!("{indent}{what}{at}");
+    }
+}
+
+struct RuntimeSlots {
+    integers: Vec<Cell<Option<AmlRuntimeIntegerSlot>>>,
+    packages: Vec<Cell<Option<AmlRuntimePackageSlot>>>,
+    buffers: Vec<Cell<Option<AmlRuntimeBufferSlot>>>,
+    strings: Vec<Cell<Option<AmlRuntimeStringSlot>>>,
+    mutexes: Vec<Cell<Option<AmlRuntimeMutexSlot>>>,
+    events: Vec<Cell<Option<AmlRuntimeEventSlot>>>,
+}
+
+impl RuntimeSlots {
+    fn new() -> Self {
+        fn slots<T: Copy>() -> Vec<Cell<Option<T>>> {
+            (0..RUNTIME_SLOTS).map(|_| Cell::new(None)).collect()
+        }
+        Self {
+            integers: slots(),
+            packages: slots(),
+            buffers: slots(),
+            strings: slots(),
+            mutexes: slots(),
+            events: slots(),
+        }
+    }
+
+    fn state(&self) -> AmlRuntimeState<'_> {
+        AmlRuntimeState::new(&self.integers)
+            .with_packages(&self.packages)
+            .with_buffers(&self.buffers)
+            .with_strings(&self.strings)
+            .with_mutexes(&self.mutexes)
+            .with_events(&self.events)
+    }
+}
+
+/// Host that logs every side effect and reads zero from every region.
+struct MockHost;
+
+impl AmlOspmInterface for MockHost {
+    fn osi_supported(&self, interface: &str) -> bool {
+        let supported = interface.starts_with("Windows");
+        eprintln!("  _OSI (\"{interface}\") -> {supported}");
+        supported
+    }
+
+    fn os_revision(&self) -> u64 {
+        0
+    }
+}
+
+impl AmlSleepHost for MockHost {
+    fn stall_us(&self, microseconds: u32) -> AmlResult<()> {
+        eprintln!("  Stall ({microseconds} us)");
+        Ok(())
+    }
+
+    fn sleep_ms(&self, milliseconds: u32) -> AmlResult<()> {
+        eprintln!("  Sleep ({milliseconds} ms)");
+        Ok(())
+    }
+
+    fn timer_100ns(&self) -> AmlResult<u64> {
+        Ok(0)
+    }
+}
+
+impl AmlNotifySink for MockHost {
+    fn notify(&self, source: AmlNamespaceNodeId, value: u8) -> AmlResult<()> {
+        eprintln!("  Notify (node {}, 0x{value:02X})", source.0);
+        Ok(())
+    }
+
+    fn fatal(&self, fatal_type: u8, code: u32, argument: u64) -> AmlResult<()> {
+        eprintln!("  Fatal (0x{fatal_type:02X}, 0x{code:08X}, 0x{argument:X})");
+        Ok(())
+    }
+}
+
+impl AmlTableHost for MockHost {}
+
+impl AmlTraceHost for MockHost {}
+
+impl AmlHost for MockHost {}
+
+impl AmlSystemMemoryHost for MockHost {
+    fn read_system_memory(&self, address: u64, width: AmlAccessWidth) -> AmlResult<u64> {
+        eprintln!("  read  SystemMemory 0x{address:X} {width:?}");
+        Ok(0)
+    }
+
+    fn write_system_memory(
+        &self,
+        address: u64,
+        width: AmlAccessWidth,
+        value: u64,
+    ) -> AmlResult<()> {
+        eprintln!("  write SystemMemory 0x{address:X} {width:?} = 0x{value:X}");
+        Ok(())
+    }
+}
+
+impl AmlSystemIoHost for MockHost {
+    fn read_system_io(&self, port: u64, width: AmlAccessWidth) -> AmlResult<u64> {
+        eprintln!("  read  SystemIO 0x{port:X} {width:?}");
+        Ok(0)
+    }
+
+    fn write_system_io(&self, port: u64, width: AmlAccessWidth, value: u64) -> AmlResult<()> {
+        eprintln!("  write SystemIO 0x{port:X} {width:?} = 0x{value:X}");
+        Ok(())
+    }
+}
+
+impl AmlPciConfigHost for MockHost {
+    fn read_pci_config(&self, address: u64, width: AmlAccessWidth) -> AmlResult<u64> {
+        eprintln!("  read  PCI_Config 0x{address:X} {width:?}");
+        Ok(0)
+    }
+
+    fn write_pci_config(&self, address: u64, width: AmlAccessWidth, value: u64) -> AmlResult<()> {
+        eprintln!("  write PCI_Config 0x{address:X} {width:?} = 0x{value:X}");
+        Ok(())
+    }
+}
+
+impl AmlEmbeddedControllerHost for MockHost {
+    fn read_embedded_controller(&self, register: u8) -> AmlResult<u8> {
+        eprintln!("  read  EmbeddedControl 0x{register:02X}");
+        Ok(0)
+    }
+
+    fn write_embedded_controller(&self, register: u8, value: u8) -> AmlResult<()> {
+        eprintln!("  write EmbeddedControl 0x{register:02X} = 0x{value:02X}");
+        Ok(())
+    }
+}
@@ -12,8 +12,10 @@
 //! Specification 6.6 sections that define the table envelope and the first
 //! discovery hop:
 //!
+//! - Section 5.2.5.1 for finding the RSDP on legacy BIOS systems,
 //! - Section 5.2.5.2 for finding the RSDP on UEFI-enabled systems,
 //! - Section 5.2.6 for the common `DESCRIPTION_HEADER`,
+//! - Section 5.2.7 for `RSDT` (Root System Description Table),
 //! - Section 5.2.8 for `XSDT` (Extended System Description Table),
 //! - Section 5.2.9 for `FADT` (Fixed ACPI Description Table),
 //! - Section 5.2.12 for `MADT` (Multiple APIC Description Table).
@@ -91,6 +93,9 @@
 //! instead of a sprawling pile of optimistic byte-casting. Right now Fusion is
 //! only carving out the early spine:
 //!
+//! - `RSDP` (Root System Description Pointer), located through the legacy BIOS
+//!   areas or the UEFI configuration table
+//! - `RSDT` (Root System Description Table), for firmware without an `XSDT`
 //! - `XSDT` (Extended System Description Table)
 //! - `FADT` (Fixed ACPI Description Table)
 //! - `FACS` (Firmware ACPI Control Structure)
@@ -98,31 +103,63 @@
 //! - `MCFG` (PCI Express Memory-mapped Configuration Space base address
 //!   description table)
 //! - `MADT` (Multiple APIC Description Table)
+//! - `HPET` (IA-PC High Precision Event Timer Table)
+//! - `SRAT` (System Resource Affinity Table) and `SLIT` (System Locality
+//!   Information Table)
+//! - `SPCR` (Serial Port Console Redirection Table) and `DBG2` (Debug Port
+//!   Table 2)
+//! - `BGRT` (Boot Graphics Resource Table)
+//! - `FPDT` (Firmware Performance Data Table) and the `FBPT` it points at
+//! - `ECDT` (Embedded Controller Boot Resources Table)
 //!
 //! Everything else can wait its turn in the standards minefield.
 
+mod bgrt;
+mod dbg2;
 mod dsdt;
+mod ecdt;
 mod error;
 mod facs;
 mod fadt;
+mod fpdt;
+mod gas;
+mod generic;
 mod header;
+mod hpet;
 mod madt;
 mod mcfg;
 mod realize;
+mod rsdp;
+mod rsdt;
+mod slit;
+mod spcr;
+mod srat;
 mod xsdt;
 
 use core::mem::MaybeUninit;
 use core::mem::size_of;
 use core::ptr;
 
+pub use bgrt::*;
+pub use dbg2::*;
 pub use dsdt::*;
+pub use ecdt::*;
 pub use error::*;
 pub use facs::*;
 pub use fadt::*;
+pub use fpdt::*;
+pub use gas::*;
+pub use generic::*;
 pub use header::*;
+pub use hpet::*;
 pub use madt::*;
 pub use mcfg::*;
 pub use realize::*;
+pub use rsdp::*;
+pub use rsdt::*;
+pub use slit::*;
+pub use spcr::*;
+pub use srat::*;
 pub use xsdt::*;
 
 pub(crate) fn read_unaligned_copy<T: Copy>(bytes: &[u8]) -> Result<T, AcpiError> {
@@ -0,0 +1,188 @@
+//! BGRT definitions and helpers.
+//!
+//! The Boot Graphics Resource Table (`BGRT`) lets firmware hand its boot logo
+//! to the OS so the handoff to a native display path does not flash. ACPI 6.6
+//! Section 5.2.23 defines one fixed payload:
+//!
+//! - a status byte saying whether the image is currently displayed and how the
+//!   display was rotated when it was drawn,
+//! - the image type (only BMP is defined),
+//! - the physical address of the image and its on-screen offset.
+//!
+//! The image itself lives in boot-services memory outside the table, so this
+//! view only describes where it was; whether those bytes are still intact is a
+//! question for the memory map, not for this parser.
+
+use core::mem::size_of;
+
+use super::{
+    AcpiError,
+    AcpiSignature,
+    AcpiTableView,
+    read_unaligned_copy,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawBgrt {
+    version: u16,
+    status: u8,
+    image_type: u8,
+    image_address: u64,
+    image_offset_x: u32,
+    image_offset_y: u32,
+}
+
+/// Rotation applied to the boot image relative to the panel's native orientation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BgrtOrientation {
+    None,
+    Clockwise90,
+    Clockwise180,
+    Clockwise270,
+}
+
+/// Encoding of the boot image.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BgrtImageType {
+    Bitmap,
+    Reserved(u8),
+}
+
+/// Borrowed validated BGRT view.
+#[derive(Clone, Copy, Debug)]
+pub struct Bgrt<'a> {
+    table: AcpiTableView<'a>,
+    status: u8,
+    image_type: u8,
+    image_address: u64,
+    image_offset_x: u32,
+    image_offset_y: u32,
+}
+
+impl<'a> Bgrt<'a> {
+    /// Parses one validated BGRT.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the table is malformed, truncated, not one BGRT, or carries
+    /// an unknown version.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, AcpiError> {
+        let table = AcpiTableView::parse_signature(bytes, AcpiSignature::BGRT)?;
+        let payload = table.payload();
+        if payload.len() < size_of::<RawBgrt>() {
+            return Err(AcpiError::truncated());
+        }
+        let raw: RawBgrt = read_unaligned_copy(payload)?;
+        if u16::from_le(raw.version) != 1 {
+            return Err(AcpiError::invalid_layout());
+        }
+        Ok(Self {
+            table,
+            status: raw.status,
+            image_type: raw.image_type,
+            image_address: u64::from_le(raw.image_address),
+            image_offset_x: u32::from_le(raw.image_offset_x),
+            image_offset_y: u32::from_le(raw.image_offset_y),
+        })
+    }
+
+    /// Returns the underlying validated ACPI table view.
+    #[must_use]
+    pub const fn table(self) -> AcpiTableView<'a> {
+        self.table
+    }
+
+    /// Returns `true` when the image is still on screen.
+    #[must_use]
+    pub const fn displayed(self) -> bool {
+        self.status & 0x01 != 0
+    }
+
+    /// Returns the rotation the firmware applied when drawing the image.
+    #[must_use]
+    pub const fn orientation(self) -> BgrtOrientation {
+        match (self.status >> 1) & 0x03 {
+            0 => BgrtOrientation::None,
+            1 => BgrtOrientation::Clockwise90,
+            2 => BgrtOrientation::Clockwise180,
+            _ => BgrtOrientation::Clockwise270,
+        }
+    }
+
+    /// Returns the image encoding.
+    #[must_use]
+    pub const fn image_type(self) -> BgrtImageType {
+        match self.image_type {
+            0 => BgrtImageType::Bitmap,
+            other => BgrtImageType::Reserved(other),
+        }
+    }
+
+    /// Returns the physical address of the image.
+    #[must_use]
+    pub const fn image_address(self) -> u64 {
+        self.image_address
+    }
+
+    /// Returns the image's top-left corner in screen pixels.
+    #[must_use]
+    pub const fn image_offset(self) -> (u32, u32) {
+        (self.image_offset_x, self.image_offset_y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::AcpiErrorKind;
+    use super::*;
+
+    /// BGRT shaped like EDK2 OVMF publishes it for a centred 1024x768 logo.
+    fn build_bgrt(version: u16) -> [u8; 56] {
+        let mut bytes = [0_u8; 56];
+        bytes[0..4].copy_from_slice(b"BGRT");
+        bytes[4..8].copy_from_slice(&(56_u32).to_le_bytes());
+        bytes[8] = 1;
+        bytes[10..16].copy_from_slice(b"INTEL ");
+        bytes[16..24].copy_from_slice(b"EDK2    ");
+        bytes[36..38].copy_from_slice(&version.to_le_bytes());
+        bytes[38] = 0x01;
+        bytes[39] = 0;
+        bytes[40..48].copy_from_slice(&0x7E5C_2018_u64.to_le_bytes());
+        bytes[48..52].copy_from_slice(&413_u32.to_le_bytes());
+        bytes[52..56].copy_from_slice(&271_u32.to_le_bytes());
+        let checksum =
+            (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        bytes[9] = checksum;
+        bytes
+    }
+
+    #[test]
+    fn bgrt_describes_displayed_bitmap() {
+        let bytes = build_bgrt(1);
+        let bgrt = Bgrt::parse(&bytes).expect("bgrt should parse");
+        assert!(bgrt.displayed());
+        assert_eq!(bgrt.orientation(), BgrtOrientation::None);
+        assert_eq!(bgrt.image_type(), BgrtImageType::Bitmap);
+        assert_eq!(bgrt.image_address(), 0x7E5C_2018);
+        assert_eq!(bgrt.image_offset(), (413, 271));
+    }
+
+    #[test]
+    fn bgrt_rejects_unknown_version() {
+        let bytes = build_bgrt(2);
+        let error = Bgrt::parse(&bytes).expect_err("version 2 should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidLayout);
+    }
+
+    #[test]
+    fn bgrt_parses_acpica_template() {
+        let bytes = include_bytes!("../../../tests/fixtures/acpi/bgrt.dat");
+        let bgrt = Bgrt::parse(bytes).expect("template bgrt should parse");
+        assert!(!bgrt.displayed());
+        assert_eq!(bgrt.orientation(), BgrtOrientation::None);
+        assert_eq!(bgrt.image_type(), BgrtImageType::Bitmap);
+        assert_eq!(bgrt.image_address(), 0);
+        assert_eq!(bgrt.image_offset(), (0, 0));
+    }
+}
@@ -0,0 +1,452 @@
+//! DBG2 definitions and helpers.
+//!
+//! The Debug Port Table 2 (`DBG2`) is Microsoft-defined and only
+//! signature-reserved by ACPI 6.6. Where `SPCR` names one console, `DBG2` lists
+//! every debug transport firmware is willing to hand over: serial ports,
+//! IEEE 1394, USB debug-capable controllers and network debug devices.
+//!
+//! The table is a count and offset to an array of variable-length device
+//! information blocks. Each block in turn carries offsets, relative to itself,
+//! to:
+//!
+//! - an array of Generic Address Structures for the device's registers,
+//! - a parallel array of `u32` register-window sizes,
+//! - a NUL-terminated ACPI namespace path (`"."` when there is none),
+//! - optional OEM data.
+//!
+//! That is four nested offset hops per device, so every one of them is bounds
+//! checked against its own block before a view is handed out.
+
+use core::mem::size_of;
+
+use super::{
+    AcpiError,
+    AcpiGenericAddress,
+    AcpiSerialPortType,
+    AcpiSignature,
+    AcpiTableView,
+    read_unaligned_copy,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawDbg2Header {
+    device_info_offset: u32,
+    device_info_count: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawDbg2DeviceInfo {
+    revision: u8,
+    length: u16,
+    register_count: u8,
+    namespace_string_length: u16,
+    namespace_string_offset: u16,
+    oem_data_length: u16,
+    oem_data_offset: u16,
+    port_type: u16,
+    port_subtype: u16,
+    reserved: u16,
+    base_address_offset: u16,
+    address_size_offset: u16,
+}
+
+/// USB debug-capable host controller kind.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Dbg2UsbController {
+    Xhci,
+    Ehci,
+    Reserved(u16),
+}
+
+/// Decoded DBG2 debug-port type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Dbg2PortType {
+    Serial(AcpiSerialPortType),
+    Ieee1394 {
+        subtype: u16,
+    },
+    Usb(Dbg2UsbController),
+    /// Network debug device; the subtype is the PCI vendor ID of the NIC.
+    Net {
+        pci_vendor_id: u16,
+    },
+    Reserved {
+        port_type: u16,
+        subtype: u16,
+    },
+}
+
+impl Dbg2PortType {
+    #[must_use]
+    pub const fn from_raw(port_type: u16, subtype: u16) -> Self {
+        match port_type {
+            0x8000 => Self::Serial(AcpiSerialPortType::from_raw(subtype)),
+            0x8001 => Self::Ieee1394 { subtype },
+            0x8002 => Self::Usb(match subtype {
+                0 => Dbg2UsbController::Xhci,
+                1 => Dbg2UsbController::Ehci,
+                other => Dbg2UsbController::Reserved(other),
+            }),
+            0x8003 => Self::Net {
+                pci_vendor_id: subtype,
+            },
+            _ => Self::Reserved { port_type, subtype },
+        }
+    }
+}
+
+/// One register window of a DBG2 device.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Dbg2Register {
+    pub address: AcpiGenericAddress,
+    pub size: u32,
+}
+
+/// Borrowed validated DBG2 view.
+#[derive(Clone, Copy, Debug)]
+pub struct Dbg2<'a> {
+    table: AcpiTableView<'a>,
+    device_info_offset: usize,
+    device_info_count: u32,
+}
+
+impl<'a> Dbg2<'a> {
+    /// Parses one validated DBG2.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the table is malformed, truncated, not one DBG2, or its
+    /// device-information array starts outside the table.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, AcpiError> {
+        let table = AcpiTableView::parse_signature(bytes, AcpiSignature::DBG2)?;
+        let payload = table.payload();
+        if payload.len() < size_of::<RawDbg2Header>() {
+            return Err(AcpiError::truncated());
+        }
+        let raw: RawDbg2Header = read_unaligned_copy(payload)?;
+        let device_info_offset = usize::try_from(u32::from_le(raw.device_info_offset))
+            .map_err(|_| AcpiError::invalid_layout())?;
+        let header_end = table.bytes().len() - payload.len() + size_of::<RawDbg2Header>();
+        if device_info_offset < header_end || device_info_offset > table.bytes().len() {
+            return Err(AcpiError::invalid_layout());
+        }
+        Ok(Self {
+            table,
+            device_info_offset,
+            device_info_count: u32::from_le(raw.device_info_count),
+        })
+    }
+
+    /// Returns the underlying validated ACPI table view.
+    #[must_use]
+    pub const fn table(self) -> AcpiTableView<'a> {
+        self.table
+    }
+
+    /// Returns the number of debug devices the table declares.
+    #[must_use]
+    pub const fn device_count(self) -> u32 {
+        self.device_info_count
+    }
+
+    /// Returns an iterator over the declared debug devices.
+    #[must_use]
+    pub fn devices(&self) -> Dbg2DeviceIter<'a> {
+        Dbg2DeviceIter {
+            bytes: &self.table.bytes()[self.device_info_offset..],
+            offset: 0,
+            remaining: self.device_info_count,
+        }
+    }
+}
+
+/// Borrowed validated DBG2 device-information block.
+#[derive(Clone, Copy, Debug)]
+pub struct Dbg2Device<'a> {
+    bytes: &'a [u8],
+    raw: RawDbg2DeviceInfo,
+    namespace_path: &'a [u8],
+}
+
+impl<'a> Dbg2Device<'a> {
+    fn parse(bytes: &'a [u8]) -> Result<Self, AcpiError> {
+        let raw: RawDbg2DeviceInfo = read_unaligned_copy(bytes)?;
+        let count = usize::from(raw.register_count);
+        block_range(
+            bytes,
+            u16::from_le(raw.base_address_offset),
+            count * AcpiGenericAddress::SIZE,
+        )?;
+        block_range(
+            bytes,
+            u16::from_le(raw.address_size_offset),
+            count * size_of::<u32>(),
+        )?;
+        block_range(
+            bytes,
+            u16::from_le(raw.oem_data_offset),
+            usize::from(u16::from_le(raw.oem_data_length)),
+        )?;
+        let namespace = block_range(
+            bytes,
+            u16::from_le(raw.namespace_string_offset),
+            usize::from(u16::from_le(raw.namespace_string_length)),
+        )?;
+        let Some((0, namespace_path)) = namespace.split_last() else {
+            return Err(AcpiError::invalid_layout());
+        };
+        Ok(Self {
+            bytes,
+            raw,
+            namespace_path,
+        })
+    }
+
+    /// Returns the device-information block revision.
+    #[must_use]
+    pub const fn revision(self) -> u8 {
+        self.raw.revision
+    }
+
+    /// Returns the decoded debug-port type.
+    #[must_use]
+    pub const fn port_type(self) -> Dbg2PortType {
+        Dbg2PortType::from_raw(
+            u16::from_le(self.raw.port_type),
+            u16::from_le(self.raw.port_subtype),
+        )
+    }
+
+    /// Returns the ACPI namespace path without its NUL terminator, or `None` for `"."`.
+    #[must_use]
+    pub fn namespace_path(self) -> Option<&'a [u8]> {
+        (self.namespace_path != b".").then_some(self.namespace_path)
+    }
+
+    /// Returns the OEM data bytes, if any.
+    #[must_use]
+    pub fn oem_data(self) -> Option<&'a [u8]> {
+        let length = usize::from(u16::from_le(self.raw.oem_data_length));
+        if length == 0 {
+            return None;
+        }
+        block_range(self.bytes, u16::from_le(self.raw.oem_data_offset), length).ok()
+    }
+
+    /// Returns the number of register windows.
+    #[must_use]
+    pub const fn register_count(self) -> u8 {
+        self.raw.register_count
+    }
+
+    /// Returns one register window.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the index is out of range or the address is malformed.
+    pub fn register(self, index: u8) -> Result<Dbg2Register, AcpiError> {
+        if index >= self.raw.register_count {
+            return Err(AcpiError::invalid_layout());
+        }
+        let index = usize::from(index);
+        let addresses = &self.bytes[usize::from(u16::from_le(self.raw.base_address_offset))..];
+        let sizes = &self.bytes[usize::from(u16::from_le(self.raw.address_size_offset))..];
+        let address = AcpiGenericAddress::parse(&addresses[index * AcpiGenericAddress::SIZE..])?;
+        let size: u32 = read_unaligned_copy(&sizes[index * size_of::<u32>()..])?;
+        Ok(Dbg2Register {
+            address,
+            size: u32::from_le(size),
+        })
+    }
+}
+
+fn block_range(bytes: &[u8], offset: u16, length: usize) -> Result<&[u8], AcpiError> {
+    let start = usize::from(offset);
+    if length != 0 && start < size_of::<RawDbg2DeviceInfo>() {
+        return Err(AcpiError::invalid_layout());
+    }
+    bytes
+        .get(start..start + length)
+        .ok_or_else(AcpiError::truncated)
+}
+
+/// Iterator over DBG2 device-information blocks.
+#[derive(Clone, Debug)]
+pub struct Dbg2DeviceIter<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    remaining: u32,
+}
+
+impl<'a> Iterator for Dbg2DeviceIter<'a> {
+    type Item = Result<Dbg2Device<'a>, AcpiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let Some(header_bytes) = self.bytes.get(self.offset..) else {
+            self.remaining = 0;
+            return Some(Err(AcpiError::truncated()));
+        };
+        let raw: RawDbg2DeviceInfo = match read_unaligned_copy(header_bytes) {
+            Ok(raw) => raw,
+            Err(error) => {
+                self.remaining = 0;
+                return Some(Err(error));
+            }
+        };
+        let length = usize::from(u16::from_le(raw.length));
+        if length < size_of::<RawDbg2DeviceInfo>() {
+            self.remaining = 0;
+            return Some(Err(AcpiError::invalid_layout()));
+        }
+        let Some(block) = header_bytes.get(..length) else {
+            self.remaining = 0;
+            return Some(Err(AcpiError::truncated()));
+        };
+        self.offset += length;
+        Some(Dbg2Device::parse(block))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        AcpiAccessSize,
+        AcpiAddressSpace,
+        AcpiErrorKind,
+    };
+    use super::*;
+    use std::vec::Vec;
+
+    /// One 16550 at COM1 plus one xHCI debug port, laid out like a client laptop DBG2.
+    fn build_dbg2() -> Vec<u8> {
+        let mut bytes = vec![0_u8; 44];
+        bytes[0..4].copy_from_slice(b"DBG2");
+        bytes[8] = 0;
+        bytes[10..16].copy_from_slice(b"FUSION");
+        bytes[16..24].copy_from_slice(b"DBG2TEST");
+        bytes[36..40].copy_from_slice(&44_u32.to_le_bytes());
+        bytes[40..44].copy_from_slice(&2_u32.to_le_bytes());
+
+        let devices: [(u16, u16, u64, &[u8]); 2] = [
+            (0x8000, 0x0000, 0x3F8, b"\\_SB.PCI0.UAR1\0"),
+            (0x8002, 0x0000, 0xFE00_0000, b".\0"),
+        ];
+        for (port_type, subtype, address, namespace) in devices {
+            let base_offset = 22_u16;
+            let size_offset = base_offset + 12;
+            let namespace_offset = size_offset + 4;
+            let namespace_length = u16::try_from(namespace.len()).expect("namespace should fit");
+            let length = namespace_offset + namespace_length;
+            let mut block = vec![0_u8; usize::from(namespace_offset)];
+            block[1..3].copy_from_slice(&length.to_le_bytes());
+            block[3] = 1;
+            block[4..6].copy_from_slice(&namespace_length.to_le_bytes());
+            block[6..8].copy_from_slice(&namespace_offset.to_le_bytes());
+            block[12..14].copy_from_slice(&port_type.to_le_bytes());
+            block[14..16].copy_from_slice(&subtype.to_le_bytes());
+            block[18..20].copy_from_slice(&base_offset.to_le_bytes());
+            block[20..22].copy_from_slice(&size_offset.to_le_bytes());
+            let gas = usize::from(base_offset);
+            block[gas] = u8::from(port_type == 0x8000);
+            block[gas + 1] = 8;
+            block[gas + 4..gas + 12].copy_from_slice(&address.to_le_bytes());
+            block[usize::from(size_offset)..usize::from(size_offset) + 4]
+                .copy_from_slice(&8_u32.to_le_bytes());
+            block.extend_from_slice(namespace);
+            bytes.extend_from_slice(&block);
+        }
+        finish(bytes)
+    }
+
+    fn finish(mut bytes: Vec<u8>) -> Vec<u8> {
+        let length = u32::try_from(bytes.len()).expect("table should fit");
+        bytes[4..8].copy_from_slice(&length.to_le_bytes());
+        bytes[9] = 0;
+        let checksum =
+            (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        bytes[9] = checksum;
+        bytes
+    }
+
+    #[test]
+    fn dbg2_lists_serial_and_usb_debug_ports() {
+        let bytes = build_dbg2();
+        let dbg2 = Dbg2::parse(&bytes).expect("dbg2 should parse");
+        let devices = dbg2
+            .devices()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("devices should parse");
+        assert_eq!(devices.len(), 2);
+
+        let serial = devices[0];
+        assert_eq!(
+            serial.port_type(),
+            Dbg2PortType::Serial(AcpiSerialPortType::Ns16550)
+        );
+        assert_eq!(serial.namespace_path(), Some(&b"\\_SB.PCI0.UAR1"[..]));
+        let register = serial.register(0).expect("register should decode");
+        assert_eq!(register.address.address, 0x3F8);
+        assert_eq!(register.size, 8);
+
+        let usb = devices[1];
+        assert_eq!(usb.port_type(), Dbg2PortType::Usb(Dbg2UsbController::Xhci));
+        assert_eq!(usb.namespace_path(), None);
+        assert!(usb.register(1).is_err());
+    }
+
+    #[test]
+    fn dbg2_rejects_register_array_past_device_block() {
+        let mut bytes = build_dbg2();
+        // Claim a second register on the first device; its GAS array now runs into the sizes.
+        bytes[44 + 3] = 4;
+        let bytes = finish(bytes);
+        let dbg2 = Dbg2::parse(&bytes).expect("dbg2 should still parse");
+        let error = dbg2
+            .devices()
+            .next()
+            .expect("first device should exist")
+            .expect_err("overrunning register array should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::Truncated);
+    }
+
+    #[test]
+    fn dbg2_parses_acpica_template() {
+        let bytes = include_bytes!("../../../tests/fixtures/acpi/dbg2.dat");
+        let dbg2 = Dbg2::parse(bytes).expect("template dbg2 should parse");
+        assert_eq!(dbg2.device_count(), 2);
+        let devices = dbg2
+            .devices()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("template devices should parse");
+
+        let first = devices[0];
+        assert_eq!(first.revision(), 0xEE);
+        assert_eq!(
+            first.port_type(),
+            Dbg2PortType::Serial(AcpiSerialPortType::Ns16550)
+        );
+        assert_eq!(first.namespace_path(), Some(&b"MyDevice"[..]));
+        assert_eq!(first.oem_data(), None);
+        assert_eq!(first.register_count(), 2);
+        let register = first.register(1).expect("second register should parse");
+        assert_eq!(register.address.space, AcpiAddressSpace::SystemIo);
+        assert_eq!(register.address.access_size, AcpiAccessSize::QWord);
+        assert_eq!(register.address.address, 0xAABB_CCDD_EEFF_0011);
+        assert_eq!(register.size, 0xFEDC_BA98);
+        assert_eq!(
+            first.register(0).expect("first register should parse").size,
+            0x7654_3210
+        );
+
+        let second = devices[1];
+        assert_eq!(second.namespace_path(), Some(&b"\\\\_SB_.PCI0.DBGP"[..]));
+        assert_eq!(second.oem_data().map(<[u8]>::len), Some(16));
+        assert_eq!(second.register_count(), 1);
+    }
+}
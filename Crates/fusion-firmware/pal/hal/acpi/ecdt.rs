@@ -0,0 +1,175 @@
+//! ECDT definitions and helpers.
+//!
+//! The Embedded Controller Boot Resources Table (`ECDT`) exists so OSPM can
+//! talk to the embedded controller before the namespace has been loaded.
+//! ACPI 6.6 Section 5.2.15 defines one fixed payload:
+//!
+//! - the EC command/status and data registers as Generic Address Structures,
+//! - the EC's `_UID` and the SCI GPE bit it signals on,
+//! - the EC's fully qualified namespace path, so the early handle can later
+//!   be reconciled with the real `PNP0C09` device.
+//!
+//! Both registers are normally in system I/O space. Fusion does not insist on
+//! that, but it does reject a namespace path that is not NUL-terminated. An
+//! empty path is accepted, since ACPICA's own template ships one; such an
+//! early EC has to be matched back to its device object some other way.
+
+use core::mem::size_of;
+
+use super::{
+    AcpiError,
+    AcpiGenericAddress,
+    AcpiSignature,
+    AcpiTableView,
+    read_unaligned_copy,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawEcdt {
+    ec_control: [u8; AcpiGenericAddress::SIZE],
+    ec_data: [u8; AcpiGenericAddress::SIZE],
+    uid: u32,
+    gpe_bit: u8,
+}
+
+/// Borrowed validated ECDT view.
+#[derive(Clone, Copy, Debug)]
+pub struct Ecdt<'a> {
+    table: AcpiTableView<'a>,
+    ec_control: AcpiGenericAddress,
+    ec_data: AcpiGenericAddress,
+    uid: u32,
+    gpe_bit: u8,
+    ec_id: &'a [u8],
+}
+
+impl<'a> Ecdt<'a> {
+    /// Parses one validated ECDT.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the table is malformed, truncated, not one ECDT, or its
+    /// namespace path is unterminated.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, AcpiError> {
+        let table = AcpiTableView::parse_signature(bytes, AcpiSignature::ECDT)?;
+        let payload = table.payload();
+        if payload.len() < size_of::<RawEcdt>() {
+            return Err(AcpiError::truncated());
+        }
+        let raw: RawEcdt = read_unaligned_copy(payload)?;
+        let id_bytes = &payload[size_of::<RawEcdt>()..];
+        let ec_id = match id_bytes.iter().position(|byte| *byte == 0) {
+            Some(end) => &id_bytes[..end],
+            None => return Err(AcpiError::invalid_layout()),
+        };
+        Ok(Self {
+            table,
+            ec_control: AcpiGenericAddress::parse(&raw.ec_control)?,
+            ec_data: AcpiGenericAddress::parse(&raw.ec_data)?,
+            uid: u32::from_le(raw.uid),
+            gpe_bit: raw.gpe_bit,
+            ec_id,
+        })
+    }
+
+    /// Returns the underlying validated ACPI table view.
+    #[must_use]
+    pub const fn table(self) -> AcpiTableView<'a> {
+        self.table
+    }
+
+    /// Returns the EC command/status register.
+    #[must_use]
+    pub const fn ec_control(self) -> AcpiGenericAddress {
+        self.ec_control
+    }
+
+    /// Returns the EC data register.
+    #[must_use]
+    pub const fn ec_data(self) -> AcpiGenericAddress {
+        self.ec_data
+    }
+
+    /// Returns the EC's `_UID`.
+    #[must_use]
+    pub const fn uid(self) -> u32 {
+        self.uid
+    }
+
+    /// Returns the GPE bit the EC raises its SCI on.
+    #[must_use]
+    pub const fn gpe_bit(self) -> u8 {
+        self.gpe_bit
+    }
+
+    /// Returns the EC's fully qualified namespace path without its NUL terminator.
+    ///
+    /// The path is empty when firmware left only the terminator.
+    #[must_use]
+    pub const fn ec_id(self) -> &'a [u8] {
+        self.ec_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        AcpiAddressSpace,
+        AcpiErrorKind,
+    };
+    use super::*;
+    use std::vec::Vec;
+
+    /// ECDT shaped like a laptop EC at the conventional `0x66`/`0x62` ports.
+    fn build_ecdt(ec_id: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0_u8; 65];
+        bytes[0..4].copy_from_slice(b"ECDT");
+        bytes[8] = 1;
+        bytes[10..16].copy_from_slice(b"LENOVO");
+        bytes[16..24].copy_from_slice(b"TP-N2H  ");
+        for (base, port) in [(36, 0x66_u64), (48, 0x62)] {
+            bytes[base] = 1;
+            bytes[base + 1] = 8;
+            bytes[base + 3] = 1;
+            bytes[base + 4..base + 12].copy_from_slice(&port.to_le_bytes());
+        }
+        bytes[64] = 0x16;
+        bytes.extend_from_slice(ec_id);
+        let length = u32::try_from(bytes.len()).expect("table should fit");
+        bytes[4..8].copy_from_slice(&length.to_le_bytes());
+        let checksum =
+            (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        bytes[9] = checksum;
+        bytes
+    }
+
+    #[test]
+    fn ecdt_describes_early_ec_ports() {
+        let bytes = build_ecdt(b"\\_SB.PCI0.LPCB.EC\0");
+        let ecdt = Ecdt::parse(&bytes).expect("ecdt should parse");
+        assert_eq!(ecdt.ec_control().address, 0x66);
+        assert_eq!(ecdt.ec_data().address, 0x62);
+        assert_eq!(ecdt.gpe_bit(), 0x16);
+        assert_eq!(ecdt.ec_id(), b"\\_SB.PCI0.LPCB.EC");
+    }
+
+    #[test]
+    fn ecdt_rejects_unterminated_namespace_path() {
+        let bytes = build_ecdt(b"\\_SB.EC");
+        let error = Ecdt::parse(&bytes).expect_err("unterminated id should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidLayout);
+    }
+
+    #[test]
+    fn ecdt_parses_acpica_template() {
+        let bytes = include_bytes!("../../../tests/fixtures/acpi/ecdt.dat");
+        let ecdt = Ecdt::parse(bytes).expect("template ecdt should parse");
+        assert_eq!(ecdt.ec_control().space, AcpiAddressSpace::SystemIo);
+        assert_eq!(ecdt.ec_control().address, 0x66);
+        assert_eq!(ecdt.ec_data().address, 0x62);
+        assert_eq!(ecdt.uid(), 0);
+        assert_eq!(ecdt.gpe_bit(), 9);
+        assert_eq!(ecdt.ec_id(), b"");
+    }
+}
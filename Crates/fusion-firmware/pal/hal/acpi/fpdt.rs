@@ -0,0 +1,431 @@
+//! FPDT definitions and helpers.
+//!
+//! The Firmware Performance Data Table (`FPDT`) is how firmware reports how
+//! long it took to boot. ACPI 6.6 Section 5.2.24 splits that into two layers:
+//!
+//! - the checksummed `FPDT` itself, which only carries pointer records to
+//!   the firmware-owned performance tables,
+//! - the Firmware Basic Boot Performance Table (`FBPT`) and S3 Performance
+//!   Table (`S3PT`), which live in firmware-reserved memory, carry only a
+//!   signature and length, and are not checksummed at all.
+//!
+//! Fusion parses the `FPDT` pointer records and the `FBPT` basic boot
+//! record. The `FBPT` view still enforces length and record-overrun checks;
+//! the missing checksum is the firmware's choice, not a reason to read past the
+//! end of a buffer.
+//!
+//! All timestamps are nanoseconds since the processor's reset vector ran.
+
+use core::mem::size_of;
+
+use super::{
+    AcpiError,
+    AcpiSignature,
+    AcpiTableView,
+    read_unaligned_copy,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawPerformanceRecordHeader {
+    kind: u16,
+    length: u8,
+    revision: u8,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawPointerRecord {
+    reserved: u32,
+    address: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawFbptHeader {
+    signature: [u8; 4],
+    length: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawBasicBootRecord {
+    reserved: u32,
+    reset_end: u64,
+    os_loader_load_image_start: u64,
+    os_loader_start_image_start: u64,
+    exit_boot_services_entry: u64,
+    exit_boot_services_exit: u64,
+}
+
+/// Borrowed parsed FPDT record view.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FpdtRecord<'a> {
+    /// Physical address of the Firmware Basic Boot Performance Table.
+    FirmwareBasicBootPointer(u64),
+    /// Physical address of the S3 Performance Table.
+    S3PerformancePointer(u64),
+    Unknown {
+        kind: u16,
+        revision: u8,
+        bytes: &'a [u8],
+    },
+}
+
+/// Firmware basic boot timestamps, in nanoseconds since reset.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FbptBasicBoot {
+    pub reset_end: u64,
+    pub os_loader_load_image_start: u64,
+    pub os_loader_start_image_start: u64,
+    pub exit_boot_services_entry: u64,
+    pub exit_boot_services_exit: u64,
+}
+
+/// Borrowed parsed FBPT record view.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FbptRecord<'a> {
+    BasicBoot(FbptBasicBoot),
+    Unknown {
+        kind: u16,
+        revision: u8,
+        bytes: &'a [u8],
+    },
+}
+
+/// Borrowed validated FPDT view.
+#[derive(Clone, Copy, Debug)]
+pub struct Fpdt<'a> {
+    table: AcpiTableView<'a>,
+}
+
+impl<'a> Fpdt<'a> {
+    /// Parses one validated FPDT.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the table is malformed, truncated, or not one FPDT.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, AcpiError> {
+        let table = AcpiTableView::parse_signature(bytes, AcpiSignature::FPDT)?;
+        Ok(Self { table })
+    }
+
+    /// Returns the underlying validated ACPI table view.
+    #[must_use]
+    pub const fn table(self) -> AcpiTableView<'a> {
+        self.table
+    }
+
+    /// Returns an iterator over FPDT pointer records.
+    #[must_use]
+    pub fn records(&self) -> FpdtRecordIter<'a> {
+        FpdtRecordIter {
+            records: PerformanceRecordIter {
+                bytes: self.table.payload(),
+                offset: 0,
+            },
+        }
+    }
+
+    /// Returns the physical address of the FBPT, if the table points at one.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when record parsing fails.
+    pub fn firmware_basic_boot_address(&self) -> Result<Option<u64>, AcpiError> {
+        for record in self.records() {
+            if let FpdtRecord::FirmwareBasicBootPointer(address) = record? {
+                return Ok(Some(address));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Borrowed validated FBPT view.
+#[derive(Clone, Copy, Debug)]
+pub struct Fbpt<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Fbpt<'a> {
+    /// Parses one FBPT copied or mapped from the address an FPDT pointer record names.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the signature is wrong or the declared length does not fit.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, AcpiError> {
+        let raw: RawFbptHeader = read_unaligned_copy(bytes)?;
+        if raw.signature != *b"FBPT" {
+            return Err(AcpiError::invalid_signature());
+        }
+        let length =
+            usize::try_from(u32::from_le(raw.length)).map_err(|_| AcpiError::invalid_layout())?;
+        if length < size_of::<RawFbptHeader>() {
+            return Err(AcpiError::invalid_layout());
+        }
+        let bytes = bytes.get(..length).ok_or_else(AcpiError::truncated)?;
+        Ok(Self { bytes })
+    }
+
+    /// Returns the exact FBPT bytes covered by its declared length.
+    #[must_use]
+    pub const fn bytes(self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns an iterator over FBPT performance records.
+    #[must_use]
+    pub fn records(&self) -> FbptRecordIter<'a> {
+        FbptRecordIter {
+            records: PerformanceRecordIter {
+                bytes: &self.bytes[size_of::<RawFbptHeader>()..],
+                offset: 0,
+            },
+        }
+    }
+
+    /// Returns the basic boot performance record, if present.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when record parsing fails.
+    pub fn basic_boot(&self) -> Result<Option<FbptBasicBoot>, AcpiError> {
+        for record in self.records() {
+            if let FbptRecord::BasicBoot(basic) = record? {
+                return Ok(Some(basic));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PerformanceRecordIter<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for PerformanceRecordIter<'a> {
+    type Item = Result<(RawPerformanceRecordHeader, &'a [u8]), AcpiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header_bytes = self
+            .bytes
+            .get(self.offset..self.offset + size_of::<RawPerformanceRecordHeader>())?;
+        let header: RawPerformanceRecordHeader = match read_unaligned_copy(header_bytes) {
+            Ok(header) => header,
+            Err(error) => return Some(Err(error)),
+        };
+        if usize::from(header.length) < size_of::<RawPerformanceRecordHeader>() {
+            self.offset = self.bytes.len();
+            return Some(Err(AcpiError::invalid_layout()));
+        }
+        let end = self.offset + usize::from(header.length);
+        let Some(record_bytes) = self.bytes.get(self.offset..end) else {
+            self.offset = self.bytes.len();
+            return Some(Err(AcpiError::truncated()));
+        };
+        self.offset = end;
+        Some(Ok((
+            header,
+            &record_bytes[size_of::<RawPerformanceRecordHeader>()..],
+        )))
+    }
+}
+
+/// Iterator over FPDT pointer records.
+#[derive(Clone, Debug)]
+pub struct FpdtRecordIter<'a> {
+    records: PerformanceRecordIter<'a>,
+}
+
+impl<'a> Iterator for FpdtRecordIter<'a> {
+    type Item = Result<FpdtRecord<'a>, AcpiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (header, payload) = match self.records.next()? {
+            Ok(record) => record,
+            Err(error) => return Some(Err(error)),
+        };
+        let kind = u16::from_le(header.kind);
+        Some(match kind {
+            0 | 1 => parse_pointer(payload).map(|address| {
+                if kind == 0 {
+                    FpdtRecord::FirmwareBasicBootPointer(address)
+                } else {
+                    FpdtRecord::S3PerformancePointer(address)
+                }
+            }),
+            _ => Ok(FpdtRecord::Unknown {
+                kind,
+                revision: header.revision,
+                bytes: payload,
+            }),
+        })
+    }
+}
+
+/// Iterator over FBPT performance records.
+#[derive(Clone, Debug)]
+pub struct FbptRecordIter<'a> {
+    records: PerformanceRecordIter<'a>,
+}
+
+impl<'a> Iterator for FbptRecordIter<'a> {
+    type Item = Result<FbptRecord<'a>, AcpiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (header, payload) = match self.records.next()? {
+            Ok(record) => record,
+            Err(error) => return Some(Err(error)),
+        };
+        let kind = u16::from_le(header.kind);
+        Some(match kind {
+            2 => parse_basic_boot(payload).map(FbptRecord::BasicBoot),
+            _ => Ok(FbptRecord::Unknown {
+                kind,
+                revision: header.revision,
+                bytes: payload,
+            }),
+        })
+    }
+}
+
+fn parse_pointer(payload: &[u8]) -> Result<u64, AcpiError> {
+    // ACPICA's template declares a 48-byte boot pointer record; only the leading address is
+    // defined, so anything past it is skipped rather than rejected.
+    if payload.len() < size_of::<RawPointerRecord>() {
+        return Err(AcpiError::invalid_layout());
+    }
+    let raw: RawPointerRecord = read_unaligned_copy(payload)?;
+    Ok(u64::from_le(raw.address))
+}
+
+fn parse_basic_boot(payload: &[u8]) -> Result<FbptBasicBoot, AcpiError> {
+    if payload.len() != size_of::<RawBasicBootRecord>() {
+        return Err(AcpiError::invalid_layout());
+    }
+    let raw: RawBasicBootRecord = read_unaligned_copy(payload)?;
+    Ok(FbptBasicBoot {
+        reset_end: u64::from_le(raw.reset_end),
+        os_loader_load_image_start: u64::from_le(raw.os_loader_load_image_start),
+        os_loader_start_image_start: u64::from_le(raw.os_loader_start_image_start),
+        exit_boot_services_entry: u64::from_le(raw.exit_boot_services_entry),
+        exit_boot_services_exit: u64::from_le(raw.exit_boot_services_exit),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::AcpiErrorKind;
+    use super::*;
+    use std::vec::Vec;
+
+    fn build_fpdt() -> [u8; 68] {
+        let mut bytes = [0_u8; 68];
+        bytes[0..4].copy_from_slice(b"FPDT");
+        bytes[4..8].copy_from_slice(&(68_u32).to_le_bytes());
+        bytes[8] = 1;
+        bytes[10..16].copy_from_slice(b"INTEL ");
+        bytes[16..24].copy_from_slice(b"EDK2    ");
+        for (index, (kind, address)) in [(0_u16, 0x7F6B_E000_u64), (1, 0x7F6B_D000)]
+            .into_iter()
+            .enumerate()
+        {
+            let base = 36 + index * 16;
+            bytes[base..base + 2].copy_from_slice(&kind.to_le_bytes());
+            bytes[base + 2] = 16;
+            bytes[base + 3] = 1;
+            bytes[base + 8..base + 16].copy_from_slice(&address.to_le_bytes());
+        }
+        let checksum =
+            (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        bytes[9] = checksum;
+        bytes
+    }
+
+    fn build_fbpt(record_length: u8) -> Vec<u8> {
+        let mut bytes = vec![0_u8; 8 + 48];
+        bytes[0..4].copy_from_slice(b"FBPT");
+        bytes[4..8].copy_from_slice(&56_u32.to_le_bytes());
+        bytes[8..10].copy_from_slice(&2_u16.to_le_bytes());
+        bytes[10] = record_length;
+        bytes[11] = 2;
+        let stamps = [
+            1_250_000_000_u64,
+            2_100_000_000,
+            2_150_000_000,
+            3_400_000_000,
+            3_400_050_000,
+        ];
+        for (index, stamp) in stamps.into_iter().enumerate() {
+            let base = 16 + index * 8;
+            bytes[base..base + 8].copy_from_slice(&stamp.to_le_bytes());
+        }
+        // Trailing slack firmware reserves for runtime-appended records.
+        bytes.extend_from_slice(&[0_u8; 16]);
+        bytes
+    }
+
+    #[test]
+    fn fpdt_points_at_boot_and_s3_tables() {
+        let bytes = build_fpdt();
+        let fpdt = Fpdt::parse(&bytes).expect("fpdt should parse");
+        let records = fpdt
+            .records()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("records should parse");
+        assert_eq!(
+            records,
+            [
+                FpdtRecord::FirmwareBasicBootPointer(0x7F6B_E000),
+                FpdtRecord::S3PerformancePointer(0x7F6B_D000),
+            ]
+        );
+        assert_eq!(
+            fpdt.firmware_basic_boot_address().unwrap(),
+            Some(0x7F6B_E000)
+        );
+    }
+
+    #[test]
+    fn fbpt_reports_basic_boot_timestamps() {
+        let bytes = build_fbpt(48);
+        let fbpt = Fbpt::parse(&bytes).expect("fbpt should parse");
+        assert_eq!(fbpt.bytes().len(), 56);
+        let basic = fbpt
+            .basic_boot()
+            .expect("records should parse")
+            .expect("basic boot record should exist");
+        assert_eq!(basic.reset_end, 1_250_000_000);
+        assert_eq!(basic.exit_boot_services_exit, 3_400_050_000);
+    }
+
+    #[test]
+    fn fbpt_rejects_record_overrunning_declared_length() {
+        let bytes = build_fbpt(56);
+        let fbpt = Fbpt::parse(&bytes).expect("fbpt header should parse");
+        let error = fbpt
+            .basic_boot()
+            .expect_err("overrunning record should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::Truncated);
+    }
+
+    #[test]
+    fn fpdt_parses_acpica_template() {
+        let bytes = include_bytes!("../../../tests/fixtures/acpi/fpdt.dat");
+        let fpdt = Fpdt::parse(bytes).expect("template fpdt should parse");
+        let records = fpdt
+            .records()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("template records should parse");
+        assert_eq!(
+            records,
+            [
+                FpdtRecord::FirmwareBasicBootPointer(0),
+                FpdtRecord::S3PerformancePointer(0),
+            ]
+        );
+    }
+}
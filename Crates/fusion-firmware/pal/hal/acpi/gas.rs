@@ -0,0 +1,212 @@
+//! Generic Address Structure definitions.
+//!
+//! ACPI 6.6 Section 5.2.3.2 defines the 12-byte Generic Address Structure
+//! (`GAS`) that tables use to point at registers without committing to one
+//! address space. `ECDT`, `HPET`, `SPCR` and `DBG2` all carry GAS fields, so
+//! the decoding lives here once instead of being re-invented per table.
+//!
+//! The parser is deliberately literal: it decodes the address space and access
+//! size, rejects the reserved access-size encodings, and leaves any policy about
+//! whether a given space is acceptable for a given table to that table.
+
+use core::mem::size_of;
+
+use super::{
+    AcpiError,
+    read_unaligned_copy,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawGenericAddress {
+    address_space_id: u8,
+    register_bit_width: u8,
+    register_bit_offset: u8,
+    access_size: u8,
+    address: u64,
+}
+
+/// Address space named by one Generic Address Structure.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AcpiAddressSpace {
+    SystemMemory,
+    SystemIo,
+    PciConfig,
+    EmbeddedController,
+    SmBus,
+    SystemCmos,
+    PciBarTarget,
+    Ipmi,
+    GeneralPurposeIo,
+    GenericSerialBus,
+    PlatformCommunicationChannel,
+    PlatformRuntimeMechanism,
+    FunctionalFixedHardware,
+    Reserved(u8),
+    OemDefined(u8),
+}
+
+impl AcpiAddressSpace {
+    #[must_use]
+    pub const fn from_raw(raw: u8) -> Self {
+        match raw {
+            0x00 => Self::SystemMemory,
+            0x01 => Self::SystemIo,
+            0x02 => Self::PciConfig,
+            0x03 => Self::EmbeddedController,
+            0x04 => Self::SmBus,
+            0x05 => Self::SystemCmos,
+            0x06 => Self::PciBarTarget,
+            0x07 => Self::Ipmi,
+            0x08 => Self::GeneralPurposeIo,
+            0x09 => Self::GenericSerialBus,
+            0x0A => Self::PlatformCommunicationChannel,
+            0x0B => Self::PlatformRuntimeMechanism,
+            0x7F => Self::FunctionalFixedHardware,
+            0xC0..=0xFF => Self::OemDefined(raw),
+            other => Self::Reserved(other),
+        }
+    }
+}
+
+/// Access width requested by one Generic Address Structure.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AcpiAccessSize {
+    /// Legacy encoding: the register bit width implies the access width.
+    Undefined,
+    Byte,
+    Word,
+    DWord,
+    QWord,
+}
+
+/// Decoded Generic Address Structure.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct AcpiGenericAddress {
+    pub space: AcpiAddressSpace,
+    pub register_bit_width: u8,
+    pub register_bit_offset: u8,
+    pub access_size: AcpiAccessSize,
+    pub address: u64,
+}
+
+impl AcpiGenericAddress {
+    /// Encoded size of one Generic Address Structure.
+    pub const SIZE: usize = size_of::<RawGenericAddress>();
+
+    /// Parses one Generic Address Structure from exactly [`Self::SIZE`] leading bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the bytes are truncated or the access size is reserved.
+    pub fn parse(bytes: &[u8]) -> Result<Self, AcpiError> {
+        let raw: RawGenericAddress = read_unaligned_copy(bytes)?;
+        let access_size = match raw.access_size {
+            0 => AcpiAccessSize::Undefined,
+            1 => AcpiAccessSize::Byte,
+            2 => AcpiAccessSize::Word,
+            3 => AcpiAccessSize::DWord,
+            4 => AcpiAccessSize::QWord,
+            _ => return Err(AcpiError::invalid_layout()),
+        };
+        Ok(Self {
+            space: AcpiAddressSpace::from_raw(raw.address_space_id),
+            register_bit_width: raw.register_bit_width,
+            register_bit_offset: raw.register_bit_offset,
+            access_size,
+            address: u64::from_le(raw.address),
+        })
+    }
+
+    /// Returns `true` when firmware left the structure zeroed to mean "not present".
+    #[must_use]
+    pub const fn is_null(self) -> bool {
+        self.address == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::AcpiErrorKind;
+    use super::*;
+
+    fn encode(space: u8, width: u8, offset: u8, access_size: u8, address: u64) -> [u8; 12] {
+        let mut bytes = [0_u8; 12];
+        bytes[0] = space;
+        bytes[1] = width;
+        bytes[2] = offset;
+        bytes[3] = access_size;
+        bytes[4..12].copy_from_slice(&address.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn gas_decodes_fadt_style_pm_timer_block() {
+        let bytes = encode(0x01, 32, 0, 3, 0x608);
+        let gas = AcpiGenericAddress::parse(&bytes).expect("gas should parse");
+        assert_eq!(
+            gas,
+            AcpiGenericAddress {
+                space: AcpiAddressSpace::SystemIo,
+                register_bit_width: 32,
+                register_bit_offset: 0,
+                access_size: AcpiAccessSize::DWord,
+                address: 0x608,
+            }
+        );
+        assert!(!gas.is_null());
+    }
+
+    #[test]
+    fn gas_decodes_every_defined_space_and_oem_range() {
+        assert_eq!(
+            AcpiAddressSpace::from_raw(0x00),
+            AcpiAddressSpace::SystemMemory
+        );
+        assert_eq!(
+            AcpiAddressSpace::from_raw(0x0A),
+            AcpiAddressSpace::PlatformCommunicationChannel
+        );
+        assert_eq!(
+            AcpiAddressSpace::from_raw(0x0B),
+            AcpiAddressSpace::PlatformRuntimeMechanism
+        );
+        assert_eq!(
+            AcpiAddressSpace::from_raw(0x0C),
+            AcpiAddressSpace::Reserved(0x0C)
+        );
+        assert_eq!(
+            AcpiAddressSpace::from_raw(0x7F),
+            AcpiAddressSpace::FunctionalFixedHardware
+        );
+        assert_eq!(
+            AcpiAddressSpace::from_raw(0x80),
+            AcpiAddressSpace::Reserved(0x80)
+        );
+        assert_eq!(
+            AcpiAddressSpace::from_raw(0xC0),
+            AcpiAddressSpace::OemDefined(0xC0)
+        );
+        assert_eq!(
+            AcpiAddressSpace::from_raw(0xFF),
+            AcpiAddressSpace::OemDefined(0xFF)
+        );
+    }
+
+    #[test]
+    fn gas_treats_zero_address_as_absent() {
+        let gas = AcpiGenericAddress::parse(&[0_u8; 12]).expect("zeroed gas should parse");
+        assert_eq!(gas.access_size, AcpiAccessSize::Undefined);
+        assert!(gas.is_null());
+    }
+
+    #[test]
+    fn gas_rejects_reserved_access_size_and_truncation() {
+        let error = AcpiGenericAddress::parse(&encode(0x00, 8, 0, 5, 0x1000))
+            .expect_err("access size 5 is reserved");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidLayout);
+        let error = AcpiGenericAddress::parse(&encode(0x00, 8, 0, 1, 0x1000)[..11])
+            .expect_err("eleven bytes cannot hold a gas");
+        assert_eq!(error.kind(), AcpiErrorKind::Truncated);
+    }
+}
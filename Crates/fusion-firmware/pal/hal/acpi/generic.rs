@@ -0,0 +1,1851 @@
+//! Generic spec-driven ACPI device realization over one loaded AML namespace.
+//!
+//! Vendor backends describe one captured machine by hand. This backend goes the other way: it
+//! walks the loaded namespace for the device identities the ACPI specification itself defines
+//! and binds the canonical public ACPI driver families by evaluating the predefined objects
+//! those devices are required to carry:
+//!
+//! - `PNP0C0A` control-method batteries through `_BIX`/`_BIF` and `_BST` (section 10.2)
+//! - `ACPI0003` power sources through `_PSR` (section 10.3)
+//! - `PNP0C0D` lids, `PNP0C0C` power buttons, and `PNP0C0E` sleep buttons (section 9.4)
+//! - `PNP0C09` embedded controllers through `_CRS`/`_GPE` and the host EC window (section 12)
+//! - `PNP0C0B` fans through `_FST`, or `_PSC` for ACPI 1.0 fans (section 11.3)
+//! - thermal zones through `_TMP`, `_CRT`, and `_PSV` (section 11.4)
+//! - processors, both legacy `Processor` objects and `ACPI0007` devices (section 8.4)
+//!
+//! Vendor knowledge shrinks to [`AcpiGenericQuirks`]: extra hardware IDs worth binding and
+//! namespace paths that should stay unbound.
+//!
+//! The public ACPI hardware traits are static, so the bound namespace lives in one install-once
+//! firmware slot. That binding needs `'static` AML tables, runtime state, and host surface,
+//! which is how firmware owns them anyway. Evaluation through the slot is serialized; a
+//! concurrent caller sees `busy` rather than racing the runtime overlay.
+
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{
+    AtomicBool,
+    AtomicU8,
+    Ordering,
+};
+
+use crate::aml::{
+    AML_MAX_BUFFER_BYTES,
+    AML_MAX_PATH_TEXT_BYTES,
+    AmlError,
+    AmlErrorKind,
+    AmlExecutionPhase,
+    AmlLoadedNamespace,
+    AmlNamespaceLoadRecord,
+    AmlNamespaceNodeId,
+    AmlObjectKind,
+    AmlPureEvaluator,
+    AmlRegionAccessHost,
+    AmlResolvedNamePath,
+    AmlResourceDescriptor,
+    AmlResourceTemplate,
+    AmlRuntimeState,
+    AmlValue,
+};
+use crate::pal::hal::acpi::AcpiRealizationError;
+use fusion_hal::contract::drivers::acpi::{
+    AcpiBatteryDescriptor,
+    AcpiBatteryInformation,
+    AcpiBatteryStatus,
+    AcpiBatterySupport,
+    AcpiBatteryTechnology,
+    AcpiButtonDescriptor,
+    AcpiButtonKind,
+    AcpiButtonState,
+    AcpiButtonSupport,
+    AcpiComponentSupport,
+    AcpiDeciKelvin,
+    AcpiEmbeddedControllerDescriptor,
+    AcpiEmbeddedControllerSupport,
+    AcpiError,
+    AcpiErrorKind,
+    AcpiFanDescriptor,
+    AcpiFanState,
+    AcpiFanSupport,
+    AcpiLidDescriptor,
+    AcpiLidState,
+    AcpiLidSupport,
+    AcpiObjectDescriptor,
+    AcpiPowerSourceDescriptor,
+    AcpiPowerSourceState,
+    AcpiPowerSourceSupport,
+    AcpiProcessorDescriptor,
+    AcpiProcessorState,
+    AcpiProcessorSupport,
+    AcpiProviderDescriptor,
+    AcpiThermalReading,
+    AcpiThermalSupport,
+    AcpiThermalZoneDescriptor,
+};
+use fusion_hal::drivers::acpi::public::interface::backend::{
+    AcpiAmlBackend,
+    AcpiAmlFieldDescriptor,
+    AcpiAmlMethodDescriptor,
+    AcpiAmlNamespaceDescriptor,
+    AcpiAmlOpRegionDescriptor,
+};
+use fusion_hal::drivers::acpi::public::interface::contract::{
+    AcpiBatteryHardware,
+    AcpiButtonHardware,
+    AcpiEmbeddedControllerHardware,
+    AcpiFanHardware,
+    AcpiHardware,
+    AcpiLidHardware,
+    AcpiPowerSourceHardware,
+    AcpiProcessorHardware,
+    AcpiThermalHardware,
+};
+
+/// Upper bound on devices one generic realization binds across every family.
+pub const ACPI_GENERIC_MAX_DEVICES: usize = 64;
+/// Upper bound on devices one generic realization binds per non-processor family.
+pub const ACPI_GENERIC_MAX_DEVICES_PER_FAMILY: usize = 8;
+
+/// ACPI-defined ACPI `_STA` default when a device carries no status object.
+const STATUS_DEFAULT: u64 = 0x0f;
+const STATUS_PRESENT: u64 = 0x01;
+const STATUS_ENABLED: u64 = 0x02;
+const STATUS_BATTERY_PRESENT: u64 = 0x10;
+/// ACPI "unknown" marker for 32-bit battery and fan package fields.
+const UNKNOWN_U32: u64 = 0xffff_ffff;
+
+const GENERIC_PROVIDER: AcpiProviderDescriptor = AcpiProviderDescriptor {
+    id: "generic-acpi",
+    vendor: "ACPI",
+    platform: "specification-defined namespace",
+    description: "Generic ACPI backend bound from standard namespace device identities",
+};
+
+const GENERIC_AML_NAMESPACE: AcpiAmlNamespaceDescriptor = AcpiAmlNamespaceDescriptor {
+    root: "\\_SB",
+    description: "ACPI system-bus scope every definition block set must carry",
+};
+
+const BATTERY_BAY_NAMES: [&str; ACPI_GENERIC_MAX_DEVICES_PER_FAMILY] = [
+    "battery-bay-0",
+    "battery-bay-1",
+    "battery-bay-2",
+    "battery-bay-3",
+    "battery-bay-4",
+    "battery-bay-5",
+    "battery-bay-6",
+    "battery-bay-7",
+];
+
+/// Device family the generic backend binds for one namespace object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AcpiGenericDeviceClass {
+    Battery,
+    PowerSource,
+    Lid,
+    Button(AcpiButtonKind),
+    EmbeddedController,
+    Fan,
+    ThermalZone,
+    Processor,
+}
+
+/// One vendor hardware ID the generic backend should bind as a standard family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AcpiGenericHardwareIdQuirk {
+    pub hid: &'static str,
+    pub class: AcpiGenericDeviceClass,
+}
+
+/// Vendor overrides layered over the generic spec-driven walk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AcpiGenericQuirks {
+    pub hardware_ids: &'static [AcpiGenericHardwareIdQuirk],
+    pub ignored_paths: &'static [&'static str],
+}
+
+impl AcpiGenericQuirks {
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            hardware_ids: &[],
+            ignored_paths: &[],
+        }
+    }
+}
+
+impl Default for AcpiGenericQuirks {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+/// One namespace device matched by the generic walk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcpiGenericDevice {
+    pub class: AcpiGenericDeviceClass,
+    pub node: AmlNamespaceNodeId,
+    pub path: AmlResolvedNamePath,
+    pub hid: Option<&'static str>,
+    pub uid: Option<u32>,
+}
+
+const STANDARD_HARDWARE_IDS: [(&str, AcpiGenericDeviceClass); 8] = [
+    ("PNP0C0A", AcpiGenericDeviceClass::Battery),
+    ("ACPI0003", AcpiGenericDeviceClass::PowerSource),
+    ("PNP0C0D", AcpiGenericDeviceClass::Lid),
+    (
+        "PNP0C0C",
+        AcpiGenericDeviceClass::Button(AcpiButtonKind::Power),
+    ),
+    (
+        "PNP0C0E",
+        AcpiGenericDeviceClass::Button(AcpiButtonKind::Sleep),
+    ),
+    ("PNP0C09", AcpiGenericDeviceClass::EmbeddedController),
+    ("PNP0C0B", AcpiGenericDeviceClass::Fan),
+    ("ACPI0007", AcpiGenericDeviceClass::Processor),
+];
+
+/// Classifies one ACPI hardware or compatible ID against the spec-defined device identities.
+///
+/// Returns the canonical static spelling of the matched ID alongside its family.
+#[must_use]
+pub fn classify_acpi_hardware_id(id: &str) -> Option<(&'static str, AcpiGenericDeviceClass)> {
+    STANDARD_HARDWARE_IDS
+        .iter()
+        .copied()
+        .find(|(standard, _)| *standard == id)
+}
+
+/// Decodes one compressed EISA ID integer (as produced by ASL `EISAID`) into its 7-character
+/// text form, for example `0x0a0cd041` into `PNP0C0A`.
+#[must_use]
+pub fn decode_eisa_id(value: u64) -> Option<[u8; 7]> {
+    let value = u32::try_from(value).ok()?.swap_bytes();
+    if value & 0x8000_0000 != 0 {
+        return None;
+    }
+
+    let mut text = [0_u8; 7];
+    let vendor = [
+        (value >> 26) & 0x1f,
+        (value >> 21) & 0x1f,
+        (value >> 16) & 0x1f,
+    ];
+    for (slot, letter) in text.iter_mut().zip(vendor) {
+        if letter == 0 || letter > 26 {
+            return None;
+        }
+        *slot = b'@' + u8::try_from(letter).ok()?;
+    }
+    for (index, slot) in text[3..].iter_mut().enumerate() {
+        let nibble = (value >> (12 - (index * 4))) & 0x0f;
+        *slot = b"0123456789ABCDEF"[nibble as usize];
+    }
+    Some(text)
+}
+
+/// Walks one loaded namespace for spec-defined ACPI devices the generic backend can bind.
+///
+/// Devices whose `_STA` reports them absent are skipped, as are paths listed in
+/// `quirks.ignored_paths`.
+///
+/// # Errors
+///
+/// Returns one honest error when `storage` is too small for the matched devices or when
+/// evaluating `_HID`, `_CID`, `_UID`, or `_STA` fails.
+pub fn discover_generic_devices<'storage>(
+    namespace: AmlLoadedNamespace<'_, '_>,
+    host: &dyn AmlRegionAccessHost,
+    runtime: &AmlRuntimeState<'_>,
+    quirks: &AcpiGenericQuirks,
+    storage: &'storage mut [MaybeUninit<AcpiGenericDevice>],
+) -> Result<&'storage [AcpiGenericDevice], AcpiRealizationError> {
+    let scope = GenericAmlScope::new(namespace, host, runtime);
+    let mut written = 0_usize;
+
+    for record in namespace.records {
+        if is_ignored(record.descriptor.path, quirks)? {
+            continue;
+        }
+        let Some(device) = scope
+            .classify_record(record, quirks)
+            .map_err(map_acpi_error)?
+        else {
+            continue;
+        };
+        let status = scope.status(record.descriptor.id).map_err(map_acpi_error)?;
+        if status & STATUS_PRESENT == 0 {
+            continue;
+        }
+
+        let slot = storage
+            .get_mut(written)
+            .ok_or_else(AcpiRealizationError::resource_exhausted)?;
+        slot.write(device);
+        written += 1;
+    }
+
+    // SAFETY: the first `written` slots were initialized above.
+    Ok(unsafe {
+        core::slice::from_raw_parts(storage.as_ptr().cast::<AcpiGenericDevice>(), written)
+    })
+}
+
+/// Generic spec-driven ACPI backend bound over the installed AML namespace.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GenericAcpiHardware;
+
+/// Binds the generic backend to one loaded namespace for the rest of this boot.
+///
+/// # Errors
+///
+/// Returns `state_conflict` when a generic namespace is already bound, and any discovery error
+/// from [`discover_generic_devices`].
+pub(super) fn install_generic_platform(
+    namespace: AmlLoadedNamespace<'static, 'static>,
+    host: &'static (dyn AmlRegionAccessHost + Sync),
+    runtime: &'static AmlRuntimeState<'static>,
+    quirks: &AcpiGenericQuirks,
+) -> Result<(), AcpiRealizationError> {
+    let mut device_storage = [MaybeUninit::uninit(); ACPI_GENERIC_MAX_DEVICES];
+    let devices = discover_generic_devices(namespace, host, runtime, quirks, &mut device_storage)?;
+    let scope = GenericAmlScope::new(namespace, host, runtime);
+
+    // The path table is only ever filled while the platform slot is claimed, and emptied again
+    // before that claim is dropped, so a failed install leaves both slots free for a retry.
+    GENERIC_PLATFORM.install(|| {
+        let paths = GENERIC_PATHS.install(|| {
+            let mut paths = GenericPathTable::new();
+            for (index, device) in devices.iter().enumerate() {
+                paths.write(index, device.path)?;
+            }
+            Ok(paths)
+        })?;
+        let mut platform = GenericPlatform::new(namespace, host, runtime);
+        for (index, device) in devices.iter().enumerate() {
+            if let Err(error) = platform.bind(&scope, *device, paths.object(index, device)) {
+                // SAFETY: only the platform dropped here borrowed from the table.
+                unsafe { GENERIC_PATHS.reset() };
+                return Err(map_acpi_error(error));
+            }
+        }
+        Ok(platform)
+    })?;
+    Ok(())
+}
+
+fn is_ignored(
+    path: AmlResolvedNamePath,
+    quirks: &AcpiGenericQuirks,
+) -> Result<bool, AcpiRealizationError> {
+    for ignored in quirks.ignored_paths {
+        let ignored = AmlResolvedNamePath::parse_text(ignored)
+            .map_err(|_| AcpiRealizationError::invalid())?;
+        if ignored == path {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn platform() -> Result<&'static GenericPlatform, AcpiError> {
+    GENERIC_PLATFORM.get().ok_or_else(AcpiError::unsupported)
+}
+
+fn bound_platform(provider: u8) -> Result<&'static GenericPlatform, AcpiError> {
+    if provider != 0 {
+        return Err(AcpiError::invalid());
+    }
+    platform()
+}
+
+impl AcpiHardware for GenericAcpiHardware {
+    fn provider_count() -> u8 {
+        u8::from(GENERIC_PLATFORM.get().is_some())
+    }
+
+    fn provider(provider: u8) -> Option<&'static AcpiProviderDescriptor> {
+        match provider {
+            0 if GENERIC_PLATFORM.get().is_some() => Some(&GENERIC_PROVIDER),
+            _ => None,
+        }
+    }
+}
+
+impl AcpiAmlBackend for GenericAcpiHardware {
+    fn aml_namespace(provider: u8) -> Result<AcpiAmlNamespaceDescriptor, AcpiError> {
+        if provider != 0 {
+            return Err(AcpiError::invalid());
+        }
+
+        Ok(GENERIC_AML_NAMESPACE)
+    }
+
+    fn aml_methods(_provider: u8) -> &'static [AcpiAmlMethodDescriptor] {
+        &[]
+    }
+
+    fn aml_fields(_provider: u8) -> &'static [AcpiAmlFieldDescriptor] {
+        &[]
+    }
+
+    fn aml_opregions(_provider: u8) -> &'static [AcpiAmlOpRegionDescriptor] {
+        &[]
+    }
+}
+
+impl AcpiBatteryHardware for GenericAcpiHardware {
+    fn batteries(provider: u8) -> &'static [AcpiBatteryDescriptor] {
+        bound_platform(provider).map_or(&[], |platform| platform.batteries.descriptors())
+    }
+
+    fn battery_support(provider: u8, index: u8) -> Result<AcpiBatterySupport, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.batteries.node(index)?;
+        platform.with_scope(|scope| {
+            Ok(AcpiBatterySupport {
+                component: AcpiComponentSupport::runtime_methods(),
+                information_method_present: scope.has_child(node, *b"_BIX")
+                    || scope.has_child(node, *b"_BIF"),
+                status_method_present: scope.has_child(node, *b"_BST"),
+            })
+        })
+    }
+
+    fn battery_information(provider: u8, index: u8) -> Result<AcpiBatteryInformation, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.batteries.node(index)?;
+        platform.with_scope(|scope| scope.battery_information(node))
+    }
+
+    fn battery_status(provider: u8, index: u8) -> Result<AcpiBatteryStatus, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.batteries.node(index)?;
+        platform.with_scope(|scope| scope.battery_status(node))
+    }
+}
+
+impl AcpiPowerSourceHardware for GenericAcpiHardware {
+    fn power_sources(provider: u8) -> &'static [AcpiPowerSourceDescriptor] {
+        bound_platform(provider).map_or(&[], |platform| platform.power_sources.descriptors())
+    }
+
+    fn power_source_support(provider: u8, index: u8) -> Result<AcpiPowerSourceSupport, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.power_sources.node(index)?;
+        platform.with_scope(|scope| {
+            Ok(AcpiPowerSourceSupport {
+                component: AcpiComponentSupport::runtime_methods(),
+                state_method_present: scope.has_child(node, *b"_PSR"),
+            })
+        })
+    }
+
+    fn power_source_state(provider: u8, index: u8) -> Result<AcpiPowerSourceState, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.power_sources.node(index)?;
+        platform.with_scope(|scope| {
+            let online = scope
+                .integer_child(node, *b"_PSR")?
+                .ok_or_else(AcpiError::unsupported)?;
+            Ok(AcpiPowerSourceState {
+                online: online != 0,
+            })
+        })
+    }
+}
+
+impl AcpiThermalHardware for GenericAcpiHardware {
+    fn thermal_zones(provider: u8) -> &'static [AcpiThermalZoneDescriptor] {
+        bound_platform(provider).map_or(&[], |platform| platform.thermal_zones.descriptors())
+    }
+
+    fn thermal_zone_support(provider: u8, index: u8) -> Result<AcpiThermalSupport, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.thermal_zones.node(index)?;
+        platform.with_scope(|scope| {
+            Ok(AcpiThermalSupport {
+                component: AcpiComponentSupport::runtime_methods(),
+                critical_temperature_present: scope.has_child(node, *b"_CRT"),
+                current_temperature_present: scope.has_child(node, *b"_TMP"),
+            })
+        })
+    }
+
+    fn thermal_reading(provider: u8, index: u8) -> Result<AcpiThermalReading, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.thermal_zones.node(index)?;
+        platform.with_scope(|scope| {
+            let current = scope
+                .temperature_child(node, *b"_TMP")?
+                .ok_or_else(AcpiError::unsupported)?;
+            Ok(AcpiThermalReading {
+                current,
+                critical: scope.temperature_child(node, *b"_CRT")?,
+                passive: scope.temperature_child(node, *b"_PSV")?,
+            })
+        })
+    }
+}
+
+impl AcpiFanHardware for GenericAcpiHardware {
+    fn fans(provider: u8) -> &'static [AcpiFanDescriptor] {
+        bound_platform(provider).map_or(&[], |platform| platform.fans.descriptors())
+    }
+
+    fn fan_support(provider: u8, index: u8) -> Result<AcpiFanSupport, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.fans.node(index)?;
+        platform.with_scope(|scope| {
+            Ok(AcpiFanSupport {
+                component: AcpiComponentSupport::runtime_methods(),
+                state_methods_present: scope.has_child(node, *b"_FST")
+                    || scope.has_child(node, *b"_PSC"),
+            })
+        })
+    }
+
+    fn fan_state(provider: u8, index: u8) -> Result<AcpiFanState, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.fans.node(index)?;
+        platform.with_scope(|scope| scope.fan_state(node))
+    }
+}
+
+impl AcpiButtonHardware for GenericAcpiHardware {
+    fn buttons(provider: u8) -> &'static [AcpiButtonDescriptor] {
+        bound_platform(provider).map_or(&[], |platform| platform.buttons.descriptors())
+    }
+
+    fn button_support(provider: u8, index: u8) -> Result<AcpiButtonSupport, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.buttons.node(index)?;
+        platform.with_scope(|scope| {
+            Ok(AcpiButtonSupport {
+                component: AcpiComponentSupport::runtime_methods(),
+                wake_control_present: scope.has_child(node, *b"_PRW"),
+                state_method_present: false,
+                notification_present: true,
+            })
+        })
+    }
+
+    fn button_state(provider: u8, index: u8) -> Result<AcpiButtonState, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let _ = platform.buttons.node(index)?;
+
+        // Control-method buttons only report presses through `Notify(0x80)`.
+        Ok(AcpiButtonState {
+            pressed: None,
+            wake_enabled: None,
+        })
+    }
+}
+
+impl AcpiLidHardware for GenericAcpiHardware {
+    fn lids(provider: u8) -> &'static [AcpiLidDescriptor] {
+        bound_platform(provider).map_or(&[], |platform| platform.lids.descriptors())
+    }
+
+    fn lid_support(provider: u8, index: u8) -> Result<AcpiLidSupport, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.lids.node(index)?;
+        platform.with_scope(|scope| {
+            Ok(AcpiLidSupport {
+                component: AcpiComponentSupport::runtime_methods(),
+                state_method_present: scope.has_child(node, *b"_LID"),
+                wake_control_present: scope.has_child(node, *b"_PRW"),
+            })
+        })
+    }
+
+    fn lid_state(provider: u8, index: u8) -> Result<AcpiLidState, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.lids.node(index)?;
+        platform.with_scope(|scope| {
+            let open = scope
+                .integer_child(node, *b"_LID")?
+                .ok_or_else(AcpiError::unsupported)?;
+            Ok(AcpiLidState {
+                open: open != 0,
+                wake_enabled: None,
+            })
+        })
+    }
+}
+
+impl AcpiEmbeddedControllerHardware for GenericAcpiHardware {
+    fn embedded_controllers(provider: u8) -> &'static [AcpiEmbeddedControllerDescriptor] {
+        bound_platform(provider).map_or(&[], |platform| platform.embedded_controllers.descriptors())
+    }
+
+    fn embedded_controller_support(
+        provider: u8,
+        index: u8,
+    ) -> Result<AcpiEmbeddedControllerSupport, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let _ = platform.embedded_controllers.node(index)?;
+        Ok(AcpiEmbeddedControllerSupport {
+            component: AcpiComponentSupport::runtime_methods(),
+            raw_read_write: true,
+        })
+    }
+
+    fn embedded_controller_read(provider: u8, index: u8, register: u8) -> Result<u8, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let _ = platform.embedded_controllers.node(index)?;
+        platform.with_scope(|scope| {
+            scope
+                .host
+                .read_embedded_controller(register)
+                .map_err(map_aml_error)
+        })
+    }
+
+    fn embedded_controller_write(
+        provider: u8,
+        index: u8,
+        register: u8,
+        value: u8,
+    ) -> Result<(), AcpiError> {
+        let platform = bound_platform(provider)?;
+        let _ = platform.embedded_controllers.node(index)?;
+        platform.with_scope(|scope| {
+            scope
+                .host
+                .write_embedded_controller(register, value)
+                .map_err(map_aml_error)
+        })
+    }
+}
+
+impl AcpiProcessorHardware for GenericAcpiHardware {
+    fn processors(provider: u8) -> &'static [AcpiProcessorDescriptor] {
+        bound_platform(provider).map_or(&[], |platform| platform.processors.descriptors())
+    }
+
+    fn processor_support(provider: u8, index: u8) -> Result<AcpiProcessorSupport, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.processors.node(index)?;
+        platform.with_scope(|scope| {
+            Ok(AcpiProcessorSupport {
+                component: AcpiComponentSupport::runtime_methods(),
+                performance_states_present: scope.has_child(node, *b"_PSS"),
+                idle_states_present: scope.has_child(node, *b"_CST")
+                    || scope.has_child(node, *b"_LPI"),
+            })
+        })
+    }
+
+    fn processor_state(provider: u8, index: u8) -> Result<AcpiProcessorState, AcpiError> {
+        let platform = bound_platform(provider)?;
+        let node = platform.processors.node(index)?;
+        platform.with_scope(|scope| {
+            let status = scope.status(node)?;
+            Ok(AcpiProcessorState {
+                online: status & (STATUS_PRESENT | STATUS_ENABLED)
+                    == (STATUS_PRESENT | STATUS_ENABLED),
+            })
+        })
+    }
+}
+
+/// Borrowed evaluation surface shared by discovery and the bound runtime reads.
+struct GenericAmlScope<'records, 'blocks, 'host, 'state> {
+    namespace: AmlLoadedNamespace<'records, 'blocks>,
+    evaluator: AmlPureEvaluator<'records, 'blocks>,
+    host: &'host dyn AmlRegionAccessHost,
+    runtime: &'state AmlRuntimeState<'state>,
+}
+
+impl<'records, 'blocks, 'host, 'state> GenericAmlScope<'records, 'blocks, 'host, 'state> {
+    const fn new(
+        namespace: AmlLoadedNamespace<'records, 'blocks>,
+        host: &'host dyn AmlRegionAccessHost,
+        runtime: &'state AmlRuntimeState<'state>,
+    ) -> Self {
+        Self {
+            namespace,
+            evaluator: AmlPureEvaluator::new(namespace),
+            host,
+            runtime,
+        }
+    }
+
+    fn child(&self, parent: AmlNamespaceNodeId, name: [u8; 4]) -> Option<AmlNamespaceNodeId> {
+        self.namespace
+            .records
+            .iter()
+            .find(|record| {
+                record.descriptor.parent == Some(parent)
+                    && record
+                        .descriptor
+                        .path
+                        .last_segment()
+                        .is_some_and(|segment| segment.bytes() == name)
+            })
+            .map(|record| record.descriptor.id)
+    }
+
+    fn has_child(&self, parent: AmlNamespaceNodeId, name: [u8; 4]) -> bool {
+        self.child(parent, name).is_some()
+    }
+
+    fn evaluate_child(
+        &self,
+        parent: AmlNamespaceNodeId,
+        name: [u8; 4],
+    ) -> Result<Option<AmlValue<'blocks>>, AcpiError> {
+        let Some(node) = self.child(parent, name) else {
+            return Ok(None);
+        };
+        let outcome = self
+            .evaluator
+            .evaluate_object_with_host_and_state(
+                self.host,
+                self.runtime,
+                node,
+                AmlExecutionPhase::Runtime,
+            )
+            .map_err(map_aml_error)?;
+        if outcome.blocked {
+            return Err(AcpiError::busy());
+        }
+        outcome
+            .return_value
+            .map(Some)
+            .ok_or_else(AcpiError::state_conflict)
+    }
+
+    fn integer_child(
+        &self,
+        parent: AmlNamespaceNodeId,
+        name: [u8; 4],
+    ) -> Result<Option<u64>, AcpiError> {
+        self.evaluate_child(parent, name)?
+            .map(|value| value.as_integer().map_err(map_aml_error))
+            .transpose()
+    }
+
+    fn temperature_child(
+        &self,
+        parent: AmlNamespaceNodeId,
+        name: [u8; 4],
+    ) -> Result<Option<AcpiDeciKelvin>, AcpiError> {
+        self.integer_child(parent, name)?
+            .map(|value| {
+                u32::try_from(value)
+                    .map(AcpiDeciKelvin)
+                    .map_err(|_| AcpiError::invalid())
+            })
+            .transpose()
+    }
+
+    fn status(&self, node: AmlNamespaceNodeId) -> Result<u64, AcpiError> {
+        Ok(self
+            .integer_child(node, *b"_STA")?
+            .unwrap_or(STATUS_DEFAULT))
+    }
+
+    fn element(
+        &self,
+        package: AmlValue<'blocks>,
+        index: u8,
+    ) -> Result<AmlValue<'blocks>, AcpiError> {
+        self.evaluator
+            .package_element(Some(self.runtime), package, index)
+            .map_err(map_aml_error)
+    }
+
+    /// Reads one 32-bit package field, folding the ACPI "unknown" marker into `None`.
+    fn element_u32(&self, package: AmlValue<'blocks>, index: u8) -> Result<Option<u32>, AcpiError> {
+        let value = self
+            .element(package, index)?
+            .as_integer()
+            .map_err(map_aml_error)?;
+        if value == UNKNOWN_U32 {
+            return Ok(None);
+        }
+        u32::try_from(value)
+            .map(Some)
+            .map_err(|_| AcpiError::invalid())
+    }
+
+    fn element_string(&self, package: AmlValue<'blocks>, index: u8) -> Option<&'blocks str> {
+        let value = self.element(package, index).ok()?;
+        self.evaluator
+            .string_value(&value)
+            .ok()
+            .filter(|text| !text.is_empty())
+    }
+
+    fn identity_matches(
+        &self,
+        value: AmlValue<'blocks>,
+        quirks: &AcpiGenericQuirks,
+    ) -> Result<Option<(&'static str, AcpiGenericDeviceClass)>, AcpiError> {
+        let eisa;
+        let text = match value {
+            AmlValue::Integer(raw) => {
+                let Some(decoded) = decode_eisa_id(raw) else {
+                    return Ok(None);
+                };
+                eisa = decoded;
+                core::str::from_utf8(&eisa).map_err(|_| AcpiError::invalid())?
+            }
+            other => match self.evaluator.string_value(&other) {
+                Ok(text) => text,
+                Err(_) => return Ok(None),
+            },
+        };
+
+        for quirk in quirks.hardware_ids {
+            if quirk.hid == text {
+                return Ok(Some((quirk.hid, quirk.class)));
+            }
+        }
+        Ok(classify_acpi_hardware_id(text))
+    }
+
+    fn classify_record(
+        &self,
+        record: &AmlNamespaceLoadRecord,
+        quirks: &AcpiGenericQuirks,
+    ) -> Result<Option<AcpiGenericDevice>, AcpiError> {
+        let node = record.descriptor.id;
+        let path = record.descriptor.path;
+        match record.descriptor.kind {
+            AmlObjectKind::ThermalZone => Ok(Some(AcpiGenericDevice {
+                class: AcpiGenericDeviceClass::ThermalZone,
+                node,
+                path,
+                hid: None,
+                uid: None,
+            })),
+            AmlObjectKind::Processor => Ok(Some(AcpiGenericDevice {
+                class: AcpiGenericDeviceClass::Processor,
+                node,
+                path,
+                hid: None,
+                uid: self.processor_object_id(record).map(u32::from),
+            })),
+            AmlObjectKind::Device => {
+                let Some((hid, class)) = self.device_identity(node, quirks)? else {
+                    return Ok(None);
+                };
+                let uid = self
+                    .evaluate_child(node, *b"_UID")?
+                    .and_then(|value| value.as_integer().ok())
+                    .and_then(|value| u32::try_from(value).ok());
+                Ok(Some(AcpiGenericDevice {
+                    class,
+                    node,
+                    path,
+                    hid: Some(hid),
+                    uid,
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn device_identity(
+        &self,
+        node: AmlNamespaceNodeId,
+        quirks: &AcpiGenericQuirks,
+    ) -> Result<Option<(&'static str, AcpiGenericDeviceClass)>, AcpiError> {
+        if let Some(hid) = self.evaluate_child(node, *b"_HID")?
+            && let Some(matched) = self.identity_matches(hid, quirks)?
+        {
+            return Ok(Some(matched));
+        }
+
+        let Some(cid) = self.evaluate_child(node, *b"_CID")? else {
+            return Ok(None);
+        };
+        if !matches!(
+            cid,
+            AmlValue::Package(_) | AmlValue::StaticPackage(_) | AmlValue::PackageHandle(_)
+        ) {
+            return self.identity_matches(cid, quirks);
+        }
+
+        let count = self
+            .evaluator
+            .value_length(Some(self.runtime), cid.clone())
+            .map_err(map_aml_error)?;
+        for index in 0..count {
+            let index = u8::try_from(index).map_err(|_| AcpiError::invalid())?;
+            if let Some(matched) =
+                self.identity_matches(self.element(cid.clone(), index)?, quirks)?
+            {
+                return Ok(Some(matched));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Reads the `ProcID` byte that precedes the fixed `PBlk` fields of one `Processor` object.
+    fn processor_object_id(&self, record: &AmlNamespaceLoadRecord) -> Option<u8> {
+        let body = record.body?;
+        let mut location = body;
+        location.span.offset = body.span.offset.checked_sub(6)?;
+        location.span.length = 1;
+        self.namespace.code_bytes(location)?.first().copied()
+    }
+
+    fn embedded_controller_ports(&self, node: AmlNamespaceNodeId) -> Result<(u16, u16), AcpiError> {
+        let resources = self
+            .evaluate_child(node, *b"_CRS")?
+            .ok_or_else(AcpiError::unsupported)?;
+        let mut scratch = [0_u8; AML_MAX_BUFFER_BYTES];
+        let template =
+            AmlResourceTemplate::from_value(Some(self.runtime), &resources, &mut scratch)
+                .map_err(map_aml_error)?;
+
+        let mut ports = template.iter().filter_map(|descriptor| match descriptor {
+            AmlResourceDescriptor::Io(io) => Some(io.minimum),
+            AmlResourceDescriptor::FixedIo(io) => Some(io.base),
+            _ => None,
+        });
+        let data = ports.next().ok_or_else(AcpiError::invalid)?;
+        let command = ports.next().ok_or_else(AcpiError::invalid)?;
+        Ok((data, command))
+    }
+
+    fn fan_state(&self, node: AmlNamespaceNodeId) -> Result<AcpiFanState, AcpiError> {
+        let present = self.status(node)? & STATUS_PRESENT != 0;
+        if let Some(status) = self.evaluate_child(node, *b"_FST")? {
+            let control = self.element_u32(status.clone(), 1)?;
+            let speed = self.element_u32(status, 2)?;
+            return Ok(AcpiFanState {
+                present,
+                active: speed.map_or(control.is_some_and(|control| control != 0), |speed| {
+                    speed != 0
+                }),
+                controllable: self.has_child(node, *b"_FSL"),
+            });
+        }
+
+        let power_state = self
+            .integer_child(node, *b"_PSC")?
+            .ok_or_else(AcpiError::unsupported)?;
+        Ok(AcpiFanState {
+            present,
+            active: power_state == 0,
+            controllable: self.has_child(node, *b"_PS0") && self.has_child(node, *b"_PS3"),
+        })
+    }
+
+    fn battery_status(&self, node: AmlNamespaceNodeId) -> Result<AcpiBatteryStatus, AcpiError> {
+        let present = self.status(node)? & STATUS_BATTERY_PRESENT != 0;
+        let status = self
+            .evaluate_child(node, *b"_BST")?
+            .ok_or_else(AcpiError::unsupported)?;
+        let state = self.element_u32(status.clone(), 0)?.unwrap_or(0);
+        Ok(AcpiBatteryStatus {
+            present,
+            discharging: state & 0x01 != 0,
+            charging: state & 0x02 != 0,
+            present_rate: self.element_u32(status.clone(), 1)?,
+            remaining_capacity: self.element_u32(status.clone(), 2)?,
+            present_voltage_mv: self.element_u32(status, 3)?,
+        })
+    }
+}
+
+impl GenericAmlScope<'static, 'static, 'static, 'static> {
+    fn battery_information(
+        &self,
+        node: AmlNamespaceNodeId,
+    ) -> Result<AcpiBatteryInformation, AcpiError> {
+        // `_BIX` prefixes the `_BIF` layout with a revision and inserts cycle count and
+        // accuracy fields before the strings.
+        if let Some(info) = self.evaluate_child(node, *b"_BIX")? {
+            return Ok(AcpiBatteryInformation {
+                design_capacity: self.element_u32(info.clone(), 2)?,
+                last_full_charge_capacity: self.element_u32(info.clone(), 3)?,
+                design_voltage_mv: self.element_u32(info.clone(), 5)?,
+                cycle_count: self.element_u32(info.clone(), 8)?,
+                model: self.element_string(info.clone(), 16),
+                serial: self.element_string(info.clone(), 17),
+                oem: self.element_string(info, 19),
+            });
+        }
+
+        let info = self
+            .evaluate_child(node, *b"_BIF")?
+            .ok_or_else(AcpiError::unsupported)?;
+        Ok(AcpiBatteryInformation {
+            design_capacity: self.element_u32(info.clone(), 1)?,
+            last_full_charge_capacity: self.element_u32(info.clone(), 2)?,
+            design_voltage_mv: self.element_u32(info.clone(), 4)?,
+            cycle_count: None,
+            model: self.element_string(info.clone(), 9),
+            serial: self.element_string(info.clone(), 10),
+            oem: self.element_string(info, 12),
+        })
+    }
+}
+
+/// Fixed-capacity descriptor table for one bound public ACPI family.
+struct GenericFamily<D: Copy, const N: usize> {
+    descriptors: [MaybeUninit<D>; N],
+    nodes: [AmlNamespaceNodeId; N],
+    len: usize,
+}
+
+impl<D: Copy, const N: usize> GenericFamily<D, N> {
+    const fn new() -> Self {
+        Self {
+            descriptors: [MaybeUninit::uninit(); N],
+            nodes: [AmlNamespaceNodeId(0); N],
+            len: 0,
+        }
+    }
+
+    fn ordinal(&self) -> Result<u8, AcpiError> {
+        if self.len >= N {
+            return Err(AcpiError::resource_exhausted());
+        }
+        u8::try_from(self.len).map_err(|_| AcpiError::resource_exhausted())
+    }
+
+    fn push(&mut self, descriptor: D, node: AmlNamespaceNodeId) -> Result<(), AcpiError> {
+        let _ = self.ordinal()?;
+        self.descriptors[self.len].write(descriptor);
+        self.nodes[self.len] = node;
+        self.len += 1;
+        Ok(())
+    }
+
+    const fn descriptors(&self) -> &[D] {
+        // SAFETY: the first `len` descriptors were initialized by `push`.
+        unsafe { core::slice::from_raw_parts(self.descriptors.as_ptr().cast::<D>(), self.len) }
+    }
+
+    fn node(&self, index: u8) -> Result<AmlNamespaceNodeId, AcpiError> {
+        let index = usize::from(index);
+        if index >= self.len {
+            return Err(AcpiError::invalid());
+        }
+        Ok(self.nodes[index])
+    }
+}
+
+/// Rendered namespace paths backing the `&'static str` fields of bound descriptors.
+struct GenericPathTable {
+    text: [[u8; AML_MAX_PATH_TEXT_BYTES]; ACPI_GENERIC_MAX_DEVICES],
+    len: [usize; ACPI_GENERIC_MAX_DEVICES],
+}
+
+impl GenericPathTable {
+    const fn new() -> Self {
+        Self {
+            text: [[0; AML_MAX_PATH_TEXT_BYTES]; ACPI_GENERIC_MAX_DEVICES],
+            len: [0; ACPI_GENERIC_MAX_DEVICES],
+        }
+    }
+
+    fn write(
+        &mut self,
+        index: usize,
+        path: AmlResolvedNamePath,
+    ) -> Result<(), AcpiRealizationError> {
+        let text = self
+            .text
+            .get_mut(index)
+            .ok_or_else(AcpiRealizationError::resource_exhausted)?;
+        self.len[index] = path
+            .write_text(text)
+            .map_err(|_| AcpiRealizationError::invalid())?;
+        Ok(())
+    }
+
+    fn object(&'static self, index: usize, device: &AcpiGenericDevice) -> AcpiObjectDescriptor {
+        let path = core::str::from_utf8(&self.text[index][..self.len[index]]).unwrap_or("\\");
+        let name = path
+            .rsplit(['.', '\\'])
+            .next()
+            .filter(|name| !name.is_empty())
+            .unwrap_or(path);
+        AcpiObjectDescriptor {
+            name,
+            path,
+            hid: device.hid,
+            uid: device.uid,
+            description: class_description(device.class),
+        }
+    }
+}
+
+const fn class_description(class: AcpiGenericDeviceClass) -> &'static str {
+    match class {
+        AcpiGenericDeviceClass::Battery => "ACPI control-method battery",
+        AcpiGenericDeviceClass::PowerSource => "ACPI power-source device",
+        AcpiGenericDeviceClass::Lid => "ACPI lid device",
+        AcpiGenericDeviceClass::Button(AcpiButtonKind::Power) => "ACPI power button",
+        AcpiGenericDeviceClass::Button(AcpiButtonKind::Sleep) => "ACPI sleep button",
+        AcpiGenericDeviceClass::Button(_) => "ACPI vendor button or switch",
+        AcpiGenericDeviceClass::EmbeddedController => "ACPI embedded controller",
+        AcpiGenericDeviceClass::Fan => "ACPI fan device",
+        AcpiGenericDeviceClass::ThermalZone => "ACPI thermal zone",
+        AcpiGenericDeviceClass::Processor => "ACPI processor",
+    }
+}
+
+/// Bound generic platform: the namespace it evaluates against plus every family it surfaced.
+struct GenericPlatform {
+    namespace: AmlLoadedNamespace<'static, 'static>,
+    host: &'static (dyn AmlRegionAccessHost + Sync),
+    runtime: &'static AmlRuntimeState<'static>,
+    evaluating: AtomicBool,
+    batteries: GenericFamily<AcpiBatteryDescriptor, ACPI_GENERIC_MAX_DEVICES_PER_FAMILY>,
+    power_sources: GenericFamily<AcpiPowerSourceDescriptor, ACPI_GENERIC_MAX_DEVICES_PER_FAMILY>,
+    thermal_zones: GenericFamily<AcpiThermalZoneDescriptor, ACPI_GENERIC_MAX_DEVICES_PER_FAMILY>,
+    fans: GenericFamily<AcpiFanDescriptor, ACPI_GENERIC_MAX_DEVICES_PER_FAMILY>,
+    buttons: GenericFamily<AcpiButtonDescriptor, ACPI_GENERIC_MAX_DEVICES_PER_FAMILY>,
+    lids: GenericFamily<AcpiLidDescriptor, ACPI_GENERIC_MAX_DEVICES_PER_FAMILY>,
+    embedded_controllers:
+        GenericFamily<AcpiEmbeddedControllerDescriptor, ACPI_GENERIC_MAX_DEVICES_PER_FAMILY>,
+    processors: GenericFamily<AcpiProcessorDescriptor, ACPI_GENERIC_MAX_DEVICES>,
+}
+
+impl GenericPlatform {
+    const fn new(
+        namespace: AmlLoadedNamespace<'static, 'static>,
+        host: &'static (dyn AmlRegionAccessHost + Sync),
+        runtime: &'static AmlRuntimeState<'static>,
+    ) -> Self {
+        Self {
+            namespace,
+            host,
+            runtime,
+            evaluating: AtomicBool::new(false),
+            batteries: GenericFamily::new(),
+            power_sources: GenericFamily::new(),
+            thermal_zones: GenericFamily::new(),
+            fans: GenericFamily::new(),
+            buttons: GenericFamily::new(),
+            lids: GenericFamily::new(),
+            embedded_controllers: GenericFamily::new(),
+            processors: GenericFamily::new(),
+        }
+    }
+
+    fn bind(
+        &mut self,
+        scope: &GenericAmlScope<'_, '_, '_, '_>,
+        device: AcpiGenericDevice,
+        object: AcpiObjectDescriptor,
+    ) -> Result<(), AcpiError> {
+        let node = device.node;
+        match device.class {
+            AcpiGenericDeviceClass::Battery => {
+                let slot_index = self.batteries.ordinal()?;
+                self.batteries.push(
+                    AcpiBatteryDescriptor {
+                        object,
+                        slot_index,
+                        bay_name: BATTERY_BAY_NAMES[usize::from(slot_index)],
+                        secondary: slot_index != 0,
+                        technology: AcpiBatteryTechnology::Unknown,
+                    },
+                    node,
+                )
+            }
+            AcpiGenericDeviceClass::PowerSource => {
+                let consumer_count = scope
+                    .evaluate_child(node, *b"_PCL")?
+                    .and_then(|consumers| {
+                        scope
+                            .evaluator
+                            .value_length(Some(scope.runtime), consumers)
+                            .ok()
+                    })
+                    .and_then(|count| u8::try_from(count).ok())
+                    .unwrap_or(0);
+                self.power_sources.push(
+                    AcpiPowerSourceDescriptor {
+                        object,
+                        consumer_count,
+                    },
+                    node,
+                )
+            }
+            AcpiGenericDeviceClass::ThermalZone => self.thermal_zones.push(
+                AcpiThermalZoneDescriptor {
+                    object,
+                    critical_temperature: scope.temperature_child(node, *b"_CRT")?,
+                },
+                node,
+            ),
+            AcpiGenericDeviceClass::Fan => self.fans.push(AcpiFanDescriptor { object }, node),
+            AcpiGenericDeviceClass::Button(kind) => self
+                .buttons
+                .push(AcpiButtonDescriptor { object, kind }, node),
+            AcpiGenericDeviceClass::Lid => self.lids.push(AcpiLidDescriptor { object }, node),
+            AcpiGenericDeviceClass::EmbeddedController => {
+                let (data_port, command_port) = scope.embedded_controller_ports(node)?;
+                let gpe = scope
+                    .integer_child(node, *b"_GPE")?
+                    .and_then(|gpe| u8::try_from(gpe).ok());
+                self.embedded_controllers.push(
+                    AcpiEmbeddedControllerDescriptor {
+                        object,
+                        data_port,
+                        command_port,
+                        gpe,
+                    },
+                    node,
+                )
+            }
+            AcpiGenericDeviceClass::Processor => {
+                let logical_index = self.processors.ordinal()?;
+                let acpi_processor_id = device
+                    .uid
+                    .and_then(|uid| u8::try_from(uid).ok())
+                    .unwrap_or(logical_index);
+                self.processors.push(
+                    AcpiProcessorDescriptor {
+                        object,
+                        acpi_processor_id,
+                        logical_index,
+                    },
+                    node,
+                )
+            }
+        }
+    }
+
+    fn with_scope<R>(
+        &'static self,
+        read: impl FnOnce(&GenericAmlScope<'static, 'static, 'static, 'static>) -> Result<R, AcpiError>,
+    ) -> Result<R, AcpiError> {
+        if self.evaluating.swap(true, Ordering::Acquire) {
+            return Err(AcpiError::busy());
+        }
+        let _evaluation = GenericEvaluation(&self.evaluating);
+        let scope = GenericAmlScope::new(self.namespace, self.host, self.runtime);
+        read(&scope)
+    }
+}
+
+// SAFETY: the namespace and runtime state are only reached through `with_scope`, which
+// `evaluating` serializes, and the host is `Sync` on its own.
+unsafe impl Send for GenericPlatform {}
+// SAFETY: see the `Send` impl above.
+unsafe impl Sync for GenericPlatform {}
+
+/// Releases `GenericPlatform::evaluating` however the evaluation ends, unwinding included.
+struct GenericEvaluation<'a>(&'a AtomicBool);
+
+impl Drop for GenericEvaluation<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+const SLOT_EMPTY: u8 = 0;
+const SLOT_INSTALLING: u8 = 1;
+const SLOT_READY: u8 = 2;
+
+/// Install-once firmware slot; readers only ever observe a fully built value.
+struct GenericOnce<T> {
+    state: AtomicU8,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+impl<T> GenericOnce<T> {
+    const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(SLOT_EMPTY),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    fn install(
+        &'static self,
+        build: impl FnOnce() -> Result<T, AcpiRealizationError>,
+    ) -> Result<&'static T, AcpiRealizationError> {
+        if self
+            .state
+            .compare_exchange(
+                SLOT_EMPTY,
+                SLOT_INSTALLING,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_err()
+        {
+            return Err(AcpiRealizationError::state_conflict());
+        }
+
+        match build() {
+            Ok(value) => {
+                // SAFETY: the `INSTALLING` transition above grants exclusive write access.
+                let value = unsafe { (*self.value.get()).write(value) };
+                self.state.store(SLOT_READY, Ordering::Release);
+                Ok(value)
+            }
+            Err(error) => {
+                self.state.store(SLOT_EMPTY, Ordering::Release);
+                Err(error)
+            }
+        }
+    }
+
+    /// Empties a slot whose value nobody may observe any more.
+    ///
+    /// # Safety
+    ///
+    /// No reference obtained from [`Self::install`] or [`Self::get`] may still be alive.
+    unsafe fn reset(&'static self) {
+        if self
+            .state
+            .compare_exchange(
+                SLOT_READY,
+                SLOT_INSTALLING,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_ok()
+        {
+            // SAFETY: `READY` means the value was written; the caller vouches nobody reads it.
+            unsafe { (*self.value.get()).assume_init_drop() };
+            self.state.store(SLOT_EMPTY, Ordering::Release);
+        }
+    }
+
+    fn get(&'static self) -> Option<&'static T> {
+        if self.state.load(Ordering::Acquire) != SLOT_READY {
+            return None;
+        }
+        // SAFETY: `READY` is only published after the value was written, and only revoked once
+        // no reference is left.
+        Some(unsafe { (*self.value.get()).assume_init_ref() })
+    }
+}
+
+// SAFETY: the value is written once before `READY` is published and is read-only afterwards,
+// so sharing the slot only ever shares `&T` across threads (and may drop `T` on another one).
+unsafe impl<T: Send + Sync> Sync for GenericOnce<T> {}
+
+static GENERIC_PATHS: GenericOnce<GenericPathTable> = GenericOnce::new();
+static GENERIC_PLATFORM: GenericOnce<GenericPlatform> = GenericOnce::new();
+
+const fn map_aml_error(error: AmlError) -> AcpiError {
+    match error.kind {
+        AmlErrorKind::Unsupported => AcpiError::unsupported(),
+        AmlErrorKind::Overflow => AcpiError::resource_exhausted(),
+        AmlErrorKind::NamespaceConflict | AmlErrorKind::InvalidState => AcpiError::state_conflict(),
+        AmlErrorKind::HostFailure => AcpiError::platform(-1),
+        AmlErrorKind::Truncated
+        | AmlErrorKind::InvalidBytecode
+        | AmlErrorKind::InvalidDefinitionBlock
+        | AmlErrorKind::InvalidName
+        | AmlErrorKind::InvalidNamespace
+        | AmlErrorKind::UndefinedObject => AcpiError::invalid(),
+    }
+}
+
+const fn map_acpi_error(error: AcpiError) -> AcpiRealizationError {
+    match error.kind() {
+        AcpiErrorKind::Unsupported => AcpiRealizationError::unsupported(),
+        AcpiErrorKind::Invalid => AcpiRealizationError::invalid(),
+        AcpiErrorKind::Busy => AcpiRealizationError::busy(),
+        AcpiErrorKind::ResourceExhausted => AcpiRealizationError::resource_exhausted(),
+        AcpiErrorKind::StateConflict => AcpiRealizationError::state_conflict(),
+        AcpiErrorKind::Platform(code) => AcpiRealizationError::platform(code),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use std::boxed::Box;
+    use std::vec;
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::aml::{
+        AmlAccessWidth,
+        AmlBackendVerificationIssue,
+        AmlDefinitionBlock,
+        AmlDefinitionBlockSet,
+        AmlEmbeddedControllerHost,
+        AmlHost,
+        AmlNamespaceLoadPlan,
+        AmlNotifySink,
+        AmlOspmInterface,
+        AmlPciConfigHost,
+        AmlResult,
+        AmlRuntimeIntegerSlot,
+        AmlSleepHost,
+        AmlSystemIoHost,
+        AmlSystemMemoryHost,
+        AmlTableHost,
+        AmlTraceHost,
+    };
+    use crate::pal::hal::acpi::{
+        AcpiPlatformBackendKind,
+        AcpiRealizationErrorKind,
+        Dsdt,
+        RealizedAcpiPlatform,
+        dell_latitude_e6430_generic_quirks,
+        realize_generic_platform_with_aml,
+    };
+
+    fn encode_pkg_length(payload_len: usize) -> Vec<u8> {
+        let one_byte_value = payload_len + 1;
+        if one_byte_value < 0x40 {
+            return vec![u8::try_from(one_byte_value).unwrap()];
+        }
+        let two_byte_value = payload_len + 2;
+        vec![
+            0b0100_0000 | u8::try_from(two_byte_value & 0x0f).unwrap(),
+            u8::try_from((two_byte_value >> 4) & 0xff).unwrap(),
+        ]
+    }
+
+    fn pkg(opcode: &[u8], payload: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::from(opcode);
+        bytes.extend_from_slice(&encode_pkg_length(payload.len()));
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    fn named(opcode: &[u8], name: &[u8], body: &[u8]) -> Vec<u8> {
+        let mut payload = Vec::from(name);
+        payload.extend_from_slice(body);
+        pkg(opcode, &payload)
+    }
+
+    fn method(name: [u8; 4], body: &[u8]) -> Vec<u8> {
+        let mut payload = Vec::from(name);
+        payload.push(0x00);
+        payload.extend_from_slice(body);
+        pkg(&[0x14], &payload)
+    }
+
+    fn name(name: [u8; 4], value: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0x08];
+        bytes.extend_from_slice(&name);
+        bytes.extend_from_slice(value);
+        bytes
+    }
+
+    fn string(text: &str) -> Vec<u8> {
+        let mut bytes = vec![0x0d];
+        bytes.extend_from_slice(text.as_bytes());
+        bytes.push(0x00);
+        bytes
+    }
+
+    fn package(elements: &[&[u8]]) -> Vec<u8> {
+        let mut payload = vec![u8::try_from(elements.len()).unwrap()];
+        for element in elements {
+            payload.extend_from_slice(element);
+        }
+        pkg(&[0x12], &payload)
+    }
+
+    fn eisa(id: u32) -> Vec<u8> {
+        let mut bytes = vec![0x0c];
+        bytes.extend_from_slice(&id.to_le_bytes());
+        bytes
+    }
+
+    fn load_namespace(payload: &[u8]) -> AmlLoadedNamespace<'static, 'static> {
+        let mut table = Vec::from([0_u8; 36]);
+        table[0..4].copy_from_slice(b"DSDT");
+        table[4..8].copy_from_slice(&u32::try_from(36 + payload.len()).unwrap().to_le_bytes());
+        table[8] = 2;
+        table[10..16].copy_from_slice(b"FUSION");
+        table[16..24].copy_from_slice(b"GENERIC_");
+        table.extend_from_slice(payload);
+        let checksum =
+            (!table.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        table[9] = checksum;
+        let leaked = Box::leak(table.into_boxed_slice());
+        let block = AmlDefinitionBlock::from_dsdt(Dsdt::parse(leaked).unwrap()).unwrap();
+        let storage = Box::leak(
+            vec![MaybeUninit::<AmlNamespaceLoadRecord>::uninit(); 128].into_boxed_slice(),
+        );
+        AmlNamespaceLoadPlan::from_definition_blocks(AmlDefinitionBlockSet::new(block, &[]))
+            .load_into(storage)
+            .unwrap()
+    }
+
+    fn laptop_namespace() -> AmlLoadedNamespace<'static, 'static> {
+        let mut sb = Vec::new();
+
+        let mut battery = name(*b"_HID", &eisa(0x0a0c_d041));
+        battery.extend(name(*b"_UID", &[0x01]));
+        battery.extend(method(*b"_STA", &[0xa4, 0x0a, 0x1f]));
+        battery.extend(name(
+            *b"_BIF",
+            &package(&[
+                &[0x00],
+                &[0x0b, 0x60, 0x22],
+                &[0x0b, 0x10, 0x1f],
+                &[0x01],
+                &[0x0b, 0x4c, 0x2c],
+                &[0x0a, 0x64],
+                &[0x0a, 0x32],
+                &[0x0a, 0x01],
+                &[0x0a, 0x01],
+                &string("DELL 4M5290"),
+                &string("1234"),
+                &string("LION"),
+                &string("SMP"),
+            ]),
+        ));
+        battery.extend(name(
+            *b"BSTP",
+            &package(&[
+                &[0x0a, 0x02],
+                &[0x0b, 0xe8, 0x03],
+                &[0x0b, 0x00, 0x10],
+                &[0x0c, 0xff, 0xff, 0xff, 0xff],
+            ]),
+        ));
+        battery.extend(method(*b"_BST", &[0xa4, b'B', b'S', b'T', b'P']));
+        sb.extend(named(&[0x5b, 0x82], b"BAT0", &battery));
+
+        let mut adapter = name(*b"_HID", &string("ACPI0003"));
+        adapter.extend(name(*b"_PCL", &package(&[&string("BAT0")])));
+        adapter.extend(method(*b"_PSR", &[0xa4, 0x01]));
+        sb.extend(named(&[0x5b, 0x82], b"AC__", &adapter));
+
+        let mut lid = name(*b"_HID", &eisa(0x0d0c_d041));
+        lid.extend(name(*b"_PRW", &package(&[&[0x0a, 0x1d], &[0x0a, 0x03]])));
+        lid.extend(method(*b"_LID", &[0xa4, 0x00]));
+        sb.extend(named(&[0x5b, 0x82], b"LID0", &lid));
+
+        sb.extend(named(
+            &[0x5b, 0x82],
+            b"PWRB",
+            &name(*b"_HID", &eisa(0x0c0c_d041)),
+        ));
+        sb.extend(named(
+            &[0x5b, 0x82],
+            b"RBTN",
+            &name(*b"_HID", &string("DELLABCE")),
+        ));
+
+        let mut ec = name(*b"_HID", &eisa(0x090c_d041));
+        ec.extend(name(*b"_GPE", &[0x0a, 0x17]));
+        let resources: &[u8] = &[
+            0x47, 0x01, 0x62, 0x00, 0x62, 0x00, 0x00, 0x01, // IO(Decode16, 0x62, 0x62, 0, 1)
+            0x47, 0x01, 0x66, 0x00, 0x66, 0x00, 0x00, 0x01, // IO(Decode16, 0x66, 0x66, 0, 1)
+            0x79, 0x00, // EndTag
+        ];
+        let mut buffer = vec![0x0a, u8::try_from(resources.len()).unwrap()];
+        buffer.extend_from_slice(resources);
+        ec.extend(name(*b"_CRS", &pkg(&[0x11], &buffer)));
+        sb.extend(named(&[0x5b, 0x82], b"EC0_", &ec));
+
+        let mut fan = name(*b"_HID", &eisa(0x0b0c_d041));
+        fan.extend(name(
+            *b"_FST",
+            &package(&[&[0x00], &[0x0a, 0x32], &[0x0b, 0x80, 0x0c]]),
+        ));
+        sb.extend(named(&[0x5b, 0x82], b"FAN0", &fan));
+
+        let mut thermal = method(*b"_TMP", &[0xa4, 0x0b, 0x2a, 0x0c]);
+        thermal.extend(method(*b"_CRT", &[0xa4, 0x0b, 0xae, 0x0e]));
+        thermal.extend(name(*b"_PSV", &[0x0b, 0x4a, 0x0e]));
+        sb.extend(named(&[0x5b, 0x85], b"THM0", &thermal));
+
+        sb.extend(named(
+            &[0x5b, 0x83],
+            b"CPU0",
+            &[0x02, 0x10, 0x04, 0x00, 0x00, 0x06],
+        ));
+
+        load_namespace(&named(&[0x10], b"\\_SB_", &sb))
+    }
+
+    /// One embedded controller whose `_CRS` names no ports, which binding must refuse.
+    fn portless_ec_namespace() -> AmlLoadedNamespace<'static, 'static> {
+        let mut ec = name(*b"_HID", &eisa(0x090c_d041));
+        ec.extend(name(*b"_CRS", &pkg(&[0x11], &[0x0a, 0x02, 0x79, 0x00])));
+        load_namespace(&named(
+            &[0x10],
+            b"\\_SB_",
+            &named(&[0x5b, 0x82], b"EC1_", &ec),
+        ))
+    }
+
+    struct FakeEcHost {
+        ec: [AtomicU8; 256],
+    }
+
+    impl AmlOspmInterface for FakeEcHost {
+        fn osi_supported(&self, _interface: &str) -> bool {
+            false
+        }
+
+        fn os_revision(&self) -> u64 {
+            0
+        }
+    }
+
+    impl AmlSleepHost for FakeEcHost {
+        fn stall_us(&self, _microseconds: u32) -> AmlResult<()> {
+            Ok(())
+        }
+
+        fn sleep_ms(&self, _milliseconds: u32) -> AmlResult<()> {
+            Ok(())
+        }
+    }
+
+    impl AmlNotifySink for FakeEcHost {
+        fn notify(&self, _source: AmlNamespaceNodeId, _value: u8) -> AmlResult<()> {
+            Ok(())
+        }
+    }
+
+    impl AmlSystemMemoryHost for FakeEcHost {
+        fn read_system_memory(&self, _address: u64, _width: AmlAccessWidth) -> AmlResult<u64> {
+            Err(AmlError::unsupported())
+        }
+
+        fn write_system_memory(
+            &self,
+            _address: u64,
+            _width: AmlAccessWidth,
+            _value: u64,
+        ) -> AmlResult<()> {
+            Err(AmlError::unsupported())
+        }
+    }
+
+    impl AmlSystemIoHost for FakeEcHost {
+        fn read_system_io(&self, _port: u64, _width: AmlAccessWidth) -> AmlResult<u64> {
+            Err(AmlError::unsupported())
+        }
+
+        fn write_system_io(
+            &self,
+            _port: u64,
+            _width: AmlAccessWidth,
+            _value: u64,
+        ) -> AmlResult<()> {
+            Err(AmlError::unsupported())
+        }
+    }
+
+    impl AmlPciConfigHost for FakeEcHost {
+        fn read_pci_config(&self, _address: u64, _width: AmlAccessWidth) -> AmlResult<u64> {
+            Err(AmlError::unsupported())
+        }
+
+        fn write_pci_config(
+            &self,
+            _address: u64,
+            _width: AmlAccessWidth,
+            _value: u64,
+        ) -> AmlResult<()> {
+            Err(AmlError::unsupported())
+        }
+    }
+
+    impl AmlEmbeddedControllerHost for FakeEcHost {
+        fn read_embedded_controller(&self, register: u8) -> AmlResult<u8> {
+            Ok(self.ec[usize::from(register)].load(Ordering::Relaxed))
+        }
+
+        fn write_embedded_controller(&self, register: u8, value: u8) -> AmlResult<()> {
+            self.ec[usize::from(register)].store(value, Ordering::Relaxed);
+            Ok(())
+        }
+    }
+
+    impl AmlTableHost for FakeEcHost {}
+
+    impl AmlTraceHost for FakeEcHost {}
+
+    impl AmlHost for FakeEcHost {}
+
+    fn leaked_runtime() -> &'static AmlRuntimeState<'static> {
+        let integers: &'static [Cell<Option<AmlRuntimeIntegerSlot>>; 16] =
+            Box::leak(Box::new(core::array::from_fn(|_| Cell::new(None))));
+        Box::leak(Box::new(AmlRuntimeState::new(integers)))
+    }
+
+    #[test]
+    fn eisa_ids_decode_to_their_textual_spelling() {
+        assert_eq!(decode_eisa_id(0x0a0c_d041), Some(*b"PNP0C0A"));
+        assert_eq!(decode_eisa_id(0x090c_d041), Some(*b"PNP0C09"));
+        assert_eq!(decode_eisa_id(0x1_0000_0000), None);
+        assert_eq!(decode_eisa_id(0), None);
+    }
+
+    #[test]
+    fn standard_hardware_ids_classify_into_public_families() {
+        assert_eq!(
+            classify_acpi_hardware_id("PNP0C0A"),
+            Some(("PNP0C0A", AcpiGenericDeviceClass::Battery))
+        );
+        assert_eq!(
+            classify_acpi_hardware_id("PNP0C0E"),
+            Some((
+                "PNP0C0E",
+                AcpiGenericDeviceClass::Button(AcpiButtonKind::Sleep)
+            ))
+        );
+        assert_eq!(classify_acpi_hardware_id("DELLABCE"), None);
+    }
+
+    #[test]
+    fn discovery_honors_ignored_paths() {
+        static IGNORED: [&str; 1] = ["\\_SB.FAN0"];
+        let namespace = laptop_namespace();
+        let host = FakeEcHost {
+            ec: core::array::from_fn(|_| AtomicU8::new(0)),
+        };
+        let runtime = leaked_runtime();
+        let quirks = AcpiGenericQuirks {
+            hardware_ids: &[],
+            ignored_paths: &IGNORED,
+        };
+        let mut storage = [MaybeUninit::uninit(); ACPI_GENERIC_MAX_DEVICES];
+
+        let devices = discover_generic_devices(namespace, &host, runtime, &quirks, &mut storage)
+            .expect("generic discovery should work");
+
+        assert!(
+            devices
+                .iter()
+                .all(|device| device.class != AcpiGenericDeviceClass::Fan)
+        );
+        assert!(
+            devices.iter().all(|device| device.class
+                != AcpiGenericDeviceClass::Button(AcpiButtonKind::AirplaneMode))
+        );
+        assert_eq!(devices.len(), 7);
+    }
+
+    fn check_power_surfaces(platform: &RealizedAcpiPlatform) {
+        let battery = platform.battery().expect("battery should be realized");
+        let descriptor = battery.batteries()[0];
+        assert_eq!(descriptor.object.path, "\\_SB.BAT0");
+        assert_eq!(descriptor.object.name, "BAT0");
+        assert_eq!(descriptor.object.hid, Some("PNP0C0A"));
+        assert_eq!(descriptor.object.uid, Some(1));
+        let information = battery.battery_information(0).unwrap();
+        assert_eq!(information.design_capacity, Some(0x2260));
+        assert_eq!(information.last_full_charge_capacity, Some(0x1f10));
+        assert_eq!(information.design_voltage_mv, Some(0x2c4c));
+        assert_eq!(information.model, Some("DELL 4M5290"));
+        assert_eq!(information.oem, Some("SMP"));
+        let status = battery.battery_status(0).unwrap();
+        assert!(status.present && status.charging && !status.discharging);
+        assert_eq!(status.present_rate, Some(1000));
+        assert_eq!(status.remaining_capacity, Some(0x1000));
+        assert_eq!(status.present_voltage_mv, None);
+
+        let power_source = platform.power_source().expect("adapter should be realized");
+        assert_eq!(power_source.power_sources()[0].consumer_count, 1);
+        assert!(power_source.power_source_state(0).unwrap().online);
+    }
+
+    fn check_platform_surfaces(platform: &RealizedAcpiPlatform) {
+        let lid = platform.lid().expect("lid should be realized");
+        assert!(lid.lid_support(0).unwrap().wake_control_present);
+        assert!(!lid.lid_state(0).unwrap().open);
+
+        let button = platform.button().expect("buttons should be realized");
+        let kinds: Vec<_> = button.buttons().iter().map(|button| button.kind).collect();
+        assert_eq!(kinds, [AcpiButtonKind::Power, AcpiButtonKind::AirplaneMode]);
+
+        let ec = platform
+            .embedded_controller()
+            .expect("embedded controller should be realized");
+        let controller = ec.embedded_controllers()[0];
+        assert_eq!(
+            (
+                controller.data_port,
+                controller.command_port,
+                controller.gpe
+            ),
+            (0x62, 0x66, Some(0x17))
+        );
+        GenericAcpiHardware::embedded_controller_write(0, 0, 0x40, 0x5a).unwrap();
+        assert_eq!(ec.embedded_controller_read(0, 0x40).unwrap(), 0x5a);
+
+        let fan = platform.fan().expect("fan should be realized");
+        let fan_state = fan.fan_state(0).unwrap();
+        assert!(fan_state.present && fan_state.active && !fan_state.controllable);
+
+        let thermal = platform.thermal().expect("thermal zone should be realized");
+        assert_eq!(
+            thermal.thermal_zones()[0].critical_temperature,
+            Some(AcpiDeciKelvin(0x0eae))
+        );
+        let reading = thermal.thermal_reading(0).unwrap();
+        assert_eq!(reading.current, AcpiDeciKelvin(0x0c2a));
+        assert_eq!(reading.passive, Some(AcpiDeciKelvin(0x0e4a)));
+
+        let processor = platform.processor().expect("processor should be realized");
+        assert_eq!(processor.processors()[0].acpi_processor_id, 2);
+        assert!(processor.processor_state(0).unwrap().online);
+    }
+
+    #[test]
+    fn generic_platform_binds_standard_devices_after_a_failed_attempt() {
+        let namespace = laptop_namespace();
+        let host: &'static FakeEcHost = Box::leak(Box::new(FakeEcHost {
+            ec: core::array::from_fn(|_| AtomicU8::new(0)),
+        }));
+        let runtime = leaked_runtime();
+        let mut issues = [MaybeUninit::<AmlBackendVerificationIssue>::uninit(); 4];
+
+        let failed = realize_generic_platform_with_aml(
+            portless_ec_namespace(),
+            host,
+            leaked_runtime(),
+            &AcpiGenericQuirks::none(),
+            &mut issues,
+        );
+        assert_eq!(
+            failed.map(|_| ()).unwrap_err().kind(),
+            AcpiRealizationErrorKind::Invalid
+        );
+        assert_eq!(GenericAcpiHardware::provider_count(), 0);
+
+        let mut issues = [MaybeUninit::<AmlBackendVerificationIssue>::uninit(); 4];
+        let realized = realize_generic_platform_with_aml(
+            namespace,
+            host,
+            runtime,
+            &dell_latitude_e6430_generic_quirks(),
+            &mut issues,
+        )
+        .expect("generic realization should work");
+        assert!(realized.aml().is_clean());
+        let platform = realized.platform();
+        assert_eq!(platform.matched().backend, AcpiPlatformBackendKind::Generic);
+
+        check_power_surfaces(platform);
+        check_platform_surfaces(platform);
+
+        let mut issues = [MaybeUninit::<AmlBackendVerificationIssue>::uninit(); 4];
+        let again = realize_generic_platform_with_aml(
+            namespace,
+            host,
+            runtime,
+            &AcpiGenericQuirks::none(),
+            &mut issues,
+        );
+        assert_eq!(
+            again.map(|_| ()).unwrap_err().kind(),
+            AcpiRealizationErrorKind::StateConflict
+        );
+    }
+}
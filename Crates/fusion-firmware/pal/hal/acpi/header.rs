@@ -41,12 +41,30 @@ impl AcpiSignature {
     pub const FADT: Self = Self(*b"FACP");
     /// FACS signature.
     pub const FACS: Self = Self(*b"FACS");
+    /// RSDT signature.
+    pub const RSDT: Self = Self(*b"RSDT");
     /// XSDT signature.
     pub const XSDT: Self = Self(*b"XSDT");
     /// MCFG signature.
     pub const MCFG: Self = Self(*b"MCFG");
     /// MADT signature.
     pub const MADT: Self = Self(*b"APIC");
+    /// HPET signature.
+    pub const HPET: Self = Self(*b"HPET");
+    /// SRAT signature.
+    pub const SRAT: Self = Self(*b"SRAT");
+    /// SLIT signature.
+    pub const SLIT: Self = Self(*b"SLIT");
+    /// BGRT signature.
+    pub const BGRT: Self = Self(*b"BGRT");
+    /// SPCR signature.
+    pub const SPCR: Self = Self(*b"SPCR");
+    /// DBG2 signature.
+    pub const DBG2: Self = Self(*b"DBG2");
+    /// FPDT signature.
+    pub const FPDT: Self = Self(*b"FPDT");
+    /// ECDT signature.
+    pub const ECDT: Self = Self(*b"ECDT");
 
     /// Creates one signature from four bytes.
     #[must_use]
@@ -0,0 +1,219 @@
+//! HPET definitions and helpers.
+//!
+//! The High Precision Event Timer table (`HPET`) is defined by the IA-PC HPET
+//! specification rather than ACPI itself; ACPI 6.6 only reserves the signature.
+//! It tells OSPM where the event-timer block lives before any AML has run:
+//!
+//! - the event-timer block ID, which mirrors the block's capabilities register
+//!   (revision, comparator count, counter width, legacy-replacement routing and
+//!   PCI vendor),
+//! - the block's base address as a Generic Address Structure,
+//! - the minimum periodic tick and page-protection attributes.
+//!
+//! The base address must be in system memory; anything else is rejected as a
+//! layout error rather than quietly handed to a timer driver.
+
+use core::mem::size_of;
+
+use super::{
+    AcpiAddressSpace,
+    AcpiError,
+    AcpiGenericAddress,
+    AcpiSignature,
+    AcpiTableView,
+    read_unaligned_copy,
+};
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawHpetHeader {
+    event_timer_block_id: u32,
+    base_address: [u8; AcpiGenericAddress::SIZE],
+    hpet_number: u8,
+    minimum_clock_tick: u16,
+    page_protection: u8,
+}
+
+/// Page-protection guarantee for the event-timer block's mapping.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HpetPageProtection {
+    None,
+    /// Nothing else shares the 4 KiB page holding the block.
+    Protected4K,
+    /// Nothing else shares the 64 KiB region holding the block.
+    Protected64K,
+    Reserved(u8),
+}
+
+/// Borrowed validated HPET view.
+#[derive(Clone, Copy, Debug)]
+pub struct Hpet<'a> {
+    table: AcpiTableView<'a>,
+    event_timer_block_id: u32,
+    base_address: AcpiGenericAddress,
+    sequence_number: u8,
+    minimum_clock_tick: u16,
+    page_protection: u8,
+}
+
+impl<'a> Hpet<'a> {
+    /// Parses one validated HPET.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the table is malformed, truncated, not one HPET, or places
+    /// the timer block outside system memory.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, AcpiError> {
+        let table = AcpiTableView::parse_signature(bytes, AcpiSignature::HPET)?;
+        let payload = table.payload();
+        if payload.len() < size_of::<RawHpetHeader>() {
+            return Err(AcpiError::truncated());
+        }
+        let raw: RawHpetHeader = read_unaligned_copy(payload)?;
+        let base_address = AcpiGenericAddress::parse(&raw.base_address)?;
+        if base_address.space != AcpiAddressSpace::SystemMemory || base_address.is_null() {
+            return Err(AcpiError::invalid_layout());
+        }
+        Ok(Self {
+            table,
+            event_timer_block_id: u32::from_le(raw.event_timer_block_id),
+            base_address,
+            sequence_number: raw.hpet_number,
+            minimum_clock_tick: u16::from_le(raw.minimum_clock_tick),
+            page_protection: raw.page_protection,
+        })
+    }
+
+    /// Returns the underlying validated ACPI table view.
+    #[must_use]
+    pub const fn table(self) -> AcpiTableView<'a> {
+        self.table
+    }
+
+    /// Returns the raw event-timer block ID.
+    #[must_use]
+    pub const fn event_timer_block_id(self) -> u32 {
+        self.event_timer_block_id
+    }
+
+    /// Returns the hardware revision of the event-timer block.
+    #[must_use]
+    pub const fn hardware_revision(self) -> u8 {
+        (self.event_timer_block_id & 0xFF) as u8
+    }
+
+    /// Returns the number of comparators in the first timer block.
+    #[must_use]
+    pub const fn comparator_count(self) -> u8 {
+        ((self.event_timer_block_id >> 8) & 0x1F) as u8 + 1
+    }
+
+    /// Returns `true` when the main counter is 64 bits wide.
+    #[must_use]
+    pub const fn counter_is_64bit(self) -> bool {
+        self.event_timer_block_id & (1 << 13) != 0
+    }
+
+    /// Returns `true` when the block can take over the legacy PIT and RTC interrupt routes.
+    #[must_use]
+    pub const fn legacy_replacement_capable(self) -> bool {
+        self.event_timer_block_id & (1 << 15) != 0
+    }
+
+    /// Returns the PCI vendor ID of the first timer block.
+    #[must_use]
+    pub const fn pci_vendor_id(self) -> u16 {
+        (self.event_timer_block_id >> 16) as u16
+    }
+
+    /// Returns the event-timer block base address.
+    #[must_use]
+    pub const fn base_address(self) -> AcpiGenericAddress {
+        self.base_address
+    }
+
+    /// Returns the HPET sequence number.
+    #[must_use]
+    pub const fn hpet_number(self) -> u8 {
+        self.sequence_number
+    }
+
+    /// Returns the minimum main-counter tick usable in periodic mode.
+    #[must_use]
+    pub const fn minimum_clock_tick(self) -> u16 {
+        self.minimum_clock_tick
+    }
+
+    /// Returns the page-protection guarantee.
+    #[must_use]
+    pub const fn page_protection(self) -> HpetPageProtection {
+        match self.page_protection & 0x0F {
+            0 => HpetPageProtection::None,
+            1 => HpetPageProtection::Protected4K,
+            2 => HpetPageProtection::Protected64K,
+            other => HpetPageProtection::Reserved(other),
+        }
+    }
+
+    /// Returns the OEM attribute nibble.
+    #[must_use]
+    pub const fn oem_attributes(self) -> u8 {
+        self.page_protection >> 4
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::AcpiErrorKind;
+    use super::*;
+
+    /// HPET shaped like an Intel PCH reports it: eight 64-bit comparators at `0xFED0_0000`.
+    fn build_hpet(space: u8) -> [u8; 56] {
+        let mut bytes = [0_u8; 56];
+        bytes[0..4].copy_from_slice(b"HPET");
+        bytes[4..8].copy_from_slice(&(56_u32).to_le_bytes());
+        bytes[8] = 1;
+        bytes[10..16].copy_from_slice(b"INTEL ");
+        bytes[16..24].copy_from_slice(b"EDK2    ");
+        bytes[36..40].copy_from_slice(&0x8086_A701_u32.to_le_bytes());
+        bytes[40] = space;
+        bytes[41] = 64;
+        bytes[44..52].copy_from_slice(&0xFED0_0000_u64.to_le_bytes());
+        bytes[52] = 0;
+        bytes[53..55].copy_from_slice(&0x0080_u16.to_le_bytes());
+        bytes[55] = 0;
+        let checksum =
+            (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        bytes[9] = checksum;
+        bytes
+    }
+
+    #[test]
+    fn hpet_decodes_block_id_and_base() {
+        let bytes = build_hpet(0);
+        let hpet = Hpet::parse(&bytes).expect("hpet should parse");
+        assert_eq!(hpet.hardware_revision(), 1);
+        assert_eq!(hpet.comparator_count(), 8);
+        assert!(hpet.counter_is_64bit());
+        assert!(hpet.legacy_replacement_capable());
+        assert_eq!(hpet.pci_vendor_id(), 0x8086);
+        assert_eq!(hpet.base_address().address, 0xFED0_0000);
+        assert_eq!(hpet.base_address().register_bit_width, 64);
+        assert_eq!(hpet.minimum_clock_tick(), 0x80);
+        assert_eq!(hpet.page_protection(), HpetPageProtection::None);
+    }
+
+    #[test]
+    fn hpet_rejects_block_outside_system_memory() {
+        let bytes = build_hpet(1);
+        let error = Hpet::parse(&bytes).expect_err("i/o-space hpet should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidLayout);
+    }
+
+    #[test]
+    fn hpet_rejects_acpica_template_with_null_base() {
+        let bytes = include_bytes!("../../../tests/fixtures/acpi/hpet.dat");
+        let error = Hpet::parse(bytes).expect_err("zeroed timer block should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidLayout);
+    }
+}
@@ -15,15 +15,20 @@
 //!
 //! ACPI 6.6 permits a fairly absurd variety of record types here: classic APIC
 //! and x2APIC structures, GIC structures, RISC-V interrupt-controller
-//! structures, and more. Fusion only parses the subset needed for the first
-//! x86-style bring-up path:
+//! structures, and more. Fusion parses the records the firmware-topology path
+//! needs on each architecture it boots through ACPI:
 //!
-//! - Processor Local APIC,
-//! - I/O APIC,
-//! - Interrupt Source Override,
-//! - Local APIC NMI,
-//! - Local APIC Address Override,
-//! - Processor Local x2APIC.
+//! - x86: Processor Local APIC, I/O APIC, Interrupt Source Override, Local
+//!   APIC NMI, Local APIC Address Override, Processor Local x2APIC, and the
+//!   Multiprocessor Wakeup mailbox,
+//! - Arm64: GIC CPU Interface (GICC), Distributor (GICD), MSI Frame,
+//!   Redistributor (GICR) and Interrupt Translation Service (ITS),
+//! - RISC-V: RINTC, IMSIC, APLIC and PLIC.
+//!
+//! Several of those records grew over successive ACPI revisions (GICC, RINTC
+//! and Multiprocessor Wakeup in particular). Each accepts exactly the lengths a
+//! published revision defines and surfaces the later fields as `Option`s rather
+//! than guessing at bytes an older table never had.
 //!
 //! Unknown record types are preserved as opaque borrowed payloads so the parser
 //! stays forward-compatible instead of exploding the moment firmware remembers
@@ -53,7 +58,7 @@ bitflags! {
 }
 
 bitflags! {
-    /// Processor local-APIC enablement flags.
+    /// Processor enablement flags shared by local APIC, x2APIC and RINTC records.
     #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
     pub struct MadtLocalApicFlags: u32 {
         /// Processor is usable immediately.
@@ -63,6 +68,49 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// GIC CPU interface flags.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub struct MadtGiccFlags: u32 {
+        /// Processor is usable immediately.
+        const ENABLED = 1 << 0;
+        /// The performance interrupt is edge-triggered.
+        const PERFORMANCE_INTERRUPT_EDGE = 1 << 1;
+        /// The VGIC maintenance interrupt is edge-triggered.
+        const VGIC_MAINTENANCE_INTERRUPT_EDGE = 1 << 2;
+        /// Processor may be brought online later.
+        const ONLINE_CAPABLE = 1 << 3;
+        /// The GICR frame is not coherent with the CPU.
+        const GICR_NON_COHERENT = 1 << 4;
+    }
+}
+
+/// GIC architecture version named by one GICD record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MadtGicVersion {
+    /// Firmware left the version for OSPM to probe from the hardware.
+    Unspecified,
+    V1,
+    V2,
+    V3,
+    V4,
+    Reserved(u8),
+}
+
+impl MadtGicVersion {
+    #[must_use]
+    pub const fn from_raw(raw: u8) -> Self {
+        match raw {
+            0 => Self::Unspecified,
+            1 => Self::V1,
+            2 => Self::V2,
+            3 => Self::V3,
+            4 => Self::V4,
+            other => Self::Reserved(other),
+        }
+    }
+}
+
 /// Interrupt-source polarity from MADT flags.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum MadtInterruptPolarity {
@@ -187,6 +235,140 @@ struct RawProcessorLocalX2Apic {
     processor_uid: u32,
 }
 
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawGicc {
+    reserved: u16,
+    cpu_interface_number: u32,
+    processor_uid: u32,
+    flags: u32,
+    parking_protocol_version: u32,
+    performance_interrupt_gsiv: u32,
+    parked_address: u64,
+    physical_base_address: u64,
+    gicv_base_address: u64,
+    gich_base_address: u64,
+    vgic_maintenance_interrupt: u32,
+    gicr_base_address: u64,
+    mpidr: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawGiccAcpi60 {
+    processor_power_efficiency_class: u8,
+    reserved: u8,
+    spe_overflow_interrupt: u16,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawGicd {
+    reserved0: u16,
+    gic_id: u32,
+    physical_base_address: u64,
+    system_vector_base: u32,
+    gic_version: u8,
+    reserved1: [u8; 3],
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawGicMsiFrame {
+    reserved: u16,
+    msi_frame_id: u32,
+    physical_base_address: u64,
+    flags: u32,
+    spi_count: u16,
+    spi_base: u16,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawGicr {
+    flags: u8,
+    reserved: u8,
+    discovery_range_base_address: u64,
+    discovery_range_length: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawGicIts {
+    reserved0: u16,
+    its_id: u32,
+    physical_base_address: u64,
+    reserved1: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawMultiprocessorWakeup {
+    mailbox_version: u16,
+    reserved: u32,
+    mailbox_address: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawRintc {
+    version: u8,
+    reserved: u8,
+    flags: u32,
+    hart_id: u64,
+    processor_uid: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawRintcImsic {
+    external_interrupt_controller_id: u32,
+    imsic_base_address: u64,
+    imsic_size: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawImsic {
+    version: u8,
+    reserved: u8,
+    flags: u32,
+    interrupt_identities: u16,
+    guest_interrupt_identities: u16,
+    guest_index_bits: u8,
+    hart_index_bits: u8,
+    group_index_bits: u8,
+    group_index_shift: u8,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawAplic {
+    version: u8,
+    aplic_id: u8,
+    flags: u32,
+    hardware_id: [u8; 8],
+    idc_count: u16,
+    source_count: u16,
+    global_system_interrupt_base: u32,
+    address: u64,
+    size: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawPlic {
+    version: u8,
+    plic_id: u8,
+    hardware_id: [u8; 8],
+    interrupt_count: u16,
+    max_priority: u16,
+    flags: u32,
+    size: u32,
+    address: u64,
+    global_system_interrupt_base: u32,
+}
+
 /// Parsed processor-local APIC record.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct MadtProcessorLocalApic {
@@ -234,6 +416,137 @@ pub struct MadtProcessorLocalX2Apic {
     pub processor_uid: u32,
 }
 
+/// Parsed GIC CPU interface record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MadtGicc {
+    pub cpu_interface_number: u32,
+    pub processor_uid: u32,
+    pub flags: MadtGiccFlags,
+    pub parking_protocol_version: u32,
+    pub performance_interrupt_gsiv: u32,
+    pub parked_address: u64,
+    pub physical_base_address: u64,
+    pub gicv_base_address: u64,
+    pub gich_base_address: u64,
+    pub vgic_maintenance_interrupt: u32,
+    pub gicr_base_address: u64,
+    pub mpidr: u64,
+    /// ACPI 6.0 and later.
+    pub processor_power_efficiency_class: Option<u8>,
+    /// ACPI 6.3 and later; `0` inside `Some` still means "no SPE interrupt".
+    pub spe_overflow_interrupt: Option<u16>,
+    /// ACPI 6.5 and later; `0` inside `Some` still means "no TRBE interrupt".
+    pub trbe_interrupt: Option<u16>,
+}
+
+/// Parsed GIC distributor record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MadtGicd {
+    pub gic_id: u32,
+    pub physical_base_address: u64,
+    pub gic_version: MadtGicVersion,
+}
+
+/// Parsed GIC MSI frame record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MadtGicMsiFrame {
+    pub msi_frame_id: u32,
+    pub physical_base_address: u64,
+    /// SPI range overriding the frame's `MSI_TYPER`, when firmware supplied one.
+    pub spi_range: Option<MadtGicSpiRange>,
+}
+
+/// SPI range assigned to one GIC MSI frame.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MadtGicSpiRange {
+    pub base: u16,
+    pub count: u16,
+}
+
+/// Parsed GIC redistributor discovery-range record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MadtGicr {
+    pub discovery_range_base_address: u64,
+    pub discovery_range_length: u32,
+    pub non_coherent: bool,
+}
+
+/// Parsed GIC interrupt translation service record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MadtGicIts {
+    pub its_id: u32,
+    pub physical_base_address: u64,
+}
+
+/// Parsed multiprocessor wakeup mailbox record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MadtMultiprocessorWakeup {
+    pub mailbox_version: u16,
+    pub mailbox_address: u64,
+    /// ACPI 6.6 and later; the vector that returns an AP to the wakeup loop.
+    pub reset_vector: Option<u64>,
+}
+
+/// IMSIC wiring reported by one RINTC record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MadtRintcImsic {
+    pub external_interrupt_controller_id: u32,
+    pub base_address: u64,
+    pub size: u32,
+}
+
+/// Parsed RISC-V hart-local interrupt controller record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MadtRintc {
+    pub version: u8,
+    pub flags: MadtLocalApicFlags,
+    pub hart_id: u64,
+    pub processor_uid: u32,
+    /// Present on revisions that describe the hart's external interrupt controller.
+    pub imsic: Option<MadtRintcImsic>,
+}
+
+/// Parsed RISC-V incoming MSI controller record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MadtImsic {
+    pub version: u8,
+    pub flags: u32,
+    pub interrupt_identities: u16,
+    pub guest_interrupt_identities: u16,
+    pub guest_index_bits: u8,
+    pub hart_index_bits: u8,
+    pub group_index_bits: u8,
+    pub group_index_shift: u8,
+}
+
+/// Parsed RISC-V advanced platform-level interrupt controller record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MadtAplic {
+    pub version: u8,
+    pub aplic_id: u8,
+    pub flags: u32,
+    pub hardware_id: [u8; 8],
+    pub idc_count: u16,
+    pub source_count: u16,
+    pub global_system_interrupt_base: u32,
+    pub address: u64,
+    pub size: u32,
+}
+
+/// Parsed RISC-V platform-level interrupt controller record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MadtPlic {
+    pub version: u8,
+    pub plic_id: u8,
+    pub hardware_id: [u8; 8],
+    pub interrupt_count: u16,
+    pub max_priority: u16,
+    pub flags: u32,
+    pub size: u32,
+    pub address: u64,
+    pub global_system_interrupt_base: u32,
+}
+
 /// Borrowed parsed MADT record view.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum MadtRecord<'a> {
@@ -243,6 +556,16 @@ pub enum MadtRecord<'a> {
     LocalApicNmi(MadtLocalApicNmi),
     LocalApicAddressOverride(MadtLocalApicAddressOverride),
     ProcessorLocalX2Apic(MadtProcessorLocalX2Apic),
+    Gicc(MadtGicc),
+    Gicd(MadtGicd),
+    GicMsiFrame(MadtGicMsiFrame),
+    Gicr(MadtGicr),
+    GicIts(MadtGicIts),
+    MultiprocessorWakeup(MadtMultiprocessorWakeup),
+    Rintc(MadtRintc),
+    Imsic(MadtImsic),
+    Aplic(MadtAplic),
+    Plic(MadtPlic),
     Unknown { kind: u8, bytes: &'a [u8] },
 }
 
@@ -371,6 +694,21 @@ fn parse_record_body<T: Copy>(payload: &[u8]) -> Result<T, AcpiError> {
     read_unaligned_copy(payload)
 }
 
+/// Reads one record whose body grew across ACPI revisions.
+///
+/// `lengths` lists every body length a published revision defines; the returned extension slice
+/// is whatever follows the original `T` layout.
+fn parse_extensible_record_body<'a, T: Copy>(
+    payload: &'a [u8],
+    lengths: &[usize],
+) -> Result<(T, &'a [u8]), AcpiError> {
+    if !lengths.contains(&payload.len()) {
+        return Err(AcpiError::invalid_layout());
+    }
+    let raw = read_unaligned_copy(payload)?;
+    Ok((raw, &payload[size_of::<T>()..]))
+}
+
 fn parse_local_apic_flags(raw: u32) -> Result<MadtLocalApicFlags, AcpiError> {
     let enabled = MadtLocalApicFlags::ENABLED.bits();
     let online_capable = MadtLocalApicFlags::ONLINE_CAPABLE.bits();
@@ -456,6 +794,17 @@ fn parse_record<'a>(kind: u8, payload: &'a [u8]) -> Result<MadtRecord<'a>, AcpiE
                 processor_uid: u32::from_le(raw.processor_uid),
             }))
         }
+        0x0B..=0x0F => parse_gic_record(kind, payload),
+        0x10 => {
+            let (raw, extension): (RawMultiprocessorWakeup, _) =
+                parse_extensible_record_body(payload, &[14, 22])?;
+            Ok(MadtRecord::MultiprocessorWakeup(MadtMultiprocessorWakeup {
+                mailbox_version: u16::from_le(raw.mailbox_version),
+                mailbox_address: u64::from_le(raw.mailbox_address),
+                reset_vector: read_extension::<u64>(extension)?.map(u64::from_le),
+            }))
+        }
+        0x18..=0x1B => parse_riscv_record(kind, payload),
         _ => Ok(MadtRecord::Unknown {
             kind,
             bytes: payload,
@@ -463,6 +812,163 @@ fn parse_record<'a>(kind: u8, payload: &'a [u8]) -> Result<MadtRecord<'a>, AcpiE
     }
 }
 
+/// Parses the Arm GIC record family (kinds `0x0B..=0x0F`).
+fn parse_gic_record(kind: u8, payload: &[u8]) -> Result<MadtRecord<'_>, AcpiError> {
+    match kind {
+        0x0B => parse_gicc(payload).map(MadtRecord::Gicc),
+        0x0C => {
+            let raw: RawGicd = parse_record_body(payload)?;
+            if raw.system_vector_base != 0 {
+                return Err(AcpiError::invalid_layout());
+            }
+            Ok(MadtRecord::Gicd(MadtGicd {
+                gic_id: u32::from_le(raw.gic_id),
+                physical_base_address: u64::from_le(raw.physical_base_address),
+                gic_version: MadtGicVersion::from_raw(raw.gic_version),
+            }))
+        }
+        0x0D => {
+            let raw: RawGicMsiFrame = parse_record_body(payload)?;
+            let flags = u32::from_le(raw.flags);
+            if flags & !1 != 0 {
+                return Err(AcpiError::invalid_layout());
+            }
+            Ok(MadtRecord::GicMsiFrame(MadtGicMsiFrame {
+                msi_frame_id: u32::from_le(raw.msi_frame_id),
+                physical_base_address: u64::from_le(raw.physical_base_address),
+                spi_range: (flags & 1 != 0).then(|| MadtGicSpiRange {
+                    base: u16::from_le(raw.spi_base),
+                    count: u16::from_le(raw.spi_count),
+                }),
+            }))
+        }
+        0x0E => {
+            let raw: RawGicr = parse_record_body(payload)?;
+            Ok(MadtRecord::Gicr(MadtGicr {
+                discovery_range_base_address: u64::from_le(raw.discovery_range_base_address),
+                discovery_range_length: u32::from_le(raw.discovery_range_length),
+                non_coherent: raw.flags & 1 != 0,
+            }))
+        }
+        0x0F => {
+            let raw: RawGicIts = parse_record_body(payload)?;
+            Ok(MadtRecord::GicIts(MadtGicIts {
+                its_id: u32::from_le(raw.its_id),
+                physical_base_address: u64::from_le(raw.physical_base_address),
+            }))
+        }
+        _ => Ok(MadtRecord::Unknown {
+            kind,
+            bytes: payload,
+        }),
+    }
+}
+
+/// Parses the RISC-V interrupt-controller record family (kinds `0x18..=0x1B`).
+fn parse_riscv_record(kind: u8, payload: &[u8]) -> Result<MadtRecord<'_>, AcpiError> {
+    match kind {
+        0x18 => parse_rintc(payload).map(MadtRecord::Rintc),
+        0x19 => {
+            let raw: RawImsic = parse_record_body(payload)?;
+            Ok(MadtRecord::Imsic(MadtImsic {
+                version: raw.version,
+                flags: u32::from_le(raw.flags),
+                interrupt_identities: u16::from_le(raw.interrupt_identities),
+                guest_interrupt_identities: u16::from_le(raw.guest_interrupt_identities),
+                guest_index_bits: raw.guest_index_bits,
+                hart_index_bits: raw.hart_index_bits,
+                group_index_bits: raw.group_index_bits,
+                group_index_shift: raw.group_index_shift,
+            }))
+        }
+        0x1A => parse_aplic(payload).map(MadtRecord::Aplic),
+        0x1B => parse_plic(payload).map(MadtRecord::Plic),
+        _ => Ok(MadtRecord::Unknown {
+            kind,
+            bytes: payload,
+        }),
+    }
+}
+
+fn read_extension<T: Copy>(extension: &[u8]) -> Result<Option<T>, AcpiError> {
+    if extension.is_empty() {
+        return Ok(None);
+    }
+    read_unaligned_copy(extension).map(Some)
+}
+
+fn parse_rintc(payload: &[u8]) -> Result<MadtRintc, AcpiError> {
+    let (raw, extension): (RawRintc, _) = parse_extensible_record_body(payload, &[18, 34])?;
+    Ok(MadtRintc {
+        version: raw.version,
+        flags: parse_local_apic_flags(u32::from_le(raw.flags))?,
+        hart_id: u64::from_le(raw.hart_id),
+        processor_uid: u32::from_le(raw.processor_uid),
+        imsic: read_extension::<RawRintcImsic>(extension)?.map(|imsic| MadtRintcImsic {
+            external_interrupt_controller_id: u32::from_le(imsic.external_interrupt_controller_id),
+            base_address: u64::from_le(imsic.imsic_base_address),
+            size: u32::from_le(imsic.imsic_size),
+        }),
+    })
+}
+
+fn parse_aplic(payload: &[u8]) -> Result<MadtAplic, AcpiError> {
+    let raw: RawAplic = parse_record_body(payload)?;
+    Ok(MadtAplic {
+        version: raw.version,
+        aplic_id: raw.aplic_id,
+        flags: u32::from_le(raw.flags),
+        hardware_id: raw.hardware_id,
+        idc_count: u16::from_le(raw.idc_count),
+        source_count: u16::from_le(raw.source_count),
+        global_system_interrupt_base: u32::from_le(raw.global_system_interrupt_base),
+        address: u64::from_le(raw.address),
+        size: u32::from_le(raw.size),
+    })
+}
+
+fn parse_plic(payload: &[u8]) -> Result<MadtPlic, AcpiError> {
+    let raw: RawPlic = parse_record_body(payload)?;
+    Ok(MadtPlic {
+        version: raw.version,
+        plic_id: raw.plic_id,
+        hardware_id: raw.hardware_id,
+        interrupt_count: u16::from_le(raw.interrupt_count),
+        max_priority: u16::from_le(raw.max_priority),
+        flags: u32::from_le(raw.flags),
+        size: u32::from_le(raw.size),
+        address: u64::from_le(raw.address),
+        global_system_interrupt_base: u32::from_le(raw.global_system_interrupt_base),
+    })
+}
+
+fn parse_gicc(payload: &[u8]) -> Result<MadtGicc, AcpiError> {
+    // ACPI 5.1 (76-byte record), 6.0 (80) and 6.5 (82).
+    let (raw, extension): (RawGicc, _) = parse_extensible_record_body(payload, &[74, 78, 80])?;
+    let acpi60 = read_extension::<RawGiccAcpi60>(extension)?;
+    let trbe_interrupt = match extension.get(size_of::<RawGiccAcpi60>()..) {
+        Some(trbe) => read_extension::<u16>(trbe)?.map(u16::from_le),
+        None => None,
+    };
+    Ok(MadtGicc {
+        cpu_interface_number: u32::from_le(raw.cpu_interface_number),
+        processor_uid: u32::from_le(raw.processor_uid),
+        flags: MadtGiccFlags::from_bits_retain(u32::from_le(raw.flags)),
+        parking_protocol_version: u32::from_le(raw.parking_protocol_version),
+        performance_interrupt_gsiv: u32::from_le(raw.performance_interrupt_gsiv),
+        parked_address: u64::from_le(raw.parked_address),
+        physical_base_address: u64::from_le(raw.physical_base_address),
+        gicv_base_address: u64::from_le(raw.gicv_base_address),
+        gich_base_address: u64::from_le(raw.gich_base_address),
+        vgic_maintenance_interrupt: u32::from_le(raw.vgic_maintenance_interrupt),
+        gicr_base_address: u64::from_le(raw.gicr_base_address),
+        mpidr: u64::from_le(raw.mpidr),
+        processor_power_efficiency_class: acpi60.map(|raw| raw.processor_power_efficiency_class),
+        spe_overflow_interrupt: acpi60.map(|raw| u16::from_le(raw.spe_overflow_interrupt)),
+        trbe_interrupt,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::AcpiErrorKind;
@@ -558,11 +1064,196 @@ mod tests {
         );
     }
 
+    fn build_madt_from_records(flags: u32, records: &[(u8, Vec<u8>)]) -> Vec<u8> {
+        let mut bytes = vec![0_u8; 44];
+        bytes[0..4].copy_from_slice(b"APIC");
+        bytes[8] = 5;
+        bytes[10..16].copy_from_slice(b"FUSION");
+        bytes[16..24].copy_from_slice(b"MADTRECS");
+        bytes[40..44].copy_from_slice(&flags.to_le_bytes());
+        for (kind, body) in records {
+            bytes.push(*kind);
+            bytes.push(u8::try_from(body.len() + 2).expect("record should fit"));
+            bytes.extend_from_slice(body);
+        }
+        let table_len = u32::try_from(bytes.len()).expect("table should fit");
+        bytes[4..8].copy_from_slice(&table_len.to_le_bytes());
+        let checksum =
+            (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        bytes[9] = checksum;
+        bytes
+    }
+
+    fn put(body: &mut [u8], offset: usize, value: &[u8]) {
+        body[offset..offset + value.len()].copy_from_slice(value);
+    }
+
+    /// `GICv3` layout like a Neoverse server: GICC per core, one GICD, one GICR range, one ITS.
+    fn arm64_records(gicc_len: usize) -> Vec<(u8, Vec<u8>)> {
+        let mut gicc = vec![0_u8; gicc_len];
+        put(&mut gicc, 2, &1_u32.to_le_bytes());
+        put(&mut gicc, 6, &1_u32.to_le_bytes());
+        put(&mut gicc, 10, &1_u32.to_le_bytes());
+        put(&mut gicc, 18, &23_u32.to_le_bytes());
+        put(&mut gicc, 54, &25_u32.to_le_bytes());
+        put(&mut gicc, 66, &0x0001_0100_u64.to_le_bytes());
+        if gicc_len >= 78 {
+            gicc[74] = 1;
+            put(&mut gicc, 76, &21_u16.to_le_bytes());
+        }
+
+        let mut distributor = vec![0_u8; 22];
+        put(&mut distributor, 6, &0x3000_0000_u64.to_le_bytes());
+        distributor[18] = 3;
+
+        let mut redistributor = vec![0_u8; 14];
+        put(&mut redistributor, 2, &0x3010_0000_u64.to_le_bytes());
+        put(&mut redistributor, 10, &0x0100_0000_u32.to_le_bytes());
+
+        let mut its = vec![0_u8; 18];
+        put(&mut its, 6, &0x3004_0000_u64.to_le_bytes());
+
+        vec![
+            (0x0B, gicc),
+            (0x0C, distributor),
+            (0x0E, redistributor),
+            (0x0F, its),
+        ]
+    }
+
+    #[test]
+    fn madt_parses_arm64_gic_records() {
+        let bytes = build_madt_from_records(0, &arm64_records(78));
+        let madt = Madt::parse(&bytes).expect("madt should parse");
+        let records = madt
+            .records()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("records should parse");
+        let MadtRecord::Gicc(gicc) = records[0] else {
+            panic!("first record should be a gicc");
+        };
+        assert_eq!(gicc.processor_uid, 1);
+        assert!(gicc.flags.contains(MadtGiccFlags::ENABLED));
+        assert_eq!(gicc.performance_interrupt_gsiv, 23);
+        assert_eq!(gicc.vgic_maintenance_interrupt, 25);
+        assert_eq!(gicc.mpidr, 0x0001_0100);
+        assert_eq!(gicc.processor_power_efficiency_class, Some(1));
+        assert_eq!(gicc.spe_overflow_interrupt, Some(21));
+        assert_eq!(gicc.trbe_interrupt, None);
+        assert_eq!(
+            records[1],
+            MadtRecord::Gicd(MadtGicd {
+                gic_id: 0,
+                physical_base_address: 0x3000_0000,
+                gic_version: MadtGicVersion::V3,
+            })
+        );
+        assert_eq!(
+            records[2],
+            MadtRecord::Gicr(MadtGicr {
+                discovery_range_base_address: 0x3010_0000,
+                discovery_range_length: 0x0100_0000,
+                non_coherent: false,
+            })
+        );
+        assert!(matches!(
+            records[3],
+            MadtRecord::GicIts(MadtGicIts {
+                physical_base_address: 0x3004_0000,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn madt_rejects_gicc_with_unpublished_length() {
+        let bytes = build_madt_from_records(0, &arm64_records(77));
+        let madt = Madt::parse(&bytes).expect("madt should parse");
+        let error = madt
+            .records()
+            .next()
+            .expect("gicc should exist")
+            .expect_err("77-byte gicc body should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidLayout);
+    }
+
+    #[test]
+    fn madt_parses_riscv_and_wakeup_records() {
+        let mut rintc = vec![0_u8; 34];
+        rintc[0] = 1;
+        put(&mut rintc, 2, &1_u32.to_le_bytes());
+        put(&mut rintc, 6, &3_u64.to_le_bytes());
+        put(&mut rintc, 14, &3_u32.to_le_bytes());
+        put(&mut rintc, 22, &0x2800_3000_u64.to_le_bytes());
+        put(&mut rintc, 30, &0x1000_u32.to_le_bytes());
+
+        let mut imsic = vec![0_u8; 14];
+        imsic[0] = 1;
+        put(&mut imsic, 6, &255_u16.to_le_bytes());
+        imsic[11] = 2;
+
+        let mut aplic = vec![0_u8; 34];
+        aplic[0] = 1;
+        put(&mut aplic, 6, b"QEMU0000");
+        put(&mut aplic, 16, &96_u16.to_le_bytes());
+        put(&mut aplic, 22, &0x0D00_0000_u64.to_le_bytes());
+        put(&mut aplic, 30, &0x8000_u32.to_le_bytes());
+
+        let mut wakeup = vec![0_u8; 22];
+        put(&mut wakeup, 0, &1_u16.to_le_bytes());
+        put(&mut wakeup, 6, &0x7FFF_E000_u64.to_le_bytes());
+        put(&mut wakeup, 14, &0x7FFF_F000_u64.to_le_bytes());
+
+        let bytes = build_madt_from_records(
+            0,
+            &[(0x18, rintc), (0x19, imsic), (0x1A, aplic), (0x10, wakeup)],
+        );
+        let madt = Madt::parse(&bytes).expect("madt should parse");
+        let records = madt
+            .records()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("records should parse");
+        let MadtRecord::Rintc(rintc) = records[0] else {
+            panic!("first record should be a rintc");
+        };
+        assert_eq!(rintc.hart_id, 3);
+        assert_eq!(
+            rintc.imsic,
+            Some(MadtRintcImsic {
+                external_interrupt_controller_id: 0,
+                base_address: 0x2800_3000,
+                size: 0x1000,
+            })
+        );
+        assert!(matches!(
+            records[1],
+            MadtRecord::Imsic(MadtImsic {
+                interrupt_identities: 255,
+                hart_index_bits: 2,
+                ..
+            })
+        ));
+        let MadtRecord::Aplic(aplic) = records[2] else {
+            panic!("third record should be an aplic");
+        };
+        assert_eq!(&aplic.hardware_id, b"QEMU0000");
+        assert_eq!(aplic.source_count, 96);
+        assert_eq!(aplic.address, 0x0D00_0000);
+        assert_eq!(
+            records[3],
+            MadtRecord::MultiprocessorWakeup(MadtMultiprocessorWakeup {
+                mailbox_version: 1,
+                mailbox_address: 0x7FFF_E000,
+                reset_vector: Some(0x7FFF_F000),
+            })
+        );
+    }
+
     #[test]
     fn madt_rejects_supported_record_with_wrong_length() {
         let mut bytes = build_madt();
         bytes.push(0);
-        let table_len = bytes.len() as u32;
+        let table_len = u32::try_from(bytes.len()).expect("table should fit");
         bytes[4..8].copy_from_slice(&table_len.to_le_bytes());
         bytes[53] = 13;
         bytes[9] = 0;
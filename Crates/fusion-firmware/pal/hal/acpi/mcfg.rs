@@ -59,6 +59,24 @@ pub struct McfgAllocation {
     pub end_bus: u8,
 }
 
+#[cfg(feature = "fd-bus-pci")]
+impl McfgAllocation {
+    /// Converts this allocation into one ECAM window for the universal PCI driver.
+    #[must_use]
+    pub const fn ecam_window(
+        self,
+        controller: fd_bus_pci::PciControllerDescriptor,
+    ) -> fd_bus_pci::interface::backend::ecam::PciEcamWindow {
+        fd_bus_pci::interface::backend::ecam::PciEcamWindow::new(
+            controller,
+            self.base_address,
+            self.segment_group,
+            self.start_bus,
+            self.end_bus,
+        )
+    }
+}
+
 /// Borrowed validated MCFG view.
 #[derive(Clone, Copy, Debug)]
 pub struct Mcfg<'a> {
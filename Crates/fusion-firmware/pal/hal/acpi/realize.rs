@@ -8,7 +8,8 @@
 //! It does not parse AML, and it does not replace the public ACPI driver contracts. Its job is
 //! narrower:
 //!
-//! - identify which vendor backend should realize a machine,
+//! - identify which vendor backend should realize a machine, falling back to the generic
+//!   spec-driven backend when no vendor backend claims it,
 //! - activate the matching public ACPI driver families over that backend,
 //! - expose the realized surfaces upward to later firmware/sys layers through stable contract
 //!   traits rather than vendor-specific types.
@@ -38,12 +39,18 @@ use crate::contract::firmware::topology::{
     AcpiTopologySupport,
 };
 use crate::pal::hal::acpi::{
+    AcpiGenericDeviceClass,
+    AcpiGenericHardwareIdQuirk,
+    AcpiGenericQuirks,
     AcpiTableView,
     Dsdt,
+    GenericAcpiHardware,
+    install_generic_platform,
 };
 use fusion_hal::contract::drivers::acpi::{
     AcpiBatteryContract,
     AcpiButtonContract,
+    AcpiButtonKind,
     AcpiEmbeddedControllerContract,
     AcpiFanContract,
     AcpiLidContract,
@@ -107,6 +114,16 @@ use fusion_hal::drivers::acpi::public::thermal::{
     AcpiThermalDriverContext,
 };
 use fusion_hal::drivers::acpi::public::interface::backend::AcpiAmlBackend;
+use fusion_hal::drivers::acpi::public::interface::contract::{
+    AcpiBatteryHardware,
+    AcpiButtonHardware,
+    AcpiEmbeddedControllerHardware,
+    AcpiFanHardware,
+    AcpiLidHardware,
+    AcpiPowerSourceHardware,
+    AcpiProcessorHardware,
+    AcpiThermalHardware,
+};
 use fusion_hal::drivers::acpi::vendor::dell::DellLatitudeE6430AcpiHardware;
 
 /// Stable firmware-side fingerprint used to match one ACPI-backed platform realization.
@@ -123,12 +140,16 @@ pub struct AcpiPlatformFingerprint {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum AcpiPlatformBackendKind {
     DellLatitudeE6430,
+    /// Spec-driven backend bound from standard namespace device identities.
+    Generic,
 }
 
 /// Match confidence for one platform/backend selection.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum AcpiPlatformMatchStrength {
     Exact,
+    /// No vendor backend claimed the machine; only ACPI-defined identities are trusted.
+    Generic,
 }
 
 /// Concrete backend selection made by the firmware ACPI matcher.
@@ -229,18 +250,73 @@ pub fn match_platform_backend(fingerprint: &AcpiPlatformFingerprint) -> Option<A
     None
 }
 
+/// Generic ACPI quirks for the Dell Latitude E6430 proving machine.
+///
+/// The Dell namespace carries its radio switch under the vendor `DELLABCE` ID; everything else
+/// it exposes is reachable through the ACPI-defined identities alone.
+#[must_use]
+pub const fn dell_latitude_e6430_generic_quirks() -> AcpiGenericQuirks {
+    AcpiGenericQuirks {
+        hardware_ids: &[AcpiGenericHardwareIdQuirk {
+            hid: "DELLABCE",
+            class: AcpiGenericDeviceClass::Button(AcpiButtonKind::AirplaneMode),
+        }],
+        ignored_paths: &[],
+    }
+}
+
 /// Firmware-realized public ACPI surfaces for one matched platform.
 #[derive(Debug)]
 pub struct RealizedAcpiPlatform {
     matched: AcpiPlatformMatch,
-    battery: Option<AcpiBattery<DellLatitudeE6430AcpiHardware>>,
-    power_source: Option<AcpiPowerSource<DellLatitudeE6430AcpiHardware>>,
-    thermal: Option<AcpiThermal<DellLatitudeE6430AcpiHardware>>,
-    fan: Option<AcpiFan<DellLatitudeE6430AcpiHardware>>,
-    button: Option<AcpiButton<DellLatitudeE6430AcpiHardware>>,
-    lid: Option<AcpiLid<DellLatitudeE6430AcpiHardware>>,
-    embedded_controller: Option<AcpiEmbeddedController<DellLatitudeE6430AcpiHardware>>,
-    processor: Option<AcpiProcessor<DellLatitudeE6430AcpiHardware>>,
+    surfaces: RealizedAcpiSurfaces,
+}
+
+/// Every public ACPI hardware family one realizable backend implements.
+trait AcpiRealizableHardware:
+    AcpiBatteryHardware
+    + AcpiPowerSourceHardware
+    + AcpiThermalHardware
+    + AcpiFanHardware
+    + AcpiButtonHardware
+    + AcpiLidHardware
+    + AcpiEmbeddedControllerHardware
+    + AcpiProcessorHardware
+    + 'static
+{
+}
+
+impl<H> AcpiRealizableHardware for H where
+    H: AcpiBatteryHardware
+        + AcpiPowerSourceHardware
+        + AcpiThermalHardware
+        + AcpiFanHardware
+        + AcpiButtonHardware
+        + AcpiLidHardware
+        + AcpiEmbeddedControllerHardware
+        + AcpiProcessorHardware
+        + 'static
+{
+}
+
+/// Public ACPI families activated over one backend.
+#[derive(Debug)]
+struct RealizedAcpiFamilies<H: AcpiRealizableHardware> {
+    battery: Option<AcpiBattery<H>>,
+    power_source: Option<AcpiPowerSource<H>>,
+    thermal: Option<AcpiThermal<H>>,
+    fan: Option<AcpiFan<H>>,
+    button: Option<AcpiButton<H>>,
+    lid: Option<AcpiLid<H>>,
+    embedded_controller: Option<AcpiEmbeddedController<H>>,
+    processor: Option<AcpiProcessor<H>>,
+}
+
+/// Backend-specific family set behind one realized platform.
+#[derive(Debug)]
+enum RealizedAcpiSurfaces {
+    DellLatitudeE6430(RealizedAcpiFamilies<DellLatitudeE6430AcpiHardware>),
+    Generic(RealizedAcpiFamilies<GenericAcpiHardware>),
 }
 
 /// Firmware-side AML activation report for one realized ACPI platform.
@@ -296,62 +372,120 @@ impl RealizedAcpiPlatform {
     /// Returns the canonical ACPI battery surface, when one was realized.
     #[must_use]
     pub fn battery(&self) -> Option<&dyn AcpiBatteryContract> {
-        self.battery
-            .as_ref()
-            .map(|surface| surface as &dyn AcpiBatteryContract)
+        match &self.surfaces {
+            RealizedAcpiSurfaces::DellLatitudeE6430(families) => families.battery(),
+            RealizedAcpiSurfaces::Generic(families) => families.battery(),
+        }
     }
 
     /// Returns the canonical ACPI power-source surface, when one was realized.
     #[must_use]
     pub fn power_source(&self) -> Option<&dyn AcpiPowerSourceContract> {
-        self.power_source
-            .as_ref()
-            .map(|surface| surface as &dyn AcpiPowerSourceContract)
+        match &self.surfaces {
+            RealizedAcpiSurfaces::DellLatitudeE6430(families) => families.power_source(),
+            RealizedAcpiSurfaces::Generic(families) => families.power_source(),
+        }
     }
 
     /// Returns the canonical ACPI thermal surface, when one was realized.
     #[must_use]
     pub fn thermal(&self) -> Option<&dyn AcpiThermalContract> {
-        self.thermal
-            .as_ref()
-            .map(|surface| surface as &dyn AcpiThermalContract)
+        match &self.surfaces {
+            RealizedAcpiSurfaces::DellLatitudeE6430(families) => families.thermal(),
+            RealizedAcpiSurfaces::Generic(families) => families.thermal(),
+        }
     }
 
     /// Returns the canonical ACPI fan surface, when one was realized.
     #[must_use]
     pub fn fan(&self) -> Option<&dyn AcpiFanContract> {
-        self.fan
-            .as_ref()
-            .map(|surface| surface as &dyn AcpiFanContract)
+        match &self.surfaces {
+            RealizedAcpiSurfaces::DellLatitudeE6430(families) => families.fan(),
+            RealizedAcpiSurfaces::Generic(families) => families.fan(),
+        }
     }
 
     /// Returns the canonical ACPI button/switch surface, when one was realized.
     #[must_use]
     pub fn button(&self) -> Option<&dyn AcpiButtonContract> {
-        self.button
-            .as_ref()
-            .map(|surface| surface as &dyn AcpiButtonContract)
+        match &self.surfaces {
+            RealizedAcpiSurfaces::DellLatitudeE6430(families) => families.button(),
+            RealizedAcpiSurfaces::Generic(families) => families.button(),
+        }
     }
 
     /// Returns the canonical ACPI lid surface, when one was realized.
     #[must_use]
     pub fn lid(&self) -> Option<&dyn AcpiLidContract> {
-        self.lid
-            .as_ref()
-            .map(|surface| surface as &dyn AcpiLidContract)
+        match &self.surfaces {
+            RealizedAcpiSurfaces::DellLatitudeE6430(families) => families.lid(),
+            RealizedAcpiSurfaces::Generic(families) => families.lid(),
+        }
     }
 
     /// Returns the canonical ACPI embedded-controller surface, when one was realized.
     #[must_use]
     pub fn embedded_controller(&self) -> Option<&dyn AcpiEmbeddedControllerContract> {
-        self.embedded_controller
-            .as_ref()
-            .map(|surface| surface as &dyn AcpiEmbeddedControllerContract)
+        match &self.surfaces {
+            RealizedAcpiSurfaces::DellLatitudeE6430(families) => families.embedded_controller(),
+            RealizedAcpiSurfaces::Generic(families) => families.embedded_controller(),
+        }
     }
 
     /// Returns the canonical ACPI processor surface, when one was realized.
     #[must_use]
     pub fn processor(&self) -> Option<&dyn AcpiProcessorContract> {
+        match &self.surfaces {
+            RealizedAcpiSurfaces::DellLatitudeE6430(families) => families.processor(),
+            RealizedAcpiSurfaces::Generic(families) => families.processor(),
+        }
+    }
+}
+
+impl<H: AcpiRealizableHardware> RealizedAcpiFamilies<H> {
+    fn battery(&self) -> Option<&dyn AcpiBatteryContract> {
+        self.battery
+            .as_ref()
+            .map(|surface| surface as &dyn AcpiBatteryContract)
+    }
+
+    fn power_source(&self) -> Option<&dyn AcpiPowerSourceContract> {
+        self.power_source
+            .as_ref()
+            .map(|surface| surface as &dyn AcpiPowerSourceContract)
+    }
+
+    fn thermal(&self) -> Option<&dyn AcpiThermalContract> {
+        self.thermal
+            .as_ref()
+            .map(|surface| surface as &dyn AcpiThermalContract)
+    }
+
+    fn fan(&self) -> Option<&dyn AcpiFanContract> {
+        self.fan
+            .as_ref()
+            .map(|surface| surface as &dyn AcpiFanContract)
+    }
+
+    fn button(&self) -> Option<&dyn AcpiButtonContract> {
+        self.button
+            .as_ref()
+            .map(|surface| surface as &dyn AcpiButtonContract)
+    }
+
+    fn lid(&self) -> Option<&dyn AcpiLidContract> {
+        self.lid
+            .as_ref()
+            .map(|surface| surface as &dyn AcpiLidContract)
+    }
+
+    fn embedded_controller(&self) -> Option<&dyn AcpiEmbeddedControllerContract> {
+        self.embedded_controller
+            .as_ref()
+            .map(|surface| surface as &dyn AcpiEmbeddedControllerContract)
+    }
+
+    fn processor(&self) -> Option<&dyn AcpiProcessorContract> {
         self.processor
             .as_ref()
             .map(|surface| surface as &dyn AcpiProcessorContract)
@@ -378,6 +512,7 @@ pub fn realize_platform(
 
     match matched.backend {
         AcpiPlatformBackendKind::DellLatitudeE6430 => realize_dell_latitude_e6430(matched),
+        AcpiPlatformBackendKind::Generic => Err(AcpiRealizationError::unsupported()),
     }
 }
 
@@ -411,9 +546,75 @@ pub fn realize_platform_with_aml<'records, 'blocks, 'issues>(
             )?;
             Ok(RealizedAcpiPlatformWithAml { platform, aml })
         }
+        AcpiPlatformBackendKind::Generic => Err(AcpiRealizationError::unsupported()),
     }
 }
 
+/// Realizes the generic spec-driven backend over one loaded namespace and activates its AML.
+///
+/// The namespace is walked for ACPI-defined device identities (plus any `quirks`), and every
+/// match is bound to its canonical public ACPI driver family. The binding lasts for the rest of
+/// this boot, which is why the namespace, host, and runtime state must all be `'static`. The
+/// bound families can be queried from any thread, so the host must also be `Sync`.
+///
+/// A failed realization binds nothing, so it can be retried (for example with quirks that
+/// ignore the device it tripped over).
+///
+/// # Errors
+///
+/// Returns one honest error when:
+/// - a generic namespace is already bound,
+/// - AML lifecycle activation does not complete cleanly,
+/// - a matched device cannot be described (for example an embedded controller without I/O
+///   ports in `_CRS`),
+/// - or public ACPI driver activation fails.
+pub fn realize_generic_platform_with_aml<'issues>(
+    namespace: AmlLoadedNamespace<'static, 'static>,
+    host: &'static (dyn AmlRegionAccessHost + Sync),
+    runtime: &'static AmlRuntimeState<'static>,
+    quirks: &AcpiGenericQuirks,
+    issue_storage: &'issues mut [MaybeUninit<AmlBackendVerificationIssue>],
+) -> Result<RealizedAcpiPlatformWithAml<'issues>, AcpiRealizationError> {
+    let aml = activate_backend_aml::<GenericAcpiHardware>(namespace, host, runtime, issue_storage)?;
+    install_generic_platform(namespace, host, runtime, quirks)?;
+
+    let platform = RealizedAcpiPlatform {
+        matched: AcpiPlatformMatch {
+            backend: AcpiPlatformBackendKind::Generic,
+            strength: AcpiPlatformMatchStrength::Generic,
+        },
+        surfaces: RealizedAcpiSurfaces::Generic(realize_families()?),
+    };
+    Ok(RealizedAcpiPlatformWithAml { platform, aml })
+}
+
+/// Realizes the matched vendor backend, or the generic spec-driven backend when no vendor
+/// backend claims the supplied fingerprint.
+///
+/// # Errors
+///
+/// Returns the same errors as [`realize_platform_with_aml`] for vendor matches and as
+/// [`realize_generic_platform_with_aml`] otherwise.
+pub fn realize_platform_with_generic_fallback<'issues>(
+    fingerprint: &AcpiPlatformFingerprint,
+    namespace: AmlLoadedNamespace<'static, 'static>,
+    host: &'static (dyn AmlRegionAccessHost + Sync),
+    runtime: &'static AmlRuntimeState<'static>,
+    issue_storage: &'issues mut [MaybeUninit<AmlBackendVerificationIssue>],
+) -> Result<RealizedAcpiPlatformWithAml<'issues>, AcpiRealizationError> {
+    if match_platform_backend(fingerprint).is_some() {
+        return realize_platform_with_aml(fingerprint, namespace, host, runtime, issue_storage);
+    }
+
+    realize_generic_platform_with_aml(
+        namespace,
+        host,
+        runtime,
+        &AcpiGenericQuirks::none(),
+        issue_storage,
+    )
+}
+
 /// Loads one AML namespace from validated ACPI definition tables with caller-provided storage.
 ///
 /// `definition_storage` is only used for the secondary definition blocks (`SSDT`/`PSDT`). The
@@ -516,28 +717,25 @@ pub fn realize_dell_latitude_e6430_platform_from_definition_tables_with_aml<
 fn realize_dell_latitude_e6430(
     matched: AcpiPlatformMatch,
 ) -> Result<RealizedAcpiPlatform, AcpiRealizationError> {
-    let mut registry = DriverRegistry::<8>::new();
-
-    let battery = activate_battery::<DellLatitudeE6430AcpiHardware>(&mut registry)?;
-    let power_source = activate_power_source::<DellLatitudeE6430AcpiHardware>(&mut registry)?;
-    let thermal = activate_thermal::<DellLatitudeE6430AcpiHardware>(&mut registry)?;
-    let fan = activate_fan::<DellLatitudeE6430AcpiHardware>(&mut registry)?;
-    let button = activate_button::<DellLatitudeE6430AcpiHardware>(&mut registry)?;
-    let lid = activate_lid::<DellLatitudeE6430AcpiHardware>(&mut registry)?;
-    let embedded_controller =
-        activate_embedded_controller::<DellLatitudeE6430AcpiHardware>(&mut registry)?;
-    let processor = activate_processor::<DellLatitudeE6430AcpiHardware>(&mut registry)?;
-
     Ok(RealizedAcpiPlatform {
         matched,
-        battery,
-        power_source,
-        thermal,
-        fan,
-        button,
-        lid,
-        embedded_controller,
-        processor,
+        surfaces: RealizedAcpiSurfaces::DellLatitudeE6430(realize_families()?),
+    })
+}
+
+fn realize_families<H: AcpiRealizableHardware>()
+-> Result<RealizedAcpiFamilies<H>, AcpiRealizationError> {
+    let mut registry = DriverRegistry::<8>::new();
+
+    Ok(RealizedAcpiFamilies {
+        battery: activate_battery::<H>(&mut registry)?,
+        power_source: activate_power_source::<H>(&mut registry)?,
+        thermal: activate_thermal::<H>(&mut registry)?,
+        fan: activate_fan::<H>(&mut registry)?,
+        button: activate_button::<H>(&mut registry)?,
+        lid: activate_lid::<H>(&mut registry)?,
+        embedded_controller: activate_embedded_controller::<H>(&mut registry)?,
+        processor: activate_processor::<H>(&mut registry)?,
     })
 }
 
@@ -0,0 +1,484 @@
+//! RSDP definitions and discovery helpers.
+//!
+//! The Root System Description Pointer (`RSDP`) is the front door to every
+//! other ACPI table. ACPI 6.6 Section 5.2.5 defines two generations:
+//!
+//! - revision 0 (ACPI 1.0): a 20-byte structure carrying a 32-bit `RSDT`
+//!   address and one checksum over those 20 bytes,
+//! - revision 2+ (ACPI 2.0 and later): the same 20 bytes followed by a length,
+//!   a 64-bit `XSDT` address and an extended checksum over the whole structure.
+//!
+//! Both checksums must validate. A revision-2 `RSDP` that only passes the
+//! legacy checksum is a corrupt `RSDP`, not a revision-0 one.
+//!
+//! Finding the structure is platform business, so Section 5.2.5.1 and 5.2.5.2
+//! get one helper each:
+//!
+//! - legacy BIOS systems: scan the first KiB of the Extended BIOS Data Area,
+//!   then the `0xE0000..=0xFFFFF` BIOS ROM window, on 16-byte boundaries,
+//! - UEFI systems: look the `RSDP` address up in the EFI configuration table by
+//!   GUID, preferring the ACPI 2.0 entry over the ACPI 1.0 one.
+//!
+//! Neither helper maps memory by itself. BIOS scanning goes through one
+//! caller-supplied [`AcpiPhysicalMemoryReader`] so the same code works from a
+//! loader with identity mappings and from a kernel with a temporary window.
+
+use core::mem::size_of;
+
+use super::{
+    AcpiError,
+    AcpiErrorKind,
+    checksum_is_valid,
+    read_unaligned_copy,
+};
+
+/// Eight-byte `RSDP` signature, trailing space included.
+pub const ACPI_RSDP_SIGNATURE: [u8; 8] = *b"RSD PTR ";
+
+/// `EFI_ACPI_20_TABLE_GUID` (`8868E871-E4F1-11D3-BC22-0080C73C8881`) in EFI wire order.
+pub const ACPI_20_TABLE_GUID: [u8; 16] = [
+    0x71, 0xE8, 0x68, 0x88, 0xF1, 0xE4, 0xD3, 0x11, 0xBC, 0x22, 0x00, 0x80, 0xC7, 0x3C, 0x88, 0x81,
+];
+
+/// `ACPI_TABLE_GUID` (`EB9D2D30-2D88-11D3-9A16-0090273FC14D`) in EFI wire order.
+pub const ACPI_10_TABLE_GUID: [u8; 16] = [
+    0x30, 0x2D, 0x9D, 0xEB, 0x88, 0x2D, 0xD3, 0x11, 0x9A, 0x16, 0x00, 0x90, 0x27, 0x3F, 0xC1, 0x4D,
+];
+
+/// Real-mode pointer to the EBDA segment in the BIOS Data Area.
+const EBDA_SEGMENT_POINTER: u64 = 0x040E;
+/// Bytes of the EBDA the specification asks OSPM to search.
+const EBDA_SEARCH_LEN: usize = 1024;
+/// Start of the BIOS read-only memory window searched for the `RSDP`.
+const BIOS_AREA_START: u64 = 0x000E_0000;
+/// Length of the BIOS read-only memory window searched for the `RSDP`.
+const BIOS_AREA_LEN: usize = 0x0002_0000;
+/// Alignment every `RSDP` candidate must sit on.
+const RSDP_ALIGNMENT: usize = 16;
+/// Largest declared `RSDP` length [`Rsdp::read`] will fetch.
+///
+/// The length field comes from memory nobody has validated yet, so it is bounded before it sizes
+/// a read; every revision defined so far is 36 bytes.
+const RSDP_MAX_LEN: usize = 256;
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawRsdpV1 {
+    signature: [u8; 8],
+    checksum: u8,
+    oem_id: [u8; 6],
+    revision: u8,
+    rsdt_address: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawRsdpV2 {
+    v1: RawRsdpV1,
+    length: u32,
+    xsdt_address: u64,
+    extended_checksum: u8,
+    reserved: [u8; 3],
+}
+
+/// Firmware-owned physical memory read surface used by legacy `RSDP` scanning.
+pub trait AcpiPhysicalMemoryReader {
+    /// Copies `buffer.len()` bytes starting at one physical address.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the range cannot be mapped or read.
+    fn read_physical(&self, physical_address: u64, buffer: &mut [u8]) -> Result<(), AcpiError>;
+}
+
+/// One EFI configuration-table entry as the caller copied it out of the system table.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct AcpiUefiConfigurationTable {
+    pub vendor_guid: [u8; 16],
+    pub vendor_table: u64,
+}
+
+/// Root description table the `RSDP` directs OSPM to.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AcpiRootTableAddress {
+    Xsdt(u64),
+    Rsdt(u32),
+}
+
+/// Validated copy of one `RSDP`.
+///
+/// The structure is small and usually read through a temporary mapping, so
+/// this view owns its decoded fields instead of borrowing.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rsdp {
+    revision: u8,
+    oem_id: [u8; 6],
+    rsdt_address: u32,
+    length: u32,
+    xsdt_address: u64,
+}
+
+impl Rsdp {
+    /// Size of a revision-0 `RSDP`.
+    pub const V1_SIZE: usize = size_of::<RawRsdpV1>();
+    /// Size of a revision-2 `RSDP`.
+    pub const V2_SIZE: usize = size_of::<RawRsdpV2>();
+
+    /// Parses and validates one `RSDP`.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the signature is wrong, either checksum fails, or a
+    /// revision-2 structure declares a length shorter than its own fields.
+    pub fn parse(bytes: &[u8]) -> Result<Self, AcpiError> {
+        let v1: RawRsdpV1 = read_unaligned_copy(bytes)?;
+        if v1.signature != ACPI_RSDP_SIGNATURE {
+            return Err(AcpiError::invalid_signature());
+        }
+        if !checksum_is_valid(&bytes[..Self::V1_SIZE]) {
+            return Err(AcpiError::invalid_checksum());
+        }
+        if v1.revision < 2 {
+            return Ok(Self {
+                revision: v1.revision,
+                oem_id: v1.oem_id,
+                rsdt_address: u32::from_le(v1.rsdt_address),
+                length: u32::try_from(Self::V1_SIZE).map_err(|_| AcpiError::invalid_layout())?,
+                xsdt_address: 0,
+            });
+        }
+
+        let v2: RawRsdpV2 = read_unaligned_copy(bytes)?;
+        let length = u32::from_le(v2.length);
+        let declared_len = usize::try_from(length).map_err(|_| AcpiError::invalid_layout())?;
+        if declared_len < Self::V2_SIZE {
+            return Err(AcpiError::invalid_layout());
+        }
+        let covered = bytes.get(..declared_len).ok_or_else(AcpiError::truncated)?;
+        if !checksum_is_valid(covered) {
+            return Err(AcpiError::invalid_checksum());
+        }
+        Ok(Self {
+            revision: v1.revision,
+            oem_id: v1.oem_id,
+            rsdt_address: u32::from_le(v1.rsdt_address),
+            length,
+            xsdt_address: u64::from_le(v2.xsdt_address),
+        })
+    }
+
+    /// Reads and validates one `RSDP` at a known physical address, such as a UEFI lookup result.
+    ///
+    /// A revision-2 structure is fetched by its declared length, so a later revision that grows
+    /// the structure is still checksummed over all of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the read fails, the declared length is shorter than a
+    /// revision-2 `RSDP` or longer than this reader will fetch, or the structure does not validate.
+    pub fn read<M>(memory: &M, physical_address: u64) -> Result<Self, AcpiError>
+    where
+        M: AcpiPhysicalMemoryReader + ?Sized,
+    {
+        let mut bytes = [0_u8; RSDP_MAX_LEN];
+        memory.read_physical(physical_address, &mut bytes[..Self::V1_SIZE])?;
+        let v1: RawRsdpV1 = read_unaligned_copy(&bytes)?;
+        if v1.signature != ACPI_RSDP_SIGNATURE {
+            return Err(AcpiError::invalid_signature());
+        }
+        if v1.revision < 2 {
+            return Self::parse(&bytes[..Self::V1_SIZE]);
+        }
+
+        let length_end = Self::V1_SIZE + size_of::<u32>();
+        memory.read_physical(
+            physical_address + Self::V1_SIZE as u64,
+            &mut bytes[Self::V1_SIZE..length_end],
+        )?;
+        let length: u32 = read_unaligned_copy(&bytes[Self::V1_SIZE..])?;
+        let declared_len =
+            usize::try_from(u32::from_le(length)).map_err(|_| AcpiError::invalid_layout())?;
+        if !(Self::V2_SIZE..=RSDP_MAX_LEN).contains(&declared_len) {
+            return Err(AcpiError::invalid_layout());
+        }
+        memory.read_physical(
+            physical_address + length_end as u64,
+            &mut bytes[length_end..declared_len],
+        )?;
+        Self::parse(&bytes[..declared_len])
+    }
+
+    /// Returns the `RSDP` revision.
+    #[must_use]
+    pub const fn revision(self) -> u8 {
+        self.revision
+    }
+
+    /// Returns the OEM identifier.
+    #[must_use]
+    pub const fn oem_id(self) -> [u8; 6] {
+        self.oem_id
+    }
+
+    /// Returns the 32-bit `RSDT` address.
+    #[must_use]
+    pub const fn rsdt_address(self) -> u32 {
+        self.rsdt_address
+    }
+
+    /// Returns the 64-bit `XSDT` address, when this is a revision-2 `RSDP` that carries one.
+    #[must_use]
+    pub const fn xsdt_address(self) -> Option<u64> {
+        if self.revision < 2 || self.xsdt_address == 0 {
+            return None;
+        }
+        Some(self.xsdt_address)
+    }
+
+    /// Returns the structure length covered by the checksums.
+    #[must_use]
+    pub const fn length(self) -> u32 {
+        self.length
+    }
+
+    /// Returns the root table OSPM must use: the `XSDT` when present, otherwise the `RSDT`.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the `RSDP` names neither root.
+    pub const fn root_table_address(self) -> Result<AcpiRootTableAddress, AcpiError> {
+        if let Some(xsdt) = self.xsdt_address() {
+            return Ok(AcpiRootTableAddress::Xsdt(xsdt));
+        }
+        if self.rsdt_address == 0 {
+            return Err(AcpiError::invalid_layout());
+        }
+        Ok(AcpiRootTableAddress::Rsdt(self.rsdt_address))
+    }
+}
+
+/// Located and validated `RSDP`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct AcpiRsdpLocation {
+    pub physical_address: u64,
+    pub rsdp: Rsdp,
+}
+
+/// Returns the `RSDP` address published in the EFI configuration table.
+///
+/// The ACPI 2.0 entry wins over the ACPI 1.0 entry when firmware publishes both.
+#[must_use]
+pub fn find_rsdp_in_uefi_configuration_table(
+    entries: &[AcpiUefiConfigurationTable],
+) -> Option<u64> {
+    let find = |guid: [u8; 16]| {
+        entries
+            .iter()
+            .find(|entry| entry.vendor_guid == guid && entry.vendor_table != 0)
+            .map(|entry| entry.vendor_table)
+    };
+    find(ACPI_20_TABLE_GUID).or_else(|| find(ACPI_10_TABLE_GUID))
+}
+
+/// Scans the legacy BIOS areas for one valid `RSDP`.
+///
+/// Candidates with the right signature but a bad checksum are skipped, matching how firmware
+/// leaves stale copies lying around in shadowed ROM.
+///
+/// # Errors
+///
+/// Returns one honest error when the reader cannot supply the scanned ranges.
+pub fn find_rsdp_in_bios_area<M>(memory: &M) -> Result<Option<AcpiRsdpLocation>, AcpiError>
+where
+    M: AcpiPhysicalMemoryReader + ?Sized,
+{
+    let mut segment = [0_u8; size_of::<u16>()];
+    memory.read_physical(EBDA_SEGMENT_POINTER, &mut segment)?;
+    let ebda = u64::from(u16::from_le_bytes(segment)) << 4;
+    if ebda != 0
+        && let Some(found) = scan_for_rsdp(memory, ebda, EBDA_SEARCH_LEN)?
+    {
+        return Ok(Some(found));
+    }
+    scan_for_rsdp(memory, BIOS_AREA_START, BIOS_AREA_LEN)
+}
+
+fn scan_for_rsdp<M>(
+    memory: &M,
+    start: u64,
+    length: usize,
+) -> Result<Option<AcpiRsdpLocation>, AcpiError>
+where
+    M: AcpiPhysicalMemoryReader + ?Sized,
+{
+    let mut chunk = [0_u8; EBDA_SEARCH_LEN];
+    let mut chunk_offset = 0;
+    while chunk_offset < length {
+        let chunk_len = chunk.len().min(length - chunk_offset);
+        let chunk_start = start + chunk_offset as u64;
+        memory.read_physical(chunk_start, &mut chunk[..chunk_len])?;
+        for candidate in (0..chunk_len).step_by(RSDP_ALIGNMENT) {
+            if !chunk[candidate..chunk_len].starts_with(&ACPI_RSDP_SIGNATURE) {
+                continue;
+            }
+            let physical_address = chunk_start + candidate as u64;
+            match Rsdp::read(memory, physical_address) {
+                Ok(rsdp) => {
+                    return Ok(Some(AcpiRsdpLocation {
+                        physical_address,
+                        rsdp,
+                    }));
+                }
+                Err(error) if error.kind() == AcpiErrorKind::InvalidChecksum => {}
+                Err(error) => return Err(error),
+            }
+        }
+        chunk_offset += chunk_len;
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec;
+    use std::vec::Vec;
+
+    fn build_rsdp(revision: u8, rsdt: u32, xsdt: u64) -> Vec<u8> {
+        let mut bytes = vec![0_u8; if revision >= 2 { 36 } else { 20 }];
+        bytes[0..8].copy_from_slice(&ACPI_RSDP_SIGNATURE);
+        bytes[9..15].copy_from_slice(b"BOCHS ");
+        bytes[15] = revision;
+        bytes[16..20].copy_from_slice(&rsdt.to_le_bytes());
+        bytes[8] = (!bytes[..20]
+            .iter()
+            .fold(0_u8, |sum, byte| sum.wrapping_add(*byte)))
+        .wrapping_add(1);
+        if revision >= 2 {
+            bytes[20..24].copy_from_slice(&36_u32.to_le_bytes());
+            bytes[24..32].copy_from_slice(&xsdt.to_le_bytes());
+            bytes[32] =
+                (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        }
+        bytes
+    }
+
+    /// Low 1 MiB of physical memory, as a BIOS would leave it.
+    struct LowMemory {
+        bytes: Vec<u8>,
+    }
+
+    impl LowMemory {
+        fn new() -> Self {
+            Self {
+                bytes: vec![0_u8; 0x0010_0000],
+            }
+        }
+
+        fn place(&mut self, address: usize, bytes: &[u8]) {
+            self.bytes[address..address + bytes.len()].copy_from_slice(bytes);
+        }
+    }
+
+    impl AcpiPhysicalMemoryReader for LowMemory {
+        fn read_physical(&self, physical_address: u64, buffer: &mut [u8]) -> Result<(), AcpiError> {
+            let start = usize::try_from(physical_address).map_err(|_| AcpiError::truncated())?;
+            let source = self
+                .bytes
+                .get(start..start + buffer.len())
+                .ok_or_else(AcpiError::truncated)?;
+            buffer.copy_from_slice(source);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn rsdp_prefers_xsdt_and_falls_back_to_rsdt() {
+        let v2 = Rsdp::parse(&build_rsdp(2, 0x7FE1_4000, 0x7FE1_5000)).expect("v2 should parse");
+        assert_eq!(v2.oem_id(), *b"BOCHS ");
+        assert_eq!(
+            v2.root_table_address(),
+            Ok(AcpiRootTableAddress::Xsdt(0x7FE1_5000))
+        );
+        let v1 = Rsdp::parse(&build_rsdp(0, 0x7FE1_4000, 0)).expect("v1 should parse");
+        assert_eq!(v1.xsdt_address(), None);
+        assert_eq!(
+            v1.root_table_address(),
+            Ok(AcpiRootTableAddress::Rsdt(0x7FE1_4000))
+        );
+    }
+
+    #[test]
+    fn rsdp_checks_extended_checksum() {
+        let mut bytes = build_rsdp(2, 0x7FE1_4000, 0x7FE1_5000);
+        bytes[33] = 1;
+        let error = Rsdp::parse(&bytes).expect_err("bad extended checksum should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidChecksum);
+    }
+
+    #[test]
+    fn rsdp_read_fetches_declared_length() {
+        let mut bytes = build_rsdp(2, 0x7FE1_4000, 0x7FE1_5000);
+        bytes.extend([0xAA; 4]);
+        bytes[20..24].copy_from_slice(&40_u32.to_le_bytes());
+        bytes[32] = 0;
+        bytes[32] = (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        let mut memory = LowMemory::new();
+        memory.place(0xF_6A10, &bytes);
+        let rsdp = Rsdp::read(&memory, 0xF_6A10).expect("longer rsdp should read");
+        assert_eq!(rsdp.length(), 40);
+
+        bytes[38] ^= 0xFF;
+        memory.place(0xF_6A10, &bytes);
+        let error = Rsdp::read(&memory, 0xF_6A10).expect_err("tail should be checksummed");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidChecksum);
+
+        bytes[20..24].copy_from_slice(&0x1000_u32.to_le_bytes());
+        memory.place(0xF_6A10, &bytes);
+        let error = Rsdp::read(&memory, 0xF_6A10).expect_err("oversized length should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidLayout);
+    }
+
+    #[test]
+    fn bios_scan_skips_stale_copy_and_finds_rsdp_in_rom_window() {
+        let mut memory = LowMemory::new();
+        let mut stale = build_rsdp(2, 0x1000, 0x2000);
+        stale[8] ^= 0xFF;
+        memory.place(0x9FC10, &stale);
+        memory.place(0x40E, &0x9FC0_u16.to_le_bytes());
+        memory.place(0xF_6A10, &build_rsdp(2, 0x7FE1_4000, 0x7FE1_5000));
+
+        let found = find_rsdp_in_bios_area(&memory)
+            .expect("scan should succeed")
+            .expect("rsdp should be found");
+        assert_eq!(found.physical_address, 0xF_6A10);
+        assert_eq!(found.rsdp.xsdt_address(), Some(0x7FE1_5000));
+    }
+
+    #[test]
+    fn uefi_lookup_prefers_acpi_20_entry() {
+        let entries = [
+            AcpiUefiConfigurationTable {
+                vendor_guid: ACPI_10_TABLE_GUID,
+                vendor_table: 0x7FBF_A000,
+            },
+            AcpiUefiConfigurationTable {
+                vendor_guid: [0xAA; 16],
+                vendor_table: 0x1234,
+            },
+            AcpiUefiConfigurationTable {
+                vendor_guid: ACPI_20_TABLE_GUID,
+                vendor_table: 0x7FBF_A014,
+            },
+        ];
+        assert_eq!(
+            find_rsdp_in_uefi_configuration_table(&entries),
+            Some(0x7FBF_A014)
+        );
+        assert_eq!(
+            find_rsdp_in_uefi_configuration_table(&entries[..2]),
+            Some(0x7FBF_A000)
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! RSDT definitions and helpers.
+//!
+//! The Root System Description Table (`RSDT`) is the original 32-bit root of
+//! the ACPI table graph. ACPI 6.6 Section 5.2.7 defines it as the common
+//! `DESCRIPTION_HEADER` followed by an array of 32-bit physical addresses.
+//!
+//! It only matters when the `RSDP` is revision 0 or carries no `XSDT`
+//! address; when both roots exist, Section 5.2.8 requires OSPM to use the
+//! `XSDT`. [`AcpiRootTable`] is the small seam that lets discovery walk
+//! whichever root the `RSDP` actually selected without caring which width its
+//! pointers are.
+
+use core::mem::size_of;
+
+use super::{
+    AcpiError,
+    AcpiSignature,
+    AcpiTableView,
+    Xsdt,
+    XsdtEntryIter,
+    read_unaligned_copy,
+};
+
+/// Borrowed validated RSDT view.
+#[derive(Clone, Copy, Debug)]
+pub struct Rsdt<'a> {
+    table: AcpiTableView<'a>,
+}
+
+impl<'a> Rsdt<'a> {
+    /// Parses one validated RSDT.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the table is malformed, truncated, or not one RSDT.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, AcpiError> {
+        let table = AcpiTableView::parse_signature(bytes, AcpiSignature::RSDT)?;
+        if !table.payload().len().is_multiple_of(size_of::<u32>()) {
+            return Err(AcpiError::invalid_layout());
+        }
+        Ok(Self { table })
+    }
+
+    /// Returns the underlying validated ACPI table view.
+    #[must_use]
+    pub const fn table(self) -> AcpiTableView<'a> {
+        self.table
+    }
+
+    /// Returns the number of physical table pointers in the RSDT.
+    #[must_use]
+    pub fn entry_count(&self) -> usize {
+        self.table.payload().len() / size_of::<u32>()
+    }
+
+    /// Returns an iterator over the RSDT's physical table pointers.
+    #[must_use]
+    pub fn entries(&self) -> RsdtEntryIter<'a> {
+        RsdtEntryIter {
+            payload: self.table.payload(),
+            offset: 0,
+        }
+    }
+}
+
+/// Iterator over physical table addresses stored in one RSDT.
+#[derive(Clone, Debug)]
+pub struct RsdtEntryIter<'a> {
+    payload: &'a [u8],
+    offset: usize,
+}
+
+impl Iterator for RsdtEntryIter<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self
+            .payload
+            .get(self.offset..self.offset + size_of::<u32>())?;
+        self.offset += size_of::<u32>();
+        Some(u32::from_le(read_unaligned_copy::<u32>(bytes).ok()?))
+    }
+}
+
+/// Whichever root description table one `RSDP` selected.
+#[derive(Clone, Copy, Debug)]
+pub enum AcpiRootTable<'a> {
+    Xsdt(Xsdt<'a>),
+    Rsdt(Rsdt<'a>),
+}
+
+impl<'a> AcpiRootTable<'a> {
+    /// Returns the underlying validated ACPI table view.
+    #[must_use]
+    pub const fn table(self) -> AcpiTableView<'a> {
+        match self {
+            Self::Xsdt(xsdt) => xsdt.table(),
+            Self::Rsdt(rsdt) => rsdt.table(),
+        }
+    }
+
+    /// Returns an iterator over the root's physical table pointers, widened to 64 bits.
+    #[must_use]
+    pub fn entries(&self) -> AcpiRootTableEntryIter<'a> {
+        match self {
+            Self::Xsdt(xsdt) => AcpiRootTableEntryIter::Xsdt(xsdt.entries()),
+            Self::Rsdt(rsdt) => AcpiRootTableEntryIter::Rsdt(rsdt.entries()),
+        }
+    }
+}
+
+/// Iterator over physical table addresses stored in either root table.
+#[derive(Clone, Debug)]
+pub enum AcpiRootTableEntryIter<'a> {
+    Xsdt(XsdtEntryIter<'a>),
+    Rsdt(RsdtEntryIter<'a>),
+}
+
+impl Iterator for AcpiRootTableEntryIter<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Xsdt(entries) => entries.next(),
+            Self::Rsdt(entries) => entries.next().map(u64::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::AcpiErrorKind;
+    use super::*;
+
+    fn build_rsdt(entries: &[u32], extra: usize) -> [u8; 46] {
+        let mut bytes = [0_u8; 46];
+        let length = 36 + entries.len() * 4 + extra;
+        bytes[0..4].copy_from_slice(b"RSDT");
+        bytes[4..8].copy_from_slice(
+            &u32::try_from(length)
+                .expect("table should fit")
+                .to_le_bytes(),
+        );
+        bytes[8] = 1;
+        bytes[10..16].copy_from_slice(b"FUSION");
+        bytes[16..24].copy_from_slice(b"RSDTTEST");
+        for (index, entry) in entries.iter().enumerate() {
+            let start = 36 + index * 4;
+            bytes[start..start + 4].copy_from_slice(&entry.to_le_bytes());
+        }
+        let checksum = (!bytes[..length]
+            .iter()
+            .fold(0_u8, |sum, byte| sum.wrapping_add(*byte)))
+        .wrapping_add(1);
+        bytes[9] = checksum;
+        bytes
+    }
+
+    #[test]
+    fn rsdt_entries_widen_through_root_table() {
+        let bytes = build_rsdt(&[0x000F_1000, 0x000F_2000], 0);
+        let rsdt = Rsdt::parse(&bytes).expect("rsdt should parse");
+        assert_eq!(rsdt.entry_count(), 2);
+        let root = AcpiRootTable::Rsdt(rsdt);
+        assert!(root.entries().eq([0x000F_1000_u64, 0x000F_2000]));
+        assert_eq!(root.table().header().signature(), AcpiSignature::RSDT);
+    }
+
+    #[test]
+    fn rsdt_rejects_partial_entry() {
+        let bytes = build_rsdt(&[0x000F_1000], 2);
+        let error = Rsdt::parse(&bytes).expect_err("partial pointer should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidLayout);
+    }
+}
@@ -0,0 +1,151 @@
+//! SLIT definitions and helpers.
+//!
+//! The System Locality Information Table (`SLIT`) is the distance half of
+//! ACPI's NUMA description. ACPI 6.6 Section 5.2.17 defines it as a locality
+//! count followed by one `count * count` byte matrix of relative distances.
+//!
+//! Row and column indices are the proximity domains `SRAT` hands out. A
+//! distance of `10` means "local", `0xFF` means "unreachable", and values
+//! `0..=9` are reserved. Fusion validates the matrix shape up front and reports
+//! the diagonal-is-local rule as one layout error instead of letting a scheduler
+//! trip over it later.
+
+use core::mem::size_of;
+
+use super::{
+    AcpiError,
+    AcpiSignature,
+    AcpiTableView,
+    read_unaligned_copy,
+};
+
+/// Relative distance reported for one locality to itself.
+pub const SLIT_LOCAL_DISTANCE: u8 = 10;
+/// Relative distance reported for an unreachable locality.
+pub const SLIT_UNREACHABLE_DISTANCE: u8 = 0xFF;
+
+/// Borrowed validated SLIT view.
+#[derive(Clone, Copy, Debug)]
+pub struct Slit<'a> {
+    table: AcpiTableView<'a>,
+    locality_count: usize,
+    matrix: &'a [u8],
+}
+
+impl<'a> Slit<'a> {
+    /// Parses one validated SLIT.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the table is malformed, truncated, not one SLIT, or its
+    /// matrix does not report every locality as local to itself.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, AcpiError> {
+        let table = AcpiTableView::parse_signature(bytes, AcpiSignature::SLIT)?;
+        let payload = table.payload();
+        let raw_count: u64 = u64::from_le(read_unaligned_copy(payload)?);
+        let locality_count = usize::try_from(raw_count).map_err(|_| AcpiError::invalid_layout())?;
+        let matrix_len = locality_count
+            .checked_mul(locality_count)
+            .ok_or_else(AcpiError::invalid_layout)?;
+        let matrix = payload[size_of::<u64>()..]
+            .get(..matrix_len)
+            .ok_or_else(AcpiError::truncated)?;
+        if (0..locality_count)
+            .any(|index| matrix[index * locality_count + index] != SLIT_LOCAL_DISTANCE)
+        {
+            return Err(AcpiError::invalid_layout());
+        }
+        Ok(Self {
+            table,
+            locality_count,
+            matrix,
+        })
+    }
+
+    /// Returns the underlying validated ACPI table view.
+    #[must_use]
+    pub const fn table(self) -> AcpiTableView<'a> {
+        self.table
+    }
+
+    /// Returns the number of system localities.
+    #[must_use]
+    pub const fn locality_count(self) -> usize {
+        self.locality_count
+    }
+
+    /// Returns the relative distance from locality `from` to locality `to`.
+    #[must_use]
+    pub fn distance(self, from: usize, to: usize) -> Option<u8> {
+        if from >= self.locality_count || to >= self.locality_count {
+            return None;
+        }
+        Some(self.matrix[from * self.locality_count + to])
+    }
+
+    /// Returns the distance row for locality `from`.
+    #[must_use]
+    pub fn row(self, from: usize) -> Option<&'a [u8]> {
+        if from >= self.locality_count {
+            return None;
+        }
+        let start = from * self.locality_count;
+        Some(&self.matrix[start..start + self.locality_count])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::AcpiErrorKind;
+    use super::*;
+    use std::vec::Vec;
+
+    fn build_slit(count: u64, matrix: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0_u8; 44];
+        bytes[0..4].copy_from_slice(b"SLIT");
+        bytes[8] = 1;
+        bytes[10..16].copy_from_slice(b"FUSION");
+        bytes[16..24].copy_from_slice(b"SLITTEST");
+        bytes[36..44].copy_from_slice(&count.to_le_bytes());
+        bytes.extend_from_slice(matrix);
+        let length = u32::try_from(bytes.len()).expect("table should fit");
+        bytes[4..8].copy_from_slice(&length.to_le_bytes());
+        let checksum =
+            (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        bytes[9] = checksum;
+        bytes
+    }
+
+    #[test]
+    fn slit_reports_two_socket_distances() {
+        let bytes = build_slit(2, &[10, 21, 21, 10]);
+        let slit = Slit::parse(&bytes).expect("slit should parse");
+        assert_eq!(slit.locality_count(), 2);
+        assert_eq!(slit.distance(0, 1), Some(21));
+        assert_eq!(slit.distance(1, 1), Some(SLIT_LOCAL_DISTANCE));
+        assert_eq!(slit.distance(2, 0), None);
+        assert_eq!(slit.row(1), Some(&[21_u8, 10][..]));
+    }
+
+    #[test]
+    fn slit_rejects_matrix_overrun_and_overflowing_counts() {
+        let bytes = build_slit(3, &[10, 21, 21, 10]);
+        let error = Slit::parse(&bytes).expect_err("short matrix should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::Truncated);
+
+        let bytes = build_slit(u64::MAX, &[10]);
+        let error = Slit::parse(&bytes).expect_err("overflowing count should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidLayout);
+    }
+
+    #[test]
+    fn slit_parses_acpica_template() {
+        let bytes = include_bytes!("../../../tests/fixtures/acpi/slit.dat");
+        let slit = Slit::parse(bytes).expect("template slit should parse");
+        assert_eq!(slit.locality_count(), 20);
+        assert_eq!(slit.distance(0, 0), Some(10));
+        assert_eq!(slit.distance(19, 19), Some(10));
+        assert_eq!(slit.row(7).map(<[u8]>::len), Some(20));
+        assert_eq!(slit.distance(20, 0), None);
+    }
+}
@@ -0,0 +1,399 @@
+//! SPCR definitions and helpers.
+//!
+//! The Serial Port Console Redirection table (`SPCR`) is Microsoft-defined and
+//! only signature-reserved by ACPI 6.6. It names the one UART firmware was
+//! using as its console, which makes it the earliest place an OS can find a
+//! place to print before any bus enumeration:
+//!
+//! - the UART flavour and its register block as a Generic Address Structure,
+//! - the interrupt wiring (legacy IRQ and/or Global System Interrupt),
+//! - line settings: baud, parity, stop bits, flow control, terminal type,
+//! - for PCI UARTs, the device's identity and location.
+//!
+//! Revision 3 adds the UART input clock; revision 4 adds a precise baud rate
+//! and an ACPI namespace path for the port. Older revisions simply report those
+//! as absent instead of reading whatever reserved bytes happen to be there.
+
+use core::mem::size_of;
+
+use bitflags::bitflags;
+
+use super::{
+    AcpiError,
+    AcpiGenericAddress,
+    AcpiSignature,
+    AcpiTableView,
+    read_unaligned_copy,
+};
+
+bitflags! {
+    /// Interrupt controllers the console UART is wired to.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub struct SpcrInterruptType: u8 {
+        /// PC-AT compatible dual-8259 IRQ.
+        const PIC_8259 = 1 << 0;
+        /// I/O APIC Global System Interrupt.
+        const IO_APIC = 1 << 1;
+        /// I/O SAPIC Global System Interrupt.
+        const IO_SAPIC = 1 << 2;
+        /// ARM GIC Global System Interrupt.
+        const GIC = 1 << 3;
+        /// RISC-V PLIC/APLIC Global System Interrupt.
+        const RISCV_PLIC = 1 << 4;
+    }
+}
+
+/// Serial interface subtype shared by `SPCR` and `DBG2` serial ports.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AcpiSerialPortType {
+    /// Full 16550 interface.
+    Ns16550,
+    /// 16550 register subset, as described by the DBGP revision 1 table.
+    Ns16450,
+    ArmPl011,
+    ArmSbsaGeneric32Bit,
+    ArmSbsaGeneric,
+    ArmDcc,
+    Bcm2835,
+    /// 16550-compatible with register layout described by the GAS.
+    Ns16550WithGas,
+    RiscvSbiConsole,
+    Other(u16),
+}
+
+impl AcpiSerialPortType {
+    #[must_use]
+    pub const fn from_raw(raw: u16) -> Self {
+        match raw {
+            0x0000 => Self::Ns16550,
+            0x0001 => Self::Ns16450,
+            0x0003 => Self::ArmPl011,
+            0x000D => Self::ArmSbsaGeneric32Bit,
+            0x000E => Self::ArmSbsaGeneric,
+            0x000F => Self::ArmDcc,
+            0x0010 => Self::Bcm2835,
+            0x0012 => Self::Ns16550WithGas,
+            0x0015 => Self::RiscvSbiConsole,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// Terminal emulation firmware used on the console.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SpcrTerminalType {
+    Vt100,
+    Vt100Plus,
+    VtUtf8,
+    Ansi,
+    Reserved(u8),
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawSpcr {
+    interface_type: u8,
+    reserved0: [u8; 3],
+    base_address: [u8; AcpiGenericAddress::SIZE],
+    interrupt_type: u8,
+    irq: u8,
+    global_system_interrupt: u32,
+    configured_baud_rate: u8,
+    parity: u8,
+    stop_bits: u8,
+    flow_control: u8,
+    terminal_type: u8,
+    language: u8,
+    pci_device_id: u16,
+    pci_vendor_id: u16,
+    pci_bus: u8,
+    pci_device: u8,
+    pci_function: u8,
+    pci_flags: u32,
+    pci_segment: u8,
+    uart_clock_frequency: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawSpcrRevision4 {
+    precise_baud_rate: u32,
+    namespace_string_length: u16,
+    namespace_string_offset: u16,
+}
+
+/// PCI location of a PCI-attached console UART.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SpcrPciLocation {
+    pub vendor_id: u16,
+    pub device_id: u16,
+    pub segment: u8,
+    pub bus: u8,
+    pub device: u8,
+    pub function: u8,
+    pub flags: u32,
+}
+
+/// Borrowed validated SPCR view.
+#[derive(Clone, Copy, Debug)]
+pub struct Spcr<'a> {
+    table: AcpiTableView<'a>,
+    raw: RawSpcr,
+    base_address: AcpiGenericAddress,
+    precise_baud_rate: u32,
+    namespace_path: Option<&'a [u8]>,
+}
+
+impl<'a> Spcr<'a> {
+    /// Parses one validated SPCR.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the table is malformed, truncated, not one SPCR, or its
+    /// revision-4 namespace string overruns the table.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, AcpiError> {
+        let table = AcpiTableView::parse_signature(bytes, AcpiSignature::SPCR)?;
+        let payload = table.payload();
+        if payload.len() < size_of::<RawSpcr>() {
+            return Err(AcpiError::truncated());
+        }
+        let raw: RawSpcr = read_unaligned_copy(payload)?;
+        let base_address = AcpiGenericAddress::parse(&raw.base_address)?;
+
+        let mut precise_baud_rate = 0;
+        let mut namespace_path = None;
+        if table.header().revision() >= 4 {
+            let raw4: RawSpcrRevision4 = read_unaligned_copy(&payload[size_of::<RawSpcr>()..])?;
+            precise_baud_rate = u32::from_le(raw4.precise_baud_rate);
+            let length = usize::from(u16::from_le(raw4.namespace_string_length));
+            let offset = usize::from(u16::from_le(raw4.namespace_string_offset));
+            if length != 0 {
+                let string = table
+                    .bytes()
+                    .get(offset..offset + length)
+                    .ok_or_else(AcpiError::truncated)?;
+                let Some((0, path)) = string.split_last() else {
+                    return Err(AcpiError::invalid_layout());
+                };
+                namespace_path = (path != b".").then_some(path);
+            }
+        }
+
+        Ok(Self {
+            table,
+            raw,
+            base_address,
+            precise_baud_rate,
+            namespace_path,
+        })
+    }
+
+    /// Returns the underlying validated ACPI table view.
+    #[must_use]
+    pub const fn table(self) -> AcpiTableView<'a> {
+        self.table
+    }
+
+    /// Returns the UART interface subtype.
+    #[must_use]
+    pub const fn interface_type(self) -> AcpiSerialPortType {
+        AcpiSerialPortType::from_raw(self.raw.interface_type as u16)
+    }
+
+    /// Returns the UART register block.
+    #[must_use]
+    pub const fn base_address(self) -> AcpiGenericAddress {
+        self.base_address
+    }
+
+    /// Returns the interrupt controllers the UART is wired to.
+    #[must_use]
+    pub const fn interrupt_type(self) -> SpcrInterruptType {
+        SpcrInterruptType::from_bits_retain(self.raw.interrupt_type)
+    }
+
+    /// Returns the PC-AT IRQ, meaningful only when [`SpcrInterruptType::PIC_8259`] is set.
+    #[must_use]
+    pub const fn irq(self) -> u8 {
+        self.raw.irq
+    }
+
+    /// Returns the Global System Interrupt, meaningful for every non-8259 interrupt type.
+    #[must_use]
+    pub const fn global_system_interrupt(self) -> u32 {
+        u32::from_le(self.raw.global_system_interrupt)
+    }
+
+    /// Returns the console baud rate, or `None` when firmware left the port as it found it.
+    #[must_use]
+    pub const fn baud_rate(self) -> Option<u32> {
+        if self.precise_baud_rate != 0 {
+            return Some(self.precise_baud_rate);
+        }
+        match self.raw.configured_baud_rate {
+            3 => Some(9_600),
+            4 => Some(19_200),
+            6 => Some(57_600),
+            7 => Some(115_200),
+            _ => None,
+        }
+    }
+
+    /// Returns the raw parity setting; `0` is the only defined value (no parity).
+    #[must_use]
+    pub const fn parity(self) -> u8 {
+        self.raw.parity
+    }
+
+    /// Returns the raw stop-bit setting; `1` is the only defined value (one stop bit).
+    #[must_use]
+    pub const fn stop_bits(self) -> u8 {
+        self.raw.stop_bits
+    }
+
+    /// Returns the raw flow-control bits (DCD, RTS/CTS, XON/XOFF).
+    #[must_use]
+    pub const fn flow_control(self) -> u8 {
+        self.raw.flow_control
+    }
+
+    /// Returns the terminal emulation type.
+    #[must_use]
+    pub const fn terminal_type(self) -> SpcrTerminalType {
+        match self.raw.terminal_type {
+            0 => SpcrTerminalType::Vt100,
+            1 => SpcrTerminalType::Vt100Plus,
+            2 => SpcrTerminalType::VtUtf8,
+            3 => SpcrTerminalType::Ansi,
+            other => SpcrTerminalType::Reserved(other),
+        }
+    }
+
+    /// Returns the PCI location of the UART, or `None` when it is not a PCI device.
+    #[must_use]
+    pub const fn pci_location(self) -> Option<SpcrPciLocation> {
+        let device_id = u16::from_le(self.raw.pci_device_id);
+        if device_id == 0xFFFF {
+            return None;
+        }
+        Some(SpcrPciLocation {
+            vendor_id: u16::from_le(self.raw.pci_vendor_id),
+            device_id,
+            segment: self.raw.pci_segment,
+            bus: self.raw.pci_bus,
+            device: self.raw.pci_device,
+            function: self.raw.pci_function,
+            flags: u32::from_le(self.raw.pci_flags),
+        })
+    }
+
+    /// Returns the UART input clock in Hz when revision 3+ firmware reported one.
+    #[must_use]
+    pub const fn uart_clock_frequency(self) -> Option<u32> {
+        if self.table.header().revision() < 3 {
+            return None;
+        }
+        match u32::from_le(self.raw.uart_clock_frequency) {
+            0 => None,
+            frequency => Some(frequency),
+        }
+    }
+
+    /// Returns the ACPI namespace path of the UART without its NUL terminator, or `None` for
+    /// `"."`.
+    #[must_use]
+    pub const fn namespace_path(self) -> Option<&'a [u8]> {
+        self.namespace_path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        AcpiAddressSpace,
+        AcpiErrorKind,
+    };
+    use super::*;
+    use std::vec::Vec;
+
+    /// SPCR shaped like an Arm server reports its PL011 console at 115200 baud.
+    fn build_spcr(revision: u8, namespace: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0_u8; 88];
+        bytes[0..4].copy_from_slice(b"SPCR");
+        bytes[8] = revision;
+        bytes[10..16].copy_from_slice(b"ARMLTD");
+        bytes[16..24].copy_from_slice(b"ARMSGI  ");
+        bytes[36] = 0x03;
+        bytes[40] = 0;
+        bytes[41] = 32;
+        bytes[43] = 3;
+        bytes[44..52].copy_from_slice(&0x6000_0000_u64.to_le_bytes());
+        bytes[52] = 0x08;
+        bytes[54..58].copy_from_slice(&33_u32.to_le_bytes());
+        bytes[58] = 7;
+        bytes[60] = 1;
+        bytes[62] = 0;
+        bytes[64..66].copy_from_slice(&0xFFFF_u16.to_le_bytes());
+        bytes[66..68].copy_from_slice(&0xFFFF_u16.to_le_bytes());
+        bytes[76..80].copy_from_slice(&24_000_000_u32.to_le_bytes());
+        if !namespace.is_empty() {
+            bytes[84..86].copy_from_slice(
+                &u16::try_from(namespace.len())
+                    .expect("namespace should fit")
+                    .to_le_bytes(),
+            );
+            bytes[86..88].copy_from_slice(&88_u16.to_le_bytes());
+            bytes.extend_from_slice(namespace);
+        }
+        let length = u32::try_from(bytes.len()).expect("table should fit");
+        bytes[4..8].copy_from_slice(&length.to_le_bytes());
+        let checksum =
+            (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        bytes[9] = checksum;
+        bytes
+    }
+
+    #[test]
+    fn spcr_describes_pl011_console() {
+        let bytes = build_spcr(4, b"\\_SB.COM0\0");
+        let spcr = Spcr::parse(&bytes).expect("spcr should parse");
+        assert_eq!(spcr.interface_type(), AcpiSerialPortType::ArmPl011);
+        assert_eq!(spcr.base_address().address, 0x6000_0000);
+        assert_eq!(spcr.interrupt_type(), SpcrInterruptType::GIC);
+        assert_eq!(spcr.global_system_interrupt(), 33);
+        assert_eq!(spcr.baud_rate(), Some(115_200));
+        assert_eq!(spcr.terminal_type(), SpcrTerminalType::Vt100);
+        assert_eq!(spcr.pci_location(), None);
+        assert_eq!(spcr.uart_clock_frequency(), Some(24_000_000));
+        assert_eq!(spcr.namespace_path(), Some(&b"\\_SB.COM0"[..]));
+    }
+
+    #[test]
+    fn spcr_ignores_newer_fields_on_old_revisions() {
+        let bytes = build_spcr(2, &[]);
+        let spcr = Spcr::parse(&bytes).expect("spcr should parse");
+        assert_eq!(spcr.uart_clock_frequency(), None);
+        assert_eq!(spcr.namespace_path(), None);
+    }
+
+    #[test]
+    fn spcr_rejects_unterminated_namespace_string() {
+        let bytes = build_spcr(4, b"\\_SB.COM0");
+        let error = Spcr::parse(&bytes).expect_err("unterminated path should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidLayout);
+    }
+
+    #[test]
+    fn spcr_parses_acpica_template() {
+        let bytes = include_bytes!("../../../tests/fixtures/acpi/spcr.dat");
+        let spcr = Spcr::parse(bytes).expect("template spcr should parse");
+        assert_eq!(spcr.interface_type(), AcpiSerialPortType::Ns16550);
+        assert_eq!(spcr.base_address().space, AcpiAddressSpace::SystemMemory);
+        assert_eq!(spcr.base_address().register_bit_width, 8);
+        assert_eq!(spcr.interrupt_type(), SpcrInterruptType::empty());
+        assert_eq!(spcr.baud_rate(), None);
+        assert_eq!(spcr.terminal_type(), SpcrTerminalType::Vt100);
+        assert_eq!(spcr.uart_clock_frequency(), None);
+        assert_eq!(spcr.namespace_path(), None);
+    }
+}
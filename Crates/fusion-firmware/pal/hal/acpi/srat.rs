@@ -0,0 +1,455 @@
+//! SRAT definitions and helpers.
+//!
+//! The System Resource Affinity Table (`SRAT`) is ACPI's boot-time NUMA map.
+//! ACPI 6.6 Section 5.2.16 defines it as a short fixed header followed by a
+//! stream of affinity records that place processors and memory ranges into
+//! proximity domains.
+//!
+//! Fusion parses the records the first NUMA-aware bring-up path needs:
+//!
+//! - Processor Local APIC/SAPIC Affinity,
+//! - Memory Affinity,
+//! - Processor Local x2APIC Affinity,
+//! - GICC Affinity.
+//!
+//! Like `MADT`, unknown record types are preserved as opaque borrowed payloads
+//! and every record must match its documented length exactly. Proximity-domain
+//! numbers are only meaningful relative to each other and to `SLIT`; nothing
+//! here assumes they are dense or zero-based.
+
+use core::mem::size_of;
+
+use bitflags::bitflags;
+
+use super::{
+    AcpiError,
+    AcpiSignature,
+    AcpiTableView,
+    read_unaligned_copy,
+};
+
+bitflags! {
+    /// Processor affinity flags shared by the APIC, x2APIC and GICC records.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub struct SratProcessorFlags: u32 {
+        /// The record describes a usable processor.
+        const ENABLED = 1 << 0;
+    }
+}
+
+bitflags! {
+    /// Memory affinity flags.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub struct SratMemoryFlags: u32 {
+        /// The range is usable; disabled records must be ignored.
+        const ENABLED = 1 << 0;
+        /// The range may be hot-added or removed.
+        const HOT_PLUGGABLE = 1 << 1;
+        /// The range is non-volatile.
+        const NON_VOLATILE = 1 << 2;
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawSratHeader {
+    table_revision: u32,
+    reserved: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+struct RawSratRecordHeader {
+    kind: u8,
+    length: u8,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawLocalApicAffinity {
+    proximity_domain_low: u8,
+    apic_id: u8,
+    flags: u32,
+    local_sapic_eid: u8,
+    proximity_domain_high: [u8; 3],
+    clock_domain: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawMemoryAffinity {
+    proximity_domain: u32,
+    reserved0: u16,
+    base_address: u64,
+    length: u64,
+    reserved1: u32,
+    flags: u32,
+    reserved2: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawLocalX2ApicAffinity {
+    reserved0: u16,
+    proximity_domain: u32,
+    x2apic_id: u32,
+    flags: u32,
+    clock_domain: u32,
+    reserved1: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C, packed)]
+struct RawGiccAffinity {
+    proximity_domain: u32,
+    acpi_processor_uid: u32,
+    flags: u32,
+    clock_domain: u32,
+}
+
+/// Parsed processor local APIC/SAPIC affinity record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SratLocalApicAffinity {
+    pub proximity_domain: u32,
+    pub apic_id: u8,
+    pub flags: SratProcessorFlags,
+    pub local_sapic_eid: u8,
+    pub clock_domain: u32,
+}
+
+/// Parsed memory affinity record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SratMemoryAffinity {
+    pub proximity_domain: u32,
+    pub base_address: u64,
+    pub length: u64,
+    pub flags: SratMemoryFlags,
+}
+
+/// Parsed processor local x2APIC affinity record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SratLocalX2ApicAffinity {
+    pub proximity_domain: u32,
+    pub x2apic_id: u32,
+    pub flags: SratProcessorFlags,
+    pub clock_domain: u32,
+}
+
+/// Parsed GICC affinity record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SratGiccAffinity {
+    pub proximity_domain: u32,
+    pub acpi_processor_uid: u32,
+    pub flags: SratProcessorFlags,
+    pub clock_domain: u32,
+}
+
+/// Borrowed parsed SRAT record view.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SratRecord<'a> {
+    LocalApicAffinity(SratLocalApicAffinity),
+    MemoryAffinity(SratMemoryAffinity),
+    LocalX2ApicAffinity(SratLocalX2ApicAffinity),
+    GiccAffinity(SratGiccAffinity),
+    Unknown { kind: u8, bytes: &'a [u8] },
+}
+
+/// Borrowed validated SRAT view.
+#[derive(Clone, Copy, Debug)]
+pub struct Srat<'a> {
+    table: AcpiTableView<'a>,
+}
+
+impl<'a> Srat<'a> {
+    /// Parses one validated SRAT.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the table is malformed, truncated, or not one SRAT.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, AcpiError> {
+        let table = AcpiTableView::parse_signature(bytes, AcpiSignature::SRAT)?;
+        if table.payload().len() < size_of::<RawSratHeader>() {
+            return Err(AcpiError::truncated());
+        }
+        Ok(Self { table })
+    }
+
+    /// Returns the underlying validated ACPI table view.
+    #[must_use]
+    pub const fn table(self) -> AcpiTableView<'a> {
+        self.table
+    }
+
+    /// Returns an iterator over SRAT affinity records.
+    #[must_use]
+    pub fn records(&self) -> SratRecordIter<'a> {
+        SratRecordIter {
+            bytes: &self.table.payload()[size_of::<RawSratHeader>()..],
+            offset: 0,
+        }
+    }
+
+    /// Returns the proximity domain of the enabled memory range containing `address`.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when record parsing fails.
+    pub fn memory_proximity_domain(&self, address: u64) -> Result<Option<u32>, AcpiError> {
+        for record in self.records() {
+            let SratRecord::MemoryAffinity(memory) = record? else {
+                continue;
+            };
+            if memory.flags.contains(SratMemoryFlags::ENABLED)
+                && address >= memory.base_address
+                && address - memory.base_address < memory.length
+            {
+                return Ok(Some(memory.proximity_domain));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Iterator over SRAT variable-length records.
+#[derive(Clone, Debug)]
+pub struct SratRecordIter<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for SratRecordIter<'a> {
+    type Item = Result<SratRecord<'a>, AcpiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header_bytes = self
+            .bytes
+            .get(self.offset..self.offset + size_of::<RawSratRecordHeader>())?;
+        let header: RawSratRecordHeader = match read_unaligned_copy(header_bytes) {
+            Ok(header) => header,
+            Err(error) => return Some(Err(error)),
+        };
+        if usize::from(header.length) < size_of::<RawSratRecordHeader>() {
+            return Some(Err(AcpiError::invalid_layout()));
+        }
+        let end = self.offset + usize::from(header.length);
+        let Some(record_bytes) = self.bytes.get(self.offset..end) else {
+            return Some(Err(AcpiError::truncated()));
+        };
+        self.offset = end;
+        Some(parse_record(
+            header.kind,
+            &record_bytes[size_of::<RawSratRecordHeader>()..],
+        ))
+    }
+}
+
+fn parse_record_body<T: Copy>(payload: &[u8]) -> Result<T, AcpiError> {
+    if payload.len() != size_of::<T>() {
+        return Err(AcpiError::invalid_layout());
+    }
+    read_unaligned_copy(payload)
+}
+
+fn parse_record(kind: u8, payload: &[u8]) -> Result<SratRecord<'_>, AcpiError> {
+    match kind {
+        0 => {
+            let raw: RawLocalApicAffinity = parse_record_body(payload)?;
+            let [high0, high1, high2] = raw.proximity_domain_high;
+            Ok(SratRecord::LocalApicAffinity(SratLocalApicAffinity {
+                proximity_domain: u32::from_le_bytes([
+                    raw.proximity_domain_low,
+                    high0,
+                    high1,
+                    high2,
+                ]),
+                apic_id: raw.apic_id,
+                flags: SratProcessorFlags::from_bits_retain(u32::from_le(raw.flags)),
+                local_sapic_eid: raw.local_sapic_eid,
+                clock_domain: u32::from_le(raw.clock_domain),
+            }))
+        }
+        1 => {
+            let raw: RawMemoryAffinity = parse_record_body(payload)?;
+            Ok(SratRecord::MemoryAffinity(SratMemoryAffinity {
+                proximity_domain: u32::from_le(raw.proximity_domain),
+                base_address: u64::from_le(raw.base_address),
+                length: u64::from_le(raw.length),
+                flags: SratMemoryFlags::from_bits_retain(u32::from_le(raw.flags)),
+            }))
+        }
+        2 => {
+            let raw: RawLocalX2ApicAffinity = parse_record_body(payload)?;
+            Ok(SratRecord::LocalX2ApicAffinity(SratLocalX2ApicAffinity {
+                proximity_domain: u32::from_le(raw.proximity_domain),
+                x2apic_id: u32::from_le(raw.x2apic_id),
+                flags: SratProcessorFlags::from_bits_retain(u32::from_le(raw.flags)),
+                clock_domain: u32::from_le(raw.clock_domain),
+            }))
+        }
+        3 => {
+            let raw: RawGiccAffinity = parse_record_body(payload)?;
+            Ok(SratRecord::GiccAffinity(SratGiccAffinity {
+                proximity_domain: u32::from_le(raw.proximity_domain),
+                acpi_processor_uid: u32::from_le(raw.acpi_processor_uid),
+                flags: SratProcessorFlags::from_bits_retain(u32::from_le(raw.flags)),
+                clock_domain: u32::from_le(raw.clock_domain),
+            }))
+        }
+        _ => Ok(SratRecord::Unknown {
+            kind,
+            bytes: payload,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::AcpiErrorKind;
+    use super::*;
+    use std::vec::Vec;
+
+    fn finish(mut bytes: Vec<u8>) -> Vec<u8> {
+        let length = u32::try_from(bytes.len()).expect("table should fit");
+        bytes[4..8].copy_from_slice(&length.to_le_bytes());
+        bytes[9] = 0;
+        let checksum =
+            (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        bytes[9] = checksum;
+        bytes
+    }
+
+    /// Two-socket layout: one APIC per node, low memory plus 4 GiB above 4 GiB on node 1.
+    fn build_srat() -> Vec<u8> {
+        let mut bytes = vec![0_u8; 48];
+        bytes[0..4].copy_from_slice(b"SRAT");
+        bytes[8] = 3;
+        bytes[10..16].copy_from_slice(b"FUSION");
+        bytes[16..24].copy_from_slice(b"SRATTEST");
+        bytes[36..40].copy_from_slice(&1_u32.to_le_bytes());
+
+        for (domain, apic_id) in [(0_u8, 0_u8), (1, 2)] {
+            let mut record = [0_u8; 16];
+            record[0] = 0;
+            record[1] = 16;
+            record[2] = domain;
+            record[3] = apic_id;
+            record[4..8].copy_from_slice(&1_u32.to_le_bytes());
+            bytes.extend_from_slice(&record);
+        }
+        for (domain, base, length) in [
+            (0_u32, 0_u64, 0x8000_0000_u64),
+            (1, 0x1_0000_0000, 0x1_0000_0000),
+        ] {
+            let mut record = [0_u8; 40];
+            record[0] = 1;
+            record[1] = 40;
+            record[2..6].copy_from_slice(&domain.to_le_bytes());
+            record[8..16].copy_from_slice(&base.to_le_bytes());
+            record[16..24].copy_from_slice(&length.to_le_bytes());
+            record[28..32].copy_from_slice(&1_u32.to_le_bytes());
+            bytes.extend_from_slice(&record);
+        }
+        let mut x2apic = [0_u8; 24];
+        x2apic[0] = 2;
+        x2apic[1] = 24;
+        x2apic[4..8].copy_from_slice(&1_u32.to_le_bytes());
+        x2apic[8..12].copy_from_slice(&0x100_u32.to_le_bytes());
+        x2apic[12..16].copy_from_slice(&1_u32.to_le_bytes());
+        bytes.extend_from_slice(&x2apic);
+        finish(bytes)
+    }
+
+    #[test]
+    fn srat_maps_processors_and_memory_to_domains() {
+        let bytes = build_srat();
+        let srat = Srat::parse(&bytes).expect("srat should parse");
+        let records = srat
+            .records()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("records should parse");
+        assert_eq!(records.len(), 5);
+        assert!(matches!(
+            records[1],
+            SratRecord::LocalApicAffinity(SratLocalApicAffinity {
+                proximity_domain: 1,
+                apic_id: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            records[4],
+            SratRecord::LocalX2ApicAffinity(SratLocalX2ApicAffinity {
+                proximity_domain: 1,
+                x2apic_id: 0x100,
+                ..
+            })
+        ));
+        assert_eq!(srat.memory_proximity_domain(0x1000).unwrap(), Some(0));
+        assert_eq!(
+            srat.memory_proximity_domain(0x1_8000_0000).unwrap(),
+            Some(1)
+        );
+        assert_eq!(srat.memory_proximity_domain(0x9000_0000).unwrap(), None);
+    }
+
+    #[test]
+    fn srat_rejects_memory_record_with_wrong_length() {
+        let mut bytes = build_srat();
+        // Shrink the first memory record by one byte and let it swallow its neighbour's type.
+        bytes[48 + 32 + 1] = 39;
+        let bytes = finish(bytes);
+        let srat = Srat::parse(&bytes).expect("srat should still parse");
+        let error = srat
+            .records()
+            .nth(2)
+            .expect("memory record should exist")
+            .expect_err("short memory record should be rejected");
+        assert_eq!(error.kind(), AcpiErrorKind::InvalidLayout);
+    }
+
+    #[test]
+    fn srat_parses_acpica_template() {
+        let bytes = include_bytes!("../../../tests/fixtures/acpi/srat.dat");
+        let srat = Srat::parse(bytes).expect("template srat should parse");
+        let records = srat
+            .records()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("template records should parse");
+        assert_eq!(records.len(), 7);
+        assert_eq!(
+            records[0],
+            SratRecord::LocalApicAffinity(SratLocalApicAffinity {
+                proximity_domain: 0,
+                apic_id: 0,
+                flags: SratProcessorFlags::ENABLED,
+                local_sapic_eid: 0,
+                clock_domain: 0,
+            })
+        );
+        assert_eq!(
+            records[1],
+            SratRecord::MemoryAffinity(SratMemoryAffinity {
+                proximity_domain: 0,
+                base_address: 0,
+                length: 0x9_FC00,
+                flags: SratMemoryFlags::ENABLED,
+            })
+        );
+        assert!(matches!(
+            records[2],
+            SratRecord::LocalX2ApicAffinity(SratLocalX2ApicAffinity { x2apic_id: 0, .. })
+        ));
+        assert!(matches!(
+            records[3],
+            SratRecord::GiccAffinity(SratGiccAffinity {
+                acpi_processor_uid: 0,
+                ..
+            })
+        ));
+        assert!(matches!(records[4], SratRecord::Unknown { kind: 4, bytes } if bytes.len() == 10));
+        assert!(matches!(records[5], SratRecord::Unknown { kind: 5, bytes } if bytes.len() == 30));
+        assert!(matches!(records[6], SratRecord::Unknown { kind: 7, bytes } if bytes.len() == 18));
+        assert_eq!(srat.memory_proximity_domain(0x9_FBFF), Ok(Some(0)));
+        assert_eq!(srat.memory_proximity_domain(0x9_FC00), Ok(None));
+    }
+}
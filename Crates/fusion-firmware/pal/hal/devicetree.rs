@@ -1 +0,0 @@
-//! Devicetree-driven hardware enumeration support for the dynamic HAL lane.
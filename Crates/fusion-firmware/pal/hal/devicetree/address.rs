@@ -0,0 +1,210 @@
+//! `reg` and `ranges` decoding.
+//!
+//! Devicetree Specification v0.4, Sections 2.3.5 through 2.3.8: a node's `reg` entries are
+//! sized by its parent's `#address-cells` and `#size-cells`, while a bus node's `ranges`
+//! entries pair its own child address width with its parent's address width. Addresses are
+//! decoded into `u128` so three-cell PCI addresses survive intact; sizes are capped at two
+//! cells.
+
+use super::{
+    DeviceTreeCells,
+    DeviceTreeError,
+};
+
+/// Largest cell count accepted for one size field.
+pub const DEVICETREE_MAX_SIZE_CELLS: u32 = 2;
+
+/// One decoded `reg` entry.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DeviceTreeRegion {
+    /// Address in the parent bus address space, or in the CPU space after translation.
+    pub address: u128,
+    /// Size of the region in bytes.
+    pub size: u64,
+}
+
+impl DeviceTreeRegion {
+    /// Returns the address as one CPU-width `u64` when it fits.
+    #[must_use]
+    pub fn address_u64(&self) -> Option<u64> {
+        u64::try_from(self.address).ok()
+    }
+}
+
+/// One decoded `ranges` entry.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DeviceTreeRange {
+    /// Base address in the child bus address space.
+    pub child_address: u128,
+    /// Base address in the parent bus address space.
+    pub parent_address: u128,
+    /// Size of the window in bytes.
+    pub size: u64,
+}
+
+impl DeviceTreeRange {
+    /// Maps one child-bus address through this window, if it falls inside it.
+    #[must_use]
+    pub fn translate(&self, address: u128) -> Option<u128> {
+        let offset = address.checked_sub(self.child_address)?;
+        if offset >= u128::from(self.size) {
+            return None;
+        }
+        self.parent_address.checked_add(offset)
+    }
+}
+
+/// Iterator over one node's `reg` entries.
+#[derive(Clone, Debug, Default)]
+pub struct DeviceTreeRegIter<'a> {
+    cells: DeviceTreeCells<'a>,
+    address_cells: u32,
+    size_cells: u32,
+    index: usize,
+}
+
+impl<'a> DeviceTreeRegIter<'a> {
+    pub(crate) fn new(
+        cells: DeviceTreeCells<'a>,
+        address_cells: u32,
+        size_cells: u32,
+    ) -> Result<Self, DeviceTreeError> {
+        check_entry_shape(cells, &[address_cells, size_cells])?;
+        Ok(Self {
+            cells,
+            address_cells,
+            size_cells,
+            index: 0,
+        })
+    }
+}
+
+impl Iterator for DeviceTreeRegIter<'_> {
+    type Item = DeviceTreeRegion;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let address = self.cells.read(self.index, self.address_cells)?;
+        let size = self
+            .cells
+            .read(self.index + self.address_cells as usize, self.size_cells)?;
+        self.index += (self.address_cells + self.size_cells) as usize;
+        Some(DeviceTreeRegion {
+            address,
+            size: u64::try_from(size).ok()?,
+        })
+    }
+}
+
+/// Decoded `ranges` property of one bus node.
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceTreeRanges<'a> {
+    cells: DeviceTreeCells<'a>,
+    child_address_cells: u32,
+    parent_address_cells: u32,
+    size_cells: u32,
+}
+
+impl<'a> DeviceTreeRanges<'a> {
+    pub(crate) fn new(
+        cells: DeviceTreeCells<'a>,
+        child_address_cells: u32,
+        parent_address_cells: u32,
+        size_cells: u32,
+    ) -> Result<Self, DeviceTreeError> {
+        check_entry_shape(
+            cells,
+            &[child_address_cells, parent_address_cells, size_cells],
+        )?;
+        Ok(Self {
+            cells,
+            child_address_cells,
+            parent_address_cells,
+            size_cells,
+        })
+    }
+
+    /// Returns whether the bus maps its child address space one-to-one onto its parent's.
+    ///
+    /// That is what an empty `ranges;` property means.
+    #[must_use]
+    pub const fn is_identity(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the translation windows.
+    #[must_use]
+    pub const fn iter(&self) -> DeviceTreeRangeIter<'a> {
+        DeviceTreeRangeIter {
+            ranges: *self,
+            index: 0,
+        }
+    }
+
+    /// Maps one child-bus address into the parent bus address space.
+    ///
+    /// Returns `None` when no window covers the address.
+    #[must_use]
+    pub fn translate(&self, address: u128) -> Option<u128> {
+        if self.is_identity() {
+            return Some(address);
+        }
+        self.iter().find_map(|range| range.translate(address))
+    }
+}
+
+impl<'a> IntoIterator for &DeviceTreeRanges<'a> {
+    type Item = DeviceTreeRange;
+    type IntoIter = DeviceTreeRangeIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over one bus node's `ranges` entries.
+#[derive(Clone, Debug)]
+pub struct DeviceTreeRangeIter<'a> {
+    ranges: DeviceTreeRanges<'a>,
+    index: usize,
+}
+
+impl Iterator for DeviceTreeRangeIter<'_> {
+    type Item = DeviceTreeRange;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ranges = &self.ranges;
+        let child = ranges.child_address_cells as usize;
+        let parent = ranges.parent_address_cells as usize;
+        let child_address = ranges.cells.read(self.index, ranges.child_address_cells)?;
+        let parent_address = ranges
+            .cells
+            .read(self.index + child, ranges.parent_address_cells)?;
+        let size = ranges
+            .cells
+            .read(self.index + child + parent, ranges.size_cells)?;
+        self.index += child + parent + ranges.size_cells as usize;
+        Some(DeviceTreeRange {
+            child_address,
+            parent_address,
+            size: u64::try_from(size).ok()?,
+        })
+    }
+}
+
+fn check_entry_shape(cells: DeviceTreeCells<'_>, widths: &[u32]) -> Result<(), DeviceTreeError> {
+    let (size, addresses) = widths
+        .split_last()
+        .ok_or_else(DeviceTreeError::invalid_property)?;
+    if *size > DEVICETREE_MAX_SIZE_CELLS
+        || addresses
+            .iter()
+            .any(|cells| *cells == 0 || *cells > super::DEVICETREE_MAX_CELLS)
+    {
+        return Err(DeviceTreeError::invalid_property());
+    }
+    let stride: u32 = widths.iter().sum();
+    if !cells.len().is_multiple_of(stride as usize) {
+        return Err(DeviceTreeError::invalid_property());
+    }
+    Ok(())
+}
@@ -0,0 +1,426 @@
+//! Validated devicetree blob and structure-block walking.
+//!
+//! [`DeviceTree::parse`] walks the whole structure block exactly once up front. Every view this
+//! module hands out afterwards (nodes, properties, children) re-walks the same token stream
+//! lazily, so those accessors can stay infallible and allocation-free.
+
+use core::str;
+
+use super::{
+    DEVICETREE_MAX_DEPTH,
+    DeviceTreeError,
+    DeviceTreeNode,
+    DeviceTreeProperty,
+    FdtHeader,
+    FdtReservationIter,
+    align4,
+    read_be_u32,
+};
+use super::header::reservation_block;
+
+const FDT_BEGIN_NODE: u32 = 0x1;
+const FDT_END_NODE: u32 = 0x2;
+const FDT_PROP: u32 = 0x3;
+const FDT_NOP: u32 = 0x4;
+const FDT_END: u32 = 0x9;
+
+/// One structure-block token decoded at a known offset.
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Token<'a> {
+    BeginNode {
+        name: &'a str,
+        next: usize,
+    },
+    EndNode {
+        next: usize,
+    },
+    Prop {
+        property: DeviceTreeProperty<'a>,
+        next: usize,
+    },
+    Nop {
+        next: usize,
+    },
+    End,
+}
+
+/// Borrowed validated flattened devicetree.
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceTree<'a> {
+    header: FdtHeader,
+    reservations: &'a [u8],
+    structure: &'a [u8],
+    strings: &'a [u8],
+}
+
+impl<'a> DeviceTree<'a> {
+    /// Parses and fully validates one devicetree blob.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the header is invalid, the reservation map does not
+    /// terminate, or the structure block is not one well-formed node tree.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, DeviceTreeError> {
+        let header = FdtHeader::parse(bytes)?;
+        let total = &bytes[..header.total_size as usize];
+        let reservations = reservation_block(total, header.memory_reservation_offset as usize)?;
+        let structure_start = header.structure_offset as usize;
+        let strings_start = header.strings_offset as usize;
+        let tree = Self {
+            header,
+            reservations,
+            structure: &total[structure_start..structure_start + header.structure_size as usize],
+            strings: &total[strings_start..strings_start + header.strings_size as usize],
+        };
+        tree.validate_structure()?;
+        Ok(tree)
+    }
+
+    /// Returns the validated header.
+    #[must_use]
+    pub const fn header(&self) -> FdtHeader {
+        self.header
+    }
+
+    /// Returns the physical ID of the boot CPU recorded in the header.
+    #[must_use]
+    pub const fn boot_cpu_physical_id(&self) -> u32 {
+        self.header.boot_cpu_physical_id
+    }
+
+    /// Returns the entries of the memory reservation block.
+    #[must_use]
+    pub const fn memory_reservations(&self) -> FdtReservationIter<'a> {
+        FdtReservationIter::new(self.reservations)
+    }
+
+    /// Returns the root node.
+    #[must_use]
+    pub fn root(&self) -> DeviceTreeNode<'a> {
+        let mut offset = 0;
+        while let Some(Token::Nop { next }) = self.token(offset) {
+            offset = next;
+        }
+        DeviceTreeNode::new(*self, offset, None)
+    }
+
+    /// Returns every node in document order, root first.
+    #[must_use]
+    pub fn nodes(&self) -> DeviceTreeNodeIter<'a> {
+        let root = self.root();
+        DeviceTreeNodeIter {
+            tree: *self,
+            offset: root.offset(),
+            stack: [0; DEVICETREE_MAX_DEPTH],
+            depth: 0,
+        }
+    }
+
+    /// Looks up one node by absolute path or by alias.
+    ///
+    /// Absolute paths start with `/`; each component may omit the unit address when the base
+    /// name alone is unambiguous among its siblings. Anything else is resolved through
+    /// `/aliases` first, with an optional trailing path relative to the aliased node.
+    #[must_use]
+    pub fn find_node(&self, path: &str) -> Option<DeviceTreeNode<'a>> {
+        let (mut node, rest) = if let Some(rest) = path.strip_prefix('/') {
+            (self.root(), rest)
+        } else {
+            let (alias, rest) = path.split_once('/').unwrap_or((path, ""));
+            let target = self.root().child("aliases")?.property(alias)?.as_str()?;
+            if !target.starts_with('/') {
+                return None;
+            }
+            (self.find_node(target)?, rest)
+        };
+        for component in rest.split('/').filter(|component| !component.is_empty()) {
+            node = node.child(component)?;
+        }
+        Some(node)
+    }
+
+    /// Resolves one phandle to its node.
+    #[must_use]
+    pub fn node_by_phandle(&self, phandle: u32) -> Option<DeviceTreeNode<'a>> {
+        if phandle == 0 || phandle == u32::MAX {
+            return None;
+        }
+        self.nodes().find(|node| node.phandle() == Some(phandle))
+    }
+
+    /// Returns every node whose `compatible` list names `compatible`.
+    pub fn compatible_nodes<'b>(
+        &self,
+        compatible: &'b str,
+    ) -> impl Iterator<Item = DeviceTreeNode<'a>> + use<'a, 'b> {
+        self.nodes()
+            .filter(move |node| node.is_compatible(compatible))
+    }
+
+    pub(super) fn token(&self, offset: usize) -> Option<Token<'a>> {
+        let token = read_be_u32(self.structure, offset)?;
+        let body = offset + 4;
+        match token {
+            FDT_BEGIN_NODE => {
+                let tail = self.structure.get(body..)?;
+                let len = tail.iter().position(|byte| *byte == 0)?;
+                let name = str::from_utf8(&tail[..len]).ok()?;
+                let next = align4(body + len + 1);
+                (next <= self.structure.len()).then_some(Token::BeginNode { name, next })
+            }
+            FDT_END_NODE => Some(Token::EndNode { next: body }),
+            FDT_PROP => {
+                let len = read_be_u32(self.structure, body)? as usize;
+                let name_offset = read_be_u32(self.structure, body + 4)? as usize;
+                let data = body + 8;
+                let value = self.structure.get(data..data.checked_add(len)?)?;
+                let next = align4(data + len);
+                if next > self.structure.len() {
+                    return None;
+                }
+                let name = self.string_at(name_offset)?;
+                Some(Token::Prop {
+                    property: DeviceTreeProperty::new(name, value),
+                    next,
+                })
+            }
+            FDT_NOP => Some(Token::Nop { next: body }),
+            FDT_END => Some(Token::End),
+            _ => None,
+        }
+    }
+
+    /// Returns the offset just past the `FDT_END_NODE` matching the node at `offset`.
+    pub(super) fn skip_node(&self, offset: usize) -> Option<usize> {
+        let mut depth = 0_usize;
+        let mut cursor = offset;
+        loop {
+            match self.token(cursor)? {
+                Token::BeginNode { next, .. } => {
+                    depth += 1;
+                    cursor = next;
+                }
+                Token::EndNode { next } => {
+                    depth -= 1;
+                    cursor = next;
+                    if depth == 0 {
+                        return Some(cursor);
+                    }
+                }
+                Token::Prop { next, .. } | Token::Nop { next } => cursor = next,
+                Token::End => return None,
+            }
+        }
+    }
+
+    /// Returns the offset of the parent of the node at `offset`, if it has one.
+    pub(super) fn parent_offset(&self, offset: usize) -> Option<usize> {
+        let mut nodes = self.nodes();
+        while let Some(node) = nodes.next() {
+            if node.offset() == offset {
+                return nodes.depth.checked_sub(2).map(|index| nodes.stack[index]);
+            }
+        }
+        None
+    }
+
+    fn string_at(&self, offset: usize) -> Option<&'a str> {
+        let tail = self.strings.get(offset..)?;
+        let len = tail.iter().position(|byte| *byte == 0)?;
+        str::from_utf8(&tail[..len]).ok()
+    }
+
+    fn validate_structure(&self) -> Result<(), DeviceTreeError> {
+        let invalid = DeviceTreeError::invalid_layout;
+        let mut seen_child = [false; DEVICETREE_MAX_DEPTH];
+        let mut depth = 0_usize;
+        let mut seen_root = false;
+        let mut cursor = 0;
+        loop {
+            match self.token(cursor).ok_or_else(invalid)? {
+                Token::BeginNode { name, next } => {
+                    if depth == 0 {
+                        if seen_root || !name.is_empty() {
+                            return Err(invalid());
+                        }
+                        seen_root = true;
+                    } else {
+                        if name.is_empty() {
+                            return Err(invalid());
+                        }
+                        seen_child[depth - 1] = true;
+                    }
+                    if depth == DEVICETREE_MAX_DEPTH {
+                        return Err(invalid());
+                    }
+                    seen_child[depth] = false;
+                    depth += 1;
+                    cursor = next;
+                }
+                Token::EndNode { next } => {
+                    depth = depth.checked_sub(1).ok_or_else(invalid)?;
+                    cursor = next;
+                }
+                Token::Prop { next, .. } => {
+                    if depth == 0 || seen_child[depth - 1] {
+                        return Err(invalid());
+                    }
+                    cursor = next;
+                }
+                Token::Nop { next } => cursor = next,
+                Token::End => {
+                    return if depth == 0 && seen_root {
+                        Ok(())
+                    } else {
+                        Err(invalid())
+                    };
+                }
+            }
+        }
+    }
+}
+
+/// Depth-first iterator over every node in one devicetree.
+#[derive(Clone, Debug)]
+pub struct DeviceTreeNodeIter<'a> {
+    tree: DeviceTree<'a>,
+    offset: usize,
+    stack: [usize; DEVICETREE_MAX_DEPTH],
+    depth: usize,
+}
+
+impl<'a> Iterator for DeviceTreeNodeIter<'a> {
+    type Item = DeviceTreeNode<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.tree.token(self.offset)? {
+                Token::BeginNode { next, .. } => {
+                    let parent = self.depth.checked_sub(1).map(|index| self.stack[index]);
+                    let node = DeviceTreeNode::new(self.tree, self.offset, parent);
+                    *self.stack.get_mut(self.depth)? = self.offset;
+                    self.depth += 1;
+                    self.offset = next;
+                    return Some(node);
+                }
+                Token::EndNode { next } => {
+                    self.depth = self.depth.checked_sub(1)?;
+                    self.offset = next;
+                }
+                Token::Prop { next, .. } | Token::Nop { next } => self.offset = next,
+                Token::End => return None,
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::pal::hal::devicetree::DeviceTreeErrorKind;
+
+    const MINIMAL: &[u8] = include_bytes!("../../../tests/fixtures/devicetree/minimal.dtb");
+
+    #[test]
+    fn parse_walks_nodes_in_document_order() {
+        let tree = DeviceTree::parse(MINIMAL).expect("minimal tree should parse");
+        let names: Vec<_> = tree.nodes().map(|node| node.name()).collect();
+        assert_eq!(
+            names,
+            [
+                "",
+                "aliases",
+                "soc",
+                "uart@1000",
+                "interrupt-controller@2000"
+            ]
+        );
+        let reservations: Vec<_> = tree.memory_reservations().collect();
+        assert_eq!(reservations.len(), 1);
+        assert_eq!(reservations[0].address, 0x1000);
+        assert_eq!(reservations[0].size, 0x2000);
+        assert_eq!(
+            tree.root()
+                .property("model")
+                .and_then(|model| model.as_str()),
+            Some("fusion,minimal")
+        );
+    }
+
+    #[test]
+    fn find_node_resolves_paths_aliases_and_phandles() {
+        let tree = DeviceTree::parse(MINIMAL).expect("minimal tree should parse");
+        let uart = tree.find_node("/soc/uart@1000").expect("absolute path");
+        assert_eq!(
+            tree.find_node("/soc/uart").map(|node| node.offset()),
+            Some(uart.offset())
+        );
+        assert_eq!(
+            tree.find_node("serial0").map(|node| node.offset()),
+            Some(uart.offset())
+        );
+        assert!(tree.find_node("/soc/missing").is_none());
+        assert!(tree.find_node("serial9").is_none());
+
+        let intc = tree.find_node("/soc/interrupt-controller").expect("intc");
+        let peer = intc
+            .property("peer")
+            .and_then(|peer| peer.as_u32())
+            .expect("peer");
+        assert_eq!(
+            tree.node_by_phandle(peer).map(|node| node.offset()),
+            Some(uart.offset())
+        );
+        assert_eq!(uart.parent().map(|node| node.name()), Some("soc"));
+        assert_eq!(
+            uart.parent()
+                .and_then(|soc| soc.parent())
+                .map(|node| node.name()),
+            Some("")
+        );
+        assert_eq!(tree.compatible_nodes("ns16550a").count(), 1);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_blobs() {
+        let blob = MINIMAL;
+        let kind = |bytes: &[u8]| DeviceTree::parse(bytes).map(|_| ()).unwrap_err().kind();
+
+        assert_eq!(kind(&blob[..20]), DeviceTreeErrorKind::Truncated);
+        assert_eq!(
+            kind(&blob[..blob.len() - 4]),
+            DeviceTreeErrorKind::Truncated
+        );
+
+        let mut bad_magic = blob.to_vec();
+        bad_magic[0] = 0;
+        assert_eq!(kind(&bad_magic), DeviceTreeErrorKind::InvalidMagic);
+
+        let mut old = blob.to_vec();
+        old[20..24].copy_from_slice(&16_u32.to_be_bytes());
+        assert_eq!(kind(&old), DeviceTreeErrorKind::UnsupportedVersion);
+
+        let mut oversized = blob.to_vec();
+        let strings_size = read_be_u32(blob, 32).unwrap() + 0x1000;
+        oversized[32..36].copy_from_slice(&strings_size.to_be_bytes());
+        assert_eq!(kind(&oversized), DeviceTreeErrorKind::InvalidLayout);
+
+        // Corrupt the first property's name offset so it points past the strings block.
+        let header = FdtHeader::parse(blob).unwrap();
+        let structure = header.structure_offset as usize;
+        let mut cursor = structure + 8;
+        while read_be_u32(blob, cursor) != Some(FDT_PROP) {
+            cursor += 4;
+        }
+        let mut bad_name = blob.to_vec();
+        bad_name[cursor + 8..cursor + 12].copy_from_slice(&0xffff_u32.to_be_bytes());
+        assert_eq!(kind(&bad_name), DeviceTreeErrorKind::InvalidLayout);
+
+        // Drop the root's END_NODE by turning it into a NOP.
+        let mut unbalanced = blob.to_vec();
+        let end = structure + header.structure_size as usize - 8;
+        assert_eq!(read_be_u32(blob, end), Some(FDT_END_NODE));
+        unbalanced[end..end + 4].copy_from_slice(&FDT_NOP.to_be_bytes());
+        assert_eq!(kind(&unbalanced), DeviceTreeErrorKind::InvalidLayout);
+    }
+}
@@ -0,0 +1,75 @@
+//! Flattened Devicetree parsing for the dynamic HAL lane.
+//!
+//! <https://github.com/devicetree-org/devicetree-specification/releases>
+//!
+//! This is the devicetree sibling of the ACPI table path. Where ACPI hands Fusion a pile of
+//! checksummed tables, devicetree hands it one self-contained blob (`DTB`) laid out by the
+//! Devicetree Specification v0.4:
+//!
+//! - Section 5.2 for the `fdt_header`,
+//! - Section 5.3 for the memory reservation block,
+//! - Section 5.4 for the structure block and its token stream,
+//! - Section 5.5 for the strings block,
+//! - Sections 2.3 and 2.4 for the standard properties this layer decodes (`compatible`,
+//!   `phandle`, `status`, `#address-cells`, `#size-cells`, `reg`, `ranges`, `interrupts`,
+//!   `interrupt-parent`, `interrupts-extended`, `#interrupt-cells`).
+//!
+//! The parser is zero-copy and validation-first, in the same spirit as the ACPI tables:
+//!
+//! - the magic and version must match a format Fusion understands,
+//! - every block must fit inside the declared total size,
+//! - the memory reservation list must terminate inside the blob,
+//! - the structure block must be one well-nested token stream ending in `FDT_END`,
+//! - every property name must resolve to a NUL-terminated string inside the strings block.
+//!
+//! Once [`DeviceTree::parse`] succeeds, node and property views borrow straight from the blob.
+//! Semantic decoding (`reg`, `ranges`, interrupts) still validates cell counts and phandle
+//! references at the point of use, because a structurally sound blob can still describe
+//! nonsense.
+//!
+//! [`DeviceTreeTopology`] then lifts the blob into the same kind of records the ACPI path
+//! produces: memory and reservation windows, processors, interrupt controllers, PCI ECAM
+//! windows as [`McfgAllocation`](crate::pal::hal::acpi::McfgAllocation) entries, and
+//! devicetree-sourced driver bindings keyed by `compatible`.
+//!
+//! Deliberately out of scope for now: `interrupt-map` routing, overlays, and address
+//! translation through buses whose child addresses carry flag cells (PCI).
+
+mod address;
+mod blob;
+mod error;
+mod header;
+mod interrupts;
+mod node;
+mod property;
+mod topology;
+
+pub use address::*;
+pub use blob::*;
+pub use error::*;
+pub use header::*;
+pub use interrupts::*;
+pub use node::*;
+pub use property::*;
+pub use topology::*;
+
+/// Deepest node nesting accepted by the parser.
+///
+/// Real trees rarely exceed a handful of levels; the bound keeps ancestor walks on fixed
+/// stack storage.
+pub const DEVICETREE_MAX_DEPTH: usize = 32;
+
+pub(crate) fn read_be_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    let raw = bytes.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_be_bytes([raw[0], raw[1], raw[2], raw[3]]))
+}
+
+pub(crate) fn read_be_u64(bytes: &[u8], offset: usize) -> Option<u64> {
+    let high = read_be_u32(bytes, offset)?;
+    let low = read_be_u32(bytes, offset.checked_add(4)?)?;
+    Some((u64::from(high) << 32) | u64::from(low))
+}
+
+pub(crate) const fn align4(offset: usize) -> usize {
+    (offset + 3) & !3
+}
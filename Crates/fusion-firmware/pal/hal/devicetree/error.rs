@@ -0,0 +1,117 @@
+//! Error vocabulary for devicetree blob parsing and decoding.
+//!
+//! Structural failures mirror the ACPI table errors: the blob is truncated, carries the wrong
+//! magic or an unsupported version, or its blocks and token stream do not line up. Decoding
+//! failures are narrower and belong to one property: a cell count that does not divide the
+//! value, or a phandle that names no node.
+
+use core::fmt;
+
+/// Kind of devicetree failure surfaced by the dynamic HAL lane.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DeviceTreeErrorKind {
+    /// The supplied bytes are too short for the requested structure.
+    Truncated,
+    /// The blob does not start with the FDT magic.
+    InvalidMagic,
+    /// The blob uses a format version this parser cannot read.
+    UnsupportedVersion,
+    /// The header blocks or the structure token stream are malformed.
+    InvalidLayout,
+    /// One property value does not match the shape its name requires.
+    InvalidProperty,
+    /// One phandle reference does not resolve to a node.
+    UnresolvedPhandle,
+    /// Caller-provided record storage is exhausted.
+    ResourceExhausted,
+}
+
+/// Devicetree parsing or decoding failure.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DeviceTreeError {
+    kind: DeviceTreeErrorKind,
+}
+
+impl DeviceTreeError {
+    /// Creates one truncated-blob error.
+    #[must_use]
+    pub const fn truncated() -> Self {
+        Self {
+            kind: DeviceTreeErrorKind::Truncated,
+        }
+    }
+
+    /// Creates one invalid-magic error.
+    #[must_use]
+    pub const fn invalid_magic() -> Self {
+        Self {
+            kind: DeviceTreeErrorKind::InvalidMagic,
+        }
+    }
+
+    /// Creates one unsupported-version error.
+    #[must_use]
+    pub const fn unsupported_version() -> Self {
+        Self {
+            kind: DeviceTreeErrorKind::UnsupportedVersion,
+        }
+    }
+
+    /// Creates one invalid-layout error.
+    #[must_use]
+    pub const fn invalid_layout() -> Self {
+        Self {
+            kind: DeviceTreeErrorKind::InvalidLayout,
+        }
+    }
+
+    /// Creates one invalid-property error.
+    #[must_use]
+    pub const fn invalid_property() -> Self {
+        Self {
+            kind: DeviceTreeErrorKind::InvalidProperty,
+        }
+    }
+
+    /// Creates one unresolved-phandle error.
+    #[must_use]
+    pub const fn unresolved_phandle() -> Self {
+        Self {
+            kind: DeviceTreeErrorKind::UnresolvedPhandle,
+        }
+    }
+
+    /// Creates one storage-exhausted error.
+    #[must_use]
+    pub const fn resource_exhausted() -> Self {
+        Self {
+            kind: DeviceTreeErrorKind::ResourceExhausted,
+        }
+    }
+
+    /// Returns the concrete error kind.
+    #[must_use]
+    pub const fn kind(self) -> DeviceTreeErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for DeviceTreeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Truncated => f.write_str("devicetree blob truncated"),
+            Self::InvalidMagic => f.write_str("devicetree blob magic mismatch"),
+            Self::UnsupportedVersion => f.write_str("devicetree blob version unsupported"),
+            Self::InvalidLayout => f.write_str("devicetree blob layout invalid"),
+            Self::InvalidProperty => f.write_str("devicetree property value invalid"),
+            Self::UnresolvedPhandle => f.write_str("devicetree phandle unresolved"),
+            Self::ResourceExhausted => f.write_str("devicetree record storage exhausted"),
+        }
+    }
+}
+
+impl fmt::Display for DeviceTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind.fmt(f)
+    }
+}
@@ -0,0 +1,157 @@
+//! FDT header and memory reservation block.
+//!
+//! The header is ten big-endian 32-bit words (Devicetree Specification v0.4, Section 5.2). It
+//! locates the other three blocks; this parser only accepts blobs whose blocks sit inside the
+//! declared total size with the alignment the specification requires. Version 17 is the
+//! current format and the only one carrying `size_dt_struct`, so older blobs are refused
+//! rather than guessed at.
+
+use super::{
+    DeviceTreeError,
+    read_be_u32,
+    read_be_u64,
+};
+
+/// FDT magic word.
+pub const FDT_MAGIC: u32 = 0xd00d_feed;
+/// Size of the version-17 FDT header in bytes.
+pub const FDT_HEADER_LEN: usize = 40;
+/// Newest FDT format version this parser reads.
+pub const FDT_VERSION: u32 = 17;
+
+const FDT_RESERVATION_ENTRY_LEN: usize = 16;
+
+/// Validated FDT header fields.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FdtHeader {
+    /// Total blob size in bytes.
+    pub total_size: u32,
+    /// Offset of the structure block.
+    pub structure_offset: u32,
+    /// Offset of the strings block.
+    pub strings_offset: u32,
+    /// Offset of the memory reservation block.
+    pub memory_reservation_offset: u32,
+    /// Format version of the blob.
+    pub version: u32,
+    /// Oldest format version the blob is backwards compatible with.
+    pub last_compatible_version: u32,
+    /// Physical ID of the boot CPU.
+    pub boot_cpu_physical_id: u32,
+    /// Size of the strings block in bytes.
+    pub strings_size: u32,
+    /// Size of the structure block in bytes.
+    pub structure_size: u32,
+}
+
+impl FdtHeader {
+    /// Parses and validates one FDT header against the supplied blob bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the bytes are truncated, the magic does not match, the
+    /// version is unsupported, or any block falls outside the declared blob.
+    pub fn parse(bytes: &[u8]) -> Result<Self, DeviceTreeError> {
+        if bytes.len() < FDT_HEADER_LEN {
+            return Err(DeviceTreeError::truncated());
+        }
+        let word = |index: usize| read_be_u32(bytes, index * 4).unwrap_or(0);
+        if word(0) != FDT_MAGIC {
+            return Err(DeviceTreeError::invalid_magic());
+        }
+        let header = Self {
+            total_size: word(1),
+            structure_offset: word(2),
+            strings_offset: word(3),
+            memory_reservation_offset: word(4),
+            version: word(5),
+            last_compatible_version: word(6),
+            boot_cpu_physical_id: word(7),
+            strings_size: word(8),
+            structure_size: word(9),
+        };
+        if header.version < FDT_VERSION || header.last_compatible_version > FDT_VERSION {
+            return Err(DeviceTreeError::unsupported_version());
+        }
+
+        let total = header.total_size as usize;
+        if total < FDT_HEADER_LEN {
+            return Err(DeviceTreeError::invalid_layout());
+        }
+        if total > bytes.len() {
+            return Err(DeviceTreeError::truncated());
+        }
+        let structure = header.structure_offset as usize;
+        let reservations = header.memory_reservation_offset as usize;
+        if !structure.is_multiple_of(4)
+            || !reservations.is_multiple_of(8)
+            || !header.structure_size.is_multiple_of(4)
+        {
+            return Err(DeviceTreeError::invalid_layout());
+        }
+        for (offset, size) in [
+            (header.structure_offset, header.structure_size),
+            (header.strings_offset, header.strings_size),
+        ] {
+            let end = (offset as usize)
+                .checked_add(size as usize)
+                .ok_or_else(DeviceTreeError::invalid_layout)?;
+            if (offset as usize) < FDT_HEADER_LEN || end > total {
+                return Err(DeviceTreeError::invalid_layout());
+            }
+        }
+        if reservations < FDT_HEADER_LEN || reservations >= total {
+            return Err(DeviceTreeError::invalid_layout());
+        }
+        Ok(header)
+    }
+}
+
+/// One entry from the FDT memory reservation block.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FdtReservation {
+    /// Physical base address of the reserved range.
+    pub address: u64,
+    /// Size of the reserved range in bytes.
+    pub size: u64,
+}
+
+/// Iterator over FDT memory reservation entries.
+#[derive(Clone, Debug)]
+pub struct FdtReservationIter<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> FdtReservationIter<'a> {
+    pub(crate) const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+}
+
+impl Iterator for FdtReservationIter<'_> {
+    type Item = FdtReservation;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let address = read_be_u64(self.bytes, self.offset)?;
+        let size = read_be_u64(self.bytes, self.offset + 8)?;
+        if address == 0 && size == 0 {
+            return None;
+        }
+        self.offset += FDT_RESERVATION_ENTRY_LEN;
+        Some(FdtReservation { address, size })
+    }
+}
+
+/// Returns the reservation block bytes up to and including its terminator.
+pub(super) fn reservation_block(bytes: &[u8], offset: usize) -> Result<&[u8], DeviceTreeError> {
+    let mut cursor = offset;
+    loop {
+        let address = read_be_u64(bytes, cursor).ok_or_else(DeviceTreeError::invalid_layout)?;
+        let size = read_be_u64(bytes, cursor + 8).ok_or_else(DeviceTreeError::invalid_layout)?;
+        cursor += FDT_RESERVATION_ENTRY_LEN;
+        if address == 0 && size == 0 {
+            return Ok(&bytes[offset..cursor]);
+        }
+    }
+}
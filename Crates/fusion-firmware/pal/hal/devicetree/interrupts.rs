@@ -0,0 +1,145 @@
+//! Interrupt specifier decoding.
+//!
+//! Devicetree Specification v0.4, Section 2.4: a device names its interrupts either through
+//! `interrupts` (specifiers interpreted by one inherited interrupt parent) or through
+//! `interrupts-extended` (one controller phandle per specifier). Either way the specifier width
+//! is the controller's `#interrupt-cells`. Nexus routing through `interrupt-map` is not decoded
+//! here; a specifier aimed at a nexus node is reported against that nexus as-is.
+
+use super::{
+    DeviceTree,
+    DeviceTreeCells,
+    DeviceTreeError,
+    DeviceTreeNode,
+};
+
+/// One decoded interrupt specifier and the controller that interprets it.
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceTreeInterrupt<'a> {
+    /// Interrupt controller (or nexus) the specifier is addressed to.
+    pub controller: DeviceTreeNode<'a>,
+    /// Raw specifier cells, `#interrupt-cells` wide.
+    pub specifier: DeviceTreeCells<'a>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Source<'a> {
+    Empty,
+    Inherited {
+        controller: DeviceTreeNode<'a>,
+        width: usize,
+    },
+    Extended {
+        tree: DeviceTree<'a>,
+    },
+}
+
+/// Iterator over one node's interrupt specifiers.
+#[derive(Clone, Debug)]
+pub struct DeviceTreeInterruptIter<'a> {
+    source: Source<'a>,
+    cells: DeviceTreeCells<'a>,
+    index: usize,
+}
+
+impl<'a> DeviceTreeInterruptIter<'a> {
+    pub(crate) fn empty() -> Self {
+        Self {
+            source: Source::Empty,
+            cells: DeviceTreeCells::default(),
+            index: 0,
+        }
+    }
+
+    pub(crate) fn inherited(
+        controller: DeviceTreeNode<'a>,
+        cells: DeviceTreeCells<'a>,
+    ) -> Result<Self, DeviceTreeError> {
+        let width = controller_width(&controller)?;
+        if !cells.len().is_multiple_of(width) {
+            return Err(DeviceTreeError::invalid_property());
+        }
+        Ok(Self {
+            source: Source::Inherited { controller, width },
+            cells,
+            index: 0,
+        })
+    }
+
+    pub(crate) fn extended(
+        tree: DeviceTree<'a>,
+        cells: DeviceTreeCells<'a>,
+    ) -> Result<Self, DeviceTreeError> {
+        let iter = Self {
+            source: Source::Extended { tree },
+            cells,
+            index: 0,
+        };
+        // Walk once up front so iteration itself can stay infallible.
+        let mut index = 0;
+        while index < cells.len() {
+            let (_, next) = iter.extended_entry(tree, index)?;
+            index = next;
+        }
+        Ok(iter)
+    }
+
+    fn extended_entry(
+        &self,
+        tree: DeviceTree<'a>,
+        index: usize,
+    ) -> Result<(DeviceTreeInterrupt<'a>, usize), DeviceTreeError> {
+        let phandle = self
+            .cells
+            .get(index)
+            .ok_or_else(DeviceTreeError::invalid_property)?;
+        let controller = tree
+            .node_by_phandle(phandle)
+            .ok_or_else(DeviceTreeError::unresolved_phandle)?;
+        let width = controller_width(&controller)?;
+        let specifier = self
+            .cells
+            .slice(index + 1, width)
+            .ok_or_else(DeviceTreeError::invalid_property)?;
+        Ok((
+            DeviceTreeInterrupt {
+                controller,
+                specifier,
+            },
+            index + 1 + width,
+        ))
+    }
+}
+
+impl<'a> Iterator for DeviceTreeInterruptIter<'a> {
+    type Item = DeviceTreeInterrupt<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.cells.len() {
+            return None;
+        }
+        match self.source {
+            Source::Empty => None,
+            Source::Inherited { controller, width } => {
+                let specifier = self.cells.slice(self.index, width)?;
+                self.index += width;
+                Some(DeviceTreeInterrupt {
+                    controller,
+                    specifier,
+                })
+            }
+            Source::Extended { tree } => {
+                let (interrupt, next) = self.extended_entry(tree, self.index).ok()?;
+                self.index = next;
+                Some(interrupt)
+            }
+        }
+    }
+}
+
+fn controller_width(controller: &DeviceTreeNode<'_>) -> Result<usize, DeviceTreeError> {
+    match controller.interrupt_cells() {
+        Some(width @ 1..) => Ok(width as usize),
+        _ => Err(DeviceTreeError::invalid_property()),
+    }
+}
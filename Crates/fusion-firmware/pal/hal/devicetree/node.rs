@@ -0,0 +1,386 @@
+//! Borrowed devicetree node views.
+
+use super::blob::Token;
+use super::{
+    DEVICETREE_MAX_DEPTH,
+    DeviceTree,
+    DeviceTreeError,
+    DeviceTreeInterruptIter,
+    DeviceTreeProperty,
+    DeviceTreeRanges,
+    DeviceTreeRegIter,
+    DeviceTreeStringIter,
+};
+
+/// `#address-cells` assumed when a bus node does not say.
+pub const DEVICETREE_DEFAULT_ADDRESS_CELLS: u32 = 2;
+/// `#size-cells` assumed when a bus node does not say.
+pub const DEVICETREE_DEFAULT_SIZE_CELLS: u32 = 1;
+
+/// One node inside a validated devicetree.
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceTreeNode<'a> {
+    tree: DeviceTree<'a>,
+    offset: usize,
+    parent: Option<usize>,
+}
+
+impl<'a> DeviceTreeNode<'a> {
+    pub(crate) const fn new(tree: DeviceTree<'a>, offset: usize, parent: Option<usize>) -> Self {
+        Self {
+            tree,
+            offset,
+            parent,
+        }
+    }
+
+    /// Returns the tree this node belongs to.
+    #[must_use]
+    pub const fn tree(&self) -> DeviceTree<'a> {
+        self.tree
+    }
+
+    /// Returns the node's offset inside the structure block.
+    ///
+    /// Offsets are stable for the lifetime of the blob and make cheap node identities.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the full node name, including any unit address. The root's name is empty.
+    #[must_use]
+    pub fn name(&self) -> &'a str {
+        match self.tree.token(self.offset) {
+            Some(Token::BeginNode { name, .. }) => name,
+            _ => "",
+        }
+    }
+
+    /// Returns the node name without its unit address.
+    #[must_use]
+    pub fn base_name(&self) -> &'a str {
+        let name = self.name();
+        name.split_once('@').map_or(name, |(base, _)| base)
+    }
+
+    /// Returns the unit address text after `@`, if any.
+    #[must_use]
+    pub fn unit_address(&self) -> Option<&'a str> {
+        self.name().split_once('@').map(|(_, unit)| unit)
+    }
+
+    /// Returns whether this is the root node.
+    #[must_use]
+    pub const fn is_root(&self) -> bool {
+        self.parent.is_none()
+    }
+
+    /// Returns the parent node, unless this is the root.
+    #[must_use]
+    pub fn parent(&self) -> Option<Self> {
+        let offset = self.parent?;
+        Some(Self::new(
+            self.tree,
+            offset,
+            self.tree.parent_offset(offset),
+        ))
+    }
+
+    /// Returns the node's properties in blob order.
+    #[must_use]
+    pub fn properties(&self) -> DeviceTreePropertyIter<'a> {
+        DeviceTreePropertyIter {
+            tree: self.tree,
+            offset: self.body(),
+        }
+    }
+
+    /// Looks up one property by name.
+    #[must_use]
+    pub fn property(&self, name: &str) -> Option<DeviceTreeProperty<'a>> {
+        self.properties().find(|property| property.name() == name)
+    }
+
+    /// Returns the node's direct children in blob order.
+    #[must_use]
+    pub fn children(&self) -> DeviceTreeChildIter<'a> {
+        DeviceTreeChildIter {
+            tree: self.tree,
+            offset: self.body(),
+            parent: self.offset,
+        }
+    }
+
+    /// Looks up one direct child by name.
+    ///
+    /// `name` may omit the unit address; the first child whose base name matches is returned.
+    #[must_use]
+    pub fn child(&self, name: &str) -> Option<Self> {
+        let mut fallback = None;
+        for child in self.children() {
+            if child.name() == name {
+                return Some(child);
+            }
+            if fallback.is_none() && !name.contains('@') && child.base_name() == name {
+                fallback = Some(child);
+            }
+        }
+        fallback
+    }
+
+    /// Returns the node's phandle, if it declares one.
+    #[must_use]
+    pub fn phandle(&self) -> Option<u32> {
+        self.property("phandle")
+            .or_else(|| self.property("linux,phandle"))
+            .and_then(|property| property.as_u32())
+    }
+
+    /// Returns the node's `compatible` list, most specific entry first.
+    #[must_use]
+    pub fn compatible(&self) -> DeviceTreeStringIter<'a> {
+        self.property("compatible")
+            .unwrap_or(DeviceTreeProperty::new("compatible", &[]))
+            .strings()
+    }
+
+    /// Returns whether any `compatible` entry equals `compatible`.
+    #[must_use]
+    pub fn is_compatible(&self, compatible: &str) -> bool {
+        self.compatible().any(|entry| entry == compatible)
+    }
+
+    /// Returns the `device_type` string, if present.
+    #[must_use]
+    pub fn device_type(&self) -> Option<&'a str> {
+        self.property("device_type")?.as_str()
+    }
+
+    /// Returns whether the node is usable, per its `status` property.
+    ///
+    /// A missing `status` means `"okay"`; the legacy spelling `"ok"` is also accepted.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.property("status")
+            .is_none_or(|status| matches!(status.as_str(), Some("okay" | "ok")))
+    }
+
+    /// Returns the `#address-cells` this node imposes on its children.
+    #[must_use]
+    pub fn address_cells(&self) -> u32 {
+        self.property("#address-cells")
+            .and_then(|property| property.as_u32())
+            .unwrap_or(DEVICETREE_DEFAULT_ADDRESS_CELLS)
+    }
+
+    /// Returns the `#size-cells` this node imposes on its children.
+    #[must_use]
+    pub fn size_cells(&self) -> u32 {
+        self.property("#size-cells")
+            .and_then(|property| property.as_u32())
+            .unwrap_or(DEVICETREE_DEFAULT_SIZE_CELLS)
+    }
+
+    /// Returns the `#interrupt-cells` of this node, if it is an interrupt controller or nexus.
+    #[must_use]
+    pub fn interrupt_cells(&self) -> Option<u32> {
+        self.property("#interrupt-cells")?.as_u32()
+    }
+
+    /// Returns whether this node declares itself an interrupt controller.
+    #[must_use]
+    pub fn is_interrupt_controller(&self) -> bool {
+        self.property("interrupt-controller").is_some()
+    }
+
+    /// Decodes the node's `reg` entries in its parent's address space.
+    ///
+    /// A node without `reg` yields no entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeviceTreeError::invalid_property`] when the value does not divide into the
+    /// parent's `#address-cells` plus `#size-cells`, or either width is out of range.
+    pub fn reg(&self) -> Result<DeviceTreeRegIter<'a>, DeviceTreeError> {
+        let Some(reg) = self.property("reg") else {
+            return Ok(DeviceTreeRegIter::default());
+        };
+        let parent = self
+            .parent()
+            .ok_or_else(DeviceTreeError::invalid_property)?;
+        DeviceTreeRegIter::new(reg.cells()?, parent.address_cells(), parent.size_cells())
+    }
+
+    /// Decodes this bus node's `ranges`, if it has any.
+    ///
+    /// `None` means the bus address space is not mapped into its parent at all.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeviceTreeError::invalid_property`] when the entries do not divide evenly.
+    pub fn ranges(&self) -> Result<Option<DeviceTreeRanges<'a>>, DeviceTreeError> {
+        let Some(ranges) = self.property("ranges") else {
+            return Ok(None);
+        };
+        let parent_address_cells = self
+            .parent()
+            .map_or(DEVICETREE_DEFAULT_ADDRESS_CELLS, |parent| {
+                parent.address_cells()
+            });
+        DeviceTreeRanges::new(
+            ranges.cells()?,
+            self.address_cells(),
+            parent_address_cells,
+            self.size_cells(),
+        )
+        .map(Some)
+    }
+
+    /// Translates one address from this node's `reg` space into the CPU address space.
+    ///
+    /// Returns `Ok(None)` when some ancestor bus has no `ranges` or no window covers the
+    /// address, which is the honest answer for buses that are not memory-mapped.
+    ///
+    /// # Errors
+    ///
+    /// Returns one error when an ancestor's `ranges` property is malformed.
+    pub fn translate_address(&self, address: u128) -> Result<Option<u128>, DeviceTreeError> {
+        let mut address = address;
+        let mut bus = self.parent();
+        let mut hops = 0;
+        while let Some(node) = bus {
+            let Some(grandparent) = node.parent() else {
+                return Ok(Some(address));
+            };
+            let Some(ranges) = node.ranges()? else {
+                return Ok(None);
+            };
+            let Some(translated) = ranges.translate(address) else {
+                return Ok(None);
+            };
+            address = translated;
+            bus = Some(grandparent);
+            hops += 1;
+            if hops > DEVICETREE_MAX_DEPTH {
+                return Err(DeviceTreeError::invalid_layout());
+            }
+        }
+        Ok(Some(address))
+    }
+
+    /// Resolves the node's interrupt parent.
+    ///
+    /// Follows `interrupt-parent` when present and the tree parent otherwise, until one node
+    /// with `#interrupt-cells` is reached, as Section 2.4.1 describes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeviceTreeError::unresolved_phandle`] when an `interrupt-parent` phandle does
+    /// not resolve or the chain never reaches an interrupt domain.
+    pub fn interrupt_parent(&self) -> Result<Option<Self>, DeviceTreeError> {
+        let mut current = *self;
+        for _ in 0..DEVICETREE_MAX_DEPTH * 2 {
+            let next = match current.property("interrupt-parent") {
+                Some(property) => {
+                    let phandle = property
+                        .as_u32()
+                        .ok_or_else(DeviceTreeError::invalid_property)?;
+                    Some(
+                        self.tree
+                            .node_by_phandle(phandle)
+                            .ok_or_else(DeviceTreeError::unresolved_phandle)?,
+                    )
+                }
+                None => current.parent(),
+            };
+            let Some(next) = next else {
+                return Ok(None);
+            };
+            if next.interrupt_cells().is_some() {
+                return Ok(Some(next));
+            }
+            current = next;
+        }
+        Err(DeviceTreeError::unresolved_phandle())
+    }
+
+    /// Decodes the node's interrupt specifiers.
+    ///
+    /// `interrupts-extended` wins over `interrupts` when both are present. A node without
+    /// either yields no entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns one error when a controller phandle does not resolve, a controller lacks a
+    /// usable `#interrupt-cells`, or the value does not divide into whole specifiers.
+    pub fn interrupts(&self) -> Result<DeviceTreeInterruptIter<'a>, DeviceTreeError> {
+        if let Some(extended) = self.property("interrupts-extended") {
+            return DeviceTreeInterruptIter::extended(self.tree, extended.cells()?);
+        }
+        let Some(interrupts) = self.property("interrupts") else {
+            return Ok(DeviceTreeInterruptIter::empty());
+        };
+        let controller = self
+            .interrupt_parent()?
+            .ok_or_else(DeviceTreeError::unresolved_phandle)?;
+        DeviceTreeInterruptIter::inherited(controller, interrupts.cells()?)
+    }
+
+    fn body(&self) -> usize {
+        match self.tree.token(self.offset) {
+            Some(Token::BeginNode { next, .. }) => next,
+            _ => self.offset,
+        }
+    }
+}
+
+/// Iterator over the properties of one node.
+#[derive(Clone, Debug)]
+pub struct DeviceTreePropertyIter<'a> {
+    tree: DeviceTree<'a>,
+    offset: usize,
+}
+
+impl<'a> Iterator for DeviceTreePropertyIter<'a> {
+    type Item = DeviceTreeProperty<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.tree.token(self.offset)? {
+                Token::Prop { property, next } => {
+                    self.offset = next;
+                    return Some(property);
+                }
+                Token::Nop { next } => self.offset = next,
+                Token::BeginNode { .. } | Token::EndNode { .. } | Token::End => return None,
+            }
+        }
+    }
+}
+
+/// Iterator over the direct children of one node.
+#[derive(Clone, Debug)]
+pub struct DeviceTreeChildIter<'a> {
+    tree: DeviceTree<'a>,
+    offset: usize,
+    parent: usize,
+}
+
+impl<'a> Iterator for DeviceTreeChildIter<'a> {
+    type Item = DeviceTreeNode<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.tree.token(self.offset)? {
+                Token::BeginNode { .. } => {
+                    let child = DeviceTreeNode::new(self.tree, self.offset, Some(self.parent));
+                    self.offset = self.tree.skip_node(self.offset)?;
+                    return Some(child);
+                }
+                Token::Prop { next, .. } | Token::Nop { next } => self.offset = next,
+                Token::EndNode { .. } | Token::End => return None,
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Devicetree property values and cell decoding.
+//!
+//! Property values are opaque byte strings on the wire. The accessors here apply the encodings
+//! from Devicetree Specification v0.4, Section 2.2.4: big-endian `<u32>` cells, `<u64>` as two
+//! cells, NUL-terminated `<string>`, and `<stringlist>` as back-to-back strings.
+
+use core::str;
+
+use super::{
+    DeviceTreeError,
+    read_be_u32,
+    read_be_u64,
+};
+
+/// Largest cell count one decoded address or size may span.
+pub const DEVICETREE_MAX_CELLS: u32 = 4;
+
+/// One borrowed devicetree property.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DeviceTreeProperty<'a> {
+    name: &'a str,
+    value: &'a [u8],
+}
+
+impl<'a> DeviceTreeProperty<'a> {
+    pub(crate) const fn new(name: &'a str, value: &'a [u8]) -> Self {
+        Self { name, value }
+    }
+
+    /// Returns the property name.
+    #[must_use]
+    pub const fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns the raw property value.
+    #[must_use]
+    pub const fn value(&self) -> &'a [u8] {
+        self.value
+    }
+
+    /// Returns whether the property carries no value, as boolean properties do.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Returns the value as one `<u32>` when it is exactly one cell wide.
+    #[must_use]
+    pub fn as_u32(&self) -> Option<u32> {
+        (self.value.len() == 4)
+            .then(|| read_be_u32(self.value, 0))
+            .flatten()
+    }
+
+    /// Returns the value as one `<u64>` when it is exactly two cells wide.
+    #[must_use]
+    pub fn as_u64(&self) -> Option<u64> {
+        (self.value.len() == 8)
+            .then(|| read_be_u64(self.value, 0))
+            .flatten()
+    }
+
+    /// Returns the value as one `<string>` when it holds exactly one NUL-terminated string.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&'a str> {
+        let (last, body) = self.value.split_last()?;
+        if *last != 0 || body.contains(&0) {
+            return None;
+        }
+        str::from_utf8(body).ok()
+    }
+
+    /// Returns the value as one `<stringlist>`.
+    ///
+    /// Malformed lists (missing terminator or invalid UTF-8) yield nothing past the first bad
+    /// entry.
+    #[must_use]
+    pub const fn strings(&self) -> DeviceTreeStringIter<'a> {
+        DeviceTreeStringIter { bytes: self.value }
+    }
+
+    /// Returns the value as an array of `<u32>` cells.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeviceTreeError::invalid_property`] when the value is not a whole number of
+    /// cells.
+    pub const fn cells(&self) -> Result<DeviceTreeCells<'a>, DeviceTreeError> {
+        if !self.value.len().is_multiple_of(4) {
+            return Err(DeviceTreeError::invalid_property());
+        }
+        Ok(DeviceTreeCells { bytes: self.value })
+    }
+}
+
+/// Iterator over the entries of one `<stringlist>` value.
+#[derive(Clone, Debug)]
+pub struct DeviceTreeStringIter<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for DeviceTreeStringIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.bytes.iter().position(|byte| *byte == 0)?;
+        let entry = str::from_utf8(&self.bytes[..len]).ok();
+        self.bytes = if entry.is_some() {
+            &self.bytes[len + 1..]
+        } else {
+            &[]
+        };
+        entry
+    }
+}
+
+/// Borrowed run of big-endian `<u32>` cells.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DeviceTreeCells<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> DeviceTreeCells<'a> {
+    /// Returns the number of cells.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.bytes.len() / 4
+    }
+
+    /// Returns whether the run holds no cells.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns one cell by index.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<u32> {
+        read_be_u32(self.bytes, index.checked_mul(4)?)
+    }
+
+    /// Reads `count` consecutive cells starting at `index` as one big-endian number.
+    ///
+    /// Returns `None` when the run is too short or `count` exceeds [`DEVICETREE_MAX_CELLS`].
+    #[must_use]
+    pub fn read(&self, index: usize, count: u32) -> Option<u128> {
+        if count > DEVICETREE_MAX_CELLS {
+            return None;
+        }
+        (0..count as usize).try_fold(0_u128, |value, cell| {
+            Some((value << 32) | u128::from(self.get(index + cell)?))
+        })
+    }
+
+    /// Returns one sub-run of `count` cells starting at `index`.
+    #[must_use]
+    pub fn slice(&self, index: usize, count: usize) -> Option<Self> {
+        let start = index.checked_mul(4)?;
+        let end = start.checked_add(count.checked_mul(4)?)?;
+        Some(Self {
+            bytes: self.bytes.get(start..end)?,
+        })
+    }
+
+    /// Returns the cells in order.
+    pub fn iter(&self) -> impl Iterator<Item = u32> + use<'a> {
+        self.bytes
+            .chunks_exact(4)
+            .map(|cell| u32::from_be_bytes([cell[0], cell[1], cell[2], cell[3]]))
+    }
+}
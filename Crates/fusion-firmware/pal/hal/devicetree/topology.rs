@@ -0,0 +1,568 @@
+//! Devicetree-backed firmware topology records.
+//!
+//! This is the devicetree counterpart of what MADT, MCFG, and the ACPI realization path hand
+//! upward: CPU-visible memory and reservation windows, processors, interrupt controllers, PCI
+//! ECAM windows, and driver bindings. Every query writes into caller-provided storage and
+//! reports how many records it produced, so the whole layer stays allocation-free.
+
+use crate::contract::firmware::topology::{
+    DeviceTreeTopologyContract,
+    DeviceTreeTopologySupport,
+};
+use crate::pal::hal::acpi::McfgAllocation;
+use fusion_hal::contract::drivers::driver::DriverBindingSource;
+
+use super::{
+    DeviceTree,
+    DeviceTreeError,
+    DeviceTreeNode,
+    DeviceTreeRegion,
+};
+
+/// `compatible` string of the generic PCI ECAM host bridge binding.
+pub const DEVICETREE_PCI_ECAM_COMPATIBLE: &str = "pci-host-ecam-generic";
+
+/// One processor described under `/cpus`.
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceTreeProcessor<'a> {
+    /// The CPU node itself.
+    pub node: DeviceTreeNode<'a>,
+    /// Hardware ID from the first `reg` entry (MPIDR, hart ID, APIC ID, ...).
+    pub hardware_id: u128,
+    /// Whether the node is usable per `status`.
+    pub enabled: bool,
+}
+
+/// One interrupt controller node.
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceTreeInterruptController<'a> {
+    /// The controller node itself.
+    pub node: DeviceTreeNode<'a>,
+    /// Phandle other nodes use to reference the controller, if any.
+    pub phandle: Option<u32>,
+    /// Width of the controller's interrupt specifiers.
+    pub interrupt_cells: u32,
+    /// Most specific `compatible` entry, if any.
+    pub compatible: Option<&'a str>,
+    /// First register window translated into the CPU address space, if memory-mapped.
+    pub region: Option<DeviceTreeRegion>,
+}
+
+/// One devicetree-sourced driver binding candidate.
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceTreeBinding<'a> {
+    /// The device node to bind.
+    pub node: DeviceTreeNode<'a>,
+    /// `compatible` entry the binding was matched on.
+    pub compatible: &'a str,
+    /// Where the binding came from; always [`DriverBindingSource::Devicetree`].
+    pub source: DriverBindingSource,
+}
+
+/// Topology view over one validated devicetree blob.
+#[derive(Clone, Copy, Debug)]
+pub struct DeviceTreeTopology<'a> {
+    tree: DeviceTree<'a>,
+}
+
+impl<'a> DeviceTreeTopology<'a> {
+    /// Creates one topology view over a validated tree.
+    #[must_use]
+    pub const fn new(tree: DeviceTree<'a>) -> Self {
+        Self { tree }
+    }
+
+    /// Parses one blob and wraps it in a topology view.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error [`DeviceTree::parse`] reports.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, DeviceTreeError> {
+        DeviceTree::parse(bytes).map(Self::new)
+    }
+
+    /// Returns the underlying tree.
+    #[must_use]
+    pub const fn tree(&self) -> DeviceTree<'a> {
+        self.tree
+    }
+
+    /// Writes the CPU-visible RAM windows from every enabled `device_type = "memory"` node.
+    ///
+    /// # Errors
+    ///
+    /// Returns one decoding error or [`DeviceTreeError::resource_exhausted`] when `out` is too
+    /// small.
+    pub fn memory_regions(&self, out: &mut [DeviceTreeRegion]) -> Result<usize, DeviceTreeError> {
+        let mut sink = Sink::new(out);
+        for node in self.tree.nodes() {
+            if node.device_type() == Some("memory") && node.is_enabled() {
+                push_translated_regions(&node, &mut sink)?;
+            }
+        }
+        Ok(sink.len)
+    }
+
+    /// Writes every reserved window: the memory reservation block, then each
+    /// `/reserved-memory` child with a `reg`.
+    ///
+    /// # Errors
+    ///
+    /// Returns one decoding error or [`DeviceTreeError::resource_exhausted`] when `out` is too
+    /// small.
+    pub fn reserved_regions(&self, out: &mut [DeviceTreeRegion]) -> Result<usize, DeviceTreeError> {
+        let mut sink = Sink::new(out);
+        for reservation in self.tree.memory_reservations() {
+            sink.push(DeviceTreeRegion {
+                address: u128::from(reservation.address),
+                size: reservation.size,
+            })?;
+        }
+        if let Some(reserved) = self.tree.root().child("reserved-memory") {
+            for child in reserved.children().filter(DeviceTreeNode::is_enabled) {
+                push_translated_regions(&child, &mut sink)?;
+            }
+        }
+        Ok(sink.len)
+    }
+
+    /// Writes every `device_type = "cpu"` child of `/cpus`.
+    ///
+    /// # Errors
+    ///
+    /// Returns one decoding error or [`DeviceTreeError::resource_exhausted`] when `out` is too
+    /// small.
+    pub fn processors(
+        &self,
+        out: &mut [DeviceTreeProcessor<'a>],
+    ) -> Result<usize, DeviceTreeError> {
+        let mut sink = Sink::new(out);
+        let Some(cpus) = self.tree.root().child("cpus") else {
+            return Ok(0);
+        };
+        for node in cpus.children() {
+            if node.device_type() != Some("cpu") {
+                continue;
+            }
+            let hardware_id = node
+                .reg()?
+                .next()
+                .ok_or_else(DeviceTreeError::invalid_property)?
+                .address;
+            sink.push(DeviceTreeProcessor {
+                node,
+                hardware_id,
+                enabled: node.is_enabled(),
+            })?;
+        }
+        Ok(sink.len)
+    }
+
+    /// Writes every node carrying the `interrupt-controller` property.
+    ///
+    /// # Errors
+    ///
+    /// Returns one decoding error or [`DeviceTreeError::resource_exhausted`] when `out` is too
+    /// small.
+    pub fn interrupt_controllers(
+        &self,
+        out: &mut [DeviceTreeInterruptController<'a>],
+    ) -> Result<usize, DeviceTreeError> {
+        let mut sink = Sink::new(out);
+        for node in self
+            .tree
+            .nodes()
+            .filter(DeviceTreeNode::is_interrupt_controller)
+        {
+            let interrupt_cells = node
+                .interrupt_cells()
+                .ok_or_else(DeviceTreeError::invalid_property)?;
+            let region = match node.reg()?.next() {
+                Some(region) => node
+                    .translate_address(region.address)?
+                    .map(|address| DeviceTreeRegion { address, ..region }),
+                None => None,
+            };
+            sink.push(DeviceTreeInterruptController {
+                node,
+                phandle: node.phandle(),
+                interrupt_cells,
+                compatible: node.compatible().next(),
+                region,
+            })?;
+        }
+        Ok(sink.len)
+    }
+
+    /// Writes one MCFG-shaped allocation per enabled generic ECAM host bridge.
+    ///
+    /// The bus range comes from `bus-range` (default `0..=255`) and the segment from
+    /// `linux,pci-domain` (default 0), so PCI code can consume devicetree and ACPI platforms
+    /// through the same record.
+    ///
+    /// # Errors
+    ///
+    /// Returns one decoding error, [`DeviceTreeError::invalid_property`] when the window is not
+    /// CPU-addressable or the bus numbers are out of range, or
+    /// [`DeviceTreeError::resource_exhausted`] when `out` is too small.
+    pub fn pci_ecam_allocations(
+        &self,
+        out: &mut [McfgAllocation],
+    ) -> Result<usize, DeviceTreeError> {
+        let mut sink = Sink::new(out);
+        for node in self.tree.compatible_nodes(DEVICETREE_PCI_ECAM_COMPATIBLE) {
+            if !node.is_enabled() {
+                continue;
+            }
+            let invalid = DeviceTreeError::invalid_property;
+            let window = node.reg()?.next().ok_or_else(invalid)?;
+            let base_address = node
+                .translate_address(window.address)?
+                .and_then(|address| u64::try_from(address).ok())
+                .ok_or_else(invalid)?;
+            let (start_bus, end_bus) = match node.property("bus-range") {
+                Some(range) => {
+                    let cells = range.cells()?;
+                    if cells.len() != 2 {
+                        return Err(invalid());
+                    }
+                    let bus = |index| {
+                        cells
+                            .get(index)
+                            .and_then(|bus| u8::try_from(bus).ok())
+                            .ok_or_else(invalid)
+                    };
+                    (bus(0)?, bus(1)?)
+                }
+                None => (0, u8::MAX),
+            };
+            if start_bus > end_bus {
+                return Err(invalid());
+            }
+            let segment_group = match node.property("linux,pci-domain") {
+                Some(domain) => domain
+                    .as_u32()
+                    .and_then(|domain| u16::try_from(domain).ok())
+                    .ok_or_else(invalid)?,
+                None => 0,
+            };
+            sink.push(McfgAllocation {
+                base_address,
+                segment_group,
+                start_bus,
+                end_bus,
+            })?;
+        }
+        Ok(sink.len)
+    }
+
+    /// Writes one binding per enabled node that declares `compatible`, keyed on its most
+    /// specific entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeviceTreeError::resource_exhausted`] when `out` is too small.
+    pub fn bindings(&self, out: &mut [DeviceTreeBinding<'a>]) -> Result<usize, DeviceTreeError> {
+        let mut sink = Sink::new(out);
+        for node in self.tree.nodes().filter(DeviceTreeNode::is_enabled) {
+            if let Some(compatible) = node.compatible().next() {
+                sink.push(binding(node, compatible))?;
+            }
+        }
+        Ok(sink.len)
+    }
+
+    /// Writes one binding per enabled node whose `compatible` list names `compatible`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeviceTreeError::resource_exhausted`] when `out` is too small.
+    pub fn bindings_for(
+        &self,
+        compatible: &str,
+        out: &mut [DeviceTreeBinding<'a>],
+    ) -> Result<usize, DeviceTreeError> {
+        let mut sink = Sink::new(out);
+        for node in self.tree.compatible_nodes(compatible) {
+            if !node.is_enabled() {
+                continue;
+            }
+            if let Some(entry) = node.compatible().find(|entry| *entry == compatible) {
+                sink.push(binding(node, entry))?;
+            }
+        }
+        Ok(sink.len)
+    }
+}
+
+impl DeviceTreeTopologyContract for DeviceTreeTopology<'_> {
+    fn devicetree_topology_support(&self) -> DeviceTreeTopologySupport {
+        DeviceTreeTopologySupport::StaticBlob
+    }
+}
+
+const fn binding<'a>(node: DeviceTreeNode<'a>, compatible: &'a str) -> DeviceTreeBinding<'a> {
+    DeviceTreeBinding {
+        node,
+        compatible,
+        source: DriverBindingSource::Devicetree,
+    }
+}
+
+fn push_translated_regions(
+    node: &DeviceTreeNode<'_>,
+    sink: &mut Sink<'_, DeviceTreeRegion>,
+) -> Result<(), DeviceTreeError> {
+    for region in node.reg()? {
+        if let Some(address) = node.translate_address(region.address)? {
+            sink.push(DeviceTreeRegion { address, ..region })?;
+        }
+    }
+    Ok(())
+}
+
+struct Sink<'o, T> {
+    out: &'o mut [T],
+    len: usize,
+}
+
+impl<'o, T> Sink<'o, T> {
+    const fn new(out: &'o mut [T]) -> Self {
+        Self { out, len: 0 }
+    }
+
+    fn push(&mut self, value: T) -> Result<(), DeviceTreeError> {
+        let slot = self
+            .out
+            .get_mut(self.len)
+            .ok_or_else(DeviceTreeError::resource_exhausted)?;
+        *slot = value;
+        self.len += 1;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::pal::hal::devicetree::DeviceTreeErrorKind;
+
+    const AARCH64_VIRT: &[u8] =
+        include_bytes!("../../../tests/fixtures/devicetree/aarch64-virt.dtb");
+    const RISCV_VIRT: &[u8] = include_bytes!("../../../tests/fixtures/devicetree/riscv-virt.dtb");
+
+    fn regions(topology: &DeviceTreeTopology<'_>, reserved: bool) -> Vec<(u128, u64)> {
+        let mut out = [DeviceTreeRegion {
+            address: 0,
+            size: 0,
+        }; 8];
+        let count = if reserved {
+            topology.reserved_regions(&mut out)
+        } else {
+            topology.memory_regions(&mut out)
+        }
+        .expect("regions should decode");
+        out[..count]
+            .iter()
+            .map(|region| (region.address, region.size))
+            .collect()
+    }
+
+    #[test]
+    fn aarch64_fixture_yields_memory_cpus_and_reservations() {
+        let topology = DeviceTreeTopology::parse(AARCH64_VIRT).expect("fixture should parse");
+        assert_eq!(
+            topology.devicetree_topology_support(),
+            DeviceTreeTopologySupport::StaticBlob
+        );
+        assert_eq!(topology.tree().boot_cpu_physical_id(), 0);
+        assert_eq!(
+            regions(&topology, false),
+            [(0x4000_0000, 0x8000_0000), (0x1_0000_0000, 0x4000_0000)]
+        );
+        assert_eq!(
+            regions(&topology, true),
+            [(0x4800_0000, 0x10_0000), (0x5000_0000, 0x0200_0000)]
+        );
+
+        let placeholder = DeviceTreeProcessor {
+            node: topology.tree().root(),
+            hardware_id: 0,
+            enabled: false,
+        };
+        let mut cpus = [placeholder; 4];
+        let count = topology.processors(&mut cpus).expect("cpus should decode");
+        let cpus: Vec<_> = cpus[..count]
+            .iter()
+            .map(|cpu| (cpu.hardware_id, cpu.enabled))
+            .collect();
+        assert_eq!(cpus, [(0, true), (1, true), (0x100, false)]);
+    }
+
+    #[test]
+    fn aarch64_fixture_translates_soc_bus_and_gic_interrupts() {
+        let topology = DeviceTreeTopology::parse(AARCH64_VIRT).expect("fixture should parse");
+        let tree = topology.tree();
+
+        let uart = tree.find_node("serial0").expect("serial0 alias");
+        assert_eq!(uart.name(), "pl011@9000");
+        let region = uart.reg().expect("reg").next().expect("one reg entry");
+        assert_eq!((region.address, region.size), (0x9000, 0x1000));
+        assert_eq!(
+            uart.translate_address(region.address),
+            Ok(Some(0x0900_9000))
+        );
+
+        let interrupts: Vec<_> = uart
+            .interrupts()
+            .expect("interrupts")
+            .map(|irq| {
+                (
+                    irq.controller.name(),
+                    irq.specifier.iter().collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        assert_eq!(interrupts, [("intc@8000000", vec![0, 1, 4])]);
+
+        let placeholder = DeviceTreeInterruptController {
+            node: tree.root(),
+            phandle: None,
+            interrupt_cells: 0,
+            compatible: None,
+            region: None,
+        };
+        let mut controllers = [placeholder; 2];
+        let count = topology
+            .interrupt_controllers(&mut controllers)
+            .expect("controllers should decode");
+        assert_eq!(count, 1);
+        assert_eq!(controllers[0].compatible, Some("arm,gic-v3"));
+        assert_eq!(controllers[0].interrupt_cells, 3);
+        assert_eq!(
+            controllers[0].region,
+            Some(DeviceTreeRegion {
+                address: 0x0800_0000,
+                size: 0x1_0000,
+            })
+        );
+    }
+
+    #[test]
+    fn aarch64_fixture_yields_pci_ecam_and_bindings() {
+        let topology = DeviceTreeTopology::parse(AARCH64_VIRT).expect("fixture should parse");
+
+        let mut ecam = [McfgAllocation {
+            base_address: 0,
+            segment_group: 0,
+            start_bus: 0,
+            end_bus: 0,
+        }; 2];
+        assert_eq!(topology.pci_ecam_allocations(&mut ecam), Ok(1));
+        assert_eq!(
+            ecam[0],
+            McfgAllocation {
+                base_address: 0x40_1000_0000,
+                segment_group: 1,
+                start_bus: 0,
+                end_bus: 0x0f,
+            }
+        );
+
+        let placeholder = binding(topology.tree().root(), "");
+        let mut bindings = [placeholder; 16];
+        let count = topology.bindings(&mut bindings).expect("bindings");
+        let compatibles: Vec<_> = bindings[..count]
+            .iter()
+            .map(|binding| binding.compatible)
+            .collect();
+        assert_eq!(
+            compatibles,
+            [
+                "linux,dummy-virt",
+                "arm,cortex-a57",
+                "arm,cortex-a57",
+                "simple-bus",
+                "arm,pl011",
+                "arm,gic-v3",
+                "pci-host-ecam-generic",
+                "shared-dma-pool",
+            ]
+        );
+        assert!(
+            bindings[..count]
+                .iter()
+                .all(|binding| binding.source == DriverBindingSource::Devicetree)
+        );
+
+        let count = topology
+            .bindings_for("arm,primecell", &mut bindings)
+            .expect("bindings_for");
+        assert_eq!(count, 1);
+        assert_eq!(bindings[0].node.name(), "pl011@9000");
+
+        assert_eq!(
+            topology
+                .bindings(&mut bindings[..2])
+                .map_err(DeviceTreeError::kind),
+            Err(DeviceTreeErrorKind::ResourceExhausted)
+        );
+    }
+
+    #[test]
+    fn riscv_fixture_resolves_interrupts_extended_and_inherited_parents() {
+        let topology = DeviceTreeTopology::parse(RISCV_VIRT).expect("fixture should parse");
+        let tree = topology.tree();
+
+        let plic = tree.find_node("/soc/plic@c000000").expect("plic");
+        let contexts: Vec<_> = plic
+            .interrupts()
+            .expect("interrupts-extended")
+            .map(|irq| {
+                (
+                    irq.controller.parent().map(|cpu| cpu.name()),
+                    irq.specifier.get(0),
+                )
+            })
+            .collect();
+        assert_eq!(
+            contexts,
+            [
+                (Some("cpu@0"), Some(11)),
+                (Some("cpu@0"), Some(9)),
+                (Some("cpu@1"), Some(11)),
+                (Some("cpu@1"), Some(9)),
+            ]
+        );
+
+        let uart = tree.find_node("/soc/serial").expect("uart");
+        let irq = uart
+            .interrupts()
+            .expect("inherited interrupt parent")
+            .next()
+            .expect("one interrupt");
+        assert_eq!(irq.controller.offset(), plic.offset());
+        assert_eq!(irq.specifier.get(0), Some(10));
+
+        let mut regions = [DeviceTreeRegion {
+            address: 0,
+            size: 0,
+        }; 1];
+        assert_eq!(topology.memory_regions(&mut regions), Ok(1));
+        assert_eq!(regions[0].address_u64(), Some(0x8000_0000));
+
+        let broken = tree.find_node("/soc/broken").expect("broken node");
+        assert_eq!(
+            broken
+                .interrupts()
+                .map(|_| ())
+                .map_err(DeviceTreeError::kind),
+            Err(DeviceTreeErrorKind::UnresolvedPhandle)
+        );
+        let misaligned = tree.find_node("/soc/misaligned").expect("misaligned node");
+        assert_eq!(
+            misaligned.reg().map(|_| ()).map_err(DeviceTreeError::kind),
+            Err(DeviceTreeErrorKind::InvalidProperty)
+        );
+    }
+}
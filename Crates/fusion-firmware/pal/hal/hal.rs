@@ -12,6 +12,7 @@ pub struct SelectedPalLane;
 
 #[path = "acpi/acpi.rs"]
 pub mod acpi;
+#[path = "devicetree/devicetree.rs"]
 pub mod devicetree;
 pub mod hardware;
 pub mod runtime;
@@ -7,7 +7,8 @@
 //! - and activate AML lifecycle against a resolved namespace.
 //!
 //! What it did not know how to do was the boring, necessary middle step:
-//! - start from an `XSDT`,
+//! - start from an `RSDP` and pick its `XSDT`, or its `RSDT` on firmware too old
+//!   to carry one,
 //! - resolve table pointers through one firmware-owned mapping surface,
 //! - find the `FADT`,
 //! - follow it to the `DSDT`,
@@ -32,11 +33,15 @@ use crate::pal::hal::acpi::{
     AcpiErrorKind,
     AcpiPlatformFingerprint,
     AcpiRealizationError,
+    AcpiRootTable,
+    AcpiRootTableAddress,
     AcpiSignature,
     AcpiTableView,
     Dsdt,
     Fadt,
     RealizedAcpiPlatformWithAml,
+    Rsdp,
+    Rsdt,
     Xsdt,
     realize_platform_from_definition_tables_with_aml,
 };
@@ -57,7 +62,7 @@ pub trait AcpiPhysicalTableResolver {
 /// Borrowed discovery result for the ACPI definition-table subset needed by AML bring-up.
 #[derive(Clone, Copy, Debug)]
 pub struct AcpiDefinitionTableDiscovery<'a> {
-    xsdt: Xsdt<'a>,
+    root: AcpiRootTable<'a>,
     fadt: Fadt<'a>,
     dsdt: Dsdt<'a>,
     secondary_definition_tables: &'a [AcpiTableView<'a>],
@@ -65,8 +70,16 @@ pub struct AcpiDefinitionTableDiscovery<'a> {
 
 impl<'a> AcpiDefinitionTableDiscovery<'a> {
     #[must_use]
-    pub const fn xsdt(self) -> Xsdt<'a> {
-        self.xsdt
+    pub const fn root(self) -> AcpiRootTable<'a> {
+        self.root
+    }
+
+    #[must_use]
+    pub const fn xsdt(self) -> Option<Xsdt<'a>> {
+        match self.root {
+            AcpiRootTable::Xsdt(xsdt) => Some(xsdt),
+            AcpiRootTable::Rsdt(_) => None,
+        }
     }
 
     #[must_use]
@@ -169,17 +182,59 @@ impl<'a, 'tables> SecondaryDefinitionTableWriter<'a, 'tables> {
     }
 }
 
+/// Resolves the root table one validated `RSDP` selects, then discovers definition tables from
+/// it.
+///
+/// The `XSDT` is used whenever the `RSDP` carries one; the `RSDT` is only followed on firmware
+/// that publishes nothing newer.
+///
+/// # Errors
+///
+/// Returns one honest error when the `RSDP` names no root, the root table does not validate, or
+/// discovery from the root fails for any reason [`discover_definition_tables_from_root`] lists.
+pub fn discover_definition_tables_from_rsdp<'tables, R>(
+    rsdp: Rsdp,
+    resolver: &'tables R,
+    secondary_definition_table_storage: &'tables mut [MaybeUninit<AcpiTableView<'tables>>],
+) -> Result<AcpiDefinitionTableDiscovery<'tables>, AcpiRealizationError>
+where
+    R: AcpiPhysicalTableResolver,
+{
+    let root = resolve_root_table(rsdp, resolver)?;
+    discover_definition_tables_from_root(root, resolver, secondary_definition_table_storage)
+}
+
 /// Resolves `FADT`, `DSDT`, and secondary AML definition tables from one validated `XSDT`.
 ///
 /// # Errors
 ///
+/// Returns one honest error for any reason [`discover_definition_tables_from_root`] lists.
+pub fn discover_definition_tables_from_xsdt<'tables, R>(
+    xsdt: Xsdt<'tables>,
+    resolver: &'tables R,
+    secondary_definition_table_storage: &'tables mut [MaybeUninit<AcpiTableView<'tables>>],
+) -> Result<AcpiDefinitionTableDiscovery<'tables>, AcpiRealizationError>
+where
+    R: AcpiPhysicalTableResolver,
+{
+    discover_definition_tables_from_root(
+        AcpiRootTable::Xsdt(xsdt),
+        resolver,
+        secondary_definition_table_storage,
+    )
+}
+
+/// Resolves `FADT`, `DSDT`, and secondary AML definition tables from one validated root table.
+///
+/// # Errors
+///
 /// Returns one honest error when:
-/// - the `XSDT` does not lead to one valid `FADT`,
+/// - the root table does not lead to one valid `FADT`,
 /// - the `FADT` does not lead to one valid `DSDT`,
 /// - secondary definition-table storage is exhausted,
 /// - or the resolver cannot surface the pointed-to table bytes.
-pub fn discover_definition_tables_from_xsdt<'tables, R>(
-    xsdt: Xsdt<'tables>,
+pub fn discover_definition_tables_from_root<'tables, R>(
+    root: AcpiRootTable<'tables>,
     resolver: &'tables R,
     secondary_definition_table_storage: &'tables mut [MaybeUninit<AcpiTableView<'tables>>],
 ) -> Result<AcpiDefinitionTableDiscovery<'tables>, AcpiRealizationError>
@@ -190,7 +245,7 @@ where
     let mut secondary_tables =
         SecondaryDefinitionTableWriter::new(secondary_definition_table_storage);
 
-    for table_address in xsdt.entries() {
+    for table_address in root.entries() {
         let table_bytes = resolver.resolve_table_bytes(table_address)?;
         let table = AcpiTableView::parse(table_bytes).map_err(map_acpi_table_error)?;
         match table.header().signature() {
@@ -209,13 +264,36 @@ where
     let dsdt = Dsdt::parse(dsdt_bytes).map_err(map_acpi_table_error)?;
 
     Ok(AcpiDefinitionTableDiscovery {
-        xsdt,
+        root,
         fadt,
         dsdt,
         secondary_definition_tables: secondary_tables.finish(),
     })
 }
 
+/// Discovers AML definition tables from one `RSDP`, then realizes and activates the matched ACPI
+/// backend against them.
+///
+/// # Errors
+///
+/// Returns one honest error when root-table resolution or table discovery fails, AML namespace
+/// loading fails, backend verification fails, or AML lifecycle activation cannot complete
+/// cleanly.
+pub fn realize_platform_from_rsdp_with_aml<'tables, 'issues, R>(
+    fingerprint: &AcpiPlatformFingerprint,
+    rsdp: Rsdp,
+    resolver: &'tables R,
+    mut storage: AcpiAmlBringupStorage<'_, 'tables, 'issues>,
+    host: &dyn AmlRegionAccessHost,
+    runtime: &AmlRuntimeState<'_>,
+) -> Result<DiscoveredAcpiPlatformWithAml<'tables, 'issues>, AcpiRealizationError>
+where
+    R: AcpiPhysicalTableResolver,
+{
+    let root = resolve_root_table(rsdp, resolver)?;
+    realize_platform_from_root_with_aml(fingerprint, root, resolver, &mut storage, host, runtime)
+}
+
 /// Discovers AML definition tables from one `XSDT`, then realizes and activates the matched ACPI
 /// backend against them.
 ///
@@ -223,28 +301,54 @@ where
 ///
 /// Returns one honest error when table discovery fails, AML namespace loading fails, backend
 /// verification fails, or AML lifecycle activation cannot complete cleanly.
-pub fn realize_platform_from_xsdt_with_aml<'records, 'tables, 'issues, R>(
+pub fn realize_platform_from_xsdt_with_aml<'tables, 'issues, R>(
     fingerprint: &AcpiPlatformFingerprint,
     xsdt: Xsdt<'tables>,
     resolver: &'tables R,
-    storage: AcpiAmlBringupStorage<'records, 'tables, 'issues>,
+    mut storage: AcpiAmlBringupStorage<'_, 'tables, 'issues>,
+    host: &dyn AmlRegionAccessHost,
+    runtime: &AmlRuntimeState<'_>,
+) -> Result<DiscoveredAcpiPlatformWithAml<'tables, 'issues>, AcpiRealizationError>
+where
+    R: AcpiPhysicalTableResolver,
+{
+    realize_platform_from_root_with_aml(
+        fingerprint,
+        AcpiRootTable::Xsdt(xsdt),
+        resolver,
+        &mut storage,
+        host,
+        runtime,
+    )
+}
+
+fn realize_platform_from_root_with_aml<'tables, 'issues, R>(
+    fingerprint: &AcpiPlatformFingerprint,
+    root: AcpiRootTable<'tables>,
+    resolver: &'tables R,
+    storage: &mut AcpiAmlBringupStorage<'_, 'tables, 'issues>,
     host: &dyn AmlRegionAccessHost,
     runtime: &AmlRuntimeState<'_>,
 ) -> Result<DiscoveredAcpiPlatformWithAml<'tables, 'issues>, AcpiRealizationError>
 where
     R: AcpiPhysicalTableResolver,
 {
-    let definition_tables =
-        discover_definition_tables_from_xsdt(xsdt, resolver, storage.secondary_definition_tables)?;
+    // The realized platform borrows the storage for `'tables`, so each slice is moved out of the
+    // caller's storage rather than reborrowed.
+    let definition_tables = discover_definition_tables_from_root(
+        root,
+        resolver,
+        core::mem::take(&mut storage.secondary_definition_tables),
+    )?;
     let realized = realize_platform_from_definition_tables_with_aml(
         fingerprint,
         definition_tables.dsdt,
         definition_tables.secondary_definition_tables,
-        storage.definition_blocks,
-        storage.namespace_records,
+        core::mem::take(&mut storage.definition_blocks),
+        core::mem::take(&mut storage.namespace_records),
         host,
         runtime,
-        storage.verification_issues,
+        core::mem::take(&mut storage.verification_issues),
     )?;
     Ok(DiscoveredAcpiPlatformWithAml {
         definition_tables,
@@ -252,6 +356,29 @@ where
     })
 }
 
+fn resolve_root_table<R>(
+    rsdp: Rsdp,
+    resolver: &R,
+) -> Result<AcpiRootTable<'_>, AcpiRealizationError>
+where
+    R: AcpiPhysicalTableResolver,
+{
+    match rsdp.root_table_address().map_err(map_acpi_table_error)? {
+        AcpiRootTableAddress::Xsdt(address) => {
+            let bytes = resolver.resolve_table_bytes(address)?;
+            Ok(AcpiRootTable::Xsdt(
+                Xsdt::parse(bytes).map_err(map_acpi_table_error)?,
+            ))
+        }
+        AcpiRootTableAddress::Rsdt(address) => {
+            let bytes = resolver.resolve_table_bytes(u64::from(address))?;
+            Ok(AcpiRootTable::Rsdt(
+                Rsdt::parse(bytes).map_err(map_acpi_table_error)?,
+            ))
+        }
+    }
+}
+
 fn map_acpi_table_error(error: AcpiError) -> AcpiRealizationError {
     match error.kind() {
         AcpiErrorKind::Truncated
@@ -401,6 +528,70 @@ mod tests {
         );
     }
 
+    fn build_rsdt(entries: &[u32]) -> &'static [u8] {
+        let mut bytes = vec![0_u8; 36 + entries.len() * 4];
+        let length = u32::try_from(bytes.len()).expect("table should fit");
+        bytes[0..4].copy_from_slice(b"RSDT");
+        bytes[4..8].copy_from_slice(&length.to_le_bytes());
+        bytes[8] = 1;
+        bytes[10..16].copy_from_slice(b"FUSION");
+        bytes[16..24].copy_from_slice(b"HWDISCOV");
+        for (index, entry) in entries.iter().enumerate() {
+            let start = 36 + (index * 4);
+            bytes[start..start + 4].copy_from_slice(&entry.to_le_bytes());
+        }
+        let checksum =
+            (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        bytes[9] = checksum;
+        leak_boxed(bytes)
+    }
+
+    fn build_rsdp_v1(rsdt_address: u32) -> Rsdp {
+        let mut bytes = [0_u8; 20];
+        bytes[0..8].copy_from_slice(b"RSD PTR ");
+        bytes[9..15].copy_from_slice(b"FUSION");
+        bytes[16..20].copy_from_slice(&rsdt_address.to_le_bytes());
+        bytes[8] = (!bytes.iter().fold(0_u8, |sum, byte| sum.wrapping_add(*byte))).wrapping_add(1);
+        Rsdp::parse(&bytes).unwrap()
+    }
+
+    #[test]
+    fn rsdp_discovery_falls_back_to_rsdt_without_xsdt() {
+        let rsdt_address = 0x1000;
+        let fadt_address = 0x2000;
+        let dsdt_address = 0x3000;
+        let resolver = StaticAcpiTableResolver::new(&[
+            (
+                rsdt_address,
+                build_rsdt(&[u32::try_from(fadt_address).expect("FADT address fits 32 bits")]),
+            ),
+            (fadt_address, build_fadt(dsdt_address)),
+            (
+                dsdt_address,
+                build_definition_table(
+                    *b"DSDT",
+                    &[0x10, 0x08, b'\\', b'_', b'S', b'B', b'_', 0x08],
+                ),
+            ),
+        ]);
+        let mut secondary_storage = [MaybeUninit::<AcpiTableView<'static>>::uninit(); 4];
+
+        let discovered = discover_definition_tables_from_rsdp(
+            build_rsdp_v1(u32::try_from(rsdt_address).expect("RSDT address fits 32 bits")),
+            &resolver,
+            &mut secondary_storage,
+        )
+        .unwrap();
+
+        assert!(discovered.xsdt().is_none());
+        assert_eq!(
+            discovered.root().table().header().signature(),
+            AcpiSignature::RSDT
+        );
+        assert_eq!(discovered.fadt().effective_dsdt_address(), dsdt_address);
+        assert!(discovered.secondary_definition_tables().is_empty());
+    }
+
     #[test]
     fn xsdt_discovery_loads_and_verifies_dell_namespace_from_captured_dsdt() {
         if !Path::new(DELL_DSDT_PATH).exists() {
@@ -38,6 +38,7 @@ impl AcpiThermalSupport {
 pub struct AcpiThermalReading {
     pub current: AcpiDeciKelvin,
     pub critical: Option<AcpiDeciKelvin>,
+    pub passive: Option<AcpiDeciKelvin>,
 }
 
 /// Public ACPI thermal-zone contract.
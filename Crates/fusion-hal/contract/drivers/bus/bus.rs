@@ -3,6 +3,9 @@
 #[path = "gpio/gpio.rs"]
 pub mod gpio;
 
+#[path = "i2c/i2c.rs"]
+pub mod i2c;
+
 #[path = "pci/pci.rs"]
 pub mod pci;
 
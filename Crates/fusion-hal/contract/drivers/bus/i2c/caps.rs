@@ -0,0 +1,64 @@
+//! Capability vocabulary for generic I2C backends.
+
+use bitflags::bitflags;
+
+/// Implementation-category vocabulary specialized for I2C support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum I2cImplementationKind {
+    /// Native controller peripheral implementation.
+    Native,
+    /// Lowered or adapted implementation, such as GPIO bit-banging, that preserves the public
+    /// I2C contract with caveats.
+    Emulated,
+    /// Unsupported placeholder.
+    Unsupported,
+}
+
+bitflags! {
+    /// Generic I2C backend features the provider can honestly surface.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct I2cProviderCaps: u32 {
+        /// The backend can initiate transfers as one bus controller.
+        const CONTROLLER          = 1 << 0;
+        /// The backend can answer transfers as one addressed target.
+        const TARGET              = 1 << 1;
+        /// The backend accepts 10-bit addresses in addition to 7-bit addresses.
+        const TEN_BIT_ADDRESS     = 1 << 2;
+        /// Multi-operation transactions are joined with repeated-start conditions.
+        const REPEATED_START      = 1 << 3;
+        /// The backend honors targets that stretch the clock.
+        const CLOCK_STRETCHING    = 1 << 4;
+        /// The backend can clock one stuck target off the bus.
+        const BUS_RECOVERY        = 1 << 5;
+        /// The backend detects lost arbitration on one multi-controller bus.
+        const ARBITRATION         = 1 << 6;
+        /// Standard mode (100 kHz) is supported.
+        const STANDARD_MODE       = 1 << 7;
+        /// Fast mode (400 kHz) is supported.
+        const FAST_MODE           = 1 << 8;
+        /// Fast mode plus (1 MHz) is supported.
+        const FAST_MODE_PLUS      = 1 << 9;
+        /// High-speed mode (3.4 MHz) is supported.
+        const HIGH_SPEED_MODE     = 1 << 10;
+    }
+}
+
+/// Full capability surface for one generic I2C backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct I2cSupport {
+    /// Backend-supported generic I2C features.
+    pub caps: I2cProviderCaps,
+    /// Native, lowered-with-restrictions, or unsupported implementation category.
+    pub implementation: I2cImplementationKind,
+}
+
+impl I2cSupport {
+    /// Returns a fully unsupported generic I2C surface.
+    #[must_use]
+    pub const fn unsupported() -> Self {
+        Self {
+            caps: I2cProviderCaps::empty(),
+            implementation: I2cImplementationKind::Unsupported,
+        }
+    }
+}
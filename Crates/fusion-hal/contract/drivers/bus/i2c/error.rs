@@ -0,0 +1,158 @@
+//! Error types for generic I2C backends.
+
+use core::fmt;
+
+/// Bus phase in which one addressed device refused the transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum I2cNackSource {
+    /// No device acknowledged the address phase.
+    Address,
+    /// The addressed device refused one data byte.
+    Data,
+    /// The source of the refusal is not reported by the backend.
+    Unknown,
+}
+
+/// Kind of failure returned by a generic I2C backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum I2cErrorKind {
+    /// The requested capability is unsupported.
+    Unsupported,
+    /// The request was structurally invalid.
+    Invalid,
+    /// The bus or backend is currently busy.
+    Busy,
+    /// The system could not provide the required runtime resources.
+    ResourceExhausted,
+    /// The request conflicted with current backend state.
+    StateConflict,
+    /// One device answered with a not-acknowledge.
+    Nack(I2cNackSource),
+    /// Another controller won arbitration for the bus.
+    ArbitrationLost,
+    /// The bus showed one misplaced start/stop condition or one stuck line.
+    Bus,
+    /// One target stretched the clock beyond the configured limit.
+    Timeout,
+    /// Backend-specific failure code.
+    Platform(i32),
+}
+
+/// Error returned by a generic I2C backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct I2cError {
+    kind: I2cErrorKind,
+}
+
+impl I2cError {
+    /// Creates an unsupported-operation error.
+    #[must_use]
+    pub const fn unsupported() -> Self {
+        Self {
+            kind: I2cErrorKind::Unsupported,
+        }
+    }
+
+    /// Creates an invalid-request error.
+    #[must_use]
+    pub const fn invalid() -> Self {
+        Self {
+            kind: I2cErrorKind::Invalid,
+        }
+    }
+
+    /// Creates a busy-bus error.
+    #[must_use]
+    pub const fn busy() -> Self {
+        Self {
+            kind: I2cErrorKind::Busy,
+        }
+    }
+
+    /// Creates a resource-exhausted error.
+    #[must_use]
+    pub const fn resource_exhausted() -> Self {
+        Self {
+            kind: I2cErrorKind::ResourceExhausted,
+        }
+    }
+
+    /// Creates a state-conflict error.
+    #[must_use]
+    pub const fn state_conflict() -> Self {
+        Self {
+            kind: I2cErrorKind::StateConflict,
+        }
+    }
+
+    /// Creates a not-acknowledge error for one bus phase.
+    #[must_use]
+    pub const fn nack(source: I2cNackSource) -> Self {
+        Self {
+            kind: I2cErrorKind::Nack(source),
+        }
+    }
+
+    /// Creates a lost-arbitration error.
+    #[must_use]
+    pub const fn arbitration_lost() -> Self {
+        Self {
+            kind: I2cErrorKind::ArbitrationLost,
+        }
+    }
+
+    /// Creates a bus-protocol or stuck-line error.
+    #[must_use]
+    pub const fn bus() -> Self {
+        Self {
+            kind: I2cErrorKind::Bus,
+        }
+    }
+
+    /// Creates a clock-stretch timeout error.
+    #[must_use]
+    pub const fn timeout() -> Self {
+        Self {
+            kind: I2cErrorKind::Timeout,
+        }
+    }
+
+    /// Creates a platform-specific error.
+    #[must_use]
+    pub const fn platform(code: i32) -> Self {
+        Self {
+            kind: I2cErrorKind::Platform(code),
+        }
+    }
+
+    /// Returns the concrete I2C error kind.
+    #[must_use]
+    pub const fn kind(self) -> I2cErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for I2cErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Unsupported => f.write_str("i2c operation unsupported"),
+            Self::Invalid => f.write_str("invalid i2c request"),
+            Self::Busy => f.write_str("i2c bus busy"),
+            Self::ResourceExhausted => f.write_str("i2c resources exhausted"),
+            Self::StateConflict => f.write_str("i2c state conflict"),
+            Self::Nack(I2cNackSource::Address) => f.write_str("i2c address not acknowledged"),
+            Self::Nack(I2cNackSource::Data) => f.write_str("i2c data not acknowledged"),
+            Self::Nack(I2cNackSource::Unknown) => f.write_str("i2c transfer not acknowledged"),
+            Self::ArbitrationLost => f.write_str("i2c arbitration lost"),
+            Self::Bus => f.write_str("i2c bus error"),
+            Self::Timeout => f.write_str("i2c clock stretch timed out"),
+            Self::Platform(code) => write!(f, "platform i2c error {code}"),
+        }
+    }
+}
+
+impl fmt::Display for I2cError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind.fmt(f)
+    }
+}
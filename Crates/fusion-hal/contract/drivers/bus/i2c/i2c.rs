@@ -0,0 +1,116 @@
+//! DriverContract-facing I2C contract vocabulary.
+
+mod caps;
+mod error;
+mod types;
+mod unsupported;
+
+pub use caps::*;
+pub use error::*;
+pub use types::*;
+pub use unsupported::*;
+
+/// Capability trait for generic I2C backends.
+pub trait I2cBaseContract {
+    /// Returns the stable controller/provider identity for this I2C bus.
+    fn controller(&self) -> &'static I2cControllerDescriptor;
+
+    /// Reports the truthful I2C surface for this backend.
+    fn support(&self) -> I2cSupport;
+}
+
+/// Controller-role contract for generic I2C backends.
+pub trait I2cControllerContract: I2cBaseContract {
+    /// Applies one bus configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the requested speed or timing cannot be realized.
+    fn configure(&mut self, config: I2cBusConfig) -> Result<(), I2cError>;
+
+    /// Runs one transaction against one target: a start condition, every operation in order,
+    /// and one final stop condition.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the target refuses the transfer, arbitration is lost, or
+    /// the bus misbehaves. The backend releases the bus before returning.
+    fn transaction(
+        &mut self,
+        address: I2cAddress,
+        operations: &mut [I2cOperation<'_>],
+    ) -> Result<(), I2cError>;
+
+    /// Clocks one stuck target off the bus and leaves both lines released.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the bus stays held or recovery is unsupported.
+    fn recover_bus(&mut self) -> Result<(), I2cError>;
+
+    /// Writes bytes to one target in a single transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest transaction failure.
+    fn write(&mut self, address: I2cAddress, bytes: &[u8]) -> Result<(), I2cError> {
+        self.transaction(address, &mut [I2cOperation::Write(bytes)])
+    }
+
+    /// Reads bytes from one target in a single transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest transaction failure.
+    fn read(&mut self, address: I2cAddress, buffer: &mut [u8]) -> Result<(), I2cError> {
+        self.transaction(address, &mut [I2cOperation::Read(buffer)])
+    }
+
+    /// Writes bytes then reads a reply across one repeated start.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest transaction failure.
+    fn write_read(
+        &mut self,
+        address: I2cAddress,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), I2cError> {
+        self.transaction(
+            address,
+            &mut [I2cOperation::Write(bytes), I2cOperation::Read(buffer)],
+        )
+    }
+}
+
+/// Target-role contract for generic I2C backends.
+pub trait I2cTargetContract: I2cBaseContract {
+    /// Starts answering transfers addressed to `address`.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the address is invalid or target mode is unsupported.
+    fn listen(&mut self, address: I2cAddress) -> Result<(), I2cError>;
+
+    /// Returns the next pending bus event, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the bus misbehaves.
+    fn poll_event(&mut self) -> Result<Option<I2cTargetEvent>, I2cError>;
+
+    /// Supplies bytes for one controller read, returning how many were clocked out.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when no controller read is in progress.
+    fn respond(&mut self, bytes: &[u8]) -> Result<usize, I2cError>;
+
+    /// Receives bytes from one controller write, returning how many were clocked in.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when no controller write is in progress.
+    fn receive(&mut self, buffer: &mut [u8]) -> Result<usize, I2cError>;
+}
@@ -0,0 +1,165 @@
+//! Shared generic I2C identifier, addressing, and transfer vocabulary.
+
+use super::I2cError;
+
+/// Stable controller/provider identity for one surfaced I2C bus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct I2cControllerDescriptor {
+    /// Stable machine-readable controller identifier.
+    pub id: &'static str,
+    /// Human-readable controller/provider name.
+    pub name: &'static str,
+}
+
+/// Side of the bus one I2C endpoint plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum I2cRole {
+    /// Drives the clock and initiates transfers.
+    Controller,
+    /// Answers transfers addressed to it.
+    Target,
+}
+
+/// One 7-bit or 10-bit I2C device address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum I2cAddress {
+    /// Classic 7-bit address.
+    SevenBit(u8),
+    /// Extended 10-bit address.
+    TenBit(u16),
+}
+
+impl I2cAddress {
+    /// Creates one validated 7-bit address.
+    ///
+    /// # Errors
+    ///
+    /// Returns one invalid-request error when the address does not fit in 7 bits.
+    pub const fn seven_bit(address: u8) -> Result<Self, I2cError> {
+        if address > 0x7f {
+            return Err(I2cError::invalid());
+        }
+        Ok(Self::SevenBit(address))
+    }
+
+    /// Creates one validated 10-bit address.
+    ///
+    /// # Errors
+    ///
+    /// Returns one invalid-request error when the address does not fit in 10 bits.
+    pub const fn ten_bit(address: u16) -> Result<Self, I2cError> {
+        if address > 0x3ff {
+            return Err(I2cError::invalid());
+        }
+        Ok(Self::TenBit(address))
+    }
+
+    /// Returns the raw address value without the direction bit.
+    #[must_use]
+    pub const fn raw(self) -> u16 {
+        match self {
+            Self::SevenBit(address) => address as u16,
+            Self::TenBit(address) => address,
+        }
+    }
+
+    /// Returns whether this address uses the 10-bit addressing scheme.
+    #[must_use]
+    pub const fn is_ten_bit(self) -> bool {
+        matches!(self, Self::TenBit(_))
+    }
+
+    /// Returns whether the address is structurally valid for its addressing scheme.
+    #[must_use]
+    pub const fn is_valid(self) -> bool {
+        match self {
+            Self::SevenBit(address) => address <= 0x7f,
+            Self::TenBit(address) => address <= 0x3ff,
+        }
+    }
+}
+
+/// Nominal SCL frequency class for one I2C bus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum I2cSpeed {
+    /// Standard mode, 100 kHz.
+    Standard,
+    /// Fast mode, 400 kHz.
+    Fast,
+    /// Fast mode plus, 1 MHz.
+    FastPlus,
+    /// High-speed mode, 3.4 MHz.
+    HighSpeed,
+}
+
+impl I2cSpeed {
+    /// Returns the nominal SCL frequency in hertz.
+    #[must_use]
+    pub const fn hz(self) -> u32 {
+        match self {
+            Self::Standard => 100_000,
+            Self::Fast => 400_000,
+            Self::FastPlus => 1_000_000,
+            Self::HighSpeed => 3_400_000,
+        }
+    }
+}
+
+/// Runtime configuration for one I2C controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct I2cBusConfig {
+    /// Requested SCL frequency class.
+    pub speed: I2cSpeed,
+    /// Longest time one target may hold SCL low before the transfer fails, in microseconds.
+    ///
+    /// Zero disables clock-stretch support: SCL is assumed high as soon as it is released.
+    pub stretch_timeout_us: u32,
+}
+
+impl I2cBusConfig {
+    /// Returns one standard-mode configuration with a 25 ms clock-stretch budget.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            speed: I2cSpeed::Standard,
+            stretch_timeout_us: 25_000,
+        }
+    }
+}
+
+impl Default for I2cBusConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One leg of a controller transaction.
+///
+/// Adjacent operations of the same direction continue the same transfer; a change of direction
+/// is joined with one repeated-start condition and a fresh address phase.
+#[derive(Debug, PartialEq, Eq)]
+pub enum I2cOperation<'a> {
+    /// Reads bytes from the addressed target.
+    Read(&'a mut [u8]),
+    /// Writes bytes to the addressed target.
+    Write(&'a [u8]),
+}
+
+impl I2cOperation<'_> {
+    /// Returns whether this operation reads from the target.
+    #[must_use]
+    pub const fn is_read(&self) -> bool {
+        matches!(self, Self::Read(_))
+    }
+}
+
+/// Bus event observed by one endpoint acting as an addressed target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum I2cTargetEvent {
+    /// The controller addressed this target and will read from it.
+    Read(I2cAddress),
+    /// The controller addressed this target and will write to it.
+    Write(I2cAddress),
+    /// The controller released the bus with one stop condition.
+    Stop,
+}
@@ -0,0 +1,76 @@
+//! Backend-neutral unsupported generic I2C implementation.
+
+use super::{
+    I2cAddress,
+    I2cBaseContract,
+    I2cBusConfig,
+    I2cControllerContract,
+    I2cControllerDescriptor,
+    I2cError,
+    I2cOperation,
+    I2cSupport,
+    I2cTargetContract,
+    I2cTargetEvent,
+};
+
+/// Unsupported generic I2C provider placeholder.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnsupportedI2c;
+
+impl UnsupportedI2c {
+    /// Creates a new unsupported generic I2C provider placeholder.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl I2cBaseContract for UnsupportedI2c {
+    fn controller(&self) -> &'static I2cControllerDescriptor {
+        const CONTROLLER: I2cControllerDescriptor = I2cControllerDescriptor {
+            id: "unsupported-i2c",
+            name: "Unsupported I2C",
+        };
+        &CONTROLLER
+    }
+
+    fn support(&self) -> I2cSupport {
+        I2cSupport::unsupported()
+    }
+}
+
+impl I2cControllerContract for UnsupportedI2c {
+    fn configure(&mut self, _config: I2cBusConfig) -> Result<(), I2cError> {
+        Err(I2cError::unsupported())
+    }
+
+    fn transaction(
+        &mut self,
+        _address: I2cAddress,
+        _operations: &mut [I2cOperation<'_>],
+    ) -> Result<(), I2cError> {
+        Err(I2cError::unsupported())
+    }
+
+    fn recover_bus(&mut self) -> Result<(), I2cError> {
+        Err(I2cError::unsupported())
+    }
+}
+
+impl I2cTargetContract for UnsupportedI2c {
+    fn listen(&mut self, _address: I2cAddress) -> Result<(), I2cError> {
+        Err(I2cError::unsupported())
+    }
+
+    fn poll_event(&mut self) -> Result<Option<I2cTargetEvent>, I2cError> {
+        Err(I2cError::unsupported())
+    }
+
+    fn respond(&mut self, _bytes: &[u8]) -> Result<usize, I2cError> {
+        Err(I2cError::unsupported())
+    }
+
+    fn receive(&mut self, _buffer: &mut [u8]) -> Result<usize, I2cError> {
+        Err(I2cError::unsupported())
+    }
+}
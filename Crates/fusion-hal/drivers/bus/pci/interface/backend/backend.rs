@@ -1,3 +1,6 @@
 //! PCI driver backend families composed over other drivers.
 //!
-//! No generic PCI-composed backends exist yet.
+//! - [`ecam`]: configuration access through firmware-described ECAM windows.
+
+#[path = "ecam/ecam.rs"]
+pub mod ecam;
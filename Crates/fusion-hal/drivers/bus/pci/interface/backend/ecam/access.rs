@@ -0,0 +1,235 @@
+//! Configuration-space accessors for ECAM windows.
+//!
+//! The ECAM backend never touches memory directly. It asks one accessor for byte, word, and
+//! dword reads and writes at offsets relative to the window base, which keeps the decoding
+//! logic identical whether the window is real MMIO, a synthetic image, or a dump captured from
+//! another machine.
+
+use core::ptr::NonNull;
+
+use fusion_hal::contract::drivers::bus::pci::PciError;
+
+/// Width-exact access to one ECAM window.
+///
+/// Offsets are byte offsets from the start of the window (the first bus the window decodes).
+/// Callers guarantee natural alignment and stay inside [`window_len`](Self::window_len).
+pub trait PciConfigAccess: Sync {
+    /// Returns the number of bytes this accessor decodes.
+    fn window_len(&self) -> u64;
+
+    /// Reads one byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the offset cannot be read.
+    fn read_u8(&self, offset: u64) -> Result<u8, PciError>;
+
+    /// Reads one 16-bit word.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the offset cannot be read.
+    fn read_u16(&self, offset: u64) -> Result<u16, PciError>;
+
+    /// Reads one 32-bit dword.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the offset cannot be read.
+    fn read_u32(&self, offset: u64) -> Result<u32, PciError>;
+
+    /// Writes one byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PciError::unsupported`] for read-only accessors.
+    fn write_u8(&self, offset: u64, value: u8) -> Result<(), PciError>;
+
+    /// Writes one 16-bit word.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PciError::unsupported`] for read-only accessors.
+    fn write_u16(&self, offset: u64, value: u16) -> Result<(), PciError>;
+
+    /// Writes one 32-bit dword.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PciError::unsupported`] for read-only accessors.
+    fn write_u32(&self, offset: u64, value: u32) -> Result<(), PciError>;
+}
+
+impl<T> PciConfigAccess for &T
+where
+    T: PciConfigAccess + ?Sized,
+{
+    fn window_len(&self) -> u64 {
+        (**self).window_len()
+    }
+
+    fn read_u8(&self, offset: u64) -> Result<u8, PciError> {
+        (**self).read_u8(offset)
+    }
+
+    fn read_u16(&self, offset: u64) -> Result<u16, PciError> {
+        (**self).read_u16(offset)
+    }
+
+    fn read_u32(&self, offset: u64) -> Result<u32, PciError> {
+        (**self).read_u32(offset)
+    }
+
+    fn write_u8(&self, offset: u64, value: u8) -> Result<(), PciError> {
+        (**self).write_u8(offset, value)
+    }
+
+    fn write_u16(&self, offset: u64, value: u16) -> Result<(), PciError> {
+        (**self).write_u16(offset, value)
+    }
+
+    fn write_u32(&self, offset: u64, value: u32) -> Result<(), PciError> {
+        (**self).write_u32(offset, value)
+    }
+}
+
+/// Volatile accessor over one mapped ECAM window.
+#[derive(Debug)]
+pub struct PciMmioConfigAccess {
+    base: NonNull<u8>,
+    len: u64,
+}
+
+// SAFETY: the accessor only performs naturally aligned volatile loads and stores inside the
+// window the constructor's caller vouched for; ECAM itself serializes concurrent accesses.
+unsafe impl Send for PciMmioConfigAccess {}
+// SAFETY: see the `Send` impl above; no accessor state is mutated after construction.
+unsafe impl Sync for PciMmioConfigAccess {}
+
+impl PciMmioConfigAccess {
+    /// Wraps one already-mapped ECAM window.
+    ///
+    /// # Safety
+    ///
+    /// `base` must point to `len` bytes of device memory mapped uncached for the lifetime of
+    /// the accessor, and nothing else may treat that range as ordinary memory.
+    #[must_use]
+    pub const unsafe fn new(base: NonNull<u8>, len: u64) -> Self {
+        Self { base, len }
+    }
+
+    fn pointer<T>(&self, offset: u64) -> Result<*mut T, PciError> {
+        let end = offset
+            .checked_add(size_of::<T>() as u64)
+            .ok_or_else(PciError::invalid)?;
+        if end > self.len || !offset.is_multiple_of(size_of::<T>() as u64) {
+            return Err(PciError::invalid());
+        }
+        let offset = usize::try_from(offset).map_err(|_| PciError::invalid())?;
+        // SAFETY: the range was bounds-checked against the mapped window above.
+        Ok(unsafe { self.base.as_ptr().add(offset) }.cast::<T>())
+    }
+}
+
+impl PciConfigAccess for PciMmioConfigAccess {
+    fn window_len(&self) -> u64 {
+        self.len
+    }
+
+    fn read_u8(&self, offset: u64) -> Result<u8, PciError> {
+        let pointer = self.pointer::<u8>(offset)?;
+        // SAFETY: `pointer` is in-bounds and naturally aligned inside the mapped window.
+        Ok(unsafe { pointer.read_volatile() })
+    }
+
+    fn read_u16(&self, offset: u64) -> Result<u16, PciError> {
+        let pointer = self.pointer::<u16>(offset)?;
+        // SAFETY: `pointer` is in-bounds and naturally aligned inside the mapped window.
+        Ok(u16::from_le(unsafe { pointer.read_volatile() }))
+    }
+
+    fn read_u32(&self, offset: u64) -> Result<u32, PciError> {
+        let pointer = self.pointer::<u32>(offset)?;
+        // SAFETY: `pointer` is in-bounds and naturally aligned inside the mapped window.
+        Ok(u32::from_le(unsafe { pointer.read_volatile() }))
+    }
+
+    fn write_u8(&self, offset: u64, value: u8) -> Result<(), PciError> {
+        let pointer = self.pointer::<u8>(offset)?;
+        // SAFETY: `pointer` is in-bounds and naturally aligned inside the mapped window.
+        unsafe { pointer.write_volatile(value) };
+        Ok(())
+    }
+
+    fn write_u16(&self, offset: u64, value: u16) -> Result<(), PciError> {
+        let pointer = self.pointer::<u16>(offset)?;
+        // SAFETY: `pointer` is in-bounds and naturally aligned inside the mapped window.
+        unsafe { pointer.write_volatile(value.to_le()) };
+        Ok(())
+    }
+
+    fn write_u32(&self, offset: u64, value: u32) -> Result<(), PciError> {
+        let pointer = self.pointer::<u32>(offset)?;
+        // SAFETY: `pointer` is in-bounds and naturally aligned inside the mapped window.
+        unsafe { pointer.write_volatile(value.to_le()) };
+        Ok(())
+    }
+}
+
+/// Read-only accessor over one captured ECAM image.
+///
+/// Useful for decoding dumps (for example `/sys/bus/pci/devices/*/config` laid out at their ECAM
+/// offsets). Writes are refused, so BAR and ROM sizing report zero sizes rather than guesses.
+#[derive(Debug, Clone, Copy)]
+pub struct PciConfigSnapshot<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> PciConfigSnapshot<'a> {
+    /// Wraps one captured ECAM image. Absent functions should read as all-ones.
+    #[must_use]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn read<const N: usize>(&self, offset: u64) -> Result<[u8; N], PciError> {
+        let start = usize::try_from(offset).map_err(|_| PciError::invalid())?;
+        let bytes = start
+            .checked_add(N)
+            .and_then(|end| self.bytes.get(start..end))
+            .ok_or_else(PciError::invalid)?;
+        let mut out = [0; N];
+        out.copy_from_slice(bytes);
+        Ok(out)
+    }
+}
+
+impl PciConfigAccess for PciConfigSnapshot<'_> {
+    fn window_len(&self) -> u64 {
+        self.bytes.len() as u64
+    }
+
+    fn read_u8(&self, offset: u64) -> Result<u8, PciError> {
+        self.read::<1>(offset).map(u8::from_le_bytes)
+    }
+
+    fn read_u16(&self, offset: u64) -> Result<u16, PciError> {
+        self.read::<2>(offset).map(u16::from_le_bytes)
+    }
+
+    fn read_u32(&self, offset: u64) -> Result<u32, PciError> {
+        self.read::<4>(offset).map(u32::from_le_bytes)
+    }
+
+    fn write_u8(&self, _offset: u64, _value: u8) -> Result<(), PciError> {
+        Err(PciError::unsupported())
+    }
+
+    fn write_u16(&self, _offset: u64, _value: u16) -> Result<(), PciError> {
+        Err(PciError::unsupported())
+    }
+
+    fn write_u32(&self, _offset: u64, _value: u32) -> Result<(), PciError> {
+        Err(PciError::unsupported())
+    }
+}
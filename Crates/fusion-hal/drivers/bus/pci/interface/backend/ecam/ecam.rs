@@ -0,0 +1,339 @@
+//! ECAM-backed PCI hardware substrate.
+//!
+//! PCI Express Base Specification, Section 7.2.2 ("Enhanced Configuration Access Mechanism")
+//! maps every function's 4 KiB configuration space into one flat window:
+//!
+//! `offset = (bus - start_bus) << 20 | device << 15 | function << 12 | register`
+//!
+//! Firmware describes those windows through the ACPI `MCFG` table (one allocation per segment
+//! and bus range) or the devicetree `pci-host-ecam-generic` binding. Each [`PciEcamWindow`]
+//! becomes one provider of the universal PCI driver. [`PciEcamHardware`] enumerates it by
+//! walking bridges recursively from the window's first bus, and opens functions by decoding
+//! their headers: identity, BARs (sized by the usual write-ones probe), option ROM, bridge
+//! windows, and both capability lists.
+//!
+//! Enumeration is read-only: it follows whatever bus numbers firmware already assigned and
+//! never programs bridges. Function opens do write, but only to size BARs and the ROM, with
+//! decode disabled for the duration and every register restored afterwards.
+
+mod access;
+mod function;
+
+pub use access::*;
+pub use function::*;
+
+use core::slice;
+
+use fusion_hal::contract::drivers::bus::pci::{
+    PciBus,
+    PciControllerDescriptor,
+    PciDevice,
+    PciError,
+    PciFunction,
+    PciFunctionAddress,
+    PciHeaderType,
+    PciImplementationKind,
+    PciSegment,
+    PciSegmentDescriptor,
+    PciSupport,
+};
+
+use crate::interface::contract::PciHardware;
+
+/// Bytes of configuration space ECAM exposes per function.
+pub const PCI_ECAM_FUNCTION_SPAN: u64 = 1 << 12;
+/// Bytes of configuration space ECAM exposes per bus.
+pub const PCI_ECAM_BUS_SPAN: u64 = 1 << 20;
+
+const PCI_VENDOR_ABSENT: u16 = 0xffff;
+const PCI_HEADER_MULTIFUNCTION: u8 = 0x80;
+const PCI_BUS_COUNT: usize = 256;
+
+/// One ECAM window surfaced as a PCI provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PciEcamWindow {
+    /// Descriptor reported for the provider.
+    pub controller: PciControllerDescriptor,
+    /// Physical base address of the window (the configuration space of `start_bus`).
+    pub base_address: u64,
+    /// Segment group and inclusive bus range the window decodes.
+    pub segment: PciSegmentDescriptor,
+}
+
+impl PciEcamWindow {
+    /// Builds one window from the fields an `MCFG` allocation carries.
+    #[must_use]
+    pub const fn new(
+        controller: PciControllerDescriptor,
+        base_address: u64,
+        segment_group: u16,
+        start_bus: u8,
+        end_bus: u8,
+    ) -> Self {
+        Self {
+            controller,
+            base_address,
+            segment: PciSegmentDescriptor {
+                segment: PciSegment(segment_group),
+                start_bus: PciBus(start_bus),
+                end_bus: PciBus(end_bus),
+            },
+        }
+    }
+
+    /// Returns the number of bytes the window spans.
+    #[must_use]
+    pub const fn len(&self) -> u64 {
+        (self.segment.end_bus.0 as u64 - self.segment.start_bus.0 as u64 + 1) * PCI_ECAM_BUS_SPAN
+    }
+
+    /// Returns whether the window decodes no buses, which only happens for inverted ranges.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.segment.end_bus.0 < self.segment.start_bus.0
+    }
+
+    /// Returns whether the window decodes `address`.
+    #[must_use]
+    pub const fn contains(&self, address: PciFunctionAddress) -> bool {
+        address.segment.0 == self.segment.segment.0
+            && address.bus.0 >= self.segment.start_bus.0
+            && address.bus.0 <= self.segment.end_bus.0
+    }
+
+    /// Returns the window-relative offset of one function's configuration space.
+    #[must_use]
+    pub const fn function_offset(&self, address: PciFunctionAddress) -> Option<u64> {
+        if !self.contains(address) {
+            return None;
+        }
+        Some(
+            ((address.bus.0 - self.segment.start_bus.0) as u64) << 20
+                | (address.device.get() as u64) << 15
+                | (address.function.get() as u64) << 12,
+        )
+    }
+}
+
+/// Static platform description consumed by [`PciEcamHardware`].
+///
+/// The universal PCI driver addresses providers by index through associated functions, so the
+/// windows and their accessors live in platform-owned statics.
+pub trait PciEcamPlatform: 'static {
+    /// Accessor type used for every window.
+    type Access: PciConfigAccess + 'static;
+
+    /// Returns every ECAM window, one per provider.
+    fn windows() -> &'static [PciEcamWindow];
+
+    /// Returns the accessor for one provider, when that window is reachable.
+    fn access(provider: u8) -> Option<&'static Self::Access>;
+}
+
+/// PCI hardware substrate over the ECAM windows one platform describes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PciEcamHardware<P> {
+    marker: core::marker::PhantomData<fn() -> P>,
+}
+
+impl<P> PciEcamHardware<P>
+where
+    P: PciEcamPlatform,
+{
+    fn provider(provider: u8) -> Option<(&'static PciEcamWindow, &'static P::Access)> {
+        let window = P::windows().get(usize::from(provider))?;
+        let access = P::access(provider)?;
+        (!window.is_empty() && access.window_len() >= window.len()).then_some((window, access))
+    }
+}
+
+impl<P> PciHardware for PciEcamHardware<P>
+where
+    P: PciEcamPlatform,
+{
+    type Function = PciEcamFunction<&'static P::Access>;
+
+    fn provider_count() -> u8 {
+        u8::try_from(P::windows().len()).unwrap_or(u8::MAX)
+    }
+
+    fn controller(provider: u8) -> Option<&'static PciControllerDescriptor> {
+        P::windows()
+            .get(usize::from(provider))
+            .map(|window| &window.controller)
+    }
+
+    fn support(provider: u8) -> PciSupport {
+        if Self::provider(provider).is_none() {
+            return PciSupport::unsupported();
+        }
+        PciSupport {
+            implementation: PciImplementationKind::Hardware,
+            pcie: true,
+            interrupts: true,
+            dma: true,
+            power_management: true,
+            error_reporting: true,
+            virtualization: true,
+            hotplug: true,
+        }
+    }
+
+    fn segments(provider: u8) -> &'static [PciSegmentDescriptor] {
+        P::windows()
+            .get(usize::from(provider))
+            .map_or(&[], |window| slice::from_ref(&window.segment))
+    }
+
+    fn enumerate_functions(
+        provider: u8,
+        out: &mut [PciFunctionAddress],
+    ) -> Result<usize, PciError> {
+        let (window, access) = Self::provider(provider).ok_or_else(PciError::not_present)?;
+        let mut written = 0;
+        walk_hierarchy(window, access, |address, _| {
+            let slot = out
+                .get_mut(written)
+                .ok_or_else(PciError::resource_exhausted)?;
+            *slot = address;
+            written += 1;
+            Ok(false)
+        })?;
+        Ok(written)
+    }
+
+    fn function(
+        provider: u8,
+        address: PciFunctionAddress,
+    ) -> Result<Option<Self::Function>, PciError> {
+        let (window, access) = Self::provider(provider).ok_or_else(PciError::not_present)?;
+        if !window.contains(address) {
+            return Err(PciError::invalid());
+        }
+        if !function_present(window, access, address)? {
+            return Ok(None);
+        }
+        let parent = find_parent(window, access, address)?;
+        PciEcamFunction::open(window, access, address, parent).map(Some)
+    }
+}
+
+/// Visits every reachable function, breadth-first by bus, starting at the window's first bus.
+///
+/// The visitor sees each address with the secondary bus it bridges to, and returns `Ok(true)`
+/// to stop early.
+fn walk_hierarchy<A, V>(window: &PciEcamWindow, access: &A, mut visit: V) -> Result<(), PciError>
+where
+    A: PciConfigAccess + ?Sized,
+    V: FnMut(PciFunctionAddress, Option<PciBus>) -> Result<bool, PciError>,
+{
+    let mut visited = [false; PCI_BUS_COUNT];
+    let mut pending = [0_u8; PCI_BUS_COUNT];
+    let (mut head, mut tail) = (0_usize, 1_usize);
+    pending[0] = window.segment.start_bus.0;
+    visited[usize::from(window.segment.start_bus.0)] = true;
+
+    while head < tail {
+        let bus = PciBus(pending[head]);
+        head += 1;
+        for device in 0..=PciDevice::MAX {
+            for function in 0..=PciFunction::MAX {
+                let address = function_address(window, bus, device, function)?;
+                let offset = window
+                    .function_offset(address)
+                    .ok_or_else(PciError::invalid)?;
+                if access.read_u16(offset)? == PCI_VENDOR_ABSENT {
+                    if function == 0 {
+                        break;
+                    }
+                    continue;
+                }
+                let raw_header = access.read_u8(offset + 0x0e)?;
+                let secondary_bus = match PciHeaderType::from_u8(raw_header) {
+                    PciHeaderType::Type1 | PciHeaderType::Type2 => {
+                        Some(PciBus(access.read_u8(offset + 0x19)?))
+                    }
+                    _ => None,
+                };
+                if visit(address, secondary_bus)? {
+                    return Ok(());
+                }
+                if let Some(PciBus(secondary)) = secondary_bus
+                    && secondary > bus.0
+                    && secondary <= window.segment.end_bus.0
+                    && !visited[usize::from(secondary)]
+                {
+                    visited[usize::from(secondary)] = true;
+                    pending[tail] = secondary;
+                    tail += 1;
+                }
+                if function == 0 && raw_header & PCI_HEADER_MULTIFUNCTION == 0 {
+                    break;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn function_address(
+    window: &PciEcamWindow,
+    bus: PciBus,
+    device: u8,
+    function: u8,
+) -> Result<PciFunctionAddress, PciError> {
+    Ok(PciFunctionAddress {
+        segment: window.segment.segment,
+        bus,
+        device: PciDevice::from_u8(device).ok_or_else(PciError::invalid)?,
+        function: PciFunction::from_u8(function).ok_or_else(PciError::invalid)?,
+    })
+}
+
+fn function_present<A>(
+    window: &PciEcamWindow,
+    access: &A,
+    address: PciFunctionAddress,
+) -> Result<bool, PciError>
+where
+    A: PciConfigAccess + ?Sized,
+{
+    let offset = window
+        .function_offset(address)
+        .ok_or_else(PciError::invalid)?;
+    if access.read_u16(offset)? == PCI_VENDOR_ABSENT {
+        return Ok(false);
+    }
+    if address.function.get() == 0 {
+        return Ok(true);
+    }
+    // Non-zero functions only exist behind a multi-function function 0.
+    let function0 = offset & !(PCI_ECAM_FUNCTION_SPAN * 7);
+    Ok(access.read_u16(function0)? != PCI_VENDOR_ABSENT
+        && access.read_u8(function0 + 0x0e)? & PCI_HEADER_MULTIFUNCTION != 0)
+}
+
+fn find_parent<A>(
+    window: &PciEcamWindow,
+    access: &A,
+    address: PciFunctionAddress,
+) -> Result<Option<PciFunctionAddress>, PciError>
+where
+    A: PciConfigAccess + ?Sized,
+{
+    if address.bus == window.segment.start_bus {
+        return Ok(None);
+    }
+    let mut parent = None;
+    walk_hierarchy(window, access, |candidate, secondary_bus| {
+        if secondary_bus == Some(address.bus) {
+            parent = Some(candidate);
+            return Ok(true);
+        }
+        Ok(false)
+    })?;
+    Ok(parent)
+}
+
+#[cfg(test)]
+mod tests;
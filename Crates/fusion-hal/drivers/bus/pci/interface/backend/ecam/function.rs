@@ -0,0 +1,1005 @@
+//! One opened ECAM function and its decoded configuration header.
+//!
+//! Everything the contract reports as a snapshot (identity, BARs, windows, capability lists,
+//! and the per-lane profiles) is decoded once at open time and cached inline. Raw config reads
+//! and writes always go straight to the accessor, so callers that reprogram a function should
+//! reopen it to refresh the snapshot.
+//!
+//! The decoder only needs a [`PciConfigAccess`], so backends that reach configuration space some
+//! other way (a hosted OS interface, a captured dump) can reuse it through
+//! [`PciEcamFunction::from_config`].
+
+use fusion_hal::contract::drivers::bus::pci::{
+    PciBarDescriptor,
+    PciBarKind,
+    PciBridgeWindow,
+    PciBridgeWindowKind,
+    PciBus,
+    PciCapabilityId,
+    PciCapabilityRecord,
+    PciClassCode,
+    PciConfigOffset,
+    PciConfigurationModel,
+    PciDeviceId,
+    PciDmaProfile,
+    PciError,
+    PciErrorKind,
+    PciErrorReportingProfile,
+    PciExpressDevicePortType,
+    PciExpressProfile,
+    PciExpressVersion,
+    PciExtendedCapabilityId,
+    PciExtendedCapabilityRecord,
+    PciFunctionAddress,
+    PciFunctionIdentity,
+    PciFunctionKind,
+    PciFunctionProfile,
+    PciHeaderType,
+    PciHotplugProfile,
+    PciInterruptPin,
+    PciInterruptProfile,
+    PciLinkSpeed,
+    PciLinkWidth,
+    PciMsiProfile,
+    PciMsixProfile,
+    PciPowerProfile,
+    PciPowerState,
+    PciRomDescriptor,
+    PciSriovProfile,
+    PciSubsystemId,
+    PciSubsystemVendorId,
+    PciTopologyProfile,
+    PciTransportFamily,
+    PciVendorId,
+    PciVirtualizationProfile,
+};
+
+use super::{
+    PCI_ECAM_FUNCTION_SPAN,
+    PCI_HEADER_MULTIFUNCTION,
+    PciConfigAccess,
+    PciEcamWindow,
+};
+use crate::interface::contract::PciHardwareFunction;
+
+/// Largest number of BAR slots one header exposes.
+pub const PCI_ECAM_MAX_BARS: usize = 6;
+/// Largest number of bridge windows one type 1 header exposes.
+pub const PCI_ECAM_MAX_BRIDGE_WINDOWS: usize = 3;
+/// Largest number of standard capabilities the legacy config space can hold.
+pub const PCI_ECAM_MAX_CAPABILITIES: usize = 48;
+/// Largest number of extended capabilities one opened function keeps.
+pub const PCI_ECAM_MAX_EXTENDED_CAPABILITIES: usize = 64;
+
+const PCI_CONVENTIONAL_CONFIG_LEN: u16 = 0x100;
+const PCI_ENHANCED_CONFIG_LEN: u16 = 0x1000;
+
+const REG_VENDOR_ID: u16 = 0x00;
+const REG_DEVICE_ID: u16 = 0x02;
+const REG_COMMAND: u16 = 0x04;
+const REG_STATUS: u16 = 0x06;
+const REG_REVISION_ID: u16 = 0x08;
+const REG_CLASS_INTERFACE: u16 = 0x09;
+const REG_CLASS_SUB: u16 = 0x0a;
+const REG_CLASS_BASE: u16 = 0x0b;
+const REG_HEADER_TYPE: u16 = 0x0e;
+const REG_BAR0: u16 = 0x10;
+const REG_INTERRUPT_PIN: u16 = 0x3d;
+
+const REG_TYPE0_SUBSYSTEM_VENDOR_ID: u16 = 0x2c;
+const REG_TYPE0_SUBSYSTEM_ID: u16 = 0x2e;
+const REG_TYPE0_ROM: u16 = 0x30;
+const REG_TYPE0_CAPABILITIES: u16 = 0x34;
+
+const REG_TYPE1_SECONDARY_BUS: u16 = 0x19;
+const REG_TYPE1_SUBORDINATE_BUS: u16 = 0x1a;
+const REG_TYPE1_IO_BASE: u16 = 0x1c;
+const REG_TYPE1_IO_LIMIT: u16 = 0x1d;
+const REG_TYPE1_MEMORY_BASE: u16 = 0x20;
+const REG_TYPE1_MEMORY_LIMIT: u16 = 0x22;
+const REG_TYPE1_PREFETCH_BASE: u16 = 0x24;
+const REG_TYPE1_PREFETCH_LIMIT: u16 = 0x26;
+const REG_TYPE1_PREFETCH_BASE_UPPER: u16 = 0x28;
+const REG_TYPE1_PREFETCH_LIMIT_UPPER: u16 = 0x2c;
+const REG_TYPE1_IO_BASE_UPPER: u16 = 0x30;
+const REG_TYPE1_IO_LIMIT_UPPER: u16 = 0x32;
+const REG_TYPE1_ROM: u16 = 0x38;
+
+const REG_TYPE2_CAPABILITIES: u16 = 0x14;
+const REG_TYPE2_SECONDARY_BUS: u16 = 0x19;
+const REG_TYPE2_SUBORDINATE_BUS: u16 = 0x1a;
+const REG_TYPE2_SUBSYSTEM_VENDOR_ID: u16 = 0x40;
+const REG_TYPE2_SUBSYSTEM_ID: u16 = 0x42;
+
+const COMMAND_DECODE: u16 = 0x0003;
+const COMMAND_BUS_MASTER: u16 = 0x0004;
+const STATUS_CAPABILITIES: u16 = 0x0010;
+
+const CAPABILITY_POINTER_MASK: u8 = 0xfc;
+const CAPABILITY_FIRST: u8 = 0x40;
+const EXTENDED_CAPABILITY_FIRST: u16 = 0x100;
+
+const BAR_IO: u32 = 0x1;
+const BAR_IO_MASK: u32 = !0x3;
+const BAR_MEMORY_MASK: u32 = !0xf;
+const BAR_MEMORY_64: u32 = 0x4;
+const BAR_PREFETCHABLE: u32 = 0x8;
+const ROM_ADDRESS_MASK: u32 = 0xffff_f800;
+const ROM_ENABLE: u32 = 0x1;
+
+/// Aux current encodings from the Power Management Capabilities register, in milliamps.
+const PM_AUX_CURRENT_MA: [u16; 8] = [0, 55, 100, 160, 220, 270, 320, 375];
+
+/// One function opened through an ECAM window.
+#[derive(Debug)]
+pub struct PciEcamFunction<A> {
+    access: A,
+    base: u64,
+    header: DecodedHeader,
+}
+
+/// Snapshot of everything decoded from one configuration header.
+#[derive(Debug, Clone, Copy)]
+struct DecodedHeader {
+    address: PciFunctionAddress,
+    identity: PciFunctionIdentity,
+    profile: PciFunctionProfile,
+    bars: [PciBarDescriptor; PCI_ECAM_MAX_BARS],
+    bar_count: usize,
+    bridge_windows: [PciBridgeWindow; PCI_ECAM_MAX_BRIDGE_WINDOWS],
+    bridge_window_count: usize,
+    option_rom: Option<PciRomDescriptor>,
+    capabilities: [PciCapabilityRecord; PCI_ECAM_MAX_CAPABILITIES],
+    capability_count: usize,
+    extended_capabilities: [PciExtendedCapabilityRecord; PCI_ECAM_MAX_EXTENDED_CAPABILITIES],
+    extended_capability_count: usize,
+    topology: PciTopologyProfile,
+    interrupts: PciInterruptProfile,
+    dma: PciDmaProfile,
+    power: PciPowerProfile,
+    error_reporting: PciErrorReportingProfile,
+    virtualization: PciVirtualizationProfile,
+    hotplug: Option<PciHotplugProfile>,
+    pcie: Option<PciExpressProfile>,
+}
+
+/// Config-space view of one function while its header is decoded.
+struct Registers<'a, A: ?Sized> {
+    access: &'a A,
+    base: u64,
+}
+
+impl<A> Registers<'_, A>
+where
+    A: PciConfigAccess + ?Sized,
+{
+    fn read_u8(&self, offset: u16) -> Result<u8, PciError> {
+        self.access.read_u8(self.base + u64::from(offset))
+    }
+
+    fn read_u16(&self, offset: u16) -> Result<u16, PciError> {
+        self.access.read_u16(self.base + u64::from(offset))
+    }
+
+    fn read_u32(&self, offset: u16) -> Result<u32, PciError> {
+        self.access.read_u32(self.base + u64::from(offset))
+    }
+
+    fn write_u16(&self, offset: u16, value: u16) -> Result<(), PciError> {
+        self.access.write_u16(self.base + u64::from(offset), value)
+    }
+
+    fn write_u32(&self, offset: u16, value: u32) -> Result<(), PciError> {
+        self.access.write_u32(self.base + u64::from(offset), value)
+    }
+
+    /// Writes `probe`, reads back the decoded bits, and restores `original`.
+    ///
+    /// Returns `None` when the accessor refuses writes.
+    fn size_probe(&self, offset: u16, original: u32, probe: u32) -> Result<Option<u32>, PciError> {
+        match self.write_u32(offset, probe) {
+            Ok(()) => {}
+            Err(error) if error.kind() == PciErrorKind::Unsupported => return Ok(None),
+            Err(error) => return Err(error),
+        }
+        let mask = self.read_u32(offset);
+        self.write_u32(offset, original)?;
+        mask.map(Some)
+    }
+}
+
+impl<A> PciEcamFunction<A>
+where
+    A: PciConfigAccess,
+{
+    /// Decodes one present function inside one ECAM window.
+    pub(super) fn open(
+        window: &PciEcamWindow,
+        access: A,
+        address: PciFunctionAddress,
+        parent: Option<PciFunctionAddress>,
+    ) -> Result<Self, PciError> {
+        let base = window
+            .function_offset(address)
+            .ok_or_else(PciError::invalid)?;
+        Self::from_config(access, base, address, parent)
+    }
+
+    /// Decodes one present function whose 4 KiB configuration space starts at `base`.
+    ///
+    /// BAR and ROM sizing writes through `access`; accessors that refuse writes with
+    /// [`PciError::unsupported`] get unsized resources instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PciError::invalid`] when the configuration space does not fit the accessor, or
+    /// any error the accessor reports while the header is decoded.
+    pub fn from_config(
+        access: A,
+        base: u64,
+        address: PciFunctionAddress,
+        parent: Option<PciFunctionAddress>,
+    ) -> Result<Self, PciError> {
+        let header = DecodedHeader::decode(
+            &Registers {
+                access: &access,
+                base,
+            },
+            address,
+            parent,
+        )?;
+        Ok(Self {
+            access,
+            base,
+            header,
+        })
+    }
+
+    fn config_offset(&self, offset: PciConfigOffset, width: u16) -> Result<u64, PciError> {
+        let len = match self.header.profile.configuration_model {
+            PciConfigurationModel::Conventional256B => PCI_CONVENTIONAL_CONFIG_LEN,
+            PciConfigurationModel::Enhanced4KiB => PCI_ENHANCED_CONFIG_LEN,
+        };
+        if !offset.0.is_multiple_of(width) || offset.0 + width > len {
+            return Err(PciError::invalid());
+        }
+        Ok(self.base + u64::from(offset.0))
+    }
+}
+
+impl DecodedHeader {
+    fn decode<A>(
+        registers: &Registers<'_, A>,
+        address: PciFunctionAddress,
+        parent: Option<PciFunctionAddress>,
+    ) -> Result<Self, PciError>
+    where
+        A: PciConfigAccess + ?Sized,
+    {
+        if registers.base + PCI_ECAM_FUNCTION_SPAN > registers.access.window_len() {
+            return Err(PciError::invalid());
+        }
+        let raw_header = registers.read_u8(REG_HEADER_TYPE)?;
+        let header_type = PciHeaderType::from_u8(raw_header);
+
+        let mut header = Self {
+            address,
+            identity: PciFunctionIdentity {
+                vendor_id: PciVendorId(registers.read_u16(REG_VENDOR_ID)?),
+                device_id: PciDeviceId(registers.read_u16(REG_DEVICE_ID)?),
+                subsystem_vendor_id: None,
+                subsystem_id: None,
+                class_code: PciClassCode {
+                    base: registers.read_u8(REG_CLASS_BASE)?,
+                    sub: registers.read_u8(REG_CLASS_SUB)?,
+                    interface: registers.read_u8(REG_CLASS_INTERFACE)?,
+                },
+                revision_id: registers.read_u8(REG_REVISION_ID)?,
+            },
+            profile: PciFunctionProfile {
+                transport_family: PciTransportFamily::ConventionalPci,
+                configuration_model: PciConfigurationModel::Conventional256B,
+                header_type,
+                multifunction: raw_header & PCI_HEADER_MULTIFUNCTION != 0,
+                kind: PciFunctionKind::Unknown,
+            },
+            bars: [EMPTY_BAR; PCI_ECAM_MAX_BARS],
+            bar_count: 0,
+            bridge_windows: [EMPTY_BRIDGE_WINDOW; PCI_ECAM_MAX_BRIDGE_WINDOWS],
+            bridge_window_count: 0,
+            option_rom: None,
+            capabilities: [EMPTY_CAPABILITY; PCI_ECAM_MAX_CAPABILITIES],
+            capability_count: 0,
+            extended_capabilities: [EMPTY_EXTENDED_CAPABILITY; PCI_ECAM_MAX_EXTENDED_CAPABILITIES],
+            extended_capability_count: 0,
+            topology: PciTopologyProfile {
+                parent,
+                ..PciTopologyProfile::default()
+            },
+            interrupts: PciInterruptProfile::default(),
+            dma: PciDmaProfile::default(),
+            power: PciPowerProfile::default(),
+            error_reporting: PciErrorReportingProfile::default(),
+            virtualization: PciVirtualizationProfile::default(),
+            hotplug: None,
+            pcie: None,
+        };
+
+        header.decode_capabilities(registers)?;
+        if header.capability(PciCapabilityId::PciExpress).is_some() {
+            header.decode_extended_capabilities(registers)?;
+        }
+        header.decode_header(registers)?;
+        header.decode_resources(registers)?;
+        header.decode_pcie(registers)?;
+        header.decode_interrupts(registers)?;
+        header.decode_power(registers)?;
+        header.decode_dma(registers)?;
+        header.decode_error_reporting(registers)?;
+        header.decode_virtualization(registers)?;
+        Ok(header)
+    }
+
+    fn capabilities(&self) -> &[PciCapabilityRecord] {
+        &self.capabilities[..self.capability_count]
+    }
+
+    fn extended_capabilities(&self) -> &[PciExtendedCapabilityRecord] {
+        &self.extended_capabilities[..self.extended_capability_count]
+    }
+
+    fn capability(&self, id: PciCapabilityId) -> Option<u16> {
+        self.capabilities()
+            .iter()
+            .find(|record| record.id == id)
+            .map(|record| record.offset.0)
+    }
+
+    fn extended_capability(&self, id: PciExtendedCapabilityId) -> Option<u16> {
+        self.extended_capabilities()
+            .iter()
+            .find(|record| record.id == id)
+            .map(|record| record.offset.0)
+    }
+
+    fn decode_capabilities<A: PciConfigAccess + ?Sized>(
+        &mut self,
+        registers: &Registers<'_, A>,
+    ) -> Result<(), PciError> {
+        if registers.read_u16(REG_STATUS)? & STATUS_CAPABILITIES == 0 {
+            return Ok(());
+        }
+        let pointer_register = match self.profile.header_type {
+            PciHeaderType::Type2 => REG_TYPE2_CAPABILITIES,
+            _ => REG_TYPE0_CAPABILITIES,
+        };
+        let mut pointer = registers.read_u8(pointer_register)? & CAPABILITY_POINTER_MASK;
+        // The list lives in the 192 bytes past the header, so a longer chain is a loop.
+        while pointer >= CAPABILITY_FIRST && self.capability_count < PCI_ECAM_MAX_CAPABILITIES {
+            let offset = u16::from(pointer);
+            let id = registers.read_u8(offset)?;
+            let next = registers.read_u8(offset + 1)? & CAPABILITY_POINTER_MASK;
+            self.capabilities[self.capability_count] = PciCapabilityRecord {
+                id: PciCapabilityId::from_u8(id),
+                offset: PciConfigOffset(offset),
+                next: (next >= CAPABILITY_FIRST).then_some(PciConfigOffset(u16::from(next))),
+            };
+            self.capability_count += 1;
+            pointer = next;
+        }
+        Ok(())
+    }
+
+    fn decode_extended_capabilities<A: PciConfigAccess + ?Sized>(
+        &mut self,
+        registers: &Registers<'_, A>,
+    ) -> Result<(), PciError> {
+        let mut offset = EXTENDED_CAPABILITY_FIRST;
+        loop {
+            let header = registers.read_u32(offset)?;
+            if header == 0 || header == u32::MAX {
+                return Ok(());
+            }
+            if self.extended_capability_count == PCI_ECAM_MAX_EXTENDED_CAPABILITIES {
+                return Err(PciError::resource_exhausted());
+            }
+            let next = ((header >> 20) & 0xffc) as u16;
+            let next = (next > offset).then_some(next);
+            self.extended_capabilities[self.extended_capability_count] =
+                PciExtendedCapabilityRecord {
+                    id: PciExtendedCapabilityId::from_u16((header & 0xffff) as u16),
+                    version: ((header >> 16) & 0xf) as u8,
+                    offset: PciConfigOffset(offset),
+                    next: next.map(PciConfigOffset),
+                };
+            self.extended_capability_count += 1;
+            // Requiring forward progress rules out cycles without a separate visit set.
+            match next {
+                Some(next) => offset = next,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    fn decode_header<A: PciConfigAccess + ?Sized>(
+        &mut self,
+        registers: &Registers<'_, A>,
+    ) -> Result<(), PciError> {
+        let pcie = self.capability(PciCapabilityId::PciExpress).is_some();
+        if pcie {
+            self.profile.transport_family = PciTransportFamily::PciExpress;
+            self.profile.configuration_model = PciConfigurationModel::Enhanced4KiB;
+        } else if self.capability(PciCapabilityId::PciX).is_some() {
+            self.profile.transport_family = PciTransportFamily::PciX;
+        }
+        self.profile.kind = match self.profile.header_type {
+            PciHeaderType::Type0 => PciFunctionKind::Endpoint,
+            PciHeaderType::Type1 => PciFunctionKind::Bridge,
+            PciHeaderType::Type2 => PciFunctionKind::CardBusBridge,
+            PciHeaderType::Other(_) => PciFunctionKind::Unknown,
+        };
+
+        let subsystem = match self.profile.header_type {
+            PciHeaderType::Type0 => Some((REG_TYPE0_SUBSYSTEM_VENDOR_ID, REG_TYPE0_SUBSYSTEM_ID)),
+            PciHeaderType::Type1 => self
+                .capability(PciCapabilityId::BridgeSubsystemVendor)
+                .map(|offset| (offset + 4, offset + 6)),
+            PciHeaderType::Type2 => Some((REG_TYPE2_SUBSYSTEM_VENDOR_ID, REG_TYPE2_SUBSYSTEM_ID)),
+            PciHeaderType::Other(_) => None,
+        };
+        if let Some((vendor, device)) = subsystem {
+            self.identity.subsystem_vendor_id =
+                Some(PciSubsystemVendorId(registers.read_u16(vendor)?));
+            self.identity.subsystem_id = Some(PciSubsystemId(registers.read_u16(device)?));
+        }
+
+        let buses = match self.profile.header_type {
+            PciHeaderType::Type1 => Some((REG_TYPE1_SECONDARY_BUS, REG_TYPE1_SUBORDINATE_BUS)),
+            PciHeaderType::Type2 => Some((REG_TYPE2_SECONDARY_BUS, REG_TYPE2_SUBORDINATE_BUS)),
+            _ => None,
+        };
+        if let Some((secondary, subordinate)) = buses {
+            self.topology.secondary_bus = Some(PciBus(registers.read_u8(secondary)?));
+            self.topology.subordinate_bus = Some(PciBus(registers.read_u8(subordinate)?));
+        }
+        Ok(())
+    }
+
+    fn decode_resources<A: PciConfigAccess + ?Sized>(
+        &mut self,
+        registers: &Registers<'_, A>,
+    ) -> Result<(), PciError> {
+        let (bar_slots, rom_register) = match self.profile.header_type {
+            PciHeaderType::Type0 => (6, Some(REG_TYPE0_ROM)),
+            PciHeaderType::Type1 => (2, Some(REG_TYPE1_ROM)),
+            PciHeaderType::Type2 => (1, None),
+            PciHeaderType::Other(_) => (0, None),
+        };
+        if self.profile.header_type == PciHeaderType::Type1 {
+            self.decode_bridge_windows(registers)?;
+        }
+        if bar_slots == 0 {
+            return Ok(());
+        }
+
+        // Sizing writes all-ones into live decoders, so turn decode off around the probe.
+        let command = registers.read_u16(REG_COMMAND)?;
+        let sizable = match registers.write_u16(REG_COMMAND, command & !COMMAND_DECODE) {
+            Ok(()) => true,
+            Err(error) if error.kind() == PciErrorKind::Unsupported => false,
+            Err(error) => return Err(error),
+        };
+        let decoded = self.decode_bars(registers, bar_slots).and_then(|()| {
+            rom_register.map_or(Ok(None), |register| decode_rom(registers, register))
+        });
+        if sizable {
+            registers.write_u16(REG_COMMAND, command)?;
+        }
+        self.option_rom = decoded?;
+        Ok(())
+    }
+
+    fn decode_bars<A: PciConfigAccess + ?Sized>(
+        &mut self,
+        registers: &Registers<'_, A>,
+        slots: u8,
+    ) -> Result<(), PciError> {
+        let mut index = 0;
+        while index < slots {
+            let offset = REG_BAR0 + u16::from(index) * 4;
+            let original = registers.read_u32(offset)?;
+            let mask = registers.size_probe(offset, original, u32::MAX)?;
+            let bar = if original & BAR_IO != 0 {
+                let size = mask.map_or(0, |mask| match mask & BAR_IO_MASK {
+                    0 => 0,
+                    // Devices may leave the upper half of an I/O BAR unimplemented.
+                    bits if bits & 0xffff_0000 == 0 => u64::from((!(bits | 0xffff_0000)) + 1),
+                    bits => u64::from((!bits).wrapping_add(1)),
+                });
+                PciBarDescriptor {
+                    index,
+                    kind: PciBarKind::Io,
+                    base: u64::from(original & BAR_IO_MASK),
+                    size,
+                    prefetchable: false,
+                    implemented: size != 0 || (mask.is_none() && original != 0),
+                }
+            } else if original & 0x6 == BAR_MEMORY_64 && index + 1 < slots {
+                let upper_offset = offset + 4;
+                let upper = registers.read_u32(upper_offset)?;
+                let upper_mask = registers.size_probe(upper_offset, upper, u32::MAX)?;
+                let base = (u64::from(upper) << 32) | u64::from(original & BAR_MEMORY_MASK);
+                let size = mask.zip(upper_mask).map_or(0, |(lower, upper)| {
+                    match (u64::from(upper) << 32) | u64::from(lower & BAR_MEMORY_MASK) {
+                        0 => 0,
+                        bits => (!bits).wrapping_add(1),
+                    }
+                });
+                PciBarDescriptor {
+                    index,
+                    kind: PciBarKind::Memory64,
+                    base,
+                    size,
+                    prefetchable: original & BAR_PREFETCHABLE != 0,
+                    implemented: size != 0 || (mask.is_none() && base != 0),
+                }
+            } else {
+                let size = mask.map_or(0, |mask| match mask & BAR_MEMORY_MASK {
+                    0 => 0,
+                    bits => u64::from((!bits).wrapping_add(1)),
+                });
+                PciBarDescriptor {
+                    index,
+                    kind: PciBarKind::Memory32,
+                    base: u64::from(original & BAR_MEMORY_MASK),
+                    size,
+                    prefetchable: original & BAR_PREFETCHABLE != 0,
+                    implemented: size != 0 || (mask.is_none() && original != 0),
+                }
+            };
+            index += if bar.kind == PciBarKind::Memory64 {
+                2
+            } else {
+                1
+            };
+            self.bars[self.bar_count] = bar;
+            self.bar_count += 1;
+        }
+        Ok(())
+    }
+
+    fn decode_bridge_windows<A: PciConfigAccess + ?Sized>(
+        &mut self,
+        registers: &Registers<'_, A>,
+    ) -> Result<(), PciError> {
+        // Unimplemented optional windows read back as all-zero base and limit registers.
+        let io_base = registers.read_u8(REG_TYPE1_IO_BASE)?;
+        let io_limit = registers.read_u8(REG_TYPE1_IO_LIMIT)?;
+        if io_base != 0 || io_limit != 0 {
+            let (mut base, mut limit) = (
+                u64::from(io_base & 0xf0) << 8,
+                (u64::from(io_limit & 0xf0) << 8) | 0xfff,
+            );
+            if io_base & 0x0f == 0x01 {
+                base |= u64::from(registers.read_u16(REG_TYPE1_IO_BASE_UPPER)?) << 16;
+                limit |= u64::from(registers.read_u16(REG_TYPE1_IO_LIMIT_UPPER)?) << 16;
+            }
+            self.push_bridge_window(PciBridgeWindowKind::Io, base, limit, false);
+        }
+
+        let memory_base = registers.read_u16(REG_TYPE1_MEMORY_BASE)?;
+        let memory_limit = registers.read_u16(REG_TYPE1_MEMORY_LIMIT)?;
+        if memory_base != 0 || memory_limit != 0 {
+            self.push_bridge_window(
+                PciBridgeWindowKind::Memory32,
+                u64::from(memory_base & 0xfff0) << 16,
+                (u64::from(memory_limit & 0xfff0) << 16) | 0xf_ffff,
+                false,
+            );
+        }
+
+        let prefetch_base = registers.read_u16(REG_TYPE1_PREFETCH_BASE)?;
+        let prefetch_limit = registers.read_u16(REG_TYPE1_PREFETCH_LIMIT)?;
+        if prefetch_base != 0 || prefetch_limit != 0 {
+            let (mut base, mut limit) = (
+                u64::from(prefetch_base & 0xfff0) << 16,
+                (u64::from(prefetch_limit & 0xfff0) << 16) | 0xf_ffff,
+            );
+            let kind = if prefetch_base & 0x0f == 0x01 {
+                base |= u64::from(registers.read_u32(REG_TYPE1_PREFETCH_BASE_UPPER)?) << 32;
+                limit |= u64::from(registers.read_u32(REG_TYPE1_PREFETCH_LIMIT_UPPER)?) << 32;
+                PciBridgeWindowKind::Memory64
+            } else {
+                PciBridgeWindowKind::Memory32
+            };
+            self.push_bridge_window(kind, base, limit, true);
+        }
+        Ok(())
+    }
+
+    const fn push_bridge_window(
+        &mut self,
+        kind: PciBridgeWindowKind,
+        base: u64,
+        limit: u64,
+        prefetchable: bool,
+    ) {
+        // Firmware disables a window by programming its limit below its base.
+        if limit < base {
+            return;
+        }
+        self.bridge_windows[self.bridge_window_count] = PciBridgeWindow {
+            kind,
+            base,
+            limit,
+            prefetchable,
+        };
+        self.bridge_window_count += 1;
+    }
+
+    fn decode_pcie<A: PciConfigAccess + ?Sized>(
+        &mut self,
+        registers: &Registers<'_, A>,
+    ) -> Result<(), PciError> {
+        let Some(cap) = self.capability(PciCapabilityId::PciExpress) else {
+            return Ok(());
+        };
+        let flags = registers.read_u16(cap + 0x02)?;
+        let port_type = port_type(((flags >> 4) & 0x0f) as u8);
+        let slot_implemented = flags & 0x0100 != 0;
+        let link_capabilities = registers.read_u32(cap + 0x0c)?;
+        let link_control = registers.read_u16(cap + 0x10)?;
+        let link_status = registers.read_u16(cap + 0x12)?;
+        let dll_reporting = link_capabilities & (1 << 20) != 0;
+        let downstream_port = matches!(
+            port_type,
+            PciExpressDevicePortType::RootPort
+                | PciExpressDevicePortType::DownstreamSwitchPort
+                | PciExpressDevicePortType::PciToPcieBridge
+        );
+
+        self.profile.kind = match port_type {
+            PciExpressDevicePortType::Endpoint => PciFunctionKind::Endpoint,
+            PciExpressDevicePortType::LegacyEndpoint => PciFunctionKind::LegacyEndpoint,
+            PciExpressDevicePortType::RootPort => PciFunctionKind::RootPort,
+            PciExpressDevicePortType::UpstreamSwitchPort => PciFunctionKind::UpstreamSwitchPort,
+            PciExpressDevicePortType::DownstreamSwitchPort => PciFunctionKind::DownstreamSwitchPort,
+            PciExpressDevicePortType::PcieToPciBridge => PciFunctionKind::PcieToPciBridge,
+            PciExpressDevicePortType::PciToPcieBridge => PciFunctionKind::PciToPcieBridge,
+            PciExpressDevicePortType::RootComplexIntegratedEndpoint => {
+                PciFunctionKind::RootComplexIntegratedEndpoint
+            }
+            PciExpressDevicePortType::RootComplexEventCollector => {
+                PciFunctionKind::RootComplexEventCollector
+            }
+            PciExpressDevicePortType::Reserved(_) => self.profile.kind,
+        };
+        self.power.aspm_supported = (link_capabilities >> 10) & 0x3 != 0;
+        self.power.aspm_enabled = link_control & 0x3 != 0;
+
+        let mut profile = PciExpressProfile {
+            capability_version: Some(PciExpressVersion((flags & 0x0f) as u8)),
+            device_port_type: Some(port_type),
+            max_link_speed: link_speed((link_capabilities & 0x0f) as u8),
+            current_link_speed: link_speed((link_status & 0x0f) as u8),
+            max_link_width: link_width(((link_capabilities >> 4) & 0x3f) as u8),
+            current_link_width: link_width(((link_status >> 4) & 0x3f) as u8),
+            slot_implemented,
+            hotplug_capable: false,
+            surprise_hotplug_capable: false,
+            dll_link_active_reporting_capable: dll_reporting,
+            dll_link_active: dll_reporting.then_some(link_status & (1 << 13) != 0),
+            link_training: downstream_port.then_some(link_status & (1 << 11) != 0),
+        };
+
+        if slot_implemented {
+            let slot_capabilities = registers.read_u32(cap + 0x14)?;
+            let slot_status = registers.read_u16(cap + 0x1a)?;
+            let bit = |index: u32| slot_capabilities & (1 << index) != 0;
+            let hotplug = PciHotplugProfile {
+                hotplug_capable: bit(6),
+                surprise_hotplug_capable: bit(5),
+                power_controller_present: bit(1),
+                attention_button_present: bit(0),
+                attention_indicator_present: bit(3),
+                power_indicator_present: bit(4),
+                mrl_sensor_present: bit(2),
+                slot_present: Some(slot_status & (1 << 6) != 0),
+                power_fault: bit(1).then_some(slot_status & (1 << 1) != 0),
+                latch_open: bit(2).then_some(slot_status & (1 << 5) != 0),
+            };
+            profile.hotplug_capable = hotplug.hotplug_capable;
+            profile.surprise_hotplug_capable = hotplug.surprise_hotplug_capable;
+            self.hotplug = Some(hotplug);
+            self.topology.slot = u8::try_from(slot_capabilities >> 19).ok();
+        }
+        self.pcie = Some(profile);
+        Ok(())
+    }
+
+    fn decode_interrupts<A: PciConfigAccess + ?Sized>(
+        &mut self,
+        registers: &Registers<'_, A>,
+    ) -> Result<(), PciError> {
+        self.interrupts.legacy_pin =
+            PciInterruptPin::from_u8(registers.read_u8(REG_INTERRUPT_PIN)?);
+        if let Some(cap) = self.capability(PciCapabilityId::Msi) {
+            let control = registers.read_u16(cap + 0x02)?;
+            self.interrupts.msi = Some(PciMsiProfile {
+                vector_count: 1 << ((control >> 1) & 0x7).min(5),
+                is_64_bit: control & (1 << 7) != 0,
+                per_vector_masking: control & (1 << 8) != 0,
+            });
+        }
+        if let Some(cap) = self.capability(PciCapabilityId::Msix) {
+            let control = registers.read_u16(cap + 0x02)?;
+            self.interrupts.msix = Some(PciMsixProfile {
+                table_size: (control & 0x07ff) + 1,
+                masked: control & (1 << 14) != 0,
+            });
+        }
+        Ok(())
+    }
+
+    fn decode_power<A: PciConfigAccess + ?Sized>(
+        &mut self,
+        registers: &Registers<'_, A>,
+    ) -> Result<(), PciError> {
+        let Some(cap) = self.capability(PciCapabilityId::PowerManagement) else {
+            return Ok(());
+        };
+        let capabilities = registers.read_u16(cap + 0x02)?;
+        let control = registers.read_u16(cap + 0x04)?;
+        self.power.capability_version = Some((capabilities & 0x7) as u8);
+        self.power.aux_current_ma = Some(PM_AUX_CURRENT_MA[usize::from((capabilities >> 6) & 0x7)]);
+        self.power.pme_supported = capabilities >> 11 != 0;
+        self.power.pme_enabled = control & (1 << 8) != 0;
+        self.power.current_state = Some(match control & 0x3 {
+            0 => PciPowerState::D0,
+            1 => PciPowerState::D1,
+            2 => PciPowerState::D2,
+            _ => PciPowerState::D3Hot,
+        });
+        Ok(())
+    }
+
+    fn decode_dma<A: PciConfigAccess + ?Sized>(
+        &mut self,
+        registers: &Registers<'_, A>,
+    ) -> Result<(), PciError> {
+        self.dma.bus_master_capable =
+            self.pcie.is_some() || registers.read_u16(REG_COMMAND)? & COMMAND_BUS_MASTER != 0;
+        self.dma.ats = self
+            .extended_capability(PciExtendedCapabilityId::AddressTranslationServices)
+            .is_some();
+        self.dma.pri = self
+            .extended_capability(PciExtendedCapabilityId::PageRequestInterface)
+            .is_some();
+        self.dma.pasid = self
+            .extended_capability(PciExtendedCapabilityId::ProcessAddressSpaceId)
+            .is_some();
+        self.dma.acs = self
+            .extended_capability(PciExtendedCapabilityId::AccessControlServices)
+            .is_some();
+        Ok(())
+    }
+
+    fn decode_error_reporting<A: PciConfigAccess + ?Sized>(
+        &mut self,
+        registers: &Registers<'_, A>,
+    ) -> Result<(), PciError> {
+        self.error_reporting.downstream_port_containment = self
+            .extended_capability(PciExtendedCapabilityId::DownstreamPortContainment)
+            .is_some();
+        if let Some(cap) = self.extended_capability(PciExtendedCapabilityId::AdvancedErrorReporting)
+        {
+            let control = registers.read_u32(cap + 0x18)?;
+            self.error_reporting.advanced_error_reporting = true;
+            self.error_reporting.ecrc_generation_capable = control & (1 << 5) != 0;
+            self.error_reporting.ecrc_checking_capable = control & (1 << 7) != 0;
+        }
+        Ok(())
+    }
+
+    fn decode_virtualization<A: PciConfigAccess + ?Sized>(
+        &mut self,
+        registers: &Registers<'_, A>,
+    ) -> Result<(), PciError> {
+        self.virtualization.ari = self
+            .extended_capability(PciExtendedCapabilityId::AlternativeRoutingIdInterpretation)
+            .is_some();
+        if let Some(cap) =
+            self.extended_capability(PciExtendedCapabilityId::SingleRootIoVirtualization)
+        {
+            self.virtualization.sr_iov = Some(PciSriovProfile {
+                initial_vfs: registers.read_u16(cap + 0x0c)?,
+                total_vfs: registers.read_u16(cap + 0x0e)?,
+                enabled_vfs: registers.read_u16(cap + 0x10)?,
+                vf_stride: registers.read_u16(cap + 0x16)?,
+                vf_device_id: Some(PciDeviceId(registers.read_u16(cap + 0x1a)?)),
+            });
+        }
+        Ok(())
+    }
+}
+
+fn decode_rom<A>(
+    registers: &Registers<'_, A>,
+    register: u16,
+) -> Result<Option<PciRomDescriptor>, PciError>
+where
+    A: PciConfigAccess + ?Sized,
+{
+    let original = registers.read_u32(register)?;
+    let base = u64::from(original & ROM_ADDRESS_MASK);
+    let enabled = original & ROM_ENABLE != 0;
+    let Some(mask) = registers.size_probe(register, original, ROM_ADDRESS_MASK)? else {
+        return Ok((base != 0).then_some(PciRomDescriptor {
+            base,
+            size: 0,
+            enabled,
+        }));
+    };
+    Ok(match mask & ROM_ADDRESS_MASK {
+        0 => None,
+        bits => Some(PciRomDescriptor {
+            base,
+            size: u64::from((!bits).wrapping_add(1)),
+            enabled,
+        }),
+    })
+}
+
+const fn port_type(raw: u8) -> PciExpressDevicePortType {
+    match raw {
+        0x0 => PciExpressDevicePortType::Endpoint,
+        0x1 => PciExpressDevicePortType::LegacyEndpoint,
+        0x4 => PciExpressDevicePortType::RootPort,
+        0x5 => PciExpressDevicePortType::UpstreamSwitchPort,
+        0x6 => PciExpressDevicePortType::DownstreamSwitchPort,
+        0x7 => PciExpressDevicePortType::PcieToPciBridge,
+        0x8 => PciExpressDevicePortType::PciToPcieBridge,
+        0x9 => PciExpressDevicePortType::RootComplexIntegratedEndpoint,
+        0xa => PciExpressDevicePortType::RootComplexEventCollector,
+        other => PciExpressDevicePortType::Reserved(other),
+    }
+}
+
+const fn link_speed(raw: u8) -> Option<PciLinkSpeed> {
+    match raw {
+        0 => None,
+        1 => Some(PciLinkSpeed::Gen1),
+        2 => Some(PciLinkSpeed::Gen2),
+        3 => Some(PciLinkSpeed::Gen3),
+        4 => Some(PciLinkSpeed::Gen4),
+        5 => Some(PciLinkSpeed::Gen5),
+        6 => Some(PciLinkSpeed::Gen6),
+        other => Some(PciLinkSpeed::Other(other)),
+    }
+}
+
+const fn link_width(raw: u8) -> Option<PciLinkWidth> {
+    match raw {
+        0 => None,
+        width => Some(PciLinkWidth(width)),
+    }
+}
+
+const EMPTY_BAR: PciBarDescriptor = PciBarDescriptor {
+    index: 0,
+    kind: PciBarKind::Memory32,
+    base: 0,
+    size: 0,
+    prefetchable: false,
+    implemented: false,
+};
+const EMPTY_BRIDGE_WINDOW: PciBridgeWindow = PciBridgeWindow {
+    kind: PciBridgeWindowKind::Memory32,
+    base: 0,
+    limit: 0,
+    prefetchable: false,
+};
+const EMPTY_CAPABILITY: PciCapabilityRecord = PciCapabilityRecord {
+    id: PciCapabilityId::Other(0),
+    offset: PciConfigOffset(0),
+    next: None,
+};
+const EMPTY_EXTENDED_CAPABILITY: PciExtendedCapabilityRecord = PciExtendedCapabilityRecord {
+    id: PciExtendedCapabilityId::Other(0),
+    version: 0,
+    offset: PciConfigOffset(0),
+    next: None,
+};
+
+impl<A> PciHardwareFunction for PciEcamFunction<A>
+where
+    A: PciConfigAccess,
+{
+    fn address(&self) -> PciFunctionAddress {
+        self.header.address
+    }
+
+    fn identity(&self) -> PciFunctionIdentity {
+        self.header.identity
+    }
+
+    fn profile(&self) -> PciFunctionProfile {
+        self.header.profile
+    }
+
+    fn bars(&self) -> &[PciBarDescriptor] {
+        &self.header.bars[..self.header.bar_count]
+    }
+
+    fn bridge_windows(&self) -> &[PciBridgeWindow] {
+        &self.header.bridge_windows[..self.header.bridge_window_count]
+    }
+
+    fn option_rom(&self) -> Option<PciRomDescriptor> {
+        self.header.option_rom
+    }
+
+    fn capabilities(&self) -> &[PciCapabilityRecord] {
+        self.header.capabilities()
+    }
+
+    fn extended_capabilities(&self) -> &[PciExtendedCapabilityRecord] {
+        self.header.extended_capabilities()
+    }
+
+    fn topology_profile(&self) -> PciTopologyProfile {
+        self.header.topology
+    }
+
+    fn interrupt_profile(&self) -> PciInterruptProfile {
+        self.header.interrupts
+    }
+
+    fn dma_profile(&self) -> PciDmaProfile {
+        self.header.dma
+    }
+
+    fn power_profile(&self) -> PciPowerProfile {
+        self.header.power
+    }
+
+    fn error_reporting_profile(&self) -> PciErrorReportingProfile {
+        self.header.error_reporting
+    }
+
+    fn virtualization_profile(&self) -> PciVirtualizationProfile {
+        self.header.virtualization
+    }
+
+    fn hotplug_profile(&self) -> Option<PciHotplugProfile> {
+        self.header.hotplug
+    }
+
+    fn pcie_profile(&self) -> Option<PciExpressProfile> {
+        self.header.pcie
+    }
+
+    fn read_config_u8(&self, offset: PciConfigOffset) -> Result<u8, PciError> {
+        self.access.read_u8(self.config_offset(offset, 1)?)
+    }
+
+    fn read_config_u16(&self, offset: PciConfigOffset) -> Result<u16, PciError> {
+        self.access.read_u16(self.config_offset(offset, 2)?)
+    }
+
+    fn read_config_u32(&self, offset: PciConfigOffset) -> Result<u32, PciError> {
+        self.access.read_u32(self.config_offset(offset, 4)?)
+    }
+
+    fn write_config_u8(&mut self, offset: PciConfigOffset, value: u8) -> Result<(), PciError> {
+        self.access.write_u8(self.config_offset(offset, 1)?, value)
+    }
+
+    fn write_config_u16(&mut self, offset: PciConfigOffset, value: u16) -> Result<(), PciError> {
+        self.access.write_u16(self.config_offset(offset, 2)?, value)
+    }
+
+    fn write_config_u32(&mut self, offset: PciConfigOffset, value: u32) -> Result<(), PciError> {
+        self.access.write_u32(self.config_offset(offset, 4)?, value)
+    }
+}
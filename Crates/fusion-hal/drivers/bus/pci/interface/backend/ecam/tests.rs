@@ -0,0 +1,553 @@
+extern crate std;
+
+use std::boxed::Box;
+use std::collections::BTreeMap;
+use std::fs;
+use std::sync::{
+    Mutex,
+    OnceLock,
+};
+use std::vec;
+use std::vec::Vec;
+
+use fusion_hal::contract::drivers::bus::pci::{
+    PciBarKind,
+    PciBridgeWindowKind,
+    PciCapabilityId,
+    PciConfigOffset,
+    PciErrorKind,
+    PciExpressDevicePortType,
+    PciExtendedCapabilityId,
+    PciFunctionKind,
+    PciInterruptPin,
+    PciLinkSpeed,
+    PciLinkWidth,
+    PciPowerState,
+    PciTransportFamily,
+};
+
+use super::*;
+use crate::interface::contract::PciHardwareFunction;
+
+const TEST_CONTROLLER: PciControllerDescriptor = PciControllerDescriptor {
+    id: "test-ecam",
+    name: "Test ECAM",
+};
+const EMULATED_WINDOWS: [PciEcamWindow; 1] =
+    [PciEcamWindow::new(TEST_CONTROLLER, 0xb000_0000, 0, 0, 3)];
+
+const fn address(bus: u8, device: u8, function: u8) -> PciFunctionAddress {
+    match (PciDevice::from_u8(device), PciFunction::from_u8(function)) {
+        (Some(device), Some(function)) => PciFunctionAddress {
+            segment: PciSegment(0),
+            bus: PciBus(bus),
+            device,
+            function,
+        },
+        _ => panic!("invalid test pci address"),
+    }
+}
+
+const fn offset(bus: u8, device: u8, function: u8) -> usize {
+    (bus as usize) << 20 | (device as usize) << 15 | (function as usize) << 12
+}
+
+/// Writable ECAM image where BAR registers only latch their implemented address bits.
+#[derive(Debug)]
+struct EmulatedEcam {
+    image: Mutex<Vec<u8>>,
+    // Window offset -> (writable mask, read-only flag bits).
+    decoders: BTreeMap<u64, (u32, u32)>,
+}
+
+impl EmulatedEcam {
+    fn read<const N: usize>(&self, offset: u64) -> [u8; N] {
+        let image = self.image.lock().expect("image lock");
+        let start = usize::try_from(offset).expect("offset");
+        image[start..start + N].try_into().expect("width")
+    }
+
+    fn write(&self, offset: u64, bytes: &[u8]) {
+        let mut image = self.image.lock().expect("image lock");
+        let start = usize::try_from(offset).expect("offset");
+        image[start..start + bytes.len()].copy_from_slice(bytes);
+    }
+}
+
+impl PciConfigAccess for EmulatedEcam {
+    fn window_len(&self) -> u64 {
+        self.image.lock().expect("image lock").len() as u64
+    }
+
+    fn read_u8(&self, offset: u64) -> Result<u8, PciError> {
+        Ok(self.read::<1>(offset)[0])
+    }
+
+    fn read_u16(&self, offset: u64) -> Result<u16, PciError> {
+        Ok(u16::from_le_bytes(self.read(offset)))
+    }
+
+    fn read_u32(&self, offset: u64) -> Result<u32, PciError> {
+        Ok(u32::from_le_bytes(self.read(offset)))
+    }
+
+    fn write_u8(&self, offset: u64, value: u8) -> Result<(), PciError> {
+        self.write(offset, &[value]);
+        Ok(())
+    }
+
+    fn write_u16(&self, offset: u64, value: u16) -> Result<(), PciError> {
+        self.write(offset, &value.to_le_bytes());
+        Ok(())
+    }
+
+    fn write_u32(&self, offset: u64, value: u32) -> Result<(), PciError> {
+        let value = self
+            .decoders
+            .get(&offset)
+            .map_or(value, |(mask, flags)| (value & mask) | flags);
+        self.write(offset, &value.to_le_bytes());
+        Ok(())
+    }
+}
+
+struct ImageBuilder {
+    image: Vec<u8>,
+    decoders: BTreeMap<u64, (u32, u32)>,
+}
+
+impl ImageBuilder {
+    fn new(buses: usize) -> Self {
+        Self {
+            image: vec![0xff; buses << 20],
+            decoders: BTreeMap::new(),
+        }
+    }
+
+    fn function(&mut self, bus: u8, device: u8, function: u8, vendor: u16, id: u16) -> usize {
+        let base = offset(bus, device, function);
+        self.image[base..base + 4096].fill(0);
+        self.put16(base, 0x00, vendor);
+        self.put16(base, 0x02, id);
+        base
+    }
+
+    fn put8(&mut self, base: usize, register: usize, value: u8) {
+        self.image[base + register] = value;
+    }
+
+    fn put16(&mut self, base: usize, register: usize, value: u16) {
+        self.image[base + register..base + register + 2].copy_from_slice(&value.to_le_bytes());
+    }
+
+    fn put32(&mut self, base: usize, register: usize, value: u32) {
+        self.image[base + register..base + register + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    fn decoder(&mut self, base: usize, register: usize, value: u32, mask: u32, flags: u32) {
+        self.put32(base, register, value | flags);
+        self.decoders
+            .insert((base + register) as u64, (mask, flags));
+    }
+
+    fn finish(self) -> EmulatedEcam {
+        EmulatedEcam {
+            image: Mutex::new(self.image),
+            decoders: self.decoders,
+        }
+    }
+}
+
+// One small hierarchy:
+//
+// - 00:00.0 multi-function PCIe endpoint with a 64-bit prefetchable BAR, an I/O BAR, a ROM,
+//   PM/MSI/PCIe capabilities, and AER/SR-IOV extended capabilities.
+// - 00:00.1 bare conventional endpoint.
+// - 00:01.0 PCIe root port bridging to bus 1, with a hot-plug slot.
+// - 01:00.0 PCIe endpoint with MSI-X.
+// - 02:00.0 endpoint no bridge routes to, which enumeration must not reach.
+fn emulated() -> &'static EmulatedEcam {
+    static ECAM: OnceLock<EmulatedEcam> = OnceLock::new();
+    ECAM.get_or_init(|| {
+        let mut image = ImageBuilder::new(4);
+
+        let ep = image.function(0, 0, 0, 0x8086, 0x1234);
+        image.put16(ep, 0x04, 0x0006);
+        image.put16(ep, 0x06, 0x0010);
+        image.put8(ep, 0x08, 0x03);
+        image.put8(ep, 0x0b, 0x02);
+        image.put8(ep, 0x0e, 0x80);
+        image.decoder(ep, 0x10, 0x0000_0000, 0xffff_c000, 0x0000_000c);
+        image.decoder(ep, 0x14, 0x0000_0040, 0xffff_ffff, 0);
+        image.decoder(ep, 0x18, 0x0000_e000, 0xffff_ff00, 0x0000_0001);
+        for unimplemented in [0x1c, 0x20, 0x24] {
+            image.decoder(ep, unimplemented, 0, 0, 0);
+        }
+        image.decoder(ep, 0x30, 0xfe80_0000, 0xffff_0000, 0);
+        image.put16(ep, 0x2c, 0x8086);
+        image.put16(ep, 0x2e, 0x0001);
+        image.put8(ep, 0x34, 0x40);
+        image.put8(ep, 0x3d, 0x01);
+        // Power management: version 3, 375 mA aux, PME from D3hot, currently D0.
+        image.put16(ep, 0x40, 0x5001);
+        image.put16(ep, 0x42, 0x41c3);
+        // MSI: 64-bit, 8 vectors, per-vector masking.
+        image.put16(ep, 0x50, 0x6005);
+        image.put16(ep, 0x52, 0x0186);
+        // PCIe v2 endpoint, Gen3 x4 capable, trained Gen3 x2, ASPM L1 enabled.
+        image.put16(ep, 0x60, 0x0010);
+        image.put16(ep, 0x62, 0x0002);
+        image.put32(ep, 0x6c, 0x0000_0843);
+        image.put16(ep, 0x70, 0x0002);
+        image.put16(ep, 0x72, 0x0023);
+        // AER v2 with ECRC generation and checking.
+        image.put32(ep, 0x100, 0x1402_0001);
+        image.put32(ep, 0x118, 0x0000_00a0);
+        // SR-IOV v1: 8 total/initial VFs, 2 enabled, stride 1, VF device 0x1235.
+        image.put32(ep, 0x140, 0x0001_0010);
+        image.put16(ep, 0x14c, 8);
+        image.put16(ep, 0x14e, 8);
+        image.put16(ep, 0x150, 2);
+        image.put16(ep, 0x156, 1);
+        image.put16(ep, 0x15a, 0x1235);
+
+        let second = image.function(0, 0, 1, 0x8086, 0x1236);
+        image.put8(second, 0x0b, 0x0c);
+
+        let port = image.function(0, 1, 0, 0x8086, 0x7000);
+        image.put16(port, 0x06, 0x0010);
+        image.put8(port, 0x0b, 0x06);
+        image.put8(port, 0x0a, 0x04);
+        image.put8(port, 0x0e, 0x01);
+        image.put8(port, 0x19, 0x01);
+        image.put8(port, 0x1a, 0x01);
+        image.put8(port, 0x1c, 0xf0);
+        image.put8(port, 0x1d, 0x00);
+        image.put16(port, 0x20, 0xfe00);
+        image.put16(port, 0x22, 0xfe00);
+        image.put16(port, 0x24, 0x0001);
+        image.put16(port, 0x26, 0x0fff);
+        image.put32(port, 0x28, 0x0000_0004);
+        image.put32(port, 0x2c, 0x0000_0004);
+        image.put8(port, 0x34, 0x40);
+        // PCIe v2 root port with a slot; Gen4 x16, DLL active reporting; slot 5 hot-plug.
+        image.put16(port, 0x40, 0x0010);
+        image.put16(port, 0x42, 0x0142);
+        image.put32(port, 0x4c, 0x0010_0104);
+        image.put16(port, 0x52, 0x2104);
+        image.put32(port, 0x54, (5 << 19) | 0x0000_007f);
+        image.put16(port, 0x5a, 0x0040);
+
+        let child = image.function(1, 0, 0, 0x1af4, 0x1041);
+        image.put16(child, 0x06, 0x0010);
+        image.put8(child, 0x34, 0x40);
+        image.put16(child, 0x40, 0x0011);
+        image.put16(child, 0x42, 0x4003);
+
+        image.function(2, 0, 0, 0x1af4, 0x1042);
+        image.finish()
+    })
+}
+
+struct EmulatedPlatform;
+
+impl PciEcamPlatform for EmulatedPlatform {
+    type Access = EmulatedEcam;
+
+    fn windows() -> &'static [PciEcamWindow] {
+        &EMULATED_WINDOWS
+    }
+
+    fn access(provider: u8) -> Option<&'static Self::Access> {
+        (provider == 0).then(emulated)
+    }
+}
+
+type EmulatedHardware = PciEcamHardware<EmulatedPlatform>;
+
+fn open(address: PciFunctionAddress) -> PciEcamFunction<&'static EmulatedEcam> {
+    EmulatedHardware::function(0, address)
+        .expect("function access")
+        .expect("function present")
+}
+
+#[test]
+fn enumerates_functions_reachable_through_bridges() {
+    let mut out = [address(0, 0, 0); 8];
+    let count = EmulatedHardware::enumerate_functions(0, &mut out).expect("enumerate");
+
+    assert_eq!(
+        &out[..count],
+        &[
+            address(0, 0, 0),
+            address(0, 0, 1),
+            address(0, 1, 0),
+            address(1, 0, 0)
+        ]
+    );
+    assert_eq!(
+        EmulatedHardware::enumerate_functions(0, &mut out[..2])
+            .expect_err("short output")
+            .kind(),
+        PciErrorKind::ResourceExhausted
+    );
+    assert!(
+        EmulatedHardware::function(0, address(0, 1, 1))
+            .expect("absent function access")
+            .is_none()
+    );
+}
+
+#[test]
+fn decodes_endpoint_identity_and_resources() {
+    let function = open(address(0, 0, 0));
+
+    let identity = function.identity();
+    assert_eq!(identity.vendor_id.0, 0x8086);
+    assert_eq!(identity.device_id.0, 0x1234);
+    assert_eq!(identity.subsystem_id.map(|id| id.0), Some(0x0001));
+    assert_eq!(identity.class_code.base, 0x02);
+    assert_eq!(identity.revision_id, 0x03);
+
+    let profile = function.profile();
+    assert_eq!(profile.transport_family, PciTransportFamily::PciExpress);
+    assert!(profile.multifunction);
+    assert_eq!(profile.kind, PciFunctionKind::Endpoint);
+
+    let bars = function.bars();
+    assert_eq!(bars.len(), 5);
+    assert_eq!(bars[0].kind, PciBarKind::Memory64);
+    assert_eq!(bars[0].base, 0x40_0000_0000);
+    assert_eq!(bars[0].size, 0x4000);
+    assert!(bars[0].prefetchable && bars[0].implemented);
+    assert_eq!(bars[1].index, 2);
+    assert_eq!(bars[1].kind, PciBarKind::Io);
+    assert_eq!(bars[1].base, 0xe000);
+    assert_eq!(bars[1].size, 0x100);
+    assert!(!bars[2].implemented);
+    let rom = function.option_rom().expect("rom");
+    assert_eq!(
+        (rom.base, rom.size, rom.enabled),
+        (0xfe80_0000, 0x1_0000, false)
+    );
+    // Sizing must leave the live registers exactly as firmware programmed them.
+    assert_eq!(
+        function
+            .read_config_u32(PciConfigOffset(0x14))
+            .expect("bar1"),
+        0x40
+    );
+    assert_eq!(
+        function
+            .read_config_u16(PciConfigOffset(0x04))
+            .expect("command"),
+        0x0006
+    );
+}
+
+#[test]
+fn decodes_endpoint_capability_profiles() {
+    let function = open(address(0, 0, 0));
+
+    let ids: Vec<_> = function.capabilities().iter().map(|cap| cap.id).collect();
+    assert_eq!(
+        ids,
+        [
+            PciCapabilityId::PowerManagement,
+            PciCapabilityId::Msi,
+            PciCapabilityId::PciExpress
+        ]
+    );
+    let ext: Vec<_> = function
+        .extended_capabilities()
+        .iter()
+        .map(|cap| (cap.id, cap.version))
+        .collect();
+    assert_eq!(
+        ext,
+        [
+            (PciExtendedCapabilityId::AdvancedErrorReporting, 2),
+            (PciExtendedCapabilityId::SingleRootIoVirtualization, 1)
+        ]
+    );
+
+    let pcie = function.pcie_profile().expect("pcie");
+    assert_eq!(
+        pcie.device_port_type,
+        Some(PciExpressDevicePortType::Endpoint)
+    );
+    assert_eq!(pcie.max_link_speed, Some(PciLinkSpeed::Gen3));
+    assert_eq!(pcie.max_link_width, Some(PciLinkWidth(4)));
+    assert_eq!(pcie.current_link_width, Some(PciLinkWidth(2)));
+    assert_eq!(pcie.link_training, None);
+
+    let interrupts = function.interrupt_profile();
+    assert_eq!(interrupts.legacy_pin, Some(PciInterruptPin::IntA));
+    let msi = interrupts.msi.expect("msi");
+    assert_eq!(msi.vector_count, 8);
+    assert!(msi.is_64_bit && msi.per_vector_masking);
+
+    let power = function.power_profile();
+    assert_eq!(power.capability_version, Some(3));
+    assert_eq!(power.current_state, Some(PciPowerState::D0));
+    assert_eq!(power.aux_current_ma, Some(375));
+    assert!(power.pme_supported && !power.pme_enabled);
+    assert!(power.aspm_supported && power.aspm_enabled);
+
+    let errors = function.error_reporting_profile();
+    assert!(errors.advanced_error_reporting);
+    assert!(errors.ecrc_generation_capable && errors.ecrc_checking_capable);
+
+    let sriov = function.virtualization_profile().sr_iov.expect("sr-iov");
+    assert_eq!(
+        (sriov.total_vfs, sriov.enabled_vfs, sriov.vf_stride),
+        (8, 2, 1)
+    );
+    assert_eq!(sriov.vf_device_id.map(|id| id.0), Some(0x1235));
+    assert!(function.dma_profile().bus_master_capable);
+}
+
+#[test]
+fn decodes_bridge_windows_topology_and_slot() {
+    let port = open(address(0, 1, 0));
+
+    assert_eq!(port.profile().kind, PciFunctionKind::RootPort);
+    let windows = port.bridge_windows();
+    assert_eq!(windows.len(), 2);
+    assert_eq!(windows[0].kind, PciBridgeWindowKind::Memory32);
+    assert_eq!(
+        (windows[0].base, windows[0].limit),
+        (0xfe00_0000, 0xfe0f_ffff)
+    );
+    assert_eq!(windows[1].kind, PciBridgeWindowKind::Memory64);
+    assert!(windows[1].prefetchable);
+    assert_eq!(
+        (windows[1].base, windows[1].limit),
+        (0x4_0000_0000, 0x4_0fff_ffff)
+    );
+
+    let topology = port.topology_profile();
+    assert_eq!(topology.parent, None);
+    assert_eq!(topology.secondary_bus, Some(PciBus(1)));
+    assert_eq!(topology.subordinate_bus, Some(PciBus(1)));
+    assert_eq!(topology.slot, Some(5));
+
+    let pcie = port.pcie_profile().expect("pcie");
+    assert_eq!(pcie.current_link_speed, Some(PciLinkSpeed::Gen4));
+    assert_eq!(pcie.current_link_width, Some(PciLinkWidth(16)));
+    assert_eq!(pcie.dll_link_active, Some(true));
+    assert_eq!(pcie.link_training, Some(false));
+    assert!(pcie.hotplug_capable && pcie.surprise_hotplug_capable);
+    let hotplug = port.hotplug_profile().expect("hotplug");
+    assert_eq!(hotplug.slot_present, Some(true));
+    assert_eq!(hotplug.latch_open, Some(false));
+
+    let child = open(address(1, 0, 0));
+    assert_eq!(child.topology_profile().parent, Some(address(0, 1, 0)));
+    let msix = child.interrupt_profile().msix.expect("msix");
+    assert_eq!((msix.table_size, msix.masked), (4, true));
+    assert_eq!(
+        child
+            .read_config_u32(PciConfigOffset(0x102))
+            .expect_err("misaligned")
+            .kind(),
+        PciErrorKind::Invalid
+    );
+}
+
+#[test]
+fn snapshot_decodes_host_sysfs_config_dumps() {
+    static SNAPSHOT: OnceLock<(Vec<PciEcamWindow>, PciConfigSnapshot<'static>)> = OnceLock::new();
+
+    struct SnapshotPlatform;
+
+    impl PciEcamPlatform for SnapshotPlatform {
+        type Access = PciConfigSnapshot<'static>;
+
+        fn windows() -> &'static [PciEcamWindow] {
+            SNAPSHOT
+                .get()
+                .map_or(&[], |(windows, _)| windows.as_slice())
+        }
+
+        fn access(provider: u8) -> Option<&'static Self::Access> {
+            SNAPSHOT
+                .get()
+                .filter(|_| provider == 0)
+                .map(|(_, access)| access)
+        }
+    }
+
+    // Segment 0 functions only; each dump lands at its ECAM offset in an all-ones image.
+    let mut dumps = Vec::new();
+    if let Ok(entries) = fs::read_dir("/sys/bus/pci/devices") {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let Some(bdf) = name.strip_prefix("0000:") else {
+                continue;
+            };
+            let parse = |range: core::ops::Range<usize>| {
+                bdf.get(range)
+                    .and_then(|text| u8::from_str_radix(text, 16).ok())
+            };
+            let (Some(bus), Some(device), Some(function)) = (parse(0..2), parse(3..5), parse(6..7))
+            else {
+                continue;
+            };
+            if let Ok(config) = fs::read(entry.path().join("config"))
+                && config.len() >= 64
+            {
+                dumps.push((address(bus, device, function), config));
+            }
+        }
+    }
+    if dumps.is_empty() {
+        return;
+    }
+
+    let end_bus = dumps
+        .iter()
+        .map(|(address, _)| address.bus.0)
+        .max()
+        .unwrap_or(0);
+    let window = PciEcamWindow::new(TEST_CONTROLLER, 0, 0, 0, end_bus);
+    let mut image = vec![0xff; usize::try_from(window.len()).expect("window len")];
+    for (address, config) in &dumps {
+        let base =
+            usize::try_from(window.function_offset(*address).expect("offset")).expect("offset");
+        let len = config.len().min(4096);
+        image[base..base + len].copy_from_slice(&config[..len]);
+    }
+    let image: &'static [u8] = Box::leak(image.into_boxed_slice());
+    SNAPSHOT.get_or_init(|| (vec![window], PciConfigSnapshot::new(image)));
+
+    let mut out = vec![address(0, 0, 0); dumps.len()];
+    let count = PciEcamHardware::<SnapshotPlatform>::enumerate_functions(0, &mut out)
+        .expect("enumerate snapshot");
+    assert!(count > 0);
+    for found in &out[..count] {
+        let (_, config) = dumps
+            .iter()
+            .find(|(address, _)| address == found)
+            .expect("enumerated function has a sysfs dump");
+        let function = PciEcamHardware::<SnapshotPlatform>::function(0, *found)
+            .expect("open snapshot function")
+            .expect("snapshot function present");
+        let identity = function.identity();
+        assert_eq!(
+            identity.vendor_id.0,
+            u16::from_le_bytes([config[0], config[1]])
+        );
+        assert_eq!(
+            identity.device_id.0,
+            u16::from_le_bytes([config[2], config[3]])
+        );
+    }
+    // Every bus-0 function is reachable without following any bridge.
+    assert!(
+        dumps
+            .iter()
+            .filter(|(address, _)| address.bus.0 == 0)
+            .all(|(address, _)| out[..count].contains(address))
+    );
+}
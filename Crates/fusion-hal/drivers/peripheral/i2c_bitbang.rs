@@ -0,0 +1,923 @@
+//! GPIO bit-banged I2C controller.
+//!
+//! SCL and SDA are emulated as open-drain lines over two owned GPIO pins: a line is pulled low by
+//! configuring its pin as one low output and released by switching the pin back to input, which
+//! leaves the bus pull-ups to raise it. Both lines therefore need pull-ups, either on the board
+//! or enabled on the pads before the pins are handed over.
+
+use crate::contract::drivers::bus::gpio::{
+    GpioError as ContractGpioError,
+    GpioErrorKind,
+};
+use crate::contract::drivers::bus::i2c::{
+    I2cAddress,
+    I2cBaseContract,
+    I2cBusConfig,
+    I2cControllerContract,
+    I2cControllerDescriptor,
+    I2cError,
+    I2cErrorKind,
+    I2cImplementationKind,
+    I2cNackSource,
+    I2cOperation,
+    I2cProviderCaps,
+    I2cSpeed,
+    I2cSupport,
+};
+use crate::drivers::peripheral::interface::gpio::{
+    GpioPeripheral,
+    GpioPeripheralInputPin as GpioInputPinContract,
+    GpioPeripheralOutputPin as GpioOutputPinContract,
+};
+
+const BIT_BANG_I2C_CONTROLLER: I2cControllerDescriptor = I2cControllerDescriptor {
+    id: "gpio-bitbang-i2c",
+    name: "GPIO bit-banged I2C",
+};
+
+/// Upper bound on the SCL pulses needed to walk one stuck target through its current byte.
+const BUS_RECOVERY_CLOCKS: u8 = 9;
+
+/// Pacing source for one bit-banged I2C bus.
+pub trait I2cBitBangDelay {
+    /// Waits for half of one SCL period at `speed`.
+    fn delay_half_period(&mut self, speed: I2cSpeed);
+}
+
+/// Pacing that adds no delay, for pins whose own access latency already meets the bus timing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoI2cBitBangDelay;
+
+impl I2cBitBangDelay for NoI2cBitBangDelay {
+    fn delay_half_period(&mut self, _speed: I2cSpeed) {}
+}
+
+/// I2C controller driven by toggling two owned GPIO pins.
+#[derive(Debug)]
+pub struct I2cBitBang<Scl, Sda, Delay = NoI2cBitBangDelay> {
+    scl: Scl,
+    sda: Sda,
+    delay: Delay,
+    config: I2cBusConfig,
+    stretch_polls: u32,
+}
+
+impl<Scl, Sda, Delay> I2cBitBang<Scl, Sda, Delay>
+where
+    Scl: GpioOutputPinContract + GpioInputPinContract,
+    Sda: GpioOutputPinContract + GpioInputPinContract,
+    Delay: I2cBitBangDelay,
+{
+    /// Creates one bit-banged controller and releases both lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest GPIO-derived error when either pin cannot be switched to input.
+    pub fn new(mut scl: Scl, mut sda: Sda, delay: Delay) -> Result<Self, I2cError> {
+        sda.configure_input().map_err(i2c_error_from_gpio)?;
+        scl.configure_input().map_err(i2c_error_from_gpio)?;
+        let config = I2cBusConfig::new();
+        Ok(Self {
+            scl,
+            sda,
+            delay,
+            config,
+            stretch_polls: stretch_polls(config),
+        })
+    }
+
+    /// Returns the active bus configuration.
+    #[must_use]
+    pub const fn config(&self) -> I2cBusConfig {
+        self.config
+    }
+
+    /// Returns a reference to the owned SCL pin.
+    #[must_use]
+    pub const fn scl(&self) -> &Scl {
+        &self.scl
+    }
+
+    /// Returns a reference to the owned SDA pin.
+    #[must_use]
+    pub const fn sda(&self) -> &Sda {
+        &self.sda
+    }
+
+    /// Releases the owned pins and pacing source back to the caller.
+    #[must_use]
+    pub fn into_parts(self) -> (Scl, Sda, Delay) {
+        (self.scl, self.sda, self.delay)
+    }
+
+    fn wait(&mut self) {
+        self.delay.delay_half_period(self.config.speed);
+    }
+
+    fn pull_scl(&mut self) -> Result<(), I2cError> {
+        self.scl
+            .configure_output(false)
+            .map_err(i2c_error_from_gpio)
+    }
+
+    /// Releases SCL and waits out any target holding it low.
+    fn release_scl(&mut self) -> Result<(), I2cError> {
+        self.scl.configure_input().map_err(i2c_error_from_gpio)?;
+        if self.stretch_polls == 0 {
+            return Ok(());
+        }
+        for _ in 0..self.stretch_polls {
+            if self.scl.read_level().map_err(i2c_error_from_gpio)? {
+                return Ok(());
+            }
+            self.wait();
+        }
+        Err(I2cError::timeout())
+    }
+
+    fn pull_sda(&mut self) -> Result<(), I2cError> {
+        self.sda
+            .configure_output(false)
+            .map_err(i2c_error_from_gpio)
+    }
+
+    fn release_sda(&mut self) -> Result<(), I2cError> {
+        self.sda.configure_input().map_err(i2c_error_from_gpio)
+    }
+
+    fn sda_high(&self) -> Result<bool, I2cError> {
+        self.sda.read_level().map_err(i2c_error_from_gpio)
+    }
+
+    fn release_all(&mut self) {
+        let _ = self.release_sda();
+        let _ = self.scl.configure_input();
+    }
+
+    fn start(&mut self) -> Result<(), I2cError> {
+        self.release_sda()?;
+        self.release_scl()?;
+        if !self.sda_high()? || !self.scl.read_level().map_err(i2c_error_from_gpio)? {
+            return Err(I2cError::busy());
+        }
+        self.pull_sda()?;
+        self.wait();
+        self.pull_scl()
+    }
+
+    fn repeated_start(&mut self) -> Result<(), I2cError> {
+        self.release_sda()?;
+        self.wait();
+        self.release_scl()?;
+        if !self.sda_high()? {
+            return Err(I2cError::arbitration_lost());
+        }
+        self.wait();
+        self.pull_sda()?;
+        self.wait();
+        self.pull_scl()
+    }
+
+    fn stop(&mut self) -> Result<(), I2cError> {
+        self.pull_sda()?;
+        self.wait();
+        self.release_scl()?;
+        self.wait();
+        self.release_sda()?;
+        self.wait();
+        if !self.sda_high()? {
+            return Err(I2cError::arbitration_lost());
+        }
+        Ok(())
+    }
+
+    fn write_bit(&mut self, high: bool) -> Result<(), I2cError> {
+        if high {
+            self.release_sda()?;
+        } else {
+            self.pull_sda()?;
+        }
+        self.wait();
+        self.release_scl()?;
+        // A released line that reads low is being driven by another controller.
+        if high && !self.sda_high()? {
+            return Err(I2cError::arbitration_lost());
+        }
+        self.wait();
+        self.pull_scl()
+    }
+
+    fn read_bit(&mut self) -> Result<bool, I2cError> {
+        self.release_sda()?;
+        self.wait();
+        self.release_scl()?;
+        let high = self.sda_high()?;
+        self.wait();
+        self.pull_scl()?;
+        Ok(high)
+    }
+
+    /// Shifts one byte out and returns whether the target acknowledged it.
+    fn write_byte(&mut self, byte: u8) -> Result<bool, I2cError> {
+        for bit in (0..8).rev() {
+            self.write_bit(byte & (1 << bit) != 0)?;
+        }
+        Ok(!self.read_bit()?)
+    }
+
+    fn read_byte(&mut self, ack: bool) -> Result<u8, I2cError> {
+        let mut byte = 0_u8;
+        for _ in 0..8 {
+            byte = (byte << 1) | u8::from(self.read_bit()?);
+        }
+        self.write_bit(!ack)?;
+        Ok(byte)
+    }
+
+    fn write_address_byte(&mut self, byte: u8) -> Result<(), I2cError> {
+        if self.write_byte(byte)? {
+            Ok(())
+        } else {
+            Err(I2cError::nack(I2cNackSource::Address))
+        }
+    }
+
+    /// Sends the address phase that follows one start condition.
+    ///
+    /// A 10-bit read must first select the target with the full write-direction address, then
+    /// turn the bus around with one repeated start and the read-direction header alone.
+    fn address_phase(
+        &mut self,
+        address: I2cAddress,
+        read: bool,
+        ten_bit_selected: &mut bool,
+    ) -> Result<(), I2cError> {
+        let direction = u8::from(read);
+        match address {
+            I2cAddress::SevenBit(address) => self.write_address_byte((address << 1) | direction),
+            I2cAddress::TenBit(address) => {
+                let [low, high] = address.to_le_bytes();
+                let header = 0xf0 | ((high & 0x03) << 1);
+                if !read || !*ten_bit_selected {
+                    self.write_address_byte(header)?;
+                    self.write_address_byte(low)?;
+                    *ten_bit_selected = true;
+                    if !read {
+                        return Ok(());
+                    }
+                    self.repeated_start()?;
+                }
+                self.write_address_byte(header | 1)
+            }
+        }
+    }
+
+    fn run_transaction(
+        &mut self,
+        address: I2cAddress,
+        operations: &mut [I2cOperation<'_>],
+    ) -> Result<(), I2cError> {
+        self.start()?;
+        if operations.is_empty() {
+            // One bare address phase probes for the target.
+            return self.address_phase(address, false, &mut false);
+        }
+        let mut ten_bit_selected = false;
+        let mut previous_read = None;
+        for index in 0..operations.len() {
+            let read = operations[index].is_read();
+            if previous_read == Some(!read) {
+                self.repeated_start()?;
+            }
+            if previous_read != Some(read) {
+                self.address_phase(address, read, &mut ten_bit_selected)?;
+            }
+            let continues = operations
+                .get(index + 1)
+                .is_some_and(|next| next.is_read() == read);
+            match &mut operations[index] {
+                I2cOperation::Write(bytes) => {
+                    for &byte in *bytes {
+                        if !self.write_byte(byte)? {
+                            return Err(I2cError::nack(I2cNackSource::Data));
+                        }
+                    }
+                }
+                I2cOperation::Read(buffer) => {
+                    let last = buffer.len() - 1;
+                    for (offset, slot) in buffer.iter_mut().enumerate() {
+                        *slot = self.read_byte(continues || offset != last)?;
+                    }
+                }
+            }
+            previous_read = Some(read);
+        }
+        Ok(())
+    }
+}
+
+impl<Scl, Sda, Delay> I2cBaseContract for I2cBitBang<Scl, Sda, Delay>
+where
+    Scl: GpioOutputPinContract + GpioInputPinContract,
+    Sda: GpioOutputPinContract + GpioInputPinContract,
+    Delay: I2cBitBangDelay,
+{
+    fn controller(&self) -> &'static I2cControllerDescriptor {
+        &BIT_BANG_I2C_CONTROLLER
+    }
+
+    fn support(&self) -> I2cSupport {
+        I2cSupport {
+            caps: I2cProviderCaps::CONTROLLER
+                | I2cProviderCaps::TEN_BIT_ADDRESS
+                | I2cProviderCaps::REPEATED_START
+                | I2cProviderCaps::CLOCK_STRETCHING
+                | I2cProviderCaps::BUS_RECOVERY
+                | I2cProviderCaps::ARBITRATION
+                | I2cProviderCaps::STANDARD_MODE
+                | I2cProviderCaps::FAST_MODE
+                | I2cProviderCaps::FAST_MODE_PLUS,
+            implementation: I2cImplementationKind::Emulated,
+        }
+    }
+}
+
+impl<Scl, Sda, Delay> I2cControllerContract for I2cBitBang<Scl, Sda, Delay>
+where
+    Scl: GpioOutputPinContract + GpioInputPinContract,
+    Sda: GpioOutputPinContract + GpioInputPinContract,
+    Delay: I2cBitBangDelay,
+{
+    fn configure(&mut self, config: I2cBusConfig) -> Result<(), I2cError> {
+        // High-speed mode needs current-source pull-ups and one controller code preamble that
+        // plain GPIO lines cannot provide.
+        if config.speed == I2cSpeed::HighSpeed {
+            return Err(I2cError::unsupported());
+        }
+        self.config = config;
+        self.stretch_polls = stretch_polls(config);
+        Ok(())
+    }
+
+    fn transaction(
+        &mut self,
+        address: I2cAddress,
+        operations: &mut [I2cOperation<'_>],
+    ) -> Result<(), I2cError> {
+        let empty_read = operations
+            .iter()
+            .any(|operation| matches!(operation, I2cOperation::Read(buffer) if buffer.is_empty()));
+        if !address.is_valid() || empty_read {
+            return Err(I2cError::invalid());
+        }
+        match self.run_transaction(address, operations) {
+            Ok(()) => self.stop(),
+            Err(error) => {
+                match error.kind() {
+                    // Another controller owns the bus now; only get out of its way.
+                    I2cErrorKind::ArbitrationLost | I2cErrorKind::Busy => {}
+                    _ => {
+                        let _ = self.stop();
+                    }
+                }
+                self.release_all();
+                Err(error)
+            }
+        }
+    }
+
+    fn recover_bus(&mut self) -> Result<(), I2cError> {
+        self.release_sda()?;
+        for _ in 0..BUS_RECOVERY_CLOCKS {
+            if self.sda_high()? {
+                break;
+            }
+            self.pull_scl()?;
+            self.wait();
+            self.release_scl()?;
+            self.wait();
+        }
+        if !self.sda_high()? {
+            return Err(I2cError::bus());
+        }
+        // Finish with one stop so every target resynchronizes on an idle bus.
+        self.pull_scl()?;
+        self.wait();
+        self.pull_sda()?;
+        self.wait();
+        self.release_scl()?;
+        self.wait();
+        self.release_sda()?;
+        self.wait();
+        if self.sda_high()? {
+            Ok(())
+        } else {
+            Err(I2cError::bus())
+        }
+    }
+}
+
+impl<Scl, Sda, Delay> GpioPeripheral for I2cBitBang<Scl, Sda, Delay>
+where
+    Scl: GpioOutputPinContract + GpioInputPinContract,
+    Sda: GpioOutputPinContract + GpioInputPinContract,
+    Delay: I2cBitBangDelay,
+{
+    type Error = I2cError;
+}
+
+/// Converts one clock-stretch budget into half-period SCL polls.
+fn stretch_polls(config: I2cBusConfig) -> u32 {
+    if config.stretch_timeout_us == 0 {
+        return 0;
+    }
+    let polls = u64::from(config.stretch_timeout_us) * u64::from(config.speed.hz()) * 2 / 1_000_000;
+    u32::try_from(polls).unwrap_or(u32::MAX).max(1)
+}
+
+const fn i2c_error_from_gpio(error: ContractGpioError) -> I2cError {
+    match error.kind() {
+        GpioErrorKind::Unsupported => I2cError::unsupported(),
+        GpioErrorKind::Invalid => I2cError::invalid(),
+        GpioErrorKind::Busy => I2cError::busy(),
+        GpioErrorKind::ResourceExhausted => I2cError::resource_exhausted(),
+        GpioErrorKind::StateConflict => I2cError::state_conflict(),
+        GpioErrorKind::Platform(code) => I2cError::platform(code),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::contract::drivers::bus::gpio::{
+        GpioCapabilities,
+        GpioControllerDescriptor,
+        GpioOwnedPinContract,
+    };
+
+    const TEST_GPIO_CONTROLLER: GpioControllerDescriptor = GpioControllerDescriptor {
+        id: "test-gpio",
+        name: "Test GPIO",
+    };
+    const TARGET: I2cAddress = I2cAddress::SevenBit(0x50);
+    const TEN_BIT_TARGET: I2cAddress = I2cAddress::TenBit(0x2a5);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum TargetMode {
+        Idle,
+        Address,
+        TenBitLow,
+        Write,
+        Read,
+        Ignore,
+    }
+
+    /// Wired-AND SCL/SDA pair with one register-file target answering at both test addresses.
+    #[allow(clippy::struct_excessive_bools)]
+    #[derive(Debug)]
+    struct SimBus {
+        controller_scl_low: bool,
+        controller_sda_low: bool,
+        target_sda_low: bool,
+        rival_sda_low: bool,
+        rival_on_start: bool,
+        stretch: u32,
+        scl_hold: u32,
+        stuck_clocks: u32,
+        scl: bool,
+        sda: bool,
+        mode: TargetMode,
+        bit: u8,
+        shift: u8,
+        out: u8,
+        ack_slot: bool,
+        read_pending: bool,
+        ten_bit_selected: bool,
+        pointer: Option<usize>,
+        registers: [u8; 16],
+        stops: u32,
+    }
+
+    impl SimBus {
+        fn new() -> Rc<RefCell<Self>> {
+            Rc::new(RefCell::new(Self {
+                controller_scl_low: false,
+                controller_sda_low: false,
+                target_sda_low: false,
+                rival_sda_low: false,
+                rival_on_start: false,
+                stretch: 0,
+                scl_hold: 0,
+                stuck_clocks: 0,
+                scl: true,
+                sda: true,
+                mode: TargetMode::Idle,
+                bit: 0,
+                shift: 0,
+                out: 0,
+                ack_slot: false,
+                read_pending: false,
+                ten_bit_selected: false,
+                pointer: None,
+                registers: [0; 16],
+                stops: 0,
+            }))
+        }
+
+        const fn scl_level(&self) -> bool {
+            !self.controller_scl_low && self.scl_hold == 0
+        }
+
+        const fn sda_level(&self) -> bool {
+            !(self.controller_sda_low || self.target_sda_low || self.rival_sda_low)
+        }
+
+        fn settle(&mut self) {
+            let (scl, sda) = (self.scl_level(), self.sda_level());
+            if scl && self.scl && sda != self.sda {
+                if sda {
+                    self.on_stop();
+                } else {
+                    self.on_start();
+                }
+            } else if scl && !self.scl {
+                self.on_rise();
+            } else if !scl && self.scl {
+                self.on_fall();
+            }
+            self.scl = scl;
+            self.sda = self.sda_level();
+        }
+
+        fn on_start(&mut self) {
+            self.mode = TargetMode::Address;
+            self.bit = 0;
+            self.shift = 0;
+            self.ack_slot = false;
+            self.read_pending = false;
+            self.target_sda_low = false;
+            self.rival_sda_low = self.rival_on_start;
+        }
+
+        fn on_stop(&mut self) {
+            self.mode = TargetMode::Idle;
+            self.ten_bit_selected = false;
+            self.stops += 1;
+        }
+
+        fn on_rise(&mut self) {
+            if self.stuck_clocks > 0 {
+                self.stuck_clocks -= 1;
+                self.target_sda_low = self.stuck_clocks > 0;
+                return;
+            }
+            let level = self.sda_level();
+            match self.mode {
+                TargetMode::Address | TargetMode::TenBitLow | TargetMode::Write if self.bit < 8 => {
+                    self.shift = (self.shift << 1) | u8::from(level);
+                    self.bit += 1;
+                }
+                TargetMode::Read if self.bit == 8 && level => self.mode = TargetMode::Ignore,
+                _ => {}
+            }
+        }
+
+        fn on_fall(&mut self) {
+            if self.stuck_clocks > 0 {
+                return;
+            }
+            match self.mode {
+                TargetMode::Idle | TargetMode::Ignore => self.target_sda_low = false,
+                TargetMode::Address | TargetMode::TenBitLow | TargetMode::Write => {
+                    if self.bit < 8 {
+                        return;
+                    }
+                    if !self.ack_slot {
+                        let ack = self.accept_byte();
+                        self.ack_slot = true;
+                        self.target_sda_low = ack;
+                        self.scl_hold = self.stretch;
+                        if !ack {
+                            self.mode = TargetMode::Ignore;
+                        }
+                        return;
+                    }
+                    self.ack_slot = false;
+                    self.target_sda_low = false;
+                    self.bit = 0;
+                    self.shift = 0;
+                    if self.read_pending {
+                        self.read_pending = false;
+                        self.mode = TargetMode::Read;
+                        self.load_byte();
+                    }
+                }
+                TargetMode::Read => {
+                    self.bit += 1;
+                    match self.bit {
+                        1..=7 => self.target_sda_low = self.out & (0x80 >> self.bit) == 0,
+                        8 => self.target_sda_low = false,
+                        _ => self.load_byte(),
+                    }
+                }
+            }
+        }
+
+        fn load_byte(&mut self) {
+            let pointer = self.pointer.unwrap_or(0) % self.registers.len();
+            self.out = self.registers[pointer];
+            self.pointer = Some(pointer + 1);
+            self.bit = 0;
+            self.target_sda_low = self.out & 0x80 == 0;
+        }
+
+        fn accept_byte(&mut self) -> bool {
+            let byte = self.shift;
+            match self.mode {
+                TargetMode::Address => {
+                    if byte >> 1 == 0x50 {
+                        self.read_pending = byte & 1 == 1;
+                        if !self.read_pending {
+                            self.mode = TargetMode::Write;
+                            self.pointer = None;
+                        }
+                        return true;
+                    }
+                    if byte & 0xf8 == 0xf0 && (byte >> 1) & 0x03 == 0x02 {
+                        if byte & 1 == 0 {
+                            self.mode = TargetMode::TenBitLow;
+                            return true;
+                        }
+                        self.read_pending = self.ten_bit_selected;
+                        return self.ten_bit_selected;
+                    }
+                    false
+                }
+                TargetMode::TenBitLow => {
+                    if byte != 0xa5 {
+                        return false;
+                    }
+                    self.ten_bit_selected = true;
+                    self.mode = TargetMode::Write;
+                    self.pointer = None;
+                    true
+                }
+                TargetMode::Write => match self.pointer {
+                    None => {
+                        self.pointer = Some(usize::from(byte));
+                        true
+                    }
+                    Some(pointer) if pointer < self.registers.len() => {
+                        self.registers[pointer] = byte;
+                        self.pointer = Some(pointer + 1);
+                        true
+                    }
+                    Some(_) => false,
+                },
+                _ => false,
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct SimPin {
+        pin: u8,
+        bus: Rc<RefCell<SimBus>>,
+    }
+
+    impl SimPin {
+        fn drive_low(&self, low: bool) {
+            let mut bus = self.bus.borrow_mut();
+            if self.pin == 0 {
+                bus.controller_scl_low = low;
+            } else {
+                bus.controller_sda_low = low;
+            }
+            bus.settle();
+        }
+    }
+
+    impl GpioOwnedPinContract for SimPin {
+        fn controller(&self) -> &'static GpioControllerDescriptor {
+            &TEST_GPIO_CONTROLLER
+        }
+
+        fn pin(&self) -> u8 {
+            self.pin
+        }
+
+        fn capabilities(&self) -> GpioCapabilities {
+            GpioCapabilities::INPUT | GpioCapabilities::OUTPUT
+        }
+    }
+
+    impl GpioOutputPinContract for SimPin {
+        fn configure_output(&mut self, initial_high: bool) -> Result<(), ContractGpioError> {
+            self.drive_low(!initial_high);
+            Ok(())
+        }
+
+        fn set_level(&mut self, high: bool) -> Result<(), ContractGpioError> {
+            self.drive_low(!high);
+            Ok(())
+        }
+    }
+
+    impl GpioInputPinContract for SimPin {
+        fn configure_input(&mut self) -> Result<(), ContractGpioError> {
+            self.drive_low(false);
+            Ok(())
+        }
+
+        fn read_level(&self) -> Result<bool, ContractGpioError> {
+            let mut bus = self.bus.borrow_mut();
+            if self.pin == 0 {
+                // Each sample of a stretched clock lets the target make a little progress.
+                bus.scl_hold = bus.scl_hold.saturating_sub(1);
+                bus.settle();
+                Ok(bus.scl_level())
+            } else {
+                Ok(bus.sda_level())
+            }
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingDelay(u32);
+
+    impl I2cBitBangDelay for CountingDelay {
+        fn delay_half_period(&mut self, _speed: I2cSpeed) {
+            self.0 += 1;
+        }
+    }
+
+    fn controller(bus: &Rc<RefCell<SimBus>>) -> I2cBitBang<SimPin, SimPin, CountingDelay> {
+        I2cBitBang::new(
+            SimPin {
+                pin: 0,
+                bus: Rc::clone(bus),
+            },
+            SimPin {
+                pin: 1,
+                bus: Rc::clone(bus),
+            },
+            CountingDelay::default(),
+        )
+        .expect("simulated pins should release")
+    }
+
+    #[test]
+    fn writes_then_reads_registers_across_repeated_start() {
+        let bus = SimBus::new();
+        let mut i2c = controller(&bus);
+
+        i2c.write(TARGET, &[0x02, 0xde, 0xad, 0xbe])
+            .expect("register write should be acknowledged");
+        assert_eq!(bus.borrow().registers[2..5], [0xde, 0xad, 0xbe]);
+
+        let mut reply = [0_u8; 3];
+        i2c.write_read(TARGET, &[0x02], &mut reply)
+            .expect("register read should complete");
+        assert_eq!(reply, [0xde, 0xad, 0xbe]);
+        assert_eq!(bus.borrow().stops, 2);
+        assert!(bus.borrow().sda_level() && bus.borrow().scl_level());
+
+        let (_, _, delay) = i2c.into_parts();
+        assert!(delay.0 > 0);
+    }
+
+    #[test]
+    fn addresses_ten_bit_targets() {
+        let bus = SimBus::new();
+        let mut i2c = controller(&bus);
+
+        i2c.write(TEN_BIT_TARGET, &[0x07, 0x5a])
+            .expect("10-bit write should be acknowledged");
+        assert_eq!(bus.borrow().registers[7], 0x5a);
+
+        let mut reply = [0_u8; 1];
+        i2c.write_read(TEN_BIT_TARGET, &[0x07], &mut reply)
+            .expect("10-bit read should complete");
+        assert_eq!(reply, [0x5a]);
+
+        // A read-only 10-bit transaction still selects the target in write direction first.
+        bus.borrow_mut().registers[0] = 0x33;
+        i2c.read(TEN_BIT_TARGET, &mut reply)
+            .expect("10-bit read should select the target first");
+        assert_eq!(reply, [0x33]);
+    }
+
+    #[test]
+    fn reports_address_and_data_nacks() {
+        let bus = SimBus::new();
+        let mut i2c = controller(&bus);
+
+        assert_eq!(
+            i2c.write(I2cAddress::SevenBit(0x51), &[0x00])
+                .expect_err("absent target should not acknowledge")
+                .kind(),
+            I2cErrorKind::Nack(I2cNackSource::Address)
+        );
+        assert_eq!(
+            i2c.write(TARGET, &[0x0f, 0x01, 0x02])
+                .expect_err("write past the register file should be refused")
+                .kind(),
+            I2cErrorKind::Nack(I2cNackSource::Data)
+        );
+        assert_eq!(bus.borrow().stops, 2);
+        i2c.transaction(TARGET, &mut [])
+            .expect("bare address probe should find the target");
+        assert_eq!(
+            i2c.write(I2cAddress::SevenBit(0x80), &[])
+                .expect_err("8-bit address should be rejected")
+                .kind(),
+            I2cErrorKind::Invalid
+        );
+    }
+
+    #[test]
+    fn honors_clock_stretching_within_budget() {
+        let bus = SimBus::new();
+        let mut i2c = controller(&bus);
+        bus.borrow_mut().stretch = 10;
+
+        i2c.write(TARGET, &[0x01, 0x44])
+            .expect("stretched write should complete");
+        assert_eq!(bus.borrow().registers[1], 0x44);
+
+        i2c.configure(I2cBusConfig {
+            speed: I2cSpeed::Standard,
+            stretch_timeout_us: 10,
+        })
+        .expect("standard mode should configure");
+        assert_eq!(
+            i2c.write(TARGET, &[0x01])
+                .expect_err("stretch beyond budget should time out")
+                .kind(),
+            I2cErrorKind::Timeout
+        );
+        assert_eq!(
+            i2c.configure(I2cBusConfig {
+                speed: I2cSpeed::HighSpeed,
+                ..I2cBusConfig::new()
+            })
+            .expect_err("high-speed mode needs dedicated hardware")
+            .kind(),
+            I2cErrorKind::Unsupported
+        );
+    }
+
+    #[test]
+    fn detects_lost_arbitration() {
+        let bus = SimBus::new();
+        let mut i2c = controller(&bus);
+        bus.borrow_mut().rival_on_start = true;
+
+        assert_eq!(
+            i2c.write(TARGET, &[0x00])
+                .expect_err("rival controller should win the address phase")
+                .kind(),
+            I2cErrorKind::ArbitrationLost
+        );
+        assert!(!bus.borrow().controller_sda_low && !bus.borrow().controller_scl_low);
+    }
+
+    #[test]
+    fn recovers_a_target_holding_sda_low() {
+        let bus = SimBus::new();
+        let mut i2c = controller(&bus);
+        {
+            let mut bus = bus.borrow_mut();
+            bus.target_sda_low = true;
+            bus.stuck_clocks = 3;
+            bus.sda = false;
+        }
+
+        assert_eq!(
+            i2c.write(TARGET, &[0x00])
+                .expect_err("held SDA should look like one busy bus")
+                .kind(),
+            I2cErrorKind::Busy
+        );
+        i2c.recover_bus().expect("recovery should free SDA");
+        i2c.write(TARGET, &[0x03, 0x99])
+            .expect("recovered bus should carry traffic");
+        assert_eq!(bus.borrow().registers[3], 0x99);
+
+        {
+            let mut bus = bus.borrow_mut();
+            bus.target_sda_low = true;
+            bus.stuck_clocks = u32::MAX;
+            bus.sda = false;
+        }
+        assert_eq!(
+            i2c.recover_bus()
+                .expect_err("permanently held SDA should fail recovery")
+                .kind(),
+            I2cErrorKind::Bus
+        );
+    }
+}
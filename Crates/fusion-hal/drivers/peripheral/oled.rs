@@ -1,8 +1,10 @@
 //! OLED display peripheral backed by owned I2C GPIO lines.
 //!
 //! This scaffolds the GPIO claim and pin ownership for a typical I2C OLED module wired as
-//! GND + VCC + SCL + SDA. The actual I2C protocol driver and display command sequences belong
-//! to a future I2C/display contract; this peripheral owns the pins and exposes the wiring truth.
+//! GND + VCC + SCL + SDA. The released pins can drive the bus through [`I2cBitBang`] when they
+//! also implement GPIO input; display command sequences belong to a future display contract.
+//!
+//! [`I2cBitBang`]: crate::drivers::peripheral::I2cBitBang
 
 use crate::drivers::peripheral::interface::gpio::{
     GpioPeripheral,
@@ -12,9 +14,9 @@ use crate::drivers::peripheral::interface::gpio::{
 
 /// I2C wiring contract for one OLED display module.
 ///
-/// Owns the SCL and SDA pins. The actual I2C clock/data driving is left to a future I2C
-/// peripheral contract or bitbang driver; this struct establishes pin ownership and ensures
-/// the GPIO lines are exclusively claimed.
+/// Owns the SCL and SDA pins. Clock/data driving is left to one I2C controller built from the
+/// released pins; this struct establishes pin ownership and ensures the GPIO lines are
+/// exclusively claimed.
 #[derive(Debug)]
 pub struct OledDisplay<Scl, Sda> {
     scl: Scl,
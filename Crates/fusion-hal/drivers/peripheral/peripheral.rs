@@ -6,6 +6,7 @@ pub mod interface;
 mod audio_jack;
 mod button;
 mod buzzer;
+mod i2c_bitbang;
 mod led;
 mod led_pair;
 mod oled;
@@ -16,6 +17,7 @@ mod speaker;
 pub use audio_jack::*;
 pub use button::*;
 pub use buzzer::*;
+pub use i2c_bitbang::*;
 pub use interface::*;
 pub use led::*;
 pub use led_pair::*;
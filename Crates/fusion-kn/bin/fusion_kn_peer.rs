@@ -0,0 +1,161 @@
+//! Hosted reference peer for the mediated Fusion kernel protocol.
+//!
+//! Serves the no-alloc [`FusionKnPeer`] dispatcher over a Unix stream socket so the client
+//! side, including the `fusion-pal` character-device transport, can negotiate end to end
+//! without a kernel module loaded. Every connection gets its own peer and therefore its own
+//! negotiated session.
+
+#[cfg(unix)]
+use std::env;
+#[cfg(unix)]
+use std::io::{
+    self,
+    Read,
+    Write,
+};
+#[cfg(unix)]
+use std::os::unix::net::{
+    UnixListener,
+    UnixStream,
+};
+#[cfg(unix)]
+use std::process::ExitCode;
+#[cfg(unix)]
+use std::thread;
+
+#[cfg(unix)]
+use fusion_kn::contract::wire::{
+    FusionKnMessageHeader,
+    FusionKnNegotiationResponse,
+    FusionKnTransportKind,
+};
+#[cfg(unix)]
+use fusion_kn::server::{
+    FusionKnPeer,
+    FusionKnPeerConfig,
+    declared_payload_bytes,
+};
+
+#[cfg(unix)]
+const USAGE: &str = "usage: fusion_kn_peer <socket-path> [--max-payload <bytes>]";
+
+#[cfg(unix)]
+fn main() -> ExitCode {
+    let (path, config) = match parse_args() {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("{message}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    // A stale socket from a previous run would make bind fail; the path is ours to own.
+    let _ = std::fs::remove_file(&path);
+    let listener = match UnixListener::bind(&path) {
+        Ok(listener) => listener,
+        Err(error) => {
+            eprintln!("fusion_kn_peer: cannot bind {path}: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    eprintln!(
+        "fusion_kn_peer: serving {path} (max payload {} bytes)",
+        config.max_payload_bytes
+    );
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                thread::spawn(move || {
+                    if let Err(error) = serve_connection(stream, config) {
+                        eprintln!("fusion_kn_peer: connection closed: {error}");
+                    }
+                });
+            }
+            Err(error) => eprintln!("fusion_kn_peer: accept failed: {error}"),
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(not(unix))]
+fn main() {
+    eprintln!("fusion_kn_peer requires Unix domain sockets");
+    std::process::exit(1);
+}
+
+#[cfg(unix)]
+fn parse_args() -> Result<(String, FusionKnPeerConfig), String> {
+    let mut config = FusionKnPeerConfig::reference(FusionKnTransportKind::CharacterDevice);
+    let mut path = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--max-payload" {
+            let value = args
+                .next()
+                .ok_or_else(|| String::from("--max-payload needs a value"))?;
+            config.max_payload_bytes = value
+                .parse()
+                .map_err(|_| format!("invalid --max-payload value `{value}`"))?;
+        } else if path.is_none() {
+            path = Some(arg);
+        } else {
+            return Err(format!("unexpected argument `{arg}`"));
+        }
+    }
+    let path = path.ok_or_else(|| String::from("missing socket path"))?;
+    Ok((path, config))
+}
+
+#[cfg(unix)]
+fn serve_connection(mut stream: UnixStream, config: FusionKnPeerConfig) -> io::Result<()> {
+    let mut peer = FusionKnPeer::new(config);
+    let max_payload = usize::try_from(config.max_payload_bytes).unwrap_or(usize::MAX);
+    let mut request = vec![0_u8; FusionKnMessageHeader::ENCODED_LEN + max_payload];
+    let mut response = vec![
+        0_u8;
+        FusionKnMessageHeader::ENCODED_LEN
+            + max_payload.max(FusionKnNegotiationResponse::ENCODED_LEN)
+    ];
+
+    loop {
+        let header = &mut request[..FusionKnMessageHeader::ENCODED_LEN];
+        match stream.read_exact(header) {
+            Ok(()) => {}
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => return Ok(()),
+            Err(error) => return Err(error),
+        }
+
+        // Without the magic there is no trustworthy length to resynchronize on, so answer
+        // once and hang up.
+        let Ok(declared) = declared_payload_bytes(header) else {
+            let written = peer
+                .dispatch(header, &mut response)
+                .map_err(|error| io::Error::other(format!("{error:?}")))?;
+            stream.write_all(&response[..written])?;
+            return Ok(());
+        };
+
+        let request_len = match usize::try_from(declared) {
+            Ok(payload) if payload <= max_payload => {
+                let end = FusionKnMessageHeader::ENCODED_LEN + payload;
+                stream.read_exact(&mut request[FusionKnMessageHeader::ENCODED_LEN..end])?;
+                end
+            }
+            // Oversized payloads are drained so the stream stays framed; the dispatcher sees
+            // only the header and refuses it on the declared length.
+            _ => {
+                io::copy(
+                    &mut (&mut stream).take(u64::from(declared)),
+                    &mut io::sink(),
+                )?;
+                FusionKnMessageHeader::ENCODED_LEN
+            }
+        };
+
+        let written = peer
+            .dispatch(&request[..request_len], &mut response)
+            .map_err(|error| io::Error::other(format!("{error:?}")))?;
+        stream.write_all(&response[..written])?;
+    }
+}
@@ -3,7 +3,20 @@
 //! The client side remains transport-neutral. `fusion-pal` or other consumers provide the
 //! actual transport implementation; this module owns the bitflat negotiation and response
 //! validation rules.
+//!
+//! Commands past `Negotiate` are refused locally until a session confirms their capability,
+//! so a client never spends a round trip on something the peer already said it cannot do.
 
+use crate::contract::command::{
+    FusionKnClaimRequest,
+    FusionKnClaimResponse,
+    FusionKnCourierSummary,
+    FusionKnCourierSummaryRequest,
+    FusionKnEventPollRequest,
+    FusionKnEventRecord,
+    FusionKnEventSubscribeRequest,
+    FusionKnEventSubscribeResponse,
+};
 use crate::contract::wire::{
     FusionKnCapabilityFlags,
     FusionKnCommand,
@@ -16,6 +29,13 @@ use crate::contract::wire::{
     FusionKnWireError,
 };
 
+/// Largest encoded request any client command produces.
+const MAX_REQUEST_LEN: usize =
+    FusionKnMessageHeader::ENCODED_LEN + FusionKnClaimRequest::ENCODED_LEN;
+/// Largest encoded response any client command accepts.
+const MAX_RESPONSE_LEN: usize =
+    FusionKnMessageHeader::ENCODED_LEN + FusionKnCourierSummary::ENCODED_LEN;
+
 /// Abstract transport for sending and receiving mediated Fusion kernel messages.
 pub trait FusionKnTransport {
     /// Concrete transport-level error reported by the implementation.
@@ -68,6 +88,8 @@ pub enum FusionKnClientError<E> {
     ResponseTooLarge,
     /// The peer responded with an incompatible version.
     IncompatibleVersion,
+    /// The command needs a capability or protocol minor version the session did not confirm.
+    CommandNotNegotiated,
 }
 
 impl<E> From<FusionKnWireError> for FusionKnClientError<E> {
@@ -81,6 +103,7 @@ impl<E> From<FusionKnWireError> for FusionKnClientError<E> {
 pub struct FusionKnClient<T> {
     transport: T,
     next_request_id: u32,
+    session: Option<FusionKnNegotiatedSession>,
 }
 
 impl<T> FusionKnClient<T> {
@@ -90,9 +113,16 @@ impl<T> FusionKnClient<T> {
         Self {
             transport,
             next_request_id: 1,
+            session: None,
         }
     }
 
+    /// Returns the negotiated session, if negotiation has completed.
+    #[must_use]
+    pub const fn session(&self) -> Option<FusionKnNegotiatedSession> {
+        self.session
+    }
+
     /// Returns the inner transport by value.
     #[must_use]
     pub fn into_inner(self) -> T {
@@ -113,25 +143,187 @@ where
     pub fn negotiate(
         &mut self,
     ) -> Result<FusionKnNegotiatedSession, FusionKnClientError<T::Error>> {
-        let request_id = self.allocate_request_id();
         let request_payload = FusionKnNegotiationRequest::current(self.transport.transport_kind());
-        let request_header = FusionKnMessageHeader::request(
+        let mut response = [0_u8; MAX_RESPONSE_LEN];
+        let payload = self.exchange(
             FusionKnCommand::Negotiate,
+            FusionKnNegotiationRequest::ENCODED_LEN,
+            |dst| request_payload.encode_into(dst),
+            FusionKnNegotiationResponse::ENCODED_LEN,
+            &mut response,
+        )?;
+
+        let negotiation = FusionKnNegotiationResponse::decode_from(payload)?;
+        if negotiation.selected_version_major != request_payload.max_version_major
+            || negotiation.selected_version_minor < request_payload.min_version_minor
+            || negotiation.selected_version_minor > request_payload.max_version_minor
+            || negotiation.transport != self.transport.transport_kind()
+        {
+            return Err(FusionKnClientError::IncompatibleVersion);
+        }
+
+        let session = FusionKnNegotiatedSession {
+            version_major: negotiation.selected_version_major,
+            version_minor: negotiation.selected_version_minor,
+            transport: negotiation.transport,
+            capabilities: negotiation.capabilities,
+            max_payload_bytes: negotiation.max_payload_bytes,
+        };
+        self.session = Some(session);
+        Ok(session)
+    }
+
+    /// Reports the current grant state of one claim without consuming it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the session did not confirm claims, the exchange fails, or the
+    /// peer refuses the query.
+    pub fn query_claim(
+        &mut self,
+        request: &FusionKnClaimRequest,
+    ) -> Result<FusionKnClaimResponse, FusionKnClientError<T::Error>> {
+        self.claim_exchange(FusionKnCommand::QueryClaim, request)
+    }
+
+    /// Authorizes one claim use through the courier's authority.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the session did not confirm claims, the exchange fails, or the
+    /// authority does not grant the claim.
+    pub fn request_claim(
+        &mut self,
+        request: &FusionKnClaimRequest,
+    ) -> Result<FusionKnClaimResponse, FusionKnClientError<T::Error>> {
+        self.claim_exchange(FusionKnCommand::RequestClaim, request)
+    }
+
+    /// Registers one event subscription.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the session did not confirm events, the exchange fails, or the
+    /// peer refuses the subscription.
+    pub fn subscribe_events(
+        &mut self,
+        request: &FusionKnEventSubscribeRequest,
+    ) -> Result<FusionKnEventSubscribeResponse, FusionKnClientError<T::Error>> {
+        let mut response = [0_u8; MAX_RESPONSE_LEN];
+        let payload = self.exchange(
+            FusionKnCommand::SubscribeEvents,
+            FusionKnEventSubscribeRequest::ENCODED_LEN,
+            |dst| request.encode_into(dst),
+            FusionKnEventSubscribeResponse::ENCODED_LEN,
+            &mut response,
+        )?;
+        Ok(FusionKnEventSubscribeResponse::decode_from(payload)?)
+    }
+
+    /// Dequeues the next event of one subscription, or `None` when nothing is queued.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the session did not confirm events, the exchange fails, or the
+    /// peer refuses the poll.
+    pub fn poll_event(
+        &mut self,
+        subscription: u32,
+    ) -> Result<Option<FusionKnEventRecord>, FusionKnClientError<T::Error>> {
+        let request = FusionKnEventPollRequest { subscription };
+        let mut response = [0_u8; MAX_RESPONSE_LEN];
+        match self.exchange(
+            FusionKnCommand::PollEvent,
+            FusionKnEventPollRequest::ENCODED_LEN,
+            |dst| request.encode_into(dst),
+            FusionKnEventRecord::ENCODED_LEN,
+            &mut response,
+        ) {
+            Ok(payload) => Ok(Some(FusionKnEventRecord::decode_from(payload)?)),
+            Err(FusionKnClientError::Status(FusionKnStatusCode::WouldBlock)) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Retrieves the runtime summary of one courier.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the session did not confirm courier summaries, the exchange
+    /// fails, or the peer refuses the request.
+    pub fn courier_summary(
+        &mut self,
+        courier: u64,
+    ) -> Result<FusionKnCourierSummary, FusionKnClientError<T::Error>> {
+        let request = FusionKnCourierSummaryRequest { courier };
+        let mut response = [0_u8; MAX_RESPONSE_LEN];
+        let payload = self.exchange(
+            FusionKnCommand::CourierSummary,
+            FusionKnCourierSummaryRequest::ENCODED_LEN,
+            |dst| request.encode_into(dst),
+            FusionKnCourierSummary::ENCODED_LEN,
+            &mut response,
+        )?;
+        Ok(FusionKnCourierSummary::decode_from(payload)?)
+    }
+
+    fn claim_exchange(
+        &mut self,
+        command: FusionKnCommand,
+        request: &FusionKnClaimRequest,
+    ) -> Result<FusionKnClaimResponse, FusionKnClientError<T::Error>> {
+        let mut response = [0_u8; MAX_RESPONSE_LEN];
+        let payload = self.exchange(
+            command,
+            FusionKnClaimRequest::ENCODED_LEN,
+            |dst| request.encode_into(dst),
+            FusionKnClaimResponse::ENCODED_LEN,
+            &mut response,
+        )?;
+        Ok(FusionKnClaimResponse::decode_from(payload)?)
+    }
+
+    /// Sends one framed request and returns the validated response payload.
+    fn exchange<'r>(
+        &mut self,
+        command: FusionKnCommand,
+        request_len: usize,
+        encode: impl FnOnce(&mut [u8]) -> Result<(), FusionKnWireError>,
+        response_len: usize,
+        response: &'r mut [u8; MAX_RESPONSE_LEN],
+    ) -> Result<&'r [u8], FusionKnClientError<T::Error>> {
+        if command != FusionKnCommand::Negotiate {
+            let negotiated = self.session.is_some_and(|session| {
+                session.capabilities.contains(command.required_capability())
+                    && session.version_minor >= command.introduced_in_minor()
+            });
+            if !negotiated {
+                return Err(FusionKnClientError::CommandNotNegotiated);
+            }
+        }
+
+        let request_id = self.allocate_request_id();
+        let request_header = FusionKnMessageHeader::request(
+            command,
             self.transport.transport_kind(),
             request_id,
-            FusionKnNegotiationRequest::ENCODED_LEN_U32,
+            u32::try_from(request_len).map_err(|_| FusionKnWireError::BufferTooSmall)?,
         );
+        let mut request = [0_u8; MAX_REQUEST_LEN];
+        let request = request
+            .get_mut(..FusionKnMessageHeader::ENCODED_LEN + request_len)
+            .ok_or(FusionKnWireError::BufferTooSmall)?;
+        let (header_bytes, payload_bytes) =
+            request.split_at_mut(FusionKnMessageHeader::ENCODED_LEN);
+        request_header.encode_into(header_bytes)?;
+        encode(payload_bytes)?;
 
-        let mut request =
-            [0_u8; FusionKnMessageHeader::ENCODED_LEN + FusionKnNegotiationRequest::ENCODED_LEN];
-        request_header.encode_into(&mut request[..FusionKnMessageHeader::ENCODED_LEN])?;
-        request_payload.encode_into(&mut request[FusionKnMessageHeader::ENCODED_LEN..])?;
-
-        let mut response =
-            [0_u8; FusionKnMessageHeader::ENCODED_LEN + FusionKnNegotiationResponse::ENCODED_LEN];
+        let response = response
+            .get_mut(..FusionKnMessageHeader::ENCODED_LEN + response_len)
+            .ok_or(FusionKnWireError::BufferTooSmall)?;
         let response_bytes = self
             .transport
-            .transact(&request, &mut response)
+            .transact(request, response)
             .map_err(FusionKnClientError::Transport)?;
         if response_bytes < FusionKnMessageHeader::ENCODED_LEN {
             return Err(FusionKnClientError::Wire(FusionKnWireError::BufferTooSmall));
@@ -158,24 +350,7 @@ where
         if required > response.len() || response_bytes < required {
             return Err(FusionKnClientError::ResponseTooLarge);
         }
-
-        let negotiation = FusionKnNegotiationResponse::decode_from(
-            &response[FusionKnMessageHeader::ENCODED_LEN..required],
-        )?;
-        if negotiation.selected_version_major != request_payload.max_version_major
-            || negotiation.selected_version_minor < request_payload.min_version_minor
-            || negotiation.transport != self.transport.transport_kind()
-        {
-            return Err(FusionKnClientError::IncompatibleVersion);
-        }
-
-        Ok(FusionKnNegotiatedSession {
-            version_major: negotiation.selected_version_major,
-            version_minor: negotiation.selected_version_minor,
-            transport: negotiation.transport,
-            capabilities: negotiation.capabilities,
-            max_payload_bytes: negotiation.max_payload_bytes,
-        })
+        Ok(&response[FusionKnMessageHeader::ENCODED_LEN..required])
     }
 
     const fn allocate_request_id(&mut self) -> u32 {
@@ -259,4 +434,20 @@ mod tests {
                 .contains(FusionKnCapabilityFlags::NEGOTIATION)
         );
     }
+
+    #[test]
+    fn client_refuses_commands_the_session_did_not_confirm() {
+        let mut client = FusionKnClient::new(FakeTransport);
+        assert_eq!(
+            client.courier_summary(1),
+            Err(FusionKnClientError::CommandNotNegotiated)
+        );
+
+        client.negotiate().expect("negotiation should succeed");
+        assert!(client.session().is_some());
+        assert_eq!(
+            client.poll_event(1),
+            Err(FusionKnClientError::CommandNotNegotiated)
+        );
+    }
 }
@@ -0,0 +1,884 @@
+//! Fixed-layout payloads for the commands that follow negotiation.
+//!
+//! Every payload here has one constant encoded length so both sides can size buffers without
+//! reading anything first. The vocabulary mirrors the root courier authority in `fusion-sys`
+//! (`CourierAuthorityRegistry`, claim grants, courier runtime summaries) without depending on
+//! it: couriers and claim contexts travel as their raw `u64` identifiers, and qualified claim
+//! IDs travel as bounded UTF-8 text the kernel side parses with the same grammar.
+//!
+//! Optional fields are carried as a presence bit plus a zeroed slot rather than a sentinel
+//! value, and every reserved byte must be zero on encode and is ignored on decode.
+
+use bitflags::bitflags;
+
+use super::wire::{
+    FusionKnWireError,
+    read_u16,
+    read_u32,
+    read_u64,
+    write_u16,
+    write_u32,
+    write_u64,
+};
+
+/// Largest qualified claim ID one claim payload can carry, in bytes.
+pub const FUSION_KN_CLAIM_ID_CAPACITY: usize = 120;
+
+/// Bounded qualified claim identity in canonical `principal=>claim.scope` text form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnClaimId {
+    len: u16,
+    bytes: [u8; FUSION_KN_CLAIM_ID_CAPACITY],
+}
+
+impl FusionKnClaimId {
+    /// Copies one qualified claim ID into a fixed wire slot.
+    ///
+    /// The text is not parsed here; the authority side owns the claim grammar.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `raw` is empty or longer than [`FUSION_KN_CLAIM_ID_CAPACITY`].
+    pub fn new(raw: &str) -> Result<Self, FusionKnWireError> {
+        if raw.is_empty() || raw.len() > FUSION_KN_CLAIM_ID_CAPACITY {
+            return Err(FusionKnWireError::InvalidPayload);
+        }
+        let len = u16::try_from(raw.len()).map_err(|_| FusionKnWireError::InvalidPayload)?;
+        let mut bytes = [0; FUSION_KN_CLAIM_ID_CAPACITY];
+        bytes[..raw.len()].copy_from_slice(raw.as_bytes());
+        Ok(Self { len, bytes })
+    }
+
+    /// Returns the claim ID text.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // Construction and decode both validate UTF-8, so this cannot fall back in practice.
+        core::str::from_utf8(&self.bytes[..usize::from(self.len)]).unwrap_or("")
+    }
+}
+
+/// Claim query or claim request issued against one courier authority.
+///
+/// | Offset | Size | Field                         |
+/// |-------:|-----:|-------------------------------|
+/// |      0 |    8 | courier ID                    |
+/// |      8 |    2 | claim ID length               |
+/// |     10 |    6 | reserved                      |
+/// |     16 |  120 | claim ID text, zero padded    |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnClaimRequest {
+    /// Courier whose authority is asked.
+    pub courier: u64,
+    /// Qualified claim the courier wants to use or inspect.
+    pub claim: FusionKnClaimId,
+}
+
+impl FusionKnClaimRequest {
+    /// Encoded byte length of the payload.
+    pub const ENCODED_LEN: usize = 136;
+    /// Encoded byte length of the payload as `u32`.
+    pub const ENCODED_LEN_U32: u32 = 136;
+
+    /// Encodes the payload into the provided byte buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `dst` is smaller than [`Self::ENCODED_LEN`].
+    pub fn encode_into(&self, dst: &mut [u8]) -> Result<(), FusionKnWireError> {
+        if dst.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        write_u64(&mut dst[0..8], self.courier);
+        write_u16(&mut dst[8..10], self.claim.len);
+        dst[10..16].fill(0);
+        dst[16..Self::ENCODED_LEN].copy_from_slice(&self.claim.bytes);
+        Ok(())
+    }
+
+    /// Decodes the payload from the provided byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the payload is too small or the claim ID is empty, too long, or
+    /// not UTF-8.
+    pub fn decode_from(src: &[u8]) -> Result<Self, FusionKnWireError> {
+        if src.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        let len = usize::from(read_u16(&src[8..10]));
+        if len > FUSION_KN_CLAIM_ID_CAPACITY {
+            return Err(FusionKnWireError::InvalidPayload);
+        }
+        let text = core::str::from_utf8(&src[16..16 + len])
+            .map_err(|_| FusionKnWireError::InvalidPayload)?;
+
+        Ok(Self {
+            courier: read_u64(&src[0..8]),
+            claim: FusionKnClaimId::new(text)?,
+        })
+    }
+}
+
+/// Wire form of one claim grant state.
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FusionKnClaimState {
+    /// Grant exists but has not been activated yet.
+    Pending = 1,
+    /// Grant is active.
+    Granted = 2,
+    /// One-shot grant has already been used.
+    Consumed = 3,
+    /// Grant was revoked by its authority.
+    Revoked = 4,
+    /// Grant passed its expiry.
+    Expired = 5,
+}
+
+impl FusionKnClaimState {
+    const fn from_u16(raw: u16) -> Option<Self> {
+        match raw {
+            1 => Some(Self::Pending),
+            2 => Some(Self::Granted),
+            3 => Some(Self::Consumed),
+            4 => Some(Self::Revoked),
+            5 => Some(Self::Expired),
+            _ => None,
+        }
+    }
+}
+
+/// Wire form of how one claim grant entered the system.
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FusionKnClaimSource {
+    /// Granted by local policy.
+    LocalPolicy = 1,
+    /// Delegated from a remote domain.
+    RemoteDomain = 2,
+    /// Intrinsic to the issuing authority.
+    AuthorityIntrinsic = 3,
+    /// Established by an attachment bond attestation.
+    AttachmentAttestation = 4,
+}
+
+impl FusionKnClaimSource {
+    const fn from_u16(raw: u16) -> Option<Self> {
+        match raw {
+            1 => Some(Self::LocalPolicy),
+            2 => Some(Self::RemoteDomain),
+            3 => Some(Self::AuthorityIntrinsic),
+            4 => Some(Self::AttachmentAttestation),
+            _ => None,
+        }
+    }
+}
+
+/// Wire form of one claim grant lifetime.
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FusionKnClaimLifetime {
+    /// Consumed by exactly one use.
+    OneShot = 1,
+    /// Active until revoked or the admission seal changes.
+    Retained = 2,
+    /// Active until the expiry carried in the response.
+    ExpiresAt = 3,
+}
+
+impl FusionKnClaimLifetime {
+    const fn from_u16(raw: u16) -> Option<Self> {
+        match raw {
+            1 => Some(Self::OneShot),
+            2 => Some(Self::Retained),
+            3 => Some(Self::ExpiresAt),
+            _ => None,
+        }
+    }
+}
+
+/// Claim grant snapshot answered to `QueryClaim` and `RequestClaim`.
+///
+/// | Offset | Size | Field                                  |
+/// |-------:|-----:|----------------------------------------|
+/// |      0 |    2 | state                                  |
+/// |      2 |    2 | source                                 |
+/// |      4 |    2 | lifetime                               |
+/// |      6 |    2 | flags, bit 0 = expiry present          |
+/// |      8 |    8 | claim context ID                       |
+/// |     16 |    8 | issued at, Unix seconds                |
+/// |     24 |    8 | expires at, Unix seconds, or zero      |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnClaimResponse {
+    /// Current grant state.
+    pub state: FusionKnClaimState,
+    /// How the grant entered the system.
+    pub source: FusionKnClaimSource,
+    /// How long the grant lives.
+    pub lifetime: FusionKnClaimLifetime,
+    /// Claim context holding the grant.
+    pub claim_context: u64,
+    /// Time the grant was issued.
+    pub issued_at_unix_seconds: u64,
+    /// Absolute expiry, when the grant has one.
+    pub expires_at_unix_seconds: Option<u64>,
+}
+
+impl FusionKnClaimResponse {
+    /// Encoded byte length of the payload.
+    pub const ENCODED_LEN: usize = 32;
+    /// Encoded byte length of the payload as `u32`.
+    pub const ENCODED_LEN_U32: u32 = 32;
+
+    const EXPIRES_PRESENT: u16 = 1 << 0;
+
+    /// Encodes the payload into the provided byte buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `dst` is smaller than [`Self::ENCODED_LEN`].
+    pub fn encode_into(&self, dst: &mut [u8]) -> Result<(), FusionKnWireError> {
+        if dst.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        write_u16(&mut dst[0..2], self.state as u16);
+        write_u16(&mut dst[2..4], self.source as u16);
+        write_u16(&mut dst[4..6], self.lifetime as u16);
+        write_u16(
+            &mut dst[6..8],
+            if self.expires_at_unix_seconds.is_some() {
+                Self::EXPIRES_PRESENT
+            } else {
+                0
+            },
+        );
+        write_u64(&mut dst[8..16], self.claim_context);
+        write_u64(&mut dst[16..24], self.issued_at_unix_seconds);
+        write_u64(&mut dst[24..32], self.expires_at_unix_seconds.unwrap_or(0));
+        Ok(())
+    }
+
+    /// Decodes the payload from the provided byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the payload is too small or carries an unknown state, source,
+    /// or lifetime.
+    pub fn decode_from(src: &[u8]) -> Result<Self, FusionKnWireError> {
+        if src.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        let state = FusionKnClaimState::from_u16(read_u16(&src[0..2]))
+            .ok_or(FusionKnWireError::InvalidPayload)?;
+        let source = FusionKnClaimSource::from_u16(read_u16(&src[2..4]))
+            .ok_or(FusionKnWireError::InvalidPayload)?;
+        let lifetime = FusionKnClaimLifetime::from_u16(read_u16(&src[4..6]))
+            .ok_or(FusionKnWireError::InvalidPayload)?;
+        let expires_present = read_u16(&src[6..8]) & Self::EXPIRES_PRESENT != 0;
+
+        Ok(Self {
+            state,
+            source,
+            lifetime,
+            claim_context: read_u64(&src[8..16]),
+            issued_at_unix_seconds: read_u64(&src[16..24]),
+            expires_at_unix_seconds: expires_present.then(|| read_u64(&src[24..32])),
+        })
+    }
+}
+
+/// Event kinds a subscription can carry.
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FusionKnEventKind {
+    /// A claim was granted; `value` is the claim context.
+    ClaimGranted = 1,
+    /// A claim was revoked; `value` is the claim context.
+    ClaimRevoked = 2,
+    /// A courier admission seal was reset; `detail` is the mismatch reason.
+    SealInvalidated = 3,
+    /// A courier run state changed; `detail` is the new [`FusionKnCourierRunState`].
+    CourierRunStateChanged = 4,
+    /// A courier responsiveness class changed; `detail` is the new
+    /// [`FusionKnCourierResponsiveness`].
+    CourierResponsivenessChanged = 5,
+}
+
+impl FusionKnEventKind {
+    const fn from_u16(raw: u16) -> Option<Self> {
+        match raw {
+            1 => Some(Self::ClaimGranted),
+            2 => Some(Self::ClaimRevoked),
+            3 => Some(Self::SealInvalidated),
+            4 => Some(Self::CourierRunStateChanged),
+            5 => Some(Self::CourierResponsivenessChanged),
+            _ => None,
+        }
+    }
+
+    /// Returns the subscription mask bit selecting this kind.
+    #[must_use]
+    pub const fn mask(self) -> FusionKnEventMask {
+        FusionKnEventMask::from_bits_retain(1 << (self as u16 - 1))
+    }
+}
+
+bitflags! {
+    /// Set of event kinds one subscription selects.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct FusionKnEventMask: u32 {
+        /// Selects [`FusionKnEventKind::ClaimGranted`].
+        const CLAIM_GRANTED = 1 << 0;
+        /// Selects [`FusionKnEventKind::ClaimRevoked`].
+        const CLAIM_REVOKED = 1 << 1;
+        /// Selects [`FusionKnEventKind::SealInvalidated`].
+        const SEAL_INVALIDATED = 1 << 2;
+        /// Selects [`FusionKnEventKind::CourierRunStateChanged`].
+        const COURIER_RUN_STATE = 1 << 3;
+        /// Selects [`FusionKnEventKind::CourierResponsivenessChanged`].
+        const COURIER_RESPONSIVENESS = 1 << 4;
+    }
+}
+
+/// Event subscription request.
+///
+/// | Offset | Size | Field                                   |
+/// |-------:|-----:|-----------------------------------------|
+/// |      0 |    8 | courier ID, zero when all couriers      |
+/// |      8 |    4 | requested event mask                    |
+/// |     12 |    4 | flags, bit 0 = courier filter present   |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnEventSubscribeRequest {
+    /// Courier whose events are wanted, or every courier the caller may observe.
+    pub courier: Option<u64>,
+    /// Event kinds wanted.
+    pub events: FusionKnEventMask,
+}
+
+impl FusionKnEventSubscribeRequest {
+    /// Encoded byte length of the payload.
+    pub const ENCODED_LEN: usize = 16;
+    /// Encoded byte length of the payload as `u32`.
+    pub const ENCODED_LEN_U32: u32 = 16;
+
+    const COURIER_PRESENT: u32 = 1 << 0;
+
+    /// Encodes the payload into the provided byte buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `dst` is smaller than [`Self::ENCODED_LEN`].
+    pub fn encode_into(&self, dst: &mut [u8]) -> Result<(), FusionKnWireError> {
+        if dst.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        write_u64(&mut dst[0..8], self.courier.unwrap_or(0));
+        write_u32(&mut dst[8..12], self.events.bits());
+        write_u32(
+            &mut dst[12..16],
+            if self.courier.is_some() {
+                Self::COURIER_PRESENT
+            } else {
+                0
+            },
+        );
+        Ok(())
+    }
+
+    /// Decodes the payload from the provided byte slice.
+    ///
+    /// Unknown event bits are rejected so a subscriber never believes it asked for an
+    /// event kind the peer cannot name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the payload is too small or selects unknown event kinds.
+    pub fn decode_from(src: &[u8]) -> Result<Self, FusionKnWireError> {
+        if src.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        let events = FusionKnEventMask::from_bits(read_u32(&src[8..12]))
+            .ok_or(FusionKnWireError::InvalidPayload)?;
+        let courier_present = read_u32(&src[12..16]) & Self::COURIER_PRESENT != 0;
+
+        Ok(Self {
+            courier: courier_present.then(|| read_u64(&src[0..8])),
+            events,
+        })
+    }
+}
+
+/// Event subscription confirmation.
+///
+/// | Offset | Size | Field                          |
+/// |-------:|-----:|--------------------------------|
+/// |      0 |    4 | subscription ID                |
+/// |      4 |    4 | granted event mask             |
+/// |      8 |    4 | queue depth for this subscriber |
+/// |     12 |    4 | reserved                       |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnEventSubscribeResponse {
+    /// Identifier to poll with.
+    pub subscription: u32,
+    /// Event kinds the peer will actually deliver; never wider than requested.
+    pub events: FusionKnEventMask,
+    /// Events the peer queues before the oldest ones are dropped.
+    pub queue_depth: u32,
+}
+
+impl FusionKnEventSubscribeResponse {
+    /// Encoded byte length of the payload.
+    pub const ENCODED_LEN: usize = 16;
+    /// Encoded byte length of the payload as `u32`.
+    pub const ENCODED_LEN_U32: u32 = 16;
+
+    /// Encodes the payload into the provided byte buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `dst` is smaller than [`Self::ENCODED_LEN`].
+    pub fn encode_into(&self, dst: &mut [u8]) -> Result<(), FusionKnWireError> {
+        if dst.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        write_u32(&mut dst[0..4], self.subscription);
+        write_u32(&mut dst[4..8], self.events.bits());
+        write_u32(&mut dst[8..12], self.queue_depth);
+        write_u32(&mut dst[12..16], 0);
+        Ok(())
+    }
+
+    /// Decodes the payload from the provided byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the payload is too small or grants unknown event kinds.
+    pub fn decode_from(src: &[u8]) -> Result<Self, FusionKnWireError> {
+        if src.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        Ok(Self {
+            subscription: read_u32(&src[0..4]),
+            events: FusionKnEventMask::from_bits(read_u32(&src[4..8]))
+                .ok_or(FusionKnWireError::InvalidPayload)?,
+            queue_depth: read_u32(&src[8..12]),
+        })
+    }
+}
+
+/// Request for the next queued event of one subscription.
+///
+/// | Offset | Size | Field           |
+/// |-------:|-----:|-----------------|
+/// |      0 |    4 | subscription ID |
+/// |      4 |    4 | reserved        |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnEventPollRequest {
+    /// Subscription to poll.
+    pub subscription: u32,
+}
+
+impl FusionKnEventPollRequest {
+    /// Encoded byte length of the payload.
+    pub const ENCODED_LEN: usize = 8;
+    /// Encoded byte length of the payload as `u32`.
+    pub const ENCODED_LEN_U32: u32 = 8;
+
+    /// Encodes the payload into the provided byte buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `dst` is smaller than [`Self::ENCODED_LEN`].
+    pub fn encode_into(&self, dst: &mut [u8]) -> Result<(), FusionKnWireError> {
+        if dst.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        write_u32(&mut dst[0..4], self.subscription);
+        write_u32(&mut dst[4..8], 0);
+        Ok(())
+    }
+
+    /// Decodes the payload from the provided byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the payload is too small.
+    pub fn decode_from(src: &[u8]) -> Result<Self, FusionKnWireError> {
+        if src.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        Ok(Self {
+            subscription: read_u32(&src[0..4]),
+        })
+    }
+}
+
+/// One delivered event. An empty queue is answered with `WouldBlock` instead.
+///
+/// | Offset | Size | Field                                  |
+/// |-------:|-----:|----------------------------------------|
+/// |      0 |    8 | sequence number                        |
+/// |      8 |    8 | courier ID                             |
+/// |     16 |    2 | event kind                             |
+/// |     18 |    2 | events still queued after this one     |
+/// |     20 |    4 | kind-specific detail code              |
+/// |     24 |    8 | kind-specific value                    |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnEventRecord {
+    /// Monotonic per-subscription sequence; gaps mean events were dropped.
+    pub sequence: u64,
+    /// Courier the event is about.
+    pub courier: u64,
+    /// Event kind.
+    pub kind: FusionKnEventKind,
+    /// Events still queued for this subscription, saturated at `u16::MAX`.
+    pub pending: u16,
+    /// Kind-specific detail code.
+    pub detail: u32,
+    /// Kind-specific value.
+    pub value: u64,
+}
+
+impl FusionKnEventRecord {
+    /// Encoded byte length of the payload.
+    pub const ENCODED_LEN: usize = 32;
+    /// Encoded byte length of the payload as `u32`.
+    pub const ENCODED_LEN_U32: u32 = 32;
+
+    /// Encodes the payload into the provided byte buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `dst` is smaller than [`Self::ENCODED_LEN`].
+    pub fn encode_into(&self, dst: &mut [u8]) -> Result<(), FusionKnWireError> {
+        if dst.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        write_u64(&mut dst[0..8], self.sequence);
+        write_u64(&mut dst[8..16], self.courier);
+        write_u16(&mut dst[16..18], self.kind as u16);
+        write_u16(&mut dst[18..20], self.pending);
+        write_u32(&mut dst[20..24], self.detail);
+        write_u64(&mut dst[24..32], self.value);
+        Ok(())
+    }
+
+    /// Decodes the payload from the provided byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the payload is too small or carries an unknown event kind.
+    pub fn decode_from(src: &[u8]) -> Result<Self, FusionKnWireError> {
+        if src.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        Ok(Self {
+            sequence: read_u64(&src[0..8]),
+            courier: read_u64(&src[8..16]),
+            kind: FusionKnEventKind::from_u16(read_u16(&src[16..18]))
+                .ok_or(FusionKnWireError::InvalidPayload)?,
+            pending: read_u16(&src[18..20]),
+            detail: read_u32(&src[20..24]),
+            value: read_u64(&src[24..32]),
+        })
+    }
+}
+
+/// Request for one courier runtime summary.
+///
+/// | Offset | Size | Field      |
+/// |-------:|-----:|------------|
+/// |      0 |    8 | courier ID |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnCourierSummaryRequest {
+    /// Courier to summarize.
+    pub courier: u64,
+}
+
+impl FusionKnCourierSummaryRequest {
+    /// Encoded byte length of the payload.
+    pub const ENCODED_LEN: usize = 8;
+    /// Encoded byte length of the payload as `u32`.
+    pub const ENCODED_LEN_U32: u32 = 8;
+
+    /// Encodes the payload into the provided byte buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `dst` is smaller than [`Self::ENCODED_LEN`].
+    pub fn encode_into(&self, dst: &mut [u8]) -> Result<(), FusionKnWireError> {
+        if dst.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        write_u64(&mut dst[0..8], self.courier);
+        Ok(())
+    }
+
+    /// Decodes the payload from the provided byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the payload is too small.
+    pub fn decode_from(src: &[u8]) -> Result<Self, FusionKnWireError> {
+        if src.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        Ok(Self {
+            courier: read_u64(&src[0..8]),
+        })
+    }
+}
+
+/// Wire form of one courier scheduling policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FusionKnSchedulingPolicy {
+    /// Cooperative FIFO-style execution.
+    CooperativeRoundRobin,
+    /// Cooperative execution with priority-aware ordering.
+    CooperativePriority,
+    /// Cooperative queues distributed across carriers with work stealing.
+    CooperativeWorkStealing,
+    /// Outer-layer time slicing with the given quantum.
+    TimeSliced {
+        /// Quantum length in scheduler ticks.
+        quantum_ticks: u64,
+    },
+}
+
+impl FusionKnSchedulingPolicy {
+    const fn code(self) -> u16 {
+        match self {
+            Self::CooperativeRoundRobin => 1,
+            Self::CooperativePriority => 2,
+            Self::CooperativeWorkStealing => 3,
+            Self::TimeSliced { .. } => 4,
+        }
+    }
+
+    const fn quantum_ticks(self) -> u64 {
+        match self {
+            Self::TimeSliced { quantum_ticks } => quantum_ticks,
+            Self::CooperativeRoundRobin
+            | Self::CooperativePriority
+            | Self::CooperativeWorkStealing => 0,
+        }
+    }
+
+    const fn decode(code: u16, quantum_ticks: u64) -> Option<Self> {
+        match code {
+            1 => Some(Self::CooperativeRoundRobin),
+            2 => Some(Self::CooperativePriority),
+            3 => Some(Self::CooperativeWorkStealing),
+            4 => Some(Self::TimeSliced { quantum_ticks }),
+            _ => None,
+        }
+    }
+}
+
+/// Wire form of one courier run state.
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FusionKnCourierRunState {
+    /// Nothing runnable.
+    Idle = 1,
+    /// Work is queued.
+    Runnable = 2,
+    /// Work is executing.
+    Running = 3,
+    /// Progress stalled past policy bounds.
+    Stale = 4,
+    /// Required interactions stopped making progress.
+    NonResponsive = 5,
+}
+
+impl FusionKnCourierRunState {
+    const fn from_u16(raw: u16) -> Option<Self> {
+        match raw {
+            1 => Some(Self::Idle),
+            2 => Some(Self::Runnable),
+            3 => Some(Self::Running),
+            4 => Some(Self::Stale),
+            5 => Some(Self::NonResponsive),
+            _ => None,
+        }
+    }
+}
+
+/// Wire form of one courier responsiveness class.
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FusionKnCourierResponsiveness {
+    /// Observable obligations are progressing.
+    Responsive = 1,
+    /// Observable obligations are lagging.
+    Stale = 2,
+    /// Observable obligations stopped.
+    NonResponsive = 3,
+}
+
+impl FusionKnCourierResponsiveness {
+    const fn from_u16(raw: u16) -> Option<Self> {
+        match raw {
+            1 => Some(Self::Responsive),
+            2 => Some(Self::Stale),
+            3 => Some(Self::NonResponsive),
+            _ => None,
+        }
+    }
+}
+
+/// Counters for one courier-local runnable lane.
+///
+/// | Offset | Size | Field           |
+/// |-------:|-----:|-----------------|
+/// |      0 |    4 | active units    |
+/// |      4 |    4 | runnable units  |
+/// |      8 |    4 | running units   |
+/// |     12 |    4 | blocked units   |
+/// |     16 |    4 | available slots |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FusionKnLaneSummary {
+    /// Units currently admitted.
+    pub active_units: u32,
+    /// Units ready to run.
+    pub runnable_units: u32,
+    /// Units executing.
+    pub running_units: u32,
+    /// Units waiting on something.
+    pub blocked_units: u32,
+    /// Free admission slots.
+    pub available_slots: u32,
+}
+
+impl FusionKnLaneSummary {
+    /// Encoded byte length of one lane.
+    pub const ENCODED_LEN: usize = 20;
+
+    fn encode_into(self, dst: &mut [u8]) {
+        write_u32(&mut dst[0..4], self.active_units);
+        write_u32(&mut dst[4..8], self.runnable_units);
+        write_u32(&mut dst[8..12], self.running_units);
+        write_u32(&mut dst[12..16], self.blocked_units);
+        write_u32(&mut dst[16..20], self.available_slots);
+    }
+
+    fn decode_from(src: &[u8]) -> Self {
+        Self {
+            active_units: read_u32(&src[0..4]),
+            runnable_units: read_u32(&src[4..8]),
+            running_units: read_u32(&src[8..12]),
+            blocked_units: read_u32(&src[12..16]),
+            available_slots: read_u32(&src[16..20]),
+        }
+    }
+}
+
+/// Courier runtime summary answered to `CourierSummary`.
+///
+/// | Offset | Size | Field                                              |
+/// |-------:|-----:|----------------------------------------------------|
+/// |      0 |    8 | courier ID                                         |
+/// |      8 |    2 | scheduling policy                                  |
+/// |     10 |    2 | run state                                          |
+/// |     12 |    2 | responsiveness                                     |
+/// |     14 |    2 | lanes present, bit 0 fiber, 1 async, 2 control     |
+/// |     16 |    8 | time-slice quantum ticks, zero unless time sliced  |
+/// |     24 |   20 | fiber lane                                         |
+/// |     44 |   20 | async lane                                         |
+/// |     64 |   20 | control lane                                       |
+/// |     84 |    4 | reserved                                           |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnCourierSummary {
+    /// Courier summarized.
+    pub courier: u64,
+    /// Scheduling policy in force.
+    pub policy: FusionKnSchedulingPolicy,
+    /// Coarse run state.
+    pub run_state: FusionKnCourierRunState,
+    /// Responsiveness class.
+    pub responsiveness: FusionKnCourierResponsiveness,
+    /// Fiber lane counters, when the courier runs fibers.
+    pub fiber_lane: Option<FusionKnLaneSummary>,
+    /// Async lane counters, when the courier runs async tasks.
+    pub async_lane: Option<FusionKnLaneSummary>,
+    /// Control lane counters, when the courier runs control work.
+    pub control_lane: Option<FusionKnLaneSummary>,
+}
+
+impl FusionKnCourierSummary {
+    /// Encoded byte length of the payload.
+    pub const ENCODED_LEN: usize = 88;
+    /// Encoded byte length of the payload as `u32`.
+    pub const ENCODED_LEN_U32: u32 = 88;
+
+    const LANE_OFFSETS: [usize; 3] = [24, 44, 64];
+
+    /// Encodes the payload into the provided byte buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `dst` is smaller than [`Self::ENCODED_LEN`].
+    pub fn encode_into(&self, dst: &mut [u8]) -> Result<(), FusionKnWireError> {
+        if dst.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        dst[..Self::ENCODED_LEN].fill(0);
+        write_u64(&mut dst[0..8], self.courier);
+        write_u16(&mut dst[8..10], self.policy.code());
+        write_u16(&mut dst[10..12], self.run_state as u16);
+        write_u16(&mut dst[12..14], self.responsiveness as u16);
+        write_u64(&mut dst[16..24], self.policy.quantum_ticks());
+
+        let mut present = 0_u16;
+        for (bit, (lane, offset)) in [self.fiber_lane, self.async_lane, self.control_lane]
+            .into_iter()
+            .zip(Self::LANE_OFFSETS)
+            .enumerate()
+        {
+            if let Some(lane) = lane {
+                present |= 1 << bit;
+                lane.encode_into(&mut dst[offset..offset + FusionKnLaneSummary::ENCODED_LEN]);
+            }
+        }
+        write_u16(&mut dst[14..16], present);
+        Ok(())
+    }
+
+    /// Decodes the payload from the provided byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the payload is too small or carries an unknown policy, run
+    /// state, or responsiveness class.
+    pub fn decode_from(src: &[u8]) -> Result<Self, FusionKnWireError> {
+        if src.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        let policy =
+            FusionKnSchedulingPolicy::decode(read_u16(&src[8..10]), read_u64(&src[16..24]))
+                .ok_or(FusionKnWireError::InvalidPayload)?;
+        let run_state = FusionKnCourierRunState::from_u16(read_u16(&src[10..12]))
+            .ok_or(FusionKnWireError::InvalidPayload)?;
+        let responsiveness = FusionKnCourierResponsiveness::from_u16(read_u16(&src[12..14]))
+            .ok_or(FusionKnWireError::InvalidPayload)?;
+        let present = read_u16(&src[14..16]);
+        let lane = |bit: usize| {
+            let offset = Self::LANE_OFFSETS[bit];
+            (present & (1 << bit) != 0).then(|| {
+                FusionKnLaneSummary::decode_from(
+                    &src[offset..offset + FusionKnLaneSummary::ENCODED_LEN],
+                )
+            })
+        };
+
+        Ok(Self {
+            courier: read_u64(&src[0..8]),
+            policy,
+            run_state,
+            responsiveness,
+            fiber_lane: lane(0),
+            async_lane: lane(1),
+            control_lane: lane(2),
+        })
+    }
+}
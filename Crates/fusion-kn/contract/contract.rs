@@ -6,6 +6,10 @@
 
 use bitflags::bitflags;
 
+/// Fixed-layout payloads for the commands that follow negotiation.
+pub mod command;
+/// Fixed-layout shared-memory submission/completion ring for mediated kernel exchanges.
+pub mod ring;
 /// Fixed-layout bitflat wire protocol for mediated kernel exchanges.
 pub mod wire;
 
@@ -0,0 +1,242 @@
+//! Fixed-layout shared-memory ring for the mediated Fusion kernel boundary.
+//!
+//! The ring carries the exact same bitflat messages as the byte-stream transports; it only
+//! replaces how they move. One shared mapping holds a control block followed by a submission
+//! queue (client toward kernel) and a completion queue (kernel toward client). Every field is
+//! little-endian at a fixed offset from the start of the mapping:
+//!
+//! | Offset | Size | Field                                                   |
+//! |-------:|-----:|---------------------------------------------------------|
+//! |      0 |    4 | magic `FKNR`                                            |
+//! |      4 |    2 | control block length, always [`FUSION_KN_RING_CONTROL_LEN`] |
+//! |      6 |    2 | protocol major version                                  |
+//! |      8 |    2 | protocol minor version                                  |
+//! |     10 |    2 | reserved, zero in v1                                    |
+//! |     12 |    4 | slot count per queue, a power of two                    |
+//! |     16 |    4 | slot length in bytes, a multiple of 8                   |
+//! |     20 |   44 | reserved, zero in v1                                    |
+//! |     64 |    4 | submission producer index                               |
+//! |     68 |    4 | submission waiter count                                 |
+//! |    128 |    4 | submission consumer index                               |
+//! |    192 |    4 | completion producer index                               |
+//! |    196 |    4 | completion waiter count                                 |
+//! |    256 |    4 | completion consumer index                               |
+//! |    320 |  n*s | submission slots                                        |
+//! | 320+ns |  n*s | completion slots                                        |
+//!
+//! Indices are free-running `u32` counters; a slot is addressed by `index & (count - 1)`.
+//! Producer and consumer words sit on separate 64-byte lines so the two sides never share a
+//! cache line they both write. The producer of a queue publishes by storing its index after
+//! the slot is written; the consumer releases a slot by storing its own index after the slot
+//! is read.
+//!
+//! Each slot starts with a [`FusionKnRingSlotDescriptor`] followed by one complete encoded
+//! message (header plus payload). Waiter counts let a producer skip the doorbell wake when
+//! nobody is sleeping, so a busy ring pays no syscall per message.
+
+use super::wire::{
+    FUSION_KN_PROTOCOL_VERSION_MAJOR,
+    FUSION_KN_PROTOCOL_VERSION_MINOR,
+    FusionKnMessageHeader,
+    FusionKnWireError,
+    read_u16,
+    read_u32,
+    write_u16,
+    write_u32,
+};
+
+/// Four-byte marker at the start of a Fusion kernel shared-memory ring.
+pub const FUSION_KN_RING_MAGIC: [u8; 4] = *b"FKNR";
+/// Encoded byte length of the ring control block.
+pub const FUSION_KN_RING_CONTROL_LEN: usize = 320;
+/// Encoded byte length of the ring control block as `u16`.
+pub const FUSION_KN_RING_CONTROL_LEN_U16: u16 = 320;
+
+/// Fixed geometry of one shared-memory ring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnRingLayout {
+    slot_count: u32,
+    slot_bytes: u32,
+}
+
+impl FusionKnRingLayout {
+    /// Offset of the submission producer index word.
+    pub const SUBMISSION_PRODUCER_OFFSET: usize = 64;
+    /// Offset of the submission waiter count word.
+    pub const SUBMISSION_WAITERS_OFFSET: usize = 68;
+    /// Offset of the submission consumer index word.
+    pub const SUBMISSION_CONSUMER_OFFSET: usize = 128;
+    /// Offset of the completion producer index word.
+    pub const COMPLETION_PRODUCER_OFFSET: usize = 192;
+    /// Offset of the completion waiter count word.
+    pub const COMPLETION_WAITERS_OFFSET: usize = 196;
+    /// Offset of the completion consumer index word.
+    pub const COMPLETION_CONSUMER_OFFSET: usize = 256;
+
+    /// Builds and validates one ring geometry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the slot count is not a non-zero power of two, the slot length
+    /// is not a multiple of 8 or cannot hold a descriptor plus one message header, or the
+    /// whole ring would not fit in `u32` bytes.
+    pub const fn new(slot_count: u32, slot_bytes: u32) -> Result<Self, FusionKnWireError> {
+        let min_slot = FusionKnRingSlotDescriptor::ENCODED_LEN + FusionKnMessageHeader::ENCODED_LEN;
+        if !slot_count.is_power_of_two()
+            || !slot_bytes.is_multiple_of(8)
+            || (slot_bytes as usize) < min_slot
+        {
+            return Err(FusionKnWireError::InvalidRingLayout);
+        }
+        let queues = slot_count as u64 * slot_bytes as u64 * 2;
+        if FUSION_KN_RING_CONTROL_LEN as u64 + queues > u32::MAX as u64 {
+            return Err(FusionKnWireError::InvalidRingLayout);
+        }
+        Ok(Self {
+            slot_count,
+            slot_bytes,
+        })
+    }
+
+    /// Returns the number of slots in each queue.
+    #[must_use]
+    pub const fn slot_count(self) -> u32 {
+        self.slot_count
+    }
+
+    /// Returns the byte length of one slot, descriptor included.
+    #[must_use]
+    pub const fn slot_bytes(self) -> u32 {
+        self.slot_bytes
+    }
+
+    /// Returns the largest encoded message one slot can carry.
+    #[must_use]
+    pub const fn max_message_bytes(self) -> usize {
+        self.slot_bytes as usize - FusionKnRingSlotDescriptor::ENCODED_LEN
+    }
+
+    /// Returns the byte length of the whole shared mapping.
+    #[must_use]
+    pub const fn total_bytes(self) -> usize {
+        FUSION_KN_RING_CONTROL_LEN + self.queue_bytes() * 2
+    }
+
+    /// Returns the offset of the submission slot addressed by a free-running index.
+    #[must_use]
+    pub const fn submission_slot_offset(self, index: u32) -> usize {
+        FUSION_KN_RING_CONTROL_LEN + self.slot_index(index)
+    }
+
+    /// Returns the offset of the completion slot addressed by a free-running index.
+    #[must_use]
+    pub const fn completion_slot_offset(self, index: u32) -> usize {
+        FUSION_KN_RING_CONTROL_LEN + self.queue_bytes() + self.slot_index(index)
+    }
+
+    /// Encodes a fresh control block with all indices and waiter counts at zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `dst` is smaller than [`FUSION_KN_RING_CONTROL_LEN`].
+    pub fn encode_control_into(self, dst: &mut [u8]) -> Result<(), FusionKnWireError> {
+        if dst.len() < FUSION_KN_RING_CONTROL_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+
+        dst[..FUSION_KN_RING_CONTROL_LEN].fill(0);
+        dst[..4].copy_from_slice(&FUSION_KN_RING_MAGIC);
+        write_u16(&mut dst[4..6], FUSION_KN_RING_CONTROL_LEN_U16);
+        write_u16(&mut dst[6..8], FUSION_KN_PROTOCOL_VERSION_MAJOR);
+        write_u16(&mut dst[8..10], FUSION_KN_PROTOCOL_VERSION_MINOR);
+        write_u32(&mut dst[12..16], self.slot_count);
+        write_u32(&mut dst[16..20], self.slot_bytes);
+        Ok(())
+    }
+
+    /// Decodes and validates the control block at the start of a shared mapping.
+    ///
+    /// `mapping` must cover the whole ring, not only the control block, so a peer cannot be
+    /// talked into indexing slots past the end of what it mapped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the mapping is too small for the layout it describes, the
+    /// framing fields are wrong, or the protocol major version differs from this build.
+    pub fn decode_control(mapping: &[u8]) -> Result<Self, FusionKnWireError> {
+        if mapping.len() < FUSION_KN_RING_CONTROL_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        if mapping[..4] != FUSION_KN_RING_MAGIC {
+            return Err(FusionKnWireError::InvalidMagic);
+        }
+        if read_u16(&mapping[4..6]) != FUSION_KN_RING_CONTROL_LEN_U16 {
+            return Err(FusionKnWireError::InvalidHeaderLength);
+        }
+        if read_u16(&mapping[6..8]) != FUSION_KN_PROTOCOL_VERSION_MAJOR {
+            return Err(FusionKnWireError::InvalidRingLayout);
+        }
+
+        let layout = Self::new(read_u32(&mapping[12..16]), read_u32(&mapping[16..20]))?;
+        if mapping.len() < layout.total_bytes() {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+        Ok(layout)
+    }
+
+    const fn queue_bytes(self) -> usize {
+        self.slot_count as usize * self.slot_bytes as usize
+    }
+
+    const fn slot_index(self, index: u32) -> usize {
+        (index & (self.slot_count - 1)) as usize * self.slot_bytes as usize
+    }
+}
+
+/// Per-slot descriptor preceding each message in the ring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnRingSlotDescriptor {
+    /// Encoded length of the message that follows the descriptor.
+    pub message_bytes: u32,
+    /// Free-running queue index the slot was published under.
+    ///
+    /// Consumers reject a slot whose sequence differs from the index they expect, which
+    /// catches stale or torn slots instead of decoding them.
+    pub sequence: u32,
+}
+
+impl FusionKnRingSlotDescriptor {
+    /// Encoded byte length of the descriptor.
+    pub const ENCODED_LEN: usize = 8;
+
+    /// Encodes the descriptor into the provided fixed-layout byte buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `dst` is smaller than [`Self::ENCODED_LEN`].
+    pub fn encode_into(&self, dst: &mut [u8]) -> Result<(), FusionKnWireError> {
+        if dst.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+
+        write_u32(&mut dst[0..4], self.message_bytes);
+        write_u32(&mut dst[4..8], self.sequence);
+        Ok(())
+    }
+
+    /// Decodes a descriptor from the provided byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the slice is too small.
+    pub fn decode_from(src: &[u8]) -> Result<Self, FusionKnWireError> {
+        if src.len() < Self::ENCODED_LEN {
+            return Err(FusionKnWireError::BufferTooSmall);
+        }
+
+        Ok(Self {
+            message_bytes: read_u32(&src[0..4]),
+            sequence: read_u32(&src[4..8]),
+        })
+    }
+}
@@ -12,7 +12,11 @@ pub const FUSION_KN_PROTOCOL_MAGIC: [u8; 4] = *b"FKN1";
 /// Current protocol major version.
 pub const FUSION_KN_PROTOCOL_VERSION_MAJOR: u16 = 1;
 /// Current protocol minor version.
-pub const FUSION_KN_PROTOCOL_VERSION_MINOR: u16 = 0;
+pub const FUSION_KN_PROTOCOL_VERSION_MINOR: u16 = 1;
+/// Oldest protocol minor version this build still speaks.
+///
+/// Minor versions only add commands; a 1.0 session is limited to `Negotiate`.
+pub const FUSION_KN_PROTOCOL_VERSION_MINOR_FLOOR: u16 = 0;
 
 /// Transport mechanism used to carry the Fusion kernel protocol.
 #[repr(u16)]
@@ -47,6 +51,16 @@ impl FusionKnTransportKind {
 pub enum FusionKnCommand {
     /// Capability and version negotiation.
     Negotiate = 1,
+    /// Reports the current grant state of one claim held by one courier authority.
+    QueryClaim = 2,
+    /// Authorizes one claim use through one courier authority.
+    RequestClaim = 3,
+    /// Registers interest in one set of authority and courier events.
+    SubscribeEvents = 4,
+    /// Retrieves the next queued event for one subscription.
+    PollEvent = 5,
+    /// Retrieves the runtime summary of one courier.
+    CourierSummary = 6,
 }
 
 impl FusionKnCommand {
@@ -54,9 +68,38 @@ impl FusionKnCommand {
     const fn from_u16(raw: u16) -> Option<Self> {
         match raw {
             1 => Some(Self::Negotiate),
+            2 => Some(Self::QueryClaim),
+            3 => Some(Self::RequestClaim),
+            4 => Some(Self::SubscribeEvents),
+            5 => Some(Self::PollEvent),
+            6 => Some(Self::CourierSummary),
             _ => None,
         }
     }
+
+    /// Returns the capability a session must have negotiated before issuing this command.
+    #[must_use]
+    pub const fn required_capability(self) -> FusionKnCapabilityFlags {
+        match self {
+            Self::Negotiate => FusionKnCapabilityFlags::NEGOTIATION,
+            Self::QueryClaim | Self::RequestClaim => FusionKnCapabilityFlags::CLAIMS,
+            Self::SubscribeEvents | Self::PollEvent => FusionKnCapabilityFlags::EVENTS,
+            Self::CourierSummary => FusionKnCapabilityFlags::COURIER_SUMMARY,
+        }
+    }
+
+    /// Returns the protocol minor version that introduced this command.
+    #[must_use]
+    pub const fn introduced_in_minor(self) -> u16 {
+        match self {
+            Self::Negotiate => 0,
+            Self::QueryClaim
+            | Self::RequestClaim
+            | Self::SubscribeEvents
+            | Self::PollEvent
+            | Self::CourierSummary => 1,
+        }
+    }
 }
 
 /// Status code reported in protocol responses.
@@ -79,6 +122,10 @@ pub enum FusionKnStatusCode {
     TransportFault = 6,
     /// Internal fault occurred while handling the request.
     InternalFault = 7,
+    /// The addressed courier, claim, or subscription does not exist.
+    NotFound = 8,
+    /// Nothing is ready yet; the request may be retried later.
+    WouldBlock = 9,
 }
 
 impl FusionKnStatusCode {
@@ -93,6 +140,8 @@ impl FusionKnStatusCode {
             5 => Some(Self::Denied),
             6 => Some(Self::TransportFault),
             7 => Some(Self::InternalFault),
+            8 => Some(Self::NotFound),
+            9 => Some(Self::WouldBlock),
             _ => None,
         }
     }
@@ -121,6 +170,12 @@ bitflags! {
         const BITFLAT_LE = 1 << 1;
         /// Request/response sequencing with request IDs is supported.
         const REQUEST_IDS = 1 << 2;
+        /// Claim query and claim request commands are supported.
+        const CLAIMS = 1 << 3;
+        /// Event subscription and polling commands are supported.
+        const EVENTS = 1 << 4;
+        /// Courier runtime summary retrieval is supported.
+        const COURIER_SUMMARY = 1 << 5;
     }
 }
 
@@ -141,6 +196,10 @@ pub enum FusionKnWireError {
     InvalidTransportKind,
     /// Header contains invalid flag combinations.
     InvalidFlags,
+    /// Shared-memory ring control block describes an unusable layout.
+    InvalidRingLayout,
+    /// Command payload carries an unknown enumeration value or out-of-range field.
+    InvalidPayload,
 }
 
 /// Common protocol header for every mediated message.
@@ -290,13 +349,16 @@ impl FusionKnNegotiationRequest {
     pub const fn current(transport: FusionKnTransportKind) -> Self {
         Self {
             min_version_major: FUSION_KN_PROTOCOL_VERSION_MAJOR,
-            min_version_minor: FUSION_KN_PROTOCOL_VERSION_MINOR,
+            min_version_minor: FUSION_KN_PROTOCOL_VERSION_MINOR_FLOOR,
             max_version_major: FUSION_KN_PROTOCOL_VERSION_MAJOR,
             max_version_minor: FUSION_KN_PROTOCOL_VERSION_MINOR,
             transport,
             requested_capabilities: FusionKnCapabilityFlags::NEGOTIATION
                 .union(FusionKnCapabilityFlags::BITFLAT_LE)
-                .union(FusionKnCapabilityFlags::REQUEST_IDS),
+                .union(FusionKnCapabilityFlags::REQUEST_IDS)
+                .union(FusionKnCapabilityFlags::CLAIMS)
+                .union(FusionKnCapabilityFlags::EVENTS)
+                .union(FusionKnCapabilityFlags::COURIER_SUMMARY),
         }
     }
 
@@ -405,13 +467,13 @@ impl FusionKnNegotiationResponse {
     }
 }
 
-const fn write_u16(dst: &mut [u8], value: u16) {
+pub(super) const fn write_u16(dst: &mut [u8], value: u16) {
     let bytes = value.to_le_bytes();
     dst[0] = bytes[0];
     dst[1] = bytes[1];
 }
 
-const fn write_u32(dst: &mut [u8], value: u32) {
+pub(super) const fn write_u32(dst: &mut [u8], value: u32) {
     let bytes = value.to_le_bytes();
     dst[0] = bytes[0];
     dst[1] = bytes[1];
@@ -419,10 +481,25 @@ const fn write_u32(dst: &mut [u8], value: u32) {
     dst[3] = bytes[3];
 }
 
-fn read_u16(src: &[u8]) -> u16 {
+pub(super) fn read_u16(src: &[u8]) -> u16 {
     u16::from_le_bytes([src[0], src[1]])
 }
 
-fn read_u32(src: &[u8]) -> u32 {
+pub(super) fn read_u32(src: &[u8]) -> u32 {
     u32::from_le_bytes([src[0], src[1], src[2], src[3]])
 }
+
+pub(super) const fn write_u64(dst: &mut [u8], value: u64) {
+    let bytes = value.to_le_bytes();
+    let mut index = 0;
+    while index < 8 {
+        dst[index] = bytes[index];
+        index += 1;
+    }
+}
+
+pub(super) fn read_u64(src: &[u8]) -> u64 {
+    u64::from_le_bytes([
+        src[0], src[1], src[2], src[3], src[4], src[5], src[6], src[7],
+    ])
+}
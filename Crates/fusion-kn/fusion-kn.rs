@@ -32,6 +32,10 @@ pub mod evidence;
 #[path = "module/module.rs"]
 /// Kernel integration model and module metadata vocabulary.
 pub mod module;
+#[cfg(feature = "server")]
+#[path = "server/server.rs"]
+/// No-alloc reference peer for the mediated Fusion kernel boundary.
+pub mod server;
 
 #[cfg(feature = "contract")]
 pub use blueprint::*;
@@ -43,6 +47,8 @@ pub use contract::*;
 pub use evidence::*;
 #[cfg(feature = "contract")]
 pub use module::*;
+#[cfg(feature = "server")]
+pub use server::*;
 
 #[cfg(all(test, feature = "contract", not(target_os = "none")))]
 mod tests {
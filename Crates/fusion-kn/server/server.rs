@@ -0,0 +1,539 @@
+//! No-alloc request dispatcher for the mediated Fusion kernel boundary.
+//!
+//! This is the peer half of the protocol the client module speaks. It exists so the wire
+//! contract has one reference implementation of the rules the kernel side must enforce:
+//! - every request is decoded from the fixed bitflat header before its payload is trusted
+//! - payloads larger than the advertised `max_payload_bytes` are refused, not truncated
+//! - negotiation selects a version inside the caller's range and only confirms capabilities
+//!   both sides actually share
+//! - every failure is answered with a framed response and a [`FusionKnStatusCode`] instead of
+//!   a dropped connection
+//!
+//! The dispatcher is transport-neutral and owns no buffers. A kernel module, a hosted test
+//! peer, or a fuzz harness feeds it one complete request and one response buffer at a time.
+//!
+//! Commands past `Negotiate` are framed and gated here, then answered by a
+//! [`FusionKnCommandHandler`] that owns the actual authority and courier state. A command is
+//! only handed to the handler once a session exists, its capability was confirmed, and the
+//! session minor version already includes it.
+
+use crate::contract::command::{
+    FusionKnClaimRequest,
+    FusionKnClaimResponse,
+    FusionKnCourierSummary,
+    FusionKnCourierSummaryRequest,
+    FusionKnEventPollRequest,
+    FusionKnEventRecord,
+    FusionKnEventSubscribeRequest,
+    FusionKnEventSubscribeResponse,
+};
+use crate::contract::wire::{
+    FUSION_KN_PROTOCOL_MAGIC,
+    FUSION_KN_PROTOCOL_VERSION_MAJOR,
+    FUSION_KN_PROTOCOL_VERSION_MINOR,
+    FUSION_KN_PROTOCOL_VERSION_MINOR_FLOOR,
+    FusionKnCapabilityFlags,
+    FusionKnCommand,
+    FusionKnMessageFlags,
+    FusionKnMessageHeader,
+    FusionKnNegotiationRequest,
+    FusionKnNegotiationResponse,
+    FusionKnStatusCode,
+    FusionKnTransportKind,
+    FusionKnWireError,
+};
+
+/// Capabilities every negotiated session must share.
+pub const FUSION_KN_REQUIRED_CAPABILITIES: FusionKnCapabilityFlags =
+    FusionKnCapabilityFlags::NEGOTIATION.union(FusionKnCapabilityFlags::BITFLAT_LE);
+
+/// Backing state for the commands that follow negotiation.
+///
+/// Every method receives an already validated request and answers either with its response
+/// payload or with the status to report. The defaults refuse with `Unsupported`, so a peer
+/// only implements the command families it actually advertises.
+pub trait FusionKnCommandHandler {
+    /// Reports the current grant state of one claim.
+    ///
+    /// # Errors
+    ///
+    /// Returns the status to report when the claim cannot be inspected.
+    fn query_claim(
+        &mut self,
+        request: &FusionKnClaimRequest,
+    ) -> Result<FusionKnClaimResponse, FusionKnStatusCode> {
+        let _ = request;
+        Err(FusionKnStatusCode::Unsupported)
+    }
+
+    /// Authorizes one claim use through the courier's authority.
+    ///
+    /// # Errors
+    ///
+    /// Returns the status to report when the claim is not granted.
+    fn request_claim(
+        &mut self,
+        request: &FusionKnClaimRequest,
+    ) -> Result<FusionKnClaimResponse, FusionKnStatusCode> {
+        let _ = request;
+        Err(FusionKnStatusCode::Unsupported)
+    }
+
+    /// Registers one event subscription.
+    ///
+    /// # Errors
+    ///
+    /// Returns the status to report when the subscription cannot be created.
+    fn subscribe_events(
+        &mut self,
+        request: &FusionKnEventSubscribeRequest,
+    ) -> Result<FusionKnEventSubscribeResponse, FusionKnStatusCode> {
+        let _ = request;
+        Err(FusionKnStatusCode::Unsupported)
+    }
+
+    /// Dequeues the next event of one subscription.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WouldBlock` when the queue is empty, or another status when the subscription
+    /// cannot be polled.
+    fn poll_event(
+        &mut self,
+        request: &FusionKnEventPollRequest,
+    ) -> Result<FusionKnEventRecord, FusionKnStatusCode> {
+        let _ = request;
+        Err(FusionKnStatusCode::Unsupported)
+    }
+
+    /// Reports the runtime summary of one courier.
+    ///
+    /// # Errors
+    ///
+    /// Returns the status to report when the courier cannot be summarized.
+    fn courier_summary(
+        &mut self,
+        request: &FusionKnCourierSummaryRequest,
+    ) -> Result<FusionKnCourierSummary, FusionKnStatusCode> {
+        let _ = request;
+        Err(FusionKnStatusCode::Unsupported)
+    }
+}
+
+/// Handler for peers that only speak negotiation.
+impl FusionKnCommandHandler for () {}
+
+/// Static policy the peer enforces for every exchange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnPeerConfig {
+    /// Transport this peer is reachable over.
+    pub transport: FusionKnTransportKind,
+    /// Capabilities the peer is willing to confirm.
+    pub capabilities: FusionKnCapabilityFlags,
+    /// Largest request or response payload the peer accepts.
+    pub max_payload_bytes: u32,
+}
+
+impl FusionKnPeerConfig {
+    /// Default payload ceiling for the reference peer.
+    pub const DEFAULT_MAX_PAYLOAD_BYTES: u32 = 4096;
+
+    /// Builds the reference peer policy for one transport.
+    #[must_use]
+    pub const fn reference(transport: FusionKnTransportKind) -> Self {
+        Self {
+            transport,
+            capabilities: FUSION_KN_REQUIRED_CAPABILITIES
+                .union(FusionKnCapabilityFlags::REQUEST_IDS),
+            max_payload_bytes: Self::DEFAULT_MAX_PAYLOAD_BYTES,
+        }
+    }
+}
+
+/// Session parameters the peer confirmed during negotiation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnPeerSession {
+    /// Protocol major version selected for the session.
+    pub version_major: u16,
+    /// Protocol minor version selected for the session.
+    pub version_minor: u16,
+    /// Capability set confirmed for the session.
+    pub capabilities: FusionKnCapabilityFlags,
+}
+
+/// Stateful no-alloc peer for the mediated Fusion kernel protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FusionKnPeer {
+    config: FusionKnPeerConfig,
+    session: Option<FusionKnPeerSession>,
+}
+
+impl FusionKnPeer {
+    /// Creates a peer enforcing the provided policy.
+    #[must_use]
+    pub const fn new(config: FusionKnPeerConfig) -> Self {
+        Self {
+            config,
+            session: None,
+        }
+    }
+
+    /// Returns the policy this peer enforces.
+    #[must_use]
+    pub const fn config(&self) -> FusionKnPeerConfig {
+        self.config
+    }
+
+    /// Returns the negotiated session, if negotiation has completed.
+    #[must_use]
+    pub const fn session(&self) -> Option<FusionKnPeerSession> {
+        self.session
+    }
+
+    /// Handles one complete request and writes one complete response.
+    ///
+    /// Only `Negotiate` is served; every later command is refused with `Unsupported`. Use
+    /// [`Self::dispatch_with`] to back the remaining commands.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only when `response` is too small to hold the framed answer.
+    pub fn dispatch(
+        &mut self,
+        request: &[u8],
+        response: &mut [u8],
+    ) -> Result<usize, FusionKnWireError> {
+        self.dispatch_with(&mut (), request, response)
+    }
+
+    /// Handles one complete request through `handler` and writes one complete response.
+    ///
+    /// Protocol failures are answered in-band with a non-`Ok` status and an empty payload.
+    /// Requests whose header cannot be decoded are answered as `Negotiate` with whatever
+    /// request ID the raw bytes carry, so the caller can still correlate the refusal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only when `response` is too small to hold the framed answer.
+    #[allow(clippy::too_many_lines)]
+    pub fn dispatch_with<H>(
+        &mut self,
+        handler: &mut H,
+        request: &[u8],
+        response: &mut [u8],
+    ) -> Result<usize, FusionKnWireError>
+    where
+        H: FusionKnCommandHandler + ?Sized,
+    {
+        let Ok(header) = FusionKnMessageHeader::decode_from(request) else {
+            let request_id = request.get(20..24).map_or(0, |raw| {
+                u32::from_le_bytes([raw[0], raw[1], raw[2], raw[3]])
+            });
+            return self.refuse(
+                FusionKnCommand::Negotiate,
+                request_id,
+                FusionKnStatusCode::InvalidHeader,
+                response,
+            );
+        };
+
+        if let Err(status) = self.check_request_header(&header, request.len()) {
+            return self.refuse(header.command, header.request_id, status, response);
+        }
+        let payload = &request[FusionKnMessageHeader::ENCODED_LEN..];
+
+        match header.command {
+            FusionKnCommand::Negotiate => {
+                let outcome = self.negotiate(payload);
+                self.answer(
+                    &header,
+                    outcome,
+                    FusionKnNegotiationResponse::ENCODED_LEN,
+                    FusionKnNegotiationResponse::encode_into,
+                    response,
+                )
+            }
+            FusionKnCommand::QueryClaim => {
+                let outcome = self
+                    .decode_command(
+                        header.command,
+                        payload,
+                        FusionKnClaimRequest::ENCODED_LEN,
+                        FusionKnClaimRequest::decode_from,
+                    )
+                    .and_then(|request| handler.query_claim(&request));
+                self.answer(
+                    &header,
+                    outcome,
+                    FusionKnClaimResponse::ENCODED_LEN,
+                    FusionKnClaimResponse::encode_into,
+                    response,
+                )
+            }
+            FusionKnCommand::RequestClaim => {
+                let outcome = self
+                    .decode_command(
+                        header.command,
+                        payload,
+                        FusionKnClaimRequest::ENCODED_LEN,
+                        FusionKnClaimRequest::decode_from,
+                    )
+                    .and_then(|request| handler.request_claim(&request));
+                self.answer(
+                    &header,
+                    outcome,
+                    FusionKnClaimResponse::ENCODED_LEN,
+                    FusionKnClaimResponse::encode_into,
+                    response,
+                )
+            }
+            FusionKnCommand::SubscribeEvents => {
+                let outcome = self
+                    .decode_command(
+                        header.command,
+                        payload,
+                        FusionKnEventSubscribeRequest::ENCODED_LEN,
+                        FusionKnEventSubscribeRequest::decode_from,
+                    )
+                    .and_then(|request| handler.subscribe_events(&request));
+                self.answer(
+                    &header,
+                    outcome,
+                    FusionKnEventSubscribeResponse::ENCODED_LEN,
+                    FusionKnEventSubscribeResponse::encode_into,
+                    response,
+                )
+            }
+            FusionKnCommand::PollEvent => {
+                let outcome = self
+                    .decode_command(
+                        header.command,
+                        payload,
+                        FusionKnEventPollRequest::ENCODED_LEN,
+                        FusionKnEventPollRequest::decode_from,
+                    )
+                    .and_then(|request| handler.poll_event(&request));
+                self.answer(
+                    &header,
+                    outcome,
+                    FusionKnEventRecord::ENCODED_LEN,
+                    FusionKnEventRecord::encode_into,
+                    response,
+                )
+            }
+            FusionKnCommand::CourierSummary => {
+                let outcome = self
+                    .decode_command(
+                        header.command,
+                        payload,
+                        FusionKnCourierSummaryRequest::ENCODED_LEN,
+                        FusionKnCourierSummaryRequest::decode_from,
+                    )
+                    .and_then(|request| handler.courier_summary(&request));
+                self.answer(
+                    &header,
+                    outcome,
+                    FusionKnCourierSummary::ENCODED_LEN,
+                    FusionKnCourierSummary::encode_into,
+                    response,
+                )
+            }
+        }
+    }
+
+    fn check_request_header(
+        &self,
+        header: &FusionKnMessageHeader,
+        request_len: usize,
+    ) -> Result<(), FusionKnStatusCode> {
+        if !header.flags.contains(FusionKnMessageFlags::REQUEST)
+            || header.status != FusionKnStatusCode::Ok
+        {
+            return Err(FusionKnStatusCode::InvalidHeader);
+        }
+        if header.version_major != FUSION_KN_PROTOCOL_VERSION_MAJOR {
+            return Err(FusionKnStatusCode::IncompatibleVersion);
+        }
+        if header.transport != self.config.transport {
+            return Err(FusionKnStatusCode::Unsupported);
+        }
+        if header.payload_bytes > self.config.max_payload_bytes {
+            return Err(FusionKnStatusCode::BufferTooSmall);
+        }
+        let payload_len = usize::try_from(header.payload_bytes)
+            .map_err(|_| FusionKnStatusCode::BufferTooSmall)?;
+        if request_len != FusionKnMessageHeader::ENCODED_LEN + payload_len {
+            return Err(FusionKnStatusCode::InvalidHeader);
+        }
+        Ok(())
+    }
+
+    fn negotiate(
+        &mut self,
+        payload: &[u8],
+    ) -> Result<FusionKnNegotiationResponse, FusionKnStatusCode> {
+        if payload.len() != FusionKnNegotiationRequest::ENCODED_LEN {
+            return Err(FusionKnStatusCode::InvalidHeader);
+        }
+        let request = FusionKnNegotiationRequest::decode_from(payload)
+            .map_err(|_| FusionKnStatusCode::InvalidHeader)?;
+
+        // Pick the newest version both sides speak: ours, unless the caller caps it lower.
+        let current = (
+            FUSION_KN_PROTOCOL_VERSION_MAJOR,
+            FUSION_KN_PROTOCOL_VERSION_MINOR,
+        );
+        let oldest = (
+            FUSION_KN_PROTOCOL_VERSION_MAJOR,
+            FUSION_KN_PROTOCOL_VERSION_MINOR_FLOOR,
+        );
+        let floor = (request.min_version_major, request.min_version_minor);
+        let ceiling = (request.max_version_major, request.max_version_minor);
+        let selected = if ceiling < current { ceiling } else { current };
+        if selected < floor || selected < oldest {
+            return Err(FusionKnStatusCode::IncompatibleVersion);
+        }
+        if request.transport != self.config.transport {
+            return Err(FusionKnStatusCode::Unsupported);
+        }
+        let capabilities = request
+            .requested_capabilities
+            .intersection(self.config.capabilities);
+        if !capabilities.contains(FUSION_KN_REQUIRED_CAPABILITIES) {
+            return Err(FusionKnStatusCode::Unsupported);
+        }
+
+        self.session = Some(FusionKnPeerSession {
+            version_major: selected.0,
+            version_minor: selected.1,
+            capabilities,
+        });
+        Ok(FusionKnNegotiationResponse {
+            selected_version_major: selected.0,
+            selected_version_minor: selected.1,
+            transport: self.config.transport,
+            capabilities,
+            max_payload_bytes: self.config.max_payload_bytes,
+        })
+    }
+
+    fn decode_command<Q>(
+        &self,
+        command: FusionKnCommand,
+        payload: &[u8],
+        payload_len: usize,
+        decode: fn(&[u8]) -> Result<Q, FusionKnWireError>,
+    ) -> Result<Q, FusionKnStatusCode> {
+        let Some(session) = self.session else {
+            return Err(FusionKnStatusCode::Denied);
+        };
+        if !session.capabilities.contains(command.required_capability())
+            || session.version_minor < command.introduced_in_minor()
+        {
+            return Err(FusionKnStatusCode::Unsupported);
+        }
+        if payload.len() != payload_len {
+            return Err(FusionKnStatusCode::InvalidHeader);
+        }
+        decode(payload).map_err(|_| FusionKnStatusCode::InvalidHeader)
+    }
+
+    fn answer<R>(
+        &self,
+        header: &FusionKnMessageHeader,
+        outcome: Result<R, FusionKnStatusCode>,
+        payload_len: usize,
+        encode: fn(&R, &mut [u8]) -> Result<(), FusionKnWireError>,
+        response: &mut [u8],
+    ) -> Result<usize, FusionKnWireError> {
+        let value = match outcome {
+            Ok(value) => value,
+            Err(status) => return self.refuse(header.command, header.request_id, status, response),
+        };
+        let payload_bytes =
+            u32::try_from(payload_len).map_err(|_| FusionKnWireError::BufferTooSmall)?;
+        let (header_bytes, payload) = split_response(response, payload_len)?;
+        encode(&value, payload)?;
+        self.response_header(
+            header.command,
+            header.request_id,
+            FusionKnStatusCode::Ok,
+            payload_bytes,
+        )
+        .encode_into(header_bytes)?;
+        Ok(FusionKnMessageHeader::ENCODED_LEN + payload_len)
+    }
+
+    fn refuse(
+        &self,
+        command: FusionKnCommand,
+        request_id: u32,
+        status: FusionKnStatusCode,
+        response: &mut [u8],
+    ) -> Result<usize, FusionKnWireError> {
+        let (header_bytes, _) = split_response(response, 0)?;
+        self.response_header(command, request_id, status, 0)
+            .encode_into(header_bytes)?;
+        Ok(FusionKnMessageHeader::ENCODED_LEN)
+    }
+
+    /// Frames one response at the session's negotiated version, or ours before negotiation.
+    const fn response_header(
+        &self,
+        command: FusionKnCommand,
+        request_id: u32,
+        status: FusionKnStatusCode,
+        payload_bytes: u32,
+    ) -> FusionKnMessageHeader {
+        let (version_major, version_minor) = match self.session {
+            Some(session) => (session.version_major, session.version_minor),
+            None => (
+                FUSION_KN_PROTOCOL_VERSION_MAJOR,
+                FUSION_KN_PROTOCOL_VERSION_MINOR,
+            ),
+        };
+        FusionKnMessageHeader {
+            version_major,
+            version_minor,
+            transport: self.config.transport,
+            command,
+            flags: FusionKnMessageFlags::RESPONSE.union(FusionKnMessageFlags::BITFLAT_LE),
+            status,
+            request_id,
+            payload_bytes,
+        }
+    }
+}
+
+/// Returns the payload length one raw request header declares.
+///
+/// Stream transports need this before they can read the rest of a request, and they need it
+/// even when the header is otherwise malformed so the stream can be drained rather than
+/// desynchronized.
+///
+/// # Errors
+///
+/// Returns an error when the slice is shorter than one header or does not start with the
+/// protocol magic.
+pub fn declared_payload_bytes(header: &[u8]) -> Result<u32, FusionKnWireError> {
+    if header.len() < FusionKnMessageHeader::ENCODED_LEN {
+        return Err(FusionKnWireError::BufferTooSmall);
+    }
+    if header[..4] != FUSION_KN_PROTOCOL_MAGIC {
+        return Err(FusionKnWireError::InvalidMagic);
+    }
+    Ok(u32::from_le_bytes([
+        header[24], header[25], header[26], header[27],
+    ]))
+}
+
+fn split_response(
+    response: &mut [u8],
+    payload_len: usize,
+) -> Result<(&mut [u8], &mut [u8]), FusionKnWireError> {
+    let total = FusionKnMessageHeader::ENCODED_LEN + payload_len;
+    let framed = response
+        .get_mut(..total)
+        .ok_or(FusionKnWireError::BufferTooSmall)?;
+    Ok(framed.split_at_mut(FusionKnMessageHeader::ENCODED_LEN))
+}
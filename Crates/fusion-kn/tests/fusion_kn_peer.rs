@@ -0,0 +1,158 @@
+#![cfg(all(unix, feature = "client", feature = "server"))]
+
+use std::io::{
+    self,
+    Read,
+    Write,
+};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+use std::process::{
+    Child,
+    Command,
+    Stdio,
+};
+use std::thread;
+use std::time::Duration;
+
+use fusion_kn::client::{
+    FusionKnClient,
+    FusionKnClientError,
+    FusionKnTransport,
+};
+use fusion_kn::contract::wire::{
+    FUSION_KN_PROTOCOL_VERSION_MAJOR,
+    FusionKnCapabilityFlags,
+    FusionKnCommand,
+    FusionKnMessageHeader,
+    FusionKnStatusCode,
+    FusionKnTransportKind,
+};
+use fusion_kn::server::{
+    FUSION_KN_REQUIRED_CAPABILITIES,
+    FusionKnPeerConfig,
+};
+
+struct PeerProcess {
+    child: Child,
+    path: PathBuf,
+}
+
+impl PeerProcess {
+    fn spawn(name: &str, extra: &[&str]) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("fusion_kn_peer-{}-{name}.sock", std::process::id()));
+        let child = Command::new(env!("CARGO_BIN_EXE_fusion_kn_peer"))
+            .arg(&path)
+            .args(extra)
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("peer binary should start");
+        Self { child, path }
+    }
+
+    fn connect(&self) -> UnixStream {
+        for _ in 0..200 {
+            if let Ok(stream) = UnixStream::connect(&self.path) {
+                return stream;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        panic!("peer never started listening on {}", self.path.display());
+    }
+}
+
+impl Drop for PeerProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+struct StreamTransport(UnixStream);
+
+impl FusionKnTransport for StreamTransport {
+    type Error = io::Error;
+
+    fn transport_kind(&self) -> FusionKnTransportKind {
+        FusionKnTransportKind::CharacterDevice
+    }
+
+    fn transact(&mut self, request: &[u8], response: &mut [u8]) -> Result<usize, Self::Error> {
+        self.0.write_all(request)?;
+        let (header, payload) = response.split_at_mut(FusionKnMessageHeader::ENCODED_LEN);
+        self.0.read_exact(header)?;
+        let decoded = FusionKnMessageHeader::decode_from(header)
+            .map_err(|error| io::Error::other(format!("{error:?}")))?;
+        let payload_len = decoded.payload_bytes as usize;
+        self.0.read_exact(&mut payload[..payload_len])?;
+        Ok(FusionKnMessageHeader::ENCODED_LEN + payload_len)
+    }
+}
+
+#[test]
+fn client_negotiates_with_hosted_peer() {
+    let peer = PeerProcess::spawn("negotiate", &[]);
+    let mut client = FusionKnClient::new(StreamTransport(peer.connect()));
+    let session = client.negotiate().expect("negotiation should succeed");
+
+    assert_eq!(session.version_major, FUSION_KN_PROTOCOL_VERSION_MAJOR);
+    assert_eq!(session.transport, FusionKnTransportKind::CharacterDevice);
+    assert!(
+        session
+            .capabilities
+            .contains(FUSION_KN_REQUIRED_CAPABILITIES)
+    );
+    assert!(
+        session
+            .capabilities
+            .contains(FusionKnCapabilityFlags::REQUEST_IDS)
+    );
+    assert_eq!(
+        session.max_payload_bytes,
+        FusionKnPeerConfig::DEFAULT_MAX_PAYLOAD_BYTES
+    );
+}
+
+#[test]
+fn hosted_peer_refuses_payloads_over_its_limit() {
+    let peer = PeerProcess::spawn("limit", &["--max-payload", "8"]);
+    let mut client = FusionKnClient::new(StreamTransport(peer.connect()));
+    assert_eq!(
+        client.negotiate().map_err(|error| match error {
+            FusionKnClientError::Status(status) => Some(status),
+            _ => None,
+        }),
+        Err(Some(FusionKnStatusCode::BufferTooSmall))
+    );
+}
+
+#[test]
+fn hosted_peer_stays_framed_after_oversized_request() {
+    let peer = PeerProcess::spawn("framing", &[]);
+    let mut stream = peer.connect();
+
+    let oversized = FusionKnPeerConfig::DEFAULT_MAX_PAYLOAD_BYTES + 64;
+    let mut request = vec![0_u8; FusionKnMessageHeader::ENCODED_LEN + oversized as usize];
+    FusionKnMessageHeader::request(
+        FusionKnCommand::Negotiate,
+        FusionKnTransportKind::CharacterDevice,
+        99,
+        oversized,
+    )
+    .encode_into(&mut request)
+    .expect("header should encode");
+    stream.write_all(&request).expect("request should send");
+
+    let mut header = [0_u8; FusionKnMessageHeader::ENCODED_LEN];
+    stream
+        .read_exact(&mut header)
+        .expect("refusal should arrive");
+    let refusal = FusionKnMessageHeader::decode_from(&header).expect("refusal should decode");
+    assert_eq!(refusal.status, FusionKnStatusCode::BufferTooSmall);
+    assert_eq!(refusal.request_id, 99);
+
+    let mut client = FusionKnClient::new(StreamTransport(stream));
+    assert!(client.negotiate().is_ok());
+}
@@ -0,0 +1,397 @@
+#![cfg(feature = "server")]
+
+use fusion_kn::contract::command::{
+    FusionKnClaimId,
+    FusionKnClaimLifetime,
+    FusionKnClaimRequest,
+    FusionKnClaimResponse,
+    FusionKnClaimSource,
+    FusionKnClaimState,
+    FusionKnCourierResponsiveness,
+    FusionKnCourierRunState,
+    FusionKnCourierSummary,
+    FusionKnCourierSummaryRequest,
+    FusionKnEventKind,
+    FusionKnEventMask,
+    FusionKnEventRecord,
+    FusionKnEventSubscribeRequest,
+    FusionKnLaneSummary,
+    FusionKnSchedulingPolicy,
+};
+use fusion_kn::contract::wire::{
+    FUSION_KN_PROTOCOL_VERSION_MAJOR,
+    FUSION_KN_PROTOCOL_VERSION_MINOR,
+    FusionKnCapabilityFlags,
+    FusionKnCommand,
+    FusionKnMessageFlags,
+    FusionKnMessageHeader,
+    FusionKnNegotiationRequest,
+    FusionKnNegotiationResponse,
+    FusionKnStatusCode,
+    FusionKnTransportKind,
+    FusionKnWireError,
+};
+use fusion_kn::server::{
+    FUSION_KN_REQUIRED_CAPABILITIES,
+    FusionKnCommandHandler,
+    FusionKnPeer,
+    FusionKnPeerConfig,
+};
+
+#[test]
+fn claim_request_matches_golden_bytes() {
+    let request = FusionKnClaimRequest {
+        courier: 0x0102_0304_0506_0708,
+        claim: FusionKnClaimId::new("app@svc[local]=>net.tx").expect("claim ID should fit"),
+    };
+    let mut encoded = [0xFF_u8; FusionKnClaimRequest::ENCODED_LEN];
+    request
+        .encode_into(&mut encoded)
+        .expect("request should encode");
+
+    let mut golden = [0_u8; FusionKnClaimRequest::ENCODED_LEN];
+    golden[..16].copy_from_slice(&[
+        0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 22, 0, 0, 0, 0, 0, 0, 0,
+    ]);
+    golden[16..38].copy_from_slice(b"app@svc[local]=>net.tx");
+    assert_eq!(encoded, golden);
+    assert_eq!(FusionKnClaimRequest::decode_from(&golden), Ok(request));
+    assert_eq!(request.claim.as_str(), "app@svc[local]=>net.tx");
+}
+
+#[test]
+fn claim_response_matches_golden_bytes() {
+    let response = FusionKnClaimResponse {
+        state: FusionKnClaimState::Granted,
+        source: FusionKnClaimSource::AuthorityIntrinsic,
+        lifetime: FusionKnClaimLifetime::ExpiresAt,
+        claim_context: 7,
+        issued_at_unix_seconds: 0x1000,
+        expires_at_unix_seconds: Some(0x2000),
+    };
+    let golden: [u8; FusionKnClaimResponse::ENCODED_LEN] = [
+        2, 0, 3, 0, 3, 0, 1, 0, //
+        7, 0, 0, 0, 0, 0, 0, 0, //
+        0x00, 0x10, 0, 0, 0, 0, 0, 0, //
+        0x00, 0x20, 0, 0, 0, 0, 0, 0,
+    ];
+    let mut encoded = [0_u8; FusionKnClaimResponse::ENCODED_LEN];
+    response
+        .encode_into(&mut encoded)
+        .expect("response should encode");
+    assert_eq!(encoded, golden);
+    assert_eq!(FusionKnClaimResponse::decode_from(&golden), Ok(response));
+
+    let mut unknown_state = golden;
+    unknown_state[0] = 9;
+    assert_eq!(
+        FusionKnClaimResponse::decode_from(&unknown_state),
+        Err(FusionKnWireError::InvalidPayload)
+    );
+}
+
+#[test]
+fn event_payloads_match_golden_bytes() {
+    let subscribe = FusionKnEventSubscribeRequest {
+        courier: Some(3),
+        events: FusionKnEventMask::CLAIM_REVOKED | FusionKnEventMask::COURIER_RUN_STATE,
+    };
+    let golden_subscribe: [u8; FusionKnEventSubscribeRequest::ENCODED_LEN] =
+        [3, 0, 0, 0, 0, 0, 0, 0, 0x0A, 0, 0, 0, 1, 0, 0, 0];
+    let mut encoded = [0_u8; FusionKnEventSubscribeRequest::ENCODED_LEN];
+    subscribe
+        .encode_into(&mut encoded)
+        .expect("subscription should encode");
+    assert_eq!(encoded, golden_subscribe);
+    assert_eq!(
+        FusionKnEventSubscribeRequest::decode_from(&golden_subscribe),
+        Ok(subscribe)
+    );
+
+    let record = FusionKnEventRecord {
+        sequence: 42,
+        courier: 3,
+        kind: FusionKnEventKind::CourierRunStateChanged,
+        pending: 2,
+        detail: FusionKnCourierRunState::Running as u32,
+        value: 0xAB,
+    };
+    let golden_record: [u8; FusionKnEventRecord::ENCODED_LEN] = [
+        42, 0, 0, 0, 0, 0, 0, 0, //
+        3, 0, 0, 0, 0, 0, 0, 0, //
+        4, 0, 2, 0, 3, 0, 0, 0, //
+        0xAB, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    let mut encoded = [0_u8; FusionKnEventRecord::ENCODED_LEN];
+    record
+        .encode_into(&mut encoded)
+        .expect("record should encode");
+    assert_eq!(encoded, golden_record);
+    assert_eq!(FusionKnEventRecord::decode_from(&golden_record), Ok(record));
+    assert_eq!(record.kind.mask(), FusionKnEventMask::COURIER_RUN_STATE);
+}
+
+#[test]
+fn courier_summary_matches_golden_bytes() {
+    let summary = FusionKnCourierSummary {
+        courier: 5,
+        policy: FusionKnSchedulingPolicy::TimeSliced { quantum_ticks: 16 },
+        run_state: FusionKnCourierRunState::Runnable,
+        responsiveness: FusionKnCourierResponsiveness::Responsive,
+        fiber_lane: Some(FusionKnLaneSummary {
+            active_units: 1,
+            runnable_units: 2,
+            running_units: 3,
+            blocked_units: 4,
+            available_slots: 5,
+        }),
+        async_lane: None,
+        control_lane: None,
+    };
+    let mut golden = [0_u8; FusionKnCourierSummary::ENCODED_LEN];
+    golden[..24].copy_from_slice(&[
+        5, 0, 0, 0, 0, 0, 0, 0, 4, 0, 2, 0, 1, 0, 1, 0, 16, 0, 0, 0, 0, 0, 0, 0,
+    ]);
+    golden[24..44].copy_from_slice(&[1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0, 5, 0, 0, 0]);
+    let mut encoded = [0xFF_u8; FusionKnCourierSummary::ENCODED_LEN];
+    summary
+        .encode_into(&mut encoded)
+        .expect("summary should encode");
+    assert_eq!(encoded, golden);
+    assert_eq!(FusionKnCourierSummary::decode_from(&golden), Ok(summary));
+}
+
+const REQUEST_LEN: usize =
+    FusionKnMessageHeader::ENCODED_LEN + FusionKnNegotiationRequest::ENCODED_LEN;
+const RESPONSE_LEN: usize =
+    FusionKnMessageHeader::ENCODED_LEN + FusionKnNegotiationResponse::ENCODED_LEN;
+
+fn encode_negotiation(request_id: u32, payload: FusionKnNegotiationRequest) -> [u8; REQUEST_LEN] {
+    let mut request = [0_u8; REQUEST_LEN];
+    FusionKnMessageHeader::request(
+        FusionKnCommand::Negotiate,
+        payload.transport,
+        request_id,
+        FusionKnNegotiationRequest::ENCODED_LEN_U32,
+    )
+    .encode_into(&mut request)
+    .expect("header should encode");
+    payload
+        .encode_into(&mut request[FusionKnMessageHeader::ENCODED_LEN..])
+        .expect("payload should encode");
+    request
+}
+
+fn dispatch(
+    peer: &mut FusionKnPeer,
+    request: &[u8],
+) -> (FusionKnMessageHeader, [u8; RESPONSE_LEN]) {
+    let mut response = [0_u8; RESPONSE_LEN];
+    let written = peer
+        .dispatch(request, &mut response)
+        .expect("response should fit");
+    let header = FusionKnMessageHeader::decode_from(&response[..written])
+        .expect("response header should decode");
+    (header, response)
+}
+
+#[test]
+fn peer_confirms_shared_capabilities_only() {
+    let mut peer = FusionKnPeer::new(FusionKnPeerConfig {
+        capabilities: FUSION_KN_REQUIRED_CAPABILITIES,
+        ..FusionKnPeerConfig::reference(FusionKnTransportKind::CharacterDevice)
+    });
+    let request = encode_negotiation(
+        7,
+        FusionKnNegotiationRequest::current(FusionKnTransportKind::CharacterDevice),
+    );
+    let (header, response) = dispatch(&mut peer, &request);
+    assert_eq!(header.status, FusionKnStatusCode::Ok);
+    assert_eq!(header.request_id, 7);
+    let negotiation =
+        FusionKnNegotiationResponse::decode_from(&response[FusionKnMessageHeader::ENCODED_LEN..])
+            .expect("negotiation should decode");
+    assert_eq!(negotiation.capabilities, FUSION_KN_REQUIRED_CAPABILITIES);
+    assert_eq!(
+        negotiation.max_payload_bytes,
+        FusionKnPeerConfig::DEFAULT_MAX_PAYLOAD_BYTES
+    );
+    assert!(peer.session().is_some());
+}
+
+#[test]
+fn peer_refuses_oversized_and_out_of_range_requests() {
+    let mut peer = FusionKnPeer::new(FusionKnPeerConfig {
+        max_payload_bytes: 8,
+        ..FusionKnPeerConfig::reference(FusionKnTransportKind::CharacterDevice)
+    });
+    let request = encode_negotiation(
+        1,
+        FusionKnNegotiationRequest::current(FusionKnTransportKind::CharacterDevice),
+    );
+    let (header, _) = dispatch(&mut peer, &request);
+    assert_eq!(header.status, FusionKnStatusCode::BufferTooSmall);
+    assert_eq!(header.payload_bytes, 0);
+
+    let mut peer = FusionKnPeer::new(FusionKnPeerConfig::reference(
+        FusionKnTransportKind::CharacterDevice,
+    ));
+    let request = encode_negotiation(
+        2,
+        FusionKnNegotiationRequest {
+            min_version_major: 2,
+            max_version_major: 2,
+            ..FusionKnNegotiationRequest::current(FusionKnTransportKind::CharacterDevice)
+        },
+    );
+    let (header, _) = dispatch(&mut peer, &request);
+    assert_eq!(header.status, FusionKnStatusCode::IncompatibleVersion);
+    assert!(peer.session().is_none());
+}
+
+#[test]
+fn peer_answers_garbage_with_invalid_header() {
+    let mut peer = FusionKnPeer::new(FusionKnPeerConfig::reference(
+        FusionKnTransportKind::CharacterDevice,
+    ));
+    let (header, _) = dispatch(&mut peer, b"not a fusion kernel request");
+    assert_eq!(header.status, FusionKnStatusCode::InvalidHeader);
+    assert!(header.flags.contains(FusionKnMessageFlags::RESPONSE));
+}
+
+const SUMMARY_REQUEST_LEN: usize =
+    FusionKnMessageHeader::ENCODED_LEN + FusionKnCourierSummaryRequest::ENCODED_LEN;
+
+struct SummaryHandler;
+
+impl FusionKnCommandHandler for SummaryHandler {
+    fn courier_summary(
+        &mut self,
+        request: &FusionKnCourierSummaryRequest,
+    ) -> Result<FusionKnCourierSummary, FusionKnStatusCode> {
+        if request.courier != 9 {
+            return Err(FusionKnStatusCode::NotFound);
+        }
+        Ok(FusionKnCourierSummary {
+            courier: request.courier,
+            policy: FusionKnSchedulingPolicy::CooperativeRoundRobin,
+            run_state: FusionKnCourierRunState::Idle,
+            responsiveness: FusionKnCourierResponsiveness::Responsive,
+            fiber_lane: None,
+            async_lane: None,
+            control_lane: None,
+        })
+    }
+}
+
+fn summary_request(request_id: u32, courier: u64) -> [u8; SUMMARY_REQUEST_LEN] {
+    let mut request = [0_u8; SUMMARY_REQUEST_LEN];
+    FusionKnMessageHeader::request(
+        FusionKnCommand::CourierSummary,
+        FusionKnTransportKind::CharacterDevice,
+        request_id,
+        FusionKnCourierSummaryRequest::ENCODED_LEN_U32,
+    )
+    .encode_into(&mut request)
+    .expect("header should encode");
+    FusionKnCourierSummaryRequest { courier }
+        .encode_into(&mut request[FusionKnMessageHeader::ENCODED_LEN..])
+        .expect("payload should encode");
+    request
+}
+
+#[test]
+fn peer_gates_commands_on_negotiated_session() {
+    let config = FusionKnPeerConfig {
+        capabilities: FusionKnPeerConfig::reference(FusionKnTransportKind::CharacterDevice)
+            .capabilities
+            .union(FusionKnCapabilityFlags::COURIER_SUMMARY),
+        ..FusionKnPeerConfig::reference(FusionKnTransportKind::CharacterDevice)
+    };
+    let mut handler = SummaryHandler;
+    let mut response =
+        [0_u8; FusionKnMessageHeader::ENCODED_LEN + FusionKnCourierSummary::ENCODED_LEN];
+    let status = |peer: &mut FusionKnPeer, handler: &mut SummaryHandler, request: &[u8]| {
+        let mut response = [0_u8; RESPONSE_LEN];
+        peer.dispatch_with(handler, request, &mut response)
+            .expect("refusal should fit");
+        FusionKnMessageHeader::decode_from(&response)
+            .expect("response header should decode")
+            .status
+    };
+
+    let mut peer = FusionKnPeer::new(config);
+    assert_eq!(
+        status(&mut peer, &mut handler, &summary_request(1, 9)),
+        FusionKnStatusCode::Denied
+    );
+
+    let negotiation = FusionKnNegotiationRequest::current(FusionKnTransportKind::CharacterDevice);
+    let (header, _) = dispatch(&mut peer, &encode_negotiation(2, negotiation));
+    assert_eq!(header.status, FusionKnStatusCode::Ok);
+    let written = peer
+        .dispatch_with(&mut handler, &summary_request(3, 9), &mut response)
+        .expect("summary should fit");
+    assert_eq!(written, response.len());
+    let summary =
+        FusionKnCourierSummary::decode_from(&response[FusionKnMessageHeader::ENCODED_LEN..])
+            .expect("summary should decode");
+    assert_eq!(summary.courier, 9);
+    assert_eq!(
+        status(&mut peer, &mut handler, &summary_request(4, 10)),
+        FusionKnStatusCode::NotFound
+    );
+
+    // A 1.0 session predates every command past Negotiate.
+    let mut peer = FusionKnPeer::new(config);
+    let (header, _) = dispatch(
+        &mut peer,
+        &encode_negotiation(
+            5,
+            FusionKnNegotiationRequest {
+                max_version_minor: 0,
+                ..negotiation
+            },
+        ),
+    );
+    assert_eq!(header.status, FusionKnStatusCode::Ok);
+    assert_eq!(peer.session().map(|session| session.version_minor), Some(0));
+    assert_eq!(
+        status(&mut peer, &mut handler, &summary_request(6, 9)),
+        FusionKnStatusCode::Unsupported
+    );
+
+    // Without the capability the command never reaches the handler.
+    let mut peer = FusionKnPeer::new(FusionKnPeerConfig::reference(
+        FusionKnTransportKind::CharacterDevice,
+    ));
+    dispatch(&mut peer, &encode_negotiation(7, negotiation));
+    assert_eq!(
+        status(&mut peer, &mut handler, &summary_request(8, 9)),
+        FusionKnStatusCode::Unsupported
+    );
+}
+
+#[test]
+fn peer_stamps_responses_with_negotiated_version() {
+    let mut peer = FusionKnPeer::new(FusionKnPeerConfig::reference(
+        FusionKnTransportKind::CharacterDevice,
+    ));
+    let (header, _) = dispatch(&mut peer, &summary_request(1, 9));
+    assert_eq!(header.status, FusionKnStatusCode::Denied);
+    assert_eq!(header.version_minor, FUSION_KN_PROTOCOL_VERSION_MINOR);
+
+    let negotiation = FusionKnNegotiationRequest {
+        max_version_minor: 0,
+        ..FusionKnNegotiationRequest::current(FusionKnTransportKind::CharacterDevice)
+    };
+    let (header, _) = dispatch(&mut peer, &encode_negotiation(2, negotiation));
+    assert_eq!(header.status, FusionKnStatusCode::Ok);
+    assert_eq!(
+        (header.version_major, header.version_minor),
+        (FUSION_KN_PROTOCOL_VERSION_MAJOR, 0)
+    );
+    let (header, _) = dispatch(&mut peer, &summary_request(3, 9));
+    assert_eq!(header.status, FusionKnStatusCode::Unsupported);
+    assert_eq!(header.version_minor, 0);
+}
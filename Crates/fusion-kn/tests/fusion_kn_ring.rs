@@ -0,0 +1,48 @@
+use fusion_kn::contract::ring::{
+    FUSION_KN_RING_CONTROL_LEN,
+    FusionKnRingLayout,
+};
+use fusion_kn::contract::wire::FusionKnWireError;
+
+#[test]
+fn ring_layout_round_trips_through_control_block() {
+    let layout = FusionKnRingLayout::new(4, 128).expect("layout should be valid");
+    assert_eq!(
+        layout.total_bytes(),
+        FUSION_KN_RING_CONTROL_LEN + 4 * 128 * 2
+    );
+    assert_eq!(
+        layout.submission_slot_offset(5),
+        FUSION_KN_RING_CONTROL_LEN + 128
+    );
+    assert_eq!(
+        layout.completion_slot_offset(3),
+        FUSION_KN_RING_CONTROL_LEN + 4 * 128 + 3 * 128
+    );
+
+    let mut mapping = vec![0xAA_u8; layout.total_bytes()];
+    layout
+        .encode_control_into(&mut mapping)
+        .expect("control block should encode");
+    assert_eq!(FusionKnRingLayout::decode_control(&mapping), Ok(layout));
+    assert_eq!(
+        FusionKnRingLayout::decode_control(&mapping[..layout.total_bytes() - 1]),
+        Err(FusionKnWireError::BufferTooSmall)
+    );
+}
+
+#[test]
+fn ring_layout_rejects_unusable_geometry() {
+    assert_eq!(
+        FusionKnRingLayout::new(3, 128),
+        Err(FusionKnWireError::InvalidRingLayout)
+    );
+    assert_eq!(
+        FusionKnRingLayout::new(4, 32),
+        Err(FusionKnWireError::InvalidRingLayout)
+    );
+    assert_eq!(
+        FusionKnRingLayout::new(1 << 20, 1 << 12),
+        Err(FusionKnWireError::InvalidRingLayout)
+    );
+}
@@ -88,6 +88,10 @@ pub struct EventCompletion {
     pub bytes_transferred: Option<usize>,
     /// Whether the completed operation succeeded.
     pub success: bool,
+    /// Source handle produced by the operation, such as an accepted connection.
+    pub source: Option<EventSourceHandle>,
+    /// Failure reported for the operation, when the backend can name it.
+    pub error: Option<EventError>,
 }
 
 /// Completion operation kind submitted to a completion-oriented backend.
@@ -101,10 +105,79 @@ pub enum EventCompletionOpKind {
     Accept,
     /// Completion for a connect-style operation.
     Connect,
+    /// Completion delivered once a relative deadline elapses.
+    Timeout,
     /// Backend-specific completion operation.
     Custom(u16),
 }
 
+/// Caller-owned memory handed to a completion-oriented backend for one operation.
+///
+/// Completion backends read or write this memory asynchronously, after `submit` has returned,
+/// so the region's validity cannot be tracked by the borrow checker. Constructing one is the
+/// caller's promise that it outlives the operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventCompletionBuffer {
+    ptr: *mut u8,
+    len: usize,
+}
+
+// SAFETY: the buffer is an address range, not access to it; the constructor's contract covers
+// every later use by the backend.
+unsafe impl Send for EventCompletionBuffer {}
+// SAFETY: see the `Send` impl above.
+unsafe impl Sync for EventCompletionBuffer {}
+
+impl EventCompletionBuffer {
+    /// Describes one caller-owned memory region.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `len` bytes from the moment the operation
+    /// is submitted until its completion has been polled or the poller has been dropped, and
+    /// nothing else may access the region while the backend can write to it.
+    #[must_use]
+    pub const unsafe fn new(ptr: *mut u8, len: usize) -> Self {
+        Self { ptr, len }
+    }
+
+    /// Returns the start of the region.
+    #[must_use]
+    pub const fn as_ptr(self) -> *mut u8 {
+        self.ptr
+    }
+
+    /// Returns the region length in bytes.
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the region is empty.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Operand attached to one completion-style operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventCompletionTarget {
+    /// The operation carries no operand (accepts, posted notifications, custom operations).
+    None,
+    /// Data buffer for a read or write, at an explicit offset or the source's own position.
+    Buffer {
+        /// Memory read into or written from.
+        buffer: EventCompletionBuffer,
+        /// Byte offset within seekable sources; `None` uses the current position.
+        offset: Option<u64>,
+    },
+    /// Encoded socket address for a connect.
+    Address(EventCompletionBuffer),
+    /// Relative deadline for a timeout.
+    Deadline(Duration),
+}
+
 /// Completion-style operation submitted to a backend poller.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct EventCompletionOp {
@@ -112,6 +185,8 @@ pub struct EventCompletionOp {
     pub source: EventSourceHandle,
     /// Operation kind associated with the submission.
     pub kind: EventCompletionOpKind,
+    /// Operand the operation reads, writes, or waits on.
+    pub target: EventCompletionTarget,
     /// Opaque caller-owned token echoed back by backend completion records later.
     pub user_data: usize,
 }
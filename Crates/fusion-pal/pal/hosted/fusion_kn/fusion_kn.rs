@@ -4,6 +4,9 @@
 //! `fusion-pal::sys` namespace. Transport-specific implementations live below this level so
 //! the public backend family stays generic even when a given target currently talks over a
 //! Linux character device.
+//!
+//! On Linux the non-kernel boundaries are re-exported from the hosted Linux lane, so this list must
+//! name exactly the modules `pal::hosted::linux` provides and `sys` dispatch resolves.
 
 pub use fusion_kn::client::*;
 pub use fusion_kn::contract::*;
@@ -13,16 +16,22 @@ pub use crate::pal::hosted::linux::{
     atomic,
     context,
     dma,
+    entry,
     event,
     fiber,
-    gpio,
     hal,
+    identity,
     mem,
+    pcu,
     power,
     sync,
     thread,
+    vector,
 };
 
 #[cfg(target_os = "linux")]
 /// Linux transport adapters for the mediated Fusion kernel backend.
 pub mod linux;
+#[cfg(target_os = "linux")]
+/// Linux shared-memory ring transport for the mediated Fusion kernel backend.
+pub mod shm;
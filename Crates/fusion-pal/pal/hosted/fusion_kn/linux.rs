@@ -5,6 +5,10 @@
 //! - response framing is validated from the fixed bitflat header
 //! - oversized payloads are drained before reporting failure so the stream does not remain
 //!   poisoned for the next exchange
+//!
+//! The same byte-stream framing also runs over a connected Unix stream socket, which is how
+//! the hosted `fusion_kn_peer` reference peer stands in for `/dev/fusion_kn` when no kernel
+//! module is loaded.
 
 use core::ffi::c_int;
 use core::fmt;
@@ -19,21 +23,34 @@ use fusion_kn::contract::wire::{
 /// Default Linux device path for the mediated Fusion kernel transport.
 pub const DEFAULT_DEVICE_PATH: &[u8] = b"/dev/fusion_kn\0";
 
-/// Linux transport error for mediated character-device exchanges.
+/// Linux transport error for mediated character-device and shared-memory ring exchanges.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum LinuxFusionKnTransportError {
     /// Caller passed an invalid or negative file descriptor.
     InvalidFileDescriptor,
-    /// Caller passed a path that was not explicitly nul-terminated.
+    /// Caller passed a path that was not explicitly nul-terminated or does not fit a socket
+    /// address.
     InvalidPath,
     /// Device open failed with the contained errno.
     OpenFailed(i32),
+    /// Unix socket creation or connection failed with the contained errno.
+    ConnectFailed(i32),
     /// Device write failed with the contained errno.
     WriteFailed(i32),
     /// Device read failed with the contained errno.
     ReadFailed(i32),
     /// Stream was closed before the expected number of bytes arrived.
     UnexpectedEndOfStream,
+    /// Shared-memory ring creation, sizing, or mapping failed with the contained errno.
+    RingSetupFailed(i32),
+    /// The target ring queue has no free slot.
+    RingFull,
+    /// A ring slot descriptor does not match the index or slot size it was read under.
+    RingCorrupted,
+    /// Waiting on a ring doorbell failed with the contained errno.
+    WaitFailed(i32),
+    /// The peer did not answer within the configured timeout.
+    TimedOut,
     /// Response framing failed before the message could be completed.
     Wire(FusionKnWireError),
 }
@@ -44,11 +61,17 @@ impl fmt::Display for LinuxFusionKnTransportError {
             Self::InvalidFileDescriptor => f.write_str("invalid file descriptor"),
             Self::InvalidPath => f.write_str("device path must be nul-terminated"),
             Self::OpenFailed(errno) => write!(f, "device open failed with errno {errno}"),
+            Self::ConnectFailed(errno) => write!(f, "socket connect failed with errno {errno}"),
             Self::WriteFailed(errno) => write!(f, "device write failed with errno {errno}"),
             Self::ReadFailed(errno) => write!(f, "device read failed with errno {errno}"),
             Self::UnexpectedEndOfStream => {
                 f.write_str("device stream ended before a full message arrived")
             }
+            Self::RingSetupFailed(errno) => write!(f, "ring setup failed with errno {errno}"),
+            Self::RingFull => f.write_str("ring queue has no free slot"),
+            Self::RingCorrupted => f.write_str("ring slot descriptor is inconsistent"),
+            Self::WaitFailed(errno) => write!(f, "ring doorbell wait failed with errno {errno}"),
+            Self::TimedOut => f.write_str("peer did not answer before the timeout"),
             Self::Wire(error) => write!(f, "response framing failed: {error:?}"),
         }
     }
@@ -90,6 +113,50 @@ impl LinuxFusionKnCharacterDevice {
         Ok(Self { fd })
     }
 
+    /// Connects to a peer listening on the Unix stream socket at the provided nul-terminated
+    /// path.
+    ///
+    /// This is the hosted stand-in for the device node: the reference `fusion_kn_peer` serves
+    /// the same framing over a socket, so negotiation can be exercised without a kernel
+    /// module.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the path is not nul-terminated, does not fit `sockaddr_un`, or
+    /// the socket cannot be created or connected.
+    pub fn connect_unix_socket(path: &[u8]) -> Result<Self, LinuxFusionKnTransportError> {
+        if path.last().copied() != Some(0) {
+            return Err(LinuxFusionKnTransportError::InvalidPath);
+        }
+
+        let family = libc::sa_family_t::try_from(libc::AF_UNIX)
+            .map_err(|_| LinuxFusionKnTransportError::ConnectFailed(libc::EAFNOSUPPORT))?;
+        let address_len = libc::socklen_t::try_from(core::mem::size_of::<libc::sockaddr_un>())
+            .map_err(|_| LinuxFusionKnTransportError::ConnectFailed(libc::EINVAL))?;
+        let mut address = libc::sockaddr_un {
+            sun_family: family,
+            sun_path: [0; 108],
+        };
+        if path.len() > address.sun_path.len() {
+            return Err(LinuxFusionKnTransportError::InvalidPath);
+        }
+        for (dst, src) in address.sun_path.iter_mut().zip(path) {
+            *dst = src.cast_signed();
+        }
+
+        let fd = unsafe { libc::socket(libc::AF_UNIX, libc::SOCK_STREAM | libc::SOCK_CLOEXEC, 0) };
+        if fd < 0 {
+            return Err(LinuxFusionKnTransportError::ConnectFailed(last_errno()));
+        }
+        // Constructing the transport first lets `Drop` close the socket on a failed connect.
+        let transport = Self { fd };
+        let rc = unsafe { libc::connect(fd, (&raw const address).cast(), address_len) };
+        if rc < 0 {
+            return Err(LinuxFusionKnTransportError::ConnectFailed(last_errno()));
+        }
+        Ok(transport)
+    }
+
     /// Creates a transport from an already-open file descriptor.
     ///
     /// # Errors
@@ -203,3 +270,76 @@ fn discard_exact(fd: c_int, bytes: usize) -> Result<(), LinuxFusionKnTransportEr
 fn last_errno() -> i32 {
     unsafe { *libc::__errno_location() }
 }
+
+#[cfg(all(test, feature = "std", not(target_os = "none")))]
+mod tests {
+    use super::*;
+    extern crate std;
+    use self::std::format;
+    use self::std::io::{
+        Read,
+        Write,
+    };
+    use self::std::os::unix::net::UnixListener;
+    use self::std::thread;
+    use self::std::vec::Vec;
+
+    use fusion_kn::client::FusionKnClient;
+    use fusion_kn::contract::wire::FUSION_KN_PROTOCOL_VERSION_MAJOR;
+    use fusion_kn::server::{
+        FusionKnPeer,
+        FusionKnPeerConfig,
+        declared_payload_bytes,
+    };
+
+    #[test]
+    fn unix_socket_transport_negotiates_with_reference_peer() {
+        let path = self::std::env::temp_dir()
+            .join(format!("fusion-pal-kn-{}.sock", self::std::process::id()));
+        let _ = self::std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).expect("test socket should bind");
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("client should connect");
+            let mut peer = FusionKnPeer::new(FusionKnPeerConfig::reference(
+                FusionKnTransportKind::CharacterDevice,
+            ));
+            let mut request = [0_u8; 64];
+            stream
+                .read_exact(&mut request[..FusionKnMessageHeader::ENCODED_LEN])
+                .expect("request header should arrive");
+            let payload = declared_payload_bytes(&request).expect("magic should match") as usize;
+            let end = FusionKnMessageHeader::ENCODED_LEN + payload;
+            stream
+                .read_exact(&mut request[FusionKnMessageHeader::ENCODED_LEN..end])
+                .expect("request payload should arrive");
+            let mut response = [0_u8; 64];
+            let written = peer
+                .dispatch(&request[..end], &mut response)
+                .expect("response should fit");
+            stream
+                .write_all(&response[..written])
+                .expect("response should send");
+        });
+
+        let mut socket_path: Vec<u8> = path.as_os_str().as_encoded_bytes().to_vec();
+        socket_path.push(0);
+        let transport = LinuxFusionKnCharacterDevice::connect_unix_socket(&socket_path)
+            .expect("transport should connect");
+        let session = FusionKnClient::new(transport)
+            .negotiate()
+            .expect("negotiation should succeed");
+        server.join().expect("peer thread should finish");
+        let _ = self::std::fs::remove_file(&path);
+
+        assert_eq!(session.version_major, FUSION_KN_PROTOCOL_VERSION_MAJOR);
+        assert_eq!(session.transport, FusionKnTransportKind::CharacterDevice);
+    }
+
+    #[test]
+    fn unix_socket_transport_requires_nul_terminated_path() {
+        assert_eq!(
+            LinuxFusionKnCharacterDevice::connect_unix_socket(b"/tmp/fusion_kn.sock").unwrap_err(),
+            LinuxFusionKnTransportError::InvalidPath
+        );
+    }
+}
@@ -0,0 +1,751 @@
+//! Linux shared-memory ring transport for the mediated Fusion kernel backend.
+//!
+//! The ring lives in one `memfd` mapping laid out as `fusion_kn::contract::ring` documents.
+//! Either side may create it; the other attaches through the same memory file, inherited
+//! across `fork` or passed over a Unix socket, and maps it independently.
+//!
+//! Doorbells are shared (non-private) futex waits on the producer index words. A consumer
+//! spins briefly, then registers itself in the queue's waiter count before sleeping; a
+//! producer only issues the wake syscall when that count is non-zero. Steady-state traffic
+//! therefore stays entirely in shared memory.
+//!
+//! The client side issues one request at a time, matching the synchronous
+//! [`FusionKnTransport`] contract, so a full queue is reported rather than waited out. A
+//! completion is matched to its request by the request ID in the framed header, so answers to
+//! exchanges that already timed out are drained instead of being handed to the next caller.
+//!
+//! Neither side decodes bytes in place: whatever sits in the mapping can change under the
+//! reader, so requests and completions are copied into private memory first.
+
+use core::ffi::c_void;
+use core::mem::MaybeUninit;
+use core::ptr::{
+    self,
+    NonNull,
+};
+use core::slice;
+use core::sync::atomic::{
+    AtomicU32,
+    Ordering,
+};
+use core::time::Duration;
+
+use fusion_kn::client::FusionKnTransport;
+use fusion_kn::contract::ring::{
+    FusionKnRingLayout,
+    FusionKnRingSlotDescriptor,
+};
+use fusion_kn::contract::wire::{
+    FusionKnTransportKind,
+    FusionKnWireError,
+};
+use rustix::fd::{
+    AsFd,
+    BorrowedFd,
+    OwnedFd,
+};
+use rustix::fs::{
+    MemfdFlags,
+    fstat,
+    ftruncate,
+    memfd_create,
+};
+use rustix::io::{
+    Errno,
+    fcntl_dupfd_cloexec,
+};
+use rustix::mm::{
+    MapFlags,
+    ProtFlags,
+    mmap,
+    munmap,
+};
+use rustix::thread::futex;
+
+use super::linux::LinuxFusionKnTransportError;
+
+/// Polls of a producer index before a consumer falls back to sleeping on the doorbell.
+const SPIN_LIMIT: u32 = 256;
+
+/// Byte range of the request ID inside every framed message header.
+const REQUEST_ID_RANGE: core::ops::Range<usize> = 20..24;
+
+/// Client side of a shared-memory ring: submits requests and collects completions.
+#[derive(Debug)]
+pub struct LinuxFusionKnSharedMemoryRing {
+    ring: RingMapping,
+    timeout: Option<Duration>,
+}
+
+impl LinuxFusionKnSharedMemoryRing {
+    /// Creates a fresh ring in a new memory file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the memory file cannot be created, sized, or mapped.
+    pub fn create(layout: FusionKnRingLayout) -> Result<Self, LinuxFusionKnTransportError> {
+        Ok(Self {
+            ring: RingMapping::create(layout)?,
+            timeout: None,
+        })
+    }
+
+    /// Attaches to a ring another party created.
+    ///
+    /// The descriptor is duplicated; the caller keeps ownership of `memfd`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the memory file cannot be mapped or its control block does not
+    /// describe a ring that fits inside it.
+    pub fn attach(memfd: BorrowedFd<'_>) -> Result<Self, LinuxFusionKnTransportError> {
+        Ok(Self {
+            ring: RingMapping::attach(memfd)?,
+            timeout: None,
+        })
+    }
+
+    /// Bounds how long one exchange waits for its completion.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns the ring geometry.
+    #[must_use]
+    pub const fn layout(&self) -> FusionKnRingLayout {
+        self.ring.layout
+    }
+
+    /// Returns the memory file backing the ring, for handing to the peer.
+    #[must_use]
+    pub fn memfd(&self) -> BorrowedFd<'_> {
+        self.ring.fd.as_fd()
+    }
+}
+
+impl FusionKnTransport for LinuxFusionKnSharedMemoryRing {
+    type Error = LinuxFusionKnTransportError;
+
+    fn transport_kind(&self) -> FusionKnTransportKind {
+        FusionKnTransportKind::SharedMemoryRing
+    }
+
+    fn transact(&mut self, request: &[u8], response: &mut [u8]) -> Result<usize, Self::Error> {
+        if request.len() > self.ring.layout.max_message_bytes() {
+            return Err(LinuxFusionKnTransportError::Wire(
+                FusionKnWireError::BufferTooSmall,
+            ));
+        }
+
+        let request_id = framed_request_id(request);
+        let submission = self.ring.reserve(RingQueue::Submission)?;
+        unsafe {
+            ptr::copy_nonoverlapping(
+                request.as_ptr(),
+                self.ring.message(RingQueue::Submission, submission),
+                request.len(),
+            );
+        }
+        self.ring
+            .write_descriptor(RingQueue::Submission, submission, request.len());
+        self.ring.publish(RingQueue::Submission, submission);
+
+        // One deadline covers the whole exchange, including any stale completions drained first.
+        let deadline = deadline_after(self.timeout)?;
+        let (completion, message_bytes) = loop {
+            let (completion, message_bytes) =
+                self.ring.await_slot(RingQueue::Completion, deadline)?;
+            let mut id = [0_u8; REQUEST_ID_RANGE.end];
+            let copied = message_bytes.min(id.len());
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    self.ring.message(RingQueue::Completion, completion),
+                    id.as_mut_ptr(),
+                    copied,
+                );
+            }
+            if framed_request_id(&id[..copied]) == request_id {
+                break (completion, message_bytes);
+            }
+            // Left behind by an earlier exchange that gave up waiting.
+            self.ring.release(RingQueue::Completion, completion);
+        };
+        // Oversized completions are still consumed so the queue does not stay wedged on them.
+        if message_bytes > response.len() {
+            self.ring.release(RingQueue::Completion, completion);
+            return Err(LinuxFusionKnTransportError::Wire(
+                FusionKnWireError::BufferTooSmall,
+            ));
+        }
+        unsafe {
+            ptr::copy_nonoverlapping(
+                self.ring.message(RingQueue::Completion, completion),
+                response.as_mut_ptr(),
+                message_bytes,
+            );
+        }
+        self.ring.release(RingQueue::Completion, completion);
+        Ok(message_bytes)
+    }
+}
+
+/// Peer side of a shared-memory ring: answers submissions in place.
+#[derive(Debug)]
+pub struct LinuxFusionKnSharedMemoryRingPeer {
+    ring: RingMapping,
+    timeout: Option<Duration>,
+}
+
+impl LinuxFusionKnSharedMemoryRingPeer {
+    /// Creates a fresh ring in a new memory file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the memory file cannot be created, sized, or mapped.
+    pub fn create(layout: FusionKnRingLayout) -> Result<Self, LinuxFusionKnTransportError> {
+        Ok(Self {
+            ring: RingMapping::create(layout)?,
+            timeout: None,
+        })
+    }
+
+    /// Attaches to a ring another party created.
+    ///
+    /// The descriptor is duplicated; the caller keeps ownership of `memfd`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the memory file cannot be mapped or its control block does not
+    /// describe a ring that fits inside it.
+    pub fn attach(memfd: BorrowedFd<'_>) -> Result<Self, LinuxFusionKnTransportError> {
+        Ok(Self {
+            ring: RingMapping::attach(memfd)?,
+            timeout: None,
+        })
+    }
+
+    /// Bounds how long one call waits for the next submission.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns the ring geometry.
+    #[must_use]
+    pub const fn layout(&self) -> FusionKnRingLayout {
+        self.ring.layout
+    }
+
+    /// Returns the memory file backing the ring, for handing to the client.
+    #[must_use]
+    pub fn memfd(&self) -> BorrowedFd<'_> {
+        self.ring.fd.as_fd()
+    }
+
+    /// Waits for one submission and answers it through `handler`.
+    ///
+    /// The request is copied out of the shared mapping into `scratch` before `handler` sees
+    /// it, so the client cannot change it while it is being decoded. `handler` receives that
+    /// copy and the completion slot's message area, and returns how many response bytes it
+    /// wrote, which is the shape of `FusionKnPeer::dispatch`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when waiting fails or times out, the submission slot is
+    /// inconsistent, the completion queue is full, `scratch` is shorter than the request, or
+    /// `handler` fails. A full completion queue or short `scratch` leaves the submission in
+    /// place so the call can be retried.
+    pub fn serve_next<F>(
+        &mut self,
+        scratch: &mut [u8],
+        handler: F,
+    ) -> Result<(), LinuxFusionKnTransportError>
+    where
+        F: FnOnce(&[u8], &mut [u8]) -> Result<usize, FusionKnWireError>,
+    {
+        let (submission, request_bytes) = self
+            .ring
+            .await_slot(RingQueue::Submission, deadline_after(self.timeout)?)?;
+        let completion = self.ring.reserve(RingQueue::Completion)?;
+        let capacity = self.ring.layout.max_message_bytes();
+        let request = scratch
+            .get_mut(..request_bytes)
+            .ok_or(LinuxFusionKnTransportError::Wire(
+                FusionKnWireError::BufferTooSmall,
+            ))?;
+        unsafe {
+            ptr::copy_nonoverlapping(
+                self.ring.message(RingQueue::Submission, submission),
+                request.as_mut_ptr(),
+                request_bytes,
+            );
+        }
+        self.ring.release(RingQueue::Submission, submission);
+
+        let response = unsafe {
+            slice::from_raw_parts_mut(
+                self.ring.message(RingQueue::Completion, completion),
+                capacity,
+            )
+        };
+        let written = handler(request, response);
+
+        let written = written.map_err(LinuxFusionKnTransportError::Wire)?;
+        if written > capacity {
+            return Err(LinuxFusionKnTransportError::RingCorrupted);
+        }
+        self.ring
+            .write_descriptor(RingQueue::Completion, completion, written);
+        self.ring.publish(RingQueue::Completion, completion);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum RingQueue {
+    Submission,
+    Completion,
+}
+
+impl RingQueue {
+    const fn producer_offset(self) -> usize {
+        match self {
+            Self::Submission => FusionKnRingLayout::SUBMISSION_PRODUCER_OFFSET,
+            Self::Completion => FusionKnRingLayout::COMPLETION_PRODUCER_OFFSET,
+        }
+    }
+
+    const fn waiters_offset(self) -> usize {
+        match self {
+            Self::Submission => FusionKnRingLayout::SUBMISSION_WAITERS_OFFSET,
+            Self::Completion => FusionKnRingLayout::COMPLETION_WAITERS_OFFSET,
+        }
+    }
+
+    const fn consumer_offset(self) -> usize {
+        match self {
+            Self::Submission => FusionKnRingLayout::SUBMISSION_CONSUMER_OFFSET,
+            Self::Completion => FusionKnRingLayout::COMPLETION_CONSUMER_OFFSET,
+        }
+    }
+}
+
+/// One process's mapping of a ring memory file.
+#[derive(Debug)]
+struct RingMapping {
+    base: NonNull<c_void>,
+    len: usize,
+    layout: FusionKnRingLayout,
+    // Unmapped in `Drop` before the fd itself closes.
+    fd: OwnedFd,
+}
+
+// SAFETY: the mapping is owned by this value and moves with it; slots are only touched under
+// the ring's producer/consumer protocol.
+#[allow(clippy::non_send_fields_in_send_ty)]
+unsafe impl Send for RingMapping {}
+
+impl RingMapping {
+    fn create(layout: FusionKnRingLayout) -> Result<Self, LinuxFusionKnTransportError> {
+        let fd = memfd_create(c"fusion_kn_ring", MemfdFlags::CLOEXEC).map_err(setup_error)?;
+        ftruncate(&fd, layout.total_bytes() as u64).map_err(setup_error)?;
+        let base = map_shared(&fd, layout.total_bytes())?;
+        let mapping = Self {
+            base,
+            len: layout.total_bytes(),
+            layout,
+            fd,
+        };
+
+        let control = unsafe {
+            slice::from_raw_parts_mut(mapping.base.as_ptr().cast::<u8>(), layout.total_bytes())
+        };
+        layout
+            .encode_control_into(control)
+            .map_err(LinuxFusionKnTransportError::Wire)?;
+        Ok(mapping)
+    }
+
+    fn attach(memfd: BorrowedFd<'_>) -> Result<Self, LinuxFusionKnTransportError> {
+        let fd = fcntl_dupfd_cloexec(memfd, 0).map_err(setup_error)?;
+        let len = usize::try_from(fstat(&fd).map_err(setup_error)?.st_size)
+            .map_err(|_| LinuxFusionKnTransportError::Wire(FusionKnWireError::InvalidRingLayout))?;
+        let base = map_shared(&fd, len)?;
+
+        let bytes = unsafe { slice::from_raw_parts(base.as_ptr().cast::<u8>(), len) };
+        match FusionKnRingLayout::decode_control(bytes) {
+            Ok(layout) => Ok(Self {
+                base,
+                len,
+                layout,
+                fd,
+            }),
+            Err(error) => {
+                let _ = unsafe { munmap(base.as_ptr(), len) };
+                Err(LinuxFusionKnTransportError::Wire(error))
+            }
+        }
+    }
+
+    const fn word(&self, offset: usize) -> &AtomicU32 {
+        unsafe { AtomicU32::from_ptr(self.base.as_ptr().cast::<u8>().add(offset).cast()) }
+    }
+
+    const fn slot(&self, queue: RingQueue, index: u32) -> *mut u8 {
+        let offset = match queue {
+            RingQueue::Submission => self.layout.submission_slot_offset(index),
+            RingQueue::Completion => self.layout.completion_slot_offset(index),
+        };
+        unsafe { self.base.as_ptr().cast::<u8>().add(offset) }
+    }
+
+    const fn message(&self, queue: RingQueue, index: u32) -> *mut u8 {
+        unsafe {
+            self.slot(queue, index)
+                .add(FusionKnRingSlotDescriptor::ENCODED_LEN)
+        }
+    }
+
+    /// Returns the next producer index when the queue has a free slot.
+    fn reserve(&self, queue: RingQueue) -> Result<u32, LinuxFusionKnTransportError> {
+        let producer = self.word(queue.producer_offset()).load(Ordering::Relaxed);
+        let consumer = self.word(queue.consumer_offset()).load(Ordering::Acquire);
+        if producer.wrapping_sub(consumer) >= self.layout.slot_count() {
+            return Err(LinuxFusionKnTransportError::RingFull);
+        }
+        Ok(producer)
+    }
+
+    fn write_descriptor(&self, queue: RingQueue, index: u32, message_bytes: usize) {
+        let mut encoded = [0_u8; FusionKnRingSlotDescriptor::ENCODED_LEN];
+        // Slot capacity fits `u32` by layout construction and every length is checked against
+        // it first, so the descriptor encode cannot fail.
+        let _ = FusionKnRingSlotDescriptor {
+            message_bytes: u32::try_from(message_bytes).unwrap_or(u32::MAX),
+            sequence: index,
+        }
+        .encode_into(&mut encoded);
+        unsafe {
+            ptr::copy_nonoverlapping(encoded.as_ptr(), self.slot(queue, index), encoded.len());
+        }
+    }
+
+    /// Makes the slot at `index` visible to the consumer and rings the doorbell if needed.
+    fn publish(&self, queue: RingQueue, index: u32) {
+        let producer = self.word(queue.producer_offset());
+        producer.store(index.wrapping_add(1), Ordering::SeqCst);
+        if self.word(queue.waiters_offset()).load(Ordering::SeqCst) != 0 {
+            let _ = futex::wake(producer, futex::Flags::empty(), u32::MAX);
+        }
+    }
+
+    /// Waits until the queue holds a slot for the consumer and returns it with its length.
+    ///
+    /// `deadline` is an absolute monotonic time from [`deadline_after`].
+    fn await_slot(
+        &self,
+        queue: RingQueue,
+        deadline: Option<Duration>,
+    ) -> Result<(u32, usize), LinuxFusionKnTransportError> {
+        let consumer = self.word(queue.consumer_offset()).load(Ordering::Relaxed);
+        self.wait_for_producer(queue, consumer, deadline)?;
+
+        let mut encoded = [0_u8; FusionKnRingSlotDescriptor::ENCODED_LEN];
+        unsafe {
+            ptr::copy_nonoverlapping(
+                self.slot(queue, consumer),
+                encoded.as_mut_ptr(),
+                encoded.len(),
+            );
+        }
+        let descriptor = FusionKnRingSlotDescriptor::decode_from(&encoded)
+            .map_err(LinuxFusionKnTransportError::Wire)?;
+        let message_bytes = usize::try_from(descriptor.message_bytes)
+            .map_err(|_| LinuxFusionKnTransportError::RingCorrupted)?;
+        if descriptor.sequence != consumer || message_bytes > self.layout.max_message_bytes() {
+            return Err(LinuxFusionKnTransportError::RingCorrupted);
+        }
+        Ok((consumer, message_bytes))
+    }
+
+    fn release(&self, queue: RingQueue, index: u32) {
+        self.word(queue.consumer_offset())
+            .store(index.wrapping_add(1), Ordering::Release);
+    }
+
+    fn wait_for_producer(
+        &self,
+        queue: RingQueue,
+        observed: u32,
+        deadline: Option<Duration>,
+    ) -> Result<(), LinuxFusionKnTransportError> {
+        let producer = self.word(queue.producer_offset());
+        for _ in 0..SPIN_LIMIT {
+            if producer.load(Ordering::Acquire) != observed {
+                return Ok(());
+            }
+            core::hint::spin_loop();
+        }
+
+        let waiters = self.word(queue.waiters_offset());
+        loop {
+            // Interrupted or spurious wakes only get what is left of the original bound.
+            let timespec = deadline
+                .map(|deadline| {
+                    let remaining = deadline
+                        .checked_sub(monotonic_now()?)
+                        .ok_or(LinuxFusionKnTransportError::TimedOut)?;
+                    Ok(futex::Timespec {
+                        tv_sec: i64::try_from(remaining.as_secs()).map_err(|_| {
+                            LinuxFusionKnTransportError::WaitFailed(Errno::INVAL.raw_os_error())
+                        })?,
+                        tv_nsec: i64::from(remaining.subsec_nanos()),
+                    })
+                })
+                .transpose()?;
+            // Registering before the re-check pairs with the producer's store-then-load of
+            // the waiter count, so either we see the new index or the producer sees us.
+            waiters.fetch_add(1, Ordering::SeqCst);
+            if producer.load(Ordering::SeqCst) != observed {
+                waiters.fetch_sub(1, Ordering::SeqCst);
+                return Ok(());
+            }
+            let outcome = futex::wait(producer, futex::Flags::empty(), observed, timespec.as_ref());
+            waiters.fetch_sub(1, Ordering::SeqCst);
+            match outcome {
+                Ok(()) | Err(Errno::AGAIN | Errno::INTR) => {}
+                Err(Errno::TIMEDOUT) => return Err(LinuxFusionKnTransportError::TimedOut),
+                Err(errno) => {
+                    return Err(LinuxFusionKnTransportError::WaitFailed(
+                        errno.raw_os_error(),
+                    ));
+                }
+            }
+            if producer.load(Ordering::Acquire) != observed {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl Drop for RingMapping {
+    fn drop(&mut self) {
+        let _ = unsafe { munmap(self.base.as_ptr(), self.len) };
+    }
+}
+
+fn map_shared(fd: &OwnedFd, len: usize) -> Result<NonNull<c_void>, LinuxFusionKnTransportError> {
+    let base = unsafe {
+        mmap(
+            ptr::null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::SHARED,
+            fd,
+            0,
+        )
+    }
+    .map_err(setup_error)?;
+    NonNull::new(base).ok_or(LinuxFusionKnTransportError::RingSetupFailed(
+        Errno::NOMEM.raw_os_error(),
+    ))
+}
+
+const fn setup_error(errno: Errno) -> LinuxFusionKnTransportError {
+    LinuxFusionKnTransportError::RingSetupFailed(errno.raw_os_error())
+}
+
+/// Returns the request ID of one framed message, without trusting the rest of its header.
+fn framed_request_id(message: &[u8]) -> Option<u32> {
+    message
+        .get(REQUEST_ID_RANGE)
+        .map(|raw| u32::from_le_bytes([raw[0], raw[1], raw[2], raw[3]]))
+}
+
+/// Turns a relative wait bound into an absolute monotonic deadline.
+fn deadline_after(
+    timeout: Option<Duration>,
+) -> Result<Option<Duration>, LinuxFusionKnTransportError> {
+    timeout
+        .map(|timeout| {
+            monotonic_now()?
+                .checked_add(timeout)
+                .ok_or(LinuxFusionKnTransportError::WaitFailed(
+                    Errno::INVAL.raw_os_error(),
+                ))
+        })
+        .transpose()
+}
+
+fn monotonic_now() -> Result<Duration, LinuxFusionKnTransportError> {
+    let mut current = MaybeUninit::<libc::timespec>::uninit();
+    // SAFETY: `current` is valid writable storage for one `timespec`.
+    let rc = unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, current.as_mut_ptr()) };
+    if rc != 0 {
+        return Err(LinuxFusionKnTransportError::WaitFailed(unsafe {
+            *libc::__errno_location()
+        }));
+    }
+    // SAFETY: `clock_gettime` succeeded, so it initialized `current`.
+    let current = unsafe { current.assume_init() };
+    let secs = u64::try_from(current.tv_sec)
+        .map_err(|_| LinuxFusionKnTransportError::WaitFailed(Errno::INVAL.raw_os_error()))?;
+    let nanos = u32::try_from(current.tv_nsec)
+        .map_err(|_| LinuxFusionKnTransportError::WaitFailed(Errno::INVAL.raw_os_error()))?;
+    Ok(Duration::new(secs, nanos))
+}
+
+#[cfg(all(test, feature = "std", not(target_os = "none")))]
+mod tests {
+    use super::*;
+
+    extern crate std;
+
+    use fusion_kn::client::FusionKnClient;
+    use fusion_kn::server::{
+        FusionKnPeer,
+        FusionKnPeerConfig,
+    };
+
+    const EXCHANGES: usize = 3;
+
+    #[test]
+    fn ring_negotiates_with_peer_in_another_process() {
+        let layout = FusionKnRingLayout::new(4, 128).expect("layout should be valid");
+        let client = LinuxFusionKnSharedMemoryRing::create(layout)
+            .expect("ring should be created")
+            .with_timeout(Some(Duration::from_secs(5)));
+
+        let child = unsafe { libc::fork() };
+        assert!(child >= 0, "fork should succeed");
+        if child == 0 {
+            // The child attaches through its own mapping of the inherited memfd. It must not
+            // unwind into the test harness, so every outcome ends in `_exit`.
+            let status = LinuxFusionKnSharedMemoryRingPeer::attach(client.memfd())
+                .map(|peer| peer.with_timeout(Some(Duration::from_secs(5))))
+                .and_then(|mut ring| {
+                    let mut peer = FusionKnPeer::new(FusionKnPeerConfig::reference(
+                        FusionKnTransportKind::SharedMemoryRing,
+                    ));
+                    for _ in 0..EXCHANGES {
+                        let mut scratch = [0_u8; 128];
+                        ring.serve_next(&mut scratch, |request, response| {
+                            peer.dispatch(request, response)
+                        })?;
+                    }
+                    Ok(())
+                })
+                .map_or(1, |()| 0);
+            unsafe { libc::_exit(status) };
+        }
+
+        let mut client = FusionKnClient::new(client);
+        for _ in 0..EXCHANGES {
+            let session = client.negotiate().expect("negotiation should succeed");
+            assert_eq!(session.transport, FusionKnTransportKind::SharedMemoryRing);
+        }
+
+        let mut status = 0;
+        let waited = unsafe { libc::waitpid(child, &raw mut status, 0) };
+        assert_eq!(waited, child);
+        assert!(libc::WIFEXITED(status));
+        assert_eq!(libc::WEXITSTATUS(status), 0);
+    }
+
+    #[test]
+    fn ring_attach_rejects_memory_without_control_block() {
+        let fd = memfd_create(c"fusion_kn_ring_test", MemfdFlags::CLOEXEC)
+            .expect("memfd should be created");
+        ftruncate(&fd, 4096).expect("memfd should be sized");
+        assert_eq!(
+            LinuxFusionKnSharedMemoryRing::attach(fd.as_fd()).unwrap_err(),
+            LinuxFusionKnTransportError::Wire(FusionKnWireError::InvalidMagic)
+        );
+    }
+
+    #[test]
+    fn ring_times_out_without_peer() {
+        let layout = FusionKnRingLayout::new(2, 64).expect("layout should be valid");
+        let mut ring = LinuxFusionKnSharedMemoryRing::create(layout)
+            .expect("ring should be created")
+            .with_timeout(Some(Duration::from_millis(10)));
+        let mut response = [0_u8; 64];
+        assert_eq!(
+            ring.transact(&[0_u8; 28], &mut response),
+            Err(LinuxFusionKnTransportError::TimedOut)
+        );
+    }
+
+    fn framed(request_id: u32) -> [u8; 28] {
+        let mut message = [0_u8; 28];
+        message[REQUEST_ID_RANGE].copy_from_slice(&request_id.to_le_bytes());
+        message
+    }
+
+    #[test]
+    fn ring_drains_completions_left_by_timed_out_exchanges() {
+        let layout = FusionKnRingLayout::new(4, 64).expect("layout should be valid");
+        let mut client = LinuxFusionKnSharedMemoryRing::create(layout)
+            .expect("ring should be created")
+            .with_timeout(Some(Duration::from_millis(10)));
+        let mut peer = LinuxFusionKnSharedMemoryRingPeer::attach(client.memfd())
+            .expect("peer should attach")
+            .with_timeout(Some(Duration::from_secs(5)));
+        let mut response = [0_u8; 64];
+        assert_eq!(
+            client.transact(&framed(1), &mut response),
+            Err(LinuxFusionKnTransportError::TimedOut)
+        );
+
+        // The peer answers the abandoned request late, then the next one.
+        let server = std::thread::spawn(move || {
+            let mut scratch = [0_u8; 64];
+            for _ in 0..2 {
+                peer.serve_next(&mut scratch, |request, response| {
+                    response[..request.len()].copy_from_slice(request);
+                    Ok(request.len())
+                })?;
+            }
+            Ok::<_, LinuxFusionKnTransportError>(())
+        });
+        let mut client = client.with_timeout(Some(Duration::from_secs(5)));
+        assert_eq!(client.transact(&framed(2), &mut response), Ok(28));
+        assert_eq!(response[..28], framed(2));
+        server
+            .join()
+            .expect("peer thread should not panic")
+            .expect("peer should serve both requests");
+    }
+
+    #[test]
+    fn ring_peer_refuses_scratch_shorter_than_the_request() {
+        let layout = FusionKnRingLayout::new(2, 64).expect("layout should be valid");
+        let mut client = LinuxFusionKnSharedMemoryRing::create(layout)
+            .expect("ring should be created")
+            .with_timeout(Some(Duration::from_millis(10)));
+        let mut peer = LinuxFusionKnSharedMemoryRingPeer::attach(client.memfd())
+            .expect("peer should attach")
+            .with_timeout(Some(Duration::from_millis(10)));
+        let mut response = [0_u8; 64];
+        assert_eq!(
+            client.transact(&framed(1), &mut response),
+            Err(LinuxFusionKnTransportError::TimedOut)
+        );
+
+        let echo = |request: &[u8], response: &mut [u8]| {
+            response[..request.len()].copy_from_slice(request);
+            Ok(request.len())
+        };
+        assert_eq!(
+            peer.serve_next(&mut [0_u8; 8], echo),
+            Err(LinuxFusionKnTransportError::Wire(
+                FusionKnWireError::BufferTooSmall
+            ))
+        );
+        // The submission stays queued for a retry with enough room.
+        assert_eq!(peer.serve_next(&mut [0_u8; 64], echo), Ok(()));
+    }
+}
@@ -5,6 +5,10 @@ pub const PAL_LANE_NAME: &str = "hosted";
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct SelectedPalLane;
 
+#[allow(dead_code)]
+#[path = "pcu_interpreter.rs"]
+pub(crate) mod pcu_interpreter;
+
 #[allow(dead_code)]
 #[path = "pcu_shared.rs"]
 pub(crate) mod pcu_shared;
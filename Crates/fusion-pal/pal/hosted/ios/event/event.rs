@@ -15,3 +15,11 @@ pub type PlatformPoller = UnsupportedPoller;
 pub const fn system_event() -> PlatformEvent {
     PlatformEvent::new()
 }
+
+/// Returns the event provider for consumers that only wait for readiness.
+///
+/// iOS has one event surface, so this is the same provider as [`system_event`].
+#[must_use]
+pub const fn system_readiness_event() -> PlatformEvent {
+    PlatformEvent::new()
+}
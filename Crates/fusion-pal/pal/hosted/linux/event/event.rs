@@ -1,8 +1,12 @@
-//! Linux fusion-pal event backend built on `epoll`.
+//! Linux fusion-pal event backend built on `epoll` and `io_uring`.
 //!
-//! The Linux backend exposes readiness-oriented polling honestly through `epoll`.
-//! Completion submission remains unsupported here because that would be a different model,
-//! not just a missing flag.
+//! Readiness always comes from `epoll`. The default [`LinuxEventBackend::IoUring`] backend
+//! adds one `io_uring` ring per poller for completion submission (reads, writes, accepts,
+//! connects, and timeouts), and surfaces both models honestly as [`EventModel::Hybrid`].
+//! Kernels or sandboxes that refuse `io_uring` degrade to the readiness-only surface, and
+//! [`LinuxEventBackend::Epoll`] selects that surface explicitly.
+
+mod uring;
 
 use core::mem::MaybeUninit;
 use core::time::Duration;
@@ -24,6 +28,8 @@ use crate::contract::pal::runtime::event::{
 };
 
 const EPOLL_BATCH: usize = 64;
+/// `epoll` key of a poller's own ring fd; registration keys are fds and never reach it.
+const RING_KEY: u64 = u64::MAX;
 
 const LINUX_EVENT_SUPPORT: EventSupport = EventSupport {
     caps: EventCaps::READINESS
@@ -34,14 +40,36 @@ const LINUX_EVENT_SUPPORT: EventSupport = EventSupport {
     implementation: crate::contract::pal::runtime::event::EventImplementationKind::Native,
 };
 
-/// Linux readiness-poller provider.
+const LINUX_IO_URING_EVENT_SUPPORT: EventSupport = EventSupport {
+    caps: LINUX_EVENT_SUPPORT
+        .caps
+        .union(EventCaps::COMPLETION)
+        .union(EventCaps::SUBMIT),
+    model: EventModel::Hybrid,
+    ..LINUX_EVENT_SUPPORT
+};
+
+/// Kernel interface a [`LinuxEvent`] provider builds its pollers on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LinuxEventBackend {
+    /// Readiness only, through `epoll`.
+    Epoll,
+    /// `epoll` readiness plus `io_uring` completion submission, when the kernel allows rings.
+    #[default]
+    IoUring,
+}
+
+/// Linux event provider.
 #[derive(Debug, Clone, Copy, Default)]
-pub struct LinuxEvent;
+pub struct LinuxEvent {
+    backend: LinuxEventBackend,
+}
 
-/// Linux owned `epoll` poller.
+/// Linux owned `epoll` poller, with its `io_uring` ring when completions are enabled.
 #[derive(Debug)]
 pub struct LinuxPoller {
     epoll_fd: libc::c_int,
+    ring: Option<uring::LinuxRing>,
 }
 
 /// Selected Linux event provider type.
@@ -55,11 +83,41 @@ pub const fn system_event() -> PlatformEvent {
     PlatformEvent::new()
 }
 
+/// Returns the selected Linux event provider without completion submission.
+///
+/// Consumers that only wait for readiness use this so their pollers never set up a ring.
+#[must_use]
+pub const fn system_readiness_event() -> PlatformEvent {
+    PlatformEvent::epoll()
+}
+
 impl LinuxEvent {
-    /// Creates a new Linux event provider handle.
+    /// Creates a new Linux event provider handle on the default backend.
     #[must_use]
     pub const fn new() -> Self {
-        Self
+        Self::with_backend(LinuxEventBackend::IoUring)
+    }
+
+    /// Creates a readiness-only provider handle.
+    #[must_use]
+    pub const fn epoll() -> Self {
+        Self::with_backend(LinuxEventBackend::Epoll)
+    }
+
+    /// Creates a provider handle on one explicit backend.
+    #[must_use]
+    pub const fn with_backend(backend: LinuxEventBackend) -> Self {
+        Self { backend }
+    }
+
+    /// Returns the backend this provider was asked for.
+    #[must_use]
+    pub const fn backend(&self) -> LinuxEventBackend {
+        self.backend
+    }
+
+    fn completions_enabled(self) -> bool {
+        self.backend == LinuxEventBackend::IoUring && uring::available()
     }
 }
 
@@ -67,7 +125,11 @@ impl EventBaseContract for LinuxEvent {
     type Poller = LinuxPoller;
 
     fn support(&self) -> EventSupport {
-        LINUX_EVENT_SUPPORT
+        if self.completions_enabled() {
+            LINUX_IO_URING_EVENT_SUPPORT
+        } else {
+            LINUX_EVENT_SUPPORT
+        }
     }
 }
 
@@ -77,7 +139,25 @@ impl EventSourceContract for LinuxEvent {
         if epoll_fd < 0 {
             return Err(map_errno(last_errno()));
         }
-        Ok(LinuxPoller { epoll_fd })
+        let mut poller = LinuxPoller {
+            epoll_fd,
+            ring: None,
+        };
+        if self.completions_enabled() {
+            let ring = uring::LinuxRing::new()?;
+            let mut event = libc::epoll_event {
+                events: libc::EPOLLIN as u32,
+                u64: RING_KEY,
+            };
+            let rc = unsafe {
+                libc::epoll_ctl(epoll_fd, libc::EPOLL_CTL_ADD, ring.raw_fd(), &raw mut event)
+            };
+            if rc < 0 {
+                return Err(map_errno(last_errno()));
+            }
+            poller.ring = Some(ring);
+        }
+        Ok(poller)
     }
 
     fn register(
@@ -131,10 +211,16 @@ impl EventSourceContract for LinuxEvent {
 
     fn submit(
         &self,
-        _poller: &mut Self::Poller,
-        _operation: EventCompletionOp,
+        poller: &mut Self::Poller,
+        operation: EventCompletionOp,
     ) -> Result<EventKey, EventError> {
-        Err(EventError::unsupported())
+        let ring = poller.ring.as_mut().ok_or_else(EventError::unsupported)?;
+        let key = EventKey(operation.user_data as u64);
+        if key.0 == RING_KEY {
+            return Err(EventError::invalid());
+        }
+        ring.submit(operation)?;
+        Ok(key)
     }
 
     fn poll(
@@ -147,8 +233,13 @@ impl EventSourceContract for LinuxEvent {
             return Err(EventError::invalid());
         }
 
-        let mut total = 0;
-        let mut timeout_ms = timeout_to_epoll(timeout);
+        // Completions already queued must not wait behind an idle `epoll_wait`.
+        let mut total = poller.drain_completions(events);
+        let mut timeout_ms = if total == 0 {
+            timeout_to_epoll(timeout)
+        } else {
+            0
+        };
         let mut raw = [MaybeUninit::<libc::epoll_event>::uninit(); EPOLL_BATCH];
 
         loop {
@@ -180,16 +271,24 @@ impl EventSourceContract for LinuxEvent {
 
             let ready =
                 usize::try_from(ready).map_err(|_| EventError::platform(libc::EOVERFLOW))?;
-            for index in 0..ready {
-                let raw_event = unsafe { raw[index].assume_init() };
-                events[total + index] = EventRecord {
+            for raw_event in &raw[..ready] {
+                let raw_event = unsafe { raw_event.assume_init() };
+                // Level-triggered sources left over once `events` fills are reported again.
+                if total == events.len() {
+                    break;
+                }
+                if raw_event.u64 == RING_KEY {
+                    total += poller.drain_completions(&mut events[total..]);
+                    continue;
+                }
+                events[total] = EventRecord {
                     key: EventKey(raw_event.u64),
                     notification: EventNotification::Readiness(readiness_from_epoll(
                         raw_event.events,
                     )),
                 };
+                total += 1;
             }
-            total += ready;
 
             if ready < batch_len {
                 return Ok(total);
@@ -200,6 +299,12 @@ impl EventSourceContract for LinuxEvent {
     }
 }
 
+impl LinuxPoller {
+    fn drain_completions(&mut self, events: &mut [EventRecord]) -> usize {
+        self.ring.as_mut().map_or(0, |ring| ring.drain(events))
+    }
+}
+
 impl Drop for LinuxPoller {
     fn drop(&mut self) {
         unsafe {
@@ -306,12 +411,57 @@ fn last_errno() -> libc::c_int {
 #[cfg(all(test, feature = "std", not(target_os = "none")))]
 mod tests {
     use super::*;
+    use crate::contract::pal::runtime::event::{
+        EventCompletion,
+        EventCompletionBuffer,
+        EventCompletionOpKind,
+        EventCompletionTarget,
+        EventErrorKind,
+    };
 
     extern crate std;
 
+    const EMPTY_RECORD: EventRecord = EventRecord {
+        key: EventKey(0),
+        notification: EventNotification::Readiness(EventReadiness::empty()),
+    };
+
+    fn pipe() -> [libc::c_int; 2] {
+        let mut fds = [0; 2];
+        let rc = unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_CLOEXEC) };
+        assert_eq!(rc, 0, "test pipe should create");
+        fds
+    }
+
+    fn source(fd: libc::c_int) -> EventSourceHandle {
+        EventSourceHandle(usize::try_from(fd).expect("fd should be non-negative"))
+    }
+
+    fn completion(event: LinuxEvent, poller: &mut LinuxPoller, key: EventKey) -> EventCompletion {
+        let mut records = [EMPTY_RECORD; 4];
+        let ready = event
+            .poll(poller, &mut records, Some(Duration::from_secs(5)))
+            .expect("poll should succeed");
+        let record = records[..ready]
+            .iter()
+            .find(|record| record.key == key)
+            .expect("submitted operation should complete");
+        match record.notification {
+            EventNotification::Completion(completion) => completion,
+            EventNotification::Readiness(_) => panic!("submission should complete, not ready"),
+        }
+    }
+
+    /// Returns an `io_uring` provider, or `None` when this kernel refuses rings.
+    fn uring_event() -> Option<LinuxEvent> {
+        let event = LinuxEvent::new();
+        (event.support().model == EventModel::Hybrid).then_some(event)
+    }
+
     #[test]
-    fn linux_event_support_is_readiness_native() {
-        let support = system_event().support();
+    fn linux_epoll_support_is_readiness_native() {
+        let event = LinuxEvent::epoll();
+        let support = event.support();
         assert_eq!(support.model, EventModel::Readiness);
         assert_eq!(
             support.implementation,
@@ -321,5 +471,277 @@ mod tests {
         assert!(support.caps.contains(EventCaps::LEVEL_TRIGGERED));
         assert!(support.caps.contains(EventCaps::TIMEOUT));
         assert!(!support.caps.contains(EventCaps::COMPLETION));
+
+        let mut poller = event.create().expect("epoll poller should create");
+        let submit = event.submit(
+            &mut poller,
+            EventCompletionOp {
+                source: EventSourceHandle(0),
+                kind: EventCompletionOpKind::Custom(1),
+                target: EventCompletionTarget::None,
+                user_data: 1,
+            },
+        );
+        assert_eq!(
+            submit.expect_err("epoll should refuse submissions").kind(),
+            EventErrorKind::Unsupported
+        );
+    }
+
+    #[test]
+    fn linux_io_uring_completes_pipe_reads_and_writes() {
+        let Some(event) = uring_event() else {
+            return;
+        };
+        assert!(event.support().caps.contains(EventCaps::SUBMIT));
+        let mut poller = event.create().expect("io_uring poller should create");
+        let [read_fd, write_fd] = pipe();
+        let mut output = *b"ring";
+        let mut input = [0_u8; 8];
+
+        let read = event
+            .submit(
+                &mut poller,
+                EventCompletionOp {
+                    source: source(read_fd),
+                    kind: EventCompletionOpKind::Read,
+                    target: EventCompletionTarget::Buffer {
+                        buffer: unsafe {
+                            EventCompletionBuffer::new(input.as_mut_ptr(), input.len())
+                        },
+                        offset: None,
+                    },
+                    user_data: 10,
+                },
+            )
+            .expect("read should submit");
+        let write = event
+            .submit(
+                &mut poller,
+                EventCompletionOp {
+                    source: source(write_fd),
+                    kind: EventCompletionOpKind::Write,
+                    target: EventCompletionTarget::Buffer {
+                        buffer: unsafe {
+                            EventCompletionBuffer::new(output.as_mut_ptr(), output.len())
+                        },
+                        offset: None,
+                    },
+                    user_data: 11,
+                },
+            )
+            .expect("write should submit");
+
+        let mut seen = [None; 2];
+        while seen.contains(&None) {
+            let mut records = [EMPTY_RECORD; 4];
+            let ready = event
+                .poll(&mut poller, &mut records, Some(Duration::from_secs(5)))
+                .expect("poll should succeed");
+            assert_ne!(ready, 0, "pipe transfer should complete");
+            for record in &records[..ready] {
+                let EventNotification::Completion(completion) = record.notification else {
+                    panic!("pipe transfer should complete, not ready");
+                };
+                let slot = usize::from(record.key == write);
+                seen[slot] = Some(completion.bytes_transferred);
+            }
+        }
+        assert_eq!(read, EventKey(10));
+        assert_eq!(seen, [Some(Some(4)), Some(Some(4))]);
+        assert_eq!(&input[..4], b"ring");
+
+        unsafe {
+            libc::close(read_fd);
+            libc::close(write_fd);
+        }
+    }
+
+    #[test]
+    fn linux_io_uring_reports_timeouts_and_failures() {
+        let Some(event) = uring_event() else {
+            return;
+        };
+        let mut poller = event.create().expect("io_uring poller should create");
+
+        let timeout = event
+            .submit(
+                &mut poller,
+                EventCompletionOp {
+                    source: EventSourceHandle(0),
+                    kind: EventCompletionOpKind::Timeout,
+                    target: EventCompletionTarget::Deadline(Duration::from_millis(5)),
+                    user_data: 20,
+                },
+            )
+            .expect("timeout should submit");
+        let expired = completion(event, &mut poller, timeout);
+        assert!(expired.success);
+        assert_eq!(expired.error, None);
+
+        let mut buffer = [0_u8; 4];
+        let bad = event
+            .submit(
+                &mut poller,
+                EventCompletionOp {
+                    source: EventSourceHandle(usize::try_from(i32::MAX).expect("fd")),
+                    kind: EventCompletionOpKind::Read,
+                    target: EventCompletionTarget::Buffer {
+                        buffer: unsafe {
+                            EventCompletionBuffer::new(buffer.as_mut_ptr(), buffer.len())
+                        },
+                        offset: None,
+                    },
+                    user_data: 21,
+                },
+            )
+            .expect("bad-fd read should still submit");
+        let failed = completion(event, &mut poller, bad);
+        assert!(!failed.success);
+        assert_eq!(failed.error, Some(EventError::invalid()));
+
+        let mismatched = event.submit(
+            &mut poller,
+            EventCompletionOp {
+                source: EventSourceHandle(0),
+                kind: EventCompletionOpKind::Read,
+                target: EventCompletionTarget::None,
+                user_data: 22,
+            },
+        );
+        assert_eq!(
+            mismatched.expect_err("reads need a buffer").kind(),
+            EventErrorKind::Invalid
+        );
+    }
+
+    #[test]
+    fn linux_io_uring_accepts_and_connects_loopback_sockets() {
+        let Some(event) = uring_event() else {
+            return;
+        };
+        let mut poller = event.create().expect("io_uring poller should create");
+
+        let listener =
+            unsafe { libc::socket(libc::AF_INET, libc::SOCK_STREAM | libc::SOCK_CLOEXEC, 0) };
+        assert!(listener >= 0);
+        let mut address = libc::sockaddr_in {
+            sin_family: libc::sa_family_t::try_from(libc::AF_INET).expect("address family"),
+            sin_port: 0,
+            sin_addr: libc::in_addr {
+                s_addr: u32::from_ne_bytes([127, 0, 0, 1]),
+            },
+            sin_zero: [0; 8],
+        };
+        let mut length = libc::socklen_t::try_from(core::mem::size_of::<libc::sockaddr_in>())
+            .expect("address length");
+        unsafe {
+            assert_eq!(libc::bind(listener, (&raw const address).cast(), length), 0);
+            assert_eq!(libc::listen(listener, 1), 0);
+            assert_eq!(
+                libc::getsockname(listener, (&raw mut address).cast(), &raw mut length),
+                0
+            );
+        }
+        let client =
+            unsafe { libc::socket(libc::AF_INET, libc::SOCK_STREAM | libc::SOCK_CLOEXEC, 0) };
+        assert!(client >= 0);
+
+        let accept = event
+            .submit(
+                &mut poller,
+                EventCompletionOp {
+                    source: source(listener),
+                    kind: EventCompletionOpKind::Accept,
+                    target: EventCompletionTarget::None,
+                    user_data: 30,
+                },
+            )
+            .expect("accept should submit");
+        let connect = event
+            .submit(
+                &mut poller,
+                EventCompletionOp {
+                    source: source(client),
+                    kind: EventCompletionOpKind::Connect,
+                    target: EventCompletionTarget::Address(unsafe {
+                        EventCompletionBuffer::new(
+                            (&raw mut address).cast(),
+                            core::mem::size_of::<libc::sockaddr_in>(),
+                        )
+                    }),
+                    user_data: 31,
+                },
+            )
+            .expect("connect should submit");
+
+        let mut accepted = None;
+        let mut connected = false;
+        while accepted.is_none() || !connected {
+            let mut records = [EMPTY_RECORD; 4];
+            let ready = event
+                .poll(&mut poller, &mut records, Some(Duration::from_secs(5)))
+                .expect("poll should succeed");
+            assert_ne!(ready, 0, "loopback handshake should complete");
+            for record in &records[..ready] {
+                let EventNotification::Completion(completion) = record.notification else {
+                    panic!("handshake should complete, not ready");
+                };
+                assert!(completion.success, "{completion:?}");
+                if record.key == accept {
+                    accepted = completion.source;
+                } else if record.key == connect {
+                    connected = true;
+                }
+            }
+        }
+
+        let accepted =
+            libc::c_int::try_from(accepted.expect("accepted socket").0).expect("accepted fd");
+        unsafe {
+            libc::close(accepted);
+            libc::close(client);
+            libc::close(listener);
+        }
+    }
+
+    #[test]
+    fn linux_io_uring_drop_cancels_pending_reads() {
+        let Some(event) = uring_event() else {
+            return;
+        };
+        let [read_fd, write_fd] = pipe();
+        let mut input = [0_u8; 4];
+        {
+            let mut poller = event.create().expect("io_uring poller should create");
+            event
+                .submit(
+                    &mut poller,
+                    EventCompletionOp {
+                        source: source(read_fd),
+                        kind: EventCompletionOpKind::Read,
+                        target: EventCompletionTarget::Buffer {
+                            buffer: unsafe {
+                                EventCompletionBuffer::new(input.as_mut_ptr(), input.len())
+                            },
+                            offset: None,
+                        },
+                        user_data: 40,
+                    },
+                )
+                .expect("read should submit");
+        }
+        // The cancelled read must not consume what arrives after the poller is gone.
+        let rc = unsafe { libc::write(write_fd, b"late".as_ptr().cast(), 4) };
+        assert_eq!(rc, 4);
+        let mut late = [0_u8; 4];
+        let rc = unsafe { libc::read(read_fd, late.as_mut_ptr().cast(), 4) };
+        assert_eq!(rc, 4);
+        assert_eq!(&late, b"late");
+        assert_eq!(input, [0; 4]);
+        unsafe {
+            libc::close(read_fd);
+            libc::close(write_fd);
+        }
     }
 }
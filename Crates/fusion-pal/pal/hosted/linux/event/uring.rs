@@ -0,0 +1,451 @@
+//! `io_uring` rings backing Linux completion submission.
+//!
+//! One ring belongs to one poller. Every submission is pushed and entered immediately, so the
+//! kernel has consumed the entry (and copied any timeout or socket address it points at)
+//! before `submit` returns; only read/write buffers stay borrowed until completion. The ring
+//! fd is registered in the poller's `epoll` set, which lets one `epoll_wait` cover readiness
+//! and completions together.
+//!
+//! Submission-queue `user_data` carries an in-flight slot index rather than the caller's
+//! token, so each completion can be decoded by the operation kind that produced it.
+
+use core::ffi::c_void;
+use core::mem::size_of;
+use core::ptr::{
+    self,
+    NonNull,
+};
+use core::sync::atomic::{
+    AtomicU8,
+    AtomicU32,
+    Ordering,
+};
+use core::time::Duration;
+
+use rustix::fd::{
+    AsFd,
+    AsRawFd,
+    OwnedFd,
+    RawFd,
+};
+use rustix::io::Errno;
+use rustix::io_uring::{
+    IORING_OFF_CQ_RING,
+    IORING_OFF_SQ_RING,
+    IORING_OFF_SQES,
+    IoringAsyncCancelFlags,
+    IoringEnterFlags,
+    IoringOp,
+    SocketFlags,
+    Timespec,
+    io_uring_cqe,
+    io_uring_enter,
+    io_uring_params,
+    io_uring_ptr,
+    io_uring_setup,
+    io_uring_sqe,
+    io_uring_user_data,
+};
+use rustix::mm::{
+    MapFlags,
+    ProtFlags,
+    mmap,
+    munmap,
+};
+
+use super::map_errno;
+use crate::contract::pal::runtime::event::{
+    EventCompletion,
+    EventCompletionOp,
+    EventCompletionOpKind,
+    EventCompletionTarget,
+    EventError,
+    EventKey,
+    EventNotification,
+    EventRecord,
+    EventSourceHandle,
+};
+
+/// Submission-queue depth requested from the kernel; also the in-flight operation limit.
+const RING_ENTRIES: u32 = 128;
+const SLOT_COUNT: usize = RING_ENTRIES as usize;
+/// `user_data` of the cancel-everything entry submitted on drop.
+const CANCEL_TAG: u64 = u64::MAX;
+
+const PROBE_UNKNOWN: u8 = 0;
+const PROBE_AVAILABLE: u8 = 1;
+const PROBE_UNAVAILABLE: u8 = 2;
+
+/// Returns whether this kernel lets the process create rings, probing once.
+///
+/// Kernels before 5.1, `kernel.io_uring_disabled`, and seccomp sandboxes all refuse
+/// `io_uring_setup`; the result is cached for the life of the process.
+pub(super) fn available() -> bool {
+    static PROBE: AtomicU8 = AtomicU8::new(PROBE_UNKNOWN);
+
+    match PROBE.load(Ordering::Relaxed) {
+        PROBE_AVAILABLE => true,
+        PROBE_UNAVAILABLE => false,
+        _ => {
+            let mut params = io_uring_params::default();
+            let available = unsafe { io_uring_setup(1, &mut params) }.is_ok();
+            PROBE.store(
+                if available {
+                    PROBE_AVAILABLE
+                } else {
+                    PROBE_UNAVAILABLE
+                },
+                Ordering::Relaxed,
+            );
+            available
+        }
+    }
+}
+
+/// One shared mapping of ring memory.
+#[derive(Debug)]
+struct Mapping {
+    base: NonNull<c_void>,
+    len: usize,
+}
+
+impl Mapping {
+    fn map(fd: &OwnedFd, len: usize, offset: u64) -> Result<Self, EventError> {
+        let base = unsafe {
+            mmap(
+                ptr::null_mut(),
+                len,
+                ProtFlags::READ | ProtFlags::WRITE,
+                MapFlags::SHARED | MapFlags::POPULATE,
+                fd,
+                offset,
+            )
+        }
+        .map_err(errno_error)?;
+        let base = NonNull::new(base).ok_or_else(EventError::resource_exhausted)?;
+        Ok(Self { base, len })
+    }
+
+    /// Returns a pointer `offset` bytes into the mapping.
+    const fn at<T>(&self, offset: u32) -> *mut T {
+        unsafe { self.base.as_ptr().cast::<u8>().add(offset as usize).cast() }
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        let _ = unsafe { munmap(self.base.as_ptr(), self.len) };
+    }
+}
+
+/// Bookkeeping for one in-flight operation.
+#[derive(Debug, Clone, Copy)]
+struct Slot {
+    user_data: usize,
+    kind: EventCompletionOpKind,
+    next_free: Option<u16>,
+}
+
+/// One `io_uring` instance with its rings mapped.
+#[derive(Debug)]
+pub(super) struct LinuxRing {
+    sq_head: *const AtomicU32,
+    sq_tail: *const AtomicU32,
+    sq_mask: u32,
+    sq_entries: u32,
+    sq_array: *mut u32,
+    sqes: *mut io_uring_sqe,
+    cq_head: *const AtomicU32,
+    cq_tail: *const AtomicU32,
+    cq_mask: u32,
+    cqes: *const io_uring_cqe,
+    slots: [Slot; SLOT_COUNT],
+    free: Option<u16>,
+    in_flight: usize,
+    // Mappings before the fd: they must be unmapped before the ring is closed.
+    _sq_ring: Mapping,
+    _cq_ring: Mapping,
+    _sqe_array: Mapping,
+    fd: OwnedFd,
+}
+
+// SAFETY: the raw pointers address the ring's own mappings, which move with it; every access
+// goes through `&mut self`.
+#[allow(clippy::non_send_fields_in_send_ty)]
+unsafe impl Send for LinuxRing {}
+
+impl LinuxRing {
+    /// Creates and maps one ring.
+    pub(super) fn new() -> Result<Self, EventError> {
+        let mut params = io_uring_params::default();
+        let fd = unsafe { io_uring_setup(RING_ENTRIES, &mut params) }.map_err(errno_error)?;
+        let sq_len = params.sq_off.array as usize + params.sq_entries as usize * size_of::<u32>();
+        let cq_len =
+            params.cq_off.cqes as usize + params.cq_entries as usize * size_of::<io_uring_cqe>();
+        let entries_len = params.sq_entries as usize * size_of::<io_uring_sqe>();
+        let sq_ring = Mapping::map(&fd, sq_len, IORING_OFF_SQ_RING)?;
+        let cq_ring = Mapping::map(&fd, cq_len, IORING_OFF_CQ_RING)?;
+        let sqe_array = Mapping::map(&fd, entries_len, IORING_OFF_SQES)?;
+
+        let mut slots = [Slot {
+            user_data: 0,
+            kind: EventCompletionOpKind::Custom(0),
+            next_free: None,
+        }; SLOT_COUNT];
+        for (index, slot) in slots.iter_mut().enumerate().skip(1) {
+            slot.next_free = u16::try_from(index - 1).ok();
+        }
+
+        Ok(Self {
+            sq_head: sq_ring.at(params.sq_off.head),
+            sq_tail: sq_ring.at(params.sq_off.tail),
+            sq_mask: unsafe { *sq_ring.at::<u32>(params.sq_off.ring_mask) },
+            sq_entries: params.sq_entries,
+            sq_array: sq_ring.at(params.sq_off.array),
+            sqes: sqe_array.at(0),
+            cq_head: cq_ring.at(params.cq_off.head),
+            cq_tail: cq_ring.at(params.cq_off.tail),
+            cq_mask: unsafe { *cq_ring.at::<u32>(params.cq_off.ring_mask) },
+            cqes: cq_ring.at(params.cq_off.cqes),
+            slots,
+            free: u16::try_from(SLOT_COUNT - 1).ok(),
+            in_flight: 0,
+            _sq_ring: sq_ring,
+            _cq_ring: cq_ring,
+            _sqe_array: sqe_array,
+            fd,
+        })
+    }
+
+    /// Returns the ring fd, which polls readable while completions are pending.
+    pub(super) fn raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+
+    /// Prepares, queues, and enters one operation.
+    pub(super) fn submit(&mut self, operation: EventCompletionOp) -> Result<(), EventError> {
+        let Some(slot) = self.free else {
+            return Err(EventError::busy());
+        };
+        // Timeouts point at this; the kernel copies it while the entry is entered below.
+        let mut timespec = Timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        let mut sqe = prepare(operation, &mut timespec)?;
+        sqe.user_data = io_uring_user_data::from_u64(u64::from(slot));
+        self.enter_one(sqe)?;
+
+        let entry = &mut self.slots[usize::from(slot)];
+        self.free = entry.next_free;
+        entry.user_data = operation.user_data;
+        entry.kind = operation.kind;
+        self.in_flight += 1;
+        Ok(())
+    }
+
+    /// Moves ready completions into `events`, returning how many were written.
+    ///
+    /// Completions that do not fit stay queued; the ring fd keeps polling readable for them.
+    pub(super) fn drain(&mut self, events: &mut [EventRecord]) -> usize {
+        let mut written = 0;
+        while written < events.len() {
+            let Some((tag, result)) = self.pop() else {
+                break;
+            };
+            if let Some(record) = self.complete(tag, result) {
+                events[written] = record;
+                written += 1;
+            }
+        }
+        written
+    }
+
+    fn enter_one(&mut self, sqe: io_uring_sqe) -> Result<(), EventError> {
+        let tail = unsafe { (*self.sq_tail).load(Ordering::Relaxed) };
+        let head = unsafe { (*self.sq_head).load(Ordering::Acquire) };
+        if tail.wrapping_sub(head) >= self.sq_entries {
+            return Err(EventError::busy());
+        }
+        let index = tail & self.sq_mask;
+        unsafe {
+            self.sqes.add(index as usize).write(sqe);
+            self.sq_array.add(index as usize).write(index);
+            (*self.sq_tail).store(tail.wrapping_add(1), Ordering::Release);
+        }
+
+        loop {
+            match unsafe { io_uring_enter(self.fd.as_fd(), 1, 0, IoringEnterFlags::empty()) } {
+                Ok(1) => return Ok(()),
+                Err(Errno::INTR) => {}
+                result => {
+                    // Without SQPOLL the kernel only consumes entries inside `io_uring_enter`,
+                    // so an entry it refused can be withdrawn.
+                    unsafe { (*self.sq_tail).store(tail, Ordering::Release) };
+                    return Err(result.map_or_else(errno_error, |_| EventError::busy()));
+                }
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<(u64, i32)> {
+        let head = unsafe { (*self.cq_head).load(Ordering::Relaxed) };
+        let tail = unsafe { (*self.cq_tail).load(Ordering::Acquire) };
+        if head == tail {
+            return None;
+        }
+        let cqe = unsafe { self.cqes.add((head & self.cq_mask) as usize) };
+        let entry = unsafe { ((*cqe).user_data.u64_(), (*cqe).res) };
+        unsafe { (*self.cq_head).store(head.wrapping_add(1), Ordering::Release) };
+        Some(entry)
+    }
+
+    fn complete(&mut self, tag: u64, result: i32) -> Option<EventRecord> {
+        let index = u16::try_from(tag).ok()?;
+        let slot = self.slots.get_mut(usize::from(index))?;
+        slot.next_free = self.free;
+        self.free = Some(index);
+        self.in_flight -= 1;
+        Some(completion_record(slot.user_data, slot.kind, result))
+    }
+}
+
+impl Drop for LinuxRing {
+    fn drop(&mut self) {
+        if self.in_flight == 0 {
+            return;
+        }
+        // Closing the ring does not stop the kernel from finishing reads into caller buffers,
+        // so cancel everything and wait the cancellations out before unmapping.
+        let mut cancel = io_uring_sqe {
+            opcode: IoringOp::AsyncCancel,
+            fd: -1,
+            user_data: io_uring_user_data::from_u64(CANCEL_TAG),
+            ..io_uring_sqe::default()
+        };
+        cancel.op_flags.cancel_flags = IoringAsyncCancelFlags::ANY;
+        if self.enter_one(cancel).is_err() {
+            return;
+        }
+        while self.in_flight != 0 {
+            let Some((tag, result)) = self.pop() else {
+                match unsafe { io_uring_enter(self.fd.as_fd(), 0, 1, IoringEnterFlags::GETEVENTS) }
+                {
+                    Ok(_) | Err(Errno::INTR) => continue,
+                    Err(_) => return,
+                }
+            };
+            // `ENOENT` means nothing was cancellable; anything else means the kernel cannot
+            // cancel by "any", and waiting could block forever.
+            if tag == CANCEL_TAG && result < 0 && result != -libc::ENOENT {
+                return;
+            }
+            // Nobody will poll this completion, so nobody else can close what it accepted.
+            if let Some(EventRecord {
+                notification:
+                    EventNotification::Completion(EventCompletion {
+                        source: Some(source),
+                        ..
+                    }),
+                ..
+            }) = self.complete(tag, result)
+                && let Ok(fd) = fd_from_source(source)
+            {
+                unsafe { libc::close(fd) };
+            }
+        }
+    }
+}
+
+/// Builds the submission entry for one operation, minus its `user_data`.
+fn prepare(
+    operation: EventCompletionOp,
+    timespec: &mut Timespec,
+) -> Result<io_uring_sqe, EventError> {
+    let mut sqe = io_uring_sqe::default();
+    match (operation.kind, operation.target) {
+        (
+            kind @ (EventCompletionOpKind::Read | EventCompletionOpKind::Write),
+            EventCompletionTarget::Buffer { buffer, offset },
+        ) => {
+            sqe.opcode = if kind == EventCompletionOpKind::Read {
+                IoringOp::Read
+            } else {
+                IoringOp::Write
+            };
+            sqe.fd = fd_from_source(operation.source)?;
+            sqe.addr_or_splice_off_in.addr = io_uring_ptr::new(buffer.as_ptr().cast());
+            sqe.len.len = u32::try_from(buffer.len()).map_err(|_| EventError::invalid())?;
+            // `-1` reads or writes at the file position, and is the only choice for streams.
+            sqe.off_or_addr2.off = offset.unwrap_or(u64::MAX);
+        }
+        (EventCompletionOpKind::Accept, EventCompletionTarget::None) => {
+            sqe.opcode = IoringOp::Accept;
+            sqe.fd = fd_from_source(operation.source)?;
+            sqe.op_flags.accept_flags = SocketFlags::CLOEXEC;
+        }
+        (EventCompletionOpKind::Connect, EventCompletionTarget::Address(address)) => {
+            sqe.opcode = IoringOp::Connect;
+            sqe.fd = fd_from_source(operation.source)?;
+            sqe.addr_or_splice_off_in.addr = io_uring_ptr::new(address.as_ptr().cast());
+            sqe.off_or_addr2.off = address.len() as u64;
+        }
+        (EventCompletionOpKind::Timeout, EventCompletionTarget::Deadline(deadline)) => {
+            *timespec = timespec_from_duration(deadline);
+            sqe.opcode = IoringOp::Timeout;
+            sqe.fd = -1;
+            sqe.addr_or_splice_off_in.addr = io_uring_ptr::new(ptr::from_mut(timespec).cast());
+            sqe.len.len = 1;
+        }
+        (EventCompletionOpKind::Custom(_), EventCompletionTarget::None) => {
+            sqe.opcode = IoringOp::Nop;
+            sqe.fd = -1;
+        }
+        _ => return Err(EventError::invalid()),
+    }
+    Ok(sqe)
+}
+
+fn completion_record(user_data: usize, kind: EventCompletionOpKind, result: i32) -> EventRecord {
+    let mut completion = EventCompletion {
+        bytes_transferred: None,
+        success: result >= 0,
+        source: None,
+        error: None,
+    };
+    match usize::try_from(result) {
+        Ok(value) => match kind {
+            EventCompletionOpKind::Read | EventCompletionOpKind::Write => {
+                completion.bytes_transferred = Some(value);
+            }
+            EventCompletionOpKind::Accept => completion.source = Some(EventSourceHandle(value)),
+            EventCompletionOpKind::Connect
+            | EventCompletionOpKind::Timeout
+            | EventCompletionOpKind::Custom(_) => {}
+        },
+        // An expired timeout is the timeout succeeding.
+        Err(_) if kind == EventCompletionOpKind::Timeout && result == -libc::ETIME => {
+            completion.success = true;
+        }
+        Err(_) => completion.error = Some(map_errno(-result)),
+    }
+    EventRecord {
+        key: EventKey(user_data as u64),
+        notification: EventNotification::Completion(completion),
+    }
+}
+
+fn fd_from_source(source: EventSourceHandle) -> Result<RawFd, EventError> {
+    RawFd::try_from(source.0).map_err(|_| EventError::invalid())
+}
+
+fn timespec_from_duration(duration: Duration) -> Timespec {
+    Timespec {
+        tv_sec: i64::try_from(duration.as_secs()).unwrap_or(i64::MAX),
+        tv_nsec: i64::from(duration.subsec_nanos()),
+    }
+}
+
+const fn errno_error(errno: Errno) -> EventError {
+    map_errno(errno.raw_os_error())
+}
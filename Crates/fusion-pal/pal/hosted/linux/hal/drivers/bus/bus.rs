@@ -0,0 +1,2 @@
+#[path = "pci/pci.rs"]
+pub mod pci;
@@ -0,0 +1,580 @@
+//! Linux sysfs PCI substrate.
+//!
+//! Hosted Linux has no config-space window to map, but the kernel publishes every function it
+//! enumerated under `/sys/bus/pci/devices/DDDD:BB:DD.F`:
+//!
+//! - `config` exposes configuration space (the full 4 KiB to root, the first 64 bytes to
+//!   everyone else),
+//! - `resource` lists the kernel's view of each BAR, the option ROM, and bridge windows as
+//!   `start end flags` triples.
+//!
+//! This backend surfaces each PCI domain as one provider. Headers and capability lists go
+//! through the shared ECAM decoder over the `config` file; BARs and the ROM come from
+//! `resource`, since sizing them by hand would mean writing to live devices behind their
+//! drivers' backs. The backend is inspection-only: config writes are refused.
+//!
+//! The device directory is chosen by one [`LinuxSysfsPciRoot`], so tests can point the backend
+//! at a captured sysfs tree instead of the running machine. Each root is scanned once, on first
+//! use, and the snapshot is kept for the life of the process.
+
+use core::marker::PhantomData;
+use core::slice;
+use std::collections::BTreeMap;
+use std::fs::{
+    self,
+    File,
+};
+use std::io;
+use std::os::unix::fs::FileExt;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use std::sync::Mutex;
+
+use fd_bus_pci::interface::backend::ecam::{
+    PCI_ECAM_FUNCTION_SPAN,
+    PCI_ECAM_MAX_BARS,
+    PciConfigAccess,
+    PciEcamFunction,
+};
+use fd_bus_pci::interface::contract::{
+    PciHardware,
+    PciHardwareFunction,
+};
+use fusion_hal::contract::drivers::bus::pci::{
+    PciBarDescriptor,
+    PciBarKind,
+    PciBridgeWindow,
+    PciBus,
+    PciCapabilityRecord,
+    PciConfigOffset,
+    PciControllerDescriptor,
+    PciDevice,
+    PciDmaProfile,
+    PciError,
+    PciErrorReportingProfile,
+    PciExpressProfile,
+    PciExtendedCapabilityRecord,
+    PciFunction,
+    PciFunctionAddress,
+    PciFunctionIdentity,
+    PciFunctionProfile,
+    PciHeaderType,
+    PciHotplugProfile,
+    PciImplementationKind,
+    PciInterruptProfile,
+    PciPowerProfile,
+    PciRomDescriptor,
+    PciSegment,
+    PciSegmentDescriptor,
+    PciSupport,
+    PciTopologyProfile,
+    PciVirtualizationProfile,
+};
+
+/// Device directory the running kernel publishes.
+pub const LINUX_SYSFS_PCI_DEVICES: &str = "/sys/bus/pci/devices";
+
+const REG_HEADER_TYPE: u64 = 0x0e;
+const REG_SECONDARY_BUS: u64 = 0x19;
+
+// `include/linux/ioport.h` resource flags, as printed in `resource`.
+const IORESOURCE_IO: u64 = 0x0000_0100;
+const IORESOURCE_MEM: u64 = 0x0000_0200;
+const IORESOURCE_PREFETCH: u64 = 0x0000_2000;
+const IORESOURCE_MEM_64: u64 = 0x0010_0000;
+const IORESOURCE_ROM_ENABLE: u64 = 0x0000_0001;
+/// `resource` line holding the option ROM (`PCI_ROM_RESOURCE`).
+const RESOURCE_ROM_LINE: usize = 6;
+
+/// Source of the sysfs device directory one [`LinuxSysfsPciHardware`] reads.
+pub trait LinuxSysfsPciRoot: 'static {
+    /// Returns the directory holding one entry per function, named `DDDD:BB:DD.F`.
+    fn devices() -> &'static Path;
+}
+
+/// The running kernel's device directory.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinuxSystemPciRoot;
+
+impl LinuxSysfsPciRoot for LinuxSystemPciRoot {
+    fn devices() -> &'static Path {
+        Path::new(LINUX_SYSFS_PCI_DEVICES)
+    }
+}
+
+/// PCI hardware substrate over one sysfs device directory.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinuxSysfsPciHardware<R = LinuxSystemPciRoot> {
+    marker: PhantomData<fn() -> R>,
+}
+
+/// Target-selected Linux PCI hardware substrate.
+pub type PlatformPciHardware = LinuxSysfsPciHardware;
+
+/// One scanned PCI domain.
+#[derive(Debug)]
+struct LinuxPciDomain {
+    controller: PciControllerDescriptor,
+    segment: PciSegmentDescriptor,
+    functions: Vec<(PciFunctionAddress, PathBuf)>,
+}
+
+/// One scanned sysfs device directory.
+#[derive(Debug)]
+struct LinuxPciTopology {
+    root: PathBuf,
+    domains: Vec<LinuxPciDomain>,
+}
+
+impl<R> LinuxSysfsPciHardware<R>
+where
+    R: LinuxSysfsPciRoot,
+{
+    fn domain(provider: u8) -> Option<&'static LinuxPciDomain> {
+        topology(R::devices()).domains.get(usize::from(provider))
+    }
+}
+
+impl<R> PciHardware for LinuxSysfsPciHardware<R>
+where
+    R: LinuxSysfsPciRoot,
+{
+    type Function = LinuxSysfsPciFunction;
+
+    fn provider_count() -> u8 {
+        u8::try_from(topology(R::devices()).domains.len()).unwrap_or(u8::MAX)
+    }
+
+    fn controller(provider: u8) -> Option<&'static PciControllerDescriptor> {
+        Self::domain(provider).map(|domain| &domain.controller)
+    }
+
+    fn support(provider: u8) -> PciSupport {
+        if Self::domain(provider).is_none() {
+            return PciSupport::unsupported();
+        }
+        PciSupport {
+            implementation: PciImplementationKind::Hardware,
+            pcie: true,
+            interrupts: true,
+            dma: true,
+            power_management: true,
+            error_reporting: true,
+            virtualization: true,
+            hotplug: true,
+        }
+    }
+
+    fn segments(provider: u8) -> &'static [PciSegmentDescriptor] {
+        Self::domain(provider).map_or(&[], |domain| slice::from_ref(&domain.segment))
+    }
+
+    fn enumerate_functions(
+        provider: u8,
+        out: &mut [PciFunctionAddress],
+    ) -> Result<usize, PciError> {
+        let domain = Self::domain(provider).ok_or_else(PciError::not_present)?;
+        let out = out
+            .get_mut(..domain.functions.len())
+            .ok_or_else(PciError::resource_exhausted)?;
+        for (slot, (address, _)) in out.iter_mut().zip(&domain.functions) {
+            *slot = *address;
+        }
+        Ok(domain.functions.len())
+    }
+
+    fn function(
+        provider: u8,
+        address: PciFunctionAddress,
+    ) -> Result<Option<Self::Function>, PciError> {
+        let domain = Self::domain(provider).ok_or_else(PciError::not_present)?;
+        if address.segment != domain.segment.segment {
+            return Err(PciError::invalid());
+        }
+        let Some((_, path)) = domain
+            .functions
+            .iter()
+            .find(|(candidate, _)| *candidate == address)
+        else {
+            return Ok(None);
+        };
+        let parent = find_parent(domain, address.bus)?;
+        LinuxSysfsPciFunction::open(path, address, parent).map(Some)
+    }
+}
+
+/// Read-only accessor over one function's sysfs `config` file.
+///
+/// The accessor spans one function's 4 KiB configuration space starting at offset 0. Bytes
+/// the kernel does not expose to the caller read back as all-ones, as absent config space does
+/// on real hardware.
+#[derive(Debug)]
+pub struct LinuxSysfsConfig {
+    file: File,
+}
+
+impl LinuxSysfsConfig {
+    /// Opens one function's `config` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns one honest error when the file cannot be opened.
+    pub fn open(path: &Path) -> Result<Self, PciError> {
+        File::open(path.join("config"))
+            .map(|file| Self { file })
+            .map_err(|error| io_error(&error))
+    }
+
+    fn read<const N: usize>(&self, offset: u64) -> Result<[u8; N], PciError> {
+        let end = offset.checked_add(N as u64).ok_or_else(PciError::invalid)?;
+        if end > PCI_ECAM_FUNCTION_SPAN {
+            return Err(PciError::invalid());
+        }
+        let mut bytes = [0xff; N];
+        let mut filled = 0;
+        while filled < N {
+            match self
+                .file
+                .read_at(&mut bytes[filled..], offset + filled as u64)
+            {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(io_error(&error)),
+            }
+        }
+        Ok(bytes)
+    }
+}
+
+impl PciConfigAccess for LinuxSysfsConfig {
+    fn window_len(&self) -> u64 {
+        PCI_ECAM_FUNCTION_SPAN
+    }
+
+    fn read_u8(&self, offset: u64) -> Result<u8, PciError> {
+        self.read::<1>(offset).map(u8::from_le_bytes)
+    }
+
+    fn read_u16(&self, offset: u64) -> Result<u16, PciError> {
+        self.read::<2>(offset).map(u16::from_le_bytes)
+    }
+
+    fn read_u32(&self, offset: u64) -> Result<u32, PciError> {
+        self.read::<4>(offset).map(u32::from_le_bytes)
+    }
+
+    fn write_u8(&self, _offset: u64, _value: u8) -> Result<(), PciError> {
+        Err(PciError::unsupported())
+    }
+
+    fn write_u16(&self, _offset: u64, _value: u16) -> Result<(), PciError> {
+        Err(PciError::unsupported())
+    }
+
+    fn write_u32(&self, _offset: u64, _value: u32) -> Result<(), PciError> {
+        Err(PciError::unsupported())
+    }
+}
+
+/// One function opened through sysfs.
+#[derive(Debug)]
+pub struct LinuxSysfsPciFunction {
+    config: PciEcamFunction<LinuxSysfsConfig>,
+    bars: [PciBarDescriptor; PCI_ECAM_MAX_BARS],
+    bar_count: usize,
+    option_rom: Option<PciRomDescriptor>,
+}
+
+impl LinuxSysfsPciFunction {
+    fn open(
+        path: &Path,
+        address: PciFunctionAddress,
+        parent: Option<PciFunctionAddress>,
+    ) -> Result<Self, PciError> {
+        let config =
+            PciEcamFunction::from_config(LinuxSysfsConfig::open(path)?, 0, address, parent)?;
+        let mut function = Self {
+            bars: [PciBarDescriptor {
+                index: 0,
+                kind: PciBarKind::Memory32,
+                base: 0,
+                size: 0,
+                prefetchable: false,
+                implemented: false,
+            }; PCI_ECAM_MAX_BARS],
+            bar_count: 0,
+            option_rom: config.option_rom(),
+            config,
+        };
+        // Without `resource` the decoder's unsized view of the BAR registers is all there is.
+        match fs::read_to_string(path.join("resource")) {
+            Ok(resource) => function.apply_resources(&resource)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                let bars = function.config.bars();
+                function.bars[..bars.len()].copy_from_slice(bars);
+                function.bar_count = bars.len();
+            }
+            Err(error) => return Err(io_error(&error)),
+        }
+        Ok(function)
+    }
+
+    fn apply_resources(&mut self, resource: &str) -> Result<(), PciError> {
+        let mut lines = [(0_u64, 0_u64, 0_u64); RESOURCE_ROM_LINE + 1];
+        for (line, text) in lines.iter_mut().zip(resource.lines()) {
+            *line = parse_resource_line(text).ok_or_else(PciError::invalid)?;
+        }
+        let slots = match self.config.profile().header_type {
+            PciHeaderType::Type0 => 6,
+            PciHeaderType::Type1 => 2,
+            PciHeaderType::Type2 | PciHeaderType::Other(_) => 0,
+        };
+
+        let mut index = 0_u8;
+        while index < slots {
+            let (start, end, flags) = lines[usize::from(index)];
+            let implemented = flags & (IORESOURCE_IO | IORESOURCE_MEM) != 0 && end > start;
+            let kind = if flags & IORESOURCE_IO != 0 {
+                PciBarKind::Io
+            } else if flags & IORESOURCE_MEM_64 != 0 {
+                PciBarKind::Memory64
+            } else {
+                PciBarKind::Memory32
+            };
+            self.bars[self.bar_count] = PciBarDescriptor {
+                index,
+                kind,
+                base: start,
+                size: if implemented { end - start + 1 } else { 0 },
+                prefetchable: flags & IORESOURCE_PREFETCH != 0,
+                implemented,
+            };
+            self.bar_count += 1;
+            index += if kind == PciBarKind::Memory64 { 2 } else { 1 };
+        }
+
+        let (start, end, flags) = lines[RESOURCE_ROM_LINE];
+        self.option_rom = (slots != 0 && flags & IORESOURCE_MEM != 0 && end > start).then_some(
+            PciRomDescriptor {
+                base: start,
+                size: end - start + 1,
+                enabled: flags & IORESOURCE_ROM_ENABLE != 0,
+            },
+        );
+        Ok(())
+    }
+}
+
+impl PciHardwareFunction for LinuxSysfsPciFunction {
+    fn address(&self) -> PciFunctionAddress {
+        self.config.address()
+    }
+
+    fn identity(&self) -> PciFunctionIdentity {
+        self.config.identity()
+    }
+
+    fn profile(&self) -> PciFunctionProfile {
+        self.config.profile()
+    }
+
+    fn bars(&self) -> &[PciBarDescriptor] {
+        &self.bars[..self.bar_count]
+    }
+
+    fn bridge_windows(&self) -> &[PciBridgeWindow] {
+        self.config.bridge_windows()
+    }
+
+    fn option_rom(&self) -> Option<PciRomDescriptor> {
+        self.option_rom
+    }
+
+    fn capabilities(&self) -> &[PciCapabilityRecord] {
+        self.config.capabilities()
+    }
+
+    fn extended_capabilities(&self) -> &[PciExtendedCapabilityRecord] {
+        self.config.extended_capabilities()
+    }
+
+    fn topology_profile(&self) -> PciTopologyProfile {
+        self.config.topology_profile()
+    }
+
+    fn interrupt_profile(&self) -> PciInterruptProfile {
+        self.config.interrupt_profile()
+    }
+
+    fn dma_profile(&self) -> PciDmaProfile {
+        self.config.dma_profile()
+    }
+
+    fn power_profile(&self) -> PciPowerProfile {
+        self.config.power_profile()
+    }
+
+    fn error_reporting_profile(&self) -> PciErrorReportingProfile {
+        self.config.error_reporting_profile()
+    }
+
+    fn virtualization_profile(&self) -> PciVirtualizationProfile {
+        self.config.virtualization_profile()
+    }
+
+    fn hotplug_profile(&self) -> Option<PciHotplugProfile> {
+        self.config.hotplug_profile()
+    }
+
+    fn pcie_profile(&self) -> Option<PciExpressProfile> {
+        self.config.pcie_profile()
+    }
+
+    fn read_config_u8(&self, offset: PciConfigOffset) -> Result<u8, PciError> {
+        self.config.read_config_u8(offset)
+    }
+
+    fn read_config_u16(&self, offset: PciConfigOffset) -> Result<u16, PciError> {
+        self.config.read_config_u16(offset)
+    }
+
+    fn read_config_u32(&self, offset: PciConfigOffset) -> Result<u32, PciError> {
+        self.config.read_config_u32(offset)
+    }
+
+    fn write_config_u8(&mut self, offset: PciConfigOffset, value: u8) -> Result<(), PciError> {
+        self.config.write_config_u8(offset, value)
+    }
+
+    fn write_config_u16(&mut self, offset: PciConfigOffset, value: u16) -> Result<(), PciError> {
+        self.config.write_config_u16(offset, value)
+    }
+
+    fn write_config_u32(&mut self, offset: PciConfigOffset, value: u32) -> Result<(), PciError> {
+        self.config.write_config_u32(offset, value)
+    }
+}
+
+/// Returns the cached scan of one device directory, scanning it on first use.
+fn topology(root: &Path) -> &'static LinuxPciTopology {
+    static TOPOLOGIES: Mutex<Vec<&'static LinuxPciTopology>> = Mutex::new(Vec::new());
+
+    let mut topologies = TOPOLOGIES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(topology) = topologies.iter().find(|topology| topology.root == root) {
+        return topology;
+    }
+    // One leak per distinct root: the contract hands out `'static` descriptors.
+    let topology: &'static LinuxPciTopology = Box::leak(Box::new(scan(root)));
+    topologies.push(topology);
+    topology
+}
+
+/// Returns the interned controller id for one segment.
+fn controller_id(segment: u16) -> &'static str {
+    static IDS: Mutex<BTreeMap<u16, &'static str>> = Mutex::new(BTreeMap::new());
+
+    let mut ids = IDS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    // One leak per distinct segment, however many roots or scans mention it.
+    ids.entry(segment)
+        .or_insert_with(|| Box::leak(format!("linux-sysfs-pci-{segment:04x}").into_boxed_str()))
+}
+
+fn scan(root: &Path) -> LinuxPciTopology {
+    let mut domains = BTreeMap::<u16, Vec<(PciFunctionAddress, PathBuf)>>::new();
+    for entry in fs::read_dir(root).into_iter().flatten().flatten() {
+        if let Some(address) = entry.file_name().to_str().and_then(parse_address) {
+            domains
+                .entry(address.segment.0)
+                .or_default()
+                .push((address, entry.path()));
+        }
+    }
+    LinuxPciTopology {
+        root: root.to_path_buf(),
+        domains: domains
+            .into_iter()
+            .map(|(segment, mut functions)| {
+                functions
+                    .sort_by_key(|(address, _)| (address.bus, address.device, address.function));
+                let buses = functions.iter().map(|(address, _)| address.bus);
+                let start_bus = buses.clone().min().unwrap_or(PciBus(0));
+                let end_bus = buses.max().unwrap_or(PciBus(0));
+                LinuxPciDomain {
+                    controller: PciControllerDescriptor {
+                        id: controller_id(segment),
+                        name: "Linux sysfs PCI domain",
+                    },
+                    segment: PciSegmentDescriptor {
+                        segment: PciSegment(segment),
+                        start_bus,
+                        end_bus,
+                    },
+                    functions,
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Parses one `DDDD:BB:DD.F` sysfs entry name.
+fn parse_address(name: &str) -> Option<PciFunctionAddress> {
+    let (segment, rest) = name.split_once(':')?;
+    let (bus, rest) = rest.split_once(':')?;
+    let (device, function) = rest.split_once('.')?;
+    Some(PciFunctionAddress {
+        segment: PciSegment(u16::from_str_radix(segment, 16).ok()?),
+        bus: PciBus(u8::from_str_radix(bus, 16).ok()?),
+        device: PciDevice::from_u8(u8::from_str_radix(device, 16).ok()?)?,
+        function: PciFunction::from_u8(u8::from_str_radix(function, 16).ok()?)?,
+    })
+}
+
+/// Parses one `0xSTART 0xEND 0xFLAGS` line from `resource`.
+fn parse_resource_line(line: &str) -> Option<(u64, u64, u64)> {
+    let mut fields = line
+        .split_whitespace()
+        .map(|field| u64::from_str_radix(field.strip_prefix("0x")?, 16).ok());
+    let parsed = (fields.next()??, fields.next()??, fields.next()??);
+    fields.next().is_none().then_some(parsed)
+}
+
+/// Finds the bridge whose secondary bus is `bus`, reading each candidate's header live.
+fn find_parent(
+    domain: &LinuxPciDomain,
+    bus: PciBus,
+) -> Result<Option<PciFunctionAddress>, PciError> {
+    for (candidate, path) in &domain.functions {
+        if candidate.bus >= bus {
+            continue;
+        }
+        let config = LinuxSysfsConfig::open(path)?;
+        let bridge = matches!(
+            PciHeaderType::from_u8(config.read_u8(REG_HEADER_TYPE)?),
+            PciHeaderType::Type1 | PciHeaderType::Type2
+        );
+        if bridge && config.read_u8(REG_SECONDARY_BUS)? == bus.0 {
+            return Ok(Some(*candidate));
+        }
+    }
+    Ok(None)
+}
+
+fn io_error(error: &io::Error) -> PciError {
+    match error.kind() {
+        io::ErrorKind::NotFound => PciError::not_present(),
+        io::ErrorKind::PermissionDenied => PciError::unsupported(),
+        _ => error
+            .raw_os_error()
+            .map_or_else(PciError::fault, PciError::platform),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,351 @@
+use core::fmt::Write as _;
+use std::fs;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use std::process;
+use std::sync::{
+    Mutex,
+    OnceLock,
+};
+
+use fusion_hal::contract::drivers::bus::pci::{
+    PciCapabilityId,
+    PciErrorKind,
+    PciExtendedCapabilityId,
+    PciTransportFamily,
+};
+
+use super::*;
+
+const fn address(segment: u16, bus: u8, device: u8, function: u8) -> PciFunctionAddress {
+    match (PciDevice::from_u8(device), PciFunction::from_u8(function)) {
+        (Some(device), Some(function)) => PciFunctionAddress {
+            segment: PciSegment(segment),
+            bus: PciBus(bus),
+            device,
+            function,
+        },
+        _ => panic!("invalid test pci address"),
+    }
+}
+
+/// One captured function directory: `config` bytes plus `resource` text.
+struct FixtureFunction {
+    config: Vec<u8>,
+    resource: Option<String>,
+}
+
+impl FixtureFunction {
+    fn new(len: usize, vendor: u16, device: u16, class: [u8; 3], header: u8) -> Self {
+        let mut config = vec![0; len];
+        config[0x00..0x02].copy_from_slice(&vendor.to_le_bytes());
+        config[0x02..0x04].copy_from_slice(&device.to_le_bytes());
+        config[0x09..0x0c].copy_from_slice(&class);
+        config[0x0e] = header;
+        Self {
+            config,
+            resource: None,
+        }
+    }
+
+    fn put8(mut self, register: usize, value: u8) -> Self {
+        self.config[register] = value;
+        self
+    }
+
+    fn put16(mut self, register: usize, value: u16) -> Self {
+        self.config[register..register + 2].copy_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn put32(mut self, register: usize, value: u32) -> Self {
+        self.config[register..register + 4].copy_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn resources(mut self, lines: &[(u64, u64, u64)]) -> Self {
+        let mut resource = String::new();
+        for (start, end, flags) in lines {
+            let _ = writeln!(resource, "0x{start:016x} 0x{end:016x} 0x{flags:016x}");
+        }
+        self.resource = Some(resource);
+        self
+    }
+
+    fn write(&self, root: &Path, name: &str) {
+        let directory = root.join(name);
+        fs::create_dir_all(&directory).expect("fixture function directory");
+        fs::write(directory.join("config"), &self.config).expect("fixture config");
+        if let Some(resource) = &self.resource {
+            fs::write(directory.join("resource"), resource).expect("fixture resource");
+        }
+    }
+}
+
+const EMPTY: (u64, u64, u64) = (0, 0, 0);
+
+/// Captured tree: a host bridge and a root port on domain 0 with one endpoint behind the
+/// port, plus one endpoint alone on domain 1.
+fn write_fixture(root: &Path) {
+    let _ = fs::remove_dir_all(root);
+    fs::create_dir_all(root).expect("fixture root");
+    fs::write(root.join("not-a-function"), b"").expect("fixture noise");
+
+    FixtureFunction::new(256, 0x8086, 0x29c0, [0x00, 0x00, 0x06], 0x00)
+        .resources(&[EMPTY; 13])
+        .write(root, "0000:00:00.0");
+
+    // Root port: PCIe capability at 0x40, secondary/subordinate bus 1.
+    FixtureFunction::new(4096, 0x1b36, 0x000c, [0x00, 0x04, 0x06], 0x01)
+        .put16(0x06, 0x0010)
+        .put8(0x34, 0x40)
+        .put8(0x18, 0x00)
+        .put8(0x19, 0x01)
+        .put8(0x1a, 0x01)
+        .put16(0x40, 0x0010)
+        .put16(0x42, 0x0042)
+        .resources(&[EMPTY; 17])
+        .write(root, "0000:00:01.0");
+
+    // Endpoint: power management then PCIe capability, AER in extended space.
+    let mut resources = [EMPTY; 13];
+    resources[0] = (0xfe00_0000, 0xfe00_0fff, 0x0004_0200);
+    resources[2] = (0x80_0000_0000, 0x80_0000_3fff, 0x0014_220c);
+    resources[4] = (0xc000, 0xc01f, 0x0004_0101);
+    resources[6] = (0xfeb8_0000, 0xfebb_ffff, 0x0004_6201);
+    FixtureFunction::new(4096, 0x1af4, 0x1041, [0x00, 0x00, 0x02], 0x00)
+        .put16(0x06, 0x0010)
+        .put8(0x34, 0x40)
+        .put16(0x40, 0x5001)
+        .put16(0x50, 0x0010)
+        .put16(0x52, 0x0002)
+        .put32(0x100, 0x0002_0001)
+        .resources(&resources)
+        .write(root, "0000:01:00.0");
+
+    // Unprivileged capture: only the first 64 bytes of config space, no `resource`.
+    FixtureFunction::new(64, 0x10ec, 0x8168, [0x00, 0x00, 0x02], 0x00)
+        .put32(0x10, 0x0000_e001)
+        .write(root, "0001:02:00.0");
+}
+
+fn fixture_root() -> &'static Path {
+    static ROOT: OnceLock<PathBuf> = OnceLock::new();
+    ROOT.get_or_init(|| {
+        std::env::temp_dir().join(format!("fusion-pal-sysfs-pci-{}", process::id()))
+    })
+}
+
+/// Keeps the captured tree on disk while at least one test holds it, and removes it after.
+struct Fixture;
+
+static FIXTURE_USERS: Mutex<usize> = Mutex::new(0);
+
+impl Fixture {
+    fn hold() -> Self {
+        let mut users = FIXTURE_USERS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if *users == 0 {
+            write_fixture(fixture_root());
+        }
+        *users += 1;
+        Self
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let mut users = FIXTURE_USERS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        *users -= 1;
+        if *users == 0 {
+            let _ = fs::remove_dir_all(fixture_root());
+        }
+    }
+}
+
+struct FixtureRoot;
+
+impl LinuxSysfsPciRoot for FixtureRoot {
+    fn devices() -> &'static Path {
+        fixture_root()
+    }
+}
+
+type FixtureHardware = LinuxSysfsPciHardware<FixtureRoot>;
+
+fn open(provider: u8, address: PciFunctionAddress) -> LinuxSysfsPciFunction {
+    FixtureHardware::function(provider, address)
+        .expect("open function")
+        .expect("function present")
+}
+
+#[test]
+fn enumerates_one_provider_per_domain() {
+    let _fixture = Fixture::hold();
+    assert_eq!(FixtureHardware::provider_count(), 2);
+    let controller = FixtureHardware::controller(1).expect("controller");
+    assert_eq!(controller.id, "linux-sysfs-pci-0001");
+
+    let segment = FixtureHardware::segments(0)[0];
+    assert_eq!(segment.segment, PciSegment(0));
+    assert_eq!((segment.start_bus, segment.end_bus), (PciBus(0), PciBus(1)));
+
+    let mut functions = [address(0, 0, 0, 0); 4];
+    let count = FixtureHardware::enumerate_functions(0, &mut functions).expect("enumerate");
+    assert_eq!(
+        &functions[..count],
+        &[
+            address(0, 0, 0, 0),
+            address(0, 0, 1, 0),
+            address(0, 1, 0, 0)
+        ]
+    );
+    let mut short = [address(0, 0, 0, 0); 2];
+    assert_eq!(
+        FixtureHardware::enumerate_functions(0, &mut short)
+            .expect_err("short buffer")
+            .kind(),
+        PciErrorKind::ResourceExhausted
+    );
+    assert_eq!(
+        FixtureHardware::enumerate_functions(1, &mut functions).expect("enumerate"),
+        1
+    );
+    assert!(
+        FixtureHardware::function(0, address(0, 0, 2, 0))
+            .expect("absent function")
+            .is_none()
+    );
+    assert_eq!(
+        FixtureHardware::function(0, address(1, 2, 0, 0))
+            .expect_err("foreign segment")
+            .kind(),
+        PciErrorKind::Invalid
+    );
+}
+
+#[test]
+fn reports_bars_and_rom_from_resource() {
+    let _fixture = Fixture::hold();
+    let endpoint = open(0, address(0, 1, 0, 0));
+    assert_eq!(endpoint.identity().vendor_id.0, 0x1af4);
+    assert_eq!(
+        endpoint.topology_profile().parent,
+        Some(address(0, 0, 1, 0))
+    );
+
+    let bars = endpoint.bars();
+    assert_eq!(bars.len(), 5);
+    assert_eq!(
+        (bars[0].kind, bars[0].base, bars[0].size),
+        (PciBarKind::Memory32, 0xfe00_0000, 0x1000)
+    );
+    assert!(!bars[1].implemented);
+    assert_eq!(
+        (
+            bars[2].index,
+            bars[2].kind,
+            bars[2].size,
+            bars[2].prefetchable
+        ),
+        (2, PciBarKind::Memory64, 0x4000, true)
+    );
+    assert_eq!(
+        (bars[3].index, bars[3].kind, bars[3].base, bars[3].size),
+        (4, PciBarKind::Io, 0xc000, 0x20)
+    );
+    assert!(!bars[4].implemented);
+
+    let rom = endpoint.option_rom().expect("option rom");
+    assert_eq!(
+        (rom.base, rom.size, rom.enabled),
+        (0xfeb8_0000, 0x4_0000, true)
+    );
+
+    let port = open(0, address(0, 0, 1, 0));
+    assert_eq!(port.bars().len(), 2);
+    assert!(port.option_rom().is_none());
+    assert_eq!(port.topology_profile().secondary_bus, Some(PciBus(1)));
+    assert_eq!(port.topology_profile().parent, None);
+}
+
+#[test]
+fn surfaces_capabilities_through_config() {
+    let _fixture = Fixture::hold();
+    let endpoint = open(0, address(0, 1, 0, 0));
+    assert_eq!(
+        endpoint.profile().transport_family,
+        PciTransportFamily::PciExpress
+    );
+    let capabilities: Vec<_> = endpoint
+        .capabilities()
+        .iter()
+        .map(|capability| capability.id)
+        .collect();
+    assert_eq!(
+        capabilities,
+        [
+            PciCapabilityId::PowerManagement,
+            PciCapabilityId::PciExpress
+        ]
+    );
+    let extended = endpoint.extended_capabilities();
+    assert_eq!(extended.len(), 1);
+    assert_eq!(
+        extended[0].id,
+        PciExtendedCapabilityId::AdvancedErrorReporting
+    );
+    assert_eq!(extended[0].version, 2);
+    assert_eq!(
+        endpoint
+            .read_config_u32(PciConfigOffset(0x100))
+            .expect("extended read"),
+        0x0002_0001
+    );
+}
+
+#[test]
+fn truncated_captures_read_all_ones_and_refuse_writes() {
+    let _fixture = Fixture::hold();
+    let mut function = open(1, address(1, 2, 0, 0));
+    assert_eq!(
+        function
+            .read_config_u32(PciConfigOffset(0x40))
+            .expect("truncated read"),
+        u32::MAX
+    );
+    // No `resource` file: BARs come from the registers, unsized.
+    assert_eq!(function.bars()[0].kind, PciBarKind::Io);
+    assert_eq!(function.bars()[0].base, 0xe000);
+    assert_eq!(
+        function
+            .write_config_u16(PciConfigOffset(0x04), 0x0006)
+            .expect_err("inspection only")
+            .kind(),
+        PciErrorKind::Unsupported
+    );
+}
+
+#[test]
+fn opens_every_function_on_the_running_kernel() {
+    if !Path::new(LINUX_SYSFS_PCI_DEVICES).is_dir() {
+        return;
+    }
+    for provider in 0..PlatformPciHardware::provider_count() {
+        let mut functions = [address(0, 0, 0, 0); 256];
+        let count = PlatformPciHardware::enumerate_functions(provider, &mut functions)
+            .expect("enumerate host functions");
+        for address in &functions[..count] {
+            let function = PlatformPciHardware::function(provider, *address)
+                .expect("open host function")
+                .expect("host function present");
+            assert_eq!(function.address(), *address);
+            assert_ne!(function.identity().vendor_id.0, 0xffff);
+        }
+    }
+}
@@ -0,0 +1,2 @@
+#[path = "bus/bus.rs"]
+pub mod bus;
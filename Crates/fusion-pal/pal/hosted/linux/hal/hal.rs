@@ -55,6 +55,11 @@ use crate::contract::pal::{
     HardwareWriteSummary,
 };
 
+#[cfg(feature = "fd-bus-pci")]
+#[path = "drivers/drivers.rs"]
+/// Linux hosted driver substrates.
+pub mod drivers;
+
 /// Selected Linux hardware provider type.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct LinuxHardware;
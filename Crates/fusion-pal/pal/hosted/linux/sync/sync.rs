@@ -11,6 +11,7 @@
 //! memory backend.
 
 use core::convert::TryFrom;
+use core::mem::MaybeUninit;
 use core::sync::atomic::{
     AtomicU32,
     Ordering,
@@ -74,7 +75,7 @@ const LINUX_SEMAPHORE_SUPPORT: SemaphoreSupport = SemaphoreSupport {
     caps: SemaphoreCaps::TRY_ACQUIRE
         .union(SemaphoreCaps::BLOCKING)
         .union(SemaphoreCaps::RELEASE_MANY),
-    timeout: TimeoutCaps::empty(),
+    timeout: TimeoutCaps::RELATIVE.union(TimeoutCaps::RELATIVE_MONOTONIC),
     process_scope: ProcessScopeSupport::LocalOnly,
     implementation: SyncImplementationKind::Emulated,
     fallback: SyncFallbackKind::None,
@@ -411,6 +412,31 @@ impl RawSemaphoreContract for LinuxSemaphore {
         Ok(false)
     }
 
+    fn acquire_for(&self, timeout: Duration) -> Result<bool, SyncError> {
+        let deadline = monotonic_now()?
+            .checked_add(timeout)
+            .ok_or_else(SyncError::overflow)?;
+        loop {
+            if self.try_acquire()? {
+                return Ok(true);
+            }
+            // `FUTEX_WAIT` timeouts are relative, so every retry re-derives the remaining budget
+            // from the monotonic deadline rather than restarting the full timeout.
+            let Some(remaining) = deadline
+                .checked_sub(monotonic_now()?)
+                .filter(|remaining| !remaining.is_zero())
+            else {
+                return self.try_acquire();
+            };
+            match futex_wait_private(&self.permits, 0, Some(remaining))? {
+                AtomicWaitOutcome::TimedOut => return self.try_acquire(),
+                AtomicWaitOutcome::Woken
+                | AtomicWaitOutcome::Mismatch
+                | AtomicWaitOutcome::Interrupted => {}
+            }
+        }
+    }
+
     fn release(&self, permits: u32) -> Result<(), SyncError> {
         if permits == 0 {
             return Err(SyncError::invalid());
@@ -654,6 +680,22 @@ fn futex_wait_private(
     }
 }
 
+fn monotonic_now() -> Result<Duration, SyncError> {
+    let mut current = MaybeUninit::<libc::timespec>::uninit();
+    // SAFETY: `current` is valid writable storage for one `timespec`.
+    let rc = unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, current.as_mut_ptr()) };
+    if rc != 0 {
+        return Err(map_errno(Errno::from_raw_os_error(unsafe {
+            *libc::__errno_location()
+        })));
+    }
+    // SAFETY: `clock_gettime` succeeded, so it initialized `current`.
+    let current = unsafe { current.assume_init() };
+    let secs = u64::try_from(current.tv_sec).map_err(|_| SyncError::overflow())?;
+    let nanos = u32::try_from(current.tv_nsec).map_err(|_| SyncError::overflow())?;
+    Ok(Duration::new(secs, nanos))
+}
+
 fn duration_to_timespec(timeout: Option<Duration>) -> Result<Option<futex::Timespec>, SyncError> {
     timeout
         .map(|duration| {
@@ -54,6 +54,14 @@ pub const fn system_event() -> PlatformEvent {
     PlatformEvent::new()
 }
 
+/// Returns the event provider for consumers that only wait for readiness.
+///
+/// macOS has one event surface, so this is the same provider as [`system_event`].
+#[must_use]
+pub const fn system_readiness_event() -> PlatformEvent {
+    PlatformEvent::new()
+}
+
 impl MacOsEvent {
     /// Creates a new macOS event provider handle.
     #[must_use]
@@ -402,6 +410,7 @@ mod tests {
                 EventCompletionOp {
                     source: EventSourceHandle(0),
                     kind: crate::contract::pal::runtime::event::EventCompletionOpKind::Read,
+                    target: crate::contract::pal::runtime::event::EventCompletionTarget::None,
                     user_data: 0,
                 },
             )
@@ -20,11 +20,19 @@ use crate::contract::drivers::pcu::{
     PcuSupport,
     PcuTransactionSubmission,
 };
+use crate::pal::hosted::pcu_interpreter::{
+    HostedCpuCommandHandle,
+    HostedCpuDispatchHandle,
+    HostedCpuSignalHandle,
+    HostedCpuTransactionHandle,
+    install_host_cpu_signal,
+    submit_host_cpu_command,
+    submit_host_cpu_dispatch,
+    submit_host_cpu_transaction,
+};
 use crate::pal::hosted::pcu_shared::{
     HOST_CPU_EXECUTOR_ID,
     HostedCpuStreamHandle,
-    HostedCpuUnsupportedFiniteHandle,
-    HostedCpuUnsupportedPersistentHandle,
     host_cpu_executor_descriptor,
     host_pcu_support,
     install_host_cpu_stream,
@@ -66,36 +74,36 @@ impl PcuBaseContract for MacOsPcu {
 }
 
 impl PcuDirectDispatchBackend for MacOsPcu {
-    type DispatchHandle = HostedCpuUnsupportedFiniteHandle;
-    type CommandHandle = HostedCpuUnsupportedFiniteHandle;
-    type TransactionHandle = HostedCpuUnsupportedFiniteHandle;
+    type DispatchHandle = HostedCpuDispatchHandle;
+    type CommandHandle = HostedCpuCommandHandle;
+    type TransactionHandle = HostedCpuTransactionHandle;
     type StreamHandle = HostedCpuStreamHandle;
-    type SignalHandle = HostedCpuUnsupportedPersistentHandle;
+    type SignalHandle = HostedCpuSignalHandle;
 
     fn submit_dispatch_direct(
         &self,
-        _submission: crate::contract::drivers::pcu::PcuDispatchSubmission<'_>,
-        _bindings: PcuInvocationBindings<'_>,
-        _parameters: PcuInvocationParameters<'_>,
+        submission: crate::contract::drivers::pcu::PcuDispatchSubmission<'_>,
+        bindings: PcuInvocationBindings<'_>,
+        parameters: PcuInvocationParameters<'_>,
     ) -> Result<Self::DispatchHandle, PcuError> {
-        Err(PcuError::unsupported())
+        submit_host_cpu_dispatch(submission, bindings, parameters)
     }
 
     fn submit_command_direct(
         &self,
-        _submission: PcuCommandSubmission<'_>,
-        _parameters: PcuInvocationParameters<'_>,
+        submission: PcuCommandSubmission<'_>,
+        parameters: PcuInvocationParameters<'_>,
     ) -> Result<Self::CommandHandle, PcuError> {
-        Err(PcuError::unsupported())
+        submit_host_cpu_command(submission, parameters)
     }
 
     fn submit_transaction_direct(
         &self,
-        _submission: PcuTransactionSubmission<'_>,
-        _bindings: PcuInvocationBindings<'_>,
-        _parameters: PcuInvocationParameters<'_>,
+        submission: PcuTransactionSubmission<'_>,
+        bindings: PcuInvocationBindings<'_>,
+        parameters: PcuInvocationParameters<'_>,
     ) -> Result<Self::TransactionHandle, PcuError> {
-        Err(PcuError::unsupported())
+        submit_host_cpu_transaction(submission, bindings, parameters)
     }
 
     fn install_stream_direct(
@@ -109,10 +117,10 @@ impl PcuDirectDispatchBackend for MacOsPcu {
 
     fn install_signal_direct(
         &self,
-        _installation: PcuSignalInstallation<'_>,
-        _parameters: PcuInvocationParameters<'_>,
+        installation: PcuSignalInstallation<'_>,
+        parameters: PcuInvocationParameters<'_>,
     ) -> Result<Self::SignalHandle, PcuError> {
-        Err(PcuError::unsupported())
+        install_host_cpu_signal(installation, parameters)
     }
 }
 
@@ -0,0 +1,2188 @@
+//! Hosted CPU reference interpreter for PCU dispatch, command, transaction, and signal kernels.
+//!
+//! The interpreter is the desktop correctness oracle for the PCU execution models. Every kernel
+//! runs synchronously on the submitting thread: finite handles are already complete when they are
+//! returned, and installed signal handlers run on whoever calls [`HostedCpuSignalHandle::trigger`].
+//! Serial execution is one valid schedule for every invocation model the contract surfaces today,
+//! since indexed invocations are independent and unordered while command, transaction, and signal
+//! kernels are serial and in-order.
+//!
+//! Because nothing else can touch a command kernel's machine while it runs, `Await` could never
+//! see its predicate change, so it is not advertised. `Stall` and `Sleep` advance the handle's
+//! virtual tick count instead of blocking the submitting thread.
+//!
+//! Dispatch ops carry no operands, so the interpreter fixes one reference convention:
+//! - invocations run in linear order over the logical shape, each with its own operand stack
+//! - `Constant` pushes the next declared kernel parameter, in declaration order
+//! - `Load` pushes element `invocation` of the next readable binding; builtin bindings push their
+//!   builtin value instead, with the whole dispatch forming one group
+//! - `Store` pops one value into element `invocation` of the next writable binding
+//! - binary arithmetic pops `rhs` then `lhs` and pushes `lhs op rhs`, wrapping for integers
+//! - `Compare` pushes whether `lhs == rhs`, and `Select` pops `condition`, `on_false`, then
+//!   `on_true`
+//! - `Return` retires the invocation
+//! - `Barrier` and `Fence` are no-ops because no invocation can observe another's element
+//!
+//! Transactions carry no body, so one transaction copies each read-only binding into the writable
+//! binding at the same position. Atomic transactions refuse length mismatches before copying
+//! anything, best-effort transactions copy the common prefix, and exclusive transactions exclude
+//! every other hosted transaction while they run.
+//!
+//! The dispatch contract borrows invocation bindings immutably, so writable bindings are
+//! materialized inside the returned handle rather than in the caller's output slices. One PCU tick
+//! of transaction lock timeout is one microsecond of host time.
+
+use std::sync::{
+    PoisonError,
+    RwLock,
+    RwLockReadGuard,
+    RwLockWriteGuard,
+    TryLockError,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use crate::contract::drivers::pcu::{
+    PcuBinding,
+    PcuBindingAccess,
+    PcuBindingRef,
+    PcuBuiltinValue,
+    PcuCommandModifyOp,
+    PcuCommandOp,
+    PcuCommandSubmission,
+    PcuDispatchAluOp,
+    PcuDispatchControlOp,
+    PcuDispatchOp,
+    PcuDispatchResourceOp,
+    PcuDispatchSubmission,
+    PcuDispatchSyncOp,
+    PcuDispatchValueOp,
+    PcuError,
+    PcuFiniteHandle,
+    PcuFiniteState,
+    PcuInvocationBinding,
+    PcuInvocationBindings,
+    PcuInvocationBuffer,
+    PcuInvocationParameters,
+    PcuInvocationTarget,
+    PcuOperand,
+    PcuParameterBinding,
+    PcuParameterSlot,
+    PcuParameterValue,
+    PcuPersistentHandle,
+    PcuPersistentState,
+    PcuPort,
+    PcuPortDirection,
+    PcuScalarType,
+    PcuSignalInstallation,
+    PcuSignalOp,
+    PcuSignalTriggerKind,
+    PcuTarget,
+    PcuTransactionAtomicity,
+    PcuTransactionExclusivity,
+    PcuTransactionSubmission,
+    PcuValueType,
+};
+use crate::pal::hosted::pcu_shared::{
+    HOST_CPU_COMMAND_DIRECT_SUPPORT,
+    HOST_CPU_DISPATCH_DIRECT_SUPPORT,
+    HOST_CPU_SIGNAL_DIRECT_SUPPORT,
+    HOST_CPU_TRANSACTION_DIRECT_SUPPORT,
+};
+
+const HOSTED_CPU_MAX_OPERAND_STACK: usize = 16;
+const HOST_CPU_TICK: Duration = Duration::from_micros(1);
+
+static HOST_CPU_TRANSACTION_LOCK: RwLock<()> = RwLock::new(());
+
+/// Element width of one hosted CPU binding buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HostedCpuElementWidth {
+    Byte,
+    HalfWord,
+    Word,
+}
+
+/// Borrowed read-only elements of one caller-supplied binding buffer.
+#[derive(Debug, Clone, Copy)]
+enum HostedCpuElements<'a> {
+    Bytes(&'a [u8]),
+    HalfWords(&'a [u16]),
+    Words(&'a [u32]),
+}
+
+impl HostedCpuElements<'_> {
+    const fn width(self) -> HostedCpuElementWidth {
+        match self {
+            Self::Bytes(_) => HostedCpuElementWidth::Byte,
+            Self::HalfWords(_) => HostedCpuElementWidth::HalfWord,
+            Self::Words(_) => HostedCpuElementWidth::Word,
+        }
+    }
+
+    const fn len(self) -> usize {
+        match self {
+            Self::Bytes(elements) => elements.len(),
+            Self::HalfWords(elements) => elements.len(),
+            Self::Words(elements) => elements.len(),
+        }
+    }
+
+    fn get(self, index: usize) -> Option<u32> {
+        match self {
+            Self::Bytes(elements) => elements.get(index).copied().map(u32::from),
+            Self::HalfWords(elements) => elements.get(index).copied().map(u32::from),
+            Self::Words(elements) => elements.get(index).copied(),
+        }
+    }
+}
+
+/// Buffer materialized by one hosted CPU submission for one writable binding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostedCpuBuffer {
+    Bytes(Vec<u8>),
+    HalfWords(Vec<u16>),
+    Words(Vec<u32>),
+}
+
+impl HostedCpuBuffer {
+    fn zeroed(width: HostedCpuElementWidth, len: usize) -> Self {
+        match width {
+            HostedCpuElementWidth::Byte => Self::Bytes(vec![0; len]),
+            HostedCpuElementWidth::HalfWord => Self::HalfWords(vec![0; len]),
+            HostedCpuElementWidth::Word => Self::Words(vec![0; len]),
+        }
+    }
+
+    /// Returns the number of elements in this buffer.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        match self {
+            Self::Bytes(elements) => elements.len(),
+            Self::HalfWords(elements) => elements.len(),
+            Self::Words(elements) => elements.len(),
+        }
+    }
+
+    /// Returns whether this buffer holds no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get(&self, index: usize) -> Option<u32> {
+        match self {
+            Self::Bytes(elements) => elements.get(index).copied().map(u32::from),
+            Self::HalfWords(elements) => elements.get(index).copied().map(u32::from),
+            Self::Words(elements) => elements.get(index).copied(),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn set(&mut self, index: usize, bits: u32) -> Option<()> {
+        match self {
+            Self::Bytes(elements) => *elements.get_mut(index)? = bits as u8,
+            Self::HalfWords(elements) => *elements.get_mut(index)? = bits as u16,
+            Self::Words(elements) => *elements.get_mut(index)? = bits,
+        }
+        Some(())
+    }
+
+    fn copy_prefix(&mut self, source: HostedCpuElements<'_>) -> usize {
+        let count = self.len().min(source.len());
+        let mut index = 0;
+        while index < count {
+            if let Some(bits) = source.get(index) {
+                let _ = self.set(index, bits);
+            }
+            index += 1;
+        }
+        count
+    }
+}
+
+/// Writable bindings materialized by one hosted CPU submission.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HostedCpuOutputs {
+    entries: Vec<(PcuBindingRef, HostedCpuBuffer)>,
+}
+
+impl HostedCpuOutputs {
+    /// Returns the materialized buffer for one writable binding.
+    #[must_use]
+    pub fn buffer(&self, reference: PcuBindingRef) -> Option<&HostedCpuBuffer> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| *entry == reference)
+            .map(|(_, buffer)| buffer)
+    }
+
+    /// Returns the materialized bytes for one writable byte binding.
+    #[must_use]
+    pub fn bytes(&self, reference: PcuBindingRef) -> Option<&[u8]> {
+        match self.buffer(reference)? {
+            HostedCpuBuffer::Bytes(elements) => Some(elements),
+            HostedCpuBuffer::HalfWords(_) | HostedCpuBuffer::Words(_) => None,
+        }
+    }
+
+    /// Returns the materialized half-words for one writable half-word binding.
+    #[must_use]
+    pub fn half_words(&self, reference: PcuBindingRef) -> Option<&[u16]> {
+        match self.buffer(reference)? {
+            HostedCpuBuffer::HalfWords(elements) => Some(elements),
+            HostedCpuBuffer::Bytes(_) | HostedCpuBuffer::Words(_) => None,
+        }
+    }
+
+    /// Returns the materialized words for one writable word binding.
+    #[must_use]
+    pub fn words(&self, reference: PcuBindingRef) -> Option<&[u32]> {
+        match self.buffer(reference)? {
+            HostedCpuBuffer::Words(elements) => Some(elements),
+            HostedCpuBuffer::Bytes(_) | HostedCpuBuffer::HalfWords(_) => None,
+        }
+    }
+}
+
+/// Completed hosted CPU dispatch submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostedCpuDispatchHandle {
+    outputs: HostedCpuOutputs,
+    invocations: u32,
+}
+
+impl HostedCpuDispatchHandle {
+    /// Returns the writable bindings produced by this dispatch.
+    #[must_use]
+    pub const fn outputs(&self) -> &HostedCpuOutputs {
+        &self.outputs
+    }
+
+    /// Returns how many logical invocations ran.
+    #[must_use]
+    pub const fn invocations(&self) -> u32 {
+        self.invocations
+    }
+}
+
+impl PcuFiniteHandle for HostedCpuDispatchHandle {
+    fn state(&self) -> Result<PcuFiniteState, PcuError> {
+        Ok(PcuFiniteState::Complete)
+    }
+
+    fn wait(self) -> Result<(), PcuError> {
+        Ok(())
+    }
+}
+
+/// Completed hosted CPU command submission.
+#[derive(Debug, Clone)]
+pub struct HostedCpuCommandHandle {
+    machine: HostedCpuMachine,
+    result: Option<PcuParameterValue>,
+    elapsed_ticks: u64,
+}
+
+impl HostedCpuCommandHandle {
+    /// Returns the value produced by the kernel's `Return` step, if it returned one.
+    #[must_use]
+    pub const fn result(&self) -> Option<PcuParameterValue> {
+        self.result
+    }
+
+    /// Returns the final value held by one command target.
+    #[must_use]
+    pub fn register(&self, target: PcuTarget<'_>) -> Option<PcuParameterValue> {
+        self.machine.register(target)
+    }
+
+    /// Returns how many ticks the kernel stalled or slept for.
+    #[must_use]
+    pub const fn elapsed_ticks(&self) -> u64 {
+        self.elapsed_ticks
+    }
+}
+
+impl PcuFiniteHandle for HostedCpuCommandHandle {
+    fn state(&self) -> Result<PcuFiniteState, PcuError> {
+        Ok(PcuFiniteState::Complete)
+    }
+
+    fn wait(self) -> Result<(), PcuError> {
+        Ok(())
+    }
+}
+
+/// Completed hosted CPU transaction submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostedCpuTransactionHandle {
+    outputs: HostedCpuOutputs,
+    transferred: usize,
+}
+
+impl HostedCpuTransactionHandle {
+    /// Returns the writable bindings produced by this transaction.
+    #[must_use]
+    pub const fn outputs(&self) -> &HostedCpuOutputs {
+        &self.outputs
+    }
+
+    /// Returns how many elements the transaction copied across every binding pair.
+    #[must_use]
+    pub const fn transferred(&self) -> usize {
+        self.transferred
+    }
+}
+
+impl PcuFiniteHandle for HostedCpuTransactionHandle {
+    fn state(&self) -> Result<PcuFiniteState, PcuError> {
+        Ok(PcuFiniteState::Complete)
+    }
+
+    fn wait(self) -> Result<(), PcuError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HostedCpuSignalState {
+    Dormant,
+    Active,
+    Stopped,
+}
+
+/// Installed hosted CPU signal handler.
+#[derive(Debug, Clone)]
+pub struct HostedCpuSignalHandle {
+    trigger: PcuSignalTriggerKind,
+    state: HostedCpuSignalState,
+    ops: Vec<HostedCpuSignalOp>,
+    machine: HostedCpuMachine,
+    published: Vec<(Box<str>, PcuParameterValue)>,
+    notifications: Vec<(HostedCpuTargetKey, u32)>,
+    acknowledged: u32,
+    triggered: u32,
+}
+
+impl HostedCpuSignalHandle {
+    /// Returns the trigger kind this handler was installed for.
+    #[must_use]
+    pub const fn trigger_kind(&self) -> PcuSignalTriggerKind {
+        self.trigger
+    }
+
+    /// Delivers one trigger and runs the handler ops to completion.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StateConflict` when the handler is not active, and `Invalid` when one op reads an
+    /// unwritten target or writes a value of the wrong type.
+    pub fn trigger(&mut self) -> Result<(), PcuError> {
+        if self.state != HostedCpuSignalState::Active {
+            return Err(PcuError::state_conflict());
+        }
+        self.triggered = self.triggered.saturating_add(1);
+        self.machine.previous = None;
+
+        let mut index = 0;
+        while index < self.ops.len() {
+            match &self.ops[index] {
+                HostedCpuSignalOp::Ack => {
+                    self.acknowledged = self.acknowledged.saturating_add(1);
+                }
+                HostedCpuSignalOp::Read { target } => {
+                    let value = self.machine.read(target.as_ref())?;
+                    self.machine.previous = Some(value);
+                }
+                HostedCpuSignalOp::Write { target, value } => {
+                    let value = self.machine.resolve(value.as_ref())?;
+                    self.machine.write(target.as_ref(), value)?;
+                    self.machine.previous = Some(value);
+                }
+                HostedCpuSignalOp::Publish { port, value } => {
+                    let value = self.machine.resolve(value.as_ref())?;
+                    if self.machine.port_type(port) != Some(value.value_type()) {
+                        return Err(PcuError::invalid());
+                    }
+                    self.published.push((port.clone(), value));
+                    self.machine.previous = Some(value);
+                }
+                HostedCpuSignalOp::Notify { target } => {
+                    match self
+                        .notifications
+                        .iter_mut()
+                        .find(|(existing, _)| existing == target)
+                    {
+                        Some((_, count)) => *count = count.saturating_add(1),
+                        None => self.notifications.push((target.clone(), 1)),
+                    }
+                }
+            }
+            index += 1;
+        }
+        Ok(())
+    }
+
+    /// Returns how many triggers this handler has run.
+    #[must_use]
+    pub const fn trigger_count(&self) -> u32 {
+        self.triggered
+    }
+
+    /// Returns how many `Ack` ops this handler has executed.
+    #[must_use]
+    pub const fn acknowledged(&self) -> u32 {
+        self.acknowledged
+    }
+
+    /// Returns how many times this handler notified one target.
+    #[must_use]
+    pub fn notifications(&self, target: PcuTarget<'_>) -> u32 {
+        let Ok(target) = HostedCpuTargetRef::from_target(target) else {
+            return 0;
+        };
+        self.notifications
+            .iter()
+            .find(|(existing, _)| existing.as_ref() == target)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Takes the oldest value this handler published to one port.
+    pub fn take_published(&mut self, port: &str) -> Option<PcuParameterValue> {
+        let index = self
+            .published
+            .iter()
+            .position(|(published, _)| &**published == port)?;
+        Some(self.published.remove(index).1)
+    }
+
+    /// Returns the current value held by one handler target.
+    #[must_use]
+    pub fn register(&self, target: PcuTarget<'_>) -> Option<PcuParameterValue> {
+        self.machine.register(target)
+    }
+}
+
+impl PcuPersistentHandle for HostedCpuSignalHandle {
+    fn state(&self) -> Result<PcuPersistentState, PcuError> {
+        Ok(match self.state {
+            HostedCpuSignalState::Dormant => PcuPersistentState::Dormant,
+            HostedCpuSignalState::Active => PcuPersistentState::Active,
+            HostedCpuSignalState::Stopped => PcuPersistentState::Stopped,
+        })
+    }
+
+    fn start(&mut self) -> Result<(), PcuError> {
+        if self.state == HostedCpuSignalState::Active {
+            return Err(PcuError::state_conflict());
+        }
+        self.state = HostedCpuSignalState::Active;
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<(), PcuError> {
+        if self.state != HostedCpuSignalState::Active {
+            return Err(PcuError::state_conflict());
+        }
+        self.state = HostedCpuSignalState::Stopped;
+        Ok(())
+    }
+
+    fn uninstall(mut self) -> Result<(), PcuError> {
+        if self.state == HostedCpuSignalState::Active {
+            self.state = HostedCpuSignalState::Stopped;
+        }
+        Ok(())
+    }
+}
+
+/// Runs one dispatch kernel to completion on the calling thread.
+///
+/// # Errors
+///
+/// Returns `Unsupported` for ops, bindings, or ports outside the hosted interpreter's direct
+/// support, and `Invalid` when the shape, parameters, bindings, or operand stack do not line up
+/// with the kernel.
+pub fn submit_host_cpu_dispatch(
+    submission: PcuDispatchSubmission<'_>,
+    bindings: PcuInvocationBindings<'_>,
+    parameters: PcuInvocationParameters<'_>,
+) -> Result<HostedCpuDispatchHandle, PcuError> {
+    let kernel = submission.kernel;
+    if !HOST_CPU_DISPATCH_DIRECT_SUPPORT.contains(kernel.required_instruction_support()) {
+        return Err(PcuError::unsupported());
+    }
+    if !parameters.validate_against(kernel.parameters) {
+        return Err(PcuError::invalid());
+    }
+
+    let invocations = submission.shape.thread_count().get();
+    let expected = kernel
+        .entry
+        .logical_shape
+        .iter()
+        .try_fold(1_u32, |product, axis| product.checked_mul(*axis));
+    if expected != Some(invocations) {
+        return Err(PcuError::invalid());
+    }
+
+    let constants = kernel
+        .parameters
+        .iter()
+        .map(|parameter| {
+            parameters
+                .value(parameter.slot)
+                .ok_or_else(PcuError::invalid)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut resources = HostedCpuResources::new(kernel.bindings, bindings)?;
+
+    let mut invocation = 0;
+    while invocation < invocations {
+        HostedCpuLane::new(invocation).run(kernel.ops, &constants, &mut resources)?;
+        invocation += 1;
+    }
+
+    Ok(HostedCpuDispatchHandle {
+        outputs: HostedCpuOutputs {
+            entries: resources.outputs,
+        },
+        invocations,
+    })
+}
+
+/// Runs one command kernel to completion on the calling thread.
+///
+/// # Errors
+///
+/// Returns `Unsupported` for `Invoke` steps, intrinsic targets, and named predicates,
+/// `StateConflict` when an awaited predicate does not hold, and `Invalid` when one step reads an
+/// unwritten target, names an undeclared binding or port, or mixes value types.
+pub fn submit_host_cpu_command(
+    submission: PcuCommandSubmission<'_>,
+    parameters: PcuInvocationParameters<'_>,
+) -> Result<HostedCpuCommandHandle, PcuError> {
+    let kernel = submission.kernel;
+    if !HOST_CPU_COMMAND_DIRECT_SUPPORT.contains(kernel.required_instruction_support()) {
+        return Err(PcuError::unsupported());
+    }
+    if !parameters.validate_against(kernel.parameters) {
+        return Err(PcuError::invalid());
+    }
+
+    let mut machine = HostedCpuMachine::new(kernel.bindings, kernel.ports, parameters);
+    let mut result = None;
+    let mut elapsed_ticks = 0_u64;
+
+    for step in kernel.steps {
+        match step.op {
+            PcuCommandOp::Read { target } => {
+                let value = machine.read(HostedCpuTargetRef::from_target(target)?)?;
+                machine.previous = Some(value);
+            }
+            PcuCommandOp::Write { target, value } => {
+                let target = HostedCpuTargetRef::from_target(target)?;
+                let value = machine.resolve(HostedCpuOperandRef::from_operand(value)?)?;
+                machine.write(target, value)?;
+                machine.previous = Some(value);
+            }
+            PcuCommandOp::Modify { target, op, value } => {
+                let target = HostedCpuTargetRef::from_target(target)?;
+                let operand = machine.resolve(HostedCpuOperandRef::from_operand(value)?)?;
+                let value = match modify_binary_op(op) {
+                    Some(op) => apply_binary(op, machine.read(target)?, operand)?,
+                    None => operand,
+                };
+                machine.write(target, value)?;
+                machine.previous = Some(value);
+            }
+            PcuCommandOp::Copy { source, target } => {
+                let value = machine.read(HostedCpuTargetRef::from_target(source)?)?;
+                machine.write(HostedCpuTargetRef::from_target(target)?, value)?;
+                machine.previous = Some(value);
+            }
+            PcuCommandOp::Stall { ticks } | PcuCommandOp::Sleep { ticks } => {
+                elapsed_ticks = elapsed_ticks.saturating_add(u64::from(ticks));
+            }
+            PcuCommandOp::Barrier => {}
+            PcuCommandOp::Return { value } => {
+                result = value
+                    .map(|value| machine.resolve(HostedCpuOperandRef::from_operand(value)?))
+                    .transpose()?;
+                break;
+            }
+            PcuCommandOp::Await { .. } | PcuCommandOp::Invoke { .. } => {
+                return Err(PcuError::unsupported());
+            }
+        }
+    }
+
+    Ok(HostedCpuCommandHandle {
+        machine,
+        result,
+        elapsed_ticks,
+    })
+}
+
+/// Runs one transaction kernel to completion on the calling thread.
+///
+/// # Errors
+///
+/// Returns `Busy` when an exclusive transaction cannot start before its timeout, `Unsupported`
+/// for builtin or port bindings, and `Invalid` when the read-only and writable bindings do not
+/// pair up or an atomic transaction would only partially transfer.
+pub fn submit_host_cpu_transaction(
+    submission: PcuTransactionSubmission<'_>,
+    bindings: PcuInvocationBindings<'_>,
+    parameters: PcuInvocationParameters<'_>,
+) -> Result<HostedCpuTransactionHandle, PcuError> {
+    let kernel = submission.kernel;
+    if !HOST_CPU_TRANSACTION_DIRECT_SUPPORT.contains(kernel.required_features()) {
+        return Err(PcuError::unsupported());
+    }
+    if !parameters.validate_against(kernel.parameters) {
+        return Err(PcuError::invalid());
+    }
+
+    let mut resources = HostedCpuResources::new(kernel.bindings, bindings)?;
+    let mut sources = Vec::with_capacity(resources.readable.len());
+    for resource in resources.readable.iter().copied() {
+        match resource {
+            HostedCpuResource::Input { elements, scalar } => sources.push((elements, scalar)),
+            HostedCpuResource::Output { .. } => {}
+            HostedCpuResource::Builtin { .. } => return Err(PcuError::unsupported()),
+        }
+    }
+    if sources.len() != resources.writable.len() {
+        return Err(PcuError::invalid());
+    }
+
+    let _guard = HostedCpuTransactionGuard::acquire(kernel.exclusivity, kernel.timeout_ticks)?;
+
+    for ((elements, scalar), destination) in sources.iter().zip(resources.writable.iter()) {
+        let HostedCpuResource::Output {
+            slot,
+            scalar: destination_scalar,
+        } = *destination
+        else {
+            return Err(PcuError::invalid());
+        };
+        if *scalar != destination_scalar {
+            return Err(PcuError::invalid());
+        }
+        if kernel.atomicity == PcuTransactionAtomicity::Atomic
+            && elements.len() != resources.outputs[slot].1.len()
+        {
+            return Err(PcuError::invalid());
+        }
+    }
+
+    let mut transferred = 0;
+    for ((elements, _), destination) in sources.iter().zip(resources.writable.iter()) {
+        if let HostedCpuResource::Output { slot, .. } = *destination {
+            transferred += resources.outputs[slot].1.copy_prefix(*elements);
+        }
+    }
+
+    Ok(HostedCpuTransactionHandle {
+        outputs: HostedCpuOutputs {
+            entries: resources.outputs,
+        },
+        transferred,
+    })
+}
+
+/// Installs one signal kernel as a dormant hosted CPU handler.
+///
+/// # Errors
+///
+/// Returns `Unsupported` for intrinsic targets, and `Invalid` when one op names an undeclared
+/// binding or port or publishes to a port that does not carry output.
+pub fn install_host_cpu_signal(
+    installation: PcuSignalInstallation<'_>,
+    parameters: PcuInvocationParameters<'_>,
+) -> Result<HostedCpuSignalHandle, PcuError> {
+    let kernel = installation.kernel;
+    if !HOST_CPU_SIGNAL_DIRECT_SUPPORT.contains(kernel.required_instruction_support()) {
+        return Err(PcuError::unsupported());
+    }
+    if !parameters.validate_against(kernel.parameters) {
+        return Err(PcuError::invalid());
+    }
+
+    let machine = HostedCpuMachine::new(kernel.bindings, kernel.ports, parameters);
+    let ops = kernel
+        .ops
+        .iter()
+        .map(|op| HostedCpuSignalOp::new(*op, &machine))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(HostedCpuSignalHandle {
+        trigger: kernel.trigger,
+        state: HostedCpuSignalState::Dormant,
+        ops,
+        machine,
+        published: Vec::new(),
+        notifications: Vec::new(),
+        acknowledged: 0,
+        triggered: 0,
+    })
+}
+
+/// One declared binding resolved against the caller's invocation bindings.
+#[derive(Debug, Clone, Copy)]
+enum HostedCpuResource<'a> {
+    Builtin {
+        builtin: PcuBuiltinValue<'a>,
+        scalar: PcuScalarType,
+    },
+    Input {
+        elements: HostedCpuElements<'a>,
+        scalar: PcuScalarType,
+    },
+    Output {
+        slot: usize,
+        scalar: PcuScalarType,
+    },
+}
+
+/// Declared bindings split into the readable and writable sequences the interpreter walks.
+#[derive(Debug)]
+struct HostedCpuResources<'a> {
+    readable: Vec<HostedCpuResource<'a>>,
+    writable: Vec<HostedCpuResource<'a>>,
+    outputs: Vec<(PcuBindingRef, HostedCpuBuffer)>,
+}
+
+impl<'a> HostedCpuResources<'a> {
+    fn new(
+        declared: &'a [PcuBinding<'a>],
+        bindings: PcuInvocationBindings<'a>,
+    ) -> Result<Self, PcuError> {
+        if bindings
+            .bindings
+            .iter()
+            .any(|binding| matches!(binding.target, PcuInvocationTarget::Port(_)))
+        {
+            return Err(PcuError::unsupported());
+        }
+
+        let mut resources = Self {
+            readable: Vec::new(),
+            writable: Vec::new(),
+            outputs: Vec::new(),
+        };
+        for binding in declared {
+            let Some(PcuValueType::Scalar(scalar)) = binding.value_type() else {
+                return Err(PcuError::unsupported());
+            };
+            if let Some(builtin) = binding.builtin {
+                resources
+                    .readable
+                    .push(HostedCpuResource::Builtin { builtin, scalar });
+                continue;
+            }
+
+            let width = element_width(scalar).ok_or_else(PcuError::unsupported_type_support)?;
+            let runtime = bindings
+                .bindings
+                .iter()
+                .find(|runtime| runtime.target == PcuInvocationTarget::Binding(binding.reference()))
+                .ok_or_else(PcuError::invalid)?;
+            let (input, output) = split_invocation_buffer(runtime);
+            if input.is_some_and(|input| input.width() != width)
+                || output.is_some_and(|(output_width, _)| output_width != width)
+            {
+                return Err(PcuError::invalid());
+            }
+
+            match binding.access {
+                PcuBindingAccess::ReadOnly => {
+                    let elements = input.ok_or_else(PcuError::invalid)?;
+                    resources
+                        .readable
+                        .push(HostedCpuResource::Input { elements, scalar });
+                }
+                PcuBindingAccess::WriteOnly | PcuBindingAccess::ReadWrite => {
+                    let (_, len) = output.ok_or_else(PcuError::invalid)?;
+                    let mut buffer = HostedCpuBuffer::zeroed(width, len);
+                    if let Some(input) = input {
+                        buffer.copy_prefix(input);
+                    }
+                    let slot = resources.outputs.len();
+                    resources.outputs.push((binding.reference(), buffer));
+                    let resource = HostedCpuResource::Output { slot, scalar };
+                    if binding.access == PcuBindingAccess::ReadWrite {
+                        resources.readable.push(resource);
+                    }
+                    resources.writable.push(resource);
+                }
+            }
+        }
+        Ok(resources)
+    }
+
+    fn load(
+        &self,
+        resource: HostedCpuResource<'_>,
+        invocation: u32,
+    ) -> Result<PcuParameterValue, PcuError> {
+        let index = usize::try_from(invocation).map_err(|_| PcuError::invalid())?;
+        match resource {
+            HostedCpuResource::Builtin { builtin, scalar } => {
+                let value = match builtin {
+                    PcuBuiltinValue::InvocationId
+                    | PcuBuiltinValue::LaneId
+                    | PcuBuiltinValue::LaneIndex => invocation,
+                    PcuBuiltinValue::GroupId => 0,
+                    PcuBuiltinValue::GroupCount => 1,
+                    PcuBuiltinValue::Named(_) => return Err(PcuError::unsupported()),
+                };
+                match scalar {
+                    PcuScalarType::U32 => Ok(PcuParameterValue::U32(value)),
+                    PcuScalarType::I32 => i32::try_from(value)
+                        .map(PcuParameterValue::I32)
+                        .map_err(|_| PcuError::invalid()),
+                    _ => Err(PcuError::invalid()),
+                }
+            }
+            HostedCpuResource::Input { elements, scalar } => {
+                value_from_bits(scalar, elements.get(index).ok_or_else(PcuError::invalid)?)
+                    .ok_or_else(PcuError::invalid)
+            }
+            HostedCpuResource::Output { slot, scalar } => value_from_bits(
+                scalar,
+                self.outputs[slot]
+                    .1
+                    .get(index)
+                    .ok_or_else(PcuError::invalid)?,
+            )
+            .ok_or_else(PcuError::invalid),
+        }
+    }
+
+    fn store(
+        &mut self,
+        resource: HostedCpuResource<'_>,
+        invocation: u32,
+        value: PcuParameterValue,
+    ) -> Result<(), PcuError> {
+        let HostedCpuResource::Output { slot, scalar } = resource else {
+            return Err(PcuError::invalid());
+        };
+        if value.value_type() != PcuValueType::Scalar(scalar) {
+            return Err(PcuError::invalid());
+        }
+        let index = usize::try_from(invocation).map_err(|_| PcuError::invalid())?;
+        let bits = value_to_bits(value).ok_or_else(PcuError::invalid)?;
+        self.outputs[slot]
+            .1
+            .set(index, bits)
+            .ok_or_else(PcuError::invalid)
+    }
+}
+
+/// Per-invocation dispatch interpreter state.
+#[derive(Debug)]
+struct HostedCpuLane {
+    invocation: u32,
+    stack: [PcuParameterValue; HOSTED_CPU_MAX_OPERAND_STACK],
+    depth: usize,
+    constant: usize,
+    load: usize,
+    store: usize,
+}
+
+impl HostedCpuLane {
+    const fn new(invocation: u32) -> Self {
+        Self {
+            invocation,
+            stack: [PcuParameterValue::Bool(false); HOSTED_CPU_MAX_OPERAND_STACK],
+            depth: 0,
+            constant: 0,
+            load: 0,
+            store: 0,
+        }
+    }
+
+    fn run(
+        &mut self,
+        ops: &[PcuDispatchOp<'_>],
+        constants: &[PcuParameterValue],
+        resources: &mut HostedCpuResources<'_>,
+    ) -> Result<(), PcuError> {
+        for op in ops.iter().copied() {
+            match op {
+                PcuDispatchOp::Value(PcuDispatchValueOp::Constant) => {
+                    let value = constants
+                        .get(self.constant)
+                        .copied()
+                        .ok_or_else(PcuError::invalid)?;
+                    self.constant += 1;
+                    self.push(value)?;
+                }
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Compare) => {
+                    let rhs = self.pop()?;
+                    let lhs = self.pop()?;
+                    self.push(PcuParameterValue::Bool(values_equal(lhs, rhs)?))?;
+                }
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Select) => {
+                    let PcuParameterValue::Bool(condition) = self.pop()? else {
+                        return Err(PcuError::invalid());
+                    };
+                    let on_false = self.pop()?;
+                    let on_true = self.pop()?;
+                    if on_true.value_type() != on_false.value_type() {
+                        return Err(PcuError::invalid());
+                    }
+                    self.push(if condition { on_true } else { on_false })?;
+                }
+                PcuDispatchOp::Arithmetic(op) => {
+                    let op = dispatch_binary_op(op).ok_or_else(PcuError::unsupported)?;
+                    let rhs = self.pop()?;
+                    let lhs = self.pop()?;
+                    self.push(apply_binary(op, lhs, rhs)?)?;
+                }
+                PcuDispatchOp::Control(PcuDispatchControlOp::Return) => return Ok(()),
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Load) => {
+                    let resource = resources
+                        .readable
+                        .get(self.load)
+                        .copied()
+                        .ok_or_else(PcuError::invalid)?;
+                    self.load += 1;
+                    let value = resources.load(resource, self.invocation)?;
+                    self.push(value)?;
+                }
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Store) => {
+                    let value = self.pop()?;
+                    let resource = resources
+                        .writable
+                        .get(self.store)
+                        .copied()
+                        .ok_or_else(PcuError::invalid)?;
+                    self.store += 1;
+                    resources.store(resource, self.invocation, value)?;
+                }
+                PcuDispatchOp::Sync(PcuDispatchSyncOp::Barrier | PcuDispatchSyncOp::Fence) => {}
+                _ => return Err(PcuError::unsupported()),
+            }
+        }
+        Ok(())
+    }
+
+    const fn push(&mut self, value: PcuParameterValue) -> Result<(), PcuError> {
+        if self.depth == HOSTED_CPU_MAX_OPERAND_STACK {
+            return Err(PcuError::resource_exhausted());
+        }
+        self.stack[self.depth] = value;
+        self.depth += 1;
+        Ok(())
+    }
+
+    const fn pop(&mut self) -> Result<PcuParameterValue, PcuError> {
+        if self.depth == 0 {
+            return Err(PcuError::invalid());
+        }
+        self.depth -= 1;
+        Ok(self.stack[self.depth])
+    }
+}
+
+/// Borrowed command or signal target the interpreter can hold a value for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HostedCpuTargetRef<'a> {
+    Binding(PcuBindingRef),
+    Port(&'a str),
+    Named(&'a str),
+}
+
+impl<'a> HostedCpuTargetRef<'a> {
+    const fn from_target(target: PcuTarget<'a>) -> Result<Self, PcuError> {
+        match target {
+            PcuTarget::Binding(reference) => Ok(Self::Binding(reference)),
+            PcuTarget::Port(name) => Ok(Self::Port(name)),
+            PcuTarget::Named(name) => Ok(Self::Named(name)),
+            PcuTarget::Intrinsic(_) => Err(PcuError::unsupported()),
+        }
+    }
+}
+
+/// Owned form of one target, kept by installed handlers and the register file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum HostedCpuTargetKey {
+    Binding(PcuBindingRef),
+    Port(Box<str>),
+    Named(Box<str>),
+}
+
+impl HostedCpuTargetKey {
+    fn new(target: HostedCpuTargetRef<'_>) -> Self {
+        match target {
+            HostedCpuTargetRef::Binding(reference) => Self::Binding(reference),
+            HostedCpuTargetRef::Port(name) => Self::Port(name.into()),
+            HostedCpuTargetRef::Named(name) => Self::Named(name.into()),
+        }
+    }
+
+    fn as_ref(&self) -> HostedCpuTargetRef<'_> {
+        match self {
+            Self::Binding(reference) => HostedCpuTargetRef::Binding(*reference),
+            Self::Port(name) => HostedCpuTargetRef::Port(name),
+            Self::Named(name) => HostedCpuTargetRef::Named(name),
+        }
+    }
+}
+
+/// Borrowed command or signal operand.
+#[derive(Debug, Clone, Copy)]
+enum HostedCpuOperandRef<'a> {
+    Immediate(PcuParameterValue),
+    Parameter(PcuParameterSlot),
+    Target(HostedCpuTargetRef<'a>),
+    PreviousResult,
+}
+
+impl<'a> HostedCpuOperandRef<'a> {
+    fn from_operand(operand: PcuOperand<'a>) -> Result<Self, PcuError> {
+        Ok(match operand {
+            PcuOperand::Immediate(value) => Self::Immediate(value),
+            PcuOperand::Parameter(slot) => Self::Parameter(slot),
+            PcuOperand::Target(target) => Self::Target(HostedCpuTargetRef::from_target(target)?),
+            PcuOperand::PreviousResult => Self::PreviousResult,
+        })
+    }
+}
+
+/// Owned form of one operand, kept by installed handlers.
+#[derive(Debug, Clone)]
+enum HostedCpuOperandKey {
+    Immediate(PcuParameterValue),
+    Parameter(PcuParameterSlot),
+    Target(HostedCpuTargetKey),
+    PreviousResult,
+}
+
+impl HostedCpuOperandKey {
+    fn new(operand: HostedCpuOperandRef<'_>) -> Self {
+        match operand {
+            HostedCpuOperandRef::Immediate(value) => Self::Immediate(value),
+            HostedCpuOperandRef::Parameter(slot) => Self::Parameter(slot),
+            HostedCpuOperandRef::Target(target) => Self::Target(HostedCpuTargetKey::new(target)),
+            HostedCpuOperandRef::PreviousResult => Self::PreviousResult,
+        }
+    }
+
+    fn as_ref(&self) -> HostedCpuOperandRef<'_> {
+        match self {
+            Self::Immediate(value) => HostedCpuOperandRef::Immediate(*value),
+            Self::Parameter(slot) => HostedCpuOperandRef::Parameter(*slot),
+            Self::Target(target) => HostedCpuOperandRef::Target(target.as_ref()),
+            Self::PreviousResult => HostedCpuOperandRef::PreviousResult,
+        }
+    }
+}
+
+/// Owned signal op validated at install time.
+#[derive(Debug, Clone)]
+enum HostedCpuSignalOp {
+    Ack,
+    Read {
+        target: HostedCpuTargetKey,
+    },
+    Write {
+        target: HostedCpuTargetKey,
+        value: HostedCpuOperandKey,
+    },
+    Publish {
+        port: Box<str>,
+        value: HostedCpuOperandKey,
+    },
+    Notify {
+        target: HostedCpuTargetKey,
+    },
+}
+
+impl HostedCpuSignalOp {
+    fn new(op: PcuSignalOp<'_>, machine: &HostedCpuMachine) -> Result<Self, PcuError> {
+        let target = |target: PcuTarget<'_>| -> Result<HostedCpuTargetKey, PcuError> {
+            let target = HostedCpuTargetRef::from_target(target)?;
+            machine.check_target(target)?;
+            Ok(HostedCpuTargetKey::new(target))
+        };
+        let operand = |operand: PcuOperand<'_>| -> Result<HostedCpuOperandKey, PcuError> {
+            let operand = HostedCpuOperandRef::from_operand(operand)?;
+            if let HostedCpuOperandRef::Target(target) = operand {
+                machine.check_target(target)?;
+            }
+            Ok(HostedCpuOperandKey::new(operand))
+        };
+
+        Ok(match op {
+            PcuSignalOp::Ack => Self::Ack,
+            PcuSignalOp::Read { target: read } => Self::Read {
+                target: target(read)?,
+            },
+            PcuSignalOp::Write {
+                target: written,
+                value,
+            } => Self::Write {
+                target: target(written)?,
+                value: operand(value)?,
+            },
+            PcuSignalOp::Publish { port, value } => {
+                if !machine.port_carries_output(port) {
+                    return Err(PcuError::invalid());
+                }
+                Self::Publish {
+                    port: port.into(),
+                    value: operand(value)?,
+                }
+            }
+            PcuSignalOp::Notify { target: notified } => Self::Notify {
+                target: target(notified)?,
+            },
+        })
+    }
+}
+
+/// Register file shared by the command and signal interpreters.
+#[derive(Debug, Clone)]
+struct HostedCpuMachine {
+    bindings: Vec<(PcuBindingRef, PcuValueType)>,
+    ports: Vec<(Box<str>, PcuPortDirection, PcuValueType)>,
+    parameters: Vec<PcuParameterBinding>,
+    registers: Vec<(HostedCpuTargetKey, PcuParameterValue)>,
+    previous: Option<PcuParameterValue>,
+}
+
+impl HostedCpuMachine {
+    fn new(
+        bindings: &[PcuBinding<'_>],
+        ports: &[PcuPort<'_>],
+        parameters: PcuInvocationParameters<'_>,
+    ) -> Self {
+        Self {
+            bindings: bindings
+                .iter()
+                .filter_map(|binding| Some((binding.reference(), binding.value_type()?)))
+                .collect(),
+            ports: ports
+                .iter()
+                .filter_map(|port| Some((port.name?.into(), port.direction, port.value_type)))
+                .collect(),
+            parameters: parameters.bindings.to_vec(),
+            registers: Vec::new(),
+            previous: None,
+        }
+    }
+
+    fn declared_type(&self, target: HostedCpuTargetRef<'_>) -> Option<PcuValueType> {
+        match target {
+            HostedCpuTargetRef::Binding(reference) => self
+                .bindings
+                .iter()
+                .find(|(declared, _)| *declared == reference)
+                .map(|(_, value_type)| *value_type),
+            HostedCpuTargetRef::Port(name) => self.port_type(name),
+            HostedCpuTargetRef::Named(_) => None,
+        }
+    }
+
+    fn port_type(&self, name: &str) -> Option<PcuValueType> {
+        self.ports
+            .iter()
+            .find(|(declared, _, _)| &**declared == name)
+            .map(|(_, _, value_type)| *value_type)
+    }
+
+    fn port_carries_output(&self, name: &str) -> bool {
+        self.ports.iter().any(|(declared, direction, _)| {
+            &**declared == name
+                && matches!(
+                    direction,
+                    PcuPortDirection::Output | PcuPortDirection::InOut
+                )
+        })
+    }
+
+    fn check_target(&self, target: HostedCpuTargetRef<'_>) -> Result<(), PcuError> {
+        match target {
+            HostedCpuTargetRef::Binding(_) | HostedCpuTargetRef::Port(_)
+                if self.declared_type(target).is_none() =>
+            {
+                Err(PcuError::invalid())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn register(&self, target: PcuTarget<'_>) -> Option<PcuParameterValue> {
+        let target = HostedCpuTargetRef::from_target(target).ok()?;
+        self.lookup(target)
+    }
+
+    fn lookup(&self, target: HostedCpuTargetRef<'_>) -> Option<PcuParameterValue> {
+        self.registers
+            .iter()
+            .find(|(key, _)| key.as_ref() == target)
+            .map(|(_, value)| *value)
+    }
+
+    fn read(&self, target: HostedCpuTargetRef<'_>) -> Result<PcuParameterValue, PcuError> {
+        self.check_target(target)?;
+        self.lookup(target).ok_or_else(PcuError::invalid)
+    }
+
+    fn write(
+        &mut self,
+        target: HostedCpuTargetRef<'_>,
+        value: PcuParameterValue,
+    ) -> Result<(), PcuError> {
+        self.check_target(target)?;
+        if self
+            .declared_type(target)
+            .is_some_and(|declared| !value.matches_type(declared))
+        {
+            return Err(PcuError::invalid());
+        }
+        match self
+            .registers
+            .iter_mut()
+            .find(|(key, _)| key.as_ref() == target)
+        {
+            Some((_, existing)) => *existing = value,
+            None => self
+                .registers
+                .push((HostedCpuTargetKey::new(target), value)),
+        }
+        Ok(())
+    }
+
+    fn resolve(&self, operand: HostedCpuOperandRef<'_>) -> Result<PcuParameterValue, PcuError> {
+        match operand {
+            HostedCpuOperandRef::Immediate(value) => Ok(value),
+            HostedCpuOperandRef::Parameter(slot) => self
+                .parameters
+                .iter()
+                .find(|binding| binding.slot == slot)
+                .map(|binding| binding.value)
+                .ok_or_else(PcuError::invalid),
+            HostedCpuOperandRef::Target(target) => self.read(target),
+            HostedCpuOperandRef::PreviousResult => self.previous.ok_or_else(PcuError::invalid),
+        }
+    }
+}
+
+/// Shared or exclusive hold on the hosted transaction lock.
+struct HostedCpuTransactionGuard {
+    _shared: Option<RwLockReadGuard<'static, ()>>,
+    _exclusive: Option<RwLockWriteGuard<'static, ()>>,
+}
+
+impl HostedCpuTransactionGuard {
+    fn acquire(
+        exclusivity: PcuTransactionExclusivity,
+        timeout_ticks: Option<u32>,
+    ) -> Result<Self, PcuError> {
+        let Some(timeout_ticks) = timeout_ticks else {
+            return Ok(match exclusivity {
+                PcuTransactionExclusivity::Shared => Self::shared(
+                    HOST_CPU_TRANSACTION_LOCK
+                        .read()
+                        .unwrap_or_else(PoisonError::into_inner),
+                ),
+                PcuTransactionExclusivity::Exclusive => Self::exclusive(
+                    HOST_CPU_TRANSACTION_LOCK
+                        .write()
+                        .unwrap_or_else(PoisonError::into_inner),
+                ),
+            });
+        };
+
+        let deadline = Instant::now() + host_cpu_ticks(timeout_ticks);
+        loop {
+            let acquired = match exclusivity {
+                PcuTransactionExclusivity::Shared => match HOST_CPU_TRANSACTION_LOCK.try_read() {
+                    Ok(guard) => Some(Self::shared(guard)),
+                    Err(TryLockError::Poisoned(poisoned)) => {
+                        Some(Self::shared(poisoned.into_inner()))
+                    }
+                    Err(TryLockError::WouldBlock) => None,
+                },
+                PcuTransactionExclusivity::Exclusive => {
+                    match HOST_CPU_TRANSACTION_LOCK.try_write() {
+                        Ok(guard) => Some(Self::exclusive(guard)),
+                        Err(TryLockError::Poisoned(poisoned)) => {
+                            Some(Self::exclusive(poisoned.into_inner()))
+                        }
+                        Err(TryLockError::WouldBlock) => None,
+                    }
+                }
+            };
+            if let Some(guard) = acquired {
+                return Ok(guard);
+            }
+            if Instant::now() >= deadline {
+                return Err(PcuError::busy());
+            }
+            std::thread::yield_now();
+        }
+    }
+
+    const fn shared(guard: RwLockReadGuard<'static, ()>) -> Self {
+        Self {
+            _shared: Some(guard),
+            _exclusive: None,
+        }
+    }
+
+    const fn exclusive(guard: RwLockWriteGuard<'static, ()>) -> Self {
+        Self {
+            _shared: None,
+            _exclusive: Some(guard),
+        }
+    }
+}
+
+/// Binary arithmetic shared by dispatch ALU ops and command modify steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HostedCpuBinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Min,
+    Max,
+    And,
+    Or,
+    Xor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+const fn dispatch_binary_op(op: PcuDispatchAluOp) -> Option<HostedCpuBinaryOp> {
+    Some(match op {
+        PcuDispatchAluOp::Add => HostedCpuBinaryOp::Add,
+        PcuDispatchAluOp::Sub => HostedCpuBinaryOp::Sub,
+        PcuDispatchAluOp::Mul => HostedCpuBinaryOp::Mul,
+        PcuDispatchAluOp::Div => HostedCpuBinaryOp::Div,
+        PcuDispatchAluOp::Min => HostedCpuBinaryOp::Min,
+        PcuDispatchAluOp::Max => HostedCpuBinaryOp::Max,
+        PcuDispatchAluOp::And => HostedCpuBinaryOp::And,
+        PcuDispatchAluOp::Or => HostedCpuBinaryOp::Or,
+        PcuDispatchAluOp::Xor => HostedCpuBinaryOp::Xor,
+        PcuDispatchAluOp::ShiftLeft => HostedCpuBinaryOp::ShiftLeft,
+        PcuDispatchAluOp::ShiftRight => HostedCpuBinaryOp::ShiftRight,
+        PcuDispatchAluOp::Compare | PcuDispatchAluOp::Select => return None,
+    })
+}
+
+const fn modify_binary_op(op: PcuCommandModifyOp) -> Option<HostedCpuBinaryOp> {
+    Some(match op {
+        PcuCommandModifyOp::Assign => return None,
+        PcuCommandModifyOp::Add => HostedCpuBinaryOp::Add,
+        PcuCommandModifyOp::Sub => HostedCpuBinaryOp::Sub,
+        PcuCommandModifyOp::And => HostedCpuBinaryOp::And,
+        PcuCommandModifyOp::Or => HostedCpuBinaryOp::Or,
+        PcuCommandModifyOp::Xor => HostedCpuBinaryOp::Xor,
+        PcuCommandModifyOp::ShiftLeft => HostedCpuBinaryOp::ShiftLeft,
+        PcuCommandModifyOp::ShiftRight => HostedCpuBinaryOp::ShiftRight,
+    })
+}
+
+fn apply_binary(
+    op: HostedCpuBinaryOp,
+    lhs: PcuParameterValue,
+    rhs: PcuParameterValue,
+) -> Result<PcuParameterValue, PcuError> {
+    if lhs.value_type() != rhs.value_type() {
+        return Err(PcuError::invalid());
+    }
+
+    if let (Some(lhs), Some(rhs)) = (lhs.as_f32(), rhs.as_f32()) {
+        return float_binary(op, f64::from(lhs), f64::from(rhs)).map(|value| {
+            #[allow(clippy::cast_possible_truncation)]
+            PcuParameterValue::from_f32(value as f32)
+        });
+    }
+    if let (Some(lhs), Some(rhs)) = (lhs.as_f64(), rhs.as_f64()) {
+        return float_binary(op, lhs, rhs).map(PcuParameterValue::from_f64);
+    }
+    if let (PcuParameterValue::Bool(lhs), PcuParameterValue::Bool(rhs)) = (lhs, rhs) {
+        return match op {
+            HostedCpuBinaryOp::And => Ok(PcuParameterValue::Bool(lhs & rhs)),
+            HostedCpuBinaryOp::Or => Ok(PcuParameterValue::Bool(lhs | rhs)),
+            HostedCpuBinaryOp::Xor => Ok(PcuParameterValue::Bool(lhs ^ rhs)),
+            _ => Err(PcuError::invalid()),
+        };
+    }
+
+    let (Some((left, bits)), Some((right, _))) = (integer_parts(lhs), integer_parts(rhs)) else {
+        return Err(PcuError::unsupported_type_support());
+    };
+    let raw = match op {
+        HostedCpuBinaryOp::Add => left.wrapping_add(right),
+        HostedCpuBinaryOp::Sub => left.wrapping_sub(right),
+        HostedCpuBinaryOp::Mul => left.wrapping_mul(right),
+        HostedCpuBinaryOp::Div => {
+            if right == 0 {
+                return Err(PcuError::invalid());
+            }
+            left.wrapping_div(right)
+        }
+        HostedCpuBinaryOp::Min => left.min(right),
+        HostedCpuBinaryOp::Max => left.max(right),
+        HostedCpuBinaryOp::And => left & right,
+        HostedCpuBinaryOp::Or => left | right,
+        HostedCpuBinaryOp::Xor => left ^ right,
+        HostedCpuBinaryOp::ShiftLeft | HostedCpuBinaryOp::ShiftRight if right < 0 => {
+            return Err(PcuError::invalid());
+        }
+        HostedCpuBinaryOp::ShiftLeft => {
+            if right >= i128::from(bits) {
+                0
+            } else {
+                left << right
+            }
+        }
+        // Operands sit well inside `i128`, so clamping the amount keeps unsigned shifts at zero
+        // and signed shifts at their sign fill once the amount reaches the type width.
+        HostedCpuBinaryOp::ShiftRight => left >> right.min(127),
+    };
+    integer_value(lhs, raw).ok_or_else(PcuError::invalid)
+}
+
+fn float_binary(op: HostedCpuBinaryOp, lhs: f64, rhs: f64) -> Result<f64, PcuError> {
+    match op {
+        HostedCpuBinaryOp::Add => Ok(lhs + rhs),
+        HostedCpuBinaryOp::Sub => Ok(lhs - rhs),
+        HostedCpuBinaryOp::Mul => Ok(lhs * rhs),
+        HostedCpuBinaryOp::Div => Ok(lhs / rhs),
+        HostedCpuBinaryOp::Min => Ok(lhs.min(rhs)),
+        HostedCpuBinaryOp::Max => Ok(lhs.max(rhs)),
+        HostedCpuBinaryOp::And
+        | HostedCpuBinaryOp::Or
+        | HostedCpuBinaryOp::Xor
+        | HostedCpuBinaryOp::ShiftLeft
+        | HostedCpuBinaryOp::ShiftRight => Err(PcuError::invalid()),
+    }
+}
+
+// Float operands compare with IEEE equality, matching what hardware comparisons report.
+#[allow(clippy::float_cmp)]
+fn values_equal(lhs: PcuParameterValue, rhs: PcuParameterValue) -> Result<bool, PcuError> {
+    if lhs.value_type() != rhs.value_type() {
+        return Err(PcuError::invalid());
+    }
+    if let (Some(lhs), Some(rhs)) = (lhs.as_f32(), rhs.as_f32()) {
+        return Ok(lhs == rhs);
+    }
+    if let (Some(lhs), Some(rhs)) = (lhs.as_f64(), rhs.as_f64()) {
+        return Ok(lhs == rhs);
+    }
+    Ok(lhs == rhs)
+}
+
+/// Returns one integer value widened to `i128` alongside its bit width.
+fn integer_parts(value: PcuParameterValue) -> Option<(i128, u32)> {
+    Some(match value {
+        PcuParameterValue::I8(value) => (i128::from(value), 8),
+        PcuParameterValue::U8(value) => (i128::from(value), 8),
+        PcuParameterValue::I16(value) => (i128::from(value), 16),
+        PcuParameterValue::U16(value) => (i128::from(value), 16),
+        PcuParameterValue::I32(value) => (i128::from(value), 32),
+        PcuParameterValue::U32(value) => (i128::from(value), 32),
+        PcuParameterValue::I64(value) => (i128::from(value), 64),
+        PcuParameterValue::U64(value) => (i128::from(value), 64),
+        _ => return None,
+    })
+}
+
+/// Wraps one widened integer back into the type of `template`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn integer_value(template: PcuParameterValue, raw: i128) -> Option<PcuParameterValue> {
+    Some(match template {
+        PcuParameterValue::I8(_) => PcuParameterValue::I8(raw as i8),
+        PcuParameterValue::U8(_) => PcuParameterValue::U8(raw as u8),
+        PcuParameterValue::I16(_) => PcuParameterValue::I16(raw as i16),
+        PcuParameterValue::U16(_) => PcuParameterValue::U16(raw as u16),
+        PcuParameterValue::I32(_) => PcuParameterValue::I32(raw as i32),
+        PcuParameterValue::U32(_) => PcuParameterValue::U32(raw as u32),
+        PcuParameterValue::I64(_) => PcuParameterValue::I64(raw as i64),
+        PcuParameterValue::U64(_) => PcuParameterValue::U64(raw as u64),
+        _ => return None,
+    })
+}
+
+const fn element_width(scalar: PcuScalarType) -> Option<HostedCpuElementWidth> {
+    match scalar {
+        PcuScalarType::Bool | PcuScalarType::I8 | PcuScalarType::U8 => {
+            Some(HostedCpuElementWidth::Byte)
+        }
+        PcuScalarType::I16 | PcuScalarType::U16 => Some(HostedCpuElementWidth::HalfWord),
+        PcuScalarType::I32 | PcuScalarType::U32 | PcuScalarType::F32 => {
+            Some(HostedCpuElementWidth::Word)
+        }
+        _ => None,
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+const fn value_from_bits(scalar: PcuScalarType, bits: u32) -> Option<PcuParameterValue> {
+    Some(match scalar {
+        PcuScalarType::Bool => PcuParameterValue::Bool(bits != 0),
+        PcuScalarType::I8 => PcuParameterValue::I8(bits as u8 as i8),
+        PcuScalarType::U8 => PcuParameterValue::U8(bits as u8),
+        PcuScalarType::I16 => PcuParameterValue::I16(bits as u16 as i16),
+        PcuScalarType::U16 => PcuParameterValue::U16(bits as u16),
+        PcuScalarType::I32 => PcuParameterValue::I32(bits as i32),
+        PcuScalarType::U32 => PcuParameterValue::U32(bits),
+        PcuScalarType::F32 => PcuParameterValue::F32(bits),
+        _ => return None,
+    })
+}
+
+#[allow(clippy::cast_sign_loss)]
+const fn value_to_bits(value: PcuParameterValue) -> Option<u32> {
+    Some(match value {
+        PcuParameterValue::Bool(value) => value as u32,
+        PcuParameterValue::I8(value) => value as u8 as u32,
+        PcuParameterValue::U8(value) => value as u32,
+        PcuParameterValue::I16(value) => value as u16 as u32,
+        PcuParameterValue::U16(value) => value as u32,
+        PcuParameterValue::I32(value) => value as u32,
+        PcuParameterValue::U32(value) | PcuParameterValue::F32(value) => value,
+        _ => return None,
+    })
+}
+
+const fn split_invocation_buffer<'a>(
+    binding: &'a PcuInvocationBinding<'a>,
+) -> (
+    Option<HostedCpuElements<'a>>,
+    Option<(HostedCpuElementWidth, usize)>,
+) {
+    match &binding.buffer {
+        PcuInvocationBuffer::BytesIn(input) => (Some(HostedCpuElements::Bytes(input)), None),
+        PcuInvocationBuffer::BytesOut(output) => {
+            (None, Some((HostedCpuElementWidth::Byte, output.len())))
+        }
+        PcuInvocationBuffer::BytesInOut { input, output } => (
+            Some(HostedCpuElements::Bytes(input)),
+            Some((HostedCpuElementWidth::Byte, output.len())),
+        ),
+        PcuInvocationBuffer::HalfWordsIn(input) => {
+            (Some(HostedCpuElements::HalfWords(input)), None)
+        }
+        PcuInvocationBuffer::HalfWordsOut(output) => {
+            (None, Some((HostedCpuElementWidth::HalfWord, output.len())))
+        }
+        PcuInvocationBuffer::HalfWordsInOut { input, output } => (
+            Some(HostedCpuElements::HalfWords(input)),
+            Some((HostedCpuElementWidth::HalfWord, output.len())),
+        ),
+        PcuInvocationBuffer::WordsIn(input) => (Some(HostedCpuElements::Words(input)), None),
+        PcuInvocationBuffer::WordsOut(output) => {
+            (None, Some((HostedCpuElementWidth::Word, output.len())))
+        }
+        PcuInvocationBuffer::WordsInOut { input, output } => (
+            Some(HostedCpuElements::Words(input)),
+            Some((HostedCpuElementWidth::Word, output.len())),
+        ),
+    }
+}
+
+fn host_cpu_ticks(ticks: u32) -> Duration {
+    HOST_CPU_TICK * ticks
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use super::{
+        HOST_CPU_TRANSACTION_LOCK,
+        install_host_cpu_signal,
+        submit_host_cpu_command,
+        submit_host_cpu_dispatch,
+        submit_host_cpu_transaction,
+    };
+    use crate::contract::drivers::pcu::{
+        PcuBinding,
+        PcuBindingAccess,
+        PcuBindingRef,
+        PcuBindingStorageClass,
+        PcuBindingType,
+        PcuBuiltinValue,
+        PcuCommandModifyOp,
+        PcuCommandOp,
+        PcuCommandPredicate,
+        PcuCommandStep,
+        PcuCommandSubmission,
+        PcuDispatchAluOp,
+        PcuDispatchControlOp,
+        PcuDispatchOp,
+        PcuDispatchResourceOp,
+        PcuDispatchSubmission,
+        PcuDispatchValueOp,
+        PcuErrorKind,
+        PcuFiniteHandle,
+        PcuFiniteState,
+        PcuInvocationBinding,
+        PcuInvocationBindings,
+        PcuInvocationBuffer,
+        PcuInvocationParameters,
+        PcuInvocationShape,
+        PcuInvocationTarget,
+        PcuKernel,
+        PcuOperand,
+        PcuParameter,
+        PcuParameterBinding,
+        PcuParameterSlot,
+        PcuParameterValue,
+        PcuPersistentHandle,
+        PcuPort,
+        PcuSignalInstallation,
+        PcuSignalOp,
+        PcuSignalTriggerKind,
+        PcuTarget,
+        PcuTransactionAtomicity,
+        PcuTransactionExclusivity,
+        PcuTransactionSubmission,
+        PcuValueType,
+        PcuValueTypeCaps,
+    };
+    use crate::pal::hosted::pcu_shared::HOST_CPU_EXECUTOR_SUPPORT;
+    use fusion_pcu::model::{
+        PcuCommandKernelBuilder,
+        PcuDispatchKernelBuilder,
+        PcuSignalKernelBuilder,
+        PcuTransactionKernelBuilder,
+    };
+
+    const INPUT: PcuBindingRef = PcuBindingRef::new(0, 0);
+    const OUTPUT: PcuBindingRef = PcuBindingRef::new(0, 1);
+
+    const fn value_binding(
+        reference: PcuBindingRef,
+        access: PcuBindingAccess,
+        value_type: PcuValueType,
+    ) -> PcuBinding<'static> {
+        PcuBinding::value(
+            None,
+            reference.set,
+            reference.binding,
+            PcuBindingStorageClass::Storage,
+            access,
+            value_type,
+        )
+    }
+
+    fn shape(threads: u32) -> PcuInvocationShape {
+        PcuInvocationShape::threads(NonZeroU32::new(threads).expect("shape should be non-zero"))
+    }
+
+    #[test]
+    fn dispatch_adds_one_parameter_to_every_element() {
+        const BINDINGS: [PcuBinding<'static>; 2] = [
+            value_binding(INPUT, PcuBindingAccess::ReadOnly, PcuValueType::u32()),
+            value_binding(OUTPUT, PcuBindingAccess::WriteOnly, PcuValueType::u32()),
+        ];
+        const PARAMETERS: [PcuParameter<'static>; 1] = [PcuParameter::named(
+            PcuParameterSlot(0),
+            "delta",
+            PcuValueType::u32(),
+        )];
+        let builder = PcuDispatchKernelBuilder::<'static, 8>::new(1, "add", [4, 1, 1])
+            .with_bindings(&BINDINGS)
+            .with_parameters(&PARAMETERS)
+            .with_type_caps(PcuValueTypeCaps::UINT32.union(PcuValueTypeCaps::SCALAR_VALUES))
+            .with_ops(&[
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Load),
+                PcuDispatchOp::Value(PcuDispatchValueOp::Constant),
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Add),
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Store),
+            ])
+            .expect("builder should accept ops");
+        let kernel = builder.ir();
+        assert!(HOST_CPU_EXECUTOR_SUPPORT.supports_kernel_direct(PcuKernel::Dispatch(kernel)));
+
+        let input = [1_u32, 2, 3, u32::MAX];
+        let mut output = [0_u32; 4];
+        let bindings = [
+            PcuInvocationBinding {
+                target: PcuInvocationTarget::Binding(INPUT),
+                buffer: PcuInvocationBuffer::WordsIn(&input),
+            },
+            PcuInvocationBinding {
+                target: PcuInvocationTarget::Binding(OUTPUT),
+                buffer: PcuInvocationBuffer::WordsOut(&mut output),
+            },
+        ];
+        let handle = submit_host_cpu_dispatch(
+            PcuDispatchSubmission {
+                kernel: &kernel,
+                shape: shape(4),
+            },
+            PcuInvocationBindings {
+                bindings: &bindings,
+            },
+            PcuInvocationParameters {
+                bindings: &[PcuParameterBinding::new(
+                    PcuParameterSlot(0),
+                    PcuParameterValue::U32(10),
+                )],
+            },
+        )
+        .expect("dispatch should run");
+
+        assert_eq!(handle.invocations(), 4);
+        assert_eq!(handle.state(), Ok(PcuFiniteState::Complete));
+        assert_eq!(
+            handle.outputs().words(OUTPUT),
+            Some(&[11_u32, 12, 13, 9][..])
+        );
+        handle.wait().expect("completed dispatch should wait");
+    }
+
+    #[test]
+    fn dispatch_selects_on_builtin_invocation_id() {
+        const BINDINGS: [PcuBinding<'static>; 2] = [
+            PcuBinding {
+                name: Some("invocation"),
+                set: 0,
+                binding: 0,
+                storage: PcuBindingStorageClass::Input,
+                access: PcuBindingAccess::ReadOnly,
+                binding_type: PcuBindingType::Value(PcuValueType::u32()),
+                builtin: Some(PcuBuiltinValue::InvocationId),
+            },
+            value_binding(OUTPUT, PcuBindingAccess::WriteOnly, PcuValueType::u32()),
+        ];
+        const PARAMETERS: [PcuParameter<'static>; 3] = [
+            PcuParameter::named(PcuParameterSlot(0), "on_true", PcuValueType::u32()),
+            PcuParameter::named(PcuParameterSlot(1), "on_false", PcuValueType::u32()),
+            PcuParameter::named(PcuParameterSlot(2), "chosen", PcuValueType::u32()),
+        ];
+        let builder = PcuDispatchKernelBuilder::<'static, 8>::new(2, "select", [2, 2, 1])
+            .with_bindings(&BINDINGS)
+            .with_parameters(&PARAMETERS)
+            .with_ops(&[
+                PcuDispatchOp::Value(PcuDispatchValueOp::Constant),
+                PcuDispatchOp::Value(PcuDispatchValueOp::Constant),
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Load),
+                PcuDispatchOp::Value(PcuDispatchValueOp::Constant),
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Compare),
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Select),
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Store),
+            ])
+            .expect("builder should accept ops");
+        let kernel = builder.ir();
+        let mut output = [0_u32; 4];
+        let bindings = [PcuInvocationBinding {
+            target: PcuInvocationTarget::Binding(OUTPUT),
+            buffer: PcuInvocationBuffer::WordsOut(&mut output),
+        }];
+        let parameters = [
+            PcuParameterBinding::new(PcuParameterSlot(0), PcuParameterValue::U32(7)),
+            PcuParameterBinding::new(PcuParameterSlot(1), PcuParameterValue::U32(9)),
+            PcuParameterBinding::new(PcuParameterSlot(2), PcuParameterValue::U32(2)),
+        ];
+
+        let handle = submit_host_cpu_dispatch(
+            PcuDispatchSubmission {
+                kernel: &kernel,
+                shape: shape(4),
+            },
+            PcuInvocationBindings {
+                bindings: &bindings,
+            },
+            PcuInvocationParameters {
+                bindings: &parameters,
+            },
+        )
+        .expect("dispatch should run");
+
+        assert_eq!(handle.outputs().words(OUTPUT), Some(&[9_u32, 9, 7, 9][..]));
+    }
+
+    #[test]
+    fn dispatch_return_retires_invocation_before_later_ops() {
+        const BINDINGS: [PcuBinding<'static>; 1] = [value_binding(
+            INPUT,
+            PcuBindingAccess::ReadWrite,
+            PcuValueType::i16(),
+        )];
+        const PARAMETERS: [PcuParameter<'static>; 1] = [PcuParameter::named(
+            PcuParameterSlot(0),
+            "shift",
+            PcuValueType::i16(),
+        )];
+        let builder = PcuDispatchKernelBuilder::<'static, 8>::new(3, "halve", [3, 1, 1])
+            .with_bindings(&BINDINGS)
+            .with_parameters(&PARAMETERS)
+            .with_ops(&[
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Load),
+                PcuDispatchOp::Value(PcuDispatchValueOp::Constant),
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::ShiftRight),
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Store),
+                PcuDispatchOp::Control(PcuDispatchControlOp::Return),
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Div),
+            ])
+            .expect("builder should accept ops");
+        let kernel = builder.ir();
+        let input = [0xfff0_u16, 0x0010, 0x7ffe];
+        let mut output = [0_u16; 3];
+        let bindings = [PcuInvocationBinding {
+            target: PcuInvocationTarget::Binding(INPUT),
+            buffer: PcuInvocationBuffer::HalfWordsInOut {
+                input: &input,
+                output: &mut output,
+            },
+        }];
+
+        let handle = submit_host_cpu_dispatch(
+            PcuDispatchSubmission {
+                kernel: &kernel,
+                shape: shape(3),
+            },
+            PcuInvocationBindings {
+                bindings: &bindings,
+            },
+            PcuInvocationParameters {
+                bindings: &[PcuParameterBinding::new(
+                    PcuParameterSlot(0),
+                    PcuParameterValue::I16(1),
+                )],
+            },
+        )
+        .expect("dispatch should run");
+
+        assert_eq!(
+            handle.outputs().half_words(INPUT),
+            Some(&[0xfff8_u16, 0x0008, 0x3fff][..])
+        );
+    }
+
+    #[test]
+    fn dispatch_rejects_unsupported_ops_and_stack_underflow() {
+        let branching = PcuDispatchKernelBuilder::<'static, 4>::new(4, "branch", [1, 1, 1])
+            .with_control_op(PcuDispatchControlOp::Branch)
+            .expect("builder should accept branch");
+        let kernel = branching.ir();
+        assert!(!HOST_CPU_EXECUTOR_SUPPORT.supports_kernel_direct(PcuKernel::Dispatch(kernel)));
+        let error = submit_host_cpu_dispatch(
+            PcuDispatchSubmission {
+                kernel: &kernel,
+                shape: shape(1),
+            },
+            PcuInvocationBindings::empty(),
+            PcuInvocationParameters::empty(),
+        )
+        .expect_err("branch should be unsupported");
+        assert_eq!(error.kind(), PcuErrorKind::Unsupported);
+
+        let underflow = PcuDispatchKernelBuilder::<'static, 4>::new(5, "underflow", [1, 1, 1])
+            .with_arithmetic_op(PcuDispatchAluOp::Add)
+            .expect("builder should accept add");
+        let kernel = underflow.ir();
+        let error = submit_host_cpu_dispatch(
+            PcuDispatchSubmission {
+                kernel: &kernel,
+                shape: shape(1),
+            },
+            PcuInvocationBindings::empty(),
+            PcuInvocationParameters::empty(),
+        )
+        .expect_err("empty stack should be invalid");
+        assert_eq!(error.kind(), PcuErrorKind::Invalid);
+    }
+
+    #[test]
+    fn command_runs_steps_against_named_and_binding_targets() {
+        const BINDINGS: [PcuBinding<'static>; 1] = [value_binding(
+            OUTPUT,
+            PcuBindingAccess::ReadWrite,
+            PcuValueType::u32(),
+        )];
+        const PARAMETERS: [PcuParameter<'static>; 1] = [PcuParameter::named(
+            PcuParameterSlot(0),
+            "seed",
+            PcuValueType::u32(),
+        )];
+        let steps = [
+            PcuCommandOp::Write {
+                target: PcuTarget::Named("acc"),
+                value: PcuOperand::Parameter(PcuParameterSlot(0)),
+            },
+            PcuCommandOp::Modify {
+                target: PcuTarget::Named("acc"),
+                op: PcuCommandModifyOp::Add,
+                value: PcuOperand::Immediate(PcuParameterValue::U32(3)),
+            },
+            PcuCommandOp::Modify {
+                target: PcuTarget::Named("acc"),
+                op: PcuCommandModifyOp::ShiftLeft,
+                value: PcuOperand::Immediate(PcuParameterValue::U32(1)),
+            },
+            PcuCommandOp::Copy {
+                source: PcuTarget::Named("acc"),
+                target: PcuTarget::Binding(OUTPUT),
+            },
+            PcuCommandOp::Stall { ticks: 2 },
+            // Sleeps advance virtual ticks, so a seventy-minute sleep returns at once.
+            PcuCommandOp::Sleep { ticks: u32::MAX },
+            PcuCommandOp::Return {
+                value: Some(PcuOperand::PreviousResult),
+            },
+        ]
+        .map(|op| PcuCommandStep { name: None, op });
+        let builder = PcuCommandKernelBuilder::<'static, 8>::new(6, "command")
+            .with_bindings(&BINDINGS)
+            .with_parameters(&PARAMETERS)
+            .with_steps(&steps)
+            .expect("builder should accept steps");
+        let kernel = builder.ir();
+        assert!(HOST_CPU_EXECUTOR_SUPPORT.supports_kernel_direct(PcuKernel::Command(kernel)));
+
+        let handle = submit_host_cpu_command(
+            PcuCommandSubmission { kernel: &kernel },
+            PcuInvocationParameters {
+                bindings: &[PcuParameterBinding::new(
+                    PcuParameterSlot(0),
+                    PcuParameterValue::U32(5),
+                )],
+            },
+        )
+        .expect("command should run");
+
+        assert_eq!(handle.result(), Some(PcuParameterValue::U32(16)));
+        assert_eq!(
+            handle.register(PcuTarget::Named("acc")),
+            Some(PcuParameterValue::U32(16))
+        );
+        assert_eq!(
+            handle.register(PcuTarget::Binding(OUTPUT)),
+            Some(PcuParameterValue::U32(16))
+        );
+        assert_eq!(handle.elapsed_ticks(), u64::from(u32::MAX) + 2);
+    }
+
+    #[test]
+    fn command_rejects_awaits_and_unwritten_reads() {
+        let awaiting = PcuCommandKernelBuilder::<'static, 2>::new(7, "await")
+            .with_step(
+                None,
+                PcuCommandOp::Await {
+                    predicate: PcuCommandPredicate::NonZero(PcuOperand::Immediate(
+                        PcuParameterValue::U32(0),
+                    )),
+                },
+            )
+            .expect("builder should accept await");
+        assert!(
+            !HOST_CPU_EXECUTOR_SUPPORT.supports_kernel_direct(PcuKernel::Command(awaiting.ir()))
+        );
+        let error = submit_host_cpu_command(
+            PcuCommandSubmission {
+                kernel: &awaiting.ir(),
+            },
+            PcuInvocationParameters::empty(),
+        )
+        .expect_err("nothing can satisfy an await while the kernel runs");
+        assert_eq!(error.kind(), PcuErrorKind::Unsupported);
+
+        let reading = PcuCommandKernelBuilder::<'static, 2>::new(8, "read")
+            .with_step(
+                None,
+                PcuCommandOp::Read {
+                    target: PcuTarget::Named("missing"),
+                },
+            )
+            .expect("builder should accept read");
+        let error = submit_host_cpu_command(
+            PcuCommandSubmission {
+                kernel: &reading.ir(),
+            },
+            PcuInvocationParameters::empty(),
+        )
+        .expect_err("unwritten target should be invalid");
+        assert_eq!(error.kind(), PcuErrorKind::Invalid);
+    }
+
+    #[test]
+    fn transaction_atomicity_decides_partial_transfers() {
+        const BINDINGS: [PcuBinding<'static>; 2] = [
+            value_binding(INPUT, PcuBindingAccess::ReadOnly, PcuValueType::u16()),
+            value_binding(OUTPUT, PcuBindingAccess::WriteOnly, PcuValueType::u16()),
+        ];
+        let source = [1_u16, 2, 3, 4];
+        let mut destination = [0_u16; 3];
+        let bindings = [
+            PcuInvocationBinding {
+                target: PcuInvocationTarget::Binding(INPUT),
+                buffer: PcuInvocationBuffer::HalfWordsIn(&source),
+            },
+            PcuInvocationBinding {
+                target: PcuInvocationTarget::Binding(OUTPUT),
+                buffer: PcuInvocationBuffer::HalfWordsOut(&mut destination),
+            },
+        ];
+
+        let atomic = PcuTransactionKernelBuilder::new(9, "copy")
+            .with_bindings(&BINDINGS)
+            .with_atomicity(PcuTransactionAtomicity::Atomic);
+        let kernel = atomic.ir();
+        assert!(HOST_CPU_EXECUTOR_SUPPORT.supports_kernel_direct(PcuKernel::Transaction(kernel)));
+        let error = submit_host_cpu_transaction(
+            PcuTransactionSubmission { kernel: &kernel },
+            PcuInvocationBindings {
+                bindings: &bindings,
+            },
+            PcuInvocationParameters::empty(),
+        )
+        .expect_err("atomic transfer should refuse a short destination");
+        assert_eq!(error.kind(), PcuErrorKind::Invalid);
+
+        let best_effort = PcuTransactionKernelBuilder::new(10, "copy").with_bindings(&BINDINGS);
+        let handle = submit_host_cpu_transaction(
+            PcuTransactionSubmission {
+                kernel: &best_effort.ir(),
+            },
+            PcuInvocationBindings {
+                bindings: &bindings,
+            },
+            PcuInvocationParameters::empty(),
+        )
+        .expect("best-effort transfer should copy the common prefix");
+        assert_eq!(handle.transferred(), 3);
+        assert_eq!(
+            handle.outputs().half_words(OUTPUT),
+            Some(&[1_u16, 2, 3][..])
+        );
+    }
+
+    #[test]
+    fn exclusive_transaction_times_out_behind_shared_holder() {
+        let exclusive = PcuTransactionKernelBuilder::new(11, "exclusive")
+            .with_exclusivity(PcuTransactionExclusivity::Exclusive)
+            .with_timeout_ticks(100);
+        let kernel = exclusive.ir();
+
+        let shared = HOST_CPU_TRANSACTION_LOCK
+            .read()
+            .expect("transaction lock should not be poisoned");
+        let error = submit_host_cpu_transaction(
+            PcuTransactionSubmission { kernel: &kernel },
+            PcuInvocationBindings::empty(),
+            PcuInvocationParameters::empty(),
+        )
+        .expect_err("exclusive transaction should time out");
+        assert_eq!(error.kind(), PcuErrorKind::Busy);
+        drop(shared);
+
+        submit_host_cpu_transaction(
+            PcuTransactionSubmission { kernel: &kernel },
+            PcuInvocationBindings::empty(),
+            PcuInvocationParameters::empty(),
+        )
+        .expect("exclusive transaction should run once the lock is free");
+    }
+
+    #[test]
+    fn signal_handler_runs_ops_per_trigger_while_active() {
+        const PORTS: [PcuPort<'static>; 1] =
+            [PcuPort::stream_output(Some("out"), PcuValueType::u32())];
+        const PARAMETERS: [PcuParameter<'static>; 1] = [PcuParameter::named(
+            PcuParameterSlot(0),
+            "level",
+            PcuValueType::u32(),
+        )];
+        let builder =
+            PcuSignalKernelBuilder::<'static, 8>::new(12, "signal", PcuSignalTriggerKind::Software)
+                .with_ports(&PORTS)
+                .with_parameters(&PARAMETERS)
+                .with_ops(&[
+                    PcuSignalOp::Ack,
+                    PcuSignalOp::Write {
+                        target: PcuTarget::Named("latched"),
+                        value: PcuOperand::Parameter(PcuParameterSlot(0)),
+                    },
+                    PcuSignalOp::Publish {
+                        port: "out",
+                        value: PcuOperand::PreviousResult,
+                    },
+                    PcuSignalOp::Notify {
+                        target: PcuTarget::Named("waiter"),
+                    },
+                ])
+                .expect("builder should accept ops");
+        let kernel = builder.ir();
+        assert!(HOST_CPU_EXECUTOR_SUPPORT.supports_kernel_direct(PcuKernel::Signal(kernel)));
+
+        let mut handle = install_host_cpu_signal(
+            PcuSignalInstallation { kernel: &kernel },
+            PcuInvocationParameters {
+                bindings: &[PcuParameterBinding::new(
+                    PcuParameterSlot(0),
+                    PcuParameterValue::U32(0x5a),
+                )],
+            },
+        )
+        .expect("signal should install");
+        assert_eq!(
+            handle
+                .trigger()
+                .expect_err("dormant handler should refuse triggers")
+                .kind(),
+            PcuErrorKind::StateConflict
+        );
+
+        handle.start().expect("handler should start");
+        handle.trigger().expect("first trigger should run");
+        handle.trigger().expect("second trigger should run");
+
+        assert_eq!(handle.trigger_kind(), PcuSignalTriggerKind::Software);
+        assert_eq!(handle.trigger_count(), 2);
+        assert_eq!(handle.acknowledged(), 2);
+        assert_eq!(handle.notifications(PcuTarget::Named("waiter")), 2);
+        assert_eq!(
+            handle.register(PcuTarget::Named("latched")),
+            Some(PcuParameterValue::U32(0x5a))
+        );
+        assert_eq!(
+            handle.take_published("out"),
+            Some(PcuParameterValue::U32(0x5a))
+        );
+        assert_eq!(
+            handle.take_published("out"),
+            Some(PcuParameterValue::U32(0x5a))
+        );
+        assert_eq!(handle.take_published("out"), None);
+        handle.uninstall().expect("handler should uninstall");
+    }
+
+    #[test]
+    fn signal_install_rejects_publish_to_undeclared_port() {
+        let builder =
+            PcuSignalKernelBuilder::<'static, 2>::new(13, "signal", PcuSignalTriggerKind::Edge)
+                .with_op(PcuSignalOp::Publish {
+                    port: "missing",
+                    value: PcuOperand::Immediate(PcuParameterValue::U8(1)),
+                })
+                .expect("builder should accept publish");
+
+        let error = install_host_cpu_signal(
+            PcuSignalInstallation {
+                kernel: &builder.ir(),
+            },
+            PcuInvocationParameters::empty(),
+        )
+        .expect_err("undeclared port should be invalid");
+        assert_eq!(error.kind(), PcuErrorKind::Invalid);
+    }
+}
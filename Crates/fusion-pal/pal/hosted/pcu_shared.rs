@@ -2,7 +2,10 @@
 
 use crate::contract::drivers::pcu::{
     PcuCaps,
+    PcuCommandOpCaps,
     PcuCommandSupport,
+    PcuDispatchFeatureCaps,
+    PcuDispatchOpCaps,
     PcuDispatchPolicyCaps,
     PcuDispatchSupport,
     PcuError,
@@ -12,8 +15,6 @@ use crate::contract::drivers::pcu::{
     PcuExecutorOrigin,
     PcuExecutorSupport,
     PcuFeatureSupport,
-    PcuFiniteHandle,
-    PcuFiniteState,
     PcuImplementationKind,
     PcuInvocationBindings,
     PcuInvocationParameters,
@@ -23,6 +24,7 @@ use crate::contract::drivers::pcu::{
     PcuPersistentState,
     PcuPrimitiveCaps,
     PcuPrimitiveSupport,
+    PcuSignalOpCaps,
     PcuSignalSupport,
     PcuStreamCapabilities,
     PcuStreamInstallation,
@@ -30,7 +32,9 @@ use crate::contract::drivers::pcu::{
     PcuStreamSupport,
     PcuStreamValueType,
     PcuSupport,
+    PcuTransactionFeatureCaps,
     PcuTransactionSupport,
+    PcuValueTypeCaps,
 };
 
 const HOSTED_CPU_MAX_STREAM_PATTERNS: usize = 16;
@@ -52,35 +56,88 @@ pub const HOST_CPU_STREAM_DIRECT_SUPPORT: PcuStreamCapabilities = PcuStreamCapab
     .union(PcuStreamCapabilities::MASK_LOWER)
     .union(PcuStreamCapabilities::BYTE_SWAP32);
 
+pub const HOST_CPU_DISPATCH_POLICY_SUPPORT: PcuDispatchPolicyCaps = PcuDispatchPolicyCaps::SERIAL
+    .union(PcuDispatchPolicyCaps::PERSISTENT_INSTALL)
+    .union(PcuDispatchPolicyCaps::ORDERED_SUBMISSION);
+
+pub const HOST_CPU_DISPATCH_DIRECT_SUPPORT: PcuDispatchOpCaps = PcuDispatchOpCaps::VALUE_CONSTANT
+    .union(PcuDispatchOpCaps::ALU_ADD)
+    .union(PcuDispatchOpCaps::ALU_SUB)
+    .union(PcuDispatchOpCaps::ALU_MUL)
+    .union(PcuDispatchOpCaps::ALU_DIV)
+    .union(PcuDispatchOpCaps::ALU_MIN)
+    .union(PcuDispatchOpCaps::ALU_MAX)
+    .union(PcuDispatchOpCaps::ALU_AND)
+    .union(PcuDispatchOpCaps::ALU_OR)
+    .union(PcuDispatchOpCaps::ALU_XOR)
+    .union(PcuDispatchOpCaps::ALU_SHIFT_LEFT)
+    .union(PcuDispatchOpCaps::ALU_SHIFT_RIGHT)
+    .union(PcuDispatchOpCaps::ALU_COMPARE)
+    .union(PcuDispatchOpCaps::ALU_SELECT)
+    .union(PcuDispatchOpCaps::CONTROL_RETURN)
+    .union(PcuDispatchOpCaps::BINDING_LOAD)
+    .union(PcuDispatchOpCaps::BINDING_STORE)
+    .union(PcuDispatchOpCaps::SYNC_BARRIER)
+    .union(PcuDispatchOpCaps::SYNC_FENCE);
+
+pub const HOST_CPU_DISPATCH_TYPE_SUPPORT: PcuValueTypeCaps = PcuValueTypeCaps::BOOL
+    .union(PcuValueTypeCaps::INT8)
+    .union(PcuValueTypeCaps::UINT8)
+    .union(PcuValueTypeCaps::INT16)
+    .union(PcuValueTypeCaps::UINT16)
+    .union(PcuValueTypeCaps::INT32)
+    .union(PcuValueTypeCaps::UINT32)
+    .union(PcuValueTypeCaps::INT64)
+    .union(PcuValueTypeCaps::UINT64)
+    .union(PcuValueTypeCaps::FLOAT32)
+    .union(PcuValueTypeCaps::FLOAT64)
+    .union(PcuValueTypeCaps::SCALAR_VALUES);
+
+pub const HOST_CPU_DISPATCH_FEATURE_SUPPORT: PcuDispatchFeatureCaps =
+    PcuDispatchFeatureCaps::MUTABLE_RESOURCES
+        .union(PcuDispatchFeatureCaps::READ_ONLY_RESOURCES)
+        .union(PcuDispatchFeatureCaps::INLINE_PARAMETERS);
+
+pub const HOST_CPU_COMMAND_DIRECT_SUPPORT: PcuCommandOpCaps = PcuCommandOpCaps::READ
+    .union(PcuCommandOpCaps::WRITE)
+    .union(PcuCommandOpCaps::MODIFY)
+    .union(PcuCommandOpCaps::COPY)
+    .union(PcuCommandOpCaps::STALL)
+    .union(PcuCommandOpCaps::SLEEP)
+    .union(PcuCommandOpCaps::BARRIER)
+    .union(PcuCommandOpCaps::RETURN);
+
+pub const HOST_CPU_TRANSACTION_DIRECT_SUPPORT: PcuTransactionFeatureCaps =
+    PcuTransactionFeatureCaps::all();
+
+pub const HOST_CPU_SIGNAL_DIRECT_SUPPORT: PcuSignalOpCaps = PcuSignalOpCaps::all();
+
 pub const HOST_CPU_EXECUTOR_SUPPORT: PcuExecutorSupport = PcuExecutorSupport {
-    primitives: PcuPrimitiveCaps::STREAM,
-    dispatch_policy: PcuDispatchPolicyCaps::PERSISTENT_INSTALL,
-    dispatch_instructions: crate::contract::drivers::pcu::PcuDispatchOpCaps::empty(),
-    dispatch_types: crate::contract::drivers::pcu::PcuValueTypeCaps::empty(),
-    dispatch_features: crate::contract::drivers::pcu::PcuDispatchFeatureCaps::empty(),
+    primitives: PcuPrimitiveCaps::all(),
+    dispatch_policy: HOST_CPU_DISPATCH_POLICY_SUPPORT,
+    dispatch_instructions: HOST_CPU_DISPATCH_DIRECT_SUPPORT,
+    dispatch_types: HOST_CPU_DISPATCH_TYPE_SUPPORT,
+    dispatch_features: HOST_CPU_DISPATCH_FEATURE_SUPPORT,
     stream_instructions: HOST_CPU_STREAM_DIRECT_SUPPORT,
-    command_instructions: crate::contract::drivers::pcu::PcuCommandOpCaps::empty(),
-    transaction_features: crate::contract::drivers::pcu::PcuTransactionFeatureCaps::empty(),
-    signal_instructions: crate::contract::drivers::pcu::PcuSignalOpCaps::empty(),
+    command_instructions: HOST_CPU_COMMAND_DIRECT_SUPPORT,
+    transaction_features: HOST_CPU_TRANSACTION_DIRECT_SUPPORT,
+    signal_instructions: HOST_CPU_SIGNAL_DIRECT_SUPPORT,
 };
 
 pub const HOST_PRIMITIVE_SUPPORT: PcuPrimitiveSupport = PcuPrimitiveSupport {
-    primitives: PcuFeatureSupport::new(PcuPrimitiveCaps::STREAM, PcuPrimitiveCaps::empty()),
+    primitives: PcuFeatureSupport::new(PcuPrimitiveCaps::all(), PcuPrimitiveCaps::empty()),
 };
 
 pub const HOST_DISPATCH_SUPPORT: PcuDispatchSupport = PcuDispatchSupport {
-    flags: PcuDispatchPolicyCaps::PERSISTENT_INSTALL,
+    flags: HOST_CPU_DISPATCH_POLICY_SUPPORT,
     instructions: PcuFeatureSupport::new(
-        crate::contract::drivers::pcu::PcuDispatchOpCaps::empty(),
-        crate::contract::drivers::pcu::PcuDispatchOpCaps::empty(),
-    ),
-    types: PcuFeatureSupport::new(
-        crate::contract::drivers::pcu::PcuValueTypeCaps::empty(),
-        crate::contract::drivers::pcu::PcuValueTypeCaps::empty(),
+        HOST_CPU_DISPATCH_DIRECT_SUPPORT,
+        PcuDispatchOpCaps::empty(),
     ),
+    types: PcuFeatureSupport::new(HOST_CPU_DISPATCH_TYPE_SUPPORT, PcuValueTypeCaps::empty()),
     features: PcuFeatureSupport::new(
-        crate::contract::drivers::pcu::PcuDispatchFeatureCaps::empty(),
-        crate::contract::drivers::pcu::PcuDispatchFeatureCaps::empty(),
+        HOST_CPU_DISPATCH_FEATURE_SUPPORT,
+        PcuDispatchFeatureCaps::empty(),
     ),
 };
 
@@ -93,23 +150,20 @@ pub const HOST_STREAM_SUPPORT: PcuStreamSupport = PcuStreamSupport {
 
 pub const HOST_COMMAND_SUPPORT: PcuCommandSupport = PcuCommandSupport {
     instructions: PcuFeatureSupport::new(
-        crate::contract::drivers::pcu::PcuCommandOpCaps::empty(),
-        crate::contract::drivers::pcu::PcuCommandOpCaps::empty(),
+        HOST_CPU_COMMAND_DIRECT_SUPPORT,
+        PcuCommandOpCaps::empty(),
     ),
 };
 
 pub const HOST_TRANSACTION_SUPPORT: PcuTransactionSupport = PcuTransactionSupport {
     features: PcuFeatureSupport::new(
-        crate::contract::drivers::pcu::PcuTransactionFeatureCaps::empty(),
-        crate::contract::drivers::pcu::PcuTransactionFeatureCaps::empty(),
+        HOST_CPU_TRANSACTION_DIRECT_SUPPORT,
+        PcuTransactionFeatureCaps::empty(),
     ),
 };
 
 pub const HOST_SIGNAL_SUPPORT: PcuSignalSupport = PcuSignalSupport {
-    instructions: PcuFeatureSupport::new(
-        crate::contract::drivers::pcu::PcuSignalOpCaps::empty(),
-        crate::contract::drivers::pcu::PcuSignalOpCaps::empty(),
-    ),
+    instructions: PcuFeatureSupport::new(HOST_CPU_SIGNAL_DIRECT_SUPPORT, PcuSignalOpCaps::empty()),
 };
 
 #[must_use]
@@ -129,7 +183,8 @@ pub const fn host_pcu_support() -> PcuSupport {
         caps: PcuCaps::ENUMERATE_EXECUTORS
             .union(PcuCaps::CLAIM_EXECUTOR)
             .union(PcuCaps::DISPATCH)
-            .union(PcuCaps::COMPLETION_STATUS),
+            .union(PcuCaps::COMPLETION_STATUS)
+            .union(PcuCaps::COMPUTE_DISPATCH),
         implementation: PcuImplementationKind::Native,
         executor_count: 1,
         primitive_support: HOST_PRIMITIVE_SUPPORT,
@@ -141,40 +196,6 @@ pub const fn host_pcu_support() -> PcuSupport {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct HostedCpuUnsupportedFiniteHandle;
-
-impl PcuFiniteHandle for HostedCpuUnsupportedFiniteHandle {
-    fn state(&self) -> Result<PcuFiniteState, PcuError> {
-        Err(PcuError::unsupported())
-    }
-
-    fn wait(self) -> Result<(), PcuError> {
-        Err(PcuError::unsupported())
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct HostedCpuUnsupportedPersistentHandle;
-
-impl PcuPersistentHandle for HostedCpuUnsupportedPersistentHandle {
-    fn state(&self) -> Result<PcuPersistentState, PcuError> {
-        Err(PcuError::unsupported())
-    }
-
-    fn start(&mut self) -> Result<(), PcuError> {
-        Err(PcuError::unsupported())
-    }
-
-    fn stop(&mut self) -> Result<(), PcuError> {
-        Err(PcuError::unsupported())
-    }
-
-    fn uninstall(self) -> Result<(), PcuError> {
-        Err(PcuError::unsupported())
-    }
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum HostedCpuStreamState {
     Dormant,
@@ -454,6 +475,7 @@ mod tests {
         PcuStreamInstallation,
     };
     use crate::contract::drivers::pcu::{
+        PcuDispatchOpCaps,
         PcuInvocationBindings,
         PcuInvocationParameters,
         PcuPrimitiveCaps,
@@ -478,18 +500,23 @@ mod tests {
     }
 
     #[test]
-    fn hosted_support_reports_direct_stream_only() {
+    fn hosted_support_reports_every_interpreted_primitive() {
         let support = host_pcu_support();
 
         assert!(
             support
                 .primitive_support
-                .supports_direct(PcuPrimitiveCaps::STREAM)
+                .supports_direct(PcuPrimitiveCaps::all())
         );
         assert!(
             !support
                 .primitive_support
-                .supports_direct(PcuPrimitiveCaps::COMMAND)
+                .supports_cpu_fallback(PcuPrimitiveCaps::COMMAND)
+        );
+        assert!(
+            !support
+                .dispatch_support
+                .supports_direct_instructions(PcuDispatchOpCaps::CONTROL_BRANCH)
         );
         assert_eq!(support.executor_count, 1);
         assert!(
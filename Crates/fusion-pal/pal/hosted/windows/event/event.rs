@@ -78,6 +78,14 @@ pub const fn system_event() -> PlatformEvent {
     PlatformEvent::new()
 }
 
+/// Returns the event provider for consumers that only wait for readiness.
+///
+/// Windows has one event surface, so this is the same provider as [`system_event`].
+#[must_use]
+pub const fn system_readiness_event() -> PlatformEvent {
+    PlatformEvent::new()
+}
+
 impl WindowsEvent {
     /// Creates a new Windows event provider handle.
     #[must_use]
@@ -232,6 +240,8 @@ fn completion_record(key: usize, transferred: u32, success: bool) -> EventRecord
         notification: EventNotification::Completion(EventCompletion {
             bytes_transferred: Some(transferred as usize),
             success,
+            source: None,
+            error: None,
         }),
     }
 }
@@ -281,6 +291,7 @@ mod tests {
         EventCaps,
         EventCompletionOp,
         EventCompletionOpKind,
+        EventCompletionTarget,
         EventModel,
         EventSourceContract,
     };
@@ -307,6 +318,7 @@ mod tests {
                 EventCompletionOp {
                     source: crate::contract::pal::runtime::event::EventSourceHandle(0),
                     kind: EventCompletionOpKind::Custom(1),
+                    target: EventCompletionTarget::None,
                     user_data: 0x55AA,
                 },
             )
@@ -318,6 +330,8 @@ mod tests {
                 crate::contract::pal::runtime::event::EventCompletion {
                     bytes_transferred: None,
                     success: false,
+                    source: None,
+                    error: None,
                 },
             ),
         }; 1];
@@ -4,6 +4,9 @@ pub mod hosted;
 #[path = "soc/soc.rs"]
 pub mod soc;
 
+#[path = "pcu/pcu.rs"]
+pub mod pcu;
+
 pub mod selected {
     include!(concat!(env!("OUT_DIR"), "/selected_pal.rs"));
 }
@@ -0,0 +1,5 @@
+//! Target-neutral PCU lowering shared by every PAL lane.
+
+#[path = "spirv.rs"]
+/// SPIR-V lowering for PCU dispatch kernels.
+pub mod spirv;
@@ -0,0 +1,2005 @@
+//! SPIR-V lowering for PCU dispatch kernels.
+//!
+//! The writer lowers one [`PcuDispatchKernelIr`] into one `GLCompute` shader module without
+//! allocating: every word lands in caller-supplied storage, so the same path serves hosted Vulkan
+//! backends and offline tooling alike. Dispatch ops carry no operands, so lowering follows the
+//! operand-stack convention the hosted CPU interpreter fixes, and one kernel means the same thing
+//! on both:
+//! - the logical shape becomes the workgroup size, and each invocation addresses its
+//!   `LocalInvocationIndex`, with the whole dispatch forming one workgroup; shapes past the
+//!   128-invocation, 128 x 128 x 64 workgroup every Vulkan device accepts are rejected rather than
+//!   split, since splitting would change what `GroupId`, `GroupCount` and `Barrier` mean
+//! - `Constant` loads the next declared parameter from one push-constant block holding every
+//!   parameter in declaration order, each aligned to its own size
+//! - `Load` and `Store` address element `invocation` of the next readable or writable binding, each
+//!   lowered to one storage buffer wrapping a runtime array at its declared set and binding
+//! - builtin bindings read `LocalInvocationIndex`, `WorkgroupId.x`, or `NumWorkgroups.x`
+//! - shift amounts at or past the operand width saturate the way the interpreter does
+//! - `Barrier` and `Fence` become workgroup control and device memory barriers
+//!
+//! Ops without one SPIR-V lowering fail with their position and [`PcuDispatchOpCaps`] flag before
+//! any word is written.
+
+use crate::contract::drivers::pcu::{
+    PcuBinding,
+    PcuBindingAccess,
+    PcuBindingRef,
+    PcuBindingStorageClass,
+    PcuBuiltinValue,
+    PcuDispatchAluOp,
+    PcuDispatchControlOp,
+    PcuDispatchKernelIr,
+    PcuDispatchOp,
+    PcuDispatchOpCaps,
+    PcuDispatchResourceOp,
+    PcuDispatchSyncOp,
+    PcuDispatchValueOp,
+    PcuError,
+    PcuKernelId,
+    PcuParameter,
+    PcuScalarType,
+    PcuValueType,
+    PcuValueTypeCaps,
+};
+
+/// Dispatch instructions the SPIR-V writer lowers.
+pub const SPIRV_DISPATCH_DIRECT_SUPPORT: PcuDispatchOpCaps = PcuDispatchOpCaps::VALUE_CONSTANT
+    .union(PcuDispatchOpCaps::ALU_ADD)
+    .union(PcuDispatchOpCaps::ALU_SUB)
+    .union(PcuDispatchOpCaps::ALU_MUL)
+    .union(PcuDispatchOpCaps::ALU_DIV)
+    .union(PcuDispatchOpCaps::ALU_MIN)
+    .union(PcuDispatchOpCaps::ALU_MAX)
+    .union(PcuDispatchOpCaps::ALU_AND)
+    .union(PcuDispatchOpCaps::ALU_OR)
+    .union(PcuDispatchOpCaps::ALU_XOR)
+    .union(PcuDispatchOpCaps::ALU_SHIFT_LEFT)
+    .union(PcuDispatchOpCaps::ALU_SHIFT_RIGHT)
+    .union(PcuDispatchOpCaps::ALU_COMPARE)
+    .union(PcuDispatchOpCaps::ALU_SELECT)
+    .union(PcuDispatchOpCaps::CONTROL_RETURN)
+    .union(PcuDispatchOpCaps::BINDING_LOAD)
+    .union(PcuDispatchOpCaps::BINDING_STORE)
+    .union(PcuDispatchOpCaps::SYNC_BARRIER)
+    .union(PcuDispatchOpCaps::SYNC_FENCE);
+
+/// Value types the SPIR-V writer lowers. Booleans only ever live between ops.
+pub const SPIRV_DISPATCH_TYPE_SUPPORT: PcuValueTypeCaps = PcuValueTypeCaps::BOOL
+    .union(PcuValueTypeCaps::INT32)
+    .union(PcuValueTypeCaps::UINT32)
+    .union(PcuValueTypeCaps::INT64)
+    .union(PcuValueTypeCaps::UINT64)
+    .union(PcuValueTypeCaps::FLOAT32)
+    .union(PcuValueTypeCaps::FLOAT64)
+    .union(PcuValueTypeCaps::SCALAR_VALUES);
+
+const SPIRV_MAGIC: u32 = 0x0723_0203;
+const SPIRV_VERSION_1_3: u32 = 0x0001_0300;
+const SPIRV_MAX_OPERAND_STACK: usize = 16;
+const SPIRV_GLSL_STD_450: &str = "GLSL.std.450";
+
+const SPIRV_OP_NAME: u16 = 5;
+const SPIRV_OP_MEMBER_NAME: u16 = 6;
+const SPIRV_OP_EXT_INST_IMPORT: u16 = 11;
+const SPIRV_OP_EXT_INST: u16 = 12;
+const SPIRV_OP_MEMORY_MODEL: u16 = 14;
+const SPIRV_OP_ENTRY_POINT: u16 = 15;
+const SPIRV_OP_EXECUTION_MODE: u16 = 16;
+const SPIRV_OP_CAPABILITY: u16 = 17;
+const SPIRV_OP_TYPE_VOID: u16 = 19;
+const SPIRV_OP_TYPE_BOOL: u16 = 20;
+const SPIRV_OP_TYPE_INT: u16 = 21;
+const SPIRV_OP_TYPE_FLOAT: u16 = 22;
+const SPIRV_OP_TYPE_VECTOR: u16 = 23;
+const SPIRV_OP_TYPE_RUNTIME_ARRAY: u16 = 29;
+const SPIRV_OP_TYPE_STRUCT: u16 = 30;
+const SPIRV_OP_TYPE_POINTER: u16 = 32;
+const SPIRV_OP_TYPE_FUNCTION: u16 = 33;
+const SPIRV_OP_CONSTANT: u16 = 43;
+const SPIRV_OP_FUNCTION: u16 = 54;
+const SPIRV_OP_FUNCTION_END: u16 = 56;
+const SPIRV_OP_VARIABLE: u16 = 59;
+const SPIRV_OP_LOAD: u16 = 61;
+const SPIRV_OP_STORE: u16 = 62;
+const SPIRV_OP_ACCESS_CHAIN: u16 = 65;
+const SPIRV_OP_DECORATE: u16 = 71;
+const SPIRV_OP_MEMBER_DECORATE: u16 = 72;
+const SPIRV_OP_COMPOSITE_EXTRACT: u16 = 81;
+const SPIRV_OP_BITCAST: u16 = 124;
+const SPIRV_OP_IADD: u16 = 128;
+const SPIRV_OP_FADD: u16 = 129;
+const SPIRV_OP_ISUB: u16 = 130;
+const SPIRV_OP_FSUB: u16 = 131;
+const SPIRV_OP_IMUL: u16 = 132;
+const SPIRV_OP_FMUL: u16 = 133;
+const SPIRV_OP_UDIV: u16 = 134;
+const SPIRV_OP_SDIV: u16 = 135;
+const SPIRV_OP_FDIV: u16 = 136;
+const SPIRV_OP_LOGICAL_EQUAL: u16 = 164;
+const SPIRV_OP_LOGICAL_NOT_EQUAL: u16 = 165;
+const SPIRV_OP_LOGICAL_OR: u16 = 166;
+const SPIRV_OP_LOGICAL_AND: u16 = 167;
+const SPIRV_OP_SELECT: u16 = 169;
+const SPIRV_OP_IEQUAL: u16 = 170;
+const SPIRV_OP_ULESS_THAN: u16 = 176;
+const SPIRV_OP_FORD_EQUAL: u16 = 180;
+const SPIRV_OP_SHIFT_RIGHT_LOGICAL: u16 = 194;
+const SPIRV_OP_SHIFT_RIGHT_ARITHMETIC: u16 = 195;
+const SPIRV_OP_SHIFT_LEFT_LOGICAL: u16 = 196;
+const SPIRV_OP_BITWISE_OR: u16 = 197;
+const SPIRV_OP_BITWISE_XOR: u16 = 198;
+const SPIRV_OP_BITWISE_AND: u16 = 199;
+const SPIRV_OP_CONTROL_BARRIER: u16 = 224;
+const SPIRV_OP_MEMORY_BARRIER: u16 = 225;
+const SPIRV_OP_LABEL: u16 = 248;
+const SPIRV_OP_RETURN: u16 = 253;
+
+const SPIRV_CAPABILITY_SHADER: u32 = 1;
+const SPIRV_CAPABILITY_FLOAT64: u32 = 10;
+const SPIRV_CAPABILITY_INT64: u32 = 11;
+const SPIRV_ADDRESSING_LOGICAL: u32 = 0;
+const SPIRV_MEMORY_MODEL_GLSL450: u32 = 1;
+const SPIRV_EXECUTION_MODEL_GLCOMPUTE: u32 = 5;
+const SPIRV_EXECUTION_MODE_LOCAL_SIZE: u32 = 17;
+const SPIRV_STORAGE_CLASS_INPUT: u32 = 1;
+const SPIRV_STORAGE_CLASS_PUSH_CONSTANT: u32 = 9;
+const SPIRV_STORAGE_CLASS_STORAGE_BUFFER: u32 = 12;
+const SPIRV_DECORATION_BLOCK: u32 = 2;
+const SPIRV_DECORATION_ARRAY_STRIDE: u32 = 6;
+const SPIRV_DECORATION_BUILTIN: u32 = 11;
+const SPIRV_DECORATION_NON_WRITABLE: u32 = 24;
+const SPIRV_DECORATION_NON_READABLE: u32 = 25;
+const SPIRV_DECORATION_BINDING: u32 = 33;
+const SPIRV_DECORATION_DESCRIPTOR_SET: u32 = 34;
+const SPIRV_DECORATION_OFFSET: u32 = 35;
+const SPIRV_BUILTIN_NUM_WORKGROUPS: u32 = 24;
+const SPIRV_BUILTIN_WORKGROUP_ID: u32 = 26;
+const SPIRV_BUILTIN_LOCAL_INVOCATION_INDEX: u32 = 29;
+const SPIRV_SCOPE_DEVICE: u32 = 1;
+const SPIRV_SCOPE_WORKGROUP: u32 = 2;
+const SPIRV_SEMANTICS_BARRIER: u32 = 0x0148;
+const SPIRV_SEMANTICS_FENCE: u32 = 0x0048;
+const SPIRV_GLSL_FMIN: u32 = 37;
+const SPIRV_GLSL_UMIN: u32 = 38;
+const SPIRV_GLSL_SMIN: u32 = 39;
+const SPIRV_GLSL_FMAX: u32 = 40;
+const SPIRV_GLSL_UMAX: u32 = 41;
+const SPIRV_GLSL_SMAX: u32 = 42;
+
+/// Scalar types the writer declares, indexed by their layout slot.
+const SPIRV_SCALARS: [PcuScalarType; 7] = [
+    PcuScalarType::Bool,
+    PcuScalarType::U32,
+    PcuScalarType::I32,
+    PcuScalarType::U64,
+    PcuScalarType::I64,
+    PcuScalarType::F32,
+    PcuScalarType::F64,
+];
+const SPIRV_SLOT_BOOL: usize = 0;
+const SPIRV_SLOT_U32: usize = 1;
+const SPIRV_SLOT_I32: usize = 2;
+
+/// Workgroup invocations every Vulkan device supports (`maxComputeWorkGroupInvocations`).
+const SPIRV_MAX_WORKGROUP_INVOCATIONS: u64 = 128;
+/// Workgroup size per axis every Vulkan device supports (`maxComputeWorkGroupSize`).
+const SPIRV_MAX_WORKGROUP_SIZE: [u32; 3] = [128, 128, 64];
+
+/// Ids each binding reserves: runtime array, block struct, block pointer, and variable.
+const SPIRV_IDS_PER_BINDING: u32 = 4;
+
+/// Lowering failures surfaced while emitting one SPIR-V module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PcuSpirvError {
+    /// The op at `index` has no SPIR-V lowering.
+    UnsupportedOp { index: usize, op: PcuDispatchOpCaps },
+    /// The kernel declares one value/type floor the writer cannot honour.
+    UnsupportedTypeSupport(PcuValueTypeCaps),
+    /// One binding or parameter carries a value type the writer cannot lay out.
+    UnsupportedType(PcuValueType),
+    /// One binding uses a storage class, builtin, or resource shape the writer cannot lay out.
+    UnsupportedBinding(PcuBindingRef),
+    /// The logical shape has one zero-sized axis or does not fit one portable workgroup.
+    InvalidShape([u32; 3]),
+    /// The op at `index` pops from an empty operand stack.
+    StackUnderflow { index: usize },
+    /// The op at `index` pushes past the operand stack depth.
+    StackOverflow { index: usize },
+    /// The `Constant` at `index` runs past the declared parameters.
+    MissingParameter { index: usize },
+    /// The `Load` or `Store` at `index` runs past the readable or writable bindings.
+    MissingBinding { index: usize },
+    /// The op at `index` mixes operand types or applies to operands it cannot take.
+    TypeMismatch { index: usize },
+    /// The module does not fit in the supplied storage.
+    StorageExhausted,
+}
+
+impl From<PcuSpirvError> for PcuError {
+    fn from(error: PcuSpirvError) -> Self {
+        match error {
+            PcuSpirvError::UnsupportedOp { .. } | PcuSpirvError::UnsupportedBinding(_) => {
+                Self::unsupported()
+            }
+            PcuSpirvError::UnsupportedTypeSupport(_) | PcuSpirvError::UnsupportedType(_) => {
+                Self::unsupported_type_support()
+            }
+            PcuSpirvError::StackOverflow { .. } | PcuSpirvError::StorageExhausted => {
+                Self::resource_exhausted()
+            }
+            PcuSpirvError::InvalidShape(_)
+            | PcuSpirvError::StackUnderflow { .. }
+            | PcuSpirvError::MissingParameter { .. }
+            | PcuSpirvError::MissingBinding { .. }
+            | PcuSpirvError::TypeMismatch { .. } => Self::invalid(),
+        }
+    }
+}
+
+/// One lowered SPIR-V module borrowed from caller storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcuSpirvModule<'a> {
+    pub id: PcuKernelId,
+    pub words: &'a [u32],
+}
+
+/// Lowers one dispatch kernel into one SPIR-V 1.3 `GLCompute` module.
+///
+/// # Errors
+///
+/// Returns `UnsupportedOp` for the first op outside [`SPIRV_DISPATCH_DIRECT_SUPPORT`], one
+/// unsupported type or binding error when the binding graph cannot be laid out, one
+/// operand-stack error naming the offending op, and `StorageExhausted` when `storage` is too
+/// small for the module.
+pub fn lower_spirv_dispatch_kernel<'a>(
+    kernel: &PcuDispatchKernelIr<'_>,
+    storage: &'a mut [u32],
+) -> Result<PcuSpirvModule<'a>, PcuSpirvError> {
+    let survey = SpirvSurvey::new(kernel)?;
+    let layout = SpirvLayout::new(kernel, &survey)?;
+    let mut lowering = SpirvLowering {
+        kernel,
+        layout,
+        writer: SpirvWriter {
+            words: storage,
+            len: 0,
+        },
+        stack: [SpirvValue {
+            id: 0,
+            scalar: PcuScalarType::Bool,
+        }; SPIRV_MAX_OPERAND_STACK],
+        depth: 0,
+        constant: 0,
+        load: 0,
+        store: 0,
+        invocation: 0,
+    };
+    lowering.emit_module()?;
+
+    let SpirvWriter { words, len } = lowering.writer;
+    Ok(PcuSpirvModule {
+        id: kernel.id,
+        words: &words[..len],
+    })
+}
+
+/// Scalar families that pick between integer, float, and logical instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpirvScalarClass {
+    Bool,
+    Unsigned,
+    Signed,
+    Float,
+}
+
+const fn spirv_scalar_slot(scalar: PcuScalarType) -> Option<usize> {
+    Some(match scalar {
+        PcuScalarType::Bool => SPIRV_SLOT_BOOL,
+        PcuScalarType::U32 => SPIRV_SLOT_U32,
+        PcuScalarType::I32 => SPIRV_SLOT_I32,
+        PcuScalarType::U64 => 3,
+        PcuScalarType::I64 => 4,
+        PcuScalarType::F32 => 5,
+        PcuScalarType::F64 => 6,
+        _ => return None,
+    })
+}
+
+const fn spirv_scalar_class(scalar: PcuScalarType) -> SpirvScalarClass {
+    match scalar {
+        PcuScalarType::Bool => SpirvScalarClass::Bool,
+        PcuScalarType::I4
+        | PcuScalarType::I8
+        | PcuScalarType::I16
+        | PcuScalarType::I32
+        | PcuScalarType::I64 => SpirvScalarClass::Signed,
+        PcuScalarType::F16 | PcuScalarType::BF16 | PcuScalarType::F32 | PcuScalarType::F64 => {
+            SpirvScalarClass::Float
+        }
+        PcuScalarType::U4
+        | PcuScalarType::U8
+        | PcuScalarType::U16
+        | PcuScalarType::U32
+        | PcuScalarType::U64 => SpirvScalarClass::Unsigned,
+    }
+}
+
+/// Returns the one scalar type one buffer or push-constant member can hold.
+fn spirv_memory_scalar(value_type: PcuValueType) -> Result<PcuScalarType, PcuSpirvError> {
+    match value_type {
+        PcuValueType::Scalar(scalar)
+            if scalar != PcuScalarType::Bool && spirv_scalar_slot(scalar).is_some() =>
+        {
+            Ok(scalar)
+        }
+        _ => Err(PcuSpirvError::UnsupportedType(value_type)),
+    }
+}
+
+const fn spirv_byte_size(scalar: PcuScalarType) -> u32 {
+    scalar.bit_width() as u32 / 8
+}
+
+/// Returns the `index`th binding `Load` reads, mirroring the interpreter's readable sequence.
+fn spirv_readable_binding<'k>(
+    bindings: &'k [PcuBinding<'k>],
+    index: usize,
+) -> Option<(usize, &'k PcuBinding<'k>)> {
+    bindings
+        .iter()
+        .enumerate()
+        .filter(|(_, binding)| {
+            binding.builtin.is_some() || binding.access != PcuBindingAccess::WriteOnly
+        })
+        .nth(index)
+}
+
+/// Returns the `index`th binding `Store` writes, mirroring the interpreter's writable sequence.
+fn spirv_writable_binding<'k>(
+    bindings: &'k [PcuBinding<'k>],
+    index: usize,
+) -> Option<(usize, &'k PcuBinding<'k>)> {
+    bindings
+        .iter()
+        .enumerate()
+        .filter(|(_, binding)| {
+            binding.builtin.is_none() && binding.access != PcuBindingAccess::ReadOnly
+        })
+        .nth(index)
+}
+
+/// Yields the push-constant offset of every parameter, in declaration order.
+fn spirv_push_offsets<'k>(
+    parameters: &'k [PcuParameter<'k>],
+) -> impl Iterator<Item = (usize, u32)> + 'k {
+    parameters
+        .iter()
+        .enumerate()
+        .scan(0_u32, |offset, (member, parameter)| {
+            let size = spirv_byte_size(parameter.value_type.scalar_type());
+            let aligned = offset.next_multiple_of(size);
+            *offset = aligned + size;
+            Some((member, aligned))
+        })
+}
+
+/// Declarations one kernel needs, gathered before any id is assigned.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Default)]
+struct SpirvSurvey {
+    scalars: [bool; SPIRV_SCALARS.len()],
+    storage_scalars: [bool; SPIRV_SCALARS.len()],
+    push_scalars: [bool; SPIRV_SCALARS.len()],
+    extended: bool,
+    shifts: bool,
+    sync: bool,
+    workgroup_id: bool,
+    num_workgroups: bool,
+}
+
+impl SpirvSurvey {
+    fn new(kernel: &PcuDispatchKernelIr<'_>) -> Result<Self, PcuSpirvError> {
+        let mut survey = Self::default();
+        survey.scalars[SPIRV_SLOT_U32] = true;
+
+        for (index, op) in kernel.ops.iter().copied().enumerate() {
+            if !SPIRV_DISPATCH_DIRECT_SUPPORT.contains(op.support_flag()) {
+                return Err(PcuSpirvError::UnsupportedOp {
+                    index,
+                    op: op.support_flag(),
+                });
+            }
+            match op {
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Min | PcuDispatchAluOp::Max) => {
+                    survey.extended = true;
+                }
+                PcuDispatchOp::Arithmetic(
+                    PcuDispatchAluOp::ShiftLeft | PcuDispatchAluOp::ShiftRight,
+                ) => {
+                    survey.shifts = true;
+                    survey.extended = true;
+                    survey.scalars[SPIRV_SLOT_BOOL] = true;
+                }
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Compare | PcuDispatchAluOp::Select) => {
+                    survey.scalars[SPIRV_SLOT_BOOL] = true;
+                }
+                PcuDispatchOp::Sync(_) => survey.sync = true,
+                _ => {}
+            }
+        }
+        if !SPIRV_DISPATCH_TYPE_SUPPORT.contains(kernel.required_type_support()) {
+            return Err(PcuSpirvError::UnsupportedTypeSupport(
+                kernel.required_type_support(),
+            ));
+        }
+        let shape = kernel.entry.logical_shape;
+        let invocations = shape.iter().map(|axis| u64::from(*axis)).product::<u64>();
+        if invocations == 0
+            || invocations > SPIRV_MAX_WORKGROUP_INVOCATIONS
+            || shape
+                .iter()
+                .zip(SPIRV_MAX_WORKGROUP_SIZE)
+                .any(|(axis, limit)| *axis > limit)
+        {
+            return Err(PcuSpirvError::InvalidShape(shape));
+        }
+
+        for binding in kernel.bindings {
+            let reference = binding.reference();
+            let value_type = binding
+                .value_type()
+                .ok_or(PcuSpirvError::UnsupportedBinding(reference))?;
+            if let Some(builtin) = binding.builtin {
+                if !matches!(
+                    value_type,
+                    PcuValueType::Scalar(PcuScalarType::U32 | PcuScalarType::I32)
+                ) {
+                    return Err(PcuSpirvError::UnsupportedBinding(reference));
+                }
+                match builtin {
+                    PcuBuiltinValue::InvocationId
+                    | PcuBuiltinValue::LaneId
+                    | PcuBuiltinValue::LaneIndex => {}
+                    PcuBuiltinValue::GroupId => survey.workgroup_id = true,
+                    PcuBuiltinValue::GroupCount => survey.num_workgroups = true,
+                    PcuBuiltinValue::Named(_) => {
+                        return Err(PcuSpirvError::UnsupportedBinding(reference));
+                    }
+                }
+                survey.mark(value_type.scalar_type());
+                continue;
+            }
+
+            if !matches!(
+                binding.storage,
+                PcuBindingStorageClass::Storage
+                    | PcuBindingStorageClass::Input
+                    | PcuBindingStorageClass::Output
+            ) {
+                return Err(PcuSpirvError::UnsupportedBinding(reference));
+            }
+            let scalar = spirv_memory_scalar(value_type)?;
+            survey.mark(scalar);
+            if let Some(slot) = spirv_scalar_slot(scalar) {
+                survey.storage_scalars[slot] = true;
+            }
+        }
+
+        for parameter in kernel.parameters {
+            let scalar = spirv_memory_scalar(parameter.value_type)?;
+            survey.mark(scalar);
+            if let Some(slot) = spirv_scalar_slot(scalar) {
+                survey.push_scalars[slot] = true;
+            }
+        }
+
+        Ok(survey)
+    }
+
+    const fn mark(&mut self, scalar: PcuScalarType) {
+        if let Some(slot) = spirv_scalar_slot(scalar) {
+            self.scalars[slot] = true;
+        }
+    }
+
+    const fn uses_vector_builtins(&self) -> bool {
+        self.workgroup_id || self.num_workgroups
+    }
+}
+
+/// Hands out consecutive result ids.
+#[derive(Debug)]
+struct SpirvIds {
+    next: u32,
+}
+
+impl SpirvIds {
+    const fn take(&mut self, count: u32) -> u32 {
+        let id = self.next;
+        self.next += count;
+        id
+    }
+}
+
+/// Result ids of every module-level declaration. Zero marks one declaration the kernel omits.
+#[derive(Debug)]
+struct SpirvLayout {
+    extended: u32,
+    void: u32,
+    function_type: u32,
+    function: u32,
+    label: u32,
+    scalar_types: [u32; SPIRV_SCALARS.len()],
+    uvec3: u32,
+    storage_pointers: [u32; SPIRV_SCALARS.len()],
+    push_pointers: [u32; SPIRV_SCALARS.len()],
+    input_u32_pointer: u32,
+    input_uvec3_pointer: u32,
+    /// First of `max(parameters, 1)` consecutive `u32` constants counting up from zero.
+    indices: u32,
+    index_count: u32,
+    /// Device scope, workgroup scope, barrier semantics, and fence semantics, in order.
+    sync_constants: u32,
+    /// Zero, width, and width minus one of every shifted integer type, in order.
+    shift_constants: [u32; SPIRV_SCALARS.len()],
+    bindings: u32,
+    push_block: u32,
+    push_pointer: u32,
+    push_variable: u32,
+    local_index: u32,
+    workgroup_id: u32,
+    num_workgroups: u32,
+    next: u32,
+}
+
+impl SpirvLayout {
+    fn new(kernel: &PcuDispatchKernelIr<'_>, survey: &SpirvSurvey) -> Result<Self, PcuSpirvError> {
+        let binding_count =
+            u32::try_from(kernel.bindings.len()).map_err(|_| PcuSpirvError::StorageExhausted)?;
+        let parameter_count =
+            u32::try_from(kernel.parameters.len()).map_err(|_| PcuSpirvError::StorageExhausted)?;
+
+        let mut ids = SpirvIds { next: 1 };
+        let extended = if survey.extended { ids.take(1) } else { 0 };
+        let void = ids.take(1);
+        let function_type = ids.take(1);
+        let function = ids.take(1);
+        let label = ids.take(1);
+
+        let mut scalar_types = [0; SPIRV_SCALARS.len()];
+        let mut storage_pointers = [0; SPIRV_SCALARS.len()];
+        let mut push_pointers = [0; SPIRV_SCALARS.len()];
+        let mut shift_constants = [0; SPIRV_SCALARS.len()];
+        for (id, used) in scalar_types.iter_mut().zip(survey.scalars) {
+            if used {
+                *id = ids.take(1);
+            }
+        }
+        let uvec3 = if survey.uses_vector_builtins() {
+            ids.take(1)
+        } else {
+            0
+        };
+        for slot in 0..SPIRV_SCALARS.len() {
+            if survey.storage_scalars[slot] {
+                storage_pointers[slot] = ids.take(1);
+            }
+            if survey.push_scalars[slot] {
+                push_pointers[slot] = ids.take(1);
+            }
+        }
+        let input_u32_pointer = ids.take(1);
+        let input_uvec3_pointer = if survey.uses_vector_builtins() {
+            ids.take(1)
+        } else {
+            0
+        };
+
+        let index_count = parameter_count.max(1);
+        let indices = ids.take(index_count);
+        let sync_constants = if survey.sync { ids.take(4) } else { 0 };
+        if survey.shifts {
+            for (slot, scalar) in SPIRV_SCALARS.iter().copied().enumerate() {
+                if survey.scalars[slot]
+                    && matches!(
+                        spirv_scalar_class(scalar),
+                        SpirvScalarClass::Signed | SpirvScalarClass::Unsigned
+                    )
+                {
+                    shift_constants[slot] = ids.take(3);
+                }
+            }
+        }
+
+        let bindings = ids.take(
+            binding_count
+                .checked_mul(SPIRV_IDS_PER_BINDING)
+                .ok_or(PcuSpirvError::StorageExhausted)?,
+        );
+        let (push_block, push_pointer, push_variable) = if parameter_count == 0 {
+            (0, 0, 0)
+        } else {
+            (ids.take(1), ids.take(1), ids.take(1))
+        };
+        let local_index = ids.take(1);
+        let workgroup_id = if survey.workgroup_id { ids.take(1) } else { 0 };
+        let num_workgroups = if survey.num_workgroups {
+            ids.take(1)
+        } else {
+            0
+        };
+
+        Ok(Self {
+            extended,
+            void,
+            function_type,
+            function,
+            label,
+            scalar_types,
+            uvec3,
+            storage_pointers,
+            push_pointers,
+            input_u32_pointer,
+            input_uvec3_pointer,
+            indices,
+            index_count,
+            sync_constants,
+            shift_constants,
+            bindings,
+            push_block,
+            push_pointer,
+            push_variable,
+            local_index,
+            workgroup_id,
+            num_workgroups,
+            next: ids.next,
+        })
+    }
+
+    fn scalar_type(&self, scalar: PcuScalarType) -> u32 {
+        spirv_scalar_slot(scalar).map_or(0, |slot| self.scalar_types[slot])
+    }
+
+    const fn binding_ids(&self, index: usize) -> [u32; SPIRV_IDS_PER_BINDING as usize] {
+        #[allow(clippy::cast_possible_truncation)]
+        let base = self.bindings + index as u32 * SPIRV_IDS_PER_BINDING;
+        [base, base + 1, base + 2, base + 3]
+    }
+}
+
+/// Bounded word sink over caller-supplied storage.
+#[derive(Debug)]
+struct SpirvWriter<'a> {
+    words: &'a mut [u32],
+    len: usize,
+}
+
+impl SpirvWriter<'_> {
+    fn word(&mut self, word: u32) -> Result<(), PcuSpirvError> {
+        let slot = self
+            .words
+            .get_mut(self.len)
+            .ok_or(PcuSpirvError::StorageExhausted)?;
+        *slot = word;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn begin(&mut self, opcode: u16, operand_words: usize) -> Result<(), PcuSpirvError> {
+        let count =
+            u16::try_from(operand_words + 1).map_err(|_| PcuSpirvError::StorageExhausted)?;
+        self.word((u32::from(count) << 16) | u32::from(opcode))
+    }
+
+    fn instruction(&mut self, opcode: u16, operands: &[u32]) -> Result<(), PcuSpirvError> {
+        self.begin(opcode, operands.len())?;
+        for operand in operands.iter().copied() {
+            self.word(operand)?;
+        }
+        Ok(())
+    }
+
+    /// Writes one instruction whose literal string sits between two operand runs.
+    fn instruction_with_string(
+        &mut self,
+        opcode: u16,
+        leading: &[u32],
+        text: &str,
+        trailing: &[u32],
+    ) -> Result<(), PcuSpirvError> {
+        // One nul terminator always follows the text, padded out to one whole word.
+        let string_words = text.len() / 4 + 1;
+        self.begin(opcode, leading.len() + string_words + trailing.len())?;
+        for operand in leading.iter().copied() {
+            self.word(operand)?;
+        }
+        let bytes = text.as_bytes();
+        for chunk in 0..string_words {
+            let mut word = [0_u8; 4];
+            for (offset, byte) in word.iter_mut().enumerate() {
+                *byte = bytes.get(chunk * 4 + offset).copied().unwrap_or(0);
+            }
+            self.word(u32::from_le_bytes(word))?;
+        }
+        for operand in trailing.iter().copied() {
+            self.word(operand)?;
+        }
+        Ok(())
+    }
+}
+
+/// One SSA value on the lowering operand stack.
+#[derive(Debug, Clone, Copy)]
+struct SpirvValue {
+    id: u32,
+    scalar: PcuScalarType,
+}
+
+/// Module emission state for one kernel.
+#[derive(Debug)]
+struct SpirvLowering<'k, 'w> {
+    kernel: &'k PcuDispatchKernelIr<'k>,
+    layout: SpirvLayout,
+    writer: SpirvWriter<'w>,
+    stack: [SpirvValue; SPIRV_MAX_OPERAND_STACK],
+    depth: usize,
+    constant: usize,
+    load: usize,
+    store: usize,
+    invocation: u32,
+}
+
+impl SpirvLowering<'_, '_> {
+    fn emit_module(&mut self) -> Result<(), PcuSpirvError> {
+        for word in [SPIRV_MAGIC, SPIRV_VERSION_1_3, 0, 0, 0] {
+            self.writer.word(word)?;
+        }
+        self.emit_preamble()?;
+        self.emit_annotations()?;
+        self.emit_declarations()?;
+        self.emit_function()?;
+        // The id bound is only known once the function body has taken its result ids.
+        self.writer.words[3] = self.layout.next;
+        Ok(())
+    }
+
+    fn emit_preamble(&mut self) -> Result<(), PcuSpirvError> {
+        let layout = &self.layout;
+        let writer = &mut self.writer;
+        writer.instruction(SPIRV_OP_CAPABILITY, &[SPIRV_CAPABILITY_SHADER])?;
+        if layout.scalar_type(PcuScalarType::U64) != 0
+            || layout.scalar_type(PcuScalarType::I64) != 0
+        {
+            writer.instruction(SPIRV_OP_CAPABILITY, &[SPIRV_CAPABILITY_INT64])?;
+        }
+        if layout.scalar_type(PcuScalarType::F64) != 0 {
+            writer.instruction(SPIRV_OP_CAPABILITY, &[SPIRV_CAPABILITY_FLOAT64])?;
+        }
+        if layout.extended != 0 {
+            writer.instruction_with_string(
+                SPIRV_OP_EXT_INST_IMPORT,
+                &[layout.extended],
+                SPIRV_GLSL_STD_450,
+                &[],
+            )?;
+        }
+        writer.instruction(
+            SPIRV_OP_MEMORY_MODEL,
+            &[SPIRV_ADDRESSING_LOGICAL, SPIRV_MEMORY_MODEL_GLSL450],
+        )?;
+
+        let mut interface = [layout.local_index, 0, 0];
+        let mut interface_len = 1;
+        for variable in [layout.workgroup_id, layout.num_workgroups] {
+            if variable != 0 {
+                interface[interface_len] = variable;
+                interface_len += 1;
+            }
+        }
+        writer.instruction_with_string(
+            SPIRV_OP_ENTRY_POINT,
+            &[SPIRV_EXECUTION_MODEL_GLCOMPUTE, layout.function],
+            self.kernel.entry.name,
+            &interface[..interface_len],
+        )?;
+        let [x, y, z] = self.kernel.entry.logical_shape;
+        writer.instruction(
+            SPIRV_OP_EXECUTION_MODE,
+            &[layout.function, SPIRV_EXECUTION_MODE_LOCAL_SIZE, x, y, z],
+        )?;
+
+        for (index, binding) in self.kernel.bindings.iter().enumerate() {
+            if let Some(name) = binding.name
+                && binding.builtin.is_none()
+            {
+                let [_, _, _, variable] = layout.binding_ids(index);
+                writer.instruction_with_string(SPIRV_OP_NAME, &[variable], name, &[])?;
+            }
+        }
+        for (member, parameter) in self.kernel.parameters.iter().enumerate() {
+            if let Some(name) = parameter.name {
+                #[allow(clippy::cast_possible_truncation)]
+                let member = member as u32;
+                writer.instruction_with_string(
+                    SPIRV_OP_MEMBER_NAME,
+                    &[layout.push_block, member],
+                    name,
+                    &[],
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn emit_annotations(&mut self) -> Result<(), PcuSpirvError> {
+        let layout = &self.layout;
+        let writer = &mut self.writer;
+        for (variable, builtin) in [
+            (layout.local_index, SPIRV_BUILTIN_LOCAL_INVOCATION_INDEX),
+            (layout.workgroup_id, SPIRV_BUILTIN_WORKGROUP_ID),
+            (layout.num_workgroups, SPIRV_BUILTIN_NUM_WORKGROUPS),
+        ] {
+            if variable != 0 {
+                writer.instruction(
+                    SPIRV_OP_DECORATE,
+                    &[variable, SPIRV_DECORATION_BUILTIN, builtin],
+                )?;
+            }
+        }
+
+        for (index, binding) in self.kernel.bindings.iter().enumerate() {
+            if binding.builtin.is_some() {
+                continue;
+            }
+            let Some(value_type) = binding.value_type() else {
+                return Err(PcuSpirvError::UnsupportedBinding(binding.reference()));
+            };
+            let [array, block, _, variable] = layout.binding_ids(index);
+            let stride = spirv_byte_size(value_type.scalar_type());
+            writer.instruction(
+                SPIRV_OP_DECORATE,
+                &[array, SPIRV_DECORATION_ARRAY_STRIDE, stride],
+            )?;
+            writer.instruction(SPIRV_OP_DECORATE, &[block, SPIRV_DECORATION_BLOCK])?;
+            writer.instruction(
+                SPIRV_OP_MEMBER_DECORATE,
+                &[block, 0, SPIRV_DECORATION_OFFSET, 0],
+            )?;
+            match binding.access {
+                PcuBindingAccess::ReadOnly => writer.instruction(
+                    SPIRV_OP_MEMBER_DECORATE,
+                    &[block, 0, SPIRV_DECORATION_NON_WRITABLE],
+                )?,
+                PcuBindingAccess::WriteOnly => writer.instruction(
+                    SPIRV_OP_MEMBER_DECORATE,
+                    &[block, 0, SPIRV_DECORATION_NON_READABLE],
+                )?,
+                PcuBindingAccess::ReadWrite => {}
+            }
+            writer.instruction(
+                SPIRV_OP_DECORATE,
+                &[variable, SPIRV_DECORATION_DESCRIPTOR_SET, binding.set],
+            )?;
+            writer.instruction(
+                SPIRV_OP_DECORATE,
+                &[variable, SPIRV_DECORATION_BINDING, binding.binding],
+            )?;
+        }
+
+        if layout.push_block != 0 {
+            writer.instruction(
+                SPIRV_OP_DECORATE,
+                &[layout.push_block, SPIRV_DECORATION_BLOCK],
+            )?;
+            for (member, offset) in spirv_push_offsets(self.kernel.parameters) {
+                #[allow(clippy::cast_possible_truncation)]
+                let member = member as u32;
+                writer.instruction(
+                    SPIRV_OP_MEMBER_DECORATE,
+                    &[layout.push_block, member, SPIRV_DECORATION_OFFSET, offset],
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn emit_declarations(&mut self) -> Result<(), PcuSpirvError> {
+        self.emit_types()?;
+        self.emit_constants()?;
+        self.emit_variables()
+    }
+
+    fn emit_types(&mut self) -> Result<(), PcuSpirvError> {
+        let layout = &self.layout;
+        let writer = &mut self.writer;
+        writer.instruction(SPIRV_OP_TYPE_VOID, &[layout.void])?;
+        writer.instruction(SPIRV_OP_TYPE_FUNCTION, &[layout.function_type, layout.void])?;
+        for (slot, scalar) in SPIRV_SCALARS.iter().copied().enumerate() {
+            let id = layout.scalar_types[slot];
+            if id == 0 {
+                continue;
+            }
+            let width = u32::from(scalar.bit_width());
+            match spirv_scalar_class(scalar) {
+                SpirvScalarClass::Bool => writer.instruction(SPIRV_OP_TYPE_BOOL, &[id])?,
+                SpirvScalarClass::Unsigned => {
+                    writer.instruction(SPIRV_OP_TYPE_INT, &[id, width, 0])?;
+                }
+                SpirvScalarClass::Signed => {
+                    writer.instruction(SPIRV_OP_TYPE_INT, &[id, width, 1])?;
+                }
+                SpirvScalarClass::Float => writer.instruction(SPIRV_OP_TYPE_FLOAT, &[id, width])?,
+            }
+        }
+        let u32_type = layout.scalar_types[SPIRV_SLOT_U32];
+        if layout.uvec3 != 0 {
+            writer.instruction(SPIRV_OP_TYPE_VECTOR, &[layout.uvec3, u32_type, 3])?;
+        }
+        for slot in 0..SPIRV_SCALARS.len() {
+            let element = layout.scalar_types[slot];
+            if layout.storage_pointers[slot] != 0 {
+                writer.instruction(
+                    SPIRV_OP_TYPE_POINTER,
+                    &[
+                        layout.storage_pointers[slot],
+                        SPIRV_STORAGE_CLASS_STORAGE_BUFFER,
+                        element,
+                    ],
+                )?;
+            }
+            if layout.push_pointers[slot] != 0 {
+                writer.instruction(
+                    SPIRV_OP_TYPE_POINTER,
+                    &[
+                        layout.push_pointers[slot],
+                        SPIRV_STORAGE_CLASS_PUSH_CONSTANT,
+                        element,
+                    ],
+                )?;
+            }
+        }
+        writer.instruction(
+            SPIRV_OP_TYPE_POINTER,
+            &[
+                layout.input_u32_pointer,
+                SPIRV_STORAGE_CLASS_INPUT,
+                u32_type,
+            ],
+        )?;
+        if layout.input_uvec3_pointer != 0 {
+            writer.instruction(
+                SPIRV_OP_TYPE_POINTER,
+                &[
+                    layout.input_uvec3_pointer,
+                    SPIRV_STORAGE_CLASS_INPUT,
+                    layout.uvec3,
+                ],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn emit_constants(&mut self) -> Result<(), PcuSpirvError> {
+        let layout = &self.layout;
+        let writer = &mut self.writer;
+        let u32_type = layout.scalar_types[SPIRV_SLOT_U32];
+        for value in 0..layout.index_count {
+            writer.instruction(
+                SPIRV_OP_CONSTANT,
+                &[u32_type, layout.indices + value, value],
+            )?;
+        }
+        if layout.sync_constants != 0 {
+            for (offset, value) in [
+                SPIRV_SCOPE_DEVICE,
+                SPIRV_SCOPE_WORKGROUP,
+                SPIRV_SEMANTICS_BARRIER,
+                SPIRV_SEMANTICS_FENCE,
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                #[allow(clippy::cast_possible_truncation)]
+                let id = layout.sync_constants + offset as u32;
+                writer.instruction(SPIRV_OP_CONSTANT, &[u32_type, id, value])?;
+            }
+        }
+        for (slot, scalar) in SPIRV_SCALARS.iter().copied().enumerate() {
+            let base = layout.shift_constants[slot];
+            if base == 0 {
+                continue;
+            }
+            let element = layout.scalar_types[slot];
+            let width = u32::from(scalar.bit_width());
+            for (offset, value) in [0, width, width - 1].into_iter().enumerate() {
+                #[allow(clippy::cast_possible_truncation)]
+                let id = base + offset as u32;
+                if width == 64 {
+                    writer.instruction(SPIRV_OP_CONSTANT, &[element, id, value, 0])?;
+                } else {
+                    writer.instruction(SPIRV_OP_CONSTANT, &[element, id, value])?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn emit_variables(&mut self) -> Result<(), PcuSpirvError> {
+        let layout = &self.layout;
+        let writer = &mut self.writer;
+        for (index, binding) in self.kernel.bindings.iter().enumerate() {
+            if binding.builtin.is_some() {
+                continue;
+            }
+            let Some(value_type) = binding.value_type() else {
+                return Err(PcuSpirvError::UnsupportedBinding(binding.reference()));
+            };
+            let [array, block, pointer, variable] = layout.binding_ids(index);
+            writer.instruction(
+                SPIRV_OP_TYPE_RUNTIME_ARRAY,
+                &[array, layout.scalar_type(value_type.scalar_type())],
+            )?;
+            writer.instruction(SPIRV_OP_TYPE_STRUCT, &[block, array])?;
+            writer.instruction(
+                SPIRV_OP_TYPE_POINTER,
+                &[pointer, SPIRV_STORAGE_CLASS_STORAGE_BUFFER, block],
+            )?;
+            writer.instruction(
+                SPIRV_OP_VARIABLE,
+                &[pointer, variable, SPIRV_STORAGE_CLASS_STORAGE_BUFFER],
+            )?;
+        }
+
+        if layout.push_block != 0 {
+            writer.begin(SPIRV_OP_TYPE_STRUCT, 1 + self.kernel.parameters.len())?;
+            writer.word(layout.push_block)?;
+            for parameter in self.kernel.parameters {
+                writer.word(layout.scalar_type(parameter.value_type.scalar_type()))?;
+            }
+            writer.instruction(
+                SPIRV_OP_TYPE_POINTER,
+                &[
+                    layout.push_pointer,
+                    SPIRV_STORAGE_CLASS_PUSH_CONSTANT,
+                    layout.push_block,
+                ],
+            )?;
+            writer.instruction(
+                SPIRV_OP_VARIABLE,
+                &[
+                    layout.push_pointer,
+                    layout.push_variable,
+                    SPIRV_STORAGE_CLASS_PUSH_CONSTANT,
+                ],
+            )?;
+        }
+
+        writer.instruction(
+            SPIRV_OP_VARIABLE,
+            &[
+                layout.input_u32_pointer,
+                layout.local_index,
+                SPIRV_STORAGE_CLASS_INPUT,
+            ],
+        )?;
+        for variable in [layout.workgroup_id, layout.num_workgroups] {
+            if variable != 0 {
+                writer.instruction(
+                    SPIRV_OP_VARIABLE,
+                    &[
+                        layout.input_uvec3_pointer,
+                        variable,
+                        SPIRV_STORAGE_CLASS_INPUT,
+                    ],
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn emit_function(&mut self) -> Result<(), PcuSpirvError> {
+        self.writer.instruction(
+            SPIRV_OP_FUNCTION,
+            &[
+                self.layout.void,
+                self.layout.function,
+                0,
+                self.layout.function_type,
+            ],
+        )?;
+        self.writer
+            .instruction(SPIRV_OP_LABEL, &[self.layout.label])?;
+        self.invocation = self.fresh();
+        self.writer.instruction(
+            SPIRV_OP_LOAD,
+            &[
+                self.layout.scalar_types[SPIRV_SLOT_U32],
+                self.invocation,
+                self.layout.local_index,
+            ],
+        )?;
+
+        for (index, op) in self.kernel.ops.iter().copied().enumerate() {
+            match op {
+                PcuDispatchOp::Value(PcuDispatchValueOp::Constant) => self.emit_constant(index)?,
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Compare) => self.emit_compare(index)?,
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Select) => self.emit_select(index)?,
+                PcuDispatchOp::Arithmetic(op) => self.emit_binary(index, op)?,
+                PcuDispatchOp::Control(PcuDispatchControlOp::Return) => break,
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Load) => self.emit_load(index)?,
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Store) => self.emit_store(index)?,
+                PcuDispatchOp::Sync(PcuDispatchSyncOp::Barrier) => {
+                    let scope = self.layout.sync_constants + 1;
+                    let semantics = self.layout.sync_constants + 2;
+                    self.writer
+                        .instruction(SPIRV_OP_CONTROL_BARRIER, &[scope, scope, semantics])?;
+                }
+                PcuDispatchOp::Sync(PcuDispatchSyncOp::Fence) => {
+                    let scope = self.layout.sync_constants;
+                    let semantics = self.layout.sync_constants + 3;
+                    self.writer
+                        .instruction(SPIRV_OP_MEMORY_BARRIER, &[scope, semantics])?;
+                }
+                _ => {
+                    return Err(PcuSpirvError::UnsupportedOp {
+                        index,
+                        op: op.support_flag(),
+                    });
+                }
+            }
+        }
+
+        self.writer.instruction(SPIRV_OP_RETURN, &[])?;
+        self.writer.instruction(SPIRV_OP_FUNCTION_END, &[])
+    }
+
+    fn emit_constant(&mut self, index: usize) -> Result<(), PcuSpirvError> {
+        let member = self.constant;
+        let parameter = self
+            .kernel
+            .parameters
+            .get(member)
+            .ok_or(PcuSpirvError::MissingParameter { index })?;
+        self.constant += 1;
+
+        let scalar = parameter.value_type.scalar_type();
+        let slot = spirv_scalar_slot(scalar)
+            .ok_or(PcuSpirvError::UnsupportedType(parameter.value_type))?;
+        #[allow(clippy::cast_possible_truncation)]
+        let member_index = self.layout.indices + member as u32;
+        let pointer = self.fresh();
+        self.writer.instruction(
+            SPIRV_OP_ACCESS_CHAIN,
+            &[
+                self.layout.push_pointers[slot],
+                pointer,
+                self.layout.push_variable,
+                member_index,
+            ],
+        )?;
+        let value = self.fresh();
+        self.writer.instruction(
+            SPIRV_OP_LOAD,
+            &[self.layout.scalar_types[slot], value, pointer],
+        )?;
+        self.push(index, value, scalar)
+    }
+
+    fn emit_load(&mut self, index: usize) -> Result<(), PcuSpirvError> {
+        let (binding_index, binding) = spirv_readable_binding(self.kernel.bindings, self.load)
+            .ok_or(PcuSpirvError::MissingBinding { index })?;
+        self.load += 1;
+        let value_type = binding
+            .value_type()
+            .ok_or_else(|| PcuSpirvError::UnsupportedBinding(binding.reference()))?;
+        let scalar = value_type.scalar_type();
+
+        if let Some(builtin) = binding.builtin {
+            let raw = match builtin {
+                PcuBuiltinValue::GroupId => self.emit_builtin_x(self.layout.workgroup_id)?,
+                PcuBuiltinValue::GroupCount => self.emit_builtin_x(self.layout.num_workgroups)?,
+                PcuBuiltinValue::Named(_) => {
+                    return Err(PcuSpirvError::UnsupportedBinding(binding.reference()));
+                }
+                PcuBuiltinValue::InvocationId
+                | PcuBuiltinValue::LaneId
+                | PcuBuiltinValue::LaneIndex => self.invocation,
+            };
+            let value = if scalar == PcuScalarType::I32 {
+                let value = self.fresh();
+                self.writer.instruction(
+                    SPIRV_OP_BITCAST,
+                    &[self.layout.scalar_types[SPIRV_SLOT_I32], value, raw],
+                )?;
+                value
+            } else {
+                raw
+            };
+            return self.push(index, value, scalar);
+        }
+
+        let pointer = self.emit_element_pointer(binding_index, scalar)?;
+        let value = self.fresh();
+        self.writer.instruction(
+            SPIRV_OP_LOAD,
+            &[self.layout.scalar_type(scalar), value, pointer],
+        )?;
+        self.push(index, value, scalar)
+    }
+
+    fn emit_store(&mut self, index: usize) -> Result<(), PcuSpirvError> {
+        let value = self.pop(index)?;
+        let (binding_index, binding) = spirv_writable_binding(self.kernel.bindings, self.store)
+            .ok_or(PcuSpirvError::MissingBinding { index })?;
+        self.store += 1;
+        if binding.value_type() != Some(PcuValueType::Scalar(value.scalar)) {
+            return Err(PcuSpirvError::TypeMismatch { index });
+        }
+
+        let pointer = self.emit_element_pointer(binding_index, value.scalar)?;
+        self.writer
+            .instruction(SPIRV_OP_STORE, &[pointer, value.id])
+    }
+
+    fn emit_element_pointer(
+        &mut self,
+        binding_index: usize,
+        scalar: PcuScalarType,
+    ) -> Result<u32, PcuSpirvError> {
+        let [_, _, _, variable] = self.layout.binding_ids(binding_index);
+        let element_pointer =
+            spirv_scalar_slot(scalar).map_or(0, |slot| self.layout.storage_pointers[slot]);
+        let pointer = self.fresh();
+        self.writer.instruction(
+            SPIRV_OP_ACCESS_CHAIN,
+            &[
+                element_pointer,
+                pointer,
+                variable,
+                self.layout.indices,
+                self.invocation,
+            ],
+        )?;
+        Ok(pointer)
+    }
+
+    fn emit_builtin_x(&mut self, variable: u32) -> Result<u32, PcuSpirvError> {
+        let vector = self.fresh();
+        self.writer
+            .instruction(SPIRV_OP_LOAD, &[self.layout.uvec3, vector, variable])?;
+        let value = self.fresh();
+        self.writer.instruction(
+            SPIRV_OP_COMPOSITE_EXTRACT,
+            &[self.layout.scalar_types[SPIRV_SLOT_U32], value, vector, 0],
+        )?;
+        Ok(value)
+    }
+
+    fn emit_compare(&mut self, index: usize) -> Result<(), PcuSpirvError> {
+        let (lhs, rhs) = self.pop_pair(index)?;
+        let opcode = match spirv_scalar_class(lhs.scalar) {
+            SpirvScalarClass::Bool => SPIRV_OP_LOGICAL_EQUAL,
+            SpirvScalarClass::Unsigned | SpirvScalarClass::Signed => SPIRV_OP_IEQUAL,
+            SpirvScalarClass::Float => SPIRV_OP_FORD_EQUAL,
+        };
+        let value = self.emit_result(opcode, PcuScalarType::Bool, &[lhs.id, rhs.id])?;
+        self.push(index, value, PcuScalarType::Bool)
+    }
+
+    fn emit_select(&mut self, index: usize) -> Result<(), PcuSpirvError> {
+        let condition = self.pop(index)?;
+        if condition.scalar != PcuScalarType::Bool {
+            return Err(PcuSpirvError::TypeMismatch { index });
+        }
+        let on_false = self.pop(index)?;
+        let on_true = self.pop(index)?;
+        if on_true.scalar != on_false.scalar {
+            return Err(PcuSpirvError::TypeMismatch { index });
+        }
+        let value = self.emit_result(
+            SPIRV_OP_SELECT,
+            on_true.scalar,
+            &[condition.id, on_true.id, on_false.id],
+        )?;
+        self.push(index, value, on_true.scalar)
+    }
+
+    fn emit_binary(&mut self, index: usize, op: PcuDispatchAluOp) -> Result<(), PcuSpirvError> {
+        let (lhs, rhs) = self.pop_pair(index)?;
+        let scalar = lhs.scalar;
+        let class = spirv_scalar_class(scalar);
+        let operands = [lhs.id, rhs.id];
+        let mismatch = PcuSpirvError::TypeMismatch { index };
+
+        let value = match (op, class) {
+            (
+                PcuDispatchAluOp::Add
+                | PcuDispatchAluOp::Sub
+                | PcuDispatchAluOp::Mul
+                | PcuDispatchAluOp::Div
+                | PcuDispatchAluOp::Min
+                | PcuDispatchAluOp::Max
+                | PcuDispatchAluOp::ShiftLeft
+                | PcuDispatchAluOp::ShiftRight,
+                SpirvScalarClass::Bool,
+            )
+            | (
+                PcuDispatchAluOp::And
+                | PcuDispatchAluOp::Or
+                | PcuDispatchAluOp::Xor
+                | PcuDispatchAluOp::ShiftLeft
+                | PcuDispatchAluOp::ShiftRight,
+                SpirvScalarClass::Float,
+            )
+            | (PcuDispatchAluOp::Compare | PcuDispatchAluOp::Select, _) => return Err(mismatch),
+            (PcuDispatchAluOp::Add, SpirvScalarClass::Float) => {
+                self.emit_result(SPIRV_OP_FADD, scalar, &operands)?
+            }
+            (PcuDispatchAluOp::Add, _) => self.emit_result(SPIRV_OP_IADD, scalar, &operands)?,
+            (PcuDispatchAluOp::Sub, SpirvScalarClass::Float) => {
+                self.emit_result(SPIRV_OP_FSUB, scalar, &operands)?
+            }
+            (PcuDispatchAluOp::Sub, _) => self.emit_result(SPIRV_OP_ISUB, scalar, &operands)?,
+            (PcuDispatchAluOp::Mul, SpirvScalarClass::Float) => {
+                self.emit_result(SPIRV_OP_FMUL, scalar, &operands)?
+            }
+            (PcuDispatchAluOp::Mul, _) => self.emit_result(SPIRV_OP_IMUL, scalar, &operands)?,
+            (PcuDispatchAluOp::Div, SpirvScalarClass::Float) => {
+                self.emit_result(SPIRV_OP_FDIV, scalar, &operands)?
+            }
+            (PcuDispatchAluOp::Div, SpirvScalarClass::Signed) => {
+                self.emit_result(SPIRV_OP_SDIV, scalar, &operands)?
+            }
+            (PcuDispatchAluOp::Div, _) => self.emit_result(SPIRV_OP_UDIV, scalar, &operands)?,
+            (PcuDispatchAluOp::Min, class) => {
+                let instruction = match class {
+                    SpirvScalarClass::Float => SPIRV_GLSL_FMIN,
+                    SpirvScalarClass::Signed => SPIRV_GLSL_SMIN,
+                    _ => SPIRV_GLSL_UMIN,
+                };
+                self.emit_extended(instruction, scalar, lhs.id, rhs.id)?
+            }
+            (PcuDispatchAluOp::Max, class) => {
+                let instruction = match class {
+                    SpirvScalarClass::Float => SPIRV_GLSL_FMAX,
+                    SpirvScalarClass::Signed => SPIRV_GLSL_SMAX,
+                    _ => SPIRV_GLSL_UMAX,
+                };
+                self.emit_extended(instruction, scalar, lhs.id, rhs.id)?
+            }
+            (PcuDispatchAluOp::And, SpirvScalarClass::Bool) => {
+                self.emit_result(SPIRV_OP_LOGICAL_AND, scalar, &operands)?
+            }
+            (PcuDispatchAluOp::And, _) => {
+                self.emit_result(SPIRV_OP_BITWISE_AND, scalar, &operands)?
+            }
+            (PcuDispatchAluOp::Or, SpirvScalarClass::Bool) => {
+                self.emit_result(SPIRV_OP_LOGICAL_OR, scalar, &operands)?
+            }
+            (PcuDispatchAluOp::Or, _) => {
+                self.emit_result(SPIRV_OP_BITWISE_OR, scalar, &operands)?
+            }
+            (PcuDispatchAluOp::Xor, SpirvScalarClass::Bool) => {
+                self.emit_result(SPIRV_OP_LOGICAL_NOT_EQUAL, scalar, &operands)?
+            }
+            (PcuDispatchAluOp::Xor, _) => {
+                self.emit_result(SPIRV_OP_BITWISE_XOR, scalar, &operands)?
+            }
+            (PcuDispatchAluOp::ShiftRight, SpirvScalarClass::Signed) => {
+                // Clamping the amount keeps the sign fill once it reaches the operand width.
+                let width_minus_one = self.shift_constant(scalar, 2);
+                let amount =
+                    self.emit_extended(SPIRV_GLSL_UMIN, scalar, rhs.id, width_minus_one)?;
+                self.emit_result(SPIRV_OP_SHIFT_RIGHT_ARITHMETIC, scalar, &[lhs.id, amount])?
+            }
+            (PcuDispatchAluOp::ShiftLeft, _) => {
+                self.emit_saturating_shift(SPIRV_OP_SHIFT_LEFT_LOGICAL, scalar, lhs.id, rhs.id)?
+            }
+            (PcuDispatchAluOp::ShiftRight, _) => {
+                self.emit_saturating_shift(SPIRV_OP_SHIFT_RIGHT_LOGICAL, scalar, lhs.id, rhs.id)?
+            }
+        };
+        self.push(index, value, scalar)
+    }
+
+    /// Emits one logical shift that yields zero once the amount reaches the operand width.
+    fn emit_saturating_shift(
+        &mut self,
+        opcode: u16,
+        scalar: PcuScalarType,
+        lhs: u32,
+        rhs: u32,
+    ) -> Result<u32, PcuSpirvError> {
+        let zero = self.shift_constant(scalar, 0);
+        let width = self.shift_constant(scalar, 1);
+        let shifted = self.emit_result(opcode, scalar, &[lhs, rhs])?;
+        let in_range = self.emit_result(SPIRV_OP_ULESS_THAN, PcuScalarType::Bool, &[rhs, width])?;
+        self.emit_result(SPIRV_OP_SELECT, scalar, &[in_range, shifted, zero])
+    }
+
+    fn shift_constant(&self, scalar: PcuScalarType, offset: u32) -> u32 {
+        spirv_scalar_slot(scalar).map_or(0, |slot| self.layout.shift_constants[slot] + offset)
+    }
+
+    fn emit_extended(
+        &mut self,
+        instruction: u32,
+        scalar: PcuScalarType,
+        lhs: u32,
+        rhs: u32,
+    ) -> Result<u32, PcuSpirvError> {
+        let extended = self.layout.extended;
+        self.emit_result(
+            SPIRV_OP_EXT_INST,
+            scalar,
+            &[extended, instruction, lhs, rhs],
+        )
+    }
+
+    fn emit_result(
+        &mut self,
+        opcode: u16,
+        scalar: PcuScalarType,
+        operands: &[u32],
+    ) -> Result<u32, PcuSpirvError> {
+        let result_type = self.layout.scalar_type(scalar);
+        let result = self.fresh();
+        self.writer.begin(opcode, operands.len() + 2)?;
+        self.writer.word(result_type)?;
+        self.writer.word(result)?;
+        for operand in operands.iter().copied() {
+            self.writer.word(operand)?;
+        }
+        Ok(result)
+    }
+
+    const fn fresh(&mut self) -> u32 {
+        let id = self.layout.next;
+        self.layout.next += 1;
+        id
+    }
+
+    fn pop_pair(&mut self, index: usize) -> Result<(SpirvValue, SpirvValue), PcuSpirvError> {
+        let rhs = self.pop(index)?;
+        let lhs = self.pop(index)?;
+        if lhs.scalar != rhs.scalar {
+            return Err(PcuSpirvError::TypeMismatch { index });
+        }
+        Ok((lhs, rhs))
+    }
+
+    const fn push(
+        &mut self,
+        index: usize,
+        id: u32,
+        scalar: PcuScalarType,
+    ) -> Result<(), PcuSpirvError> {
+        if self.depth == SPIRV_MAX_OPERAND_STACK {
+            return Err(PcuSpirvError::StackOverflow { index });
+        }
+        self.stack[self.depth] = SpirvValue { id, scalar };
+        self.depth += 1;
+        Ok(())
+    }
+
+    const fn pop(&mut self, index: usize) -> Result<SpirvValue, PcuSpirvError> {
+        if self.depth == 0 {
+            return Err(PcuSpirvError::StackUnderflow { index });
+        }
+        self.depth -= 1;
+        Ok(self.stack[self.depth])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        PcuSpirvError,
+        SPIRV_BUILTIN_LOCAL_INVOCATION_INDEX,
+        SPIRV_BUILTIN_NUM_WORKGROUPS,
+        SPIRV_CAPABILITY_INT64,
+        SPIRV_CAPABILITY_SHADER,
+        SPIRV_DECORATION_BINDING,
+        SPIRV_DECORATION_BLOCK,
+        SPIRV_DECORATION_BUILTIN,
+        SPIRV_DECORATION_DESCRIPTOR_SET,
+        SPIRV_DECORATION_NON_READABLE,
+        SPIRV_DECORATION_NON_WRITABLE,
+        SPIRV_DECORATION_OFFSET,
+        SPIRV_GLSL_UMAX,
+        SPIRV_MAGIC,
+        SPIRV_OP_CAPABILITY,
+        SPIRV_OP_DECORATE,
+        SPIRV_OP_ENTRY_POINT,
+        SPIRV_OP_EXECUTION_MODE,
+        SPIRV_OP_EXT_INST,
+        SPIRV_OP_EXT_INST_IMPORT,
+        SPIRV_OP_FUNCTION_END,
+        SPIRV_OP_IADD,
+        SPIRV_OP_ISUB,
+        SPIRV_OP_MEMBER_DECORATE,
+        SPIRV_OP_MEMORY_MODEL,
+        SPIRV_OP_RETURN,
+        SPIRV_OP_SELECT,
+        SPIRV_OP_SHIFT_LEFT_LOGICAL,
+        SPIRV_OP_TYPE_BOOL,
+        SPIRV_OP_ULESS_THAN,
+        SPIRV_OP_VARIABLE,
+        SPIRV_STORAGE_CLASS_INPUT,
+        SPIRV_STORAGE_CLASS_PUSH_CONSTANT,
+        SPIRV_STORAGE_CLASS_STORAGE_BUFFER,
+        SPIRV_VERSION_1_3,
+        lower_spirv_dispatch_kernel,
+    };
+    use crate::contract::drivers::pcu::{
+        PcuBinding,
+        PcuBindingAccess,
+        PcuBindingRef,
+        PcuBindingStorageClass,
+        PcuBindingType,
+        PcuBuiltinValue,
+        PcuDispatchAluOp,
+        PcuDispatchControlOp,
+        PcuDispatchOp,
+        PcuDispatchOpCaps,
+        PcuDispatchResourceOp,
+        PcuDispatchValueOp,
+        PcuError,
+        PcuErrorKind,
+        PcuParameter,
+        PcuParameterSlot,
+        PcuValueType,
+    };
+    use fusion_pcu::model::PcuDispatchKernelBuilder;
+
+    const INPUT: PcuBindingRef = PcuBindingRef::new(0, 0);
+    const OUTPUT: PcuBindingRef = PcuBindingRef::new(0, 1);
+
+    const fn value_binding(
+        reference: PcuBindingRef,
+        access: PcuBindingAccess,
+        value_type: PcuValueType,
+    ) -> PcuBinding<'static> {
+        PcuBinding::value(
+            None,
+            reference.set,
+            reference.binding,
+            PcuBindingStorageClass::Storage,
+            access,
+            value_type,
+        )
+    }
+
+    const fn builtin_binding(
+        reference: PcuBindingRef,
+        builtin: PcuBuiltinValue<'static>,
+    ) -> PcuBinding<'static> {
+        PcuBinding {
+            name: None,
+            set: reference.set,
+            binding: reference.binding,
+            storage: PcuBindingStorageClass::Input,
+            access: PcuBindingAccess::ReadOnly,
+            binding_type: PcuBindingType::Value(PcuValueType::u32()),
+            builtin: Some(builtin),
+        }
+    }
+
+    fn lower(
+        bindings: &[PcuBinding<'static>],
+        parameters: &[PcuParameter<'static>],
+        ops: &[PcuDispatchOp<'static>],
+    ) -> Result<Vec<u32>, PcuSpirvError> {
+        let builder = PcuDispatchKernelBuilder::<'_, 8>::new(7, "main", [4, 2, 1])
+            .with_bindings(bindings)
+            .with_parameters(parameters)
+            .with_ops(ops)
+            .expect("builder should accept ops");
+        let mut storage = [0_u32; 512];
+        lower_spirv_dispatch_kernel(&builder.ir(), &mut storage).map(|module| module.words.to_vec())
+    }
+
+    /// Splits one module body into `(opcode, operands)` pairs, checking every word count.
+    fn instructions(words: &[u32]) -> Vec<(u16, &[u32])> {
+        let mut instructions = Vec::new();
+        let mut cursor = 5;
+        while cursor < words.len() {
+            let count = usize::try_from(words[cursor] >> 16).expect("word count should fit");
+            assert!(count > 0 && cursor + count <= words.len());
+            let opcode = u16::try_from(words[cursor] & 0xffff).expect("opcode should fit");
+            instructions.push((opcode, &words[cursor + 1..cursor + count]));
+            cursor += count;
+        }
+        instructions
+    }
+
+    fn operands<'a>(instructions: &[(u16, &'a [u32])], opcode: u16) -> Vec<&'a [u32]> {
+        instructions
+            .iter()
+            .filter(|(candidate, _)| *candidate == opcode)
+            .map(|(_, operands)| *operands)
+            .collect()
+    }
+
+    fn literal_string(words: &[u32]) -> String {
+        let bytes = words
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .take_while(|byte| *byte != 0)
+            .collect::<Vec<_>>();
+        String::from_utf8(bytes).expect("literal should be utf-8")
+    }
+
+    fn decorations<'a>(instructions: &[(u16, &'a [u32])], target: u32) -> Vec<&'a [u32]> {
+        operands(instructions, SPIRV_OP_DECORATE)
+            .into_iter()
+            .filter(|operands| operands[0] == target)
+            .map(|operands| &operands[1..])
+            .collect()
+    }
+
+    #[test]
+    fn spirv_lowering_emits_header_and_compute_entry_point() {
+        const BINDINGS: [PcuBinding<'static>; 2] = [
+            value_binding(INPUT, PcuBindingAccess::ReadOnly, PcuValueType::u32()),
+            value_binding(OUTPUT, PcuBindingAccess::WriteOnly, PcuValueType::u32()),
+        ];
+        const PARAMETERS: [PcuParameter<'static>; 1] = [PcuParameter::named(
+            PcuParameterSlot(0),
+            "delta",
+            PcuValueType::u32(),
+        )];
+        let words = lower(
+            &BINDINGS,
+            &PARAMETERS,
+            &[
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Load),
+                PcuDispatchOp::Value(PcuDispatchValueOp::Constant),
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Add),
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Store),
+                PcuDispatchOp::Control(PcuDispatchControlOp::Return),
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Sub),
+            ],
+        )
+        .expect("kernel should lower");
+
+        assert_eq!(words[0], SPIRV_MAGIC);
+        assert_eq!(words[1], SPIRV_VERSION_1_3);
+        assert_eq!(words[4], 0);
+        let instructions = instructions(&words);
+        assert_eq!(
+            operands(&instructions, SPIRV_OP_CAPABILITY),
+            vec![&[SPIRV_CAPABILITY_SHADER][..]]
+        );
+        assert!(operands(&instructions, SPIRV_OP_EXT_INST_IMPORT).is_empty());
+        assert_eq!(
+            operands(&instructions, SPIRV_OP_MEMORY_MODEL),
+            vec![&[0, 1][..]]
+        );
+
+        let entry = operands(&instructions, SPIRV_OP_ENTRY_POINT);
+        assert_eq!(entry.len(), 1);
+        assert_eq!(entry[0][0], 5);
+        let function = entry[0][1];
+        assert!(function < words[3]);
+        assert_eq!(literal_string(&entry[0][2..]), "main");
+        let interface = entry[0][entry[0].len() - 1];
+        assert_eq!(
+            decorations(&instructions, interface),
+            vec![
+                &[
+                    SPIRV_DECORATION_BUILTIN,
+                    SPIRV_BUILTIN_LOCAL_INVOCATION_INDEX
+                ][..]
+            ]
+        );
+        assert_eq!(
+            operands(&instructions, SPIRV_OP_EXECUTION_MODE),
+            vec![&[function, 17, 4, 2, 1][..]]
+        );
+
+        assert_eq!(operands(&instructions, SPIRV_OP_IADD).len(), 1);
+        assert!(operands(&instructions, SPIRV_OP_ISUB).is_empty());
+        let tail = &instructions[instructions.len() - 2..];
+        assert_eq!(tail[0].0, SPIRV_OP_RETURN);
+        assert_eq!(tail[1].0, SPIRV_OP_FUNCTION_END);
+    }
+
+    #[test]
+    fn spirv_lowering_decorates_storage_bindings_and_push_constants() {
+        const BINDINGS: [PcuBinding<'static>; 2] = [
+            value_binding(
+                PcuBindingRef::new(1, 3),
+                PcuBindingAccess::ReadOnly,
+                PcuValueType::u32(),
+            ),
+            value_binding(
+                PcuBindingRef::new(2, 0),
+                PcuBindingAccess::ReadWrite,
+                PcuValueType::u32(),
+            ),
+        ];
+        const PARAMETERS: [PcuParameter<'static>; 3] = [
+            PcuParameter::anonymous(PcuParameterSlot(0), PcuValueType::u32()),
+            PcuParameter::anonymous(PcuParameterSlot(1), PcuValueType::u64()),
+            PcuParameter::anonymous(PcuParameterSlot(2), PcuValueType::f32()),
+        ];
+        let words = lower(
+            &BINDINGS,
+            &PARAMETERS,
+            &[
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Load),
+                PcuDispatchOp::Value(PcuDispatchValueOp::Constant),
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Max),
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Store),
+            ],
+        )
+        .expect("kernel should lower");
+        let instructions = instructions(&words);
+
+        let capabilities = operands(&instructions, SPIRV_OP_CAPABILITY);
+        assert!(capabilities.contains(&&[SPIRV_CAPABILITY_INT64][..]));
+        assert_eq!(capabilities.len(), 2);
+        let import = operands(&instructions, SPIRV_OP_EXT_INST_IMPORT);
+        assert_eq!(literal_string(&import[0][1..]), "GLSL.std.450");
+        let extended = operands(&instructions, SPIRV_OP_EXT_INST);
+        assert_eq!(extended.len(), 1);
+        assert_eq!(&extended[0][2..4], &[import[0][0], SPIRV_GLSL_UMAX]);
+
+        let variables = operands(&instructions, SPIRV_OP_VARIABLE);
+        let storage_classes = variables
+            .iter()
+            .map(|operands| operands[2])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            storage_classes,
+            vec![
+                SPIRV_STORAGE_CLASS_STORAGE_BUFFER,
+                SPIRV_STORAGE_CLASS_STORAGE_BUFFER,
+                SPIRV_STORAGE_CLASS_PUSH_CONSTANT,
+                SPIRV_STORAGE_CLASS_INPUT,
+            ]
+        );
+        for (variable, (set, binding)) in variables.iter().zip([(1, 3), (2, 0)]) {
+            assert_eq!(
+                decorations(&instructions, variable[1]),
+                vec![
+                    &[SPIRV_DECORATION_DESCRIPTOR_SET, set][..],
+                    &[SPIRV_DECORATION_BINDING, binding][..],
+                ]
+            );
+        }
+
+        let blocks = operands(&instructions, SPIRV_OP_DECORATE)
+            .into_iter()
+            .filter(|operands| operands[1] == SPIRV_DECORATION_BLOCK)
+            .map(|operands| operands[0])
+            .collect::<Vec<_>>();
+        assert_eq!(blocks.len(), 3);
+        let member_decorations = operands(&instructions, SPIRV_OP_MEMBER_DECORATE);
+        let non_writable = member_decorations
+            .iter()
+            .filter(|operands| operands[2] == SPIRV_DECORATION_NON_WRITABLE)
+            .collect::<Vec<_>>();
+        assert_eq!(non_writable.len(), 1);
+        assert_eq!(non_writable[0][0], blocks[0]);
+        assert!(
+            member_decorations
+                .iter()
+                .all(|operands| operands[2] != SPIRV_DECORATION_NON_READABLE)
+        );
+        let push_offsets = member_decorations
+            .iter()
+            .filter(|operands| operands[0] == blocks[2])
+            .map(|operands| (operands[1], operands[2], operands[3]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            push_offsets,
+            vec![
+                (0, SPIRV_DECORATION_OFFSET, 0),
+                (1, SPIRV_DECORATION_OFFSET, 8),
+                (2, SPIRV_DECORATION_OFFSET, 16),
+            ]
+        );
+    }
+
+    #[test]
+    fn spirv_lowering_reads_builtins_and_saturates_shifts() {
+        const BINDINGS: [PcuBinding<'static>; 3] = [
+            builtin_binding(INPUT, PcuBuiltinValue::InvocationId),
+            builtin_binding(OUTPUT, PcuBuiltinValue::GroupCount),
+            value_binding(
+                PcuBindingRef::new(0, 2),
+                PcuBindingAccess::WriteOnly,
+                PcuValueType::u32(),
+            ),
+        ];
+        let words = lower(
+            &BINDINGS,
+            &[],
+            &[
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Load),
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Load),
+                PcuDispatchOp::Arithmetic(PcuDispatchAluOp::ShiftLeft),
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Store),
+            ],
+        )
+        .expect("kernel should lower");
+        let instructions = instructions(&words);
+
+        let entry = operands(&instructions, SPIRV_OP_ENTRY_POINT);
+        let interface = &entry[0][entry[0].len() - 2..];
+        assert_eq!(
+            decorations(&instructions, interface[0]),
+            vec![
+                &[
+                    SPIRV_DECORATION_BUILTIN,
+                    SPIRV_BUILTIN_LOCAL_INVOCATION_INDEX
+                ][..]
+            ]
+        );
+        assert_eq!(
+            decorations(&instructions, interface[1]),
+            vec![&[SPIRV_DECORATION_BUILTIN, SPIRV_BUILTIN_NUM_WORKGROUPS][..]]
+        );
+
+        assert_eq!(operands(&instructions, SPIRV_OP_TYPE_BOOL).len(), 1);
+        let shifted = operands(&instructions, SPIRV_OP_SHIFT_LEFT_LOGICAL);
+        let in_range = operands(&instructions, SPIRV_OP_ULESS_THAN);
+        let selected = operands(&instructions, SPIRV_OP_SELECT);
+        assert_eq!((shifted.len(), in_range.len(), selected.len()), (1, 1, 1));
+        assert_eq!(selected[0][2], in_range[0][1]);
+        assert_eq!(selected[0][3], shifted[0][1]);
+    }
+
+    #[test]
+    fn spirv_lowering_reports_unsupported_ops_by_flag() {
+        const BINDINGS: [PcuBinding<'static>; 1] = [value_binding(
+            INPUT,
+            PcuBindingAccess::ReadOnly,
+            PcuValueType::u32(),
+        )];
+        let builder = PcuDispatchKernelBuilder::<'_, 4>::new(1, "main", [1, 1, 1])
+            .with_bindings(&BINDINGS)
+            .with_ops(&[
+                PcuDispatchOp::Resource(PcuDispatchResourceOp::Load),
+                PcuDispatchOp::Control(PcuDispatchControlOp::Branch),
+            ])
+            .expect("builder should accept ops");
+        let mut storage = [0_u32; 64];
+        let error = lower_spirv_dispatch_kernel(&builder.ir(), &mut storage)
+            .expect_err("branches should not lower");
+        assert_eq!(
+            error,
+            PcuSpirvError::UnsupportedOp {
+                index: 1,
+                op: PcuDispatchOpCaps::CONTROL_BRANCH,
+            }
+        );
+        assert_eq!(PcuError::from(error).kind(), PcuErrorKind::Unsupported);
+        assert!(storage.iter().all(|word| *word == 0));
+
+        assert_eq!(
+            lower(
+                &BINDINGS,
+                &[],
+                &[PcuDispatchOp::Intrinsic { name: "popcount" }]
+            ),
+            Err(PcuSpirvError::UnsupportedOp {
+                index: 0,
+                op: PcuDispatchOpCaps::INTRINSIC,
+            })
+        );
+        assert_eq!(
+            lower(
+                &BINDINGS,
+                &[],
+                &[PcuDispatchOp::Value(PcuDispatchValueOp::Cast)]
+            ),
+            Err(PcuSpirvError::UnsupportedOp {
+                index: 0,
+                op: PcuDispatchOpCaps::VALUE_CAST,
+            })
+        );
+    }
+
+    #[test]
+    fn spirv_lowering_rejects_stack_binding_and_storage_mismatches() {
+        const FLOAT_IN: [PcuBinding<'static>; 2] = [
+            value_binding(INPUT, PcuBindingAccess::ReadOnly, PcuValueType::f32()),
+            value_binding(OUTPUT, PcuBindingAccess::WriteOnly, PcuValueType::u32()),
+        ];
+        const SHARED: [PcuBinding<'static>; 1] = [PcuBinding::value(
+            None,
+            0,
+            0,
+            PcuBindingStorageClass::Shared,
+            PcuBindingAccess::ReadWrite,
+            PcuValueType::u32(),
+        )];
+        const BYTES: [PcuBinding<'static>; 1] = [value_binding(
+            INPUT,
+            PcuBindingAccess::ReadOnly,
+            PcuValueType::u8(),
+        )];
+        let load = PcuDispatchOp::Resource(PcuDispatchResourceOp::Load);
+        let store = PcuDispatchOp::Resource(PcuDispatchResourceOp::Store);
+
+        assert_eq!(
+            lower(
+                &FLOAT_IN,
+                &[],
+                &[PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Add)]
+            ),
+            Err(PcuSpirvError::StackUnderflow { index: 0 })
+        );
+        assert_eq!(
+            lower(&FLOAT_IN, &[], &[load, store]),
+            Err(PcuSpirvError::TypeMismatch { index: 1 })
+        );
+        assert_eq!(
+            lower(
+                &FLOAT_IN,
+                &[],
+                &[load, load, PcuDispatchOp::Arithmetic(PcuDispatchAluOp::Xor)]
+            ),
+            Err(PcuSpirvError::MissingBinding { index: 1 })
+        );
+        assert_eq!(
+            lower(
+                &FLOAT_IN,
+                &[],
+                &[PcuDispatchOp::Value(PcuDispatchValueOp::Constant)]
+            ),
+            Err(PcuSpirvError::MissingParameter { index: 0 })
+        );
+        assert_eq!(
+            lower(&SHARED, &[], &[load]),
+            Err(PcuSpirvError::UnsupportedBinding(PcuBindingRef::new(0, 0)))
+        );
+        assert_eq!(
+            lower(&BYTES, &[], &[load]),
+            Err(PcuSpirvError::UnsupportedType(PcuValueType::u8()))
+        );
+
+        let builder = PcuDispatchKernelBuilder::<'_, 4>::new(1, "main", [4, 0, 1])
+            .with_bindings(&FLOAT_IN)
+            .with_ops(&[load])
+            .expect("builder should accept ops");
+        let mut storage = [0_u32; 512];
+        assert_eq!(
+            lower_spirv_dispatch_kernel(&builder.ir(), &mut storage),
+            Err(PcuSpirvError::InvalidShape([4, 0, 1]))
+        );
+
+        for shape in [[16, 16, 1], [1, 1, 128]] {
+            let builder = PcuDispatchKernelBuilder::<'_, 4>::new(1, "main", shape)
+                .with_bindings(&FLOAT_IN)
+                .with_ops(&[load])
+                .expect("builder should accept ops");
+            assert_eq!(
+                lower_spirv_dispatch_kernel(&builder.ir(), &mut storage),
+                Err(PcuSpirvError::InvalidShape(shape))
+            );
+        }
+
+        let builder = PcuDispatchKernelBuilder::<'_, 4>::new(1, "main", [128, 1, 1])
+            .with_bindings(&FLOAT_IN)
+            .with_ops(&[load])
+            .expect("builder should accept ops");
+        assert!(lower_spirv_dispatch_kernel(&builder.ir(), &mut storage).is_ok());
+
+        let builder = PcuDispatchKernelBuilder::<'_, 4>::new(1, "main", [4, 1, 1])
+            .with_bindings(&FLOAT_IN)
+            .with_ops(&[load])
+            .expect("builder should accept ops");
+        let mut storage = [0_u32; 16];
+        let error = lower_spirv_dispatch_kernel(&builder.ir(), &mut storage)
+            .expect_err("small storage should not hold the module");
+        assert_eq!(error, PcuSpirvError::StorageExhausted);
+        assert_eq!(
+            PcuError::from(error).kind(),
+            PcuErrorKind::ResourceExhausted
+        );
+    }
+}
@@ -86,6 +86,14 @@ pub const fn system_event() -> PlatformEvent {
     PlatformEvent::new()
 }
 
+/// Returns the event provider for consumers that only wait for readiness.
+///
+/// Cortex-M has one event surface, so this is the same provider as [`system_event`].
+#[must_use]
+pub const fn system_readiness_event() -> PlatformEvent {
+    PlatformEvent::new()
+}
+
 impl CortexMEvent {
     /// Creates a new Cortex-M event provider handle.
     #[must_use]
@@ -359,6 +367,8 @@ impl EventSourceContract for CortexMEvent {
                 notification: EventNotification::Completion(EventCompletion {
                     bytes_transferred: None,
                     success: true,
+                    source: None,
+                    error: None,
                 }),
             };
             written += 1;
@@ -519,6 +529,7 @@ mod tests {
     use super::*;
     use crate::contract::pal::runtime::event::{
         EventCompletionOpKind,
+        EventCompletionTarget,
         EventSourceContract,
     };
 
@@ -544,6 +555,7 @@ mod tests {
                 EventCompletionOp {
                     source: EventSourceHandle(7),
                     kind: EventCompletionOpKind::Custom(11),
+                    target: EventCompletionTarget::None,
                     user_data: 99,
                 },
             )
@@ -554,6 +566,8 @@ mod tests {
             notification: EventNotification::Completion(EventCompletion {
                 bytes_transferred: None,
                 success: false,
+                source: None,
+                error: None,
             }),
         }; 1];
 
@@ -567,6 +581,8 @@ mod tests {
             EventNotification::Completion(EventCompletion {
                 bytes_transferred: None,
                 success: true,
+                source: None,
+                error: None,
             })
         );
     }
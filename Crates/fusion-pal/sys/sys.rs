@@ -363,6 +363,7 @@ pub mod event {
         PlatformEvent,
         PlatformPoller,
         system_event,
+        system_readiness_event,
     };
     pub use crate::contract::pal::runtime::event::*;
 }
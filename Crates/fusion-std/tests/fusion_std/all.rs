@@ -3,8 +3,13 @@ use core::sync::atomic::{
     Ordering,
 };
 
+use std::panic::{
+    AssertUnwindSafe,
+    catch_unwind,
+};
 use std::sync::Arc;
 use std::thread;
+use std::time::Duration;
 
 use fusion_std::sync::{
     Mutex,
@@ -21,6 +26,18 @@ use fusion_std::thread::{
     Executor,
     ExecutorConfig,
     ExecutorMode,
+    Job,
+    JobFailure,
+    ResizePolicy,
+    StealBoundary,
+    TaskBatch,
+    TaskDispatch,
+    TaskGraph,
+    TaskGraphError,
+    TaskGraphErrorKind,
+    TaskNodeOutcome,
+    TaskTarget,
+    TaskTargetKind,
     ThreadConfig,
     ThreadEntryReturn,
     ThreadErrorKind,
@@ -343,3 +360,401 @@ fn executor_current_thread_and_pool_paths_are_real() {
         .shutdown()
         .expect("carrier pool should shut down cleanly");
 }
+
+#[test]
+fn task_graph_runs_diamond_in_dependency_order_on_thread_pool() {
+    let _guard = lock_fusion_std_tests();
+
+    let pool = match ThreadPool::new(&ThreadPoolConfig::new()) {
+        Ok(pool) => pool,
+        Err(error) => {
+            assert_eq!(error.kind(), ThreadErrorKind::Unsupported);
+            return;
+        }
+    };
+
+    let clock = AtomicU32::new(0);
+    let stamps = [
+        AtomicU32::new(0),
+        AtomicU32::new(0),
+        AtomicU32::new(0),
+        AtomicU32::new(0),
+    ];
+    let stamp = |index: usize| {
+        stamps[index].store(clock.fetch_add(1, Ordering::AcqRel) + 1, Ordering::Release);
+        Ok(())
+    };
+    let root = || stamp(0);
+    let left = || stamp(1);
+    let right = || stamp(2);
+    let join = || stamp(3);
+
+    let mut graph = TaskGraph::<4, 4>::new();
+    let a = graph
+        .add_node(&pool, Job::new(&root))
+        .expect("root should fit");
+    let b = graph
+        .add_node(&pool, Job::new(&left))
+        .expect("left should fit");
+    let c = graph
+        .add_node(&pool, Job::new(&right))
+        .expect("right should fit");
+    let d = graph
+        .add_node(&pool, Job::new(&join))
+        .expect("join should fit");
+    graph
+        .add_dependency(a, b)
+        .expect("a -> b should be accepted");
+    graph
+        .add_dependency(a, c)
+        .expect("a -> c should be accepted");
+    graph
+        .depends_on(d, &[b, c])
+        .expect("join dependencies should be accepted");
+
+    let report = graph.run().expect("graph should run");
+    assert!(report.all_succeeded());
+    assert_eq!(report.len(), 4);
+    assert_eq!(report.batches(), 3);
+
+    let stamp_of = |index: usize| stamps[index].load(Ordering::Acquire);
+    assert_eq!(stamp_of(0), 1);
+    assert!(stamp_of(1) > stamp_of(0) && stamp_of(2) > stamp_of(0));
+    assert_eq!(stamp_of(3), 4);
+
+    let root_report = report.node(a).expect("root should be reported");
+    assert_eq!(root_report.batch, Some(TaskBatch(0)));
+    assert_eq!(
+        report.node(b).and_then(|node| node.batch),
+        Some(TaskBatch(1))
+    );
+    assert_eq!(
+        report.node(d).and_then(|node| node.batch),
+        Some(TaskBatch(2))
+    );
+    if root_report.elapsed.is_some() {
+        assert!(report.iter().all(|node| node.queued.is_some()));
+    }
+
+    // Graphs only borrow their jobs and may be run again.
+    assert!(graph.run().expect("graph should rerun").all_succeeded());
+    assert_eq!(clock.load(Ordering::Acquire), 8);
+
+    pool.shutdown().expect("pool should shut down cleanly");
+}
+
+#[test]
+fn task_graph_skips_dependents_of_failed_and_panicked_nodes() {
+    let _guard = lock_fusion_std_tests();
+
+    let pool = match ThreadPool::new(&ThreadPoolConfig::new()) {
+        Ok(pool) => pool,
+        Err(error) => {
+            assert_eq!(error.kind(), ThreadErrorKind::Unsupported);
+            return;
+        }
+    };
+
+    let ran = AtomicU32::new(0);
+    let succeed = || {
+        ran.fetch_add(1, Ordering::AcqRel);
+        Ok(())
+    };
+    let fail = || Err(JobFailure::Failed(7));
+    let cancel = || Err(JobFailure::Cancelled);
+    let panic = || -> Result<(), JobFailure> { panic!("graph job panicked") };
+
+    let mut graph = TaskGraph::<8, 8>::new();
+    let failing = graph
+        .add_node(&pool, Job::new(&fail))
+        .expect("node should fit");
+    let after_failure = graph
+        .add_node(&pool, Job::new(&succeed))
+        .expect("node should fit");
+    let transitive = graph
+        .add_node(&pool, Job::new(&succeed))
+        .expect("node should fit");
+    let cancelled = graph
+        .add_node(&pool, Job::new(&cancel))
+        .expect("node should fit");
+    let after_cancel = graph
+        .add_node(&pool, Job::new(&succeed))
+        .expect("node should fit");
+    let panicking = graph
+        .add_node(&pool, Job::new(&panic))
+        .expect("node should fit");
+    let after_panic = graph
+        .add_node(&pool, Job::new(&succeed))
+        .expect("node should fit");
+    let independent = graph
+        .add_node(&pool, Job::new(&succeed))
+        .expect("node should fit");
+    graph
+        .add_dependency(failing, after_failure)
+        .expect("edge should fit");
+    graph
+        .add_dependency(after_failure, transitive)
+        .expect("edge should fit");
+    graph
+        .add_dependency(cancelled, after_cancel)
+        .expect("edge should fit");
+    graph
+        .add_dependency(panicking, after_panic)
+        .expect("edge should fit");
+
+    let report = graph.run().expect("graph should run");
+    let outcome = |node| report.node(node).expect("node should be reported").outcome;
+    assert_eq!(outcome(failing), TaskNodeOutcome::Failed(7));
+    assert_eq!(
+        outcome(after_failure),
+        TaskNodeOutcome::Skipped { cause: failing }
+    );
+    assert_eq!(
+        outcome(transitive),
+        TaskNodeOutcome::Skipped { cause: failing }
+    );
+    assert_eq!(outcome(cancelled), TaskNodeOutcome::Cancelled);
+    assert_eq!(
+        outcome(after_cancel),
+        TaskNodeOutcome::Skipped { cause: cancelled }
+    );
+    assert_eq!(outcome(panicking), TaskNodeOutcome::Panicked);
+    assert_eq!(
+        outcome(after_panic),
+        TaskNodeOutcome::Skipped { cause: panicking }
+    );
+    assert_eq!(outcome(independent), TaskNodeOutcome::Succeeded);
+    assert!(!report.all_succeeded());
+    assert_eq!(ran.load(Ordering::Acquire), 1);
+    assert_eq!(report.node(transitive).and_then(|node| node.batch), None);
+
+    pool.shutdown().expect("pool should shut down cleanly");
+}
+
+#[test]
+fn task_graph_rejects_cycles_invalid_edges_and_overflow() {
+    let _guard = lock_fusion_std_tests();
+
+    let current = Executor::new(ExecutorConfig::new());
+    let noop = || Ok(());
+    let mut graph = TaskGraph::<3, 2>::new();
+    assert!(graph.is_empty());
+    assert_eq!(graph.slab().capacity, 3);
+    assert_eq!(graph.slab().edge_capacity, 2);
+
+    let a = graph
+        .add_node(&current, Job::new(&noop))
+        .expect("node should fit");
+    let b = graph
+        .add_node(&current, Job::new(&noop))
+        .expect("node should fit");
+    let c = graph
+        .add_node(&current, Job::new(&noop))
+        .expect("node should fit");
+    assert_eq!(
+        graph
+            .add_node(&current, Job::new(&noop))
+            .expect_err("graph should be full")
+            .kind(),
+        TaskGraphErrorKind::CapacityExhausted
+    );
+
+    assert_eq!(
+        graph.add_dependency(a, a).expect_err("self edge").kind(),
+        TaskGraphErrorKind::Invalid
+    );
+    assert_eq!(
+        graph
+            .add_dependency(a, fusion_std::thread::TaskNode(9))
+            .expect_err("unknown node")
+            .kind(),
+        TaskGraphErrorKind::Invalid
+    );
+    graph
+        .add_dependency(a, b)
+        .expect("a -> b should be accepted");
+    graph
+        .add_dependency(b, c)
+        .expect("b -> c should be accepted");
+    graph
+        .add_dependency(a, b)
+        .expect("duplicate edges should be accepted");
+    assert_eq!(graph.dependency_count(), 2);
+    assert_eq!(
+        graph.add_dependency(c, a).expect_err("cycle").kind(),
+        TaskGraphErrorKind::Cycle
+    );
+    assert_eq!(
+        graph
+            .add_dependency(a, c)
+            .expect_err("edge capacity")
+            .kind(),
+        TaskGraphErrorKind::CapacityExhausted
+    );
+
+    // The graph owner blocks inside `run`, so a current-thread executor cannot drive its nodes.
+    let report = graph.run().expect("graph should run");
+    assert_eq!(
+        report.node(a).expect("root should be reported").outcome,
+        TaskNodeOutcome::Rejected(TaskGraphErrorKind::Unsupported)
+    );
+    assert_eq!(
+        report.node(c).expect("leaf should be reported").outcome,
+        TaskNodeOutcome::Skipped { cause: a }
+    );
+}
+
+#[test]
+fn thread_pool_shrink_lets_retiring_work_submit_back() {
+    let _guard = lock_fusion_std_tests();
+
+    let config = ThreadPoolConfig {
+        min_threads: 1,
+        max_threads: 2,
+        resize_policy: ResizePolicy::Manual,
+        steal_boundary: StealBoundary::Global,
+        ..ThreadPoolConfig::new()
+    };
+    let pool = match ThreadPool::new(&config) {
+        Ok(pool) => pool,
+        Err(error) => {
+            assert_eq!(error.kind(), ThreadErrorKind::Unsupported);
+            return;
+        }
+    };
+    pool.resize(2).expect("manual pool should grow");
+
+    let started = Arc::new(AtomicU32::new(0));
+    let release = Arc::new(AtomicU32::new(0));
+    let completed = Arc::new(AtomicU32::new(0));
+    for _ in 0..2 {
+        let handle = pool.try_clone().expect("pool handle should clone");
+        let started = Arc::clone(&started);
+        let release = Arc::clone(&release);
+        let completed = Arc::clone(&completed);
+        pool.submit(move || {
+            started.fetch_add(1, Ordering::AcqRel);
+            while release.load(Ordering::Acquire) == 0 {
+                core::hint::spin_loop();
+            }
+            handle
+                .submit(move || {
+                    completed.fetch_add(1, Ordering::AcqRel);
+                })
+                .expect("retiring work should still submit into the pool");
+        })
+        .expect("pool should accept blocking work");
+    }
+    while started.load(Ordering::Acquire) != 2 {
+        thread::yield_now();
+    }
+
+    // Both carriers are busy, so the shrink retires one that is still running a job which only
+    // submits once the shrink is underway.
+    let releaser = {
+        let release = Arc::clone(&release);
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            release.store(1, Ordering::Release);
+        })
+    };
+    pool.resize(1).expect("manual pool should shrink");
+    releaser.join().expect("releaser should join");
+    assert_eq!(pool.worker_count().expect("worker count"), 1);
+
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while completed.load(Ordering::Acquire) != 2 && std::time::Instant::now() < deadline {
+        thread::yield_now();
+    }
+    assert_eq!(completed.load(Ordering::Acquire), 2);
+    pool.shutdown().expect("pool should shut down cleanly");
+}
+
+#[test]
+fn task_graph_mixes_thread_pool_and_executor_targets() {
+    let _guard = lock_fusion_std_tests();
+
+    let pool = match ThreadPool::new(&ThreadPoolConfig::new()) {
+        Ok(pool) => pool,
+        Err(error) => {
+            assert_eq!(error.kind(), ThreadErrorKind::Unsupported);
+            return;
+        }
+    };
+    // The executor drives its futures from its own carrier, so it must not share the pool that
+    // runs the graph's thread-pool nodes.
+    let carrier = ThreadPool::new(&ThreadPoolConfig::new()).expect("carrier pool should build");
+    let executor = Executor::new(ExecutorConfig {
+        mode: ExecutorMode::ThreadPool,
+        ..ExecutorConfig::new()
+    })
+    .on_pool(&carrier)
+    .expect("executor should bind to the carrier pool");
+
+    let total = AtomicU32::new(0);
+    let add = |value| {
+        total.fetch_add(value, Ordering::AcqRel);
+        Ok(())
+    };
+    let first = || add(1);
+    let second = || add(10);
+
+    let mut graph = TaskGraph::<2, 1>::new();
+    let on_pool = graph
+        .add_node(&pool, Job::new(&first))
+        .expect("node should fit");
+    let on_executor = graph
+        .add_node(&executor, Job::new(&second))
+        .expect("node should fit");
+    graph
+        .add_dependency(on_pool, on_executor)
+        .expect("edge should fit");
+
+    let report = graph.run().expect("graph should run");
+    assert!(report.all_succeeded(), "{report:?}");
+    assert_eq!(total.load(Ordering::Acquire), 11);
+
+    drop(executor);
+    pool.shutdown().expect("pool should shut down cleanly");
+    carrier
+        .shutdown()
+        .expect("carrier pool should shut down cleanly");
+}
+
+/// Target that hands the token to a late-running thread and then panics out of `dispatch`.
+struct PanickingTarget;
+
+impl TaskTarget for PanickingTarget {
+    fn target_kind(&self) -> TaskTargetKind {
+        TaskTargetKind::ThreadPool
+    }
+
+    fn dispatch(&self, work: TaskDispatch) -> Result<(), TaskGraphError> {
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            work.run();
+        });
+        panic!("dispatch failed after handing off its token");
+    }
+}
+
+#[test]
+fn task_graph_drains_in_flight_nodes_before_unwinding() {
+    let _guard = lock_fusion_std_tests();
+
+    let ran = AtomicU32::new(0);
+    let job = || {
+        ran.fetch_add(1, Ordering::AcqRel);
+        Ok(())
+    };
+    let target = PanickingTarget;
+    let mut graph = TaskGraph::<1, 0>::new();
+    graph
+        .add_node(&target, Job::new(&job))
+        .expect("node should fit");
+
+    let unwound = catch_unwind(AssertUnwindSafe(|| graph.run()));
+    assert!(unwound.is_err());
+    // The run frame may only be released once the stashed token has completed.
+    assert_eq!(ran.load(Ordering::Acquire), 1);
+}
@@ -23,7 +23,12 @@ use fusion_pal::sys::mem::{
 };
 use fusion_std::sync::Mutex as FusionMutex;
 use fusion_std::thread::{
+    AsyncIoError,
     AsyncPollStackContract,
+    AsyncTcpListener,
+    AsyncTcpStream,
+    AsyncUdpSocket,
+    AsyncUnixStream,
     CurrentAsyncRuntime,
     DeterministicConstraints,
     EventInterest,
@@ -33,6 +38,7 @@ use fusion_std::thread::{
     EventSourceHandle,
     Executor,
     ExecutorConfig,
+    ExecutorError,
     ExecutorMode,
     ExplicitFiberTask,
     FiberStackBacking,
@@ -48,6 +54,7 @@ use fusion_std::thread::{
     GreenScheduling,
     HugePagePolicy,
     HugePageSize,
+    Job,
     RedDispatchPolicy,
     RedThread,
     RedThreadConfig,
@@ -55,13 +62,17 @@ use fusion_std::thread::{
     RuntimeConfig,
     RuntimeError,
     RuntimeProfile,
+    TaskGraph,
+    TaskNodeOutcome,
     TaskPlacement,
     ThreadPool,
     ThreadPoolConfig,
     TieredGreenPool,
     TieredGreenPoolConfig,
     TieredTaskAttributes,
+    JoinSet,
     admit_generated_fiber_task_stack_bytes,
+    async_pipe,
     generated_explicit_task_contract_attributes,
     wait_for_readiness,
     yield_now as green_yield_now,
@@ -1224,3 +1235,201 @@ fn fibers_reject_readiness_park_while_cooperative_mutex_is_held() {
         .shutdown()
         .expect("carrier pool should shut down cleanly");
 }
+
+#[test]
+fn task_graph_dispatches_nodes_onto_green_pool() {
+    let _guard = lock_fusion_std_tests();
+
+    let carrier = ThreadPool::new(&ThreadPoolConfig::new()).expect("carrier pool should build");
+    let green = GreenPool::new(&GreenPoolConfig::new(), &carrier)
+        .expect("green pool should build on the carrier pool");
+    let pool = ThreadPool::new(&ThreadPoolConfig::new()).expect("graph pool should build");
+
+    let order = AtomicU32::new(0);
+    let green_seen = AtomicU32::new(0);
+    let pool_seen = AtomicU32::new(0);
+    let on_green = || {
+        green_seen.store(order.fetch_add(1, Ordering::AcqRel) + 1, Ordering::Release);
+        Ok(())
+    };
+    let on_pool = || {
+        pool_seen.store(order.fetch_add(1, Ordering::AcqRel) + 1, Ordering::Release);
+        Ok(())
+    };
+
+    let mut graph = TaskGraph::<2, 1>::new();
+    let first = graph
+        .add_node(
+            &green,
+            Job::new(&on_green).with_fiber_attributes(TEST_MIN_FIBER_ATTRIBUTES),
+        )
+        .expect("green node should fit");
+    let second = graph
+        .add_node(&pool, Job::new(&on_pool))
+        .expect("pool node should fit");
+    graph
+        .add_dependency(first, second)
+        .expect("edge should fit");
+
+    let report = graph.run().expect("graph should run");
+    assert_eq!(
+        report.node(first).map(|node| node.outcome),
+        Some(TaskNodeOutcome::Succeeded)
+    );
+    assert!(report.all_succeeded(), "{report:?}");
+    assert_eq!(green_seen.load(Ordering::Acquire), 1);
+    assert_eq!(pool_seen.load(Ordering::Acquire), 2);
+
+    drop(green);
+    pool.shutdown()
+        .expect("graph pool should shut down cleanly");
+    carrier
+        .shutdown()
+        .expect("carrier pool should shut down cleanly");
+}
+
+#[test]
+fn async_tcp_echo_runs_under_current_runtime_join_set() {
+    let _guard = lock_fusion_std_tests();
+
+    let runtime = CurrentAsyncRuntime::new();
+    let mut listener = AsyncTcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should report its address");
+    let join_set = JoinSet::<Result<[u8; 4], AsyncIoError>>::new();
+
+    join_set
+        .spawn_with_poll_stack_bytes(runtime.executor(), 2048, async move {
+            let (mut stream, _) = listener.accept().await?;
+            let mut request = [0_u8; 4];
+            stream.read_exact(&mut request).await?;
+            request.reverse();
+            stream.write_all(&request).await?;
+            Ok(request)
+        })
+        .expect("server task should spawn");
+    join_set
+        .spawn_with_poll_stack_bytes(runtime.executor(), 2048, async move {
+            let mut stream = AsyncTcpStream::connect(addr).await?;
+            assert_eq!(stream.peer_addr()?, addr);
+            stream.write_all(b"ping").await?;
+            let mut reply = [0_u8; 4];
+            stream.read_exact(&mut reply).await?;
+            Ok(reply)
+        })
+        .expect("client task should spawn");
+
+    let mut results = [
+        join_set
+            .join_next()
+            .expect("first task should complete")
+            .expect("first task should succeed"),
+        join_set
+            .join_next()
+            .expect("second task should complete")
+            .expect("second task should succeed"),
+    ];
+    results.sort_unstable();
+    assert_eq!(results, [*b"gnip", *b"gnip"]);
+}
+
+#[test]
+fn async_udp_unix_and_pipe_round_trip() {
+    let _guard = lock_fusion_std_tests();
+
+    let runtime = CurrentAsyncRuntime::new();
+    let handle = runtime
+        .spawn_with_poll_stack_bytes(2048, async {
+            let mut receiver = AsyncUdpSocket::bind("127.0.0.1:0")?;
+            let mut sender = AsyncUdpSocket::bind("127.0.0.1:0")?;
+            let target = receiver.local_addr()?;
+            sender.send_to(b"datagram", target).await?;
+            let mut datagram = [0_u8; 16];
+            let (len, from) = receiver.recv_from(&mut datagram).await?;
+            assert_eq!(&datagram[..len], b"datagram");
+            assert_eq!(from, sender.local_addr()?);
+
+            let (mut left, mut right) = AsyncUnixStream::pair()?;
+            left.write_all(b"unix").await?;
+            let mut unix = [0_u8; 4];
+            right.read_exact(&mut unix).await?;
+            assert_eq!(&unix, b"unix");
+
+            let (mut reader, mut writer) = async_pipe()?;
+            writer.write_all(b"pipe").await?;
+            drop(writer);
+            let mut pipe = [0_u8; 8];
+            let len = reader.read(&mut pipe).await?;
+            assert_eq!(&pipe[..len], b"pipe");
+            assert_eq!(reader.read(&mut pipe).await?, 0);
+            Ok::<_, AsyncIoError>(len)
+        })
+        .expect("io task should spawn");
+    assert_eq!(
+        handle
+            .join()
+            .expect("io task should complete")
+            .expect("io task should succeed"),
+        4
+    );
+}
+
+#[test]
+fn async_tcp_connect_waits_out_the_handshake_before_reporting() {
+    let _guard = lock_fusion_std_tests();
+
+    // With a zero backlog and one connection already queued, the kernel drops further SYNs, so
+    // the connect below stays in flight until the listener closes and the retransmitted SYN
+    // meets a reset.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should report its address");
+    assert_eq!(
+        unsafe { libc::listen(std::os::fd::AsRawFd::as_raw_fd(&listener), 0) },
+        0
+    );
+    let queued = std::net::TcpStream::connect(addr).expect("first connection should queue");
+    let closer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(200));
+        drop(listener);
+        drop(queued);
+    });
+
+    let started = std::time::Instant::now();
+    let runtime = CurrentAsyncRuntime::new();
+    let refused = runtime
+        .block_on_with_poll_stack_bytes(2048, AsyncTcpStream::connect(addr))
+        .expect("connect task should complete")
+        .expect_err("connect should fail once the listener is gone");
+    assert_eq!(refused.raw_os_error(), Some(libc::ECONNREFUSED));
+    assert!(started.elapsed() >= Duration::from_millis(200));
+    closer.join().expect("closer should finish");
+}
+
+#[test]
+fn async_io_surfaces_event_and_executor_errors() {
+    let _guard = lock_fusion_std_tests();
+
+    let closed = AsyncTcpListener::bind("127.0.0.1:0")
+        .expect("listener should bind")
+        .local_addr()
+        .expect("listener should report its address");
+    let runtime = CurrentAsyncRuntime::new();
+    let refused = runtime
+        .block_on_with_poll_stack_bytes(2048, AsyncTcpStream::connect(closed))
+        .expect("connect task should complete")
+        .expect_err("connect to a closed port should fail");
+    assert_eq!(refused.raw_os_error(), Some(libc::ECONNREFUSED));
+
+    // Outside the executor the would-block path has nowhere to park.
+    let (mut reader, _writer) = async_pipe().expect("pipe should create");
+    let mut byte = [0_u8; 1];
+    let mut read = core::pin::pin!(reader.read(&mut byte));
+    let mut context = Context::from_waker(core::task::Waker::noop());
+    assert!(matches!(
+        read.as_mut().poll(&mut context),
+        Poll::Ready(Err(AsyncIoError::Executor(ExecutorError::Unsupported)))
+    ));
+}
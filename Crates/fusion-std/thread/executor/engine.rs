@@ -22,7 +22,7 @@ impl Executor {
         fast_current: bool,
         backing: CurrentAsyncRuntimeBacking,
     ) -> Self {
-        let reactor = Reactor::new();
+        let reactor = Reactor::readiness();
         let CurrentAsyncRuntimeBacking {
             control,
             reactor: reactor_resource,
@@ -101,7 +101,7 @@ impl Executor {
         if let Ok(backing) = current_async_runtime_virtual_backing(config) {
             return Self::with_runtime_backing(config, scheduler, fast_current, backing);
         }
-        let reactor = Reactor::new();
+        let reactor = Reactor::readiness();
         let inner = match ControlLease::<ExecutorCore>::extent_request()
             .map_err(executor_error_from_alloc)
             .and_then(ExecutorBackingRequest::from_extent_request)
@@ -114,8 +114,10 @@ use fusion_sys::courier::{
 use fusion_sys::event::EventSystem;
 pub use fusion_sys::event::{
     EventCompletion,
+    EventCompletionBuffer,
     EventCompletionOp,
     EventCompletionOpKind,
+    EventCompletionTarget,
     EventError,
     EventErrorKind,
     EventInterest,
@@ -209,6 +211,21 @@ pub use self::hosted::{
 };
 #[cfg(feature = "std")]
 use self::hosted::executor_error_from_fiber_host;
+#[cfg(all(feature = "std", unix))]
+mod io;
+#[cfg(all(feature = "std", unix))]
+pub use self::io::{
+    AsyncFile,
+    AsyncIoError,
+    AsyncPipeReader,
+    AsyncPipeWriter,
+    AsyncTcpListener,
+    AsyncTcpStream,
+    AsyncUdpSocket,
+    AsyncUnixListener,
+    AsyncUnixStream,
+    async_pipe,
+};
 #[cfg(all(feature = "std", test))]
 use self::hosted::hosted_green_executor_stack_size;
 
@@ -847,6 +864,17 @@ impl Reactor {
         }
     }
 
+    /// Creates a reactor wrapper whose pollers only deliver readiness.
+    ///
+    /// Executors drive their own poller through this: their waits are readiness and timer
+    /// waits, so a completion-capable poller would only hold a ring nobody submits to.
+    #[must_use]
+    pub const fn readiness() -> Self {
+        Self {
+            inner: EventSystem::readiness(),
+        }
+    }
+
     /// Reports the truthful backend event support surface.
     #[must_use]
     pub fn support(&self) -> EventSupport {
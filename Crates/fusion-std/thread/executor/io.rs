@@ -0,0 +1,933 @@
+//! Hosted async file and socket I/O over the executor reactor.
+//!
+//! Every type here owns one nonblocking descriptor. Operations try the syscall first and park
+//! the calling task through [`async_wait_for_readiness`] only when the kernel reports that the
+//! descriptor would block, so no operation holds a registration between awaits. Operations take
+//! `&mut self` because one descriptor can only carry one outstanding reactor registration.
+//!
+//! The returned futures keep no inline buffers, so tasks built from them fit the same poll-stack
+//! budgets as any other small readiness wait.
+
+use core::fmt;
+use core::mem;
+
+use std::fs::File;
+use std::io::{
+    self,
+    ErrorKind,
+    PipeReader,
+    PipeWriter,
+    Read,
+    Write,
+};
+use std::net::{
+    Shutdown,
+    SocketAddr,
+    TcpListener,
+    TcpStream,
+    ToSocketAddrs,
+    UdpSocket,
+};
+use std::os::fd::{
+    AsRawFd,
+    FromRawFd,
+    OwnedFd,
+    RawFd,
+};
+use std::os::unix::net::{
+    SocketAddr as UnixSocketAddr,
+    UnixListener,
+    UnixStream,
+};
+use std::path::Path;
+
+use super::{
+    EventError,
+    EventInterest,
+    EventSourceHandle,
+    ExecutorError,
+    async_wait_for_readiness,
+};
+
+/// Error returned by one hosted async I/O operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AsyncIoError {
+    /// The executor could not park or resume the calling task.
+    Executor(ExecutorError),
+    /// The descriptor or operating system refused the operation.
+    Event(EventError),
+}
+
+impl AsyncIoError {
+    /// Returns the raw OS error code when the operating system reported one.
+    #[must_use]
+    pub const fn raw_os_error(self) -> Option<i32> {
+        match self {
+            Self::Event(error) => match error.kind() {
+                super::EventErrorKind::Platform(code) => Some(code),
+                _ => None,
+            },
+            Self::Executor(_) => None,
+        }
+    }
+}
+
+impl From<ExecutorError> for AsyncIoError {
+    fn from(value: ExecutorError) -> Self {
+        Self::Executor(value)
+    }
+}
+
+impl From<EventError> for AsyncIoError {
+    fn from(value: EventError) -> Self {
+        Self::Event(value)
+    }
+}
+
+impl fmt::Display for AsyncIoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Executor(error) => write!(f, "async i/o executor failure: {error:?}"),
+            Self::Event(error) => write!(f, "async i/o failure: {error}"),
+        }
+    }
+}
+
+fn io_error(error: &io::Error) -> AsyncIoError {
+    let Some(code) = error.raw_os_error() else {
+        return AsyncIoError::Event(match error.kind() {
+            ErrorKind::Unsupported => EventError::unsupported(),
+            ErrorKind::TimedOut => EventError::timeout(),
+            ErrorKind::OutOfMemory => EventError::resource_exhausted(),
+            _ => EventError::invalid(),
+        });
+    };
+    AsyncIoError::Event(match code {
+        libc::EBADF | libc::EINVAL | libc::ENOTSOCK => EventError::invalid(),
+        libc::EMFILE | libc::ENFILE | libc::ENOBUFS | libc::ENOMEM => {
+            EventError::resource_exhausted()
+        }
+        libc::ETIMEDOUT => EventError::timeout(),
+        _ => EventError::platform(code),
+    })
+}
+
+fn last_os_error() -> AsyncIoError {
+    io_error(&io::Error::last_os_error())
+}
+
+fn set_descriptor_flags(fd: RawFd) -> Result<(), AsyncIoError> {
+    let status = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+    if status < 0 || unsafe { libc::fcntl(fd, libc::F_SETFL, status | libc::O_NONBLOCK) } < 0 {
+        return Err(last_os_error());
+    }
+    let descriptor = unsafe { libc::fcntl(fd, libc::F_GETFD) };
+    if descriptor < 0
+        || unsafe { libc::fcntl(fd, libc::F_SETFD, descriptor | libc::FD_CLOEXEC) } < 0
+    {
+        return Err(last_os_error());
+    }
+    Ok(())
+}
+
+/// One nonblocking descriptor plus the retry loop shared by every hosted I/O type.
+#[derive(Debug)]
+struct AsyncFd<T: AsRawFd> {
+    io: T,
+}
+
+impl<T: AsRawFd> AsyncFd<T> {
+    fn new(io: T) -> Result<Self, AsyncIoError> {
+        set_descriptor_flags(io.as_raw_fd())?;
+        Ok(Self { io })
+    }
+
+    fn source(&self) -> EventSourceHandle {
+        // Open descriptors are never negative.
+        EventSourceHandle(self.io.as_raw_fd().cast_unsigned() as usize)
+    }
+
+    async fn io<R>(
+        &mut self,
+        interest: EventInterest,
+        mut operation: impl FnMut(&mut T) -> io::Result<R>,
+    ) -> Result<R, AsyncIoError> {
+        loop {
+            match operation(&mut self.io) {
+                Ok(value) => return Ok(value),
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) if error.kind() == ErrorKind::WouldBlock => {}
+                Err(error) => return Err(io_error(&error)),
+            }
+            // Error and hangup readiness fall through to the retried syscall, which reports the
+            // concrete failure or end of stream.
+            async_wait_for_readiness(self.source(), interest).await?;
+        }
+    }
+
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, AsyncIoError>
+    where
+        T: Read,
+    {
+        self.io(EventInterest::READABLE, |io| io.read(buf)).await
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, AsyncIoError>
+    where
+        T: Write,
+    {
+        self.io(EventInterest::WRITABLE, |io| io.write(buf)).await
+    }
+
+    async fn write_all(&mut self, mut buf: &[u8]) -> Result<(), AsyncIoError>
+    where
+        T: Write,
+    {
+        while !buf.is_empty() {
+            match self.write(buf).await? {
+                0 => return Err(AsyncIoError::Event(EventError::state_conflict())),
+                written => buf = &buf[written..],
+            }
+        }
+        Ok(())
+    }
+
+    async fn read_exact(&mut self, mut buf: &mut [u8]) -> Result<(), AsyncIoError>
+    where
+        T: Read,
+    {
+        while !buf.is_empty() {
+            match self.read(buf).await? {
+                0 => return Err(AsyncIoError::Event(EventError::state_conflict())),
+                read => buf = &mut buf[read..],
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Async TCP listener bound to one local address.
+#[derive(Debug)]
+pub struct AsyncTcpListener {
+    inner: AsyncFd<TcpListener>,
+}
+
+impl AsyncTcpListener {
+    /// Binds one listener to the first address that accepts it.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest resolution, bind, or descriptor-configuration failure.
+    pub fn bind(addr: impl ToSocketAddrs) -> Result<Self, AsyncIoError> {
+        Self::from_std(TcpListener::bind(addr).map_err(|error| io_error(&error))?)
+    }
+
+    /// Adopts one already-bound standard listener and switches it to nonblocking mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest descriptor-configuration failure.
+    pub fn from_std(listener: TcpListener) -> Result<Self, AsyncIoError> {
+        Ok(Self {
+            inner: AsyncFd::new(listener)?,
+        })
+    }
+
+    /// Returns the bound local address.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest socket-query failure.
+    pub fn local_addr(&self) -> Result<SocketAddr, AsyncIoError> {
+        self.inner.io.local_addr().map_err(|error| io_error(&error))
+    }
+
+    /// Returns the reactor source handle for this listener.
+    #[must_use]
+    pub fn source(&self) -> EventSourceHandle {
+        self.inner.source()
+    }
+
+    /// Accepts one inbound connection, parking the task until one arrives.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest accept failure or executor wait failure.
+    pub async fn accept(&mut self) -> Result<(AsyncTcpStream, SocketAddr), AsyncIoError> {
+        let (stream, peer) = self
+            .inner
+            .io(EventInterest::READABLE, |listener| listener.accept())
+            .await?;
+        Ok((AsyncTcpStream::from_std(stream)?, peer))
+    }
+
+    /// Returns the underlying standard listener, still in nonblocking mode.
+    #[must_use]
+    pub fn into_std(self) -> TcpListener {
+        self.inner.io
+    }
+}
+
+/// Async TCP stream.
+#[derive(Debug)]
+pub struct AsyncTcpStream {
+    inner: AsyncFd<TcpStream>,
+}
+
+impl AsyncTcpStream {
+    /// Connects to one remote address, parking the task while the handshake is in flight.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest socket, connect, or executor wait failure.
+    pub async fn connect(addr: SocketAddr) -> Result<Self, AsyncIoError> {
+        let (family, storage, len) = socket_address(addr);
+        let fd = unsafe { libc::socket(family, libc::SOCK_STREAM | libc::SOCK_CLOEXEC, 0) };
+        if fd < 0 {
+            return Err(last_os_error());
+        }
+        let socket = unsafe { OwnedFd::from_raw_fd(fd) };
+        let inner = AsyncFd::new(socket)?;
+        let rc = unsafe { libc::connect(fd, (&raw const storage).cast::<libc::sockaddr>(), len) };
+        if rc < 0 {
+            let error = io::Error::last_os_error();
+            if error.raw_os_error() != Some(libc::EINPROGRESS) {
+                return Err(io_error(&error));
+            }
+            // The handshake outcome only exists once the socket turns writable; checking first
+            // would read a clean `SO_ERROR` off a connect that has not finished yet.
+            loop {
+                async_wait_for_readiness(inner.source(), EventInterest::WRITABLE).await?;
+                match socket_connect_result(fd) {
+                    Ok(()) => break,
+                    Err(error)
+                        if matches!(
+                            error.kind(),
+                            ErrorKind::WouldBlock | ErrorKind::Interrupted
+                        ) => {}
+                    Err(error) => return Err(io_error(&error)),
+                }
+            }
+        }
+        Ok(Self {
+            inner: AsyncFd {
+                io: TcpStream::from(inner.io),
+            },
+        })
+    }
+
+    /// Adopts one connected standard stream and switches it to nonblocking mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest descriptor-configuration failure.
+    pub fn from_std(stream: TcpStream) -> Result<Self, AsyncIoError> {
+        Ok(Self {
+            inner: AsyncFd::new(stream)?,
+        })
+    }
+
+    /// Returns the local socket address.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest socket-query failure.
+    pub fn local_addr(&self) -> Result<SocketAddr, AsyncIoError> {
+        self.inner.io.local_addr().map_err(|error| io_error(&error))
+    }
+
+    /// Returns the remote socket address.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest socket-query failure.
+    pub fn peer_addr(&self) -> Result<SocketAddr, AsyncIoError> {
+        self.inner.io.peer_addr().map_err(|error| io_error(&error))
+    }
+
+    /// Enables or disables Nagle's algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest socket-option failure.
+    pub fn set_nodelay(&self, nodelay: bool) -> Result<(), AsyncIoError> {
+        self.inner
+            .io
+            .set_nodelay(nodelay)
+            .map_err(|error| io_error(&error))
+    }
+
+    /// Shuts down the read half, write half, or both halves of the connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest shutdown failure.
+    pub fn shutdown(&self, how: Shutdown) -> Result<(), AsyncIoError> {
+        self.inner
+            .io
+            .shutdown(how)
+            .map_err(|error| io_error(&error))
+    }
+
+    /// Returns the reactor source handle for this stream.
+    #[must_use]
+    pub fn source(&self) -> EventSourceHandle {
+        self.inner.source()
+    }
+
+    /// Reads available bytes, returning `0` at end of stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest read failure or executor wait failure.
+    pub async fn read(&mut self, buf: &mut [u8]) -> Result<usize, AsyncIoError> {
+        self.inner.read(buf).await
+    }
+
+    /// Fills the whole buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EventError::state_conflict`] when the stream ends early, or any honest read
+    /// failure or executor wait failure.
+    pub async fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), AsyncIoError> {
+        self.inner.read_exact(buf).await
+    }
+
+    /// Writes as many bytes as the socket accepts.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest write failure or executor wait failure.
+    pub async fn write(&mut self, buf: &[u8]) -> Result<usize, AsyncIoError> {
+        self.inner.write(buf).await
+    }
+
+    /// Writes the whole buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest write failure or executor wait failure.
+    pub async fn write_all(&mut self, buf: &[u8]) -> Result<(), AsyncIoError> {
+        self.inner.write_all(buf).await
+    }
+
+    /// Returns the underlying standard stream, still in nonblocking mode.
+    #[must_use]
+    pub fn into_std(self) -> TcpStream {
+        self.inner.io
+    }
+}
+
+// The address family constants and sockaddr sizes always fit their narrower C types.
+#[allow(clippy::cast_possible_truncation)]
+const fn socket_address(
+    addr: SocketAddr,
+) -> (libc::c_int, libc::sockaddr_storage, libc::socklen_t) {
+    let mut storage: libc::sockaddr_storage = unsafe { mem::zeroed() };
+    match addr {
+        SocketAddr::V4(addr) => {
+            let mut raw: libc::sockaddr_in = unsafe { mem::zeroed() };
+            raw.sin_family = libc::AF_INET as libc::sa_family_t;
+            raw.sin_port = addr.port().to_be();
+            raw.sin_addr.s_addr = u32::from_ne_bytes(addr.ip().octets());
+            unsafe { (&raw mut storage).cast::<libc::sockaddr_in>().write(raw) };
+            (
+                libc::AF_INET,
+                storage,
+                mem::size_of::<libc::sockaddr_in>() as libc::socklen_t,
+            )
+        }
+        SocketAddr::V6(addr) => {
+            let mut raw: libc::sockaddr_in6 = unsafe { mem::zeroed() };
+            raw.sin6_family = libc::AF_INET6 as libc::sa_family_t;
+            raw.sin6_port = addr.port().to_be();
+            raw.sin6_flowinfo = addr.flowinfo();
+            raw.sin6_addr.s6_addr = addr.ip().octets();
+            raw.sin6_scope_id = addr.scope_id();
+            unsafe { (&raw mut storage).cast::<libc::sockaddr_in6>().write(raw) };
+            (
+                libc::AF_INET6,
+                storage,
+                mem::size_of::<libc::sockaddr_in6>() as libc::socklen_t,
+            )
+        }
+    }
+}
+
+/// Reports the outcome of one in-flight nonblocking connect once the socket turns writable.
+///
+/// Returns [`ErrorKind::WouldBlock`] when the wake was spurious and the handshake is still
+/// running.
+#[allow(clippy::cast_possible_truncation)]
+fn socket_connect_result(fd: RawFd) -> io::Result<()> {
+    let mut error: libc::c_int = 0;
+    let mut len = mem::size_of::<libc::c_int>() as libc::socklen_t;
+    let rc = unsafe {
+        libc::getsockopt(
+            fd,
+            libc::SOL_SOCKET,
+            libc::SO_ERROR,
+            (&raw mut error).cast::<libc::c_void>(),
+            &raw mut len,
+        )
+    };
+    if rc < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    if error != 0 {
+        return Err(io::Error::from_raw_os_error(error));
+    }
+    // A clean `SO_ERROR` is only final once the peer is known.
+    let mut peer: libc::sockaddr_storage = unsafe { mem::zeroed() };
+    let mut peer_len = mem::size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+    if unsafe {
+        libc::getpeername(
+            fd,
+            (&raw mut peer).cast::<libc::sockaddr>(),
+            &raw mut peer_len,
+        )
+    } < 0
+    {
+        let error = io::Error::last_os_error();
+        return Err(if error.raw_os_error() == Some(libc::ENOTCONN) {
+            ErrorKind::WouldBlock.into()
+        } else {
+            error
+        });
+    }
+    Ok(())
+}
+
+/// Async UDP socket.
+#[derive(Debug)]
+pub struct AsyncUdpSocket {
+    inner: AsyncFd<UdpSocket>,
+}
+
+impl AsyncUdpSocket {
+    /// Binds one socket to the first address that accepts it.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest resolution, bind, or descriptor-configuration failure.
+    pub fn bind(addr: impl ToSocketAddrs) -> Result<Self, AsyncIoError> {
+        Self::from_std(UdpSocket::bind(addr).map_err(|error| io_error(&error))?)
+    }
+
+    /// Adopts one standard socket and switches it to nonblocking mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest descriptor-configuration failure.
+    pub fn from_std(socket: UdpSocket) -> Result<Self, AsyncIoError> {
+        Ok(Self {
+            inner: AsyncFd::new(socket)?,
+        })
+    }
+
+    /// Sets the default peer used by [`Self::send`] and filtered by [`Self::recv`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest resolution or connect failure.
+    pub fn connect(&self, addr: impl ToSocketAddrs) -> Result<(), AsyncIoError> {
+        self.inner
+            .io
+            .connect(addr)
+            .map_err(|error| io_error(&error))
+    }
+
+    /// Returns the bound local address.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest socket-query failure.
+    pub fn local_addr(&self) -> Result<SocketAddr, AsyncIoError> {
+        self.inner.io.local_addr().map_err(|error| io_error(&error))
+    }
+
+    /// Returns the reactor source handle for this socket.
+    #[must_use]
+    pub fn source(&self) -> EventSourceHandle {
+        self.inner.source()
+    }
+
+    /// Sends one datagram to `target`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest send failure or executor wait failure.
+    pub async fn send_to(&mut self, buf: &[u8], target: SocketAddr) -> Result<usize, AsyncIoError> {
+        self.inner
+            .io(EventInterest::WRITABLE, |socket| {
+                socket.send_to(buf, target)
+            })
+            .await
+    }
+
+    /// Receives one datagram and the address it came from.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest receive failure or executor wait failure.
+    pub async fn recv_from(&mut self, buf: &mut [u8]) -> Result<(usize, SocketAddr), AsyncIoError> {
+        self.inner
+            .io(EventInterest::READABLE, |socket| socket.recv_from(buf))
+            .await
+    }
+
+    /// Sends one datagram to the connected peer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest send failure or executor wait failure.
+    pub async fn send(&mut self, buf: &[u8]) -> Result<usize, AsyncIoError> {
+        self.inner
+            .io(EventInterest::WRITABLE, |socket| socket.send(buf))
+            .await
+    }
+
+    /// Receives one datagram from the connected peer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest receive failure or executor wait failure.
+    pub async fn recv(&mut self, buf: &mut [u8]) -> Result<usize, AsyncIoError> {
+        self.inner
+            .io(EventInterest::READABLE, |socket| socket.recv(buf))
+            .await
+    }
+
+    /// Returns the underlying standard socket, still in nonblocking mode.
+    #[must_use]
+    pub fn into_std(self) -> UdpSocket {
+        self.inner.io
+    }
+}
+
+/// Async Unix-domain stream listener.
+#[derive(Debug)]
+pub struct AsyncUnixListener {
+    inner: AsyncFd<UnixListener>,
+}
+
+impl AsyncUnixListener {
+    /// Binds one listener to a filesystem path.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest bind or descriptor-configuration failure.
+    pub fn bind(path: impl AsRef<Path>) -> Result<Self, AsyncIoError> {
+        Self::from_std(UnixListener::bind(path).map_err(|error| io_error(&error))?)
+    }
+
+    /// Adopts one already-bound standard listener and switches it to nonblocking mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest descriptor-configuration failure.
+    pub fn from_std(listener: UnixListener) -> Result<Self, AsyncIoError> {
+        Ok(Self {
+            inner: AsyncFd::new(listener)?,
+        })
+    }
+
+    /// Returns the reactor source handle for this listener.
+    #[must_use]
+    pub fn source(&self) -> EventSourceHandle {
+        self.inner.source()
+    }
+
+    /// Accepts one inbound connection, parking the task until one arrives.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest accept failure or executor wait failure.
+    pub async fn accept(&mut self) -> Result<(AsyncUnixStream, UnixSocketAddr), AsyncIoError> {
+        let (stream, peer) = self
+            .inner
+            .io(EventInterest::READABLE, |listener| listener.accept())
+            .await?;
+        Ok((AsyncUnixStream::from_std(stream)?, peer))
+    }
+
+    /// Returns the underlying standard listener, still in nonblocking mode.
+    #[must_use]
+    pub fn into_std(self) -> UnixListener {
+        self.inner.io
+    }
+}
+
+/// Async Unix-domain stream.
+#[derive(Debug)]
+pub struct AsyncUnixStream {
+    inner: AsyncFd<UnixStream>,
+}
+
+impl AsyncUnixStream {
+    /// Connects to one listening Unix-domain socket.
+    ///
+    /// Local connects complete or fail without waiting on the peer, so this does not park.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest connect or descriptor-configuration failure.
+    pub fn connect(path: impl AsRef<Path>) -> Result<Self, AsyncIoError> {
+        Self::from_std(UnixStream::connect(path).map_err(|error| io_error(&error))?)
+    }
+
+    /// Creates one connected pair of streams.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest socketpair or descriptor-configuration failure.
+    pub fn pair() -> Result<(Self, Self), AsyncIoError> {
+        let (left, right) = UnixStream::pair().map_err(|error| io_error(&error))?;
+        Ok((Self::from_std(left)?, Self::from_std(right)?))
+    }
+
+    /// Adopts one connected standard stream and switches it to nonblocking mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest descriptor-configuration failure.
+    pub fn from_std(stream: UnixStream) -> Result<Self, AsyncIoError> {
+        Ok(Self {
+            inner: AsyncFd::new(stream)?,
+        })
+    }
+
+    /// Shuts down the read half, write half, or both halves of the connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest shutdown failure.
+    pub fn shutdown(&self, how: Shutdown) -> Result<(), AsyncIoError> {
+        self.inner
+            .io
+            .shutdown(how)
+            .map_err(|error| io_error(&error))
+    }
+
+    /// Returns the reactor source handle for this stream.
+    #[must_use]
+    pub fn source(&self) -> EventSourceHandle {
+        self.inner.source()
+    }
+
+    /// Reads available bytes, returning `0` at end of stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest read failure or executor wait failure.
+    pub async fn read(&mut self, buf: &mut [u8]) -> Result<usize, AsyncIoError> {
+        self.inner.read(buf).await
+    }
+
+    /// Fills the whole buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EventError::state_conflict`] when the stream ends early, or any honest read
+    /// failure or executor wait failure.
+    pub async fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), AsyncIoError> {
+        self.inner.read_exact(buf).await
+    }
+
+    /// Writes as many bytes as the socket accepts.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest write failure or executor wait failure.
+    pub async fn write(&mut self, buf: &[u8]) -> Result<usize, AsyncIoError> {
+        self.inner.write(buf).await
+    }
+
+    /// Writes the whole buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest write failure or executor wait failure.
+    pub async fn write_all(&mut self, buf: &[u8]) -> Result<(), AsyncIoError> {
+        self.inner.write_all(buf).await
+    }
+
+    /// Returns the underlying standard stream, still in nonblocking mode.
+    #[must_use]
+    pub fn into_std(self) -> UnixStream {
+        self.inner.io
+    }
+}
+
+/// Creates one anonymous pipe as an async reader/writer pair.
+///
+/// # Errors
+///
+/// Returns any honest pipe-creation or descriptor-configuration failure.
+pub fn async_pipe() -> Result<(AsyncPipeReader, AsyncPipeWriter), AsyncIoError> {
+    let (reader, writer) = io::pipe().map_err(|error| io_error(&error))?;
+    Ok((
+        AsyncPipeReader {
+            inner: AsyncFd::new(reader)?,
+        },
+        AsyncPipeWriter {
+            inner: AsyncFd::new(writer)?,
+        },
+    ))
+}
+
+/// Read end of one async pipe.
+#[derive(Debug)]
+pub struct AsyncPipeReader {
+    inner: AsyncFd<PipeReader>,
+}
+
+impl AsyncPipeReader {
+    /// Returns the reactor source handle for this pipe end.
+    #[must_use]
+    pub fn source(&self) -> EventSourceHandle {
+        self.inner.source()
+    }
+
+    /// Reads available bytes, returning `0` once every writer has closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest read failure or executor wait failure.
+    pub async fn read(&mut self, buf: &mut [u8]) -> Result<usize, AsyncIoError> {
+        self.inner.read(buf).await
+    }
+
+    /// Fills the whole buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EventError::state_conflict`] when every writer closes early, or any honest read
+    /// failure or executor wait failure.
+    pub async fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), AsyncIoError> {
+        self.inner.read_exact(buf).await
+    }
+}
+
+/// Write end of one async pipe.
+#[derive(Debug)]
+pub struct AsyncPipeWriter {
+    inner: AsyncFd<PipeWriter>,
+}
+
+impl AsyncPipeWriter {
+    /// Returns the reactor source handle for this pipe end.
+    #[must_use]
+    pub fn source(&self) -> EventSourceHandle {
+        self.inner.source()
+    }
+
+    /// Writes as many bytes as the pipe accepts.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest write failure or executor wait failure.
+    pub async fn write(&mut self, buf: &[u8]) -> Result<usize, AsyncIoError> {
+        self.inner.write(buf).await
+    }
+
+    /// Writes the whole buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest write failure or executor wait failure.
+    pub async fn write_all(&mut self, buf: &[u8]) -> Result<(), AsyncIoError> {
+        self.inner.write_all(buf).await
+    }
+}
+
+/// Async wrapper over one open file, FIFO, or character device.
+///
+/// Descriptors the kernel can report readiness for park the task while they would block.
+/// Regular files never report `EAGAIN`, so their reads and writes complete synchronously inside
+/// the poll.
+#[derive(Debug)]
+pub struct AsyncFile {
+    inner: AsyncFd<File>,
+}
+
+impl AsyncFile {
+    /// Opens one existing path for reading.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest open or descriptor-configuration failure.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, AsyncIoError> {
+        Self::from_std(File::open(path).map_err(|error| io_error(&error))?)
+    }
+
+    /// Creates or truncates one path for writing.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest open or descriptor-configuration failure.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, AsyncIoError> {
+        Self::from_std(File::create(path).map_err(|error| io_error(&error))?)
+    }
+
+    /// Adopts one open standard file and switches it to nonblocking mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest descriptor-configuration failure.
+    pub fn from_std(file: File) -> Result<Self, AsyncIoError> {
+        Ok(Self {
+            inner: AsyncFd::new(file)?,
+        })
+    }
+
+    /// Returns the reactor source handle for this file.
+    #[must_use]
+    pub fn source(&self) -> EventSourceHandle {
+        self.inner.source()
+    }
+
+    /// Reads available bytes, returning `0` at end of file.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest read failure or executor wait failure.
+    pub async fn read(&mut self, buf: &mut [u8]) -> Result<usize, AsyncIoError> {
+        self.inner.read(buf).await
+    }
+
+    /// Fills the whole buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EventError::state_conflict`] when the file ends early, or any honest read
+    /// failure or executor wait failure.
+    pub async fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), AsyncIoError> {
+        self.inner.read_exact(buf).await
+    }
+
+    /// Writes as many bytes as the descriptor accepts.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest write failure or executor wait failure.
+    pub async fn write(&mut self, buf: &[u8]) -> Result<usize, AsyncIoError> {
+        self.inner.write(buf).await
+    }
+
+    /// Writes the whole buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest write failure or executor wait failure.
+    pub async fn write_all(&mut self, buf: &[u8]) -> Result<(), AsyncIoError> {
+        self.inner.write_all(buf).await
+    }
+
+    /// Returns the underlying standard file, still in nonblocking mode.
+    #[must_use]
+    pub fn into_std(self) -> File {
+        self.inner.io
+    }
+}
@@ -671,6 +671,7 @@ impl ExecutorReactorState {
                 wake_event = true;
                 continue;
             }
+            // Executor pollers come from `Reactor::readiness`, so nothing here is a completion.
             let EventNotification::Readiness(readiness) = event.notification else {
                 continue;
             };
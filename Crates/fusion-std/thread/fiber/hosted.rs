@@ -779,7 +779,7 @@ fn build_hosted_green_inner(
     }
     let alignment = support.context.min_stack_alignment.max(16);
     let stacks = FiberStackStore::new(config, alignment, support.context.stack_direction)?;
-    let reactor_enabled = EventSystem::new()
+    let reactor_enabled = EventSystem::readiness()
         .support()
         .caps
         .contains(EventCaps::READINESS)
@@ -95,7 +95,7 @@ impl GreenPool {
             backing.stack_metadata,
         )?);
         let task_capacity = stacks.total_capacity();
-        let reactor_enabled = EventSystem::new()
+        let reactor_enabled = EventSystem::readiness()
             .support()
             .caps
             .contains(EventCaps::READINESS)
@@ -575,7 +575,7 @@ fn green_pool_owned_backing(
     }
 
     let task_capacity = config.task_capacity_per_carrier()?;
-    let reactor_enabled = EventSystem::new()
+    let reactor_enabled = EventSystem::readiness()
         .support()
         .caps
         .contains(EventCaps::READINESS)
@@ -1382,7 +1382,7 @@ pub fn wait_blocking_for_readiness(
     source: EventSourceHandle,
     interest: EventInterest,
 ) -> Result<(), FiberError> {
-    let reactor = EventSystem::new();
+    let reactor = EventSystem::readiness();
     let mut poller = reactor.create().map_err(fiber_error_from_event)?;
     let key = reactor
         .register(
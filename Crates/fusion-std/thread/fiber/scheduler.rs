@@ -101,7 +101,7 @@ impl CarrierReactorState {
             }
         }
 
-        let reactor = EventSystem::new();
+        let reactor = EventSystem::readiness();
         let host = system_fiber_host();
         let mut poller = reactor.create().map_err(fiber_error_from_event)?;
         let wake = host.create_wake_signal().map_err(fiber_error_from_host)?;
@@ -1,9 +1,45 @@
 //! Domain 4: public task-graph surface.
+//!
+//! [`TaskGraph`] is a fixed-capacity dependency graph. Each node carries one borrowed [`Job`]
+//! plus the [`TaskTarget`] that should run it, and each edge states that one node must finish
+//! successfully before another may start. [`TaskGraph::run`] blocks the caller while it releases
+//! ready nodes to their targets wave by wave, skips every dependent of a node that fails, is
+//! cancelled, panics, or is rejected by its target, and reports per-node outcome and timing.
+//!
+//! Graph storage is inline and bounded by the `NODES` and `EDGES` const parameters; neither
+//! building nor running a graph allocates.
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::hint::spin_loop;
+use core::marker::PhantomData;
+use core::sync::atomic::{
+    AtomicBool,
+    Ordering,
+};
+use core::time::Duration;
+
+use fusion_sys::fiber::{
+    FiberError,
+    FiberErrorKind,
+};
+use fusion_sys::sync::{
+    Semaphore,
+    SyncError,
+    SyncErrorKind,
+};
 
 use super::{
     Executor,
+    ExecutorError,
+    ExecutorMode,
+    FiberTaskAttributes,
     GreenPool,
+    SystemWorkItem,
+    ThreadError,
+    ThreadErrorKind,
     ThreadPool,
+    system_monotonic_time,
 };
 
 /// Public task target kind for graph dispatch.
@@ -21,62 +57,912 @@ pub enum TaskTargetKind {
 pub trait TaskTarget {
     /// Returns the public kind of dispatch target.
     fn target_kind(&self) -> TaskTargetKind;
+
+    /// Hands one ready graph node to this target for execution.
+    ///
+    /// The target must eventually either call [`TaskDispatch::run`] or drop the dispatch token;
+    /// dropping it unrun reports the node as cancelled. [`TaskGraph::run`] does not return, or
+    /// finish unwinding, until every token it handed out has been run or dropped, so a leaked
+    /// token blocks it forever.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the target cannot accept the node; the graph then reports the node
+    /// as rejected and skips its dependents.
+    fn dispatch(&self, work: TaskDispatch) -> Result<(), TaskGraphError>;
 }
 
 impl TaskTarget for ThreadPool {
     fn target_kind(&self) -> TaskTargetKind {
         TaskTargetKind::ThreadPool
     }
+
+    fn dispatch(&self, work: TaskDispatch) -> Result<(), TaskGraphError> {
+        let item = work.into_work_item();
+        self.submit_raw(item).map_err(|error| {
+            // SAFETY: the pool refused the item, so its context was never handed to a worker and
+            // still owns the only pending completion for this node.
+            unsafe { cancel_task_node(item.context) };
+            TaskGraphError::from(error)
+        })
+    }
 }
 
 impl TaskTarget for GreenPool {
     fn target_kind(&self) -> TaskTargetKind {
         TaskTargetKind::GreenPool
     }
+
+    fn dispatch(&self, work: TaskDispatch) -> Result<(), TaskGraphError> {
+        // Dropping the handle detaches the green thread; completion is reported by the token.
+        let spawned = match work.fiber_attributes() {
+            Some(task) => self.spawn_with_attrs(task, move || work.run()).map(drop),
+            None => self.spawn(move || work.run()).map(drop),
+        };
+        spawned.map_err(TaskGraphError::from)
+    }
 }
 
 impl TaskTarget for Executor {
     fn target_kind(&self) -> TaskTargetKind {
         TaskTargetKind::Executor
     }
+
+    fn dispatch(&self, work: TaskDispatch) -> Result<(), TaskGraphError> {
+        // A current-thread executor only makes progress while its owner drives it, and the graph
+        // owner is blocked inside `run`.
+        if self.mode() == ExecutorMode::CurrentThread {
+            return Err(TaskGraphError::unsupported());
+        }
+        self.spawn(async move { work.run() })
+            .map(drop)
+            .map_err(TaskGraphError::from)
+    }
 }
 
 /// Handle to a task node in a graph.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TaskNode(pub u32);
 
-/// Batch of dispatched tasks.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct TaskBatch;
+impl TaskNode {
+    const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+const fn node_at(index: usize) -> TaskNode {
+    // Node indices are bounded by `add_node`, which refuses anything past `u32::MAX`.
+    TaskNode(index as u32)
+}
 
-/// Planned task-slab configuration.
+/// Dispatch wave in which a node was released to its target.
+///
+/// Wave zero holds every node without dependencies; each later wave holds the nodes unblocked by
+/// completions observed since the previous one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TaskBatch(pub u32);
+
+/// Inline storage footprint of one task graph.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TaskSlab {
     /// Maximum number of task nodes carried in the slab.
     pub capacity: usize,
+    /// Maximum number of dependency edges carried in the slab.
+    pub edge_capacity: usize,
 }
 
-/// Opaque job description placeholder.
+/// Failure reported by one job.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Job {
-    /// Stable opaque job identifier.
-    pub id: u64,
+pub enum JobFailure {
+    /// The job failed with one caller-defined code.
+    Failed(u32),
+    /// The job observed cancellation and stopped early.
+    Cancelled,
 }
 
-/// Public task-graph surface.
-#[derive(Debug, Default, Clone, Copy)]
-pub struct TaskGraph;
+/// Result returned by one job.
+pub type JobResult = Result<(), JobFailure>;
+
+/// Borrowed unit of work carried by one graph node.
+///
+/// The job may run on any carrier owned by the node's target, so the closure must be `Sync`.
+#[derive(Clone, Copy)]
+pub struct Job<'a> {
+    work: &'a (dyn Fn() -> JobResult + Sync),
+    fiber: Option<FiberTaskAttributes>,
+}
+
+impl<'a> Job<'a> {
+    /// Wraps one borrowed closure as a graph job.
+    #[must_use]
+    pub const fn new<F>(work: &'a F) -> Self
+    where
+        F: Fn() -> JobResult + Sync,
+    {
+        Self { work, fiber: None }
+    }
+
+    /// Declares the fiber contract used when this job is dispatched to a [`GreenPool`].
+    ///
+    /// Graph jobs run inside one anonymous closure, so green pools cannot resolve generated stack
+    /// metadata for them; without an explicit contract a green-pool node is rejected.
+    #[must_use]
+    pub const fn with_fiber_attributes(mut self, task: FiberTaskAttributes) -> Self {
+        self.fiber = Some(task);
+        self
+    }
 
-impl TaskGraph {
-    /// Creates a new empty graph surface.
+    /// Returns the explicit fiber contract, when one was declared.
+    #[must_use]
+    pub const fn fiber_attributes(&self) -> Option<FiberTaskAttributes> {
+        self.fiber
+    }
+
+    /// Runs the job on the calling thread.
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever failure the wrapped closure reports.
+    pub fn run(&self) -> JobResult {
+        (self.work)()
+    }
+}
+
+impl fmt::Debug for Job<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Job")
+            .field("fiber", &self.fiber)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Final outcome of one graph node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskNodeOutcome {
+    /// The job ran and returned success.
+    Succeeded,
+    /// The job ran and reported one failure code.
+    Failed(u32),
+    /// The job reported cancellation, or its target dropped it without running it.
+    Cancelled,
+    /// The job panicked while running.
+    Panicked,
+    /// The node never ran because an upstream node did not succeed.
+    Skipped {
+        /// Upstream node whose outcome caused this node to be skipped.
+        cause: TaskNode,
+    },
+    /// The node's target refused to accept it.
+    Rejected(TaskGraphErrorKind),
+}
+
+impl TaskNodeOutcome {
+    /// Returns whether the node ran to successful completion.
+    #[must_use]
+    pub const fn is_success(self) -> bool {
+        matches!(self, Self::Succeeded)
+    }
+}
+
+/// Per-node result of one graph run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaskNodeReport {
+    /// Node this report describes.
+    pub node: TaskNode,
+    /// Final node outcome.
+    pub outcome: TaskNodeOutcome,
+    /// Dispatch wave that released the node, when it was released at all.
+    pub batch: Option<TaskBatch>,
+    /// Time between dispatch and the job starting on its target, when observable.
+    pub queued: Option<Duration>,
+    /// Time the job spent running, when observable.
+    pub elapsed: Option<Duration>,
+}
+
+/// Result of one [`TaskGraph::run`].
+#[derive(Debug, Clone, Copy)]
+pub struct TaskGraphReport<const NODES: usize> {
+    nodes: [Option<TaskNodeReport>; NODES],
+    len: usize,
+    batches: u32,
+    elapsed: Option<Duration>,
+}
+
+impl<const NODES: usize> TaskGraphReport<NODES> {
+    /// Returns the report for one node.
+    #[must_use]
+    pub fn node(&self, node: TaskNode) -> Option<TaskNodeReport> {
+        self.nodes.get(node.index()).copied().flatten()
+    }
+
+    /// Iterates the node reports in node order.
+    pub fn iter(&self) -> impl Iterator<Item = TaskNodeReport> + '_ {
+        self.nodes[..self.len].iter().flatten().copied()
+    }
+
+    /// Returns the number of reported nodes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the run covered no nodes.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of dispatch waves the run used.
+    #[must_use]
+    pub const fn batches(&self) -> u32 {
+        self.batches
+    }
+
+    /// Returns the wall time of the whole run, when observable.
+    #[must_use]
+    pub const fn elapsed(&self) -> Option<Duration> {
+        self.elapsed
+    }
+
+    /// Returns whether every node succeeded.
+    #[must_use]
+    pub fn all_succeeded(&self) -> bool {
+        self.iter().all(|report| report.outcome.is_success())
+    }
+}
+
+/// Kind of task-graph failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskGraphErrorKind {
+    /// The target or operation is unsupported.
+    Unsupported,
+    /// The request named an unknown node or was otherwise structurally invalid.
+    Invalid,
+    /// The graph's fixed node or edge capacity is exhausted.
+    CapacityExhausted,
+    /// The requested dependency would close a cycle.
+    Cycle,
+    /// A thread-pool target failed.
+    Thread(ThreadErrorKind),
+    /// A green-pool target failed.
+    Fiber(FiberErrorKind),
+    /// An executor target failed.
+    Executor(ExecutorError),
+    /// Run coordination failed.
+    Sync(SyncErrorKind),
+}
+
+/// Error surfaced by task-graph construction, dispatch, or execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaskGraphError {
+    kind: TaskGraphErrorKind,
+}
+
+impl TaskGraphError {
+    /// Creates an unsupported-operation error.
+    #[must_use]
+    pub const fn unsupported() -> Self {
+        Self {
+            kind: TaskGraphErrorKind::Unsupported,
+        }
+    }
+
+    /// Creates an invalid-request error.
+    #[must_use]
+    pub const fn invalid() -> Self {
+        Self {
+            kind: TaskGraphErrorKind::Invalid,
+        }
+    }
+
+    /// Creates a capacity-exhausted error.
+    #[must_use]
+    pub const fn capacity_exhausted() -> Self {
+        Self {
+            kind: TaskGraphErrorKind::CapacityExhausted,
+        }
+    }
+
+    /// Creates a dependency-cycle error.
+    #[must_use]
+    pub const fn cycle() -> Self {
+        Self {
+            kind: TaskGraphErrorKind::Cycle,
+        }
+    }
+
+    /// Returns the concrete task-graph error kind.
+    #[must_use]
+    pub const fn kind(self) -> TaskGraphErrorKind {
+        self.kind
+    }
+}
+
+impl From<ThreadError> for TaskGraphError {
+    fn from(value: ThreadError) -> Self {
+        Self {
+            kind: TaskGraphErrorKind::Thread(value.kind()),
+        }
+    }
+}
+
+impl From<FiberError> for TaskGraphError {
+    fn from(value: FiberError) -> Self {
+        Self {
+            kind: TaskGraphErrorKind::Fiber(value.kind()),
+        }
+    }
+}
+
+impl From<ExecutorError> for TaskGraphError {
+    fn from(value: ExecutorError) -> Self {
+        Self {
+            kind: TaskGraphErrorKind::Executor(value),
+        }
+    }
+}
+
+impl From<SyncError> for TaskGraphError {
+    fn from(value: SyncError) -> Self {
+        Self {
+            kind: TaskGraphErrorKind::Sync(value.kind),
+        }
+    }
+}
+
+impl fmt::Display for TaskGraphErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Unsupported => f.write_str("task-graph operation unsupported"),
+            Self::Invalid => f.write_str("invalid task-graph request"),
+            Self::CapacityExhausted => f.write_str("task-graph capacity exhausted"),
+            Self::Cycle => f.write_str("task-graph dependency cycle"),
+            Self::Thread(kind) => write!(f, "thread-pool target error: {kind}"),
+            Self::Fiber(kind) => write!(f, "green-pool target error: {kind}"),
+            Self::Executor(error) => write!(f, "executor target error: {error:?}"),
+            Self::Sync(kind) => write!(f, "task-graph synchronization error: {kind}"),
+        }
+    }
+}
+
+impl fmt::Display for TaskGraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind.fmt(f)
+    }
+}
+
+/// Owned token for one dispatched graph node.
+///
+/// Targets move the token onto whatever carrier runs the node and call [`TaskDispatch::run`]
+/// there. Dropping the token without running it completes the node as cancelled, so a target
+/// that discards queued work cannot stall the graph.
+pub struct TaskDispatch {
+    context: *mut (),
+    _not_sync: PhantomData<UnsafeCell<()>>,
+}
+
+impl TaskDispatch {
+    /// Returns the explicit fiber contract declared by the node's job, when any.
+    #[must_use]
+    pub fn fiber_attributes(&self) -> Option<FiberTaskAttributes> {
+        // SAFETY: the context points at live run state, and the job is never mutated during a run.
+        let run = unsafe { &*self.context.cast::<NodeRun<'_>>() };
+        run.job.and_then(|job| job.fiber)
+    }
+
+    /// Runs the node's job on the calling carrier and reports its completion to the graph.
+    pub fn run(self) {
+        let context = self.context;
+        core::mem::forget(self);
+        // SAFETY: the token was the sole owner of this pending completion and has been consumed.
+        unsafe { run_task_node(context) };
+    }
+
+    fn into_work_item(self) -> SystemWorkItem {
+        let context = self.context;
+        core::mem::forget(self);
+        SystemWorkItem::with_cancel(run_task_node, context, cancel_task_node)
+    }
+}
+
+impl Drop for TaskDispatch {
+    fn drop(&mut self) {
+        // SAFETY: an unconsumed token still owns the only pending completion for its node.
+        unsafe { cancel_task_node(self.context) };
+    }
+}
+
+impl fmt::Debug for TaskDispatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskDispatch").finish_non_exhaustive()
+    }
+}
+
+// SAFETY: the token points at graph-run state that stays alive until the coordinator observes the
+// node's completion, and that state is only touched through atomics and the completion semaphore
+// once the token has left the coordinating thread.
+unsafe impl Send for TaskDispatch {}
+
+#[derive(Clone, Copy)]
+struct NodeSpec<'a> {
+    target: &'a dyn TaskTarget,
+    job: Job<'a>,
+}
+
+/// Fixed-capacity dependency graph of jobs bound to dispatch targets.
+pub struct TaskGraph<'a, const NODES: usize = 32, const EDGES: usize = 64> {
+    nodes: [Option<NodeSpec<'a>>; NODES],
+    len: usize,
+    edges: [(TaskNode, TaskNode); EDGES],
+    edge_len: usize,
+}
+
+impl<'a, const NODES: usize, const EDGES: usize> TaskGraph<'a, NODES, EDGES> {
+    /// Creates a new empty graph.
     #[must_use]
     pub const fn new() -> Self {
-        Self
+        Self {
+            nodes: [None; NODES],
+            len: 0,
+            edges: [(TaskNode(0), TaskNode(0)); EDGES],
+            edge_len: 0,
+        }
+    }
+
+    /// Returns the inline storage footprint of this graph shape.
+    #[must_use]
+    pub const fn slab(&self) -> TaskSlab {
+        TaskSlab {
+            capacity: NODES,
+            edge_capacity: EDGES,
+        }
+    }
+
+    /// Returns the number of nodes in the graph.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
     }
 
     /// Returns whether the graph currently contains no planned tasks.
     #[must_use]
     pub const fn is_empty(&self) -> bool {
-        true
+        self.len == 0
+    }
+
+    /// Returns the number of dependency edges in the graph.
+    #[must_use]
+    pub const fn dependency_count(&self) -> usize {
+        self.edge_len
+    }
+
+    /// Adds one job bound to one dispatch target.
+    ///
+    /// # Errors
+    ///
+    /// Returns `capacity_exhausted` when the graph already holds `NODES` nodes.
+    pub fn add_node(
+        &mut self,
+        target: &'a dyn TaskTarget,
+        job: Job<'a>,
+    ) -> Result<TaskNode, TaskGraphError> {
+        if self.len == NODES {
+            return Err(TaskGraphError::capacity_exhausted());
+        }
+        let node =
+            TaskNode(u32::try_from(self.len).map_err(|_| TaskGraphError::capacity_exhausted())?);
+        self.nodes[self.len] = Some(NodeSpec { target, job });
+        self.len += 1;
+        Ok(node)
+    }
+
+    /// Declares that `after` may only start once `before` has succeeded.
+    ///
+    /// Repeating an existing dependency is accepted and does not consume edge capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `invalid` for unknown nodes or a self-dependency, `cycle` when the edge would
+    /// close a dependency cycle, and `capacity_exhausted` when the graph already holds `EDGES`
+    /// edges.
+    pub fn add_dependency(
+        &mut self,
+        before: TaskNode,
+        after: TaskNode,
+    ) -> Result<(), TaskGraphError> {
+        if before.index() >= self.len || after.index() >= self.len || before == after {
+            return Err(TaskGraphError::invalid());
+        }
+        if self.edges[..self.edge_len].contains(&(before, after)) {
+            return Ok(());
+        }
+        if self.reaches(after, before) {
+            return Err(TaskGraphError::cycle());
+        }
+        if self.edge_len == EDGES {
+            return Err(TaskGraphError::capacity_exhausted());
+        }
+        self.edges[self.edge_len] = (before, after);
+        self.edge_len += 1;
+        Ok(())
+    }
+
+    /// Declares that `node` depends on every node in `dependencies`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`Self::add_dependency`] failure; dependencies added before the failing
+    /// one are kept.
+    pub fn depends_on(
+        &mut self,
+        node: TaskNode,
+        dependencies: &[TaskNode],
+    ) -> Result<(), TaskGraphError> {
+        dependencies
+            .iter()
+            .try_for_each(|dependency| self.add_dependency(*dependency, node))
+    }
+
+    fn reaches(&self, from: TaskNode, to: TaskNode) -> bool {
+        let mut visited = [false; NODES];
+        let mut stack = [TaskNode(0); NODES];
+        let mut depth = 1;
+        stack[0] = from;
+        visited[from.index()] = true;
+        while depth != 0 {
+            depth -= 1;
+            let current = stack[depth];
+            if current == to {
+                return true;
+            }
+            for (before, after) in &self.edges[..self.edge_len] {
+                if *before == current && !visited[after.index()] {
+                    visited[after.index()] = true;
+                    stack[depth] = *after;
+                    depth += 1;
+                }
+            }
+        }
+        false
+    }
+
+    /// Runs the graph to completion, blocking the calling thread.
+    ///
+    /// Nodes without pending dependencies are dispatched to their targets; every completion
+    /// releases the dependents it unblocks. A node that fails, is cancelled, panics, or is
+    /// rejected by its target causes all of its transitive dependents to be skipped. The call
+    /// returns once every dispatched node has completed, so borrowed jobs are never outlived.
+    ///
+    /// Each target must make progress on its own while the caller is blocked; a thread-pool
+    /// node will not run on a carrier whose only worker is parked driving an executor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the run's completion signal cannot be created or waited on; in the
+    /// latter case every dispatched node is still drained before returning. Individual node
+    /// failures are reported through [`TaskGraphReport`].
+    pub fn run(&self) -> Result<TaskGraphReport<NODES>, TaskGraphError> {
+        let clock = system_monotonic_time();
+        let started = clock.now().ok();
+        if self.len == 0 {
+            let mut report = RunBook::<NODES>::new(0).report;
+            report.elapsed = Some(Duration::ZERO);
+            return Ok(report);
+        }
+
+        let max_permits =
+            u32::try_from(self.len).map_err(|_| TaskGraphError::capacity_exhausted())?;
+        let signal = Semaphore::new(0, max_permits)?;
+        let runs: [NodeRun<'_>; NODES] = core::array::from_fn(|index| NodeRun {
+            job: self.nodes[index].map(|spec| spec.job),
+            signal: &signal,
+            record: UnsafeCell::new(NodeRecord::default()),
+            done: AtomicBool::new(false),
+        });
+        // Every dispatched node borrows `runs` and `signal` until its completion is observed; the
+        // guard drains whatever is still in flight before either is freed, including on an early
+        // return or while unwinding out of a target's `dispatch`.
+        let mut guard = RunGuard {
+            book: RunBook::new(self.len),
+            runs: &runs,
+        };
+        let book = &mut guard.book;
+        for (_, after) in &self.edges[..self.edge_len] {
+            book.pending[after.index()] += 1;
+        }
+
+        let mut permits = 0_usize;
+        loop {
+            if self.release_ready(book, &runs) {
+                book.report.batches += 1;
+            }
+            let progressed = self.harvest(book, &runs);
+            if book.in_flight == 0 && !book.state[..self.len].contains(&NodeState::Waiting) {
+                break;
+            }
+            if progressed {
+                continue;
+            }
+            if permits > book.harvested {
+                // A completion has been signalled but its final `done` store is not visible yet.
+                spin_loop();
+                continue;
+            }
+            signal.acquire()?;
+            permits += 1;
+        }
+
+        book.report.elapsed = elapsed_between(started, clock.now().ok());
+        Ok(book.report)
+    }
+
+    /// Dispatches every waiting node whose dependencies have all succeeded.
+    fn release_ready(&self, book: &mut RunBook<NODES>, runs: &[NodeRun<'_>; NODES]) -> bool {
+        let clock = system_monotonic_time();
+        let batch = TaskBatch(book.report.batches);
+        let mut released = false;
+        for (index, run) in runs.iter().enumerate().take(self.len) {
+            if book.state[index] != NodeState::Waiting || book.pending[index] != 0 {
+                continue;
+            }
+            released = true;
+            let Some(spec) = self.nodes[index] else {
+                book.state[index] = NodeState::Finished;
+                continue;
+            };
+            book.batch_of[index] = Some(batch);
+            book.dispatched_at[index] = clock.now().ok();
+            book.state[index] = NodeState::Running;
+            book.in_flight += 1;
+            let dispatch = TaskDispatch {
+                context: core::ptr::from_ref(run).cast_mut().cast(),
+                _not_sync: PhantomData,
+            };
+            if let Err(error) = spec.target.dispatch(dispatch) {
+                // The refused token still completes the node as cancelled; the rejection is the
+                // more truthful outcome to report.
+                book.state[index] = NodeState::Rejected(error.kind());
+            }
+        }
+        released
+    }
+
+    /// Records every newly completed node and unblocks or skips its dependents.
+    fn harvest(&self, book: &mut RunBook<NODES>, runs: &[NodeRun<'_>; NODES]) -> bool {
+        let mut progressed = false;
+        for (index, run) in runs.iter().enumerate().take(self.len) {
+            let rejected = match book.state[index] {
+                NodeState::Running => None,
+                NodeState::Rejected(kind) => Some(kind),
+                NodeState::Waiting | NodeState::Finished => continue,
+            };
+            if !run.done.load(Ordering::Acquire) {
+                continue;
+            }
+            // SAFETY: `done` is the carrier's final write, published with release ordering, so
+            // the record is complete and no longer shared.
+            let record = unsafe { *run.record.get() };
+            let node = node_at(index);
+            let report = rejected.map_or_else(
+                || TaskNodeReport {
+                    node,
+                    outcome: record.outcome,
+                    batch: book.batch_of[index],
+                    queued: elapsed_between(book.dispatched_at[index], record.started),
+                    elapsed: elapsed_between(record.started, record.finished),
+                },
+                |kind| TaskNodeReport {
+                    node,
+                    outcome: TaskNodeOutcome::Rejected(kind),
+                    batch: book.batch_of[index],
+                    queued: None,
+                    elapsed: None,
+                },
+            );
+            book.report.nodes[index] = Some(report);
+            book.state[index] = NodeState::Finished;
+            book.harvested += 1;
+            book.in_flight -= 1;
+            progressed = true;
+            if report.outcome.is_success() {
+                for (before, after) in &self.edges[..self.edge_len] {
+                    if *before == node {
+                        book.pending[after.index()] -= 1;
+                    }
+                }
+            } else {
+                self.skip_dependents(node, book);
+            }
+        }
+        progressed
+    }
+
+    fn skip_dependents(&self, cause: TaskNode, book: &mut RunBook<NODES>) {
+        let mut worklist = [cause; NODES];
+        let mut depth = 1;
+        while depth != 0 {
+            depth -= 1;
+            let current = worklist[depth];
+            for (before, after) in &self.edges[..self.edge_len] {
+                let index = after.index();
+                if *before != current || book.state[index] != NodeState::Waiting {
+                    continue;
+                }
+                book.state[index] = NodeState::Finished;
+                book.report.nodes[index] = Some(TaskNodeReport {
+                    node: *after,
+                    outcome: TaskNodeOutcome::Skipped { cause },
+                    batch: None,
+                    queued: None,
+                    elapsed: None,
+                });
+                worklist[depth] = *after;
+                depth += 1;
+            }
+        }
+    }
+}
+
+impl<const NODES: usize, const EDGES: usize> Default for TaskGraph<'_, NODES, EDGES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const NODES: usize, const EDGES: usize> fmt::Debug for TaskGraph<'_, NODES, EDGES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskGraph")
+            .field("len", &self.len)
+            .field("dependencies", &self.edge_len)
+            .field("slab", &self.slab())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Keeps a run's bookkeeping together with the node state its dispatched tokens point into.
+///
+/// Dropping the guard waits until every node that was handed to a target has completed, so the
+/// coordinator's frame is never released while a carrier can still reach it.
+struct RunGuard<'r, 'a, const NODES: usize> {
+    book: RunBook<NODES>,
+    runs: &'r [NodeRun<'a>; NODES],
+}
+
+impl<const NODES: usize> Drop for RunGuard<'_, '_, NODES> {
+    fn drop(&mut self) {
+        for (state, run) in self.book.state.iter().zip(self.runs) {
+            if matches!(state, NodeState::Running | NodeState::Rejected(_)) {
+                while !run.done.load(Ordering::Acquire) {
+                    spin_loop();
+                }
+            }
+        }
+    }
+}
+
+/// Coordinator-owned bookkeeping for one graph run.
+struct RunBook<const NODES: usize> {
+    state: [NodeState; NODES],
+    pending: [usize; NODES],
+    dispatched_at: [Option<Duration>; NODES],
+    batch_of: [Option<TaskBatch>; NODES],
+    in_flight: usize,
+    harvested: usize,
+    report: TaskGraphReport<NODES>,
+}
+
+impl<const NODES: usize> RunBook<NODES> {
+    const fn new(len: usize) -> Self {
+        Self {
+            state: [NodeState::Waiting; NODES],
+            pending: [0; NODES],
+            dispatched_at: [None; NODES],
+            batch_of: [None; NODES],
+            in_flight: 0,
+            harvested: 0,
+            report: TaskGraphReport {
+                nodes: [None; NODES],
+                len,
+                batches: 0,
+                elapsed: None,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeState {
+    Waiting,
+    Running,
+    Rejected(TaskGraphErrorKind),
+    Finished,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct NodeRecord {
+    outcome: TaskNodeOutcome,
+    started: Option<Duration>,
+    finished: Option<Duration>,
+}
+
+impl Default for NodeRecord {
+    fn default() -> Self {
+        Self {
+            outcome: TaskNodeOutcome::Cancelled,
+            started: None,
+            finished: None,
+        }
     }
 }
+
+/// Per-node run state shared between the coordinator and the carrier running the node.
+struct NodeRun<'a> {
+    job: Option<Job<'a>>,
+    signal: &'a Semaphore,
+    record: UnsafeCell<NodeRecord>,
+    done: AtomicBool,
+}
+
+impl NodeRun<'_> {
+    fn complete(&self, record: NodeRecord) {
+        // SAFETY: only the single owner of this node's dispatch token writes the record, and the
+        // coordinator reads it only after observing `done`.
+        unsafe { self.record.get().write(record) };
+        let _ = self.signal.release(1);
+        // This must stay the final access: once `done` is visible the coordinator may return and
+        // release the run state.
+        self.done.store(true, Ordering::Release);
+    }
+}
+
+unsafe fn run_task_node(context: *mut ()) {
+    // SAFETY: the context was created from a live `NodeRun` that outlives its pending completion.
+    let run = unsafe { &*context.cast::<NodeRun<'_>>() };
+    let clock = system_monotonic_time();
+    let started = clock.now().ok();
+    let outcome = run
+        .job
+        .map_or(TaskNodeOutcome::Cancelled, run_job_contained);
+    let finished = clock.now().ok();
+    run.complete(NodeRecord {
+        outcome,
+        started,
+        finished,
+    });
+}
+
+unsafe fn cancel_task_node(context: *mut ()) {
+    // SAFETY: the context was created from a live `NodeRun` that outlives its pending completion.
+    let run = unsafe { &*context.cast::<NodeRun<'_>>() };
+    run.complete(NodeRecord::default());
+}
+
+fn run_job_contained(job: Job<'_>) -> TaskNodeOutcome {
+    #[cfg(feature = "std")]
+    {
+        use std::panic::{
+            AssertUnwindSafe,
+            catch_unwind,
+        };
+
+        catch_unwind(AssertUnwindSafe(|| job.run())).map_or(TaskNodeOutcome::Panicked, job_outcome)
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        job_outcome(job.run())
+    }
+}
+
+const fn job_outcome(result: JobResult) -> TaskNodeOutcome {
+    match result {
+        Ok(()) => TaskNodeOutcome::Succeeded,
+        Err(JobFailure::Failed(code)) => TaskNodeOutcome::Failed(code),
+        Err(JobFailure::Cancelled) => TaskNodeOutcome::Cancelled,
+    }
+}
+
+fn elapsed_between(start: Option<Duration>, end: Option<Duration>) -> Option<Duration> {
+    end?.checked_sub(start?)
+}
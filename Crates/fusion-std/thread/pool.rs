@@ -7,6 +7,7 @@ use core::mem::{
     size_of,
 };
 use core::ops::Deref;
+use core::time::Duration;
 use core::ptr::{
     self,
     NonNull,
@@ -141,9 +142,9 @@ impl From<CarrierStealPolicy> for StealBoundary {
 pub enum ResizePolicy {
     /// Worker count is fixed after startup.
     Fixed,
-    /// Worker count may be adjusted only through explicit control calls.
+    /// Worker count may be adjusted only through explicit [`ThreadPool::resize`] calls.
     Manual,
-    /// Worker count may be adjusted elastically later.
+    /// Workers are spawned under backlog and retired again after idling past the linger.
     Elastic,
 }
 
@@ -193,6 +194,8 @@ pub struct ThreadPoolConfig<'a> {
     pub spawn_locality_policy: CarrierSpawnLocalityPolicy,
     /// Whether the pool may resize later.
     pub resize_policy: ResizePolicy,
+    /// How long an elastic carrier above `min_threads` may sit idle before it retires.
+    pub idle_linger: Duration,
     /// Shutdown behavior for queued and active work.
     pub shutdown_policy: ShutdownPolicy,
     /// Optional worker-name prefix.
@@ -214,6 +217,7 @@ impl<'a> ThreadPoolConfig<'a> {
             steal_boundary: StealBoundary::LocalOnly,
             spawn_locality_policy: CarrierSpawnLocalityPolicy::SameCore,
             resize_policy: ResizePolicy::Fixed,
+            idle_linger: Duration::from_secs(1),
             shutdown_policy: ShutdownPolicy::Drain,
             name_prefix: None,
             stack: ThreadStackRequest::new(),
@@ -313,6 +317,7 @@ impl<'a> ThreadPoolConfig<'a> {
             steal_boundary: self.steal_boundary.into(),
             spawn_locality_policy: self.spawn_locality_policy,
             resize_policy: self.resize_policy.into(),
+            idle_linger: self.idle_linger,
             shutdown_policy: self.shutdown_policy.into(),
             name_prefix: self.name_prefix,
             stack: self.stack,
@@ -751,6 +756,8 @@ impl ThreadPool {
                 max_threads: 0,
                 active_workers: 0,
                 queued_items: 0,
+                spawned_workers: 0,
+                retired_workers: 0,
             }),
             SystemThreadPool::stats,
         )
@@ -765,6 +772,27 @@ impl ThreadPool {
         Ok(self.stats()?.active_workers)
     }
 
+    /// Grows or shrinks a manually resized carrier pool to exactly `target` workers.
+    ///
+    /// Shrinking returns without waiting for retired carriers, which exit once their current
+    /// item finishes; work running on them may keep submitting into this pool meanwhile.
+    ///
+    /// # Errors
+    ///
+    /// Returns any honest lower-level resize failure, including `unsupported` for pools that
+    /// were not configured with [`ResizePolicy::Manual`].
+    pub fn resize(&self, target: usize) -> Result<(), ThreadPoolError> {
+        let guard = self
+            .shared
+            .inner
+            .lock()
+            .map_err(thread_pool_error_from_sync)?;
+        let Some(inner) = guard.as_ref() else {
+            return Err(fusion_sys::thread::ThreadError::state_conflict());
+        };
+        inner.resize(target)
+    }
+
     /// Submits one raw work item to the carrier pool.
     ///
     /// # Errors
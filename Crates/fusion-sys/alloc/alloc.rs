@@ -20,6 +20,7 @@ use core::ptr::NonNull;
 use fusion_pal::sys::mem::Region;
 
 use crate::sync::{
+    Mutex,
     SharedHeader,
     SharedRelease,
 };
@@ -28,6 +29,7 @@ mod arena;
 mod control;
 mod domain;
 mod error;
+mod global;
 mod heap;
 mod lifetime;
 mod metadata;
@@ -46,6 +48,8 @@ pub use arena::{
 pub use control::ControlLease;
 pub use domain::{
     AllocatorDomainAudit,
+    AllocatorDomainFailure,
+    AllocatorDomainFailureStats,
     AllocatorDomainId,
     AllocatorDomainInfo,
     AllocatorDomainKind,
@@ -55,7 +59,18 @@ pub use error::{
     AllocError,
     AllocErrorKind,
 };
-pub use heap::HeapAllocator;
+pub use global::{
+    DomainAllocHandle,
+    DomainAllocTarget,
+    DomainGlobalAlloc,
+    SlabClass,
+    SlabClassSet,
+};
+pub use heap::{
+    HeapAllocation,
+    HeapAllocator,
+    HeapStats,
+};
 pub use lifetime::{
     Immortal,
     LifetimePolicy,
@@ -173,6 +188,11 @@ pub(crate) enum AllocationBacking {
         offset: usize,
         len: usize,
     },
+    HeapBlock {
+        pool_marker: usize,
+        lease_id: MemoryPoolLeaseId,
+        block: usize,
+    },
 }
 
 /// Successful allocator result together with the resource truth attached to it.
@@ -236,6 +256,8 @@ struct PoolHandleVTable {
         unsafe fn(NonNull<()>, usize) -> Result<Option<MemoryPoolMemberInfo>, AllocError>,
     extent_info_at:
         unsafe fn(NonNull<()>, usize) -> Result<Option<MemoryPoolExtentInfo>, AllocError>,
+    failures: unsafe fn(NonNull<()>) -> Result<AllocatorDomainFailureStats, AllocError>,
+    note_failure: unsafe fn(NonNull<()>, AllocatorDomainFailure),
     retain: unsafe fn(NonNull<()>) -> Result<(), AllocError>,
     release: unsafe fn(NonNull<()>),
 }
@@ -248,6 +270,7 @@ enum PoolControlStorage {
 struct PoolControlBlock<const MEMBERS: usize, const EXTENTS: usize> {
     header: SharedHeader,
     storage: PoolControlStorage,
+    failures: Mutex<AllocatorDomainFailureStats>,
     pool: ManuallyDrop<MemoryPool<MEMBERS, EXTENTS>>,
 }
 
@@ -297,6 +320,10 @@ impl PoolHandle {
             ptr.as_ptr().write(PoolControlBlock {
                 header: SharedHeader::new(),
                 storage,
+                failures: Mutex::new(AllocatorDomainFailureStats {
+                    count: 0,
+                    last: None,
+                }),
                 pool: ManuallyDrop::new(pool),
             });
         }
@@ -311,6 +338,8 @@ impl PoolHandle {
                 member_info: member_info_impl::<MEMBERS, EXTENTS>,
                 member_info_at: member_info_at_impl::<MEMBERS, EXTENTS>,
                 extent_info_at: extent_info_at_impl::<MEMBERS, EXTENTS>,
+                failures: failures_impl::<MEMBERS, EXTENTS>,
+                note_failure: note_failure_impl::<MEMBERS, EXTENTS>,
                 retain: retain_impl::<MEMBERS, EXTENTS>,
                 release: release_impl::<MEMBERS, EXTENTS>,
             },
@@ -368,6 +397,14 @@ impl PoolHandle {
     ) -> Result<Option<MemoryPoolExtentInfo>, AllocError> {
         unsafe { (self.vtable.extent_info_at)(self.ptr, index) }
     }
+
+    pub(crate) fn failures(&self) -> Result<AllocatorDomainFailureStats, AllocError> {
+        unsafe { (self.vtable.failures)(self.ptr) }
+    }
+
+    pub(crate) fn note_failure(&self, failure: AllocatorDomainFailure) {
+        unsafe { (self.vtable.note_failure)(self.ptr, failure) }
+    }
 }
 
 impl Drop for PoolHandle {
@@ -454,6 +491,27 @@ unsafe fn stats_impl<const MEMBERS: usize, const EXTENTS: usize>(
         .map_err(Into::into)
 }
 
+unsafe fn failures_impl<const MEMBERS: usize, const EXTENTS: usize>(
+    ptr: NonNull<()>,
+) -> Result<AllocatorDomainFailureStats, AllocError> {
+    pool_block::<MEMBERS, EXTENTS>(ptr)
+        .failures
+        .lock()
+        .map(|failures| *failures)
+        .map_err(|error| AllocError::synchronization(error.kind))
+}
+
+unsafe fn note_failure_impl<const MEMBERS: usize, const EXTENTS: usize>(
+    ptr: NonNull<()>,
+    failure: AllocatorDomainFailure,
+) {
+    // A ledger that cannot be synchronized must not turn one allocation failure into a second
+    // one, so the report is dropped instead.
+    if let Ok(mut failures) = pool_block::<MEMBERS, EXTENTS>(ptr).failures.lock() {
+        failures.note(failure);
+    }
+}
+
 unsafe fn retain_impl<const MEMBERS: usize, const EXTENTS: usize>(
     ptr: NonNull<()>,
 ) -> Result<(), AllocError> {
@@ -536,6 +594,10 @@ impl AssignedPoolExtent {
     pub(crate) const fn member(&self) -> MemoryPoolMemberInfo {
         self.member
     }
+
+    pub(crate) fn note_failure(&self, failure: AllocatorDomainFailure) {
+        self.pool.note_failure(failure);
+    }
 }
 
 impl Drop for AssignedPoolExtent {
@@ -688,7 +688,42 @@ impl<L: LifetimePolicy> BoundedArena<L> {
         if pool_marker != self.control().pool_marker() || lease_id != self.control().lease_id() {
             return Err(AllocError::invalid_request());
         }
+        self.release_range(offset, len, require_top)
+    }
+
+    /// Allocates one raw block for the allocator adapters, which track layouts themselves.
+    pub(super) fn allocate_ptr(&self, request: &AllocRequest) -> Result<NonNull<u8>, AllocError> {
+        self.allocate_untyped(request)
+            .map(|allocation| allocation.ptr)
+    }
+
+    /// Retires one raw block previously returned by [`Self::allocate_ptr`].
+    ///
+    /// The cursor only rewinds when the block is the most recent one; otherwise the bytes stay
+    /// consumed until the next reset.
+    pub(super) fn release_ptr(&self, ptr: NonNull<u8>, len: usize) -> Result<(), AllocError> {
+        let offset = (ptr.as_ptr() as usize)
+            .checked_sub(self.payload_base()?)
+            .ok_or_else(AllocError::invalid_request)?;
+        if offset
+            .checked_add(len)
+            .is_none_or(|end| end > self.control().header.payload_len)
+        {
+            return Err(AllocError::invalid_request());
+        }
+        self.release_range(offset, len, false)
+    }
+
+    pub(super) fn note_failure(&self, failure: super::AllocatorDomainFailure) {
+        self.control().note_failure(failure);
+    }
 
+    fn release_range(
+        &self,
+        offset: usize,
+        len: usize,
+        require_top: bool,
+    ) -> Result<(), AllocError> {
         let mut state = self
             .control()
             .state
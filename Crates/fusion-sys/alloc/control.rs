@@ -21,6 +21,7 @@ use crate::sync::{
 };
 use super::{
     AllocError,
+    AllocatorDomainFailure,
     AssignedPoolExtent,
     MemoryPoolExtentRequest,
     MemoryPoolLeaseId,
@@ -150,6 +151,10 @@ impl<T> ControlLease<T> {
         self.extent_ref().member()
     }
 
+    pub(crate) fn note_failure(&self, failure: AllocatorDomainFailure) {
+        self.extent_ref().note_failure(failure);
+    }
+
     const fn block(&self) -> &ControlBlock<T> {
         // SAFETY: `ptr` always points at a live control block while a lease exists.
         unsafe { self.ptr.as_ref() }
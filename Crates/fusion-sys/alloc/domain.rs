@@ -6,7 +6,9 @@ use crate::mem::resource::{
     ResourceHazardSet,
 };
 use super::{
+    AllocErrorKind,
     AllocPolicy,
+    HeapStats,
     MemoryPoolStats,
 };
 
@@ -52,6 +54,37 @@ pub struct AllocatorDomainAudit {
     pub primary_layout_policy: Option<AllocatorLayoutPolicy>,
     /// Current pool stats when the domain owns a realized pool.
     pub pool_stats: Option<MemoryPoolStats>,
+    /// Current heap occupancy and fragmentation when the domain heap has been realized.
+    pub heap_stats: Option<HeapStats>,
+    /// Allocation failures reported against the domain by its allocator adapters.
+    pub failures: AllocatorDomainFailureStats,
+}
+
+/// One allocation failure reported against an allocator domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AllocatorDomainFailure {
+    /// Requested allocation length in bytes.
+    pub len: usize,
+    /// Requested alignment in bytes.
+    pub align: usize,
+    /// Why the domain could not satisfy the request.
+    pub kind: AllocErrorKind,
+}
+
+/// Running allocation-failure ledger for one allocator domain.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AllocatorDomainFailureStats {
+    /// Total failures reported since the domain pool was realized.
+    pub count: u64,
+    /// Most recently reported failure.
+    pub last: Option<AllocatorDomainFailure>,
+}
+
+impl AllocatorDomainFailureStats {
+    pub(super) const fn note(&mut self, failure: AllocatorDomainFailure) {
+        self.count = self.count.saturating_add(1);
+        self.last = Some(failure);
+    }
 }
 
 impl AllocatorDomainInfo {
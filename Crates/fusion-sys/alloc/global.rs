@@ -0,0 +1,678 @@
+//! `GlobalAlloc` and per-collection allocator adapters over allocator domains.
+//!
+//! Fusion allocators hand out linear [`AllocResult`](super::AllocResult) tokens, while
+//! `core::alloc` only ever gives back a pointer and a [`Layout`]. The adapters here bridge that
+//! gap for one domain-owned strategy at a time:
+//! - [`DomainAllocTarget`] selects the strategy: the domain heap, a set of slab size classes, or
+//!   one bounded arena
+//! - [`DomainGlobalAlloc`] is const-constructible so it can sit behind `#[global_allocator]`
+//!   and receives its target once the allocator root has been built
+//! - [`DomainAllocHandle`] is a cheap borrowed handle for per-collection use; it implements
+//!   `core::alloc::Allocator` when the `allocator-api` feature is enabled on nightly
+//!
+//! Every request the selected strategy refuses is reported against its domain, so the failure
+//! shows up in [`AllocatorDomainAudit::failures`](super::AllocatorDomainAudit::failures) and is
+//! published on the metadata channel of any [`AllocatorChannelService`](super::AllocatorChannelService)
+//! watching that allocator.
+
+use core::alloc::{
+    GlobalAlloc,
+    Layout,
+};
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ptr::{
+    self,
+    NonNull,
+};
+use core::sync::atomic::{
+    AtomicU8,
+    Ordering,
+};
+
+use super::{
+    AllocError,
+    AllocErrorKind,
+    AllocRequest,
+    AllocatorDomainFailure,
+    AllocatorDomainId,
+    BoundedArena,
+    HeapAllocator,
+    LifetimePolicy,
+    Slab,
+};
+
+const TARGET_EMPTY: u8 = 0;
+const TARGET_INSTALLING: u8 = 1;
+const TARGET_READY: u8 = 2;
+
+#[derive(Clone, Copy)]
+struct SlabClassVTable {
+    allocate: unsafe fn(NonNull<()>, &AllocRequest) -> Result<NonNull<u8>, AllocError>,
+    release: unsafe fn(NonNull<()>, NonNull<u8>) -> Result<(), AllocError>,
+    owns: unsafe fn(NonNull<()>, NonNull<u8>) -> bool,
+    note_failure: unsafe fn(NonNull<()>, AllocatorDomainFailure),
+}
+
+/// One borrowed slab acting as a size class inside a [`SlabClassSet`].
+///
+/// The slab shape is erased so classes of different slot sizes can share one set.
+#[derive(Clone, Copy)]
+pub struct SlabClass<'a> {
+    slab: NonNull<()>,
+    slot_size: usize,
+    slot_align: usize,
+    domain: AllocatorDomainId,
+    vtable: SlabClassVTable,
+    _slab: PhantomData<&'a ()>,
+}
+
+impl fmt::Debug for SlabClass<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlabClass")
+            .field("slot_size", &self.slot_size)
+            .field("slot_align", &self.slot_align)
+            .field("domain", &self.domain)
+            .finish_non_exhaustive()
+    }
+}
+
+// SAFETY: every slab is `Sync` and serializes its slot state internally; the class only forwards
+// shared references to it.
+unsafe impl Send for SlabClass<'_> {}
+// SAFETY: see the `Send` justification above.
+unsafe impl Sync for SlabClass<'_> {}
+
+impl<'a> SlabClass<'a> {
+    /// Borrows `slab` as one size class.
+    #[must_use]
+    pub fn new<const SIZE: usize, const COUNT: usize, L: LifetimePolicy>(
+        slab: &'a Slab<SIZE, COUNT, L>,
+    ) -> Self {
+        Self {
+            slab: NonNull::from(slab).cast::<()>(),
+            slot_size: SIZE,
+            slot_align: slab.slot_align(),
+            domain: slab.domain(),
+            vtable: SlabClassVTable {
+                allocate: slab_allocate_impl::<SIZE, COUNT, L>,
+                release: slab_release_impl::<SIZE, COUNT, L>,
+                owns: slab_owns_impl::<SIZE, COUNT, L>,
+                note_failure: slab_note_failure_impl::<SIZE, COUNT, L>,
+            },
+            _slab: PhantomData,
+        }
+    }
+
+    /// Returns the slot size served by this class.
+    #[must_use]
+    pub const fn slot_size(&self) -> usize {
+        self.slot_size
+    }
+
+    /// Returns the slot alignment guaranteed by this class.
+    #[must_use]
+    pub const fn slot_align(&self) -> usize {
+        self.slot_align
+    }
+
+    /// Returns the allocator domain owning the slab.
+    #[must_use]
+    pub const fn domain(&self) -> AllocatorDomainId {
+        self.domain
+    }
+
+    const fn fits(&self, request: &AllocRequest) -> bool {
+        request.len <= self.slot_size && request.align <= self.slot_align
+    }
+
+    fn allocate(&self, request: &AllocRequest) -> Result<NonNull<u8>, AllocError> {
+        // SAFETY: the pointer and vtable were derived from the same borrowed slab, which outlives
+        // `'a`.
+        unsafe { (self.vtable.allocate)(self.slab, request) }
+    }
+
+    fn release(&self, ptr: NonNull<u8>) -> Result<(), AllocError> {
+        // SAFETY: see `allocate`.
+        unsafe { (self.vtable.release)(self.slab, ptr) }
+    }
+
+    fn owns(&self, ptr: NonNull<u8>) -> bool {
+        // SAFETY: see `allocate`.
+        unsafe { (self.vtable.owns)(self.slab, ptr) }
+    }
+
+    fn note_failure(&self, failure: AllocatorDomainFailure) {
+        // SAFETY: see `allocate`.
+        unsafe { (self.vtable.note_failure)(self.slab, failure) }
+    }
+}
+
+const fn slab_ref<'a, const SIZE: usize, const COUNT: usize, L: LifetimePolicy>(
+    ptr: NonNull<()>,
+) -> &'a Slab<SIZE, COUNT, L> {
+    // SAFETY: the pointer is created from a live slab borrow and the vtable ensures each method
+    // uses the matching concrete instantiation.
+    unsafe { ptr.cast::<Slab<SIZE, COUNT, L>>().as_ref() }
+}
+
+unsafe fn slab_allocate_impl<const SIZE: usize, const COUNT: usize, L: LifetimePolicy>(
+    ptr: NonNull<()>,
+    request: &AllocRequest,
+) -> Result<NonNull<u8>, AllocError> {
+    slab_ref::<SIZE, COUNT, L>(ptr).allocate_ptr(request)
+}
+
+unsafe fn slab_release_impl<const SIZE: usize, const COUNT: usize, L: LifetimePolicy>(
+    ptr: NonNull<()>,
+    block: NonNull<u8>,
+) -> Result<(), AllocError> {
+    slab_ref::<SIZE, COUNT, L>(ptr).release_ptr(block)
+}
+
+unsafe fn slab_owns_impl<const SIZE: usize, const COUNT: usize, L: LifetimePolicy>(
+    ptr: NonNull<()>,
+    block: NonNull<u8>,
+) -> bool {
+    slab_ref::<SIZE, COUNT, L>(ptr).owns_ptr(block)
+}
+
+unsafe fn slab_note_failure_impl<const SIZE: usize, const COUNT: usize, L: LifetimePolicy>(
+    ptr: NonNull<()>,
+    failure: AllocatorDomainFailure,
+) {
+    slab_ref::<SIZE, COUNT, L>(ptr).note_failure(failure);
+}
+
+/// Fixed-capacity set of slab size classes from one allocator domain.
+///
+/// Requests are served by the smallest class whose slot size and alignment fit; when that class
+/// is exhausted the next larger fitting class is tried.
+#[derive(Debug, Clone, Copy)]
+pub struct SlabClassSet<'a, const CLASSES: usize = 8> {
+    classes: [Option<SlabClass<'a>>; CLASSES],
+    len: usize,
+}
+
+impl<const CLASSES: usize> Default for SlabClassSet<'_, CLASSES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, const CLASSES: usize> SlabClassSet<'a, CLASSES> {
+    /// Creates one empty class set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            classes: [None; CLASSES],
+            len: 0,
+        }
+    }
+
+    /// Adds one size class, keeping the set ordered by slot size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the set is full or the class belongs to a different domain than the
+    /// classes already present.
+    pub fn add_class(&mut self, class: SlabClass<'a>) -> Result<&mut Self, AllocError> {
+        if self.len == CLASSES {
+            return Err(AllocError::metadata_exhausted());
+        }
+        if self.domain().is_some_and(|domain| domain != class.domain()) {
+            return Err(AllocError::invalid_domain());
+        }
+        let mut index = self.len;
+        while index > 0
+            && self.classes[index - 1].is_some_and(|existing| existing.slot_size > class.slot_size)
+        {
+            self.classes[index] = self.classes[index - 1];
+            index -= 1;
+        }
+        self.classes[index] = Some(class);
+        self.len += 1;
+        Ok(self)
+    }
+
+    /// Returns the number of classes in the set.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the set holds no classes.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the domain shared by every class, if any class is present.
+    #[must_use]
+    pub fn domain(&self) -> Option<AllocatorDomainId> {
+        self.classes.iter().flatten().next().map(SlabClass::domain)
+    }
+
+    fn allocate(&self, request: &AllocRequest) -> Result<NonNull<u8>, AllocError> {
+        let mut exhausted = false;
+        for class in self
+            .classes
+            .iter()
+            .flatten()
+            .filter(|class| class.fits(request))
+        {
+            match class.allocate(request) {
+                Ok(ptr) => return Ok(ptr),
+                Err(error) if error.kind == AllocErrorKind::CapacityExhausted => exhausted = true,
+                Err(error) => return Err(error),
+            }
+        }
+        if exhausted {
+            Err(AllocError::capacity_exhausted())
+        } else {
+            Err(AllocError::invalid_request())
+        }
+    }
+
+    fn release(&self, ptr: NonNull<u8>) -> Result<(), AllocError> {
+        self.classes
+            .iter()
+            .flatten()
+            .find(|class| class.owns(ptr))
+            .ok_or_else(AllocError::invalid_request)?
+            .release(ptr)
+    }
+
+    fn note_failure(&self, failure: AllocatorDomainFailure) {
+        if let Some(class) = self.classes.iter().flatten().next() {
+            class.note_failure(failure);
+        }
+    }
+}
+
+/// Domain-owned strategy served through the `core::alloc` adapters.
+#[derive(Debug)]
+pub enum DomainAllocTarget<'a, const CLASSES: usize = 8> {
+    /// The general-purpose heap of one domain.
+    Heap(HeapAllocator),
+    /// A set of fixed-size slab classes from one domain.
+    SlabClasses(SlabClassSet<'a, CLASSES>),
+    /// One bounded arena; deallocation only reclaims the most recent block.
+    Arena(BoundedArena),
+}
+
+impl<const CLASSES: usize> DomainAllocTarget<'_, CLASSES> {
+    /// Returns the allocator domain backing this target.
+    #[must_use]
+    pub fn domain(&self) -> Option<AllocatorDomainId> {
+        match self {
+            Self::Heap(heap) => Some(heap.domain()),
+            Self::SlabClasses(classes) => classes.domain(),
+            Self::Arena(arena) => Some(arena.domain()),
+        }
+    }
+
+    /// Returns one borrowed per-collection handle over this target.
+    #[must_use]
+    pub const fn handle(&self) -> DomainAllocHandle<'_, CLASSES> {
+        DomainAllocHandle { target: self }
+    }
+
+    /// Allocates one block satisfying `layout`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `layout` is zero-sized or the target cannot satisfy it. Every
+    /// failure is reported against the target's domain.
+    pub fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        self.allocate_request(&AllocRequest {
+            len: layout.size(),
+            align: layout.align(),
+            zeroed: false,
+        })
+    }
+
+    /// Allocates one zero-initialized block satisfying `layout`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `layout` is zero-sized or the target cannot satisfy it. Every
+    /// failure is reported against the target's domain.
+    pub fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        self.allocate_request(&AllocRequest {
+            len: layout.size(),
+            align: layout.align(),
+            zeroed: true,
+        })
+    }
+
+    /// Releases one block previously returned by this target.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `ptr` does not address one live block of this target.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by this target for `layout` and must not be used again.
+    pub unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) -> Result<(), AllocError> {
+        match self {
+            Self::Heap(heap) => heap.release_ptr(ptr),
+            Self::SlabClasses(classes) => classes.release(ptr),
+            Self::Arena(arena) => arena.release_ptr(ptr, layout.size()),
+        }
+    }
+
+    /// Resizes one block previously returned by this target, preserving its leading bytes.
+    ///
+    /// Heap targets resize in place when the neighbouring block allows; other targets allocate a
+    /// new block, copy, and release the old one. The original block stays live on failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `new_len` is zero or the target cannot satisfy it. Every failure is
+    /// reported against the target's domain.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by this target for `layout`. On success the old pointer must
+    /// not be used again.
+    pub unsafe fn reallocate(
+        &self,
+        ptr: NonNull<u8>,
+        layout: Layout,
+        new_len: usize,
+    ) -> Result<NonNull<u8>, AllocError> {
+        let result = match self {
+            Self::Heap(heap) => heap.reallocate_ptr(ptr, layout.size(), layout.align(), new_len),
+            Self::SlabClasses(_) | Self::Arena(_) => {
+                let new_ptr = self.allocate_request(&AllocRequest {
+                    len: new_len,
+                    align: layout.align(),
+                    zeroed: false,
+                })?;
+                // SAFETY: both blocks are live, distinct, and at least `min(old, new)` bytes
+                // long.
+                unsafe {
+                    ptr::copy_nonoverlapping(
+                        ptr.as_ptr(),
+                        new_ptr.as_ptr(),
+                        layout.size().min(new_len),
+                    );
+                    self.deallocate(ptr, layout)?;
+                }
+                return Ok(new_ptr);
+            }
+        };
+        result.inspect_err(|error| {
+            self.note_failure(new_len, layout.align(), error.kind);
+        })
+    }
+
+    fn allocate_request(&self, request: &AllocRequest) -> Result<NonNull<u8>, AllocError> {
+        let result = if request.len == 0 {
+            Err(AllocError::invalid_request())
+        } else {
+            match self {
+                Self::Heap(heap) => heap.allocate_ptr(request),
+                Self::SlabClasses(classes) => classes.allocate(request),
+                Self::Arena(arena) => arena.allocate_ptr(request),
+            }
+        };
+        result.inspect_err(|error| {
+            self.note_failure(request.len, request.align, error.kind);
+        })
+    }
+
+    fn note_failure(&self, len: usize, align: usize, kind: AllocErrorKind) {
+        let failure = AllocatorDomainFailure { len, align, kind };
+        match self {
+            Self::Heap(heap) => heap.note_failure(failure),
+            Self::SlabClasses(classes) => classes.note_failure(failure),
+            Self::Arena(arena) => arena.note_failure(failure),
+        }
+    }
+}
+
+/// Borrowed per-collection allocator handle over one [`DomainAllocTarget`].
+///
+/// With the `allocator-api` feature this implements `core::alloc::Allocator`, so collections such
+/// as `Vec::new_in` can draw from one governed domain without touching the global allocator.
+#[derive(Debug, Clone, Copy)]
+pub struct DomainAllocHandle<'a, const CLASSES: usize = 8> {
+    target: &'a DomainAllocTarget<'a, CLASSES>,
+}
+
+impl<'a, const CLASSES: usize> DomainAllocHandle<'a, CLASSES> {
+    /// Returns the target this handle allocates from.
+    #[must_use]
+    pub const fn target(&self) -> &'a DomainAllocTarget<'a, CLASSES> {
+        self.target
+    }
+}
+
+#[cfg(feature = "allocator-api")]
+unsafe impl<const CLASSES: usize> core::alloc::Allocator for DomainAllocHandle<'_, CLASSES> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, core::alloc::AllocError> {
+        if layout.size() == 0 {
+            return Ok(NonNull::slice_from_raw_parts(dangling_for(layout), 0));
+        }
+        self.target
+            .allocate(layout)
+            .map(|ptr| NonNull::slice_from_raw_parts(ptr, layout.size()))
+            .map_err(|_| core::alloc::AllocError)
+    }
+
+    fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, core::alloc::AllocError> {
+        if layout.size() == 0 {
+            return Ok(NonNull::slice_from_raw_parts(dangling_for(layout), 0));
+        }
+        self.target
+            .allocate_zeroed(layout)
+            .map(|ptr| NonNull::slice_from_raw_parts(ptr, layout.size()))
+            .map_err(|_| core::alloc::AllocError)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        if layout.size() != 0 {
+            // SAFETY: the caller guarantees `ptr` was returned by this handle for `layout`.
+            let _ = unsafe { self.target.deallocate(ptr, layout) };
+        }
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, core::alloc::AllocError> {
+        // SAFETY: forwarded caller contract.
+        unsafe { self.resize(ptr, old_layout, new_layout) }
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, core::alloc::AllocError> {
+        // SAFETY: forwarded caller contract.
+        unsafe { self.resize(ptr, old_layout, new_layout) }
+    }
+}
+
+#[cfg(feature = "allocator-api")]
+impl<const CLASSES: usize> DomainAllocHandle<'_, CLASSES> {
+    unsafe fn resize(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, core::alloc::AllocError> {
+        use core::alloc::Allocator as _;
+
+        if old_layout.size() != 0
+            && new_layout.size() != 0
+            && old_layout.align() == new_layout.align()
+        {
+            // SAFETY: the caller guarantees `ptr` was returned by this handle for `old_layout`.
+            return unsafe { self.target.reallocate(ptr, old_layout, new_layout.size()) }
+                .map(|ptr| NonNull::slice_from_raw_parts(ptr, new_layout.size()))
+                .map_err(|_| core::alloc::AllocError);
+        }
+        let new_ptr = self.allocate(new_layout)?;
+        // SAFETY: both blocks are live and distinct; the copy covers the shorter of the two.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                ptr.as_ptr(),
+                new_ptr.cast::<u8>().as_ptr(),
+                old_layout.size().min(new_layout.size()),
+            );
+            self.deallocate(ptr, old_layout);
+        }
+        Ok(new_ptr)
+    }
+}
+
+#[cfg(feature = "allocator-api")]
+const fn dangling_for(layout: Layout) -> NonNull<u8> {
+    // SAFETY: `Layout` guarantees a non-zero power-of-two alignment.
+    unsafe { NonNull::new_unchecked(ptr::without_provenance_mut(layout.align())) }
+}
+
+/// `GlobalAlloc` adapter serving one installed [`DomainAllocTarget`].
+///
+/// The adapter is const-constructible so it can be declared as the crate's global allocator
+/// before any allocator root exists:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static GLOBAL: DomainGlobalAlloc = DomainGlobalAlloc::new();
+///
+/// // Early in startup, once the allocator root is built:
+/// GLOBAL.install(DomainAllocTarget::Heap(allocator.heap(domain)?))?;
+/// ```
+///
+/// Allocation requests fail with a null pointer until a target is installed, and the target can
+/// only be installed once. Slab class targets must borrow `'static` slabs, typically immortal
+/// slabs retained for the lifetime of the image.
+pub struct DomainGlobalAlloc<const CLASSES: usize = 8> {
+    state: AtomicU8,
+    target: UnsafeCell<MaybeUninit<DomainAllocTarget<'static, CLASSES>>>,
+}
+
+impl<const CLASSES: usize> fmt::Debug for DomainGlobalAlloc<CLASSES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DomainGlobalAlloc")
+            .field("target", &self.target())
+            .finish()
+    }
+}
+
+impl<const CLASSES: usize> Default for DomainGlobalAlloc<CLASSES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// SAFETY: the target is written exactly once before publication through `state` and only read
+// afterwards; every target strategy serializes its own mutable state.
+unsafe impl<const CLASSES: usize> Sync for DomainGlobalAlloc<CLASSES> {}
+
+impl<const CLASSES: usize> DomainGlobalAlloc<CLASSES> {
+    /// Creates one adapter with no installed target.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(TARGET_EMPTY),
+            target: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Installs the target every later allocation is served from.
+    ///
+    /// # Errors
+    ///
+    /// Returns a busy error when a target is already installed or being installed.
+    pub fn install(&self, target: DomainAllocTarget<'static, CLASSES>) -> Result<(), AllocError> {
+        self.state
+            .compare_exchange(
+                TARGET_EMPTY,
+                TARGET_INSTALLING,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            )
+            .map_err(|_| AllocError::busy())?;
+        // SAFETY: winning the state transition grants exclusive write access to the slot, and no
+        // reader observes it before `TARGET_READY` is published below.
+        unsafe {
+            (*self.target.get()).write(target);
+        }
+        self.state.store(TARGET_READY, Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns whether a target has been installed.
+    #[must_use]
+    pub fn is_installed(&self) -> bool {
+        self.state.load(Ordering::Acquire) == TARGET_READY
+    }
+
+    /// Returns the installed target.
+    #[must_use]
+    pub fn target(&self) -> Option<&DomainAllocTarget<'static, CLASSES>> {
+        if !self.is_installed() {
+            return None;
+        }
+        // SAFETY: `TARGET_READY` is only published after the slot is fully initialized, and the
+        // slot is never written again.
+        Some(unsafe { (*self.target.get()).assume_init_ref() })
+    }
+
+    /// Returns one per-collection handle over the installed target.
+    #[must_use]
+    pub fn handle(&self) -> Option<DomainAllocHandle<'_, CLASSES>> {
+        self.target().map(|target| DomainAllocHandle { target })
+    }
+}
+
+unsafe impl<const CLASSES: usize> GlobalAlloc for DomainGlobalAlloc<CLASSES> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.target()
+            .and_then(|target| target.allocate(layout).ok())
+            .map_or(ptr::null_mut(), NonNull::as_ptr)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.target()
+            .and_then(|target| target.allocate_zeroed(layout).ok())
+            .map_or(ptr::null_mut(), NonNull::as_ptr)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if let (Some(target), Some(ptr)) = (self.target(), NonNull::new(ptr)) {
+            // SAFETY: the caller guarantees `ptr` was returned by this adapter for `layout`.
+            let _ = unsafe { target.deallocate(ptr, layout) };
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let (Some(target), Some(ptr)) = (self.target(), NonNull::new(ptr)) else {
+            return ptr::null_mut();
+        };
+        // SAFETY: the caller guarantees `ptr` was returned by this adapter for `layout`.
+        unsafe { target.reallocate(ptr, layout, new_size) }.map_or(ptr::null_mut(), NonNull::as_ptr)
+    }
+}
+
+impl<const CLASSES: usize> Drop for DomainGlobalAlloc<CLASSES> {
+    fn drop(&mut self) {
+        if *self.state.get_mut() == TARGET_READY {
+            // SAFETY: the slot is initialized once `TARGET_READY` is published and `&mut self`
+            // proves no reader remains.
+            unsafe { self.target.get_mut().assume_init_drop() };
+        }
+    }
+}
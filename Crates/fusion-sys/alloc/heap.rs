@@ -1,5 +1,15 @@
+use core::array;
 use core::fmt;
+use core::mem::size_of;
+use core::ptr::{
+    self,
+    NonNull,
+};
+use core::slice;
 
+use crate::mem::provider::CriticalSafetyRequirements;
+use crate::mem::resource::AllocatorLayoutPolicy;
+use crate::sync::Mutex;
 use super::{
     AllocCapabilities,
     AllocError,
@@ -8,32 +18,340 @@ use super::{
     AllocPolicy,
     AllocRequest,
     AllocResult,
+    AllocSubsystemKind,
+    AllocationBacking,
     AllocationStrategy,
+    AllocatorDomainFailure,
     AllocatorDomainId,
+    AssignedPoolExtent,
+    ControlLease,
+    MemoryPoolExtentRequest,
+    MemoryPoolMemberInfo,
+    MetadataPageHeader,
     PoolHandle,
+    align_up,
+    front_metadata_layout_with_policy,
 };
 
-/// General-purpose allocator surface for non-critical-safe heap behavior.
-pub struct HeapAllocator {
+/// Granule every heap block size and payload address is rounded to.
+const HEAP_GRANULE: usize = 2 * size_of::<usize>();
+const HEAP_GRANULE_LOG2: u32 = HEAP_GRANULE.trailing_zeros();
+/// In-band header bytes preceding every payload: previous-physical link plus tagged size.
+const BLOCK_HEADER_BYTES: usize = HEAP_GRANULE;
+/// Smallest trackable block: one header plus the two free-list links threaded through payload.
+const MIN_BLOCK_BYTES: usize = 2 * HEAP_GRANULE;
+const BLOCK_FREE: usize = 1;
+const BLOCK_FLAG_MASK: usize = HEAP_GRANULE - 1;
+
+const WORD_PREV_PHYS: usize = 0;
+const WORD_SIZE: usize = 1;
+const WORD_NEXT_FREE: usize = 2;
+const WORD_PREV_FREE: usize = 3;
+
+/// Second-level subdivisions per power-of-two size class.
+const SL_LOG2: u32 = 4;
+const SL_COUNT: usize = 1 << SL_LOG2;
+const FL_SHIFT: u32 = SL_LOG2 + HEAP_GRANULE_LOG2;
+const SMALL_BLOCK_BYTES: usize = 1 << FL_SHIFT;
+const FL_INDEX_MAX: u32 = if usize::BITS >= 64 {
+    32
+} else {
+    usize::BITS - 2
+};
+const FL_COUNT: usize = (FL_INDEX_MAX - FL_SHIFT + 1) as usize;
+/// Exclusive upper bound on one block, and therefore on one heap segment.
+const MAX_BLOCK_BYTES: usize = 1 << FL_INDEX_MAX;
+
+/// Live occupancy and fragmentation snapshot of one general-purpose heap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct HeapStats {
+    /// Bytes managed across every heap segment, including in-band block headers.
+    pub capacity: usize,
+    /// Bytes held by live blocks, including their in-band headers.
+    pub used_bytes: usize,
+    /// Bytes held by free blocks.
+    pub free_bytes: usize,
+    /// Highest `used_bytes` observed since the heap was realized.
+    pub peak_used_bytes: usize,
+    /// Number of live allocations.
+    pub live_allocations: usize,
+    /// Number of distinct free blocks.
+    pub free_blocks: usize,
+    /// Size of the largest free block, which bounds the largest request that can still succeed
+    /// without growth.
+    pub largest_free_block: usize,
+    /// Number of pool extents currently backing the heap.
+    pub segments: usize,
+    /// Number of requests the heap could not satisfy.
+    pub failed_allocations: usize,
+}
+
+impl HeapStats {
+    /// Returns external fragmentation in parts per thousand.
+    ///
+    /// This is the share of free bytes that do not belong to the largest free block: `0` means
+    /// every free byte is usable by one request, values near `1000` mean free memory is shattered
+    /// into pieces too small to matter.
+    #[must_use]
+    pub const fn fragmentation_permille(&self) -> u16 {
+        if self.free_bytes == 0 {
+            return 0;
+        }
+        let scattered = self.free_bytes.saturating_sub(self.largest_free_block);
+        let permille = scattered.saturating_mul(1000) / self.free_bytes;
+        if permille >= 1000 {
+            1000
+        } else {
+            #[allow(clippy::cast_possible_truncation)]
+            let permille = permille as u16;
+            permille
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct HeapSegment {
+    base: usize,
+    len: usize,
+    member: MemoryPoolMemberInfo,
+}
+
+impl HeapSegment {
+    const fn contains_block(&self, block: usize) -> bool {
+        block >= self.base && block < self.base + self.len - BLOCK_HEADER_BYTES
+    }
+}
+
+/// Two-level segregated-fit free index.
+///
+/// The first level selects one power-of-two size class and the second level splits that class
+/// linearly into `SL_COUNT` bins. Both levels are summarized by bitmaps, so locating a suitable
+/// bin is two find-first-set operations regardless of heap size or free-block count.
+struct HeapIndex {
+    fl_bitmap: u32,
+    sl_bitmap: [u32; FL_COUNT],
+    heads: [[usize; SL_COUNT]; FL_COUNT],
+}
+
+struct HeapState {
+    index: HeapIndex,
+    segments: [Option<HeapSegment>; HeapAllocator::MAX_SEGMENTS],
+    grown: [Option<AssignedPoolExtent>; HeapAllocator::MAX_SEGMENTS],
+    capacity: usize,
+    used_bytes: usize,
+    free_bytes: usize,
+    peak_used_bytes: usize,
+    live_allocations: usize,
+    free_blocks: usize,
+    failed_allocations: usize,
+}
+
+struct HeapControl {
+    header: MetadataPageHeader,
     domain: AllocatorDomainId,
     policy: AllocPolicy,
+    growth_bytes: Option<usize>,
+    layout_policy: AllocatorLayoutPolicy,
     pool: PoolHandle,
+    state: Mutex<HeapState>,
+}
+
+/// General-purpose bounded heap carved from one allocator domain's pool extents.
+///
+/// The heap is a two-level segregated-fit (TLSF) allocator. Block headers live in-band in front
+/// of every payload, free blocks are indexed by size class, and adjacent free blocks coalesce on
+/// release, so the heap serves arbitrary sizes and alignments instead of the fixed shapes slabs
+/// and arenas require.
+///
+/// # Timing
+///
+/// Allocation, release, and in-place resize are O(1): each performs at most two bitmap
+/// find-first-set scans, one split, and two neighbour coalesces, independent of heap size and
+/// live allocation count. Aligned requests above the heap granule add one extra split. The only
+/// unbounded paths are:
+/// - growth, which acquires a new pool extent and is disabled whenever the domain policy requires
+///   [`CriticalSafetyRequirements::DETERMINISTIC_CAPACITY`]
+/// - relocating `reallocate`, which copies `min(old, new)` bytes
+/// - [`stats`](Self::stats), which walks the largest occupied bin to report the largest free
+///   block
+///
+/// All heap state is serialized by one internal mutex, so contended callers may block.
+pub struct HeapAllocator {
+    control: ControlLease<HeapControl>,
+}
+
+/// Untyped heap allocation tied to the heap that produced it.
+///
+/// Dropping this token returns the block to the heap automatically.
+pub struct HeapAllocation<'a> {
+    heap: &'a HeapAllocator,
+    allocation: Option<AllocResult>,
+}
+
+impl fmt::Debug for HeapAllocation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HeapAllocation")
+            .field("allocation", &self.allocation)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> HeapAllocation<'a> {
+    const fn new(heap: &'a HeapAllocator, allocation: AllocResult) -> Self {
+        Self {
+            heap,
+            allocation: Some(allocation),
+        }
+    }
+
+    /// Returns the allocation length in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        match self.allocation.as_ref() {
+            Some(allocation) => allocation.len,
+            None => 0,
+        }
+    }
+
+    /// Returns whether this allocation holds no bytes.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the alignment satisfied by this allocation.
+    #[must_use]
+    pub const fn align(&self) -> usize {
+        match self.allocation.as_ref() {
+            Some(allocation) => allocation.align,
+            None => 0,
+        }
+    }
+
+    /// Returns whether this allocation is still live.
+    #[must_use]
+    pub const fn is_live(&self) -> bool {
+        self.allocation.is_some()
+    }
+
+    /// Returns the base pointer of the live allocation.
+    #[must_use]
+    pub fn ptr(&self) -> Option<NonNull<u8>> {
+        self.allocation.as_ref().map(|allocation| allocation.ptr)
+    }
+
+    /// Returns the borrowed immutable byte view of the live allocation.
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        let allocation = self.allocation.as_ref()?;
+        // SAFETY: the allocation token keeps its heap block live for the lifetime of this borrow.
+        Some(unsafe { slice::from_raw_parts(allocation.ptr.as_ptr(), allocation.len) })
+    }
+
+    /// Returns the borrowed mutable byte view of the live allocation.
+    #[must_use]
+    pub fn as_bytes_mut(&mut self) -> Option<&mut [u8]> {
+        let allocation = self.allocation.as_ref()?;
+        // SAFETY: this token uniquely owns the heap block lifetime it describes.
+        Some(unsafe { slice::from_raw_parts_mut(allocation.ptr.as_ptr(), allocation.len) })
+    }
+
+    /// Grows or shrinks the live allocation, preserving its leading bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the allocation was already released or the heap cannot satisfy the
+    /// new length. The allocation is left untouched on failure.
+    pub fn resize(&mut self, new_len: usize) -> Result<(), AllocError> {
+        let allocation = self
+            .allocation
+            .as_mut()
+            .ok_or_else(AllocError::invalid_request)?;
+        self.heap.reallocate(allocation, new_len)
+    }
+
+    /// Releases the heap block early.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the allocation was already released or the block can no longer be
+    /// returned honestly.
+    pub fn try_release(&mut self) -> Result<(), AllocError> {
+        let allocation = self
+            .allocation
+            .take()
+            .ok_or_else(AllocError::invalid_request)?;
+        match self.heap.release_allocation(&allocation) {
+            Ok(()) => Ok(()),
+            Err(error) => {
+                self.allocation = Some(allocation);
+                Err(error)
+            }
+        }
+    }
+}
+
+impl Drop for HeapAllocation<'_> {
+    fn drop(&mut self) {
+        let Some(allocation) = self.allocation.take() else {
+            return;
+        };
+        let _ = self.heap.release_allocation(&allocation);
+    }
 }
 
 impl fmt::Debug for HeapAllocator {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("HeapAllocator")
-            .field("domain", &self.domain)
-            .field("policy", &self.policy)
+            .field("capacity", &self.control.header.payload_len)
+            .field("domain", &self.control.domain)
+            .field("policy", &self.control.policy)
+            .field("lease_id", &self.control.lease_id())
+            .field("growth_bytes", &self.control.growth_bytes)
             .finish_non_exhaustive()
     }
 }
 
 impl HeapAllocator {
-    pub(super) fn for_domain(
+    /// Maximum number of pool extents one heap may own, including its primary extent.
+    pub const MAX_SEGMENTS: usize = 8;
+
+    /// Initial payload capacity used when a domain heap is realized without an explicit
+    /// capacity.
+    pub const DEFAULT_CAPACITY: usize = 16 * 1024;
+
+    /// Alignment every heap payload satisfies without extra splitting.
+    pub const GRANULE: usize = HEAP_GRANULE;
+
+    /// Returns the exact pool-extent request needed to host one heap with `capacity` payload
+    /// bytes under one explicit allocator layout policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the requested heap shape cannot be represented honestly.
+    pub fn extent_request_with_layout_policy(
+        capacity: usize,
+        layout_policy: AllocatorLayoutPolicy,
+    ) -> Result<MemoryPoolExtentRequest, AllocError> {
+        let layout = front_metadata_layout_with_policy(
+            ControlLease::<HeapControl>::backing_size(),
+            ControlLease::<HeapControl>::backing_align(),
+            segment_len_for_capacity(capacity)?,
+            HEAP_GRANULE,
+            layout_policy,
+        )?;
+        Ok(MemoryPoolExtentRequest {
+            len: layout.total_len,
+            align: layout.request_align,
+        })
+    }
+
+    pub(super) fn realize(
         domain: AllocatorDomainId,
         policy: AllocPolicy,
         pool: Option<&PoolHandle>,
+        capacity: usize,
+        layout_policy: AllocatorLayoutPolicy,
     ) -> Result<Self, AllocError> {
         if !policy.allows(AllocModeSet::HEAP) {
             return Err(AllocError::policy_denied());
@@ -41,68 +359,815 @@ impl HeapAllocator {
         let pool = pool
             .ok_or_else(AllocError::capacity_exhausted)?
             .try_clone()?;
+        let segment_len = segment_len_for_capacity(capacity)?;
+        let layout = front_metadata_layout_with_policy(
+            ControlLease::<HeapControl>::backing_size(),
+            ControlLease::<HeapControl>::backing_align(),
+            segment_len,
+            HEAP_GRANULE,
+            layout_policy,
+        )?;
+        let extent = AssignedPoolExtent::assign(
+            pool.try_clone()?,
+            &MemoryPoolExtentRequest {
+                len: layout.total_len,
+                align: layout.request_align,
+            },
+        )?;
+        let region = extent.region();
+        let payload_base = region
+            .base
+            .get()
+            .checked_add(layout.payload_offset)
+            .ok_or_else(AllocError::invalid_request)?;
+        if region.len < layout.total_len || !payload_base.is_multiple_of(HEAP_GRANULE) {
+            return Err(AllocError::invalid_request());
+        }
+        let member = extent.member();
+        let growth_bytes = (!policy
+            .safety
+            .contains(CriticalSafetyRequirements::DETERMINISTIC_CAPACITY))
+        .then_some(segment_len);
+
+        let control = ControlLease::new(
+            extent,
+            HeapControl {
+                header: MetadataPageHeader::new(
+                    AllocSubsystemKind::Heap,
+                    layout.metadata_len,
+                    layout.payload_offset,
+                    layout.payload_len,
+                ),
+                domain,
+                policy,
+                growth_bytes,
+                layout_policy,
+                pool,
+                state: Mutex::new(HeapState::new()),
+            },
+        )?;
+        {
+            let mut state = control
+                .state
+                .lock()
+                .map_err(|error| AllocError::synchronization(error.kind))?;
+            // SAFETY: the payload range sits inside the freshly assigned extent, is granule
+            // aligned, and nothing else references it yet.
+            unsafe { state.add_segment(payload_base, segment_len, &member, None)? };
+        }
+        Ok(Self { control })
+    }
+
+    pub(super) fn try_clone(&self) -> Result<Self, AllocError> {
         Ok(Self {
-            domain,
-            policy,
-            pool,
+            control: self.control.try_clone()?,
         })
     }
 
-    /// Returns the capability surface currently available for the heap path.
+    /// Returns the capability surface the heap provides under `policy`.
     ///
-    /// Heap remains intentionally unimplemented on the active allocator model, so this surface
-    /// stays empty until the backing implementation lands.
+    /// Heaps whose policy requires deterministic capacity never grow after realization and are
+    /// therefore also deterministic and bounded.
     #[must_use]
     pub const fn supported_capabilities(policy: AllocPolicy) -> AllocCapabilities {
-        let _ = policy;
-        AllocCapabilities::empty()
+        if !policy.allows(AllocModeSet::HEAP) {
+            return AllocCapabilities::empty();
+        }
+        let capabilities = AllocCapabilities::HEAP
+            .union(AllocCapabilities::ZEROED_ALLOC)
+            .union(AllocCapabilities::REALLOC);
+        if policy
+            .safety
+            .contains(CriticalSafetyRequirements::DETERMINISTIC_CAPACITY)
+        {
+            capabilities
+                .union(AllocCapabilities::DETERMINISTIC)
+                .union(AllocCapabilities::BOUNDED)
+        } else {
+            capabilities
+        }
     }
 
-    /// Returns the expected coarse heap hazards.
-    ///
-    /// Heap remains stubbed, so this surface also stays empty rather than advertising hazards for
-    /// behavior that does not yet exist.
+    /// Returns the expected coarse heap hazards under `policy`.
     #[must_use]
-    pub const fn expected_hazards() -> AllocHazards {
-        AllocHazards::empty()
+    pub const fn expected_hazards(policy: AllocPolicy) -> AllocHazards {
+        if !policy.allows(AllocModeSet::HEAP) {
+            return AllocHazards::empty();
+        }
+        let hazards = AllocHazards::FRAGMENTATION.union(AllocHazards::MAY_BLOCK);
+        if policy
+            .safety
+            .contains(CriticalSafetyRequirements::DETERMINISTIC_CAPACITY)
+        {
+            hazards
+        } else {
+            hazards
+                .union(AllocHazards::EXTERNAL_GROWTH)
+                .union(AllocHazards::VARIABLE_LATENCY)
+        }
     }
 
     /// Returns the heap policy.
     #[must_use]
-    pub const fn policy(&self) -> AllocPolicy {
-        self.policy
+    pub fn policy(&self) -> AllocPolicy {
+        self.control.policy
     }
 
     /// Returns the owning allocator domain.
     #[must_use]
-    pub const fn domain(&self) -> AllocatorDomainId {
-        self.domain
+    pub fn domain(&self) -> AllocatorDomainId {
+        self.control.domain
+    }
+
+    /// Returns whether this heap may acquire additional pool extents when exhausted.
+    #[must_use]
+    pub fn is_growable(&self) -> bool {
+        self.control.growth_bytes.is_some()
+    }
+
+    /// Returns one live occupancy and fragmentation snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the heap state cannot be synchronized honestly.
+    pub fn stats(&self) -> Result<HeapStats, AllocError> {
+        let state = self.lock_state()?;
+        Ok(state.stats())
+    }
+
+    /// Allocates one heap block whose lifetime is tied to this heap reference.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the request is invalid or the heap cannot satisfy it.
+    pub fn alloc(&self, request: &AllocRequest) -> Result<HeapAllocation<'_>, AllocError> {
+        let allocation = self.allocate_untyped(request)?;
+        Ok(HeapAllocation::new(self, allocation))
+    }
+
+    /// Grows or shrinks one live heap allocation, preserving its leading bytes.
+    ///
+    /// The block is resized in place when its physical neighbour allows; otherwise a new block
+    /// with the original alignment is allocated, the contents are copied, and the old block is
+    /// released. `allocation` is updated on success and left untouched on failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `allocation` did not come from this heap, `new_len` is zero, or the
+    /// heap cannot satisfy the new length.
+    pub fn reallocate(
+        &self,
+        allocation: &mut AllocResult,
+        new_len: usize,
+    ) -> Result<(), AllocError> {
+        let block = self.owned_block(allocation)?;
+        let (block, member) = self.reallocate_block(
+            block,
+            allocation.ptr,
+            allocation.len,
+            allocation.align,
+            new_len,
+        )?;
+        *allocation = self.result_for(block, new_len, allocation.align, &member)?;
+        Ok(())
+    }
+
+    /// Allocates one raw block for the allocator adapters, which track layouts themselves.
+    pub(super) fn allocate_ptr(&self, request: &AllocRequest) -> Result<NonNull<u8>, AllocError> {
+        self.allocate_untyped(request)
+            .map(|allocation| allocation.ptr)
+    }
+
+    /// Releases one raw block previously returned by [`Self::allocate_ptr`].
+    pub(super) fn release_ptr(&self, ptr: NonNull<u8>) -> Result<(), AllocError> {
+        let block = block_of(ptr)?;
+        let mut state = self.lock_state()?;
+        // SAFETY: `validate_live` proves the block lies inside one owned segment and carries a
+        // live header before anything is rewritten.
+        unsafe {
+            state.validate_live(block, ptr)?;
+            state.release_block(block);
+        }
+        Ok(())
+    }
+
+    /// Resizes one raw block previously returned by [`Self::allocate_ptr`].
+    pub(super) fn reallocate_ptr(
+        &self,
+        ptr: NonNull<u8>,
+        len: usize,
+        align: usize,
+        new_len: usize,
+    ) -> Result<NonNull<u8>, AllocError> {
+        let (block, _) =
+            self.reallocate_block(block_of(ptr)?, ptr, len, align.max(HEAP_GRANULE), new_len)?;
+        NonNull::new(payload_of(block) as *mut u8).ok_or_else(AllocError::invalid_request)
+    }
+
+    pub(super) fn note_failure(&self, failure: AllocatorDomainFailure) {
+        self.control.note_failure(failure);
+    }
+
+    fn reallocate_block(
+        &self,
+        block: usize,
+        ptr: NonNull<u8>,
+        len: usize,
+        align: usize,
+        new_len: usize,
+    ) -> Result<(usize, MemoryPoolMemberInfo), AllocError> {
+        if new_len == 0 {
+            return Err(AllocError::invalid_request());
+        }
+        let mut state = self.lock_state()?;
+        // SAFETY: `validate_live` checks the block header before anything is rewritten.
+        unsafe {
+            state.validate_live(block, ptr)?;
+        }
+        // SAFETY: the block was validated as live and owned above.
+        let block = if unsafe { state.resize_in_place(block, new_len)? } {
+            block
+        } else {
+            let new_block = self.allocate_block(&mut state, new_len, align)?;
+            // SAFETY: both blocks are live, distinct, and at least `min(old, new)` bytes long.
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    ptr.as_ptr(),
+                    payload_of(new_block) as *mut u8,
+                    len.min(new_len),
+                );
+                state.release_block(block);
+            }
+            new_block
+        };
+        let segment = state
+            .segment_for(block)
+            .ok_or_else(AllocError::invalid_request)?;
+        Ok((block, segment.member))
+    }
+
+    fn lock_state(&self) -> Result<crate::sync::MutexGuard<'_, HeapState>, AllocError> {
+        self.control
+            .state
+            .lock()
+            .map_err(|error| AllocError::synchronization(error.kind))
+    }
+
+    fn owned_block(&self, allocation: &AllocResult) -> Result<usize, AllocError> {
+        match allocation.backing {
+            AllocationBacking::HeapBlock {
+                pool_marker,
+                lease_id,
+                block,
+            } if pool_marker == self.control.pool_marker()
+                && lease_id == self.control.lease_id() =>
+            {
+                Ok(block)
+            }
+            _ => Err(AllocError::invalid_request()),
+        }
+    }
+
+    fn allocate_block(
+        &self,
+        state: &mut HeapState,
+        len: usize,
+        align: usize,
+    ) -> Result<usize, AllocError> {
+        // SAFETY: every segment registered in `state` is owned by this heap.
+        if let Some(block) = unsafe { state.allocate(len, align)? } {
+            return Ok(block);
+        }
+        if let Some(growth_bytes) = self.control.growth_bytes
+            && self.grow(state, len, align, growth_bytes).is_ok()
+        {
+            // SAFETY: the freshly grown segment is owned by this heap like every other one.
+            if let Some(block) = unsafe { state.allocate(len, align)? } {
+                return Ok(block);
+            }
+        }
+        state.failed_allocations = state.failed_allocations.saturating_add(1);
+        Err(AllocError::capacity_exhausted())
+    }
+
+    fn grow(
+        &self,
+        state: &mut HeapState,
+        len: usize,
+        align: usize,
+        growth_bytes: usize,
+    ) -> Result<(), AllocError> {
+        let slot = state
+            .segments
+            .iter()
+            .position(Option::is_none)
+            .ok_or_else(AllocError::capacity_exhausted)?;
+        let needed = block_size_for(len)?
+            .checked_add(align.max(HEAP_GRANULE))
+            .and_then(|bytes| bytes.checked_add(MIN_BLOCK_BYTES + BLOCK_HEADER_BYTES))
+            .ok_or_else(AllocError::invalid_request)?;
+        let segment_len = align_up(
+            growth_bytes.max(needed),
+            self.control.layout_policy.metadata_granule.get(),
+        )?;
+        if segment_len >= MAX_BLOCK_BYTES {
+            return Err(AllocError::invalid_request());
+        }
+        let extent = AssignedPoolExtent::assign(
+            self.control.pool.try_clone()?,
+            &MemoryPoolExtentRequest {
+                len: segment_len,
+                align: HEAP_GRANULE.max(self.control.layout_policy.min_extent_align.get()),
+            },
+        )?;
+        let region = extent.region();
+        let base = region.base.get();
+        let len = region.len.min(MAX_BLOCK_BYTES - HEAP_GRANULE) & !BLOCK_FLAG_MASK;
+        if !base.is_multiple_of(HEAP_GRANULE) {
+            return Err(AllocError::invalid_request());
+        }
+        let member = extent.member();
+        // SAFETY: the assigned extent is uniquely owned by this heap from here on and the state
+        // keeps it alive alongside its segment record.
+        unsafe { state.add_segment(base, len, &member, Some((slot, extent))) }
+    }
+
+    fn result_for(
+        &self,
+        block: usize,
+        len: usize,
+        align: usize,
+        member: &MemoryPoolMemberInfo,
+    ) -> Result<AllocResult, AllocError> {
+        let ptr =
+            NonNull::new(payload_of(block) as *mut u8).ok_or_else(AllocError::invalid_request)?;
+        Ok(AllocResult::from_parts(
+            ptr,
+            len,
+            align,
+            member.compatibility.domain,
+            member.compatibility.attrs,
+            member.compatibility.hazards,
+            member.compatibility.geometry,
+            AllocationBacking::HeapBlock {
+                pool_marker: self.control.pool_marker(),
+                lease_id: self.control.lease_id(),
+                block,
+            },
+        ))
+    }
+
+    fn allocate_untyped(&self, request: &AllocRequest) -> Result<AllocResult, AllocError> {
+        if request.len == 0 || request.align == 0 || !request.align.is_power_of_two() {
+            return Err(AllocError::invalid_request());
+        }
+        let align = request.align.max(HEAP_GRANULE);
+        let mut state = self.lock_state()?;
+        let block = self.allocate_block(&mut state, request.len, align)?;
+        let segment = state
+            .segment_for(block)
+            .ok_or_else(AllocError::invalid_request)?;
+        drop(state);
+        if request.zeroed {
+            // SAFETY: the block was just carved for this request and is exclusively owned here.
+            unsafe {
+                (payload_of(block) as *mut u8).write_bytes(0, request.len);
+            }
+        }
+        self.result_for(block, request.len, align, &segment.member)
+    }
+
+    pub(super) fn release_allocation(&self, allocation: &AllocResult) -> Result<(), AllocError> {
+        let block = self.owned_block(allocation)?;
+        let mut state = self.lock_state()?;
+        // SAFETY: the backing belongs to this heap and the header is validated before release.
+        unsafe {
+            state.validate_live(block, allocation.ptr)?;
+            state.release_block(block);
+        }
+        Ok(())
     }
 }
 
 impl AllocationStrategy for HeapAllocator {
     fn policy(&self) -> AllocPolicy {
-        self.policy
+        self.control.policy
     }
 
     fn capabilities(&self) -> AllocCapabilities {
-        Self::supported_capabilities(self.policy)
+        Self::supported_capabilities(self.control.policy)
     }
 
     fn hazards(&self) -> AllocHazards {
-        Self::expected_hazards()
+        Self::expected_hazards(self.control.policy)
     }
 
     fn allocate(&self, request: &AllocRequest) -> Result<AllocResult, AllocError> {
-        if request.len == 0 || request.align == 0 || !request.align.is_power_of_two() {
+        self.allocate_untyped(request)
+    }
+
+    fn deallocate(&self, allocation: AllocResult) -> Result<(), AllocError> {
+        self.release_allocation(&allocation)
+    }
+}
+
+impl HeapIndex {
+    const fn new() -> Self {
+        Self {
+            fl_bitmap: 0,
+            sl_bitmap: [0; FL_COUNT],
+            heads: [[0; SL_COUNT]; FL_COUNT],
+        }
+    }
+
+    /// Finds the first non-empty bin at or above `(fl, sl)`.
+    fn find_suitable(&self, fl: usize, sl: usize) -> Option<(usize, usize)> {
+        let mut fl = fl;
+        let mut sl_map = self.sl_bitmap[fl] & (u32::MAX << sl);
+        if sl_map == 0 {
+            let fl_map = self.fl_bitmap
+                & u32::try_from(fl + 1)
+                    .ok()
+                    .and_then(|shift| u32::MAX.checked_shl(shift))
+                    .unwrap_or(0);
+            if fl_map == 0 {
+                return None;
+            }
+            fl = fl_map.trailing_zeros() as usize;
+            sl_map = self.sl_bitmap[fl];
+        }
+        Some((fl, sl_map.trailing_zeros() as usize))
+    }
+}
+
+impl HeapState {
+    fn new() -> Self {
+        Self {
+            index: HeapIndex::new(),
+            segments: [None; HeapAllocator::MAX_SEGMENTS],
+            grown: array::from_fn(|_| None),
+            capacity: 0,
+            used_bytes: 0,
+            free_bytes: 0,
+            peak_used_bytes: 0,
+            live_allocations: 0,
+            free_blocks: 0,
+            failed_allocations: 0,
+        }
+    }
+
+    fn stats(&self) -> HeapStats {
+        HeapStats {
+            capacity: self.capacity,
+            used_bytes: self.used_bytes,
+            free_bytes: self.free_bytes,
+            peak_used_bytes: self.peak_used_bytes,
+            live_allocations: self.live_allocations,
+            free_blocks: self.free_blocks,
+            largest_free_block: self.largest_free_block(),
+            segments: self.segments.iter().flatten().count(),
+            failed_allocations: self.failed_allocations,
+        }
+    }
+
+    fn largest_free_block(&self) -> usize {
+        if self.index.fl_bitmap == 0 {
+            return 0;
+        }
+        let fl = (u32::BITS - 1 - self.index.fl_bitmap.leading_zeros()) as usize;
+        let sl = (u32::BITS - 1 - self.index.sl_bitmap[fl].leading_zeros()) as usize;
+        let mut largest = 0;
+        let mut block = self.index.heads[fl][sl];
+        while block != 0 {
+            // SAFETY: indexed blocks are live free-block headers inside owned segments.
+            unsafe {
+                largest = largest.max(block_size(block));
+                block = word(block, WORD_NEXT_FREE);
+            }
+        }
+        largest
+    }
+
+    fn segment_for(&self, block: usize) -> Option<HeapSegment> {
+        self.segments
+            .iter()
+            .flatten()
+            .find(|segment| segment.contains_block(block))
+            .copied()
+    }
+
+    /// Registers one owned byte range as a heap segment: one free block followed by a zero-sized
+    /// in-use sentinel that stops forward coalescing at the segment end.
+    unsafe fn add_segment(
+        &mut self,
+        base: usize,
+        len: usize,
+        member: &MemoryPoolMemberInfo,
+        grown: Option<(usize, AssignedPoolExtent)>,
+    ) -> Result<(), AllocError> {
+        if !(MIN_BLOCK_BYTES + BLOCK_HEADER_BYTES..MAX_BLOCK_BYTES).contains(&len)
+            || !base.is_multiple_of(HEAP_GRANULE)
+            || !len.is_multiple_of(HEAP_GRANULE)
+        {
+            return Err(AllocError::invalid_request());
+        }
+        let slot = match &grown {
+            Some((slot, _)) => *slot,
+            None => self
+                .segments
+                .iter()
+                .position(Option::is_none)
+                .ok_or_else(AllocError::capacity_exhausted)?,
+        };
+        if self.segments[slot].is_some() {
             return Err(AllocError::invalid_request());
         }
-        let _ = self.pool;
-        Err(AllocError::unsupported())
+        let block_len = len - BLOCK_HEADER_BYTES;
+        let sentinel = base + block_len;
+        // SAFETY: the caller guarantees `[base, base + len)` is owned and unused.
+        unsafe {
+            set_word(base, WORD_PREV_PHYS, 0);
+            set_word(base, WORD_SIZE, block_len);
+            set_word(sentinel, WORD_PREV_PHYS, base);
+            set_word(sentinel, WORD_SIZE, 0);
+            self.insert_free(base);
+        }
+        self.segments[slot] = Some(HeapSegment {
+            base,
+            len,
+            member: *member,
+        });
+        if let Some((slot, extent)) = grown {
+            self.grown[slot] = Some(extent);
+        }
+        self.capacity += block_len;
+        Ok(())
     }
 
-    fn deallocate(&self, allocation: AllocResult) -> Result<(), AllocError> {
-        let _ = (&self.pool, allocation);
-        Err(AllocError::unsupported())
+    const unsafe fn insert_free(&mut self, block: usize) {
+        // SAFETY: the caller hands over one owned block header.
+        unsafe {
+            let size = block_size(block);
+            let (fl, sl) = mapping_insert(size);
+            let head = self.index.heads[fl][sl];
+            set_word(block, WORD_SIZE, size | BLOCK_FREE);
+            set_word(block, WORD_NEXT_FREE, head);
+            set_word(block, WORD_PREV_FREE, 0);
+            if head != 0 {
+                set_word(head, WORD_PREV_FREE, block);
+            }
+            self.index.heads[fl][sl] = block;
+            self.index.fl_bitmap |= 1 << fl;
+            self.index.sl_bitmap[fl] |= 1 << sl;
+            self.free_bytes += size;
+            self.free_blocks += 1;
+        }
+    }
+
+    const unsafe fn remove_free(&mut self, block: usize) {
+        // SAFETY: the caller guarantees `block` is currently indexed as free.
+        unsafe {
+            let size = block_size(block);
+            let (fl, sl) = mapping_insert(size);
+            let next = word(block, WORD_NEXT_FREE);
+            let prev = word(block, WORD_PREV_FREE);
+            if next != 0 {
+                set_word(next, WORD_PREV_FREE, prev);
+            }
+            if prev != 0 {
+                set_word(prev, WORD_NEXT_FREE, next);
+            }
+            if self.index.heads[fl][sl] == block {
+                self.index.heads[fl][sl] = next;
+                if next == 0 {
+                    self.index.sl_bitmap[fl] &= !(1 << sl);
+                    if self.index.sl_bitmap[fl] == 0 {
+                        self.index.fl_bitmap &= !(1 << fl);
+                    }
+                }
+            }
+            set_word(block, WORD_SIZE, size);
+            self.free_bytes -= size;
+            self.free_blocks -= 1;
+        }
+    }
+
+    /// Trims `block` down to `size` bytes, returning any viable remainder to the free index.
+    const unsafe fn split(&mut self, block: usize, size: usize) {
+        // SAFETY: the caller owns `block` as an in-use (unindexed) block of at least `size` bytes.
+        unsafe {
+            let current = block_size(block);
+            let remainder = current - size;
+            if remainder < MIN_BLOCK_BYTES {
+                return;
+            }
+            let rest = block + size;
+            set_word(block, WORD_SIZE, size);
+            set_word(rest, WORD_PREV_PHYS, block);
+            set_word(rest, WORD_SIZE, remainder);
+            set_word(rest + remainder, WORD_PREV_PHYS, rest);
+            self.insert_free(rest);
+        }
+    }
+
+    unsafe fn allocate(&mut self, len: usize, align: usize) -> Result<Option<usize>, AllocError> {
+        let size = block_size_for(len)?;
+        let search = if align > HEAP_GRANULE {
+            size.checked_add(align)
+                .and_then(|bytes| bytes.checked_add(MIN_BLOCK_BYTES))
+                .ok_or_else(AllocError::invalid_request)?
+        } else {
+            size
+        };
+        let Some((fl, sl)) = mapping_search(search) else {
+            return Ok(None);
+        };
+        let Some((fl, sl)) = self.index.find_suitable(fl, sl) else {
+            return Ok(None);
+        };
+        let mut block = self.index.heads[fl][sl];
+        // SAFETY: indexed heads are owned free blocks; the search size guarantees room for the
+        // alignment gap and the requested size.
+        unsafe {
+            self.remove_free(block);
+            let payload = payload_of(block);
+            let mut aligned = align_up(payload, align)?;
+            if aligned != payload {
+                if aligned - payload < MIN_BLOCK_BYTES {
+                    aligned = align_up(payload + MIN_BLOCK_BYTES, align)?;
+                }
+                let gap = aligned - payload;
+                let rest = block + gap;
+                set_word(rest, WORD_PREV_PHYS, block);
+                set_word(rest, WORD_SIZE, block_size(block) - gap);
+                set_word(rest + block_size(rest), WORD_PREV_PHYS, rest);
+                set_word(block, WORD_SIZE, gap);
+                self.insert_free(block);
+                block = rest;
+            }
+            self.split(block, size);
+            self.used_bytes += block_size(block);
+        }
+        self.peak_used_bytes = self.peak_used_bytes.max(self.used_bytes);
+        self.live_allocations += 1;
+        Ok(Some(block))
+    }
+
+    unsafe fn validate_live(&self, block: usize, ptr: NonNull<u8>) -> Result<(), AllocError> {
+        if self.segment_for(block).is_none() || payload_of(block) != ptr.as_ptr() as usize {
+            return Err(AllocError::invalid_request());
+        }
+        // SAFETY: `block` lies inside one owned segment.
+        let tagged = unsafe { word(block, WORD_SIZE) };
+        if tagged & BLOCK_FREE != 0 || tagged & !BLOCK_FLAG_MASK < MIN_BLOCK_BYTES {
+            return Err(AllocError::invalid_request());
+        }
+        Ok(())
+    }
+
+    const unsafe fn release_block(&mut self, block: usize) {
+        // SAFETY: the caller validated `block` as one live block inside an owned segment.
+        unsafe {
+            let size = block_size(block);
+            self.used_bytes -= size;
+            self.live_allocations -= 1;
+            let mut block = block;
+            let prev = word(block, WORD_PREV_PHYS);
+            if prev != 0 && is_free(prev) {
+                self.remove_free(prev);
+                absorb_next(prev);
+                block = prev;
+            }
+            let next = block + block_size(block);
+            if is_free(next) {
+                self.remove_free(next);
+                absorb_next(block);
+            }
+            self.insert_free(block);
+        }
+    }
+
+    unsafe fn resize_in_place(&mut self, block: usize, new_len: usize) -> Result<bool, AllocError> {
+        let size = block_size_for(new_len)?;
+        // SAFETY: the caller validated `block` as one live block inside an owned segment.
+        unsafe {
+            let current = block_size(block);
+            if size > current {
+                let next = block + current;
+                if !is_free(next) || current + block_size(next) < size {
+                    return Ok(false);
+                }
+                self.remove_free(next);
+                absorb_next(block);
+            }
+            let before = block_size(block);
+            self.split(block, size);
+            let rest = block + block_size(block);
+            if rest != block + before {
+                // The split remainder was indexed as free; merge it with a free successor so the
+                // no-adjacent-free-blocks invariant holds.
+                let next = rest + block_size(rest);
+                if is_free(next) {
+                    self.remove_free(rest);
+                    self.remove_free(next);
+                    absorb_next(rest);
+                    self.insert_free(rest);
+                }
+            }
+            self.used_bytes = self.used_bytes - current + block_size(block);
+        }
+        self.peak_used_bytes = self.peak_used_bytes.max(self.used_bytes);
+        Ok(true)
     }
 }
+
+fn segment_len_for_capacity(capacity: usize) -> Result<usize, AllocError> {
+    if capacity == 0 {
+        return Err(AllocError::invalid_request());
+    }
+    let len = align_up(capacity, HEAP_GRANULE)?
+        .max(MIN_BLOCK_BYTES)
+        .checked_add(BLOCK_HEADER_BYTES)
+        .ok_or_else(AllocError::invalid_request)?;
+    if len >= MAX_BLOCK_BYTES {
+        return Err(AllocError::invalid_request());
+    }
+    Ok(len)
+}
+
+fn block_size_for(len: usize) -> Result<usize, AllocError> {
+    let size = align_up(len, HEAP_GRANULE)?
+        .checked_add(BLOCK_HEADER_BYTES)
+        .ok_or_else(AllocError::invalid_request)?
+        .max(MIN_BLOCK_BYTES);
+    if size >= MAX_BLOCK_BYTES {
+        return Err(AllocError::invalid_request());
+    }
+    Ok(size)
+}
+
+const fn mapping_insert(size: usize) -> (usize, usize) {
+    if size < SMALL_BLOCK_BYTES {
+        (0, size / HEAP_GRANULE)
+    } else {
+        let log2 = usize::BITS - 1 - size.leading_zeros();
+        let sl = (size >> (log2 - SL_LOG2)) ^ SL_COUNT;
+        ((log2 - FL_SHIFT + 1) as usize, sl)
+    }
+}
+
+/// Rounds `size` up to the next bin boundary so any block in the returned bin satisfies it.
+const fn mapping_search(size: usize) -> Option<(usize, usize)> {
+    let rounded = if size >= SMALL_BLOCK_BYTES {
+        let log2 = usize::BITS - 1 - size.leading_zeros();
+        match size.checked_add((1 << (log2 - SL_LOG2)) - 1) {
+            Some(rounded) => rounded,
+            None => return None,
+        }
+    } else {
+        size
+    };
+    let (fl, sl) = mapping_insert(rounded);
+    if fl >= FL_COUNT { None } else { Some((fl, sl)) }
+}
+
+const fn payload_of(block: usize) -> usize {
+    block + BLOCK_HEADER_BYTES
+}
+
+fn block_of(ptr: NonNull<u8>) -> Result<usize, AllocError> {
+    (ptr.as_ptr() as usize)
+        .checked_sub(BLOCK_HEADER_BYTES)
+        .ok_or_else(AllocError::invalid_request)
+}
+
+/// Merges the in-use/unindexed `block` with its physical successor, which must be unindexed.
+const unsafe fn absorb_next(block: usize) {
+    // SAFETY: the caller guarantees both blocks are owned and already unlinked.
+    unsafe {
+        let next = block + block_size(block);
+        let merged = block_size(block) + block_size(next);
+        set_word(block, WORD_SIZE, merged);
+        set_word(block + merged, WORD_PREV_PHYS, block);
+    }
+}
+
+const unsafe fn word(block: usize, index: usize) -> usize {
+    // SAFETY: the caller guarantees `block` addresses one owned, granule-aligned block header.
+    unsafe { (block as *const usize).add(index).read() }
+}
+
+const unsafe fn set_word(block: usize, index: usize, value: usize) {
+    // SAFETY: the caller guarantees `block` addresses one owned, granule-aligned block header.
+    unsafe { (block as *mut usize).add(index).write(value) }
+}
+
+const unsafe fn block_size(block: usize) -> usize {
+    // SAFETY: forwarded caller contract.
+    unsafe { word(block, WORD_SIZE) & !BLOCK_FLAG_MASK }
+}
+
+const unsafe fn is_free(block: usize) -> bool {
+    // SAFETY: forwarded caller contract.
+    unsafe { word(block, WORD_SIZE) & BLOCK_FREE != 0 }
+}
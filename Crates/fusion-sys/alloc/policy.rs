@@ -108,6 +108,18 @@ impl AllocPolicy {
         }
     }
 
+    /// Returns a critical-safe allocation policy that additionally permits a bounded heap.
+    ///
+    /// The safety requirements stay identical to [`critical_safe`](Self::critical_safe), so a
+    /// heap realized under this policy never grows past its declared capacity.
+    #[must_use]
+    pub const fn critical_safe_heap() -> Self {
+        Self {
+            modes: Self::critical_safe().modes.union(AllocModeSet::HEAP),
+            safety: Self::critical_safe().safety,
+        }
+    }
+
     /// Returns a more permissive general-purpose allocation policy.
     #[must_use]
     pub const fn general_purpose() -> Self {
@@ -1,7 +1,7 @@
 //! Allocator-domain audit protocol vocabulary.
 //!
 //! This first allocator protocol surface is intentionally narrow and honest:
-//! - domain metadata is advertised on one read channel
+//! - domain metadata and allocation-failure events are advertised on one read channel
 //! - audit/stat requests flow in on one write channel
 //! - replies flow out on one read channel
 //!
@@ -26,6 +26,7 @@ use crate::transport::{
 use super::{
     AllocErrorKind,
     AllocatorDomainAudit,
+    AllocatorDomainFailure,
     AllocatorDomainId,
     AllocatorDomainInfo,
     MemoryPoolExtentInfo,
@@ -38,6 +39,11 @@ use super::{
 pub enum AllocatorDomainMetadataMessage {
     Advertised(AllocatorDomainInfo),
     Withdrawn(AllocatorDomainId),
+    AllocationFailed {
+        domain: AllocatorDomainId,
+        failure: AllocatorDomainFailure,
+        total: u64,
+    },
 }
 
 /// Control request sent to one allocator-domain audit service.
@@ -4,6 +4,7 @@ use core::fmt;
 use fusion_pal::sys::mem::MemBaseContract;
 use fusion_pal::sys::mem::system_mem;
 
+use crate::mem::provider::CriticalSafetyRequirements;
 use crate::mem::resource::{
     AllocatorLayoutPolicy,
     MemoryResource,
@@ -13,14 +14,18 @@ use crate::mem::resource::{
     ResourceRequest,
     VirtualMemoryResource,
 };
+use crate::sync::Mutex;
 use super::{
     AllocCapabilities,
     AllocError,
     AllocHazards,
     AllocModeSet,
     AllocPolicy,
+    AllocRequest,
     AllocResult,
+    AllocationStrategy,
     AllocatorDomainAudit,
+    AllocatorDomainFailureStats,
     AllocatorDomainId,
     AllocatorDomainInfo,
     AllocatorDomainKind,
@@ -65,11 +70,20 @@ impl AllocatorResourceRecord {
 struct AllocatorDomainRecord<const RESOURCES: usize, const EXTENTS: usize> {
     info: AllocatorDomainInfo,
     pool: Option<PoolHandle>,
+    heap: Mutex<Option<HeapAllocator>>,
 }
 
 impl<const RESOURCES: usize, const EXTENTS: usize> AllocatorDomainRecord<RESOURCES, EXTENTS> {
-    const fn new(info: AllocatorDomainInfo, pool: Option<PoolHandle>) -> Self {
-        Self { info, pool }
+    const fn new(
+        info: AllocatorDomainInfo,
+        pool: Option<PoolHandle>,
+        heap: Option<HeapAllocator>,
+    ) -> Self {
+        Self {
+            info,
+            pool,
+            heap: Mutex::new(heap),
+        }
     }
 
     fn assign_extent(
@@ -92,7 +106,7 @@ impl<const RESOURCES: usize, const EXTENTS: usize> fmt::Debug
         f.debug_struct("AllocatorDomainRecord")
             .field("info", &self.info)
             .field("pool", &self.pool.as_ref().map(|_| "owned"))
-            .finish()
+            .finish_non_exhaustive()
     }
 }
 
@@ -124,6 +138,7 @@ pub struct AllocatorBuilder<
 > {
     policy: AllocPolicy,
     domains: [Option<AllocatorDomainInfo>; DOMAINS],
+    heap_capacities: [Option<usize>; DOMAINS],
     domain_count: usize,
     resources: [Option<AllocatorResourceBinding>; RESOURCES],
     resource_count: usize,
@@ -336,10 +351,19 @@ impl<const DOMAINS: usize, const RESOURCES: usize, const EXTENTS: usize>
             .find(|binding| binding.domain == id)
             .map(|binding| binding.info.layout);
         let pool_stats = record.pool.as_ref().map(PoolHandle::stats).transpose()?;
+        let heap_stats = record
+            .heap
+            .lock()
+            .map_err(|error| AllocError::synchronization(error.kind))?
+            .as_ref()
+            .map(HeapAllocator::stats)
+            .transpose()?;
         Ok(AllocatorDomainAudit {
             info: record.info,
             primary_layout_policy,
             pool_stats,
+            heap_stats,
+            failures: self.domain_failures(id)?,
         })
     }
 
@@ -389,6 +413,30 @@ impl<const DOMAINS: usize, const RESOURCES: usize, const EXTENTS: usize>
         record.pool.as_ref().map(PoolHandle::stats).transpose()
     }
 
+    /// Returns the allocation-failure ledger for one domain.
+    ///
+    /// Domains without a realized pool never hand out allocations and therefore report an empty
+    /// ledger.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the domain does not exist or its ledger cannot be synchronized
+    /// honestly.
+    pub fn domain_failures(
+        &self,
+        id: AllocatorDomainId,
+    ) -> Result<AllocatorDomainFailureStats, AllocError> {
+        let record = self
+            .domain_record(id)
+            .ok_or_else(AllocError::invalid_domain)?;
+        Ok(record
+            .pool
+            .as_ref()
+            .map(PoolHandle::failures)
+            .transpose()?
+            .unwrap_or_default())
+    }
+
     /// Leases one exact pool extent from the supplied allocator domain.
     ///
     /// # Errors
@@ -676,12 +724,17 @@ impl<const DOMAINS: usize, const RESOURCES: usize, const EXTENTS: usize>
         super::ControlLease::new(extent, value)
     }
 
-    /// Returns a general-purpose heap strategy view for `domain`.
+    /// Returns a shared handle to the general-purpose heap of `domain`.
+    ///
+    /// Every domain owns at most one heap. A heap whose capacity was declared through
+    /// [`AllocatorBuilder::heap_capacity`] is realized at build time; otherwise the first call
+    /// realizes a growable heap of [`HeapAllocator::DEFAULT_CAPACITY`] bytes. Domains whose
+    /// policy requires deterministic capacity never realize heaps lazily.
     ///
     /// # Errors
     ///
-    /// Returns an error when the domain does not exist, heap allocation is denied by policy, or
-    /// the domain owns no realized backing pool.
+    /// Returns an error when the domain does not exist, heap allocation is denied by policy, the
+    /// domain owns no realized backing pool, or a deterministic domain declared no heap capacity.
     pub fn heap(&self, domain: AllocatorDomainId) -> Result<HeapAllocator, AllocError> {
         let domain = self
             .domain_record(domain)
@@ -689,80 +742,90 @@ impl<const DOMAINS: usize, const RESOURCES: usize, const EXTENTS: usize>
         if !domain.info.policy.allows(AllocModeSet::HEAP) {
             return Err(AllocError::policy_denied());
         }
-        HeapAllocator::for_domain(domain.info.id, domain.info.policy, domain.pool.as_ref())
+        if domain.pool.is_none() {
+            return Err(AllocError::capacity_exhausted());
+        }
+        let mut heap = domain
+            .heap
+            .lock()
+            .map_err(|error| AllocError::synchronization(error.kind))?;
+        if let Some(heap) = heap.as_ref() {
+            return heap.try_clone();
+        }
+        if domain
+            .info
+            .policy
+            .safety
+            .contains(CriticalSafetyRequirements::DETERMINISTIC_CAPACITY)
+        {
+            return Err(AllocError::capacity_exhausted());
+        }
+        let realized = HeapAllocator::realize(
+            domain.info.id,
+            domain.info.policy,
+            domain.pool.as_ref(),
+            HeapAllocator::DEFAULT_CAPACITY,
+            self.primary_layout_policy_for_domain(domain.info.id)?,
+        )?;
+        let handle = realized.try_clone()?;
+        *heap = Some(realized);
+        Ok(handle)
     }
 
-    /// Attempts to allocate one heap-routed block from the allocator root.
+    /// Allocates one heap-routed block from the default domain heap.
     ///
     /// # Errors
     ///
     /// Returns an error when no default domain exists, heap allocation is denied by policy, or
-    /// heap allocation remains unsupported on the current implementation path.
+    /// the default heap cannot satisfy the request.
     pub fn malloc(&self, len: usize) -> Result<AllocResult, AllocError> {
         if len == 0 {
             return Err(AllocError::invalid_request());
         }
-        let _ = self.heap(
-            self.default_domain()
-                .ok_or_else(AllocError::invalid_domain)?,
-        )?;
-        Err(AllocError::unsupported())
+        self.default_heap()?.allocate(&AllocRequest::new(len))
     }
 
-    /// Attempts to allocate one zero-initialized heap-routed block from the allocator root.
+    /// Allocates one zero-initialized heap-routed block from the default domain heap.
     ///
     /// # Errors
     ///
     /// Returns an error when no default domain exists, heap allocation is denied by policy, or
-    /// heap allocation remains unsupported on the current implementation path.
+    /// the default heap cannot satisfy the request.
     pub fn calloc(&self, len: usize) -> Result<AllocResult, AllocError> {
         if len == 0 {
             return Err(AllocError::invalid_request());
         }
-        let _ = self.heap(
-            self.default_domain()
-                .ok_or_else(AllocError::invalid_domain)?,
-        )?;
-        Err(AllocError::unsupported())
+        self.default_heap()?.allocate(&AllocRequest::zeroed(len))
     }
 
-    /// Attempts to grow or shrink an existing heap-routed allocation.
+    /// Grows or shrinks an existing heap-routed allocation.
+    ///
+    /// `allocation` is updated on success and left untouched on failure, so the caller still owns
+    /// the original block when the heap cannot satisfy `new_len`.
     ///
     /// # Errors
     ///
     /// Returns an error when no default domain exists, heap allocation is denied by policy, the
-    /// requested new length is invalid, or realloc remains unsupported on the current path.
-    #[allow(clippy::needless_pass_by_value)]
-    pub fn realloc(
-        &self,
-        allocation: AllocResult,
-        new_len: usize,
-    ) -> Result<AllocResult, AllocError> {
-        let _ = allocation;
-        self.heap(
-            self.default_domain()
-                .ok_or_else(AllocError::invalid_domain)?,
-        )?;
-        if new_len == 0 {
-            return Err(AllocError::invalid_request());
-        }
-        Err(AllocError::unsupported())
+    /// allocation did not come from the default heap, or the new length cannot be satisfied.
+    pub fn realloc(&self, allocation: &mut AllocResult, new_len: usize) -> Result<(), AllocError> {
+        self.default_heap()?.reallocate(allocation, new_len)
     }
 
-    /// Attempts to release a heap-routed allocation.
+    /// Releases a heap-routed allocation.
     ///
     /// # Errors
     ///
     /// Returns an error when no default domain exists, heap allocation is denied by policy, or
-    /// deallocation remains unsupported on the current implementation path.
-    #[allow(clippy::needless_pass_by_value)]
+    /// the allocation did not come from the default heap.
     pub fn free(&self, allocation: AllocResult) -> Result<(), AllocError> {
-        let _ = allocation;
-        let _ = self.heap(
+        self.default_heap()?.deallocate(allocation)
+    }
+
+    fn default_heap(&self) -> Result<HeapAllocator, AllocError> {
+        self.heap(
             self.default_domain()
                 .ok_or_else(AllocError::invalid_domain)?,
-        )?;
-        Err(AllocError::unsupported())
+        )
     }
 
     fn domain_record(
@@ -797,6 +860,7 @@ impl<const DOMAINS: usize, const RESOURCES: usize, const EXTENTS: usize>
         Self {
             policy: AllocPolicy::critical_safe(),
             domains: array::from_fn(|_| None),
+            heap_capacities: [None; DOMAINS],
             domain_count: 0,
             resources: array::from_fn(|_| None),
             resource_count: 0,
@@ -841,6 +905,34 @@ impl<const DOMAINS: usize, const RESOURCES: usize, const EXTENTS: usize>
         Ok(id)
     }
 
+    /// Declares the heap capacity of one domain so its heap is realized eagerly at build time.
+    ///
+    /// Reserving the heap during bring-up keeps its backing out of later contention for pool
+    /// capacity. Domains whose policy requires deterministic capacity must declare their heap
+    /// here because they never realize one lazily.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the domain does not exist, denies heap allocation, or `capacity` is
+    /// zero.
+    pub fn heap_capacity(
+        &mut self,
+        domain: AllocatorDomainId,
+        capacity: usize,
+    ) -> Result<&mut Self, AllocError> {
+        if capacity == 0 {
+            return Err(AllocError::invalid_request());
+        }
+        let slot = self
+            .find_domain_slot(domain)
+            .ok_or_else(AllocError::invalid_domain)?;
+        if !self.domains[slot].is_some_and(|info| info.policy.allows(AllocModeSet::HEAP)) {
+            return Err(AllocError::policy_denied());
+        }
+        self.heap_capacities[slot] = Some(capacity);
+        Ok(self)
+    }
+
     /// Adds one resource to the implicit default domain, creating it if needed.
     ///
     /// # Errors
@@ -948,7 +1040,25 @@ impl<const DOMAINS: usize, const RESOURCES: usize, const EXTENTS: usize>
                     control_region,
                 )?)
             };
-            domain_records[slot] = Some(AllocatorDomainRecord::new(info, pool));
+            let heap = match self.heap_capacities[slot] {
+                Some(capacity) => {
+                    let layout_policy = resource_records
+                        .iter()
+                        .flatten()
+                        .find(|record| record.domain == info.id)
+                        .map(|record| record.info.layout)
+                        .ok_or_else(AllocError::capacity_exhausted)?;
+                    Some(HeapAllocator::realize(
+                        info.id,
+                        info.policy,
+                        pool.as_ref(),
+                        capacity,
+                        layout_policy,
+                    )?)
+                }
+                None => None,
+            };
+            domain_records[slot] = Some(AllocatorDomainRecord::new(info, pool, heap));
         }
 
         Ok(Allocator {
@@ -1067,6 +1177,7 @@ fn allocator_capabilities_for_domains<
     domains: &[Option<AllocatorDomainRecord<RESOURCES, EXTENTS>>; DOMAINS],
 ) -> AllocCapabilities {
     let mut capabilities = AllocCapabilities::empty();
+    let mut growable = false;
     for domain in domains.iter().flatten() {
         if domain.pool.is_none() {
             continue;
@@ -1079,8 +1190,17 @@ fn allocator_capabilities_for_domains<
         if domain.info.policy.allows(AllocModeSet::ARENA) {
             capabilities = capabilities.union(AllocCapabilities::ARENA);
         }
+        if domain.info.policy.allows(AllocModeSet::HEAP) {
+            capabilities = capabilities.union(
+                HeapAllocator::supported_capabilities(domain.info.policy)
+                    .difference(AllocCapabilities::DETERMINISTIC)
+                    .difference(AllocCapabilities::BOUNDED),
+            );
+            growable |= HeapAllocator::expected_hazards(domain.info.policy)
+                .contains(AllocHazards::EXTERNAL_GROWTH);
+        }
     }
-    if !capabilities.is_empty() && !capabilities.contains(AllocCapabilities::HEAP) {
+    if !capabilities.is_empty() && !growable {
         capabilities = capabilities
             .union(AllocCapabilities::DETERMINISTIC)
             .union(AllocCapabilities::BOUNDED);
@@ -1093,7 +1213,13 @@ fn allocator_hazards_for_domains<
     const RESOURCES: usize,
     const EXTENTS: usize,
 >(
-    _domains: &[Option<AllocatorDomainRecord<RESOURCES, EXTENTS>>; DOMAINS],
+    domains: &[Option<AllocatorDomainRecord<RESOURCES, EXTENTS>>; DOMAINS],
 ) -> AllocHazards {
-    AllocHazards::empty()
+    let mut hazards = AllocHazards::empty();
+    for domain in domains.iter().flatten() {
+        if domain.pool.is_some() {
+            hazards = hazards.union(HeapAllocator::expected_hazards(domain.info.policy));
+        }
+    }
+    hazards
 }
@@ -110,6 +110,7 @@ pub struct AllocatorChannelService<
     domain_ids: [AllocatorDomainId; DOMAINS],
     domain_count: usize,
     next_metadata: usize,
+    reported_failures: [u64; DOMAINS],
     pending_status: Option<AllocatorControlStatusMessage>,
     pending_stream: Option<PendingStatusStream>,
     metadata_channel: LocalChannel<AllocatorDomainMetadataProtocol, METADATA_CAPACITY>,
@@ -168,6 +169,7 @@ impl<
             domain_ids,
             domain_count,
             next_metadata: 0,
+            reported_failures: [0; DOMAINS],
             pending_status: None,
             pending_stream: None,
             metadata_channel,
@@ -215,7 +217,7 @@ impl<
         Fiber::spawn_managed(stack, state)
     }
 
-    /// Pumps pending metadata and control requests once.
+    /// Pumps pending metadata, failure events, and control requests once.
     ///
     /// # Errors
     ///
@@ -245,6 +247,47 @@ impl<
     }
 
     fn flush_metadata(&mut self) -> Result<(), AllocatorChannelServiceError> {
+        self.flush_advertisements()?;
+        if self.next_metadata < self.domain_count {
+            return Ok(());
+        }
+        self.flush_failures()
+    }
+
+    fn flush_failures(&mut self) -> Result<(), AllocatorChannelServiceError> {
+        for index in 0..self.domain_count {
+            let domain = self.domain_ids[index];
+            let failures = self.allocator.domain_failures(domain)?;
+            let Some(failure) = failures.last else {
+                continue;
+            };
+            if failures.count == self.reported_failures[index] {
+                continue;
+            }
+            match self.metadata_channel.try_send(
+                self.metadata_producer,
+                AllocatorDomainMetadataMessage::AllocationFailed {
+                    domain,
+                    failure,
+                    total: failures.count,
+                },
+            ) {
+                Ok(()) => self.reported_failures[index] = failures.count,
+                Err(error)
+                    if matches!(
+                        error.kind(),
+                        ChannelErrorKind::Busy | ChannelErrorKind::ResourceExhausted
+                    ) =>
+                {
+                    return Ok(());
+                }
+                Err(error) => return Err(error.into()),
+            }
+        }
+        Ok(())
+    }
+
+    fn flush_advertisements(&mut self) -> Result<(), AllocatorChannelServiceError> {
         while self.next_metadata < self.domain_count {
             let domain = self.domain_ids[self.next_metadata];
             let info = self
@@ -444,6 +444,42 @@ impl<const SIZE: usize, const COUNT: usize, L: LifetimePolicy> Slab<SIZE, COUNT,
         ))
     }
 
+    /// Returns whether `ptr` addresses one slot of this slab.
+    pub(super) fn owns_ptr(&self, ptr: NonNull<u8>) -> bool {
+        self.slot_of(ptr).is_ok()
+    }
+
+    /// Allocates one raw slot for the allocator adapters, which track layouts themselves.
+    pub(super) fn allocate_ptr(&self, request: &AllocRequest) -> Result<NonNull<u8>, AllocError> {
+        self.allocate_untyped(request)
+            .map(|allocation| allocation.ptr)
+    }
+
+    /// Releases one raw slot previously returned by [`Self::allocate_ptr`].
+    pub(super) fn release_ptr(&self, ptr: NonNull<u8>) -> Result<(), AllocError> {
+        let slot = self.slot_of(ptr)?;
+        let mut state = self
+            .metadata()
+            .state
+            .lock()
+            .map_err(|error| AllocError::synchronization(error.kind))?;
+        state.release_slot(slot)
+    }
+
+    pub(super) fn note_failure(&self, failure: super::AllocatorDomainFailure) {
+        self.extent().note_failure(failure);
+    }
+
+    fn slot_of(&self, ptr: NonNull<u8>) -> Result<usize, AllocError> {
+        let offset = (ptr.as_ptr() as usize)
+            .checked_sub(self.payload_base()?)
+            .ok_or_else(AllocError::invalid_request)?;
+        if !offset.is_multiple_of(SIZE) || offset / SIZE >= COUNT {
+            return Err(AllocError::invalid_request());
+        }
+        Ok(offset / SIZE)
+    }
+
     fn release_allocation(&self, allocation: &AllocResult) -> Result<(), AllocError> {
         match allocation.backing {
             AllocationBacking::SlabSlot {
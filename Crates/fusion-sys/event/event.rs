@@ -10,8 +10,10 @@ pub use fusion_pal::sys::event::{
     EventBaseContract,
     EventCaps,
     EventCompletion,
+    EventCompletionBuffer,
     EventCompletionOp,
     EventCompletionOpKind,
+    EventCompletionTarget,
     EventError,
     EventErrorKind,
     EventImplementationKind,
@@ -31,6 +33,7 @@ use fusion_pal::sys::event::{
     PlatformEvent,
     PlatformPoller,
     system_event as pal_system_event,
+    system_readiness_event as pal_system_readiness_event,
 };
 
 /// fusion-sys event provider wrapper around the selected fusion-pal backend.
@@ -54,6 +57,17 @@ impl EventSystem {
         }
     }
 
+    /// Creates a wrapper for the selected provider without completion submission.
+    ///
+    /// Pollers created through it only deliver readiness, even where the platform default
+    /// also accepts completion-style submissions.
+    #[must_use]
+    pub const fn readiness() -> Self {
+        Self {
+            inner: pal_system_readiness_event(),
+        }
+    }
+
     /// Reports the truthful event surface for the selected backend.
     #[must_use]
     pub fn support(&self) -> EventSupport {
@@ -5,6 +5,7 @@
 //! operating system.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "allocator-api", feature(allocator_api))]
 
 /// Target platform discriminator re-exported from `fusion-pal`.
 pub use fusion_pal::{
@@ -1,4 +1,5 @@
 #![cfg(all(feature = "std", not(target_os = "none")))]
+#![cfg_attr(feature = "allocator-api", feature(allocator_api))]
 
 #[path = "fusion_sys/fusion_sys.rs"]
 mod fusion_sys;
@@ -7,6 +7,8 @@ mod all;
 mod allocator_channel;
 mod allocator_root;
 mod arena;
+mod global;
+mod heap;
 mod retained;
 mod slab;
 mod support;
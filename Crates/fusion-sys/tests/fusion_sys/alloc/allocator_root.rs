@@ -1,6 +1,7 @@
 use fusion_sys::alloc::{
     AllocCapabilities,
     AllocErrorKind,
+    AllocHazards,
     AllocModeSet,
     AllocPolicy,
     AllocationStrategy,
@@ -156,24 +157,25 @@ fn system_default_with_capacity_sizes_backing_for_requested_slabs() {
 }
 
 #[test]
-fn heap_routing_is_policy_gated_and_still_unimplemented() {
-    let allocator = Allocator::<2, 2>::system_default().expect("allocator should build");
+fn heap_routing_is_policy_gated() {
+    let allocator =
+        Allocator::<2, 2>::system_default_with_capacity(64 * 1024).expect("allocator should build");
     let default_domain = allocator
         .default_domain()
         .expect("default domain should exist");
     let heap = allocator
         .heap(default_domain)
-        .expect("general-purpose allocator should still surface a heap wrapper");
-    assert!(heap.capabilities().is_empty());
-    assert!(heap.hazards().is_empty());
+        .expect("general-purpose allocator should surface a heap");
+    assert!(heap.capabilities().contains(AllocCapabilities::HEAP));
+    assert!(heap.capabilities().contains(AllocCapabilities::REALLOC));
+    assert!(heap.hazards().contains(AllocHazards::FRAGMENTATION));
 
-    assert_eq!(
-        allocator
-            .malloc(4096)
-            .expect_err("heap remains intentionally unimplemented")
-            .kind,
-        AllocErrorKind::Unsupported
-    );
+    let allocation = allocator
+        .malloc(4096)
+        .expect("general-purpose allocator should route malloc through its heap");
+    allocator
+        .free(allocation)
+        .expect("heap-routed allocation should release");
 
     let critical = Allocator::<2, 2>::builder()
         .build()
@@ -205,6 +207,8 @@ fn allocator_can_write_domain_ids_and_audits() {
             .expect("domain info should exist"),
         primary_layout_policy: None,
         pool_stats: None,
+        heap_stats: None,
+        failures: fusion_sys::alloc::AllocatorDomainFailureStats::default(),
     }; 4];
     let written = allocator
         .write_domain_audits(&mut audits)
@@ -0,0 +1,323 @@
+use core::alloc::{
+    GlobalAlloc,
+    Layout,
+};
+
+use fusion_sys::alloc::{
+    AllocErrorKind,
+    Allocator,
+    AllocatorChannelService,
+    AllocatorDomainMetadataMessage,
+    DomainAllocTarget,
+    DomainGlobalAlloc,
+    SlabClass,
+    SlabClassSet,
+};
+use fusion_sys::channel::ChannelReceiveContract;
+use fusion_sys::transport::{
+    TransportAttachmentControlContract,
+    TransportAttachmentRequest,
+};
+
+fn layout(size: usize, align: usize) -> Layout {
+    Layout::from_size_align(size, align).expect("test layout should be valid")
+}
+
+#[test]
+fn global_adapter_serves_heap_target_after_install() {
+    let allocator = Allocator::<2, 2>::system_default_with_capacity(256 * 1024)
+        .expect("allocator should build");
+    let domain = allocator
+        .default_domain()
+        .expect("default domain should exist");
+    let global = DomainGlobalAlloc::<4>::new();
+
+    // SAFETY: the layout is non-zero sized.
+    assert!(unsafe { global.alloc(layout(64, 8)) }.is_null());
+    assert!(global.target().is_none());
+
+    global
+        .install(DomainAllocTarget::Heap(
+            allocator
+                .heap(domain)
+                .expect("general-purpose domain should realize a heap"),
+        ))
+        .expect("first install should succeed");
+    assert!(global.is_installed());
+    assert_eq!(
+        global
+            .target()
+            .expect("target should be installed")
+            .domain(),
+        Some(domain)
+    );
+    let again = allocator.heap(domain).expect("heap should still be shared");
+    assert_eq!(
+        global
+            .install(DomainAllocTarget::Heap(again))
+            .expect_err("second install should be refused")
+            .kind,
+        AllocErrorKind::Busy
+    );
+
+    let small = layout(48, 16);
+    // SAFETY: the layout is non-zero sized and every pointer is released with its own layout.
+    unsafe {
+        let ptr = global.alloc_zeroed(small);
+        assert!(!ptr.is_null());
+        assert_eq!(ptr as usize % 16, 0);
+        assert!(
+            core::slice::from_raw_parts(ptr, 48)
+                .iter()
+                .all(|byte| *byte == 0)
+        );
+        ptr.write_bytes(0x5a, 48);
+
+        let grown = global.realloc(ptr, small, 4096);
+        assert!(!grown.is_null());
+        assert!(
+            core::slice::from_raw_parts(grown, 48)
+                .iter()
+                .all(|byte| *byte == 0x5a)
+        );
+        global.dealloc(grown, layout(4096, 16));
+    }
+
+    let stats = allocator
+        .heap(domain)
+        .expect("heap should still be shared")
+        .stats()
+        .expect("heap stats should be available");
+    assert_eq!(stats.live_allocations, 0);
+}
+
+#[test]
+fn slab_class_set_routes_to_smallest_fitting_class_and_falls_back() {
+    let allocator = Allocator::<2, 2>::system_default_with_capacity(256 * 1024)
+        .expect("allocator should build");
+    let domain = allocator
+        .default_domain()
+        .expect("default domain should exist");
+    let small = allocator
+        .slab::<32, 2>(domain)
+        .expect("small slab should reserve backing");
+    let large = allocator
+        .slab::<128, 2>(domain)
+        .expect("large slab should reserve backing");
+
+    let mut classes = SlabClassSet::<2>::new();
+    classes
+        .add_class(SlabClass::new(&large))
+        .expect("large class should fit")
+        .add_class(SlabClass::new(&small))
+        .expect("small class should fit");
+    assert_eq!(classes.len(), 2);
+    assert_eq!(
+        classes
+            .add_class(SlabClass::new(&small))
+            .expect_err("full class set should refuse more classes")
+            .kind,
+        AllocErrorKind::MetadataExhausted
+    );
+
+    let target = DomainAllocTarget::SlabClasses(classes);
+    let a = target
+        .allocate(layout(16, 8))
+        .expect("small class should serve");
+    let b = target
+        .allocate(layout(24, 8))
+        .expect("small class should serve");
+    let c = target
+        .allocate(layout(20, 8))
+        .expect("exhausted small class should fall back to the large class");
+    let d = target
+        .allocate(layout(100, 8))
+        .expect("large class should serve its second slot");
+    assert_eq!(
+        target
+            .allocate(layout(100, 8))
+            .expect_err("both classes should now be exhausted")
+            .kind,
+        AllocErrorKind::CapacityExhausted
+    );
+
+    assert_eq!(
+        target
+            .allocate(layout(512, 8))
+            .expect_err("oversized requests should not fit any class")
+            .kind,
+        AllocErrorKind::InvalidRequest
+    );
+
+    // SAFETY: every pointer was returned by `target` for the matching layout.
+    unsafe {
+        target
+            .deallocate(a, layout(16, 8))
+            .expect("small block should release");
+        target
+            .deallocate(b, layout(24, 8))
+            .expect("small block should release");
+        target
+            .deallocate(c, layout(20, 8))
+            .expect("large block should release");
+        target
+            .deallocate(d, layout(100, 8))
+            .expect("large block should release");
+    }
+
+    let failures = allocator
+        .domain_failures(domain)
+        .expect("failure ledger should be readable");
+    assert_eq!(failures.count, 2);
+    let last = failures.last.expect("last failure should be recorded");
+    assert_eq!(last.len, 512);
+    assert_eq!(last.kind, AllocErrorKind::InvalidRequest);
+}
+
+#[test]
+fn arena_target_rewinds_top_block_and_reports_exhaustion() {
+    let allocator = Allocator::<2, 2>::system_default_with_capacity(256 * 1024)
+        .expect("allocator should build");
+    let domain = allocator
+        .default_domain()
+        .expect("default domain should exist");
+    let target = DomainAllocTarget::<4>::Arena(
+        allocator
+            .arena(domain, 256)
+            .expect("arena should reserve backing"),
+    );
+
+    let first = target.allocate(layout(64, 8)).expect("arena should serve");
+    let second = target.allocate(layout(64, 8)).expect("arena should serve");
+    // SAFETY: `second` was returned by `target` for this layout.
+    unsafe {
+        target
+            .deallocate(second, layout(64, 8))
+            .expect("top block should release");
+    }
+    let reused = target
+        .allocate(layout(64, 8))
+        .expect("rewound arena should serve again");
+    assert_eq!(reused, second);
+
+    assert_eq!(
+        target
+            .allocate(layout(1024, 8))
+            .expect_err("oversized request should exhaust the arena")
+            .kind,
+        AllocErrorKind::CapacityExhausted
+    );
+    let audit = allocator
+        .domain_audit(domain)
+        .expect("domain audit should succeed");
+    assert_eq!(audit.failures.count, 1);
+    assert_eq!(
+        audit
+            .failures
+            .last
+            .expect("last failure should be recorded")
+            .kind,
+        AllocErrorKind::CapacityExhausted
+    );
+
+    // SAFETY: both pointers were returned by `target` for these layouts.
+    unsafe {
+        target
+            .deallocate(reused, layout(64, 8))
+            .expect("top block should release");
+        target
+            .deallocate(first, layout(64, 8))
+            .expect("first block should release");
+    }
+}
+
+#[test]
+fn allocator_channel_service_publishes_adapter_failures() {
+    let allocator = Allocator::<2, 2>::system_default_with_capacity(256 * 1024)
+        .expect("allocator should build");
+    let domain = allocator
+        .default_domain()
+        .expect("default domain should exist");
+    let mut service: AllocatorChannelService<'_, 2, 2, 64, 4, 4, 4> =
+        AllocatorChannelService::new(&allocator).expect("allocator channel service should build");
+    let metadata_consumer = service
+        .metadata_channel()
+        .attach_consumer(TransportAttachmentRequest::same_courier())
+        .expect("metadata consumer should attach");
+
+    service.pump().expect("service should publish metadata");
+    while service
+        .metadata_channel()
+        .try_receive(metadata_consumer)
+        .expect("metadata receive should succeed")
+        .is_some()
+    {}
+
+    let target = DomainAllocTarget::<4>::Arena(
+        allocator
+            .arena(domain, 128)
+            .expect("arena should reserve backing"),
+    );
+    let _ = target.allocate(layout(4096, 8));
+
+    service.pump().expect("service should publish failures");
+    match service
+        .metadata_channel()
+        .try_receive(metadata_consumer)
+        .expect("metadata receive should succeed")
+        .expect("failure event should exist")
+    {
+        AllocatorDomainMetadataMessage::AllocationFailed {
+            domain: failed,
+            failure,
+            total,
+        } => {
+            assert_eq!(failed, domain);
+            assert_eq!(failure.len, 4096);
+            assert_eq!(failure.kind, AllocErrorKind::CapacityExhausted);
+            assert_eq!(total, 1);
+        }
+        other => panic!("unexpected metadata message: {other:?}"),
+    }
+
+    service.pump().expect("service should stay quiet");
+    assert!(
+        service
+            .metadata_channel()
+            .try_receive(metadata_consumer)
+            .expect("metadata receive should succeed")
+            .is_none()
+    );
+}
+
+#[cfg(feature = "allocator-api")]
+#[test]
+fn domain_alloc_handle_backs_collections() {
+    use std::vec::Vec;
+
+    let allocator = Allocator::<2, 2>::system_default_with_capacity(256 * 1024)
+        .expect("allocator should build");
+    let domain = allocator
+        .default_domain()
+        .expect("default domain should exist");
+    let target = DomainAllocTarget::<4>::Heap(
+        allocator
+            .heap(domain)
+            .expect("general-purpose domain should realize a heap"),
+    );
+
+    let mut values = Vec::new_in(target.handle());
+    values.extend(0u32..1024);
+    assert_eq!(values.iter().sum::<u32>(), 1023 * 1024 / 2);
+    drop(values);
+
+    let DomainAllocTarget::Heap(heap) = &target else {
+        unreachable!();
+    };
+    assert_eq!(
+        heap.stats()
+            .expect("heap stats should be available")
+            .live_allocations,
+        0
+    );
+}
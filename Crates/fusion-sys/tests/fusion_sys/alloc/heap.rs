@@ -0,0 +1,429 @@
+use fusion_sys::alloc::{
+    AllocCapabilities,
+    AllocErrorKind,
+    AllocHazards,
+    AllocPolicy,
+    AllocRequest,
+    AllocationStrategy,
+    Allocator,
+    AllocatorDomainId,
+    HeapAllocator,
+};
+use fusion_sys::mem::resource::{
+    MemoryDomain,
+    ResourceAttrs,
+    ResourceBackingKind,
+};
+
+use super::support::bound_resource;
+
+extern crate std;
+use self::std::sync::{
+    Arc,
+    Barrier,
+};
+use self::std::thread;
+use self::std::vec::Vec;
+
+const fn request(len: usize, align: usize) -> AllocRequest {
+    AllocRequest {
+        len,
+        align,
+        zeroed: false,
+    }
+}
+
+fn bounded_heap_allocator(capacity: usize) -> Allocator<2, 2> {
+    let mut builder = Allocator::<2, 2>::builder();
+    builder.policy(AllocPolicy::critical_safe_heap());
+    builder
+        .add_resource(bound_resource(
+            capacity + 16 * 1024,
+            MemoryDomain::StaticRegion,
+            ResourceBackingKind::StaticRegion,
+            ResourceAttrs::ALLOCATABLE | ResourceAttrs::CACHEABLE | ResourceAttrs::COHERENT,
+        ))
+        .expect("resource should fit");
+    builder
+        .heap_capacity(AllocatorDomainId(0), capacity)
+        .expect("default domain should accept a heap capacity");
+    builder.build().expect("allocator should build")
+}
+
+#[test]
+fn heap_serves_mixed_sizes_and_coalesces_back_to_one_block() {
+    let allocator = Allocator::<2, 2>::system_default_with_capacity(256 * 1024)
+        .expect("allocator should build");
+    let heap = allocator
+        .heap(
+            allocator
+                .default_domain()
+                .expect("default domain should exist"),
+        )
+        .expect("general-purpose domain should realize a heap");
+    let initial = heap.stats().expect("heap stats should be available");
+    assert_eq!(initial.free_blocks, 1);
+    assert_eq!(initial.used_bytes, 0);
+
+    let mut allocations = Vec::new();
+    for (fill, len) in (0u8..).zip([1usize, 24, 100, 333, 1024, 4000, 7, 512]) {
+        let align = 1 << (usize::from(fill) % 7);
+        let allocation = heap
+            .allocate(&request(len, align))
+            .expect("heap allocation should succeed");
+        assert!(allocation.len >= len);
+        assert_eq!(allocation.ptr.as_ptr() as usize % align, 0);
+        assert_eq!(allocation.ptr.as_ptr() as usize % HeapAllocator::GRANULE, 0);
+        // SAFETY: the allocation is live and exactly `len` bytes long.
+        unsafe {
+            allocation.ptr.as_ptr().write_bytes(fill, len);
+        }
+        allocations.push((allocation, fill));
+    }
+
+    let busy = heap.stats().expect("heap stats should be available");
+    assert_eq!(busy.live_allocations, 8);
+    assert!(busy.used_bytes > 0);
+    assert_eq!(busy.used_bytes + busy.free_bytes, busy.capacity);
+
+    // Release every other block first so coalescing has to stitch both neighbours back together.
+    let mut retained = Vec::new();
+    for (position, (allocation, fill)) in allocations.into_iter().enumerate() {
+        // SAFETY: the allocation is live and exactly `len` bytes long.
+        let bytes = unsafe { core::slice::from_raw_parts(allocation.ptr.as_ptr(), allocation.len) };
+        assert!(bytes.iter().all(|byte| *byte == fill));
+        if position.is_multiple_of(2) {
+            heap.deallocate(allocation)
+                .expect("heap allocation should release");
+        } else {
+            retained.push(allocation);
+        }
+    }
+    for allocation in retained {
+        heap.deallocate(allocation)
+            .expect("heap allocation should release");
+    }
+
+    let drained = heap.stats().expect("heap stats should be available");
+    assert_eq!(drained.live_allocations, 0);
+    assert_eq!(drained.used_bytes, 0);
+    assert_eq!(drained.free_blocks, 1);
+    assert_eq!(drained.largest_free_block, drained.capacity);
+    assert_eq!(drained.fragmentation_permille(), 0);
+    assert!(drained.peak_used_bytes >= busy.used_bytes);
+}
+
+#[test]
+fn heap_zeroes_requests_and_rejects_invalid_or_foreign_blocks() {
+    let allocator = Allocator::<2, 2>::system_default_with_capacity(256 * 1024)
+        .expect("allocator should build");
+    let default_domain = allocator
+        .default_domain()
+        .expect("default domain should exist");
+    let heap = allocator
+        .heap(default_domain)
+        .expect("general-purpose domain should realize a heap");
+
+    let dirty = heap
+        .allocate(&request(256, 8))
+        .expect("heap allocation should succeed");
+    // SAFETY: the allocation is live and exactly 256 bytes long.
+    unsafe {
+        dirty.ptr.as_ptr().write_bytes(0xa5, 256);
+    }
+    heap.deallocate(dirty)
+        .expect("heap allocation should release");
+    let zeroed = heap
+        .allocate(&AllocRequest::zeroed(256))
+        .expect("zeroed heap allocation should succeed");
+    // SAFETY: the allocation is live and exactly 256 bytes long.
+    let bytes = unsafe { core::slice::from_raw_parts(zeroed.ptr.as_ptr(), 256) };
+    assert!(bytes.iter().all(|byte| *byte == 0));
+    heap.deallocate(zeroed)
+        .expect("zeroed heap allocation should release");
+
+    assert_eq!(
+        heap.allocate(&request(0, 8))
+            .expect_err("zero-length requests should be rejected")
+            .kind,
+        AllocErrorKind::InvalidRequest
+    );
+    assert_eq!(
+        heap.allocate(&request(8, 3))
+            .expect_err("non-power-of-two alignment should be rejected")
+            .kind,
+        AllocErrorKind::InvalidRequest
+    );
+
+    let slab = allocator
+        .slab::<64, 2>(default_domain)
+        .expect("slab should reserve backing");
+    let foreign = slab
+        .allocate(&AllocRequest::new(64))
+        .expect("slab allocation should succeed");
+    let error = heap
+        .deallocate(foreign)
+        .expect_err("slab-backed allocations must not release through the heap");
+    assert_eq!(error.kind, AllocErrorKind::InvalidRequest);
+}
+
+#[test]
+fn heap_reallocate_grows_in_place_or_relocates_with_contents() {
+    let allocator = Allocator::<2, 2>::system_default_with_capacity(256 * 1024)
+        .expect("allocator should build");
+    let heap = allocator
+        .heap(
+            allocator
+                .default_domain()
+                .expect("default domain should exist"),
+        )
+        .expect("general-purpose domain should realize a heap");
+
+    let mut growing = heap
+        .allocate(&request(64, 16))
+        .expect("heap allocation should succeed");
+    // SAFETY: the allocation is live and exactly 64 bytes long.
+    unsafe {
+        growing.ptr.as_ptr().write_bytes(0x11, 64);
+    }
+    let original = growing.ptr;
+    heap.reallocate(&mut growing, 512)
+        .expect("free successor should allow in-place growth");
+    assert_eq!(growing.ptr, original);
+    assert_eq!(growing.len, 512);
+
+    let blocker = heap
+        .allocate(&request(32, 16))
+        .expect("heap allocation should succeed");
+    heap.reallocate(&mut growing, 4096)
+        .expect("blocked growth should relocate");
+    assert_ne!(growing.ptr, original);
+    // SAFETY: the relocated allocation is live and at least 64 bytes long.
+    let bytes = unsafe { core::slice::from_raw_parts(growing.ptr.as_ptr(), 64) };
+    assert!(bytes.iter().all(|byte| *byte == 0x11));
+
+    heap.reallocate(&mut growing, 16)
+        .expect("shrinking should always succeed in place");
+    assert_eq!(growing.len, 16);
+    assert_eq!(
+        heap.reallocate(&mut growing, 0)
+            .expect_err("zero-length resize should be rejected")
+            .kind,
+        AllocErrorKind::InvalidRequest
+    );
+
+    heap.deallocate(growing)
+        .expect("resized allocation should release");
+    heap.deallocate(blocker)
+        .expect("blocking allocation should release");
+    assert_eq!(heap.stats().expect("heap stats").free_blocks, 1);
+}
+
+#[test]
+fn heap_allocation_token_releases_on_drop_and_resizes() {
+    let allocator = Allocator::<2, 2>::system_default_with_capacity(256 * 1024)
+        .expect("allocator should build");
+    let heap = allocator
+        .heap(
+            allocator
+                .default_domain()
+                .expect("default domain should exist"),
+        )
+        .expect("general-purpose domain should realize a heap");
+
+    {
+        let mut allocation = heap
+            .alloc(&AllocRequest::zeroed(48))
+            .expect("direct heap allocation should succeed");
+        assert_eq!(allocation.len(), 48);
+        allocation
+            .as_bytes_mut()
+            .expect("live allocation should expose bytes")
+            .fill(7);
+        allocation
+            .resize(96)
+            .expect("direct heap allocation should resize");
+        assert_eq!(allocation.len(), 96);
+        assert!(
+            allocation
+                .as_bytes()
+                .expect("live allocation should expose bytes")[..48]
+                .iter()
+                .all(|byte| *byte == 7)
+        );
+        assert_eq!(heap.stats().expect("heap stats").live_allocations, 1);
+    }
+    assert_eq!(heap.stats().expect("heap stats").live_allocations, 0);
+
+    let mut released = heap
+        .alloc(&AllocRequest::new(8))
+        .expect("direct heap allocation should succeed");
+    released
+        .try_release()
+        .expect("early release should succeed");
+    assert!(!released.is_live());
+    assert_eq!(
+        released
+            .try_release()
+            .expect_err("double release should be rejected")
+            .kind,
+        AllocErrorKind::InvalidRequest
+    );
+}
+
+#[test]
+fn growable_heap_acquires_additional_pool_extents() {
+    let allocator = Allocator::<2, 2>::system_default_with_capacity(512 * 1024)
+        .expect("allocator should build");
+    let default_domain = allocator
+        .default_domain()
+        .expect("default domain should exist");
+    let heap = allocator
+        .heap(default_domain)
+        .expect("general-purpose domain should realize a heap");
+    assert!(heap.is_growable());
+    assert!(heap.hazards().contains(AllocHazards::EXTERNAL_GROWTH));
+    assert!(
+        !heap
+            .capabilities()
+            .contains(AllocCapabilities::DETERMINISTIC)
+    );
+
+    let large = heap
+        .allocate(&request(HeapAllocator::DEFAULT_CAPACITY * 2, 64))
+        .expect("growable heap should extend itself past its initial capacity");
+    let stats = heap.stats().expect("heap stats should be available");
+    assert!(stats.segments >= 2);
+    assert!(stats.capacity > HeapAllocator::DEFAULT_CAPACITY * 2);
+    heap.deallocate(large)
+        .expect("grown allocation should release");
+
+    let audit = allocator
+        .domain_audit(default_domain)
+        .expect("domain audit should be available");
+    let heap_stats = audit
+        .heap_stats
+        .expect("realized heap should report into the domain audit");
+    assert_eq!(heap_stats.segments, stats.segments);
+    assert_eq!(heap_stats.live_allocations, 0);
+}
+
+#[test]
+fn deterministic_heap_requires_declared_capacity_and_never_grows() {
+    let mut builder = Allocator::<2, 2>::builder();
+    builder.policy(AllocPolicy::critical_safe_heap());
+    builder
+        .add_resource(bound_resource(
+            64 * 1024,
+            MemoryDomain::StaticRegion,
+            ResourceBackingKind::StaticRegion,
+            ResourceAttrs::ALLOCATABLE | ResourceAttrs::CACHEABLE | ResourceAttrs::COHERENT,
+        ))
+        .expect("resource should fit");
+    let undeclared = builder.build().expect("allocator should build");
+    assert_eq!(
+        undeclared
+            .malloc(64)
+            .expect_err("deterministic domains must declare heap capacity")
+            .kind,
+        AllocErrorKind::CapacityExhausted
+    );
+
+    let allocator = bounded_heap_allocator(4096);
+    let audit = allocator
+        .domain_audit(AllocatorDomainId(0))
+        .expect("domain audit should be available");
+    assert!(
+        audit.heap_stats.is_some(),
+        "declared heaps realize at build time"
+    );
+    assert!(allocator.capabilities().contains(AllocCapabilities::HEAP));
+    assert!(
+        allocator
+            .capabilities()
+            .contains(AllocCapabilities::DETERMINISTIC | AllocCapabilities::BOUNDED)
+    );
+
+    let heap = allocator
+        .heap(AllocatorDomainId(0))
+        .expect("declared heap should be shared");
+    assert!(!heap.is_growable());
+    assert!(!heap.hazards().contains(AllocHazards::EXTERNAL_GROWTH));
+
+    let mut allocations = Vec::new();
+    while let Ok(allocation) = heap.allocate(&request(256, 16)) {
+        allocations.push(allocation);
+    }
+    assert!(!allocations.is_empty());
+    let stats = heap.stats().expect("heap stats should be available");
+    assert_eq!(stats.segments, 1);
+    assert!(stats.failed_allocations >= 1);
+    assert!(stats.capacity <= 4096 + HeapAllocator::GRANULE);
+    for allocation in allocations {
+        heap.deallocate(allocation)
+            .expect("bounded heap allocation should release");
+    }
+}
+
+#[test]
+fn allocator_root_routes_malloc_family_through_default_heap() {
+    let allocator = Allocator::<2, 2>::system_default_with_capacity(256 * 1024)
+        .expect("allocator should build");
+    let mut allocation = allocator.calloc(128).expect("calloc should succeed");
+    // SAFETY: the allocation is live and exactly 128 bytes long.
+    let bytes = unsafe { core::slice::from_raw_parts(allocation.ptr.as_ptr(), 128) };
+    assert!(bytes.iter().all(|byte| *byte == 0));
+    allocator
+        .realloc(&mut allocation, 1024)
+        .expect("realloc should succeed");
+    assert_eq!(allocation.len, 1024);
+    allocator.free(allocation).expect("free should succeed");
+
+    let plain = allocator.malloc(32).expect("malloc should succeed");
+    allocator.free(plain).expect("free should succeed");
+}
+
+#[test]
+fn heap_serializes_concurrent_allocation() {
+    let allocator = Arc::new(
+        Allocator::<2, 2>::system_default_with_capacity(512 * 1024)
+            .expect("allocator should build"),
+    );
+    let heap = Arc::new(
+        allocator
+            .heap(
+                allocator
+                    .default_domain()
+                    .expect("default domain should exist"),
+            )
+            .expect("general-purpose domain should realize a heap"),
+    );
+    let barrier = Arc::new(Barrier::new(4));
+    let workers = (0u8..4)
+        .map(|fill| {
+            let heap = Arc::clone(&heap);
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                for round in 0..64 {
+                    let len = 16 + ((usize::from(fill) * 64 + round) % 200);
+                    let allocation = heap
+                        .allocate(&request(len, 8))
+                        .expect("concurrent heap allocation should succeed");
+                    // SAFETY: the allocation is live and exactly `len` bytes long.
+                    unsafe {
+                        allocation.ptr.as_ptr().write_bytes(fill, len);
+                    }
+                    heap.deallocate(allocation)
+                        .expect("concurrent heap allocation should release");
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for worker in workers {
+        worker.join().expect("heap worker should finish");
+    }
+    let stats = heap.stats().expect("heap stats should be available");
+    assert_eq!(stats.live_allocations, 0);
+    assert_eq!(stats.used_bytes, 0);
+}
@@ -6,6 +6,7 @@ use fusion_sys::event::{
     EventCaps,
     EventCompletionOp,
     EventCompletionOpKind,
+    EventCompletionTarget,
     EventErrorKind,
     EventModel,
     EventSourceHandle,
@@ -52,6 +53,7 @@ fn create_and_submit_follow_backend_truth() {
         EventCompletionOp {
             source: EventSourceHandle(0),
             kind: EventCompletionOpKind::Custom(7),
+            target: EventCompletionTarget::None,
             user_data: 99,
         },
     );
@@ -4,7 +4,13 @@ use core::time::Duration;
 
 use fusion_sys::event::{
     EventCaps,
+    EventCompletionBuffer,
+    EventCompletionOp,
+    EventCompletionOpKind,
+    EventCompletionTarget,
+    EventErrorKind,
     EventInterest,
+    EventKey,
     EventModel,
     EventNotification,
     EventReadiness,
@@ -61,7 +67,6 @@ impl Drop for TestPipe {
 fn linux_event_support_reports_native_readiness_backend() {
     let support = EventSystem::new().support();
 
-    assert_eq!(support.model, EventModel::Readiness);
     assert_eq!(
         support.implementation,
         fusion_sys::event::EventImplementationKind::Native
@@ -69,8 +74,88 @@ fn linux_event_support_reports_native_readiness_backend() {
     assert!(support.caps.contains(EventCaps::READINESS));
     assert!(support.caps.contains(EventCaps::LEVEL_TRIGGERED));
     assert!(support.caps.contains(EventCaps::TIMEOUT));
-    assert!(!support.caps.contains(EventCaps::COMPLETION));
+    // Completions ride on io_uring, which kernels and sandboxes are free to refuse.
+    if support.model == EventModel::Hybrid {
+        assert!(support.caps.contains(EventCaps::COMPLETION));
+        assert!(support.caps.contains(EventCaps::SUBMIT));
+    } else {
+        assert_eq!(support.model, EventModel::Readiness);
+        assert!(!support.caps.contains(EventCaps::COMPLETION));
+        assert!(!support.caps.contains(EventCaps::SUBMIT));
+    }
+}
+
+#[test]
+fn linux_readiness_event_never_offers_completions() {
+    let event = EventSystem::readiness();
+    let support = event.support();
+
+    assert_eq!(support.model, EventModel::Readiness);
+    assert!(support.caps.contains(EventCaps::READINESS));
     assert!(!support.caps.contains(EventCaps::SUBMIT));
+    let mut poller = event.create().expect("poller should create");
+    let pipe = TestPipe::new();
+    let mut input = [0_u8; 1];
+    let error = event
+        .submit(
+            &mut poller,
+            EventCompletionOp {
+                source: pipe.source(),
+                kind: EventCompletionOpKind::Read,
+                target: EventCompletionTarget::Buffer {
+                    buffer: unsafe { EventCompletionBuffer::new(input.as_mut_ptr(), input.len()) },
+                    offset: None,
+                },
+                user_data: 7,
+            },
+        )
+        .expect_err("readiness pollers refuse submissions");
+    assert_eq!(error.kind(), EventErrorKind::Unsupported);
+}
+
+#[test]
+fn linux_event_completes_submitted_pipe_reads() {
+    let event = EventSystem::new();
+    if !event.support().caps.contains(EventCaps::SUBMIT) {
+        return;
+    }
+    let mut poller = event.create().expect("poller should create");
+    let pipe = TestPipe::new();
+    let mut input = [0_u8; 1];
+
+    let key = event
+        .submit(
+            &mut poller,
+            EventCompletionOp {
+                source: pipe.source(),
+                kind: EventCompletionOpKind::Read,
+                target: EventCompletionTarget::Buffer {
+                    buffer: unsafe { EventCompletionBuffer::new(input.as_mut_ptr(), input.len()) },
+                    offset: None,
+                },
+                user_data: 7,
+            },
+        )
+        .expect("read should submit");
+    pipe.write_byte(b'x');
+
+    let mut events = [EventRecord {
+        key,
+        notification: EventNotification::Readiness(EventReadiness::empty()),
+    }; 4];
+    let ready = event
+        .poll(&mut poller, &mut events, Some(Duration::from_secs(1)))
+        .expect("poll should succeed");
+    assert_eq!(ready, 1);
+    assert_eq!(events[0].key, EventKey(7));
+    match events[0].notification {
+        EventNotification::Completion(completion) => {
+            assert!(completion.success);
+            assert_eq!(completion.bytes_transferred, Some(1));
+        }
+        EventNotification::Readiness(_) => panic!("submitted reads should complete"),
+    }
+    assert_eq!(input, [b'x']);
 }
 
 #[test]
@@ -14,6 +14,7 @@ use fusion_sys::thread::{
     MonotonicDeadlineWaitKind,
     MonotonicRuntimeTimeCaps,
     RuntimeBackingPreference,
+    SystemResizePolicy,
     SystemThreadPool,
     SystemThreadPoolConfig,
     SystemWorkItem,
@@ -636,3 +637,184 @@ fn system_thread_pool_cancels_queued_work_with_cleanup_hook() {
     assert_eq!(executed.load(Ordering::Acquire), 0);
     assert_eq!(canceled.load(Ordering::Acquire), 1);
 }
+
+fn wait_until(limit: Duration, mut condition: impl FnMut() -> bool) -> bool {
+    let deadline = std::time::Instant::now() + limit;
+    while std::time::Instant::now() < deadline {
+        if condition() {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+    condition()
+}
+
+#[test]
+fn system_thread_pool_grows_elastically_and_retires_idle_workers() {
+    let config = SystemThreadPoolConfig {
+        min_threads: 1,
+        max_threads: 4,
+        resize_policy: SystemResizePolicy::Elastic,
+        idle_linger: Duration::from_millis(20),
+        ..SystemThreadPoolConfig::new()
+    };
+    let pool = SystemThreadPool::new(ThreadSystem::new(), &config)
+        .expect("elastic thread pool should build on supported backend");
+    assert_eq!(pool.worker_count().expect("worker count"), 1);
+
+    let started = Arc::new(AtomicU32::new(0));
+    let release = Arc::new(AtomicU32::new(0));
+    let blocking = BlockingPoolContext {
+        started: Arc::clone(&started),
+        release: Arc::clone(&release),
+    };
+    for _ in 0..4 {
+        pool.submit(SystemWorkItem::new(
+            blocking_pool_entry,
+            (&raw const blocking).cast_mut().cast(),
+        ))
+        .expect("elastic pool should accept blocking work");
+    }
+    let grown = wait_until(Duration::from_secs(5), || {
+        started.load(Ordering::Acquire) == 4
+    });
+    release.store(1, Ordering::Release);
+    assert!(
+        grown,
+        "backlog should have grown the pool to run every blocker"
+    );
+    assert_eq!(pool.worker_count().expect("worker count"), 4);
+
+    assert!(
+        wait_until(Duration::from_secs(5), || {
+            pool.worker_count().expect("worker count") == 1
+        }),
+        "idle workers above the minimum should retire after the linger"
+    );
+    let stats = pool.stats().expect("stats should be observable");
+    assert_eq!(stats.spawned_workers, 4);
+    assert_eq!(stats.retired_workers, 3);
+
+    let completed = AtomicU32::new(0);
+    for _ in 0..8 {
+        pool.submit(SystemWorkItem::new(
+            pool_entry,
+            (&raw const completed).cast_mut().cast(),
+        ))
+        .expect("shrunk elastic pool should keep accepting work");
+    }
+    pool.shutdown().expect("pool should drain queued work");
+    assert_eq!(completed.load(Ordering::Acquire), 8);
+}
+
+#[test]
+fn system_thread_pool_resizes_manually_and_rehomes_queued_work() {
+    let config = SystemThreadPoolConfig {
+        min_threads: 1,
+        max_threads: 3,
+        resize_policy: SystemResizePolicy::Manual,
+        ..SystemThreadPoolConfig::new()
+    };
+    let pool = SystemThreadPool::new(ThreadSystem::new(), &config)
+        .expect("manual thread pool should build on supported backend");
+    assert_eq!(pool.worker_count().expect("worker count"), 1);
+
+    pool.resize(3).expect("manual pool should grow");
+    assert_eq!(pool.worker_count().expect("worker count"), 3);
+    assert_eq!(
+        pool.resize(4)
+            .expect_err("resize above max_threads should be refused")
+            .kind(),
+        ThreadErrorKind::Invalid
+    );
+
+    let started = Arc::new(AtomicU32::new(0));
+    let release = Arc::new(AtomicU32::new(0));
+    let blocking = BlockingPoolContext {
+        started: Arc::clone(&started),
+        release: Arc::clone(&release),
+    };
+    pool.submit(SystemWorkItem::new(
+        blocking_pool_entry,
+        (&raw const blocking).cast_mut().cast(),
+    ))
+    .expect("blocking work should enter the queue");
+    while started.load(Ordering::Acquire) == 0 {
+        core::hint::spin_loop();
+    }
+    let completed = AtomicU32::new(0);
+    for _ in 0..12 {
+        pool.submit(SystemWorkItem::new(
+            pool_entry,
+            (&raw const completed).cast_mut().cast(),
+        ))
+        .expect("pool should accept submitted work");
+    }
+
+    let releaser = {
+        let release = Arc::clone(&release);
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            release.store(1, Ordering::Release);
+        })
+    };
+    pool.resize(1).expect("manual pool should shrink");
+    releaser.join().expect("releaser should join");
+    let stats = pool.stats().expect("stats should be observable");
+    assert_eq!(stats.active_workers, 1);
+    assert_eq!(stats.spawned_workers, 3);
+    assert_eq!(stats.retired_workers, 2);
+
+    // Retired carriers exit on their own once their item finishes; until then their index
+    // cannot be reused.
+    assert!(
+        wait_until(Duration::from_secs(5), || match pool.resize(2) {
+            Ok(()) => true,
+            Err(error) => {
+                assert_eq!(error.kind(), ThreadErrorKind::Busy);
+                false
+            }
+        }),
+        "manual pool should grow back into retired slots"
+    );
+    pool.shutdown().expect("pool should drain queued work");
+    assert_eq!(completed.load(Ordering::Acquire), 12);
+}
+
+#[test]
+fn system_thread_pool_resize_is_policy_gated() {
+    let fixed = SystemThreadPool::new(ThreadSystem::new(), &SystemThreadPoolConfig::new())
+        .expect("thread pool should build on supported backend");
+    assert_eq!(
+        fixed
+            .resize(1)
+            .expect_err("fixed pools should refuse explicit resizing")
+            .kind(),
+        ThreadErrorKind::Unsupported
+    );
+
+    let unbounded_fixed = SystemThreadPoolConfig {
+        min_threads: 1,
+        max_threads: 2,
+        ..SystemThreadPoolConfig::new()
+    };
+    assert_eq!(
+        SystemThreadPool::new(ThreadSystem::new(), &unbounded_fixed)
+            .expect_err("fixed pools need matching bounds")
+            .kind(),
+        ThreadErrorKind::Unsupported
+    );
+
+    let lingerless = SystemThreadPoolConfig {
+        max_threads: 2,
+        resize_policy: SystemResizePolicy::Elastic,
+        idle_linger: Duration::ZERO,
+        ..SystemThreadPoolConfig::new()
+    };
+    assert_eq!(
+        SystemThreadPool::new(ThreadSystem::new(), &lingerless)
+            .expect_err("elastic pools need a non-zero linger")
+            .kind(),
+        ThreadErrorKind::Invalid
+    );
+}
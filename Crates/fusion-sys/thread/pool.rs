@@ -1,7 +1,7 @@
 //! Low-level bounded carrier-pool primitive.
 //!
 //! This pool stays intentionally narrow:
-//! - fixed worker count only for now
+//! - fixed, manual, or elastic worker count bounded by `max_threads`
 //! - bounded raw work queue
 //! - deterministic shutdown policy over the queued work already admitted
 //! - no hidden allocation
@@ -17,6 +17,7 @@ use core::sync::atomic::{
     AtomicU32,
     Ordering,
 };
+use core::time::Duration;
 
 use fusion_pal::contract::pal::HardwareTopologyQueryContract as _;
 use fusion_pal::sys::cpu::system_cpu;
@@ -72,6 +73,8 @@ const MAX_POOL_WORKERS: usize = 32;
 const MAX_POOL_QUEUE_ITEMS: usize = 16;
 #[cfg(not(feature = "sys-cortex-m"))]
 const MAX_POOL_QUEUE_ITEMS: usize = 256;
+const MAX_POOL_NAME_BYTES: usize = 32;
+const DEFAULT_IDLE_LINGER: Duration = Duration::from_secs(1);
 const ZERO_LOGICAL_CPU: ThreadLogicalCpuId = ThreadLogicalCpuId {
     group: fusion_pal::sys::thread::ThreadProcessorGroupId(0),
     index: 0,
@@ -88,7 +91,7 @@ pub static FUSION_SYSTEM_POOL_WORKER_DEQUEUE_COUNT: AtomicU32 = AtomicU32::new(0
 #[unsafe(no_mangle)]
 pub static FUSION_SYSTEM_POOL_WORKER_BUSY_COUNT: AtomicU32 = AtomicU32::new(0);
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 enum WorkerPlacement<'a> {
     LogicalCpus([ThreadLogicalCpuId; MAX_POOL_WORKERS]),
     CoreClasses(&'a [ThreadCoreClassId]),
@@ -215,9 +218,9 @@ pub enum SystemStealBoundary {
 pub enum SystemResizePolicy {
     /// Worker count is fixed after startup.
     Fixed,
-    /// Worker count may be adjusted only through explicit management calls.
+    /// Worker count may be adjusted only through explicit [`SystemThreadPool::resize`] calls.
     Manual,
-    /// Worker count may be adjusted elastically later.
+    /// Workers are spawned under backlog and retired again after idling past the linger.
     Elastic,
 }
 
@@ -247,9 +250,13 @@ pub struct SystemThreadPoolConfig<'a> {
     pub spawn_locality_policy: CarrierSpawnLocalityPolicy,
     /// Whether the carrier count may change later.
     pub resize_policy: SystemResizePolicy,
+    /// How long an elastic worker above `min_threads` may sit idle before it retires.
+    pub idle_linger: Duration,
     /// Shutdown behavior for existing workers and queued work.
     pub shutdown_policy: SystemShutdownPolicy,
     /// Optional worker-name prefix.
+    ///
+    /// Resizable pools copy the prefix so later spawns can reuse it, which bounds it to 32 bytes.
     pub name_prefix: Option<&'a str>,
     /// Stack request applied to workers.
     pub stack: ThreadStackRequest,
@@ -268,6 +275,7 @@ impl SystemThreadPoolConfig<'_> {
             steal_boundary: SystemStealBoundary::LocalOnly,
             spawn_locality_policy: CarrierSpawnLocalityPolicy::SameCore,
             resize_policy: SystemResizePolicy::Fixed,
+            idle_linger: DEFAULT_IDLE_LINGER,
             shutdown_policy: SystemShutdownPolicy::Drain,
             name_prefix: None,
             stack: ThreadStackRequest::new(),
@@ -293,6 +301,10 @@ pub struct SystemThreadPoolStats {
     pub active_workers: usize,
     /// Queued work items.
     pub queued_items: usize,
+    /// Workers spawned since the pool was created, including the startup workers.
+    pub spawned_workers: u64,
+    /// Workers retired by elastic linger expiry or manual shrinking.
+    pub retired_workers: u64,
 }
 
 /// Low-level system thread pool error.
@@ -304,9 +316,49 @@ pub struct SystemThreadPool {
     system: ThreadSystem,
     min_threads: usize,
     max_threads: usize,
+    resize_policy: SystemResizePolicy,
+    template: Option<PoolSpawnTemplate>,
     slot_index: Option<usize>,
 }
 
+/// Owned copy of the spawn parameters a resizable pool needs after its config borrow ends.
+#[derive(Debug, Clone, Copy)]
+struct PoolSpawnTemplate {
+    name: [u8; MAX_POOL_NAME_BYTES],
+    name_len: Option<usize>,
+    logical_cpus: Option<[ThreadLogicalCpuId; MAX_POOL_WORKERS]>,
+    stack: ThreadStackRequest,
+    scheduler: ThreadSchedulerRequest,
+}
+
+// SAFETY: resizable pools reject caller-provided stack backing during validation, so the captured
+// stack request never carries a raw stack pointer; the rest of the template is plain data.
+unsafe impl Send for PoolSpawnTemplate {}
+// SAFETY: see the `Send` justification above; shared access never mutates the template.
+unsafe impl Sync for PoolSpawnTemplate {}
+
+#[derive(Clone, Copy)]
+struct WorkerSpawnSpec<'a> {
+    name: Option<&'a str>,
+    placement: Option<WorkerPlacement<'a>>,
+    stack: ThreadStackRequest,
+    scheduler: ThreadSchedulerRequest,
+}
+
+/// Worker index claimed ahead of a spawn, plus any exited carrier still parked in that index.
+#[derive(Debug)]
+struct WorkerReservation {
+    worker_index: usize,
+    stale: Option<ThreadHandle>,
+    elastic: bool,
+}
+
+enum WorkerStep {
+    Run(SystemWorkItem),
+    Idle,
+    Exit,
+}
+
 #[derive(Debug)]
 struct PoolRegistryStorage {
     lock: ThinMutex,
@@ -314,6 +366,7 @@ struct PoolRegistryStorage {
 }
 
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 struct PoolSlot {
     queue_lock: ThinMutex,
     work_ready: [Option<Semaphore>; MAX_POOL_WORKERS],
@@ -322,15 +375,22 @@ struct PoolSlot {
     workers: [Option<ThreadHandle>; MAX_POOL_WORKERS],
     worker_stack_backing: [Option<OwnedRuntimeSlab>; MAX_POOL_WORKERS],
     worker_observations: [Option<CarrierObservation>; MAX_POOL_WORKERS],
+    retiring: [bool; MAX_POOL_WORKERS],
     allocated: bool,
     accepting: bool,
     shutting_down: bool,
     shutdown_policy: SystemShutdownPolicy,
     steal_boundary: SystemStealBoundary,
     spawn_locality_policy: CarrierSpawnLocalityPolicy,
+    resize_policy: SystemResizePolicy,
+    idle_linger: Duration,
     min_threads: usize,
     max_threads: usize,
     worker_count: usize,
+    idle_workers: usize,
+    resizing: bool,
+    spawned_workers: u64,
+    retired_workers: u64,
     queued_items: usize,
     free_head: Option<u16>,
     next_worker: usize,
@@ -359,15 +419,22 @@ impl PoolSlot {
             workers: array::from_fn(|_| None),
             worker_stack_backing: array::from_fn(|_| None),
             worker_observations: [None; MAX_POOL_WORKERS],
+            retiring: [false; MAX_POOL_WORKERS],
             allocated: false,
             accepting: false,
             shutting_down: false,
             shutdown_policy: SystemShutdownPolicy::Drain,
             steal_boundary: SystemStealBoundary::LocalOnly,
             spawn_locality_policy: CarrierSpawnLocalityPolicy::SameCore,
+            resize_policy: SystemResizePolicy::Fixed,
+            idle_linger: DEFAULT_IDLE_LINGER,
             min_threads: 0,
             max_threads: 0,
             worker_count: 0,
+            idle_workers: 0,
+            resizing: false,
+            spawned_workers: 0,
+            retired_workers: 0,
             queued_items: 0,
             free_head: None,
             next_worker: 0,
@@ -399,9 +466,15 @@ impl PoolSlot {
         self.shutdown_policy = config.shutdown_policy;
         self.steal_boundary = config.steal_boundary;
         self.spawn_locality_policy = config.spawn_locality_policy;
+        self.resize_policy = config.resize_policy;
+        self.idle_linger = config.idle_linger;
         self.min_threads = config.min_threads;
         self.max_threads = config.max_threads;
         self.worker_count = worker_count;
+        self.idle_workers = 0;
+        self.resizing = false;
+        self.spawned_workers = 0;
+        self.retired_workers = 0;
         self.queued_items = 0;
         self.next_worker = 0;
         self.worker_queues = [WorkerQueue::empty(); MAX_POOL_WORKERS];
@@ -615,6 +688,192 @@ impl PoolSlot {
             while semaphore.try_acquire().unwrap_or(false) {}
         }
     }
+
+    fn release_worker(&self, worker_index: usize, permits: usize) -> Result<(), ThreadError> {
+        if permits == 0 {
+            return Ok(());
+        }
+        let permits = u32::try_from(permits).map_err(|_| ThreadError::invalid())?;
+        self.work_ready[worker_index]
+            .as_ref()
+            .ok_or_else(ThreadError::unsupported)?
+            .release(permits)
+            .map_err(thread_error_from_sync)
+    }
+
+    const fn idle_linger(&self) -> Option<Duration> {
+        match self.resize_policy {
+            SystemResizePolicy::Elastic => Some(self.idle_linger),
+            SystemResizePolicy::Fixed | SystemResizePolicy::Manual => None,
+        }
+    }
+
+    /// Splices one worker queue onto the tail of another, returning how many items moved.
+    fn migrate_queue(&mut self, from: usize, to: usize) -> usize {
+        if from == to {
+            return 0;
+        }
+        let source = core::mem::replace(&mut self.worker_queues[from], WorkerQueue::empty());
+        let Some(head) = source.head else {
+            return 0;
+        };
+        let target = &mut self.worker_queues[to];
+        if let Some(tail) = target.tail {
+            self.queue_slots[usize::from(tail)].next = Some(head);
+        } else {
+            target.head = Some(head);
+        }
+        target.tail = source.tail;
+        target.queued_items += source.queued_items;
+        source.queued_items
+    }
+
+    fn reserve_worker(&mut self, elastic: bool) -> WorkerReservation {
+        let worker_index = self.worker_count;
+        self.worker_count += 1;
+        self.worker_observations[worker_index] = None;
+        // Any permits left behind by the previous occupant would otherwise wake the new carrier
+        // for work it never received.
+        if let Some(semaphore) = self.work_ready[worker_index].as_ref() {
+            while semaphore.try_acquire().unwrap_or(false) {}
+        }
+        WorkerReservation {
+            worker_index,
+            stale: self.workers[worker_index].take(),
+            elastic,
+        }
+    }
+
+    fn reserve_elastic_worker(&mut self) -> Option<WorkerReservation> {
+        if self.resize_policy != SystemResizePolicy::Elastic
+            || self.resizing
+            || !self.accepting
+            || self.shutting_down
+            || self.worker_count >= self.max_threads
+            || self.queued_items == self.queue_slots.len()
+            || self.queued_items < self.idle_workers
+        {
+            return None;
+        }
+        self.resizing = true;
+        Some(self.reserve_worker(true))
+    }
+
+    fn commit_worker(
+        &mut self,
+        worker_index: usize,
+        elastic: bool,
+        handle: ThreadHandle,
+        owned_backing: Option<OwnedRuntimeSlab>,
+    ) {
+        self.workers[worker_index] = Some(handle);
+        self.worker_stack_backing[worker_index] = owned_backing;
+        self.spawned_workers = self.spawned_workers.saturating_add(1);
+        if elastic {
+            self.resizing = false;
+        }
+    }
+
+    fn cancel_reservation(
+        &mut self,
+        worker_index: usize,
+        elastic: bool,
+        stale: Option<ThreadHandle>,
+    ) -> Result<(), ThreadError> {
+        if stale.is_some() {
+            self.workers[worker_index] = stale;
+        }
+        self.worker_count = worker_index;
+        if elastic {
+            self.resizing = false;
+        }
+        if self.worker_count == 0 {
+            return Ok(());
+        }
+        let survivor = self.next_worker % self.worker_count;
+        let moved = self.migrate_queue(worker_index, survivor);
+        self.release_worker(survivor, moved)
+    }
+
+    /// Retires every worker at or above `target` without waiting for it to exit.
+    ///
+    /// Each retired carrier keeps its handle and stack parked in its index and clears its
+    /// `retiring` mark once it observes the retirement, after finishing any item it is running.
+    fn shed_workers(&mut self, target: usize) -> Result<(), ThreadError> {
+        let previous = self.worker_count;
+        self.worker_count = target;
+        self.next_worker %= target;
+        for worker_index in target..previous {
+            let survivor = worker_index % target;
+            let moved = self.migrate_queue(worker_index, survivor);
+            self.release_worker(survivor, moved)?;
+            self.release_worker(worker_index, 1)?;
+            self.worker_observations[worker_index] = None;
+            self.retiring[worker_index] = true;
+        }
+        self.retired_workers = self
+            .retired_workers
+            .saturating_add((previous - target) as u64);
+        Ok(())
+    }
+
+    fn retire_idle_worker(&mut self, worker_index: usize) -> bool {
+        if self.resize_policy != SystemResizePolicy::Elastic
+            || self.resizing
+            || self.shutting_down
+            || worker_index + 1 != self.worker_count
+            || self.worker_count <= self.min_threads
+            || self.worker_queues[worker_index].queued_items != 0
+        {
+            return false;
+        }
+        self.worker_count = worker_index;
+        self.next_worker %= self.worker_count;
+        self.worker_observations[worker_index] = None;
+        self.retired_workers = self.retired_workers.saturating_add(1);
+        true
+    }
+}
+
+impl PoolSpawnTemplate {
+    fn capture(
+        config: &SystemThreadPoolConfig<'_>,
+        placement: Option<WorkerPlacement<'_>>,
+    ) -> Result<Self, ThreadError> {
+        let mut name = [0; MAX_POOL_NAME_BYTES];
+        let name_len = match config.name_prefix {
+            Some(prefix) => {
+                name.get_mut(..prefix.len())
+                    .ok_or_else(ThreadError::invalid)?
+                    .copy_from_slice(prefix.as_bytes());
+                Some(prefix.len())
+            }
+            None => None,
+        };
+        let logical_cpus = match placement {
+            Some(WorkerPlacement::LogicalCpus(cpus)) => Some(cpus),
+            Some(WorkerPlacement::CoreClasses(_)) => return Err(ThreadError::unsupported()),
+            None => None,
+        };
+        Ok(Self {
+            name,
+            name_len,
+            logical_cpus,
+            stack: config.stack,
+            scheduler: config.scheduler,
+        })
+    }
+
+    fn spec(&self) -> WorkerSpawnSpec<'_> {
+        WorkerSpawnSpec {
+            name: self
+                .name_len
+                .and_then(|len| core::str::from_utf8(&self.name[..len]).ok()),
+            placement: self.logical_cpus.map(WorkerPlacement::LogicalCpus),
+            stack: self.stack,
+            scheduler: self.scheduler,
+        }
+    }
 }
 
 impl SystemThreadPool {
@@ -626,37 +885,55 @@ impl SystemThreadPool {
 
     /// Creates a carrier pool using the supplied configuration.
     ///
+    /// Fixed pools start `max_threads` workers; manual and elastic pools start `min_threads`
+    /// workers and grow from there.
+    ///
     /// # Errors
     ///
     /// Returns `invalid` for obviously inconsistent bounds and `unsupported` when the
-    /// selected backend cannot honestly realize a fixed bounded worker pool yet.
+    /// selected backend cannot honestly realize the requested bounded worker pool yet.
     pub fn new(
         system: ThreadSystem,
         config: &SystemThreadPoolConfig<'_>,
     ) -> Result<Self, SystemThreadPoolError> {
         validate_pool_config(system.support(), config)?;
         let registry = registry()?;
-        let worker_count = config.max_threads;
+        let placement = resolve_worker_placement(config, config.max_threads)?;
+        let template = match config.resize_policy {
+            SystemResizePolicy::Fixed => None,
+            SystemResizePolicy::Manual | SystemResizePolicy::Elastic => {
+                Some(PoolSpawnTemplate::capture(config, placement)?)
+            }
+        };
+        let worker_count = match config.resize_policy {
+            SystemResizePolicy::Fixed => config.max_threads,
+            SystemResizePolicy::Manual | SystemResizePolicy::Elastic => config.min_threads,
+        };
         let slot_index = allocate_pool_slot(registry, config, worker_count)?;
+        let mut pool = Self {
+            system,
+            min_threads: config.min_threads,
+            max_threads: config.max_threads,
+            resize_policy: config.resize_policy,
+            template,
+            slot_index: Some(slot_index),
+        };
 
-        let spawn_result = spawn_workers(slot_index, system, config, worker_count);
-        if let Err(error) = spawn_result {
-            let mut pool = Self {
-                system,
-                min_threads: config.min_threads,
-                max_threads: config.max_threads,
-                slot_index: Some(slot_index),
-            };
+        let spec = pool.template.as_ref().map_or(
+            WorkerSpawnSpec {
+                name: config.name_prefix,
+                placement,
+                stack: config.stack,
+                scheduler: config.scheduler,
+            },
+            PoolSpawnTemplate::spec,
+        );
+        if let Err(error) = spawn_workers(slot_index, system, &spec, worker_count) {
             let _ = pool.shutdown_inner();
             return Err(error);
         }
 
-        Ok(Self {
-            system,
-            min_threads: config.min_threads,
-            max_threads: config.max_threads,
-            slot_index: Some(slot_index),
-        })
+        Ok(pool)
     }
 
     /// Returns the configured statistics snapshot.
@@ -671,6 +948,8 @@ impl SystemThreadPool {
                 max_threads: self.max_threads,
                 active_workers: 0,
                 queued_items: 0,
+                spawned_workers: 0,
+                retired_workers: 0,
             });
         };
 
@@ -680,6 +959,8 @@ impl SystemThreadPool {
                 max_threads: self.max_threads,
                 active_workers: slot.worker_count,
                 queued_items: slot.queued_items,
+                spawned_workers: slot.spawned_workers,
+                retired_workers: slot.retired_workers,
             })
         })
     }
@@ -693,17 +974,43 @@ impl SystemThreadPool {
         Ok(self.stats()?.active_workers)
     }
 
+    /// Returns the configured resize policy.
+    #[must_use]
+    pub const fn resize_policy(&self) -> SystemResizePolicy {
+        self.resize_policy
+    }
+
     /// Submits one raw work item to the bounded carrier queue.
     ///
+    /// Elastic pools spawn one additional worker for the item when the backlog already
+    /// outnumbers the idle workers and the pool is still below `max_threads`. Growth is
+    /// opportunistic: a failed spawn folds the item back onto an existing worker.
+    ///
     /// # Errors
     ///
     /// Returns an error when the pool is shut down, the queue is full, or the pool can no
     /// longer coordinate submission honestly.
     pub fn submit(&self, work: SystemWorkItem) -> Result<(), SystemThreadPoolError> {
         let slot_index = self.slot_index.ok_or_else(ThreadError::state_conflict)?;
-        let (primary, companion) = with_slot(slot_index, |slot| {
-            let preferred_worker = slot.preferred_worker_for_current();
-            let worker_index = slot.enqueue(work, preferred_worker)?;
+        let (primary, companion, reservation) = with_slot(slot_index, |slot| {
+            let mut reservation = slot.reserve_elastic_worker();
+            let preferred_worker = reservation.as_ref().map_or_else(
+                || slot.preferred_worker_for_current(),
+                |reservation| Some(reservation.worker_index),
+            );
+            let worker_index = match slot.enqueue(work, preferred_worker) {
+                Ok(worker_index) => worker_index,
+                Err(error) => {
+                    if let Some(reservation) = reservation.as_mut() {
+                        slot.cancel_reservation(
+                            reservation.worker_index,
+                            reservation.elastic,
+                            reservation.stale.take(),
+                        )?;
+                    }
+                    return Err(error);
+                }
+            };
             FUSION_SYSTEM_POOL_SUBMIT_COUNT.fetch_add(1, Ordering::AcqRel);
             FUSION_SYSTEM_POOL_LAST_SUBMIT_WORKER.store(worker_index as u32, Ordering::Release);
             Ok((
@@ -711,8 +1018,16 @@ impl SystemThreadPool {
                 slot.companion_worker_for_submission(worker_index)
                     .map(|companion| slot.worker_semaphore_ptr(companion))
                     .transpose()?,
+                reservation,
             ))
         })?;
+        if let Some(reservation) = reservation
+            && self.spawn_reserved(slot_index, reservation).is_err()
+        {
+            // Cancelling the reservation already re-queued and signalled the item on a
+            // surviving worker.
+            return Ok(());
+        }
         // SAFETY: the slot-owned semaphore remains valid while the slot stays allocated.
         // We intentionally release after dropping the queue lock so wakeups do not occur
         // while the caller still serializes access to the ring-buffer state.
@@ -725,6 +1040,51 @@ impl SystemThreadPool {
         Ok(())
     }
 
+    /// Grows or shrinks a manually resized pool to exactly `target` workers.
+    ///
+    /// Shrinking retires the highest-indexed workers and moves their queued work onto the
+    /// survivors without waiting: each retired carrier exits once the item it is running
+    /// finishes, and is joined when its index is reused or the pool shuts down. Work running on
+    /// a retired carrier may therefore keep submitting into the pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns `unsupported` unless the pool uses [`SystemResizePolicy::Manual`], `invalid`
+    /// when `target` falls outside `min_threads..=max_threads`, `busy` while another resize is
+    /// in flight or when growing would reuse an index whose retired carrier is still finishing
+    /// its item, and any honest spawn failure hit while growing. Workers spawned before a
+    /// failure stay in the pool.
+    pub fn resize(&self, target: usize) -> Result<(), SystemThreadPoolError> {
+        if self.resize_policy != SystemResizePolicy::Manual {
+            return Err(ThreadError::unsupported());
+        }
+        if target < self.min_threads || target > self.max_threads {
+            return Err(ThreadError::invalid());
+        }
+        let slot_index = self.slot_index.ok_or_else(ThreadError::state_conflict)?;
+        let current = with_slot(slot_index, |slot| {
+            if !slot.accepting || slot.shutting_down {
+                return Err(ThreadError::state_conflict());
+            }
+            if slot.resizing {
+                return Err(ThreadError::busy());
+            }
+            slot.resizing = true;
+            Ok(slot.worker_count)
+        })?;
+
+        let result = if target > current {
+            self.grow_to(slot_index, target)
+        } else {
+            Self::shrink_to(slot_index, target)
+        };
+        with_slot(slot_index, |slot| {
+            slot.resizing = false;
+            Ok(())
+        })?;
+        result
+    }
+
     /// Shuts the pool down according to its configured shutdown policy.
     ///
     /// # Errors
@@ -741,31 +1101,90 @@ impl SystemThreadPool {
         self.system.support()
     }
 
+    fn grow_to(&self, slot_index: usize, target: usize) -> Result<(), ThreadError> {
+        loop {
+            let reservation = with_slot(slot_index, |slot| {
+                if slot.worker_count >= target {
+                    return Ok(None);
+                }
+                // Reusing the index before its retired carrier has exited would hand the index
+                // to both threads.
+                if slot.retiring[slot.worker_count] {
+                    return Err(ThreadError::busy());
+                }
+                Ok(Some(slot.reserve_worker(false)))
+            })?;
+            let Some(reservation) = reservation else {
+                return Ok(());
+            };
+            self.spawn_reserved(slot_index, reservation)?;
+        }
+    }
+
+    fn shrink_to(slot_index: usize, target: usize) -> Result<(), ThreadError> {
+        // Joining here would deadlock against retired work that waits on the caller, so retired
+        // carriers are reaped like elastic ones: when their index is reused or at shutdown.
+        with_slot(slot_index, |slot| slot.shed_workers(target))
+    }
+
+    /// Joins any exited occupant of a reserved index and spawns its replacement, cancelling
+    /// the reservation if the spawn fails.
+    fn spawn_reserved(
+        &self,
+        slot_index: usize,
+        reservation: WorkerReservation,
+    ) -> Result<(), ThreadError> {
+        let WorkerReservation {
+            worker_index,
+            stale,
+            elastic,
+        } = reservation;
+        if let Some(stale) = stale {
+            let _ = self.system.join(stale);
+        }
+        let spawned = self
+            .template
+            .as_ref()
+            .ok_or_else(ThreadError::unsupported)
+            .and_then(|template| {
+                spawn_worker(slot_index, self.system, &template.spec(), worker_index)
+            });
+        match spawned {
+            Ok((handle, owned_backing)) => with_slot(slot_index, |slot| {
+                slot.commit_worker(worker_index, elastic, handle, owned_backing);
+                Ok(())
+            }),
+            Err(error) => {
+                with_slot(slot_index, |slot| {
+                    slot.cancel_reservation(worker_index, elastic, None)
+                })?;
+                Err(error)
+            }
+        }
+    }
+
     fn shutdown_inner(&mut self) -> Result<(), ThreadError> {
         let Some(slot_index) = self.slot_index.take() else {
             return Ok(());
         };
 
+        // Retired carriers keep their handles parked in the slot until an index is reused, so
+        // shutdown joins every occupied index rather than only the live workers.
         let mut handles: [Option<ThreadHandle>; MAX_POOL_WORKERS] = array::from_fn(|_| None);
-        let worker_count = {
-            let worker_count = with_slot(slot_index, |slot| {
-                slot.accepting = false;
-                slot.shutting_down = true;
-                if !matches!(slot.shutdown_policy, SystemShutdownPolicy::Drain) {
-                    slot.clear_queue();
-                }
+        with_slot(slot_index, |slot| {
+            slot.accepting = false;
+            slot.shutting_down = true;
+            if !matches!(slot.shutdown_policy, SystemShutdownPolicy::Drain) {
+                slot.clear_queue();
+            }
 
-                let worker_count = slot.worker_count;
-                for (dst, src) in handles.iter_mut().zip(slot.workers.iter_mut()) {
-                    *dst = src.take();
-                }
-                slot.release_shutdown_wakeups()?;
-                Ok(worker_count)
-            })?;
-            worker_count
-        };
+            for (dst, src) in handles.iter_mut().zip(slot.workers.iter_mut()) {
+                *dst = src.take();
+            }
+            slot.release_shutdown_wakeups()
+        })?;
 
-        for handle in handles.into_iter().take(worker_count).flatten() {
+        for handle in handles.into_iter().flatten() {
             let _ = self.system.join(handle);
         }
 
@@ -788,8 +1207,12 @@ impl fmt::Display for SystemThreadPoolStats {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "workers {}/{} active, {} queued",
-            self.active_workers, self.max_threads, self.queued_items
+            "workers {}/{} active, {} queued, {} spawned, {} retired",
+            self.active_workers,
+            self.max_threads,
+            self.queued_items,
+            self.spawned_workers,
+            self.retired_workers
         )
     }
 }
@@ -813,11 +1236,31 @@ fn validate_pool_config(
     if config.max_threads > MAX_POOL_WORKERS {
         return Err(ThreadError::resource_exhausted());
     }
-    if config.min_threads != config.max_threads {
-        return Err(ThreadError::unsupported());
-    }
-    if config.resize_policy != SystemResizePolicy::Fixed {
-        return Err(ThreadError::unsupported());
+    match config.resize_policy {
+        SystemResizePolicy::Fixed => {
+            if config.min_threads != config.max_threads {
+                return Err(ThreadError::unsupported());
+            }
+        }
+        SystemResizePolicy::Manual | SystemResizePolicy::Elastic => {
+            // Later spawns reuse a copied spawn template, so borrowed core-class lists and
+            // one caller-provided stack shared across carriers cannot follow the pool.
+            if matches!(config.placement, SystemPoolPlacement::CoreClasses(_)) {
+                return Err(ThreadError::unsupported());
+            }
+            if matches!(
+                config.stack.backing,
+                ThreadStackBacking::CallerProvided { .. }
+            ) || config
+                .name_prefix
+                .is_some_and(|prefix| prefix.len() > MAX_POOL_NAME_BYTES)
+            {
+                return Err(ThreadError::invalid());
+            }
+            if config.resize_policy == SystemResizePolicy::Elastic && config.idle_linger.is_zero() {
+                return Err(ThreadError::invalid());
+            }
+        }
     }
     if !matches!(
         config.placement,
@@ -871,7 +1314,7 @@ fn allocate_pool_slot(
     };
     let slot = &mut slots[slot_index];
 
-    slot.install_worker_semaphores(worker_count)?;
+    slot.install_worker_semaphores(config.max_threads)?;
     slot.allocated = true;
     slot.configure_runtime(config, worker_count);
     Ok(slot_index)
@@ -880,115 +1323,77 @@ fn allocate_pool_slot(
 fn spawn_workers(
     slot_index: usize,
     system: ThreadSystem,
-    config: &SystemThreadPoolConfig<'_>,
+    spec: &WorkerSpawnSpec<'_>,
     worker_count: usize,
 ) -> Result<(), ThreadError> {
-    let worker_placement = resolve_worker_placement(config, worker_count)?;
-    let support = system.support();
-
     for worker_index in 0..worker_count {
-        let token = encode_worker_token(slot_index, worker_index);
-        match worker_placement.as_ref() {
-            Some(WorkerPlacement::LogicalCpus(cpus)) => {
-                let PreparedWorkerStack {
-                    request: stack,
-                    owned_backing,
-                } = prepare_worker_stack_request(support, config)?;
-                let single = &cpus[worker_index..=worker_index];
-                let targets = [ThreadPlacementTarget::LogicalCpus(single)];
-                let placement = fusion_pal::sys::thread::ThreadPlacementRequest {
-                    targets: &targets,
-                    mode: ThreadConstraintMode::Require,
-                    phase: ThreadPlacementPhase::PreStartPreferred,
-                    migration: ThreadMigrationPolicy::Inherit,
-                };
-                let thread_config = ThreadConfig {
-                    join_policy: fusion_pal::sys::thread::ThreadJoinPolicy::Joinable,
-                    name: config.name_prefix,
-                    start_mode: ThreadStartMode::PlacementCommitted,
-                    placement,
-                    scheduler: config.scheduler,
-                    stack,
-                };
-                let handle = unsafe {
-                    system.spawn_raw(
-                        &thread_config,
-                        worker_thread_entry as RawThreadEntry,
-                        token.cast(),
-                    )
-                }?;
-                with_slot(slot_index, |slot| {
-                    slot.workers[worker_index] = Some(handle);
-                    slot.worker_stack_backing[worker_index] = owned_backing;
-                    Ok(())
-                })?;
-                continue;
-            }
-            Some(WorkerPlacement::CoreClasses(classes)) => {
-                let PreparedWorkerStack {
-                    request: stack,
-                    owned_backing,
-                } = prepare_worker_stack_request(support, config)?;
-                let targets = [ThreadPlacementTarget::CoreClasses(classes)];
-                let placement = fusion_pal::sys::thread::ThreadPlacementRequest {
+        let (handle, owned_backing) = spawn_worker(slot_index, system, spec, worker_index)?;
+        with_slot(slot_index, |slot| {
+            slot.commit_worker(worker_index, false, handle, owned_backing);
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+fn spawn_worker(
+    slot_index: usize,
+    system: ThreadSystem,
+    spec: &WorkerSpawnSpec<'_>,
+    worker_index: usize,
+) -> Result<(ThreadHandle, Option<OwnedRuntimeSlab>), ThreadError> {
+    let token = encode_worker_token(slot_index, worker_index);
+    let PreparedWorkerStack {
+        request: stack,
+        owned_backing,
+    } = prepare_worker_stack_request(system.support(), spec.stack)?;
+    let target = match spec.placement.as_ref() {
+        Some(WorkerPlacement::LogicalCpus(cpus)) => Some((
+            ThreadPlacementTarget::LogicalCpus(&cpus[worker_index..=worker_index]),
+            ThreadConstraintMode::Require,
+        )),
+        Some(WorkerPlacement::CoreClasses(classes)) => Some((
+            ThreadPlacementTarget::CoreClasses(classes),
+            ThreadConstraintMode::Prefer,
+        )),
+        None => None,
+    };
+    let targets;
+    let (placement, start_mode) = match target {
+        Some((target, mode)) => {
+            targets = [target];
+            (
+                fusion_pal::sys::thread::ThreadPlacementRequest {
                     targets: &targets,
-                    mode: ThreadConstraintMode::Prefer,
+                    mode,
                     phase: ThreadPlacementPhase::PreStartPreferred,
                     migration: ThreadMigrationPolicy::Inherit,
-                };
-                let thread_config = ThreadConfig {
-                    join_policy: fusion_pal::sys::thread::ThreadJoinPolicy::Joinable,
-                    name: config.name_prefix,
-                    start_mode: ThreadStartMode::PlacementCommitted,
-                    placement,
-                    scheduler: config.scheduler,
-                    stack,
-                };
-                let handle = unsafe {
-                    system.spawn_raw(
-                        &thread_config,
-                        worker_thread_entry as RawThreadEntry,
-                        token.cast(),
-                    )
-                }?;
-                with_slot(slot_index, |slot| {
-                    slot.workers[worker_index] = Some(handle);
-                    slot.worker_stack_backing[worker_index] = owned_backing;
-                    Ok(())
-                })?;
-                continue;
-            }
-            None => {
-                let PreparedWorkerStack {
-                    request: stack,
-                    owned_backing,
-                } = prepare_worker_stack_request(support, config)?;
-                let thread_config = ThreadConfig {
-                    join_policy: fusion_pal::sys::thread::ThreadJoinPolicy::Joinable,
-                    name: config.name_prefix,
-                    start_mode: ThreadStartMode::Immediate,
-                    placement: fusion_pal::sys::thread::ThreadPlacementRequest::new(),
-                    scheduler: config.scheduler,
-                    stack,
-                };
-                let handle = unsafe {
-                    system.spawn_raw(
-                        &thread_config,
-                        worker_thread_entry as RawThreadEntry,
-                        token.cast(),
-                    )
-                }?;
-                with_slot(slot_index, |slot| {
-                    slot.workers[worker_index] = Some(handle);
-                    slot.worker_stack_backing[worker_index] = owned_backing;
-                    Ok(())
-                })?;
-                continue;
-            }
+                },
+                ThreadStartMode::PlacementCommitted,
+            )
         }
-    }
-
-    Ok(())
+        None => (
+            fusion_pal::sys::thread::ThreadPlacementRequest::new(),
+            ThreadStartMode::Immediate,
+        ),
+    };
+    let thread_config = ThreadConfig {
+        join_policy: fusion_pal::sys::thread::ThreadJoinPolicy::Joinable,
+        name: spec.name,
+        start_mode,
+        placement,
+        scheduler: spec.scheduler,
+        stack,
+    };
+    let handle = unsafe {
+        system.spawn_raw(
+            &thread_config,
+            worker_thread_entry as RawThreadEntry,
+            token.cast(),
+        )
+    }?;
+    Ok((handle, owned_backing))
 }
 
 fn resolve_worker_placement<'a>(
@@ -1038,24 +1443,23 @@ struct PreparedWorkerStack {
 
 fn prepare_worker_stack_request(
     support: ThreadSupport,
-    config: &SystemThreadPoolConfig<'_>,
+    stack: ThreadStackRequest,
 ) -> Result<PreparedWorkerStack, ThreadError> {
-    if !matches!(config.stack.backing, ThreadStackBacking::Default) {
+    if !matches!(stack.backing, ThreadStackBacking::Default) {
         return Ok(PreparedWorkerStack {
-            request: config.stack,
+            request: stack,
             owned_backing: None,
         });
     }
 
     let Some(explicit_backing) = support.stack.default_explicit_backing else {
         return Ok(PreparedWorkerStack {
-            request: config.stack,
+            request: stack,
             owned_backing: None,
         });
     };
 
-    let requested_bytes = config
-        .stack
+    let requested_bytes = stack
         .size_bytes
         .unwrap_or(explicit_backing.size_bytes)
         .get();
@@ -1074,7 +1478,7 @@ fn prepare_worker_stack_request(
             base: slab.lease.as_non_null(),
             len,
         },
-        ..config.stack
+        ..stack
     };
 
     Ok(PreparedWorkerStack {
@@ -1147,32 +1551,46 @@ unsafe fn worker_thread_entry(context: *mut ()) -> fusion_pal::sys::thread::Thre
 
     loop {
         FUSION_SYSTEM_POOL_WORKER_PHASE.store(3, Ordering::Release);
-        let Ok(semaphore) = with_slot(slot_index, |slot| slot.worker_semaphore_ptr(worker_index))
-        else {
+        let Ok((semaphore, linger)) = with_slot(slot_index, |slot| {
+            let semaphore = slot.worker_semaphore_ptr(worker_index)?;
+            slot.idle_workers += 1;
+            Ok((semaphore, slot.idle_linger()))
+        }) else {
             return fusion_pal::sys::thread::ThreadEntryReturn::new(2);
         };
 
         let semaphore = unsafe { &*semaphore };
-        if semaphore.acquire().is_err() {
+        let Ok(woken) = wait_for_work(semaphore, linger) else {
             return fusion_pal::sys::thread::ThreadEntryReturn::new(4);
-        }
+        };
         FUSION_SYSTEM_POOL_WORKER_PHASE.store(4, Ordering::Release);
 
-        let next = match with_slot(slot_index, |slot| {
+        let step = match with_slot(slot_index, |slot| {
+            slot.idle_workers = slot.idle_workers.saturating_sub(1);
+            if worker_index >= slot.worker_count {
+                slot.retiring[worker_index] = false;
+                return Ok(WorkerStep::Exit);
+            }
+            if !woken {
+                return Ok(if slot.retire_idle_worker(worker_index) {
+                    WorkerStep::Exit
+                } else {
+                    WorkerStep::Idle
+                });
+            }
             if let Ok(observation) = system_carrier().observe_current() {
                 slot.publish_worker_observation(worker_index, observation);
             }
             Ok(match slot.dequeue_for_worker(worker_index) {
-                Some(item) => Some(item),
+                Some(item) => WorkerStep::Run(item),
                 None => match slot.steal_for_worker(worker_index) {
-                    Some(item) => Some(item),
-                    None if slot.shutting_down => None,
+                    Some(item) => WorkerStep::Run(item),
+                    None if slot.shutting_down => WorkerStep::Exit,
                     None => return Err(ThreadError::busy()),
                 },
             })
         }) {
-            Ok(Some(item)) => Some(item),
-            Ok(None) => None,
+            Ok(step) => step,
             Err(error) if error.kind() == ThreadErrorKind::Busy => {
                 FUSION_SYSTEM_POOL_WORKER_BUSY_COUNT.fetch_add(1, Ordering::AcqRel);
                 continue;
@@ -1180,13 +1598,14 @@ unsafe fn worker_thread_entry(context: *mut ()) -> fusion_pal::sys::thread::Thre
             Err(_) => return fusion_pal::sys::thread::ThreadEntryReturn::new(5),
         };
 
-        match next {
-            Some(item) => {
+        match step {
+            WorkerStep::Run(item) => {
                 FUSION_SYSTEM_POOL_WORKER_DEQUEUE_COUNT.fetch_add(1, Ordering::AcqRel);
                 FUSION_SYSTEM_POOL_WORKER_PHASE.store(5, Ordering::Release);
                 unsafe { (item.entry)(item.context) }
             }
-            None => break,
+            WorkerStep::Idle => {}
+            WorkerStep::Exit => break,
         }
     }
 
@@ -1194,6 +1613,20 @@ unsafe fn worker_thread_entry(context: *mut ()) -> fusion_pal::sys::thread::Thre
     fusion_pal::sys::thread::ThreadEntryReturn::new(0)
 }
 
+/// Waits for one work permit, giving up after `linger` when the pool may retire idle workers.
+///
+/// Backends without timed semaphore waits keep elastic workers parked until work or shutdown
+/// arrives, so such pools grow but never shrink.
+fn wait_for_work(semaphore: &Semaphore, linger: Option<Duration>) -> Result<bool, SyncError> {
+    if let Some(linger) = linger {
+        match semaphore.acquire_for(linger) {
+            Err(error) if error.kind == SyncErrorKind::Unsupported => {}
+            result => return result,
+        }
+    }
+    semaphore.acquire().map(|()| true)
+}
+
 fn with_slot<R>(
     slot_index: usize,
     f: impl FnOnce(&mut PoolSlot) -> Result<R, ThreadError>,
@@ -374,6 +374,19 @@ fn print_metadata_message(message: AllocatorDomainMetadataMessage) {
         AllocatorDomainMetadataMessage::Withdrawn(domain) => {
             println!("metadata: withdrawn {}", allocator_domain_id_value(domain));
         }
+        AllocatorDomainMetadataMessage::AllocationFailed {
+            domain,
+            failure,
+            total,
+        } => {
+            println!(
+                "metadata: allocation-failed {} len={} align={} reason={} total={total}",
+                allocator_domain_id_value(domain),
+                failure.len,
+                failure.align,
+                failure.kind
+            );
+        }
     }
 }
 